@@ -16,28 +16,53 @@ var<uniform> light_radius: f32;
 @group(#{MATERIAL_BIND_GROUP}) @binding(2)
 var<uniform> light_color: vec4<f32>;
 
+// Direction the light cone points along (unit vector)
+@group(#{MATERIAL_BIND_GROUP}) @binding(3)
+var<uniform> light_direction: vec2<f32>;
+
+// Half-angle of the light cone in radians (PI or more = omnidirectional)
+@group(#{MATERIAL_BIND_GROUP}) @binding(4)
+var<uniform> cone_half_angle: f32;
+
+// Lowest light level anywhere (raised in high-contrast mode)
+@group(#{MATERIAL_BIND_GROUP}) @binding(5)
+var<uniform> darkness_floor: f32;
+
 /// Fragment shader for circular gradient lighting
 ///
 /// Calculates lighting intensity based on distance from light source.
-/// Creates a smooth circular gradient from bright center to dark edges.
+/// Creates a smooth circular gradient from bright center to dark edges,
+/// optionally limited to a cone when the candle is aimed.
 ///
 /// # Algorithm
 /// 1. Calculate distance from fragment to light position
 /// 2. Normalize distance by light radius
 /// 3. Apply smoothstep for smooth falloff
-/// 4. Multiply by light color and intensity
+/// 4. Fade out fragments outside the cone (soft outer 20% edge)
+/// 5. Raise the result to the darkness floor
+/// 6. Multiply by light color and intensity
 ///
 /// # Returns
 /// RGBA color with calculated lighting intensity
 @fragment
 fn fragment(mesh: VertexOutput) -> @location(0) vec4<f32> {
     // Calculate distance from fragment to light source
-    let distance = length(mesh.world_position.xy - light_position);
+    let offset = mesh.world_position.xy - light_position;
+    let distance = length(offset);
 
     // Normalize distance and invert for brightness (1.0 = bright, 0.0 = dark)
     // smoothstep creates smooth gradient transition
     let normalized_distance = distance / light_radius;
-    let intensity = 1.0 - smoothstep(0.0, 1.0, normalized_distance);
+    var intensity = 1.0 - smoothstep(0.0, 1.0, normalized_distance);
+
+    // Restrict to the aimed cone, matching LightCone::factor
+    if (cone_half_angle < 3.14159 && distance > 0.0) {
+        let angle = acos(clamp(dot(offset / distance, light_direction), -1.0, 1.0));
+        intensity *= 1.0 - smoothstep(cone_half_angle * 0.8, cone_half_angle, angle);
+    }
+
+    // Never darker than the floor (0.0 unless high-contrast mode is on)
+    intensity = max(intensity, darkness_floor);
 
     // Apply light color and intensity
     // RGB from light_color, alpha controls overall visibility
@@ -0,0 +1,161 @@
+use bevy::ecs::world::CommandQueue;
+use bevy::prelude::*;
+use criterion::{BenchmarkId, Criterion, black_box, criterion_group, criterion_main};
+use rust_game::systems::level_loader::{
+    EntitySpawn, LevelData, merge_wall_tiles, parse_level_data, spawn_level_entities_batched,
+    spawn_level_entity, spawn_wall_colliders,
+};
+use rust_game::systems::tilemap::{DEFAULT_TILE_SIZE, FLOOR_TILE, WALL_TILE, spawn_tilemap};
+
+/// Level file parsed by the parsing benchmark
+const ENTRY_HALL: &str = "assets/levels/ground_floor_entry.ron";
+
+/// Builds a large room's worth of alternating trap and checkpoint spawns
+fn room_spawns(count: usize) -> Vec<EntitySpawn> {
+    (0..count)
+        .map(|i| EntitySpawn {
+            entity_type: match i % 4 {
+                0 => "Spikes",
+                1 => "PoisonGas",
+                2 => "SwingingBlade",
+                _ => "Checkpoint",
+            }
+            .to_string(),
+            position: ((i % 64) as f32 * 32.0, (i / 64) as f32 * 32.0),
+            target_room: None,
+            locked: None,
+            key_type: None,
+            trap_config: None,
+            reveal: None,
+        })
+        .collect()
+}
+
+/// Benchmark spawning a room's entities through one command queue on one
+/// thread against batched assembly on the compute task pool
+fn bench_level_spawning(c: &mut Criterion) {
+    let mut group = c.benchmark_group("level entity spawning");
+
+    for count in [256, 1000, 5000].iter() {
+        let spawns = room_spawns(*count);
+
+        group.bench_with_input(BenchmarkId::new("serial", count), &spawns, |b, spawns| {
+            b.iter(|| {
+                let mut world = World::new();
+                let mut queue = CommandQueue::default();
+                let mut commands = Commands::new(&mut queue, &world);
+                for spawn in spawns {
+                    spawn_level_entity(&mut commands, spawn);
+                }
+                queue.apply(&mut world);
+                black_box(world.entities().len())
+            });
+        });
+
+        group.bench_with_input(BenchmarkId::new("batched", count), &spawns, |b, spawns| {
+            b.iter(|| {
+                let mut world = World::new();
+                black_box(spawn_level_entities_batched(&mut world, spawns).len())
+            });
+        });
+    }
+
+    group.finish();
+}
+
+/// Builds a `size` x `size` tile grid: walls around the edge and in every
+/// eighth row and column, with doorway gaps, floor elsewhere
+fn tile_grid(size: usize) -> Vec<Vec<u32>> {
+    (0..size)
+        .map(|row| {
+            (0..size)
+                .map(|column| {
+                    let edge = row == 0 || column == 0 || row == size - 1 || column == size - 1;
+                    let wall =
+                        (row % 8 == 0 && column % 8 != 4) || (column % 8 == 0 && row % 8 != 4);
+                    if edge || wall { WALL_TILE } else { FLOOR_TILE }
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Benchmark spawning tilemaps and their merged wall colliders up to
+/// 200x200 tiles
+fn bench_tilemap_spawning(c: &mut Criterion) {
+    let mut group = c.benchmark_group("tilemap spawning");
+    let entry_hall = parse_level_data(&std::fs::read_to_string(ENTRY_HALL).unwrap(), ENTRY_HALL)
+        .expect("entry hall level parses");
+
+    for size in [50, 100, 200] {
+        let tiles = tile_grid(size);
+
+        group.bench_with_input(BenchmarkId::new("tiles", size), &tiles, |b, tiles| {
+            b.iter(|| {
+                let mut world = World::new();
+                let mut queue = CommandQueue::default();
+                let mut commands = Commands::new(&mut queue, &world);
+                spawn_tilemap(&mut commands, tiles, Handle::default(), DEFAULT_TILE_SIZE);
+                queue.apply(&mut world);
+                black_box(world.entities().len())
+            });
+        });
+
+        group.bench_with_input(BenchmarkId::new("merge walls", size), &tiles, |b, tiles| {
+            b.iter(|| black_box(merge_wall_tiles(tiles).len()));
+        });
+
+        let level = LevelData {
+            tiles: tiles.clone(),
+            ..entry_hall.clone()
+        };
+        group.bench_with_input(
+            BenchmarkId::new("wall colliders", size),
+            &level,
+            |b, level| {
+                b.iter(|| {
+                    let mut world = World::new();
+                    let mut queue = CommandQueue::default();
+                    let mut commands = Commands::new(&mut queue, &world);
+                    let colliders = spawn_wall_colliders(&mut commands, level);
+                    queue.apply(&mut world);
+                    black_box(colliders.len())
+                });
+            },
+        );
+    }
+
+    group.finish();
+}
+
+/// Benchmark parsing level RON: the entry hall as shipped, and a 200x200
+/// room with a thousand entities
+fn bench_level_parsing(c: &mut Criterion) {
+    let mut group = c.benchmark_group("level parsing");
+    let entry_hall = std::fs::read_to_string(ENTRY_HALL).unwrap();
+    let large = ron::ser::to_string_pretty(
+        &LevelData {
+            tiles: tile_grid(200),
+            entities: room_spawns(1000),
+            ..parse_level_data(&entry_hall, ENTRY_HALL).expect("entry hall level parses")
+        },
+        ron::ser::PrettyConfig::default(),
+    )
+    .unwrap();
+
+    for (name, source) in [("entry hall", &entry_hall), ("200x200 room", &large)] {
+        group.bench_with_input(BenchmarkId::from_parameter(name), source, |b, source| {
+            b.iter(|| black_box(parse_level_data(source, name).unwrap().tiles.len()));
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_level_spawning,
+    bench_tilemap_spawning,
+    bench_level_parsing
+);
+criterion_main!(benches);
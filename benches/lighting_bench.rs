@@ -1,6 +1,11 @@
 use bevy::math::Vec2;
 use bevy::prelude::*;
 use criterion::{BenchmarkId, Criterion, black_box, criterion_group, criterion_main};
+use rust_game::components::lighting::{Candle, CandleState, CandleWax, VisibilityRadius};
+use rust_game::systems::light_bake::{
+    BakedLightMap, LIGHT_MAP_CELL_SIZE, LightContribution, LightGrid, VisibilityMap,
+    composite_visibility_system,
+};
 
 // Note: We can't directly benchmark the shader (GPU code), but we can benchmark
 // the CPU-side lighting calculations and material updates
@@ -175,6 +180,108 @@ fn bench_distance_methods(c: &mut Criterion) {
     group.finish();
 }
 
+/// Benchmark per-frame visibility map cost with many static sconces
+///
+/// Compares recomputing every sconce each frame against copying the baked
+/// static lighting and compositing only the candle on top.
+fn bench_baked_sconces(c: &mut Criterion) {
+    let mut group = c.benchmark_group("visibility map sconces");
+    let bounds = Rect::new(0.0, 0.0, 1920.0, 1080.0);
+    let candle = LightContribution {
+        position: Vec2::new(960.0, 540.0),
+        radius: 300.0,
+        intensity: 1.0,
+        cone: None,
+    };
+
+    for num_sconces in [4, 16, 32].iter() {
+        let sconces: Vec<LightContribution> = (0..*num_sconces)
+            .map(|i| LightContribution {
+                position: Vec2::new((i as f32 * 137.0) % 1920.0, (i as f32 * 251.0) % 1080.0),
+                radius: 200.0,
+                intensity: 0.6,
+                cone: None,
+            })
+            .collect();
+
+        group.bench_with_input(
+            BenchmarkId::new("recomputed", num_sconces),
+            &sconces,
+            |b, sconces| {
+                b.iter(|| {
+                    let mut grid = LightGrid::bake(bounds, LIGHT_MAP_CELL_SIZE, sconces);
+                    candle.apply(&mut grid);
+                    black_box(grid)
+                });
+            },
+        );
+
+        let baked = LightGrid::bake(bounds, LIGHT_MAP_CELL_SIZE, &sconces);
+        let mut frame = LightGrid::default();
+        group.bench_with_input(
+            BenchmarkId::new("baked", num_sconces),
+            &baked,
+            |b, baked| {
+                b.iter(|| {
+                    frame.clone_from(baked);
+                    candle.apply(&mut frame);
+                    black_box(frame.values[0])
+                });
+            },
+        );
+    }
+
+    group.finish();
+}
+
+/// Benchmark one frame of `composite_visibility_system` (baked sconces
+/// plus every lit candle) as the number of candles in a room grows
+fn bench_visibility_composite(c: &mut Criterion) {
+    let mut group = c.benchmark_group("visibility map composite");
+    let bounds = Rect::new(0.0, 0.0, 1920.0, 1080.0);
+    let sconces: Vec<LightContribution> = (0..16)
+        .map(|i| LightContribution {
+            position: Vec2::new((i as f32 * 137.0) % 1920.0, (i as f32 * 251.0) % 1080.0),
+            radius: 200.0,
+            intensity: 0.6,
+            cone: None,
+        })
+        .collect();
+
+    for num_candles in [1, 8, 32] {
+        group.bench_with_input(
+            BenchmarkId::from_parameter(num_candles),
+            &num_candles,
+            |b, &num_candles| {
+                let mut app = App::new();
+                app.add_plugins(MinimalPlugins)
+                    .insert_resource(BakedLightMap {
+                        room: Some(0),
+                        grid: LightGrid::bake(bounds, LIGHT_MAP_CELL_SIZE, &sconces),
+                    })
+                    .init_resource::<VisibilityMap>()
+                    .add_systems(Update, composite_visibility_system);
+                for i in 0..num_candles {
+                    app.world_mut().spawn((
+                        Candle,
+                        CandleState::Lit,
+                        CandleWax(80.0),
+                        VisibilityRadius(250.0),
+                        Transform::from_xyz((i as f32 * 211.0) % 1920.0, 540.0, 0.0),
+                    ));
+                }
+
+                b.iter(|| {
+                    app.update();
+                    black_box(app.world().resource::<VisibilityMap>().grid.values[0])
+                });
+            },
+        );
+    }
+
+    group.finish();
+}
+
 criterion_group!(
     benches,
     bench_single_point,
@@ -183,5 +290,7 @@ criterion_group!(
     bench_wax_intensity,
     bench_lighting_update_per_frame,
     bench_distance_methods,
+    bench_baked_sconces,
+    bench_visibility_composite,
 );
 criterion_main!(benches);
@@ -0,0 +1,156 @@
+use bevy::prelude::*;
+use criterion::{BenchmarkId, Criterion, black_box, criterion_group, criterion_main};
+use rust_game::components::player::{JumpState, Player, Velocity};
+use rust_game::components::room::{Collider, Solid};
+use rust_game::systems::collision::{
+    CollisionGrid, SolidGrid, collision_detection_system, collision_grid_system,
+    solid_collision_system, solid_grid_system,
+};
+use rust_game::systems::inventory::ItemCollectedEvent;
+use rust_game::systems::stress_room::{STRESS_ROOM_ENTITY_COUNT, spawn_stress_room};
+use rust_game::systems::trap::TrapTriggeredEvent;
+
+/// 60 FPS frame budget, for reading the results against
+const FRAME_BUDGET_MS: f64 = 1000.0 / 60.0;
+
+/// Builds an app with the stress room and a player walking through it
+fn stress_app(count: usize, with_grid: bool) -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_event::<TrapTriggeredEvent>()
+        .add_event::<ItemCollectedEvent>();
+    if with_grid {
+        app.init_resource::<CollisionGrid>().add_systems(
+            Update,
+            (collision_grid_system, collision_detection_system).chain(),
+        );
+    } else {
+        app.add_systems(Update, collision_detection_system);
+    }
+
+    spawn_stress_room(app.world_mut(), count);
+    app.world_mut().spawn((
+        Player,
+        Transform::from_xyz(400.0, 300.0, 0.0),
+        Collider {
+            min: Vec2::new(-8.0, -16.0),
+            max: Vec2::new(8.0, 16.0),
+        },
+    ));
+    app.update();
+    app
+}
+
+/// Benchmark one frame of the stress room with brute-force collision
+/// against the `CollisionGrid` broad phase
+///
+/// The player moves every frame, so the grid cost includes re-bucketing.
+/// Compare the per-frame times against `FRAME_BUDGET_MS`.
+fn bench_stress_room_frame(c: &mut Criterion) {
+    let mut group = c.benchmark_group(format!(
+        "stress room frame (budget {:.1} ms)",
+        FRAME_BUDGET_MS
+    ));
+
+    for count in [
+        250,
+        STRESS_ROOM_ENTITY_COUNT,
+        4 * STRESS_ROOM_ENTITY_COUNT,
+        10 * STRESS_ROOM_ENTITY_COUNT,
+    ] {
+        for (name, with_grid) in [("brute force", false), ("grid", true)] {
+            group.bench_with_input(BenchmarkId::new(name, count), &count, |b, &count| {
+                let mut app = stress_app(count, with_grid);
+                let mut step = 0.0_f32;
+                b.iter(|| {
+                    step += 1.0;
+                    let mut players = app
+                        .world_mut()
+                        .query_filtered::<&mut Transform, With<Player>>();
+                    for mut transform in players.iter_mut(app.world_mut()) {
+                        transform.translation.x = 400.0 + (step % 600.0);
+                    }
+                    app.update();
+                    black_box(app.world().entities().len())
+                });
+            });
+        }
+    }
+
+    group.finish();
+}
+
+/// Side (pixels) of a tile-sized solid in the solids benchmark
+const TILE: f32 = 32.0;
+
+/// Builds an app with `count` tile-sized solids (rows of ledges 64 tiles
+/// wide) and a player walking along the bottom row
+fn solid_app(count: usize, with_grid: bool) -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    if with_grid {
+        app.init_resource::<SolidGrid>()
+            .add_systems(Update, (solid_grid_system, solid_collision_system).chain());
+    } else {
+        app.add_systems(Update, solid_collision_system);
+    }
+
+    let tile = || Collider {
+        min: Vec2::splat(-TILE / 2.0),
+        max: Vec2::splat(TILE / 2.0),
+    };
+    for index in 0..count {
+        let (column, row) = ((index % 64) as f32, (index / 64) as f32);
+        app.world_mut().spawn((
+            Solid,
+            Transform::from_xyz(column * TILE, row * 3.0 * TILE, 0.0),
+            tile(),
+        ));
+    }
+    app.world_mut().spawn((
+        Player,
+        Transform::from_xyz(0.0, TILE, 0.0),
+        Velocity(Vec2::new(200.0, 0.0)),
+        JumpState::Grounded,
+        Collider {
+            min: Vec2::new(-8.0, -16.0),
+            max: Vec2::new(8.0, 16.0),
+        },
+    ));
+    app.update();
+    app
+}
+
+/// Benchmark player-vs-solid collision against the `SolidGrid` broad phase
+/// as rooms grow to thousands of tile-sized solids
+fn bench_solid_collision(c: &mut Criterion) {
+    let mut group = c.benchmark_group(format!(
+        "solid collision frame (budget {:.1} ms)",
+        FRAME_BUDGET_MS
+    ));
+
+    for count in [1_000, 4_000, 10_000, 16_000] {
+        for (name, with_grid) in [("brute force", false), ("grid", true)] {
+            group.bench_with_input(BenchmarkId::new(name, count), &count, |b, &count| {
+                let mut app = solid_app(count, with_grid);
+                let mut step = 0.0_f32;
+                b.iter(|| {
+                    step += 1.0;
+                    let mut players = app
+                        .world_mut()
+                        .query_filtered::<&mut Transform, With<Player>>();
+                    for mut transform in players.iter_mut(app.world_mut()) {
+                        transform.translation.x = step % (64.0 * TILE);
+                    }
+                    app.update();
+                    black_box(app.world().entities().len())
+                });
+            });
+        }
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_stress_room_frame, bench_solid_collision);
+criterion_main!(benches);
@@ -0,0 +1,18 @@
+#![no_main]
+
+//! Fuzz target feeding arbitrary save file content through the parsing,
+//! migration and inventory restoring that `load_game_system` does
+
+use libfuzzer_sys::fuzz_target;
+use rust_game::systems::save_load::{deserialize_hotbar, deserialize_inventory};
+use rust_game::systems::save_migration::decode_save;
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(content) = std::str::from_utf8(data) else {
+        return;
+    };
+    if let Ok((save_data, _)) = decode_save(content) {
+        deserialize_inventory(&save_data.inventory_items, &save_data.inventory_stacks);
+        deserialize_hotbar(&save_data.hotbar);
+    }
+});
@@ -0,0 +1,149 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Distance (pixels) within which a positional sound plays at full volume
+pub const FULL_VOLUME_DISTANCE: f32 = 96.0;
+
+/// Distance (pixels) beyond which a positional sound is inaudible
+pub const HEARING_DISTANCE: f32 = 640.0;
+
+/// Horizontal offset (pixels) at which a positional sound is panned furthest
+pub const PAN_DISTANCE: f32 = 320.0;
+
+/// Furthest a positional sound is panned from the center (kira panning 0.5)
+///
+/// Kept below 0.5 so sounds to one side still reach both ears.
+pub const MAX_PAN: f32 = 0.4;
+
+/// Volume bus a sound plays on
+///
+/// Every bus is scaled by `Master` (see `VolumeSettings::level`).
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum VolumeBus {
+    /// Scales every other bus
+    Master,
+    /// Sound effects (the default for sound bank cues)
+    #[default]
+    Sfx,
+    /// Soundtrack stems
+    Music,
+    /// Environmental sounds (drips, gas)
+    Ambience,
+}
+
+/// Resource holding the player's volume settings, one level per bus
+///
+/// Levels are linear, from 0.0 (muted) to 1.0 (full volume).
+#[derive(Resource, Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub struct VolumeSettings {
+    /// Level applied to every bus
+    pub master: f32,
+    /// Sound effects level
+    pub sfx: f32,
+    /// Music level
+    pub music: f32,
+    /// Ambience level
+    pub ambience: f32,
+}
+
+impl Default for VolumeSettings {
+    fn default() -> Self {
+        Self {
+            master: 1.0,
+            sfx: 1.0,
+            music: 1.0,
+            ambience: 1.0,
+        }
+    }
+}
+
+impl VolumeSettings {
+    /// Level set for `bus` alone
+    pub fn bus(&self, bus: VolumeBus) -> f32 {
+        match bus {
+            VolumeBus::Master => self.master,
+            VolumeBus::Sfx => self.sfx,
+            VolumeBus::Music => self.music,
+            VolumeBus::Ambience => self.ambience,
+        }
+    }
+
+    /// Sets the level of `bus`, clamped to 0.0-1.0
+    pub fn set(&mut self, bus: VolumeBus, level: f32) {
+        let level = level.clamp(0.0, 1.0);
+        match bus {
+            VolumeBus::Master => self.master = level,
+            VolumeBus::Sfx => self.sfx = level,
+            VolumeBus::Music => self.music = level,
+            VolumeBus::Ambience => self.ambience = level,
+        }
+    }
+
+    /// Audible level of `bus`: its own level scaled by the master level
+    pub fn level(&self, bus: VolumeBus) -> f32 {
+        match bus {
+            VolumeBus::Master => self.master,
+            _ => self.master * self.bus(bus),
+        }
+    }
+}
+
+/// Attenuation and panning of a sound heard from a distance
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SpatialMix {
+    /// Linear level from 0.0 (inaudible) to 1.0 (full volume)
+    pub level: f32,
+    /// Kira panning: 0.0 is left, 0.5 center and 1.0 right
+    pub panning: f32,
+}
+
+/// Mix of a sound at `source` heard by a listener at `listener`
+///
+/// Full volume within `FULL_VOLUME_DISTANCE`, fading linearly to silence
+/// at `HEARING_DISTANCE`. Panned by the horizontal offset, up to `MAX_PAN`
+/// at `PAN_DISTANCE`.
+pub fn spatial_mix(listener: Vec2, source: Vec2) -> SpatialMix {
+    let distance = listener.distance(source);
+    let level = 1.0
+        - ((distance - FULL_VOLUME_DISTANCE) / (HEARING_DISTANCE - FULL_VOLUME_DISTANCE))
+            .clamp(0.0, 1.0);
+    let offset = ((source.x - listener.x) / PAN_DISTANCE).clamp(-1.0, 1.0);
+    SpatialMix {
+        level,
+        panning: 0.5 + MAX_PAN * offset,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn buses_are_scaled_by_master() {
+        let mut volume = VolumeSettings::default();
+        volume.set(VolumeBus::Master, 0.5);
+        volume.set(VolumeBus::Music, 0.5);
+        volume.set(VolumeBus::Ambience, 2.0);
+
+        assert_eq!(volume.level(VolumeBus::Master), 0.5);
+        assert_eq!(volume.level(VolumeBus::Music), 0.25);
+        assert_eq!(volume.level(VolumeBus::Sfx), 0.5);
+        assert_eq!(volume.bus(VolumeBus::Ambience), 1.0, "Levels are clamped");
+    }
+
+    #[test]
+    fn distant_sounds_fade_and_pan() {
+        let near = spatial_mix(Vec2::ZERO, Vec2::new(10.0, 0.0));
+        assert_eq!(near.level, 1.0);
+        assert!(near.panning > 0.5 && near.panning < 0.52);
+
+        let halfway = (FULL_VOLUME_DISTANCE + HEARING_DISTANCE) / 2.0;
+        let left = spatial_mix(Vec2::ZERO, Vec2::new(-halfway, 0.0));
+        assert!((left.level - 0.5).abs() < 0.001);
+        assert_eq!(left.panning, 0.5 - MAX_PAN);
+
+        let far = spatial_mix(Vec2::ZERO, Vec2::new(0.0, HEARING_DISTANCE * 2.0));
+        assert_eq!(far.level, 0.0);
+        assert_eq!(far.panning, 0.5);
+    }
+}
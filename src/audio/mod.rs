@@ -3,5 +3,14 @@
 //! This module integrates bevy_kira_audio to provide responsive sound effects
 //! triggered by game events like trap activation, item collection, and puzzle solving.
 
+/// Volume buses and distance-based attenuation and panning
+pub mod mixer;
+
+/// Layered soundtrack stems and room ambience mixed by game signals
+pub mod music;
+
+/// Data-driven sound bank with variations and cooldowns
+pub mod sound_bank;
+
 /// Event-based sound playback system and plugin
 pub mod sound_events;
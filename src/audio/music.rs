@@ -0,0 +1,750 @@
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use bevy_kira_audio::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::time::Duration;
+
+use crate::audio::mixer::{VolumeBus, VolumeSettings};
+use crate::audio::sound_bank::{PlaySoundEvent, SoundCue};
+use crate::components::enemy::Stalker;
+use crate::components::lighting::{Candle, CandleWax};
+use crate::components::player::Player;
+use crate::components::room::{Floor, RoomId};
+use crate::resources::game_state::GameState;
+use crate::systems::level_loader::{LevelRegistry, RoomLevels};
+use crate::systems::puzzle::PuzzleSolvedEvent;
+
+/// Default location of the per-level stem sets, relative to the working directory
+pub const MUSIC_STEMS_PATH: &str = "assets/audio/music_stems.ron";
+
+/// Stalker distance (pixels) below which the tension stem fades in
+pub const TENSION_DISTANCE: f32 = 400.0;
+
+/// Stalker distance (pixels) below which the chase stem fades in
+pub const CHASE_DISTANCE: f32 = 150.0;
+
+/// Candle wax percentage below which the tension stem fades in
+pub const LOW_WAX_THRESHOLD: f32 = 20.0;
+
+/// Seconds of calm (tension muted) after a puzzle is solved
+pub const PUZZLE_RELIEF_DURATION: f32 = 8.0;
+
+/// Stem volume change per second (0.0-1.0 scale) while fading
+pub const STEM_FADE_SPEED: f32 = 0.5;
+
+/// Volume (decibels) treated as silent for faded-out stems
+pub const SILENT_DB: f32 = -60.0;
+
+/// Seconds over which the old stem set and ambience fade out while the new
+/// ones fade in
+pub const CROSSFADE_DURATION: f32 = 2.0;
+
+/// Layers of the soundtrack, played in sync and mixed by game signals
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize)]
+pub enum MusicStem {
+    /// Ambient bed, always audible
+    Base,
+    /// Unease layer (stalker nearby, candle running low)
+    Tension,
+    /// Percussive layer while the stalker is closing in
+    Chase,
+}
+
+impl MusicStem {
+    /// All stems in mixing order
+    pub const ALL: [MusicStem; 3] = [MusicStem::Base, MusicStem::Tension, MusicStem::Chase];
+}
+
+/// Audio channel playing the base stem
+#[derive(Resource)]
+pub struct BaseStemChannel;
+
+/// Audio channel playing the tension stem
+#[derive(Resource)]
+pub struct TensionStemChannel;
+
+/// Audio channel playing the chase stem
+#[derive(Resource)]
+pub struct ChaseStemChannel;
+
+/// Audio channel playing the room's ambience loop
+#[derive(Resource)]
+pub struct AmbienceChannel;
+
+/// Audio metadata of a room, from its level file
+///
+/// ```ron
+/// audio: Some((
+///     ambience: Some("audio/ambience/dripping_cellar.ogg"),
+///     base_tension: 0.4,
+/// )),
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Default)]
+pub struct RoomAudio {
+    /// Looping ambience file relative to assets/ (silence when omitted)
+    #[serde(default)]
+    pub ambience: Option<String>,
+    /// Lowest tension stem level (0.0-1.0) while in the room
+    #[serde(default)]
+    pub base_tension: f32,
+}
+
+/// Stem audio files for one level
+#[derive(Deserialize, Debug, Clone, PartialEq, Default)]
+pub struct StemSet {
+    /// Audio file paths relative to assets/, per stem (missing stems stay silent)
+    pub stems: HashMap<MusicStem, String>,
+}
+
+/// Resource mapping floors and levels to their stem sets
+///
+/// Loaded from `assets/audio/music_stems.ron` at startup. Rooms without an
+/// entry use their floor's set, and floors without one use `default`.
+#[derive(Resource, Deserialize, Debug, Clone, PartialEq, Default)]
+pub struct MusicStemSets {
+    /// Stem set used by rooms without their own or their floor's entry
+    pub default: StemSet,
+    /// Per-floor stem sets
+    #[serde(default)]
+    pub floors: HashMap<Floor, StemSet>,
+    /// Per-room stem sets
+    #[serde(default)]
+    pub levels: HashMap<RoomId, StemSet>,
+}
+
+impl MusicStemSets {
+    /// Returns the stem set for a room on `floor` (if known)
+    ///
+    /// Prefers the room's own set, then the floor's, then the default set.
+    pub fn for_room(&self, room: RoomId, floor: Option<Floor>) -> &StemSet {
+        self.levels
+            .get(&room)
+            .or_else(|| floor.and_then(|floor| self.floors.get(&floor)))
+            .unwrap_or(&self.default)
+    }
+}
+
+/// Load per-level music stem sets from a RON file
+///
+/// # Arguments
+/// * `path` - Path to the RON file (e.g., "assets/audio/music_stems.ron")
+///
+/// # Errors
+/// Returns error string if the file cannot be read or parsed
+pub fn load_music_stem_sets(path: &str) -> Result<MusicStemSets, String> {
+    let content = fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read music stems '{}': {}", path, e))?;
+
+    ron::from_str(&content).map_err(|e| format!("Failed to parse RON from '{}': {}", path, e))
+}
+
+/// Mood of the soundtrack, derived from game signals
+///
+/// Ordered from calmest to most intense; moving up into `Tension` or
+/// `Chase` plays `SoundCue::TensionStinger`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum MusicMood {
+    /// Post-puzzle calm, tension muted
+    Relief,
+    /// Nothing threatening the player
+    #[default]
+    Calm,
+    /// Stalker nearby or candle running low
+    Tension,
+    /// Stalker closing in
+    Chase,
+}
+
+impl MusicMood {
+    /// Stem target volumes for this mood
+    ///
+    /// Base is always at full volume; tension plays in `Tension` and
+    /// `Chase`, chase only in `Chase`.
+    pub fn stem_targets(self) -> HashMap<MusicStem, f32> {
+        let tension = self >= MusicMood::Tension;
+        let chase = self == MusicMood::Chase;
+        HashMap::from([
+            (MusicStem::Base, 1.0),
+            (MusicStem::Tension, if tension { 1.0 } else { 0.0 }),
+            (MusicStem::Chase, if chase { 1.0 } else { 0.0 }),
+        ])
+    }
+
+    /// Returns true if moving from `previous` to this mood plays a stinger
+    pub fn escalates_from(self, previous: MusicMood) -> bool {
+        self > previous && self >= MusicMood::Tension
+    }
+}
+
+/// Resource holding the soundtrack mix
+///
+/// Signal systems set the `mood` and `targets`; `music_stem_fade_system`
+/// moves the audible `levels` toward them and applies them to the stem
+/// channels.
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct MusicState {
+    /// Room whose audio is currently playing (`None` before the first room)
+    pub playing_room: Option<RoomId>,
+    /// Stem set currently playing
+    pub playing_set: Option<StemSet>,
+    /// Ambience loop currently playing
+    pub playing_ambience: Option<String>,
+    /// Lowest tension level of the current room (`RoomAudio::base_tension`)
+    pub base_tension: f32,
+    /// Current mood of the soundtrack
+    pub mood: MusicMood,
+    /// Desired volume per stem (0.0 = silent, 1.0 = full)
+    pub targets: HashMap<MusicStem, f32>,
+    /// Current volume per stem (0.0 = silent, 1.0 = full)
+    pub levels: HashMap<MusicStem, f32>,
+    /// Seconds of post-puzzle calm remaining
+    pub relief_remaining: f32,
+}
+
+impl Default for MusicState {
+    fn default() -> Self {
+        let silent: HashMap<MusicStem, f32> =
+            MusicStem::ALL.iter().map(|stem| (*stem, 0.0)).collect();
+        let mut targets = silent.clone();
+        targets.insert(MusicStem::Base, 1.0);
+        Self {
+            playing_room: None,
+            playing_set: None,
+            playing_ambience: None,
+            base_tension: 0.0,
+            mood: MusicMood::Calm,
+            targets,
+            levels: silent,
+            relief_remaining: 0.0,
+        }
+    }
+}
+
+impl MusicState {
+    /// Current volume of a stem
+    pub fn level(&self, stem: MusicStem) -> f32 {
+        self.levels.get(&stem).copied().unwrap_or(0.0)
+    }
+
+    /// Desired volume of a stem
+    pub fn target(&self, stem: MusicStem) -> f32 {
+        self.targets.get(&stem).copied().unwrap_or(0.0)
+    }
+}
+
+/// Game signals that drive the soundtrack mix
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct MusicSignals {
+    /// Distance from the player to the nearest stalker, if any
+    pub stalker_distance: Option<f32>,
+    /// Whether the candle is running low on wax
+    pub low_wax: bool,
+    /// Whether the player is enjoying post-puzzle calm
+    pub relief: bool,
+}
+
+/// Derives the soundtrack mood from game signals
+///
+/// - `Chase` while a stalker is within `CHASE_DISTANCE`, even during relief
+/// - `Tension` while a stalker is within `TENSION_DISTANCE` or wax is low,
+///   unless puzzle relief is active
+/// - `Relief` during puzzle relief, `Calm` otherwise
+pub fn music_mood(signals: MusicSignals) -> MusicMood {
+    let chase = signals
+        .stalker_distance
+        .is_some_and(|distance| distance < CHASE_DISTANCE);
+    let stalker_near = signals
+        .stalker_distance
+        .is_some_and(|distance| distance < TENSION_DISTANCE);
+
+    if chase {
+        MusicMood::Chase
+    } else if signals.relief {
+        MusicMood::Relief
+    } else if stalker_near || signals.low_wax {
+        MusicMood::Tension
+    } else {
+        MusicMood::Calm
+    }
+}
+
+/// Computes stem target volumes from game signals
+///
+/// See `music_mood` and `MusicMood::stem_targets`.
+pub fn stem_targets(signals: MusicSignals) -> HashMap<MusicStem, f32> {
+    music_mood(signals).stem_targets()
+}
+
+/// Moves `current` toward `target` by at most `max_step`
+pub fn approach(current: f32, target: f32, max_step: f32) -> f32 {
+    if (target - current).abs() <= max_step {
+        target
+    } else {
+        current + max_step.copysign(target - current)
+    }
+}
+
+/// Converts a linear stem level (0.0-1.0) to decibels
+pub fn level_to_decibels(level: f32) -> f32 {
+    if level <= 0.0 {
+        SILENT_DB
+    } else {
+        (20.0 * level.log10()).max(SILENT_DB)
+    }
+}
+
+/// System parameter bundling the three stem channels and the ambience channel
+#[derive(SystemParam)]
+pub struct MusicChannels<'w> {
+    base: Res<'w, AudioChannel<BaseStemChannel>>,
+    tension: Res<'w, AudioChannel<TensionStemChannel>>,
+    chase: Res<'w, AudioChannel<ChaseStemChannel>>,
+    ambience: Res<'w, AudioChannel<AmbienceChannel>>,
+}
+
+impl MusicChannels<'_> {
+    /// Starts a looping stem on its channel, fading in to the given volume (decibels)
+    pub fn play(&self, stem: MusicStem, source: Handle<AudioSource>, volume_db: f32) {
+        let fade = Duration::from_secs_f32(CROSSFADE_DURATION);
+        match stem {
+            MusicStem::Base => {
+                self.base
+                    .play(source)
+                    .looped()
+                    .with_volume(volume_db)
+                    .linear_fade_in(fade);
+            }
+            MusicStem::Tension => {
+                self.tension
+                    .play(source)
+                    .looped()
+                    .with_volume(volume_db)
+                    .linear_fade_in(fade);
+            }
+            MusicStem::Chase => {
+                self.chase
+                    .play(source)
+                    .looped()
+                    .with_volume(volume_db)
+                    .linear_fade_in(fade);
+            }
+        }
+    }
+
+    /// Sets a stem channel's volume (decibels)
+    pub fn set_volume(&self, stem: MusicStem, volume_db: f32) {
+        match stem {
+            MusicStem::Base => {
+                self.base.set_volume(volume_db);
+            }
+            MusicStem::Tension => {
+                self.tension.set_volume(volume_db);
+            }
+            MusicStem::Chase => {
+                self.chase.set_volume(volume_db);
+            }
+        }
+    }
+
+    /// Fades out and stops every stem channel
+    pub fn stop_all(&self) {
+        let fade = Duration::from_secs_f32(CROSSFADE_DURATION);
+        self.base.stop().linear_fade_out(fade);
+        self.tension.stop().linear_fade_out(fade);
+        self.chase.stop().linear_fade_out(fade);
+    }
+
+    /// Fades out the playing ambience and fades in `source` (if any), looped
+    pub fn crossfade_ambience(&self, source: Option<Handle<AudioSource>>, volume_db: f32) {
+        let fade = Duration::from_secs_f32(CROSSFADE_DURATION);
+        self.ambience.stop().linear_fade_out(fade);
+        if let Some(source) = source {
+            self.ambience
+                .play(source)
+                .looped()
+                .with_volume(volume_db)
+                .linear_fade_in(fade);
+        }
+    }
+
+    /// Sets the ambience channel's volume (decibels)
+    pub fn set_ambience_volume(&self, volume_db: f32) {
+        self.ambience.set_volume(volume_db);
+    }
+}
+
+/// Plugin that registers the layered soundtrack and room ambience
+///
+/// **NOTE**: bevy_kira_audio's `AudioPlugin` (added by `SoundEventsPlugin`)
+/// must be added to the app before this plugin.
+pub struct MusicPlugin;
+
+impl Plugin for MusicPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_audio_channel::<BaseStemChannel>()
+            .add_audio_channel::<TensionStemChannel>()
+            .add_audio_channel::<ChaseStemChannel>()
+            .add_audio_channel::<AmbienceChannel>()
+            .init_resource::<MusicStemSets>()
+            .init_resource::<MusicState>()
+            .init_resource::<LevelRegistry>()
+            .add_event::<PlaySoundEvent>()
+            .add_systems(Startup, load_music_stem_sets_system)
+            .add_systems(
+                Update,
+                (
+                    music_room_system,
+                    music_signal_system,
+                    music_stem_fade_system,
+                )
+                    .chain(),
+            );
+    }
+}
+
+/// Startup system that loads the per-level stem sets from disk
+///
+/// Falls back to an empty set (no music) if the file is missing or malformed.
+pub fn load_music_stem_sets_system(mut commands: Commands) {
+    match load_music_stem_sets(MUSIC_STEMS_PATH) {
+        Ok(sets) => {
+            info!("Loaded music stems for {} levels", sets.levels.len());
+            commands.insert_resource(sets);
+        }
+        Err(e) => {
+            warn!("{}. Music disabled.", e);
+            commands.insert_resource(MusicStemSets::default());
+        }
+    }
+}
+
+/// System that crossfades the soundtrack and ambience when the room changes
+///
+/// # System Dependencies
+/// - **Resources**: Reads `GameState`, `LevelRegistry`, `MusicStemSets`,
+///   `VolumeSettings` (optional); writes `MusicState`
+/// - **Upstream**: `room_transition_system` changes the current room
+///
+/// # Behavior
+/// On entering a room, its level file gives the floor and `RoomAudio`:
+/// 1. **Stems**: if the room's stem set (see `MusicStemSets::for_room`)
+///    differs from the playing one, the old stems fade out over
+///    `CROSSFADE_DURATION` while the new ones (looped, in sync) fade in at
+///    their current mix level, then `music_stem_fade_system` drives them.
+///    Rooms sharing a set keep the music playing seamlessly.
+/// 2. **Ambience**: if the room's ambience loop differs from the playing
+///    one, the two crossfade the same way.
+/// 3. **Tension**: the room's `base_tension` becomes the lowest tension level.
+pub fn music_room_system(
+    game_state: Res<GameState>,
+    levels: RoomLevels,
+    stem_sets: Res<MusicStemSets>,
+    volume: Option<Res<VolumeSettings>>,
+    asset_server: Res<AssetServer>,
+    channels: MusicChannels,
+    mut music: ResMut<MusicState>,
+) {
+    if music.playing_room == Some(game_state.current_room) {
+        return;
+    }
+    music.playing_room = Some(game_state.current_room);
+
+    let level = levels.load(game_state.current_room).unwrap_or_else(|e| {
+        warn!(
+            "No audio metadata for room {}: {}",
+            game_state.current_room, e
+        );
+        None
+    });
+    let floor = level.as_ref().map(|level| level.floor);
+    let room_audio = level.and_then(|level| level.audio).unwrap_or_default();
+    music.base_tension = room_audio.base_tension.clamp(0.0, 1.0);
+
+    let new_set = stem_sets.for_room(game_state.current_room, floor);
+    if music.playing_set.as_ref() != Some(new_set) {
+        let bus_level = music_bus_level(volume.as_deref());
+        channels.stop_all();
+        for (stem, path) in &new_set.stems {
+            channels.play(
+                *stem,
+                asset_server.load(path.clone()),
+                level_to_decibels(music.level(*stem) * bus_level),
+            );
+        }
+        music.playing_set = Some(new_set.clone());
+    }
+
+    if music.playing_ambience != room_audio.ambience {
+        channels.crossfade_ambience(
+            room_audio
+                .ambience
+                .as_ref()
+                .map(|path| asset_server.load(path.clone())),
+            level_to_decibels(ambience_bus_level(volume.as_deref())),
+        );
+        music.playing_ambience = room_audio.ambience;
+    }
+}
+
+/// System that derives the mood and stem targets from game signals
+///
+/// # Signals
+/// - **Stalker proximity**: distance from the player to the nearest `Stalker`
+/// - **Low wax**: any candle below `LOW_WAX_THRESHOLD`
+/// - **Puzzle solved**: `PuzzleSolvedEvent` starts `PUZZLE_RELIEF_DURATION`
+///   seconds of calm
+///
+/// # Behavior
+/// The signals set the `MusicMood` (see `music_mood`); escalating into
+/// `Tension` or `Chase` plays `SoundCue::TensionStinger`. The tension
+/// target never drops below the room's `base_tension`.
+///
+/// # System Dependencies
+/// - **Upstream**: `puzzle_interaction_system` emits `PuzzleSolvedEvent`;
+///   `music_room_system` sets the room's base tension
+/// - **Downstream**: `music_stem_fade_system` fades toward the targets;
+///   emits `PlaySoundEvent`
+pub fn music_signal_system(
+    time: Res<Time>,
+    mut music: ResMut<MusicState>,
+    mut solved_events: EventReader<PuzzleSolvedEvent>,
+    mut sound_events: EventWriter<PlaySoundEvent>,
+    player_query: Query<&Transform, With<Player>>,
+    stalker_query: Query<&Transform, With<Stalker>>,
+    candle_query: Query<&CandleWax, With<Candle>>,
+) {
+    if solved_events.read().count() > 0 {
+        music.relief_remaining = PUZZLE_RELIEF_DURATION;
+    } else {
+        music.relief_remaining = (music.relief_remaining - time.delta_secs()).max(0.0);
+    }
+
+    let stalker_distance = player_query.single().ok().and_then(|player| {
+        stalker_query
+            .iter()
+            .map(|stalker| {
+                player
+                    .translation
+                    .truncate()
+                    .distance(stalker.translation.truncate())
+            })
+            .min_by(f32::total_cmp)
+    });
+
+    let mood = music_mood(MusicSignals {
+        stalker_distance,
+        low_wax: candle_query.iter().any(|wax| wax.0 < LOW_WAX_THRESHOLD),
+        relief: music.relief_remaining > 0.0,
+    });
+    if mood.escalates_from(music.mood) {
+        sound_events.write(PlaySoundEvent {
+            cue: SoundCue::TensionStinger,
+        });
+    }
+
+    let mut targets = mood.stem_targets();
+    let tension = targets.entry(MusicStem::Tension).or_insert(0.0);
+    *tension = tension.max(music.base_tension);
+    music.mood = mood;
+    music.targets = targets;
+}
+
+/// System that fades stem levels toward their targets
+///
+/// Levels move at `STEM_FADE_SPEED` per second; channel volumes are only
+/// updated for stems whose level actually changed, or for every stem (and
+/// the ambience) when the `VolumeSettings` change.
+///
+/// # System Dependencies
+/// - **Upstream**: `music_signal_system` sets the targets
+/// - **Resources**: Reads `VolumeSettings` (optional); writes `MusicState`,
+///   stem `AudioChannel`s
+pub fn music_stem_fade_system(
+    time: Res<Time>,
+    channels: MusicChannels,
+    volume: Option<Res<VolumeSettings>>,
+    mut music: ResMut<MusicState>,
+) {
+    let volume_changed = volume.as_ref().is_some_and(|volume| volume.is_changed());
+    let bus_level = music_bus_level(volume.as_deref());
+    let step = STEM_FADE_SPEED * time.delta_secs();
+    for stem in MusicStem::ALL {
+        let current = music.level(stem);
+        let next = approach(current, music.target(stem), step);
+        if next != current || volume_changed {
+            music.levels.insert(stem, next);
+            channels.set_volume(stem, level_to_decibels(next * bus_level));
+        }
+    }
+    if volume_changed {
+        channels.set_ambience_volume(level_to_decibels(ambience_bus_level(volume.as_deref())));
+    }
+}
+
+/// Level of the music bus, full volume without `VolumeSettings`
+fn music_bus_level(volume: Option<&VolumeSettings>) -> f32 {
+    volume.map_or(1.0, |volume| volume.level(VolumeBus::Music))
+}
+
+/// Level of the ambience bus, full volume without `VolumeSettings`
+fn ambience_bus_level(volume: Option<&VolumeSettings>) -> f32 {
+    volume.map_or(1.0, |volume| volume.level(VolumeBus::Ambience))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stem_sets_file_parses() {
+        let sets = load_music_stem_sets(MUSIC_STEMS_PATH).expect("music stems should parse");
+        assert!(sets.default.stems.contains_key(&MusicStem::Base));
+        assert!(
+            sets.for_room(0, Some(Floor::Ground))
+                .stems
+                .contains_key(&MusicStem::Tension)
+        );
+        assert_ne!(
+            sets.for_room(10, Some(Floor::Basement)),
+            &sets.default,
+            "The basement has its own set"
+        );
+    }
+
+    #[test]
+    fn missing_room_uses_floor_then_default_set() {
+        let set = |path: &str| StemSet {
+            stems: HashMap::from([(MusicStem::Base, path.to_string())]),
+        };
+        let sets = MusicStemSets {
+            default: set("music/base.ogg"),
+            floors: HashMap::from([(Floor::Basement, set("music/basement.ogg"))]),
+            levels: HashMap::from([(7, set("music/crypt.ogg"))]),
+        };
+        assert_eq!(sets.for_room(42, None), &sets.default);
+        assert_eq!(sets.for_room(42, Some(Floor::Ground)), &sets.default);
+        assert_eq!(
+            sets.for_room(42, Some(Floor::Basement)),
+            &sets.floors[&Floor::Basement]
+        );
+        assert_eq!(sets.for_room(7, Some(Floor::Basement)), &sets.levels[&7]);
+    }
+
+    #[test]
+    fn targets_follow_signals() {
+        let calm = stem_targets(MusicSignals::default());
+        assert_eq!(calm[&MusicStem::Base], 1.0);
+        assert_eq!(calm[&MusicStem::Tension], 0.0);
+
+        let low_wax = stem_targets(MusicSignals {
+            low_wax: true,
+            ..Default::default()
+        });
+        assert_eq!(low_wax[&MusicStem::Tension], 1.0);
+        assert_eq!(low_wax[&MusicStem::Chase], 0.0);
+
+        let chase = stem_targets(MusicSignals {
+            stalker_distance: Some(100.0),
+            relief: true,
+            ..Default::default()
+        });
+        assert_eq!(chase[&MusicStem::Chase], 1.0);
+        assert_eq!(chase[&MusicStem::Tension], 1.0, "Chase overrides relief");
+    }
+
+    #[test]
+    fn puzzle_relief_mutes_tension() {
+        let targets = stem_targets(MusicSignals {
+            stalker_distance: Some(300.0),
+            low_wax: true,
+            relief: true,
+        });
+        assert_eq!(targets[&MusicStem::Tension], 0.0);
+    }
+
+    #[test]
+    fn approach_is_capped_by_step() {
+        assert_eq!(approach(0.0, 1.0, 0.25), 0.25);
+        assert_eq!(approach(1.0, 0.0, 0.25), 0.75);
+        assert_eq!(approach(0.9, 1.0, 0.25), 1.0);
+    }
+
+    #[test]
+    fn decibel_conversion() {
+        assert_eq!(level_to_decibels(1.0), 0.0);
+        assert_eq!(level_to_decibels(0.0), SILENT_DB);
+        assert!((level_to_decibels(0.5) + 6.02).abs() < 0.01);
+    }
+
+    #[test]
+    fn low_wax_raises_tension_target() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_event::<PuzzleSolvedEvent>();
+        app.add_event::<PlaySoundEvent>();
+        app.init_resource::<MusicState>();
+        app.add_systems(Update, music_signal_system);
+
+        app.world_mut().spawn((Player, Transform::default()));
+        app.world_mut().spawn((Candle, CandleWax(10.0)));
+
+        app.update();
+
+        let music = app.world().resource::<MusicState>();
+        assert_eq!(music.target(MusicStem::Tension), 1.0);
+        assert_eq!(music.target(MusicStem::Chase), 0.0);
+    }
+
+    #[test]
+    fn escalating_mood_plays_one_stinger() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_event::<PuzzleSolvedEvent>();
+        app.add_event::<PlaySoundEvent>();
+        app.insert_resource(MusicState {
+            base_tension: 0.3,
+            ..default()
+        });
+        app.add_systems(Update, music_signal_system);
+
+        app.world_mut().spawn((Player, Transform::default()));
+        let stalker = app
+            .world_mut()
+            .spawn((Stalker, Transform::from_xyz(1000.0, 0.0, 0.0)))
+            .id();
+        let stingers = |app: &App| {
+            app.world()
+                .resource::<Events<PlaySoundEvent>>()
+                .iter_current_update_events()
+                .filter(|event| event.cue == SoundCue::TensionStinger)
+                .count()
+        };
+
+        app.update();
+        let music = app.world().resource::<MusicState>();
+        assert_eq!(music.mood, MusicMood::Calm);
+        assert_eq!(
+            music.target(MusicStem::Tension),
+            0.3,
+            "Room base tension applies while calm"
+        );
+        assert_eq!(stingers(&app), 0);
+
+        for (x, mood, expected) in [
+            (300.0, MusicMood::Tension, 1),
+            (250.0, MusicMood::Tension, 0),
+            (100.0, MusicMood::Chase, 1),
+        ] {
+            app.world_mut()
+                .get_mut::<Transform>(stalker)
+                .unwrap()
+                .translation
+                .x = x;
+            app.update();
+            assert_eq!(app.world().resource::<MusicState>().mood, mood);
+            assert_eq!(stingers(&app), expected, "Stalker at {}", x);
+        }
+    }
+}
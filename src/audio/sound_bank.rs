@@ -0,0 +1,410 @@
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use bevy_kira_audio::prelude::*;
+use rand::Rng;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fs;
+
+use crate::audio::mixer::{VolumeBus, VolumeSettings, spatial_mix};
+use crate::audio::music::level_to_decibels;
+use crate::components::player::Player;
+use crate::systems::mods::ModContent;
+
+/// Default location of the sound bank definition, relative to the working directory
+pub const SOUND_BANK_PATH: &str = "assets/audio/sound_bank.ron";
+
+/// Logical sound cues that game systems can request.
+///
+/// A cue is decoupled from any particular audio file: the `SoundBank`
+/// maps each cue to one or more variations plus playback tuning.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize)]
+pub enum SoundCue {
+    /// Trap activation
+    TrapTriggered,
+    /// Player death
+    PlayerDeath,
+    /// Item pickup
+    ItemCollected,
+    /// Item usage
+    ItemUsed,
+    /// Puzzle interaction (lever flip, symbol press, etc.)
+    PuzzleInteract,
+    /// Puzzle completion
+    PuzzleSolved,
+    /// Door/room transition
+    RoomChanged,
+    /// Save confirmation (auto or manual)
+    Save,
+    /// Load confirmation
+    Load,
+    /// Player footstep
+    Footstep,
+    /// Pressure plate click
+    PlateClick,
+    /// Water drop hitting the candle flame
+    CandleSizzle,
+    /// The player's candle going out
+    CandleSnuffed,
+    /// Swinging blade sweeping through its lethal arc
+    BladeSwoosh,
+    /// Collapsing floor giving way
+    FloorCrumble,
+    /// Falling chandelier smashing into the floor
+    ChandelierCrash,
+    /// Poison gas exposure starting
+    GasHiss,
+    /// Player breaking the surface of water
+    Splash,
+    /// Grappling rope thrown and catching an anchor
+    RopeThrow,
+    /// Boards, a crate or a weak wall breaking apart
+    WoodSplinter,
+    /// Magnet pulling metal objects in
+    MagnetHum,
+    /// Wire cutters snipping through a wire
+    WireSnip,
+    /// Electric shock from a live hazard
+    ElectricZap,
+    /// Oil squirted on a stuck hinge or lever
+    OilSquirt,
+    /// Checkpoint reached
+    Checkpoint,
+    /// Soundtrack escalating into tension or a chase
+    TensionStinger,
+    /// Menu focus moved to another entry
+    UiMove,
+    /// Menu entry activated
+    UiConfirm,
+    /// Menu closed or action cancelled
+    UiCancel,
+    /// Menu action rejected
+    UiError,
+}
+
+/// Playback definition for a single sound cue.
+///
+/// Each time the cue plays, one of the `variations` is picked at random and
+/// played with a pitch and volume drawn from the configured ranges.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct SoundBankEntry {
+    /// Audio file paths relative to assets/ (e.g., "audio/footstep_1.mp3")
+    pub variations: Vec<String>,
+    /// Playback rate range (min, max), 1.0 = original pitch
+    #[serde(default = "default_pitch")]
+    pub pitch: (f32, f32),
+    /// Volume range (min, max) in decibels, 0.0 = original volume
+    #[serde(default)]
+    pub volume_db: (f32, f32),
+    /// Minimum seconds between two plays of this cue (0.0 = no limit)
+    #[serde(default)]
+    pub cooldown: f32,
+    /// Volume bus the cue plays on (defaults to `VolumeBus::Sfx`)
+    #[serde(default)]
+    pub bus: VolumeBus,
+}
+
+fn default_pitch() -> (f32, f32) {
+    (1.0, 1.0)
+}
+
+impl SoundBankEntry {
+    /// Creates an entry with a single variation and neutral pitch/volume
+    pub fn single(path: &str) -> Self {
+        Self {
+            variations: vec![path.to_string()],
+            pitch: default_pitch(),
+            volume_db: (0.0, 0.0),
+            cooldown: 0.0,
+            bus: VolumeBus::Sfx,
+        }
+    }
+}
+
+/// Resource mapping logical sound cues to their playback definitions.
+///
+/// Loaded from `assets/audio/sound_bank.ron` at startup. If the file is
+/// missing or invalid, the default bank (one variation per cue, matching
+/// the original hard-coded paths) is used instead.
+#[derive(Resource, Deserialize, Debug, Clone, PartialEq)]
+pub struct SoundBank {
+    /// Map of cues to their playback definitions
+    pub entries: HashMap<SoundCue, SoundBankEntry>,
+}
+
+impl Default for SoundBank {
+    fn default() -> Self {
+        let entries = [
+            (SoundCue::TrapTriggered, "audio/trap_triggered.mp3"),
+            (SoundCue::PlayerDeath, "audio/player_death.mp3"),
+            (SoundCue::ItemCollected, "audio/item_collected.mp3"),
+            (SoundCue::ItemUsed, "audio/item_used.mp3"),
+            (SoundCue::PuzzleInteract, "audio/puzzle_interact.mp3"),
+            (SoundCue::PuzzleSolved, "audio/puzzle_solved.mp3"),
+            (SoundCue::RoomChanged, "audio/room_changed.mp3"),
+            (SoundCue::Save, "audio/save.mp3"),
+            (SoundCue::Load, "audio/load.mp3"),
+        ]
+        .into_iter()
+        .map(|(cue, path)| (cue, SoundBankEntry::single(path)))
+        .collect();
+
+        Self { entries }
+    }
+}
+
+/// Load a sound bank from a RON file
+///
+/// # Arguments
+/// * `path` - Path to the RON file (e.g., "assets/audio/sound_bank.ron")
+///
+/// # Errors
+/// Returns error string if the file cannot be read or parsed
+pub fn load_sound_bank(path: &str) -> Result<SoundBank, String> {
+    let content = fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read sound bank '{}': {}", path, e))?;
+
+    ron::from_str(&content).map_err(|e| format!("Failed to parse RON from '{}': {}", path, e))
+}
+
+/// Resource tracking when each cue was last played (in seconds of elapsed time).
+///
+/// Used to enforce per-cue cooldowns so rapid repeats (footsteps, plate
+/// clicks) don't machine-gun.
+#[derive(Resource, Default, Debug)]
+pub struct SoundCooldowns {
+    /// Elapsed time at which each cue last played
+    pub last_played: HashMap<SoundCue, f32>,
+}
+
+impl SoundCooldowns {
+    /// Returns true and records the play if the cue is off cooldown at `now`
+    pub fn try_consume(&mut self, cue: SoundCue, cooldown: f32, now: f32) -> bool {
+        if let Some(last) = self.last_played.get(&cue)
+            && now - last < cooldown
+        {
+            return false;
+        }
+        self.last_played.insert(cue, now);
+        true
+    }
+}
+
+/// Event requesting that a sound cue be played through the sound bank.
+///
+/// Systems without a dedicated game event can use this to trigger sounds.
+#[derive(Event, Clone, Copy, Debug)]
+pub struct PlaySoundEvent {
+    /// The cue to play
+    pub cue: SoundCue,
+}
+
+/// Event requesting that a sound cue be played from an entity's position.
+///
+/// The cue is attenuated and panned by the emitter's distance from the
+/// player (see `mixer::spatial_mix`).
+#[derive(Event, Clone, Copy, Debug)]
+pub struct PlaySoundAtEvent {
+    /// The cue to play
+    pub cue: SoundCue,
+    /// Entity the sound comes from
+    pub emitter: Entity,
+}
+
+/// System parameter bundling everything needed to play a cue from the sound bank
+#[derive(SystemParam)]
+pub struct SoundBankPlayer<'w, 's> {
+    audio: Res<'w, Audio>,
+    asset_server: Res<'w, AssetServer>,
+    bank: Res<'w, SoundBank>,
+    cooldowns: ResMut<'w, SoundCooldowns>,
+    time: Res<'w, Time>,
+    volume: Option<Res<'w, VolumeSettings>>,
+    transforms: Query<'w, 's, &'static Transform>,
+    listener: Query<'w, 's, &'static Transform, With<Player>>,
+}
+
+impl SoundBankPlayer<'_, '_> {
+    /// Plays a random variation of `cue` at full level, centered
+    ///
+    /// Returns true if a sound was actually started (see `play_mixed`).
+    pub fn play(&mut self, cue: SoundCue) -> bool {
+        self.play_mixed(cue, 1.0, 0.5)
+    }
+
+    /// Plays `cue` attenuated and panned by `emitter`'s distance from the player
+    ///
+    /// Plays non-positionally if either has no `Transform`.
+    pub fn play_from(&mut self, cue: SoundCue, emitter: Entity) -> bool {
+        let Ok(source) = self.transforms.get(emitter) else {
+            return self.play(cue);
+        };
+        self.play_at(cue, source.translation.truncate())
+    }
+
+    /// Plays `cue` attenuated and panned by `position`'s distance from the player
+    ///
+    /// Plays non-positionally if there is no player.
+    pub fn play_at(&mut self, cue: SoundCue, position: Vec2) -> bool {
+        let Ok(listener) = self.listener.single() else {
+            return self.play(cue);
+        };
+        let mix = spatial_mix(listener.translation.truncate(), position);
+        self.play_mixed(cue, mix.level, mix.panning)
+    }
+
+    /// Plays a random variation of `cue` at `level` (linear) and `panning`
+    ///
+    /// Honors the cue's cooldown and its bus in `VolumeSettings`. Returns true
+    /// if a sound was actually started: inaudible cues never start and don't
+    /// use up the cooldown.
+    fn play_mixed(&mut self, cue: SoundCue, level: f32, panning: f32) -> bool {
+        let Some(entry) = self.bank.entries.get(&cue) else {
+            warn!("No sound bank entry for {:?}", cue);
+            return false;
+        };
+
+        if entry.variations.is_empty() {
+            return false;
+        }
+
+        let bus_level = self
+            .volume
+            .as_deref()
+            .map_or(1.0, |volume| volume.level(entry.bus));
+        let level = level * bus_level;
+        if level <= 0.0 {
+            return false;
+        }
+
+        let now = self.time.elapsed_secs();
+        if !self.cooldowns.try_consume(cue, entry.cooldown, now) {
+            return false;
+        }
+
+        let mut rng = rand::rng();
+        let path = &entry.variations[rng.random_range(0..entry.variations.len())];
+        let pitch = sample_range(&mut rng, entry.pitch);
+        let volume = sample_range(&mut rng, entry.volume_db) + level_to_decibels(level);
+
+        self.audio
+            .play(self.asset_server.load(path.clone()))
+            .with_playback_rate(pitch as f64)
+            .with_volume(volume)
+            .with_panning(panning as f64);
+        true
+    }
+}
+
+/// Draws a value uniformly from an inclusive (min, max) range, tolerating reversed bounds
+fn sample_range(rng: &mut impl Rng, (a, b): (f32, f32)) -> f32 {
+    let (min, max) = if a <= b { (a, b) } else { (b, a) };
+    if min == max {
+        min
+    } else {
+        rng.random_range(min..=max)
+    }
+}
+
+/// Startup system that loads the sound bank from disk
+///
+/// Falls back to the default bank if the file is missing or malformed. Cues
+/// replaced by mods (`ModContent`, when present) play the mods' sounds.
+pub fn load_sound_bank_system(mut commands: Commands, mods: Option<Res<ModContent>>) {
+    let mut bank = match load_sound_bank(SOUND_BANK_PATH) {
+        Ok(bank) => {
+            info!("Loaded sound bank with {} cues", bank.entries.len());
+            bank
+        }
+        Err(e) => {
+            warn!("{}. Using default sound bank.", e);
+            SoundBank::default()
+        }
+    };
+    if let Some(mods) = mods {
+        mods.merge_sounds(&mut bank);
+    }
+    commands.insert_resource(bank);
+}
+
+/// System that plays cues requested via `PlaySoundEvent`
+pub fn play_sound_requests(mut events: EventReader<PlaySoundEvent>, mut player: SoundBankPlayer) {
+    for event in events.read() {
+        player.play(event.cue);
+    }
+}
+
+/// System that plays positional cues requested via `PlaySoundAtEvent`
+pub fn play_positional_sound_requests(
+    mut events: EventReader<PlaySoundAtEvent>,
+    mut player: SoundBankPlayer,
+) {
+    for event in events.read() {
+        player.play_from(event.cue, event.emitter);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_bank_covers_original_events() {
+        let bank = SoundBank::default();
+        assert_eq!(bank.entries.len(), 9);
+        assert_eq!(
+            bank.entries[&SoundCue::TrapTriggered].variations,
+            vec!["audio/trap_triggered.mp3".to_string()]
+        );
+    }
+
+    #[test]
+    fn sound_bank_file_parses() {
+        let bank = load_sound_bank(SOUND_BANK_PATH).expect("sound bank should parse");
+        let footstep = &bank.entries[&SoundCue::Footstep];
+        assert!(footstep.variations.len() > 1, "Footsteps should vary");
+        assert!(footstep.cooldown > 0.0, "Footsteps should have a cooldown");
+    }
+
+    #[test]
+    fn entry_defaults_apply() {
+        let entry: SoundBankEntry = ron::from_str(r#"(variations: ["audio/a.mp3"])"#).unwrap();
+        assert_eq!(entry.pitch, (1.0, 1.0));
+        assert_eq!(entry.volume_db, (0.0, 0.0));
+        assert_eq!(entry.cooldown, 0.0);
+        assert_eq!(entry.bus, VolumeBus::Sfx);
+    }
+
+    #[test]
+    fn load_sound_bank_handles_invalid_path() {
+        let result = load_sound_bank("assets/audio/nonexistent.ron");
+        assert!(result.unwrap_err().contains("Failed to read sound bank"));
+    }
+
+    #[test]
+    fn cooldown_blocks_rapid_repeats() {
+        let mut cooldowns = SoundCooldowns::default();
+        assert!(cooldowns.try_consume(SoundCue::Footstep, 0.25, 1.0));
+        assert!(!cooldowns.try_consume(SoundCue::Footstep, 0.25, 1.1));
+        assert!(cooldowns.try_consume(SoundCue::Footstep, 0.25, 1.3));
+    }
+
+    #[test]
+    fn cooldowns_are_per_cue() {
+        let mut cooldowns = SoundCooldowns::default();
+        assert!(cooldowns.try_consume(SoundCue::Footstep, 1.0, 0.0));
+        assert!(cooldowns.try_consume(SoundCue::PlateClick, 1.0, 0.0));
+    }
+
+    #[test]
+    fn sample_range_stays_in_bounds() {
+        let mut rng = rand::rng();
+        for _ in 0..100 {
+            let v = sample_range(&mut rng, (0.9, 1.1));
+            assert!((0.9..=1.1).contains(&v));
+        }
+        assert_eq!(sample_range(&mut rng, (1.0, 1.0)), 1.0);
+        let reversed = sample_range(&mut rng, (2.0, 1.0));
+        assert!((1.0..=2.0).contains(&reversed));
+    }
+}
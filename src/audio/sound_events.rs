@@ -1,6 +1,11 @@
 use bevy::prelude::*;
 use bevy_kira_audio::prelude::*;
 
+use crate::audio::mixer::VolumeSettings;
+use crate::audio::sound_bank::{
+    PlaySoundAtEvent, PlaySoundEvent, SoundBank, SoundBankPlayer, SoundCooldowns, SoundCue,
+    load_sound_bank_system, play_positional_sound_requests, play_sound_requests,
+};
 use crate::systems::inventory::{ItemCollectedEvent, ItemUsedEvent};
 use crate::systems::puzzle::{PuzzleInteractEvent, PuzzleSolvedEvent};
 use crate::systems::room_transition::RoomChangedEvent;
@@ -11,6 +16,9 @@ use crate::systems::trap::{PlayerDeathEvent, TrapTriggeredEvent};
 ///
 /// Integrates bevy_kira_audio 0.23.0 with game events to provide
 /// responsive sound effects for player actions and game state changes.
+/// Each game event is mapped to a logical `SoundCue` which is resolved
+/// through the data-driven `SoundBank` (variations, pitch/volume ranges
+/// and per-cue cooldowns).
 ///
 /// # Audio Events
 /// - **TrapTriggeredEvent**: Plays trap activation sound
@@ -22,6 +30,14 @@ use crate::systems::trap::{PlayerDeathEvent, TrapTriggeredEvent};
 /// - **RoomChangedEvent**: Plays door/transition sound
 /// - **AutoSaveEvent/ManualSaveEvent**: Plays save confirmation sound
 /// - **LoadGameEvent**: Plays load confirmation sound
+/// - **PlaySoundEvent**: Plays any requested cue (menus use it for the
+///   `UiMove`/`UiConfirm`/`UiCancel`/`UiError` feedback cues)
+/// - **PlaySoundAtEvent**: Plays any requested cue from an entity's position
+///
+/// Each cue plays on its sound bank bus (SFX or ambience), scaled by the
+/// master level in `VolumeSettings`. Trap, item and puzzle sounds, and
+/// `PlaySoundAtEvent` cues, are attenuated and panned by the emitter's
+/// distance from the player.
 ///
 /// From tasks.md T036: Event-based audio system with bevy_kira_audio 0.23.0
 pub struct SoundEventsPlugin;
@@ -29,40 +45,56 @@ pub struct SoundEventsPlugin;
 impl Plugin for SoundEventsPlugin {
     fn build(&self, app: &mut App) {
         app.add_plugins(AudioPlugin)
-            .add_systems(Update, play_sound_effects);
+            .init_resource::<SoundBank>()
+            .init_resource::<SoundCooldowns>()
+            .init_resource::<VolumeSettings>()
+            .add_event::<PlaySoundEvent>()
+            .add_event::<PlaySoundAtEvent>()
+            .add_systems(Startup, load_sound_bank_system)
+            .add_systems(
+                Update,
+                (
+                    play_sound_effects,
+                    play_sound_requests,
+                    play_positional_sound_requests,
+                ),
+            );
     }
 }
 
 /// System that plays sound effects in response to game events
 ///
-/// Listens to all game events and triggers appropriate sound effects
-/// using the Audio resource. Sounds are loaded from assets/audio/*.
+/// Listens to all game events and plays the matching `SoundCue` through
+/// the `SoundBank`, which picks a variation and applies pitch/volume
+/// randomization and cooldowns.
 ///
 /// # System Dependencies
-/// - **Resources**: Audio (from bevy_kira_audio), AssetServer
+/// - **Resources**: Audio (from bevy_kira_audio), AssetServer, SoundBank, SoundCooldowns,
+///   VolumeSettings (optional)
+/// - **Components**: Reads the `Transform` of the player and of event emitters
 /// - **Events**: Reads all game event types (trap, inventory, puzzle, etc.)
 ///
 /// # Behavior
 /// 1. Read all event readers for game events
-/// 2. For each event, load and play corresponding audio file
-/// 3. Audio files are loaded on-demand from assets/audio/
+/// 2. For each event, resolve the corresponding cue in the sound bank
+/// 3. Play a random variation unless the cue is still on cooldown; trap,
+///    item and puzzle cues are mixed by their entity's distance from the player
 ///
-/// # Asset Paths
-/// - `assets/audio/trap_triggered.mp3`: Trap activation
-/// - `assets/audio/player_death.mp3`: Death sound
-/// - `assets/audio/item_collected.mp3`: Item pickup
-/// - `assets/audio/item_used.mp3`: Item usage
-/// - `assets/audio/puzzle_interact.mp3`: Puzzle interaction
-/// - `assets/audio/puzzle_solved.mp3`: Puzzle completion
-/// - `assets/audio/room_changed.mp3`: Door/room transition
-/// - `assets/audio/save.mp3`: Save confirmation
-/// - `assets/audio/load.mp3`: Load confirmation
+/// # Cue Mapping
+/// - `TrapTriggeredEvent` → `SoundCue::TrapTriggered`
+/// - `PlayerDeathEvent` → `SoundCue::PlayerDeath`
+/// - `ItemCollectedEvent` → `SoundCue::ItemCollected`
+/// - `ItemUsedEvent` → `SoundCue::ItemUsed`
+/// - `PuzzleInteractEvent` → `SoundCue::PuzzleInteract`
+/// - `PuzzleSolvedEvent` → `SoundCue::PuzzleSolved`
+/// - `RoomChangedEvent` → `SoundCue::RoomChanged`
+/// - `AutoSaveEvent`/`ManualSaveEvent` → `SoundCue::Save`
+/// - `LoadGameEvent` → `SoundCue::Load`
 ///
 /// From tasks.md T036: Plays sounds on events (verified manually after T024-T032)
 #[allow(clippy::too_many_arguments)]
 pub fn play_sound_effects(
-    audio: Res<Audio>,
-    asset_server: Res<AssetServer>,
+    mut player: SoundBankPlayer,
     mut trap_events: EventReader<TrapTriggeredEvent>,
     mut death_events: EventReader<PlayerDeathEvent>,
     mut item_collected_events: EventReader<ItemCollectedEvent>,
@@ -75,62 +107,52 @@ pub fn play_sound_effects(
     mut load_events: EventReader<LoadGameEvent>,
 ) {
     // Play trap triggered sound
-    for _event in trap_events.read() {
-        audio.play(asset_server.load("audio/trap_triggered.mp3"));
-        info!("Playing trap triggered sound");
+    for event in trap_events.read() {
+        player.play_from(SoundCue::TrapTriggered, event.trap);
     }
 
     // Play player death sound
     for _event in death_events.read() {
-        audio.play(asset_server.load("audio/player_death.mp3"));
-        info!("Playing player death sound");
+        player.play(SoundCue::PlayerDeath);
     }
 
     // Play item collected sound
-    for _event in item_collected_events.read() {
-        audio.play(asset_server.load("audio/item_collected.mp3"));
-        info!("Playing item collected sound");
+    for event in item_collected_events.read() {
+        player.play_from(SoundCue::ItemCollected, event.item);
     }
 
     // Play item used sound
     for _event in item_used_events.read() {
-        audio.play(asset_server.load("audio/item_used.mp3"));
-        info!("Playing item used sound");
+        player.play(SoundCue::ItemUsed);
     }
 
     // Play puzzle interaction sound
-    for _event in puzzle_interact_events.read() {
-        audio.play(asset_server.load("audio/puzzle_interact.mp3"));
-        info!("Playing puzzle interact sound");
+    for event in puzzle_interact_events.read() {
+        player.play_from(SoundCue::PuzzleInteract, event.puzzle);
     }
 
     // Play puzzle solved sound
-    for _event in puzzle_solved_events.read() {
-        audio.play(asset_server.load("audio/puzzle_solved.mp3"));
-        info!("Playing puzzle solved sound");
+    for event in puzzle_solved_events.read() {
+        player.play_from(SoundCue::PuzzleSolved, event.puzzle);
     }
 
     // Play room changed sound
     for _event in room_changed_events.read() {
-        audio.play(asset_server.load("audio/room_changed.mp3"));
-        info!("Playing room changed sound");
+        player.play(SoundCue::RoomChanged);
     }
 
     // Play save sound (auto or manual)
     for _event in auto_save_events.read() {
-        audio.play(asset_server.load("audio/save.mp3"));
-        info!("Playing auto-save sound");
+        player.play(SoundCue::Save);
     }
 
     for _event in manual_save_events.read() {
-        audio.play(asset_server.load("audio/save.mp3"));
-        info!("Playing manual save sound");
+        player.play(SoundCue::Save);
     }
 
     // Play load sound
     for _event in load_events.read() {
-        audio.play(asset_server.load("audio/load.mp3"));
-        info!("Playing load game sound");
+        player.play(SoundCue::Load);
     }
 }
 
@@ -242,4 +264,123 @@ mod tests {
         // If we reach here, the system runs without panicking
         assert!(true, "Sound events system runs successfully");
     }
+
+    #[test]
+    fn play_sound_event_records_cooldown() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, bevy::asset::AssetPlugin::default()));
+        app.add_plugins(SoundEventsPlugin);
+
+        app.add_event::<TrapTriggeredEvent>();
+        app.add_event::<PlayerDeathEvent>();
+        app.add_event::<ItemCollectedEvent>();
+        app.add_event::<ItemUsedEvent>();
+        app.add_event::<PuzzleInteractEvent>();
+        app.add_event::<PuzzleSolvedEvent>();
+        app.add_event::<RoomChangedEvent>();
+        app.add_event::<AutoSaveEvent>();
+        app.add_event::<ManualSaveEvent>();
+        app.add_event::<LoadGameEvent>();
+
+        app.world_mut().send_event(PlaySoundEvent {
+            cue: SoundCue::Footstep,
+        });
+        app.update();
+
+        let cooldowns = app.world().resource::<SoundCooldowns>();
+        assert!(
+            cooldowns.last_played.contains_key(&SoundCue::Footstep),
+            "Playing a cue should record its cooldown"
+        );
+    }
+
+    #[test]
+    fn muted_sfx_volume_skips_cues() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, bevy::asset::AssetPlugin::default()));
+        app.add_plugins(SoundEventsPlugin);
+        app.insert_resource(VolumeSettings {
+            sfx: 0.0,
+            ..default()
+        });
+
+        app.add_event::<TrapTriggeredEvent>();
+        app.add_event::<PlayerDeathEvent>();
+        app.add_event::<ItemCollectedEvent>();
+        app.add_event::<ItemUsedEvent>();
+        app.add_event::<PuzzleInteractEvent>();
+        app.add_event::<PuzzleSolvedEvent>();
+        app.add_event::<RoomChangedEvent>();
+        app.add_event::<AutoSaveEvent>();
+        app.add_event::<ManualSaveEvent>();
+        app.add_event::<LoadGameEvent>();
+
+        app.world_mut().send_event(PlaySoundEvent {
+            cue: SoundCue::UiConfirm,
+        });
+        app.update();
+
+        let cooldowns = app.world().resource::<SoundCooldowns>();
+        assert!(
+            !cooldowns.last_played.contains_key(&SoundCue::UiConfirm),
+            "Muted sound effects should not play or start a cooldown"
+        );
+    }
+
+    #[test]
+    fn out_of_earshot_cues_are_skipped() {
+        use crate::audio::mixer::HEARING_DISTANCE;
+        use crate::components::player::Player;
+
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, bevy::asset::AssetPlugin::default()));
+        app.add_plugins(SoundEventsPlugin);
+
+        app.add_event::<TrapTriggeredEvent>();
+        app.add_event::<PlayerDeathEvent>();
+        app.add_event::<ItemCollectedEvent>();
+        app.add_event::<ItemUsedEvent>();
+        app.add_event::<PuzzleInteractEvent>();
+        app.add_event::<PuzzleSolvedEvent>();
+        app.add_event::<RoomChangedEvent>();
+        app.add_event::<AutoSaveEvent>();
+        app.add_event::<ManualSaveEvent>();
+        app.add_event::<LoadGameEvent>();
+
+        app.world_mut().spawn((Player, Transform::default()));
+        let far = app
+            .world_mut()
+            .spawn(Transform::from_xyz(HEARING_DISTANCE * 2.0, 0.0, 0.0))
+            .id();
+        let near = app
+            .world_mut()
+            .spawn(Transform::from_xyz(32.0, 0.0, 0.0))
+            .id();
+
+        app.world_mut().send_event(PlaySoundAtEvent {
+            cue: SoundCue::BladeSwoosh,
+            emitter: far,
+        });
+        app.update();
+        assert!(
+            !app.world()
+                .resource::<SoundCooldowns>()
+                .last_played
+                .contains_key(&SoundCue::BladeSwoosh),
+            "Sounds beyond hearing distance should not play"
+        );
+
+        app.world_mut().send_event(PlaySoundAtEvent {
+            cue: SoundCue::BladeSwoosh,
+            emitter: near,
+        });
+        app.update();
+        assert!(
+            app.world()
+                .resource::<SoundCooldowns>()
+                .last_played
+                .contains_key(&SoundCue::BladeSwoosh),
+            "Nearby sounds should play"
+        );
+    }
 }
@@ -0,0 +1,53 @@
+//! Level checking tool
+//!
+//! Checks every level RON file before the house ships:
+//!
+//! ```text
+//! level_check [levels directory]
+//! ```
+//!
+//! The directory defaults to assets/levels. Each level is checked against
+//! the level format, the tileset in the sprite manifest and the spawn
+//! registry, then the levels together for doors that lead nowhere or don't
+//! lead back, and locks whose key the player can't reach. Every problem is
+//! listed, and the tool exits non-zero if there are any.
+
+use rust_game::resources::asset_handles::{
+    SPRITE_MANIFEST_PATH, SpriteManifest, load_sprite_manifest,
+};
+use rust_game::systems::level_check::{LEVELS_DIR, check_level_dir};
+use std::path::PathBuf;
+
+fn main() {
+    if let Err(e) = run(std::env::args().skip(1).collect()) {
+        eprintln!("{}", e);
+        std::process::exit(1);
+    }
+}
+
+/// Runs the check on the directory in `args` (program name already removed)
+fn run(args: Vec<String>) -> Result<(), String> {
+    let dir = match args.as_slice() {
+        [] => PathBuf::from(LEVELS_DIR),
+        [dir] => PathBuf::from(dir),
+        _ => return Err("Usage: level_check [levels directory]".to_string()),
+    };
+    let manifest = load_sprite_manifest(SPRITE_MANIFEST_PATH).unwrap_or_else(|e| {
+        eprintln!("{}; checking tiles against the built-in tileset", e);
+        SpriteManifest::default()
+    });
+
+    let problems = check_level_dir(&dir, &manifest)?;
+    if problems.is_empty() {
+        println!("Levels in {} are valid", dir.display());
+        return Ok(());
+    }
+    for problem in &problems {
+        println!("- {}", problem);
+    }
+    Err(format!(
+        "Levels in {} have {} problem(s)",
+        dir.display(),
+        problems.len()
+    ))
+}
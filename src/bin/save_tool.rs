@@ -0,0 +1,113 @@
+//! Save file support tool
+//!
+//! Inspects, validates, migrates and repairs save files outside the game:
+//!
+//! ```text
+//! save-tool inspect  <save>
+//! save-tool validate <save>
+//! save-tool migrate  <save> [--output <file>]
+//! save-tool fix      <save> [--output <file>]
+//! ```
+//!
+//! `<save>` is a save file path or a slot number (0 for the auto-save).
+//! `migrate` and `fix` rewrite the save in place unless `--output` is
+//! given, keeping the original next to it as `<save>.bak`.
+
+#[cfg(not(target_arch = "wasm32"))]
+fn main() {
+    if let Err(e) = tool::run(std::env::args().skip(1).collect()) {
+        eprintln!("{}", e);
+        std::process::exit(1);
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+fn main() {
+    // Browser builds keep saves in localStorage; there's no file to work on
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+mod tool {
+    use rust_game::systems::save_load::get_save_path;
+    use rust_game::systems::save_migration::{
+        fix_save, migrate_save, parse_save, validate_save, write_save,
+    };
+    use std::fs;
+    use std::path::PathBuf;
+
+    const USAGE: &str =
+        "Usage: save-tool <inspect|validate|migrate|fix> <save file or slot> [--output <file>]";
+
+    /// Runs the command in `args` (program name already removed)
+    pub fn run(args: Vec<String>) -> Result<(), String> {
+        let [command, save, rest @ ..] = args.as_slice() else {
+            return Err(USAGE.to_string());
+        };
+        let input = save_path(save);
+        let output = match rest {
+            [] => None,
+            [flag, path] if flag == "--output" => Some(PathBuf::from(path)),
+            _ => return Err(USAGE.to_string()),
+        };
+
+        let content = fs::read_to_string(&input)
+            .map_err(|e| format!("Failed to read '{}': {}", input.display(), e))?;
+        let mut save_data = parse_save(&content)?;
+
+        match command.as_str() {
+            "inspect" => {
+                println!("{}", write_save(&save_data)?);
+                Ok(())
+            }
+            "validate" => {
+                let problems = validate_save(&save_data);
+                if problems.is_empty() {
+                    println!("{} is valid", input.display());
+                    return Ok(());
+                }
+                for problem in &problems {
+                    println!("- {}", problem);
+                }
+                Err(format!(
+                    "{} has {} problem(s)",
+                    input.display(),
+                    problems.len()
+                ))
+            }
+            "migrate" | "fix" => {
+                let mut changes = migrate_save(&mut save_data)?;
+                if command == "fix" {
+                    changes.extend(fix_save(&mut save_data));
+                }
+                for change in &changes {
+                    println!("- {}", change);
+                }
+
+                let output = output.unwrap_or_else(|| input.clone());
+                if output == input {
+                    let backup = input.with_extension("ron.bak");
+                    fs::copy(&input, &backup).map_err(|e| {
+                        format!("Failed to back up to '{}': {}", backup.display(), e)
+                    })?;
+                }
+                fs::write(&output, write_save(&save_data)?)
+                    .map_err(|e| format!("Failed to write '{}': {}", output.display(), e))?;
+                println!("Wrote {}", output.display());
+
+                for problem in validate_save(&save_data) {
+                    println!("Still invalid: {}", problem);
+                }
+                Ok(())
+            }
+            _ => Err(USAGE.to_string()),
+        }
+    }
+
+    /// Resolves a slot number to its save path; anything else is a path
+    fn save_path(save: &str) -> PathBuf {
+        match save.parse::<usize>() {
+            Ok(slot) => get_save_path(slot),
+            Err(_) => PathBuf::from(save),
+        }
+    }
+}
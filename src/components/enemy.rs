@@ -0,0 +1,8 @@
+use bevy::prelude::*;
+
+/// Marker component for the stalker that hunts the player through the house.
+///
+/// Other systems react to its proximity (e.g., the music system fades in
+/// tension and chase stems as it closes in).
+#[derive(Component, Debug, Default)]
+pub struct Stalker;
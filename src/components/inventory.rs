@@ -1,17 +1,160 @@
 use bevy::prelude::*;
 
+use crate::components::lighting::FULL_CANDLE_WAX;
+
+/// Most matches that share one inventory slot
+pub const MAX_MATCH_STACK: u32 = 99;
+
+/// Charges of oil in a full oil can
+pub const OIL_CAN_CHARGES: u32 = 3;
+
 /// Component storing a collection of items with capacity limit.
 ///
 /// Attached to entities (typically the player) that can carry items.
-/// Attempting to add items beyond `max_capacity` should be rejected.
+/// Stackable items (matches) share a slot up to their `Item::max_stack`;
+/// every other item takes a slot of its own. Use `Inventory::add` to
+/// respect `max_capacity` and the per-category limits.
 #[derive(Component)]
 pub struct Inventory {
-    /// Items currently in the inventory
+    /// Items currently in the inventory (one entry per unit, stacked or not)
     pub items: Vec<Item>,
-    /// Maximum number of items that can be carried
+    /// Maximum number of slots that can be filled
     pub max_capacity: usize,
 }
 
+/// Why an item could not be added to an inventory
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InventoryFullReason {
+    /// Every slot is taken
+    NoFreeSlots,
+    /// The item's category already holds its limit
+    CategoryFull(ItemCategory),
+}
+
+impl Inventory {
+    /// Number of units of `item` carried
+    pub fn count(&self, item: &Item) -> usize {
+        self.items
+            .iter()
+            .filter(|carried| carried.same_kind(item))
+            .count()
+    }
+
+    /// Number of slots the carried items fill
+    ///
+    /// Stackable items fill one slot per `max_stack` units; every other
+    /// item fills a slot of its own.
+    pub fn slots_used(&self) -> usize {
+        let mut slots = 0;
+        for (index, item) in self.items.iter().enumerate() {
+            let stack = item.max_stack() as usize;
+            // Count each stackable kind once, at its first unit
+            if stack > 1 {
+                if self.items[..index].iter().any(|seen| seen.same_kind(item)) {
+                    continue;
+                }
+                slots += self.count(item).div_ceil(stack);
+            } else {
+                slots += 1;
+            }
+        }
+        slots
+    }
+
+    /// Number of slots the items of `category` fill
+    pub fn category_slots(&self, category: ItemCategory) -> usize {
+        self.items
+            .iter()
+            .filter(|item| item.category() == category)
+            .count()
+    }
+
+    /// Checks whether one more `item` fits
+    ///
+    /// # Errors
+    /// Returns the reason if the item would exceed `max_capacity` or its
+    /// category limit. A unit that tops up a partly filled stack always fits.
+    pub fn check_add(&self, item: &Item) -> Result<(), InventoryFullReason> {
+        let stack = item.max_stack() as usize;
+        let carried = self.count(item);
+        if stack > 1 && !carried.is_multiple_of(stack) {
+            return Ok(());
+        }
+
+        if self.slots_used() >= self.max_capacity {
+            return Err(InventoryFullReason::NoFreeSlots);
+        }
+        let category = item.category();
+        if let Some(limit) = category.slot_limit()
+            && self.category_slots(category) >= limit
+        {
+            return Err(InventoryFullReason::CategoryFull(category));
+        }
+        Ok(())
+    }
+
+    /// Adds one `item` if it fits
+    ///
+    /// # Errors
+    /// Returns the reason from `check_add` and leaves the inventory unchanged
+    pub fn add(&mut self, item: Item) -> Result<(), InventoryFullReason> {
+        self.check_add(&item)?;
+        self.items.push(item);
+        Ok(())
+    }
+
+    /// Uses one charge of the first oil can with oil left
+    ///
+    /// A can is dropped from the inventory once its last charge is used.
+    ///
+    /// # Returns
+    /// The charges left in the can, or `None` (leaving the inventory
+    /// unchanged) if no oil can has any oil
+    pub fn use_oil_charge(&mut self) -> Option<u32> {
+        let index = self.items.iter().position(
+            |item| matches!(item, Item::Tool(ToolType::OilCan(charges)) if *charges > 0),
+        )?;
+        let Item::Tool(ToolType::OilCan(charges)) = &mut self.items[index] else {
+            return None;
+        };
+        *charges -= 1;
+        let left = *charges;
+        if left == 0 {
+            self.items.remove(index);
+        }
+        Some(left)
+    }
+
+    /// Pours the wax of the candle stub at `from` into the stub at `into`
+    ///
+    /// `into` fills up to `FULL_CANDLE_WAX`; wax that doesn't fit stays in
+    /// `from`, which is removed once it's empty.
+    ///
+    /// # Returns
+    /// The wax `into` ends up with, or `None` (leaving the inventory
+    /// unchanged) unless both are different candle stubs
+    pub fn merge_candle_stubs(&mut self, into: usize, from: usize) -> Option<f32> {
+        if into == from {
+            return None;
+        }
+        let (Some(Item::CandleStub(into_wax)), Some(Item::CandleStub(from_wax))) =
+            (self.items.get(into), self.items.get(from))
+        else {
+            return None;
+        };
+
+        let merged = (into_wax + from_wax).min(FULL_CANDLE_WAX);
+        let left = from_wax - (merged - into_wax);
+        self.items[into] = Item::CandleStub(merged);
+        if left > 0.0 {
+            self.items[from] = Item::CandleStub(left);
+        } else {
+            self.items.remove(from);
+        }
+        Some(merged)
+    }
+}
+
 /// Component representing a collectible item in the game.
 ///
 /// Can exist as a world entity (with `Collectible` marker) or within
@@ -30,6 +173,95 @@ pub enum Item {
     DoubleJumpItem,
     /// Story page with associated page number
     DiaryPage(usize),
+    /// Partly burnt candle holding the given wax (up to `FULL_CANDLE_WAX`)
+    CandleStub(f32),
+}
+
+impl Item {
+    /// Returns the category the item counts against
+    pub fn category(&self) -> ItemCategory {
+        match self {
+            Item::Match | Item::CandleStub(_) => ItemCategory::Consumable,
+            Item::Key(_) => ItemCategory::Key,
+            Item::Tool(_) => ItemCategory::Tool,
+            Item::PuzzleItem(_) => ItemCategory::PuzzleItem,
+            Item::DoubleJumpItem => ItemCategory::Ability,
+            Item::DiaryPage(_) => ItemCategory::Document,
+        }
+    }
+
+    /// Most units of the item that share one slot (1 for unstackable items)
+    pub fn max_stack(&self) -> u32 {
+        match self {
+            Item::Match => MAX_MATCH_STACK,
+            _ => 1,
+        }
+    }
+
+    /// Returns true if the item can be assigned to the hotbar and used instantly
+    pub fn is_quick_usable(&self) -> bool {
+        matches!(self, Item::Match | Item::Tool(_))
+    }
+
+    /// Returns true when both items are the same kind (and key, tool or page)
+    ///
+    /// Candle stubs are only alike with the same wax, so stubs of different
+    /// sizes show in slots of their own. Oil cans are alike whatever their
+    /// charges, so a hotbar slot keeps its can as the oil runs out.
+    pub fn same_kind(&self, other: &Item) -> bool {
+        match (self, other) {
+            (Item::Match, Item::Match) => true,
+            (Item::Key(k1), Item::Key(k2)) => k1 == k2,
+            (Item::Tool(ToolType::OilCan(_)), Item::Tool(ToolType::OilCan(_))) => true,
+            (Item::Tool(t1), Item::Tool(t2)) => t1 == t2,
+            (Item::PuzzleItem(p1), Item::PuzzleItem(p2)) => p1 == p2,
+            (Item::DoubleJumpItem, Item::DoubleJumpItem) => true,
+            (Item::DiaryPage(n1), Item::DiaryPage(n2)) => n1 == n2,
+            (Item::CandleStub(w1), Item::CandleStub(w2)) => w1 == w2,
+            _ => false,
+        }
+    }
+}
+
+/// Groups of items with their own share of the inventory.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ItemCategory {
+    /// Items used up on use (matches, candle stubs)
+    Consumable,
+    /// Door keys
+    Key,
+    /// Tools for interactions and puzzles
+    Tool,
+    /// Items required by specific puzzles
+    PuzzleItem,
+    /// Items that unlock abilities
+    Ability,
+    /// Diary pages and other readable items
+    Document,
+}
+
+impl ItemCategory {
+    /// Most slots the category may fill, if limited
+    pub fn slot_limit(self) -> Option<usize> {
+        match self {
+            ItemCategory::Key => Some(4),
+            ItemCategory::Tool => Some(4),
+            ItemCategory::PuzzleItem => Some(6),
+            _ => None,
+        }
+    }
+
+    /// Display name used in UI messages
+    pub fn name(self) -> &'static str {
+        match self {
+            ItemCategory::Consumable => "consumables",
+            ItemCategory::Key => "keys",
+            ItemCategory::Tool => "tools",
+            ItemCategory::PuzzleItem => "puzzle items",
+            ItemCategory::Ability => "ability items",
+            ItemCategory::Document => "documents",
+        }
+    }
 }
 
 /// Types of keys for unlocking different doors.
@@ -48,7 +280,7 @@ pub enum KeyType {
 }
 
 /// Types of tools used for various interactions and puzzles.
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
 pub enum ToolType {
     /// Wrench for mechanical puzzles
     Wrench,
@@ -58,10 +290,14 @@ pub enum ToolType {
     WireCutters,
     /// Magnet for attracting metal objects
     Magnet,
-    /// Oil can for lubricating mechanisms
-    OilCan,
+    /// Oil can for freeing stuck doors and levers, with its charges left
+    OilCan(u32),
     /// Ladder for reaching high places
     Ladder,
+    /// Gas mask for breathing in poison gas rooms
+    GasMask,
+    /// Grappling rope for swinging from anchor points
+    GrapplingRope,
 }
 
 /// Types of items specifically used in puzzles.
@@ -75,6 +311,55 @@ pub enum PuzzleItemType {
     CircuitComponent,
 }
 
+/// Number of hotbar slots (bound to the number keys 1-4)
+pub const HOTBAR_SLOTS: usize = 4;
+
+/// Component holding the items assigned to the player's hotbar.
+///
+/// Slots remember an item kind rather than a unit, so a match slot keeps
+/// working as matches are used up and picked up again. Only quick-usable
+/// items (see `Item::is_quick_usable`) can be assigned.
+#[derive(Component, Clone, Default)]
+pub struct Hotbar {
+    /// Item assigned to each slot
+    pub slots: [Option<Item>; HOTBAR_SLOTS],
+}
+
+impl Hotbar {
+    /// Assigns `item` to `slot`, moving it out of any other slot
+    ///
+    /// # Returns
+    /// false (leaving the hotbar unchanged) if `slot` is out of range or the
+    /// item isn't quick-usable
+    pub fn assign(&mut self, slot: usize, item: Item) -> bool {
+        if slot >= HOTBAR_SLOTS || !item.is_quick_usable() {
+            return false;
+        }
+        for assigned in &mut self.slots {
+            if assigned
+                .as_ref()
+                .is_some_and(|other| other.same_kind(&item))
+            {
+                *assigned = None;
+            }
+        }
+        self.slots[slot] = Some(item);
+        true
+    }
+
+    /// Empties `slot` (out of range slots are ignored)
+    pub fn clear(&mut self, slot: usize) {
+        if let Some(assigned) = self.slots.get_mut(slot) {
+            *assigned = None;
+        }
+    }
+
+    /// Item assigned to `slot`, if any
+    pub fn get(&self, slot: usize) -> Option<&Item> {
+        self.slots.get(slot).and_then(Option::as_ref)
+    }
+}
+
 /// Component indicating an item can stack (multiple of same item).
 ///
 /// Contains the current stack count. Used for items like matches
@@ -88,6 +373,16 @@ pub struct StackableItem(pub u32);
 #[derive(Component)]
 pub struct Collectible;
 
+/// Component delaying pickup of an item the player just placed.
+///
+/// Placed items spawn under the player, so they only become `Collectible`
+/// once this timer finishes (see `pickup_cooldown_system`).
+///
+/// # Fields
+/// * `0` - The Bevy Timer tracking the remaining delay
+#[derive(Component, Debug)]
+pub struct PickupCooldown(pub Timer);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -158,7 +453,7 @@ mod tests {
         let _crowbar = Item::Tool(ToolType::Crowbar);
         let _wire_cutters = Item::Tool(ToolType::WireCutters);
         let _magnet = Item::Tool(ToolType::Magnet);
-        let _oil_can = Item::Tool(ToolType::OilCan);
+        let _oil_can = Item::Tool(ToolType::OilCan(OIL_CAN_CHARGES));
         let _ladder = Item::Tool(ToolType::Ladder);
 
         let _fuse = Item::PuzzleItem(PuzzleItemType::Fuse);
@@ -192,4 +487,114 @@ mod tests {
         assert_eq!(inventory.items.len(), 3);
         assert_eq!(inventory.items.len(), inventory.max_capacity);
     }
+
+    #[test]
+    fn matches_stack_up_to_limit_per_slot() {
+        let mut inventory = Inventory {
+            items: vec![Item::Key(KeyType::Brass)],
+            max_capacity: 3,
+        };
+
+        for _ in 0..MAX_MATCH_STACK {
+            assert!(inventory.add(Item::Match).is_ok());
+        }
+        assert_eq!(inventory.count(&Item::Match), MAX_MATCH_STACK as usize);
+        assert_eq!(inventory.slots_used(), 2);
+
+        // The 100th match starts a second stack in the last free slot
+        assert!(inventory.add(Item::Match).is_ok());
+        assert_eq!(inventory.slots_used(), 3);
+
+        // Topping up the partial stack still fits, a new item doesn't
+        assert!(inventory.add(Item::Match).is_ok());
+        assert_eq!(
+            inventory.add(Item::Tool(ToolType::Wrench)),
+            Err(InventoryFullReason::NoFreeSlots)
+        );
+        assert_eq!(inventory.slots_used(), 3);
+    }
+
+    #[test]
+    fn category_limits_refuse_items_with_free_slots() {
+        let mut inventory = Inventory {
+            items: vec![],
+            max_capacity: 20,
+        };
+
+        let limit = ItemCategory::Tool.slot_limit().unwrap();
+        for _ in 0..limit {
+            assert!(inventory.add(Item::Tool(ToolType::Crowbar)).is_ok());
+        }
+        assert_eq!(
+            inventory.add(Item::Tool(ToolType::Ladder)),
+            Err(InventoryFullReason::CategoryFull(ItemCategory::Tool))
+        );
+        assert_eq!(inventory.items.len(), limit);
+
+        // Other categories are unaffected
+        assert!(inventory.add(Item::Key(KeyType::Iron)).is_ok());
+        assert!(inventory.add(Item::DiaryPage(1)).is_ok());
+    }
+
+    #[test]
+    fn merging_stubs_sums_wax_up_to_a_full_candle() {
+        let mut inventory = Inventory {
+            items: vec![
+                Item::CandleStub(30.0),
+                Item::Match,
+                Item::CandleStub(20.0),
+                Item::CandleStub(90.0),
+            ],
+            max_capacity: 10,
+        };
+
+        assert_eq!(inventory.merge_candle_stubs(0, 2), Some(50.0));
+        assert_eq!(inventory.items.len(), 3, "The emptied stub is removed");
+
+        // Wax that doesn't fit stays behind in the poured stub
+        assert_eq!(inventory.merge_candle_stubs(2, 0), Some(FULL_CANDLE_WAX));
+        assert!(matches!(inventory.items[0], Item::CandleStub(w) if w == 40.0));
+
+        assert_eq!(inventory.merge_candle_stubs(0, 1), None);
+        assert_eq!(inventory.merge_candle_stubs(0, 0), None);
+    }
+
+    #[test]
+    fn oil_charges_run_down_until_the_can_is_dropped() {
+        let mut inventory = Inventory {
+            items: vec![Item::Match, Item::Tool(ToolType::OilCan(2))],
+            max_capacity: 10,
+        };
+        assert!(Item::Tool(ToolType::OilCan(2)).same_kind(&Item::Tool(ToolType::OilCan(3))));
+
+        assert_eq!(inventory.use_oil_charge(), Some(1));
+        assert!(matches!(
+            inventory.items[1],
+            Item::Tool(ToolType::OilCan(1))
+        ));
+        assert_eq!(inventory.use_oil_charge(), Some(0));
+        assert_eq!(inventory.items.len(), 1, "The empty can is dropped");
+        assert_eq!(inventory.use_oil_charge(), None);
+    }
+
+    #[test]
+    fn hotbar_holds_each_usable_item_once() {
+        let mut hotbar = Hotbar::default();
+
+        assert!(hotbar.assign(0, Item::Match));
+        assert!(hotbar.assign(2, Item::Tool(ToolType::Crowbar)));
+        assert!(
+            !hotbar.assign(1, Item::Key(KeyType::Brass)),
+            "Keys aren't quick-usable"
+        );
+        assert!(!hotbar.assign(HOTBAR_SLOTS, Item::Match));
+
+        // Reassigning moves the item to its new slot
+        assert!(hotbar.assign(3, Item::Match));
+        assert!(hotbar.get(0).is_none());
+        assert!(matches!(hotbar.get(3), Some(Item::Match)));
+
+        hotbar.clear(2);
+        assert!(hotbar.get(2).is_none());
+    }
 }
@@ -7,9 +7,12 @@ use bevy::prelude::*;
 #[derive(Component)]
 pub struct Candle;
 
+/// Wax in a full candle
+pub const FULL_CANDLE_WAX: f32 = 100.0;
+
 /// Component storing the remaining wax in a candle.
 ///
-/// Value ranges from 0.0 (empty) to 100.0 (full). When wax reaches 0.0,
+/// Value ranges from 0.0 (empty) to `FULL_CANDLE_WAX` (100.0). When wax reaches 0.0,
 /// the candle automatically extinguishes.
 #[derive(Component)]
 pub struct CandleWax(pub f32);
@@ -58,6 +61,103 @@ pub struct LightSource {
     pub intensity: f32,
 }
 
+/// Marker component for light sources that never move or change (wall sconces).
+///
+/// Static lights need a `Transform`, `LightSource` and `VisibilityRadius`.
+/// Their contribution to the visibility map is baked once per room load
+/// instead of being recomputed every frame (see `light_bake`).
+#[derive(Component, Debug, Default)]
+pub struct StaticLight;
+
+/// Component marking a candle whose flame is sputtering after being hit by water.
+///
+/// While the timer runs, a lit candle's visibility radius is reduced
+/// (see `candle_burn_system`). Removed once the timer finishes.
+///
+/// # Fields
+/// * `0` - The Bevy Timer tracking how long the flame keeps sputtering
+#[derive(Component, Debug)]
+pub struct FlameSputter(pub Timer);
+
+/// Component marking a candle caught in a draft.
+///
+/// Drafts fan the flame, multiplying the candle's `BurnRate` while the
+/// component is present (see `candle_burn::burn_rate_breakdown`).
+///
+/// # Fields
+/// * `0` - Burn rate multiplier (e.g., 1.5 burns 50% faster)
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct Draft(pub f32);
+
+/// Component scaling how fast candles burn.
+///
+/// Modifiers stack: every one that applies multiplies the candle's
+/// `BurnRate` (see `candle_burn::BurnContext`). On a candle it applies to
+/// that candle; on a character (the player, or whoever holds a
+/// `CarriedCandle`) it applies to the candles they carry, so items and
+/// effects can add one to the player; with a `BurnZone` it applies to
+/// candles inside the zone.
+///
+/// # Fields
+/// * `0` - Burn rate multiplier (e.g., 1.5 burns 50% faster, 0.5 half as fast)
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct BurnModifier(pub f32);
+
+/// Component marking an area whose `BurnModifier` applies to the candles
+/// inside it, such as a windy hallway or a sheltered alcove.
+///
+/// The area is the entity's `Collider` around its `Transform`; without a
+/// `Collider` the zone covers the whole room. Level designers place zones
+/// as `"BurnZone"` entities.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq)]
+pub struct BurnZone;
+
+/// Default hand position of a carried candle relative to the player's center
+pub const CANDLE_CARRY_OFFSET: Vec2 = Vec2::new(12.0, 6.0);
+
+/// Component marking a candle held by a character.
+///
+/// Any entity with a `Transform` can carry a light: the player, an NPC
+/// survivor or a stalker lure. Each frame the candle is moved to its
+/// holder's position plus `offset`, mirrored horizontally to the side the
+/// holder is facing, and drawn just in front of the player layer (see
+/// `candle_carry_system`). Every lit carried candle adds its light to the
+/// visibility map.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct CarriedCandle {
+    /// Hand position relative to the holder's center when facing right
+    pub offset: Vec2,
+    /// Whether the holder last moved left
+    pub facing_left: bool,
+    /// Entity carrying the candle (`None` is the player)
+    pub holder: Option<Entity>,
+}
+
+impl CarriedCandle {
+    /// A candle carried by `holder` instead of the player
+    pub fn held_by(holder: Entity) -> Self {
+        Self {
+            holder: Some(holder),
+            ..default()
+        }
+    }
+
+    /// Returns true if the player carries this candle
+    pub fn is_players(&self) -> bool {
+        self.holder.is_none()
+    }
+}
+
+impl Default for CarriedCandle {
+    fn default() -> Self {
+        Self {
+            offset: CANDLE_CARRY_OFFSET,
+            facing_left: false,
+            holder: None,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
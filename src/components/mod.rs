@@ -3,6 +3,9 @@
 //! This module contains all the components used to define entity behavior
 //! in the house escape game. Components are organized by functionality.
 
+/// Enemy components such as the stalker
+pub mod enemy;
+
 /// Inventory management components for items and player storage
 pub mod inventory;
 
@@ -15,6 +18,9 @@ pub mod player;
 /// Puzzle components for circuit breakers, levers, and symbol matching
 pub mod puzzle;
 
+/// Render layer component for consistent sprite z-ordering
+pub mod render_layer;
+
 /// Room and door components for level navigation
 pub mod room;
 
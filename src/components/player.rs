@@ -1,10 +1,15 @@
+use crate::components::render_layer::{RenderLayer, SortOffset, YSort};
+use crate::resources::movement_tuning::DEFAULT_MAX_STAMINA;
 use bevy::prelude::*;
 
 /// Marker component for the player character.
 ///
 /// There should only be one player entity in the game world at any time.
-/// This component is used to identify and query the player entity.
+/// This component is used to identify and query the player entity. The
+/// player is drawn y-sorted on the `Player` render layer, sorting by their
+/// feet (see `render_layer::player_sort_offset_system`).
 #[derive(Component)]
+#[require(CharacterController, Stamina, RenderLayer = RenderLayer::Player, YSort, SortOffset)]
 pub struct Player;
 
 /// Component storing entity velocity in pixels per second.
@@ -14,6 +19,28 @@ pub struct Player;
 #[derive(Component)]
 pub struct Velocity(pub Vec2);
 
+/// Component for the player's kinematic character controller.
+///
+/// Movement records how far it moved the player each tick in `motion`, and
+/// the solid collision system sweeps the player's collider along it before
+/// clearing it, so a fast fall or a slow frame can't carry the player
+/// through a thin floor or wall. The contact flags say which sides of the
+/// player touched a solid after the last collision pass; landing, ledges
+/// and head bumps update `JumpState` from them.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq)]
+pub struct CharacterController {
+    /// Movement since the last collision pass, not yet checked against solids
+    pub motion: Vec2,
+    /// Standing on a solid
+    pub on_ground: bool,
+    /// Head against the underside of a solid
+    pub on_ceiling: bool,
+    /// Touching a solid on the left
+    pub on_wall_left: bool,
+    /// Touching a solid on the right
+    pub on_wall_right: bool,
+}
+
 /// Component tracking the player's current jump state.
 ///
 /// The jump state machine transitions:
@@ -44,7 +71,8 @@ pub struct DoubleJumpUnlocked;
 /// Component tracking the player's health status.
 ///
 /// Used to determine if the player is alive or dead. When set to `Dead`,
-/// the respawn system will trigger.
+/// the respawn system will trigger. Partial damage is tracked separately by
+/// `HitPoints`; the player dies when their hit points run out.
 #[derive(Component, Debug, PartialEq)]
 pub enum Health {
     /// Player is alive and can be controlled
@@ -53,6 +81,132 @@ pub enum Health {
     Dead,
 }
 
+/// Default maximum hit points for the player
+pub const DEFAULT_MAX_HIT_POINTS: f32 = 100.0;
+
+/// Seconds of invincibility granted after taking non-lethal damage
+pub const INVINCIBILITY_DURATION: f32 = 1.0;
+
+/// Component storing the player's hit points.
+///
+/// Weaker hazards (see `TrapDamage`) subtract hit points instead of killing
+/// outright. When `current` reaches 0.0 the player's `Health` becomes `Dead`.
+/// Hit points are restored on respawn and at save points.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct HitPoints {
+    /// Remaining hit points (0.0 to `max`)
+    pub current: f32,
+    /// Maximum hit points
+    pub max: f32,
+}
+
+impl HitPoints {
+    /// Creates full hit points with the given maximum
+    pub fn new(max: f32) -> Self {
+        Self { current: max, max }
+    }
+
+    /// Subtracts `amount` (clamped at 0.0) and returns true if depleted
+    pub fn apply_damage(&mut self, amount: f32) -> bool {
+        self.current = (self.current - amount.max(0.0)).max(0.0);
+        self.is_depleted()
+    }
+
+    /// Restores hit points to the maximum
+    pub fn restore(&mut self) {
+        self.current = self.max;
+    }
+
+    /// Returns true if no hit points remain
+    pub fn is_depleted(&self) -> bool {
+        self.current <= 0.0
+    }
+
+    /// Remaining hit points as a fraction of the maximum (0.0 to 1.0)
+    pub fn fraction(&self) -> f32 {
+        if self.max > 0.0 {
+            (self.current / self.max).clamp(0.0, 1.0)
+        } else {
+            0.0
+        }
+    }
+}
+
+impl Default for HitPoints {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_HIT_POINTS)
+    }
+}
+
+/// Component granting temporary immunity to non-lethal damage.
+///
+/// Added after the player takes partial damage so a single hazard contact
+/// doesn't drain hit points every frame. `InstantDeath` traps ignore it.
+///
+/// # Fields
+/// * `0` - The Bevy Timer tracking the remaining invincibility
+#[derive(Component, Debug)]
+pub struct Invincibility(pub Timer);
+
+impl Default for Invincibility {
+    fn default() -> Self {
+        Self(Timer::from_seconds(INVINCIBILITY_DURATION, TimerMode::Once))
+    }
+}
+
+/// Component tracking the player's sprint stamina.
+///
+/// Sprinting spends stamina and anything else recovers it (see
+/// `MovementTuning`). A player who runs dry is `exhausted` and can't sprint
+/// again until `MovementTuning::sprint_recovery` has come back.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct Stamina {
+    /// Remaining stamina (0.0 to `MovementTuning::max_stamina`)
+    pub current: f32,
+    /// Whether the player sprinted on the last movement tick
+    pub sprinting: bool,
+    /// Ran dry and hasn't recovered enough to sprint yet
+    pub exhausted: bool,
+}
+
+impl Stamina {
+    /// Spends `amount` of stamina, becoming exhausted on running dry
+    pub fn drain(&mut self, amount: f32) {
+        self.current = (self.current - amount.max(0.0)).max(0.0);
+        if self.current <= 0.0 {
+            self.exhausted = true;
+        }
+    }
+
+    /// Recovers `amount` of stamina up to `max`; exhaustion ends once
+    /// `recovery` is reached
+    pub fn regen(&mut self, amount: f32, max: f32, recovery: f32) {
+        self.current = (self.current + amount.max(0.0)).min(max);
+        if self.current >= recovery.min(max) {
+            self.exhausted = false;
+        }
+    }
+
+    /// Remaining stamina as a fraction of `max` (0.0 to 1.0)
+    pub fn fraction(&self, max: f32) -> f32 {
+        if max > 0.0 {
+            (self.current / max).clamp(0.0, 1.0)
+        } else {
+            0.0
+        }
+    }
+}
+
+impl Default for Stamina {
+    fn default() -> Self {
+        Self {
+            current: DEFAULT_MAX_STAMINA,
+            sprinting: false,
+            exhausted: false,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -102,4 +256,36 @@ mod tests {
         assert_eq!(Health::Alive, Health::Alive);
         assert_ne!(Health::Alive, Health::Dead);
     }
+
+    #[test]
+    fn hit_points_take_partial_damage() {
+        let mut hit_points = HitPoints::new(100.0);
+        assert!(!hit_points.apply_damage(30.0));
+        assert_eq!(hit_points.current, 70.0);
+        assert_eq!(hit_points.fraction(), 0.7);
+
+        assert!(hit_points.apply_damage(100.0));
+        assert_eq!(hit_points.current, 0.0);
+
+        hit_points.restore();
+        assert_eq!(hit_points.current, 100.0);
+    }
+
+    #[test]
+    fn stamina_exhausts_and_recovers() {
+        let mut stamina = Stamina::default();
+        stamina.drain(60.0);
+        assert!(!stamina.exhausted);
+        stamina.drain(60.0);
+        assert_eq!(stamina.current, 0.0);
+        assert!(stamina.exhausted);
+
+        stamina.regen(20.0, 100.0, 30.0);
+        assert!(stamina.exhausted, "Still catching breath below recovery");
+        stamina.regen(20.0, 100.0, 30.0);
+        assert!(!stamina.exhausted);
+
+        stamina.regen(500.0, 100.0, 30.0);
+        assert_eq!(stamina.fraction(100.0), 1.0);
+    }
 }
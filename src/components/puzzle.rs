@@ -70,6 +70,16 @@ pub struct PressurePlatePuzzle {
     pub required_items: Vec<Entity>,
 }
 
+/// Component for a plate held down by the player or a pushable block.
+///
+/// The plate's area is the entity's `Collider`. A `PressurePlatePuzzle` is
+/// solved once all of its plates are pressed at the same time.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq)]
+pub struct PressurePlate {
+    /// Whether something is weighing the plate down
+    pub pressed: bool,
+}
+
 /// Symbol types used in symbol matching puzzles.
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub enum Symbol {
@@ -93,8 +103,11 @@ pub struct SymbolMatchPuzzle {
     pub correct_sequence: Vec<Symbol>,
 }
 
-/// State of a lever in lever combination puzzles.
-#[derive(Clone, Copy, Debug, PartialEq)]
+/// Component holding the state of a lever in lever combination puzzles.
+///
+/// Lever entities carry this component; the player flips it with the
+/// interact action (see `lever_interaction_system`).
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
 pub enum LeverState {
     /// Lever is in up position
     Up,
@@ -102,6 +115,16 @@ pub enum LeverState {
     Down,
 }
 
+impl LeverState {
+    /// Returns the opposite lever position
+    pub fn flipped(self) -> Self {
+        match self {
+            LeverState::Up => LeverState::Down,
+            LeverState::Down => LeverState::Up,
+        }
+    }
+}
+
 /// Data for lever combination puzzle requiring correct lever positions.
 ///
 /// Player must set all levers to their correct up/down states.
@@ -112,6 +135,37 @@ pub struct LeverCombinationPuzzle {
     pub correct_states: Vec<LeverState>,
 }
 
+/// Type alias for house-wide puzzle identification.
+///
+/// Unlike entities, puzzle IDs stay the same when a room is despawned and
+/// spawned again, so the puzzle graph can refer to puzzles in other rooms.
+pub type PuzzleId = usize;
+
+/// Component naming a puzzle's node in the house-wide `PuzzleGraph`.
+///
+/// Needed on any puzzle that other puzzles depend on, and on any puzzle
+/// with a `PuzzleDependency`.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PuzzleNode(pub PuzzleId);
+
+/// Component listing the puzzles that must be solved before this one.
+///
+/// For example, a symbol panel with no power requires the circuit breaker
+/// puzzle. Until every prerequisite is solved the puzzle carries
+/// `PuzzleLocked` and ignores interaction.
+#[derive(Component, Clone, Debug, Default, PartialEq)]
+pub struct PuzzleDependency {
+    /// IDs of the prerequisite puzzles
+    pub requires: Vec<PuzzleId>,
+}
+
+/// Marker component for puzzles whose prerequisites are not solved yet.
+///
+/// Added and removed by the puzzle graph systems; level data never
+/// spawns it directly.
+#[derive(Component, Debug)]
+pub struct PuzzleLocked;
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -215,6 +269,12 @@ mod tests {
         assert_ne!(LeverState::Up, LeverState::Down);
     }
 
+    #[test]
+    fn lever_state_flips() {
+        assert_eq!(LeverState::Up.flipped(), LeverState::Down);
+        assert_eq!(LeverState::Down.flipped(), LeverState::Up);
+    }
+
     #[test]
     fn circuit_breaker_puzzle_structure() {
         let puzzle = CircuitBreakerPuzzle {
@@ -0,0 +1,132 @@
+use bevy::prelude::*;
+
+/// Component placing a sprite in one of the fixed drawing layers.
+///
+/// The render layer system writes the layer's z into the entity's
+/// `Transform`, so sprites never sort randomly above or below the player.
+/// Layers are spaced 10 apart; decals that must sit just above a layer
+/// (gravestones on the floor, a candle in the player's hand) add a small
+/// offset to the layer's z instead of picking an arbitrary value.
+///
+/// Level designers: every entity spawned from level data gets a layer
+/// from its entity type, and anything drawn above `CandleGlow` is not
+/// darkened by the lighting overlay.
+///
+/// Entities with `YSort` (the player and furniture) leave their layer's
+/// fixed z and share a narrow band around the `Player` layer instead, where
+/// the lower a sprite stands the nearer it is drawn (see `y_sort_z`). The
+/// band stays between the grappling rope behind the player and water in
+/// front of it.
+///
+/// | Layer        | z     | Contents                                      |
+/// |--------------|-------|-----------------------------------------------|
+/// | `Background` | 0     | Wallpaper and backdrops behind the room       |
+/// | `Tiles`      | 10    | Tilemap floors and walls                      |
+/// | `Items`      | 20    | Collectibles, traps, checkpoints, puzzle props|
+/// | `Player`     | 30    | The player and their carried candle           |
+/// | `CandleGlow` | 100   | Lighting/darkness overlay                     |
+/// | `Foreground` | 110   | Pillars, railings and other occluders         |
+/// | `Ui`         | 200   | World-space labels and prompts                |
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum RenderLayer {
+    /// Backdrops drawn behind everything else
+    Background,
+    /// Room tilemap
+    Tiles,
+    /// Items, traps and other props the player can touch
+    Items,
+    /// The player character
+    Player,
+    /// Candle light and darkness overlay
+    CandleGlow,
+    /// Scenery drawn in front of the player and the lighting
+    Foreground,
+    /// World-space UI drawn above the whole scene
+    Ui,
+}
+
+impl RenderLayer {
+    /// Returns the z coordinate sprites in this layer are drawn at
+    pub const fn z(self) -> f32 {
+        match self {
+            RenderLayer::Background => 0.0,
+            RenderLayer::Tiles => 10.0,
+            RenderLayer::Items => 20.0,
+            RenderLayer::Player => 30.0,
+            RenderLayer::CandleGlow => 100.0,
+            RenderLayer::Foreground => 110.0,
+            RenderLayer::Ui => 200.0,
+        }
+    }
+}
+
+/// Half the z span y-sorted sprites spread over around `RenderLayer::Player`
+pub const Y_SORT_DEPTH: f32 = 0.4;
+
+/// Z per pixel of height between y-sorted sprites (2000px either side of
+/// y = 0 before the band runs out)
+pub const Y_SORT_SCALE: f32 = 0.0002;
+
+/// Marker component drawing a sprite in y order with the player.
+///
+/// The render layer system places it at `y_sort_z` of its sort point (its
+/// y plus any `SortOffset`), so the player walks behind furniture standing
+/// below them and in front of furniture standing above.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct YSort;
+
+/// Component moving where a y-sorted sprite sorts from its origin.
+///
+/// Tall objects (wardrobes, bookcases) use a negative offset to their base,
+/// so they sort by where they stand rather than by their middle.
+///
+/// # Fields
+/// * `0` - Y offset (pixels) of the sort point from the transform
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq)]
+pub struct SortOffset(pub f32);
+
+/// Returns the z a y-sorted sprite with its sort point at `sort_y` is drawn at
+///
+/// Lower sprites get a higher z, clamped to `Y_SORT_DEPTH` either side of
+/// the `Player` layer.
+pub fn y_sort_z(sort_y: f32) -> f32 {
+    RenderLayer::Player.z() + (-sort_y * Y_SORT_SCALE).clamp(-Y_SORT_DEPTH, Y_SORT_DEPTH)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn layers_are_drawn_in_declaration_order() {
+        let layers = [
+            RenderLayer::Background,
+            RenderLayer::Tiles,
+            RenderLayer::Items,
+            RenderLayer::Player,
+            RenderLayer::CandleGlow,
+            RenderLayer::Foreground,
+            RenderLayer::Ui,
+        ];
+
+        for pair in layers.windows(2) {
+            assert!(pair[0] < pair[1]);
+            assert!(
+                pair[0].z() < pair[1].z(),
+                "{:?} below {:?}",
+                pair[0],
+                pair[1]
+            );
+        }
+    }
+
+    #[test]
+    fn lower_sprites_sort_in_front_within_the_band() {
+        assert!(y_sort_z(10.0) > y_sort_z(50.0));
+        for y in [-1e6, -100.0, 0.0, 100.0, 1e6] {
+            let z = y_sort_z(y);
+            assert!(z > RenderLayer::Items.z() && z < RenderLayer::CandleGlow.z());
+            assert!((z - RenderLayer::Player.z()).abs() <= Y_SORT_DEPTH);
+        }
+    }
+}
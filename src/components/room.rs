@@ -21,7 +21,7 @@ pub struct Room {
 }
 
 /// Floors in the house where rooms can be located.
-#[derive(Clone, Copy, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, serde::Deserialize, serde::Serialize)]
 pub enum Floor {
     /// Ground floor (main entrance level)
     Ground,
@@ -95,6 +95,35 @@ pub struct Collider {
     pub max: Vec2,
 }
 
+/// Marker component for colliders that block movement (walls, ledges, crates).
+///
+/// The player is pushed out of solids and can stand on them, and pushed
+/// blocks stop against them. Colliders without it (traps, items, trigger
+/// zones) only detect overlap.
+#[derive(Component, Debug, Default)]
+pub struct Solid;
+
+/// Default multiplier on the player's walking speed while pushing a block
+pub const DEFAULT_PUSH_SPEED_FACTOR: f32 = 0.5;
+
+/// Component for a block the player can push along the floor (e.g. a crate).
+///
+/// Pushable blocks should also be `Solid`, so the player can climb onto
+/// them to reach high ledges and blocks stop against each other.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct Pushable {
+    /// Multiplier on the player's walking speed while pushing
+    pub speed_factor: f32,
+}
+
+impl Default for Pushable {
+    fn default() -> Self {
+        Self {
+            speed_factor: DEFAULT_PUSH_SPEED_FACTOR,
+        }
+    }
+}
+
 /// Marker component for door entities.
 ///
 /// Doors can be locked, unlocked, or open and lead to other rooms.
@@ -124,11 +153,159 @@ pub enum DoorState {
 #[derive(Component)]
 pub struct TargetRoom(pub RoomId);
 
-/// Marker component indicating an entity can be interacted with by the player.
+/// Marker component for the door out of the house.
 ///
-/// Entities with this component respond to the interact action (F key).
-#[derive(Component)]
-pub struct Interactable;
+/// An exit door is a `Door` without a `TargetRoom`: once it is open,
+/// walking through it with a lit candle ends the game in victory.
+#[derive(Component, Debug, Default)]
+pub struct ExitDoor;
+
+/// Default distance (pixels) within which the player can interact with an entity
+pub const DEFAULT_INTERACT_RANGE: f32 = 48.0;
+
+/// What happens when the player interacts with an `Interactable`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InteractionKind {
+    /// Open (or unlock) a door
+    OpenDoor,
+    /// Pick up an item
+    Collect,
+    /// Open or operate a puzzle
+    TogglePuzzle,
+    /// Read a note or diary page
+    ReadNote,
+    /// Look closely at an object (plays its dialogue, see `DialogueTrigger`)
+    Examine,
+    /// Hide inside furniture, or come back out (see `HidingSpot`)
+    Hide,
+    /// Pry or smash an object open with a tool (see `Breakable`)
+    Break,
+    /// Cut a wire feeding an electrified hazard (see `LiveWire`)
+    CutWire,
+}
+
+impl InteractionKind {
+    /// Returns the prompt shown when no custom prompt is given
+    pub fn default_prompt(self) -> &'static str {
+        match self {
+            InteractionKind::OpenDoor => "Open door",
+            InteractionKind::Collect => "Pick up",
+            InteractionKind::TogglePuzzle => "Examine",
+            InteractionKind::ReadNote => "Read",
+            InteractionKind::Examine => "Look closer",
+            InteractionKind::Hide => "Hide",
+            InteractionKind::Break => "Pry open",
+            InteractionKind::CutWire => "Cut wire",
+        }
+    }
+}
+
+/// Component indicating an entity can be interacted with by the player.
+///
+/// When the player is within `range`, the nearest interactable's `prompt`
+/// is shown on the HUD, and the interact action (F key) emits the event
+/// for its `kind` (see `interaction_system`).
+#[derive(Component, Clone, Debug, PartialEq)]
+pub struct Interactable {
+    /// What interacting does
+    pub kind: InteractionKind,
+    /// Maximum distance (pixels) from the player
+    pub range: f32,
+    /// Action text shown on the HUD (e.g., "Open door")
+    pub prompt: String,
+}
+
+impl Interactable {
+    /// Creates an interactable with the default range and prompt for `kind`
+    pub fn new(kind: InteractionKind) -> Self {
+        Self {
+            kind,
+            range: DEFAULT_INTERACT_RANGE,
+            prompt: kind.default_prompt().to_string(),
+        }
+    }
+}
+
+/// Component for checkpoint (save-point) entities.
+///
+/// Touching a checkpoint moves the player's respawn location to it and
+/// triggers an auto-save. Only the most recently reached checkpoint is active.
+#[derive(Component, Debug, Default)]
+pub struct Checkpoint {
+    /// Whether this is the checkpoint the player will respawn at
+    pub activated: bool,
+}
+
+/// How a hidden passage is revealed.
+#[derive(Clone, Copy, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+pub enum PassageReveal {
+    /// Revealed only by solving a puzzle with a `PuzzleReward::RevealPassage`
+    /// reward for the passage's target room
+    PuzzleReward,
+    /// Revealed when the player carries a lit candle within `radius` pixels
+    CandleLight {
+        /// Distance (pixels) at which the candle light shows the passage
+        radius: f32,
+    },
+    /// Revealed after the player pushes against it for `seconds`
+    Push {
+        /// Seconds of continuous pushing needed
+        seconds: f32,
+    },
+}
+
+/// Component for a secret passage that looks like a wall until revealed.
+///
+/// Hidden passages need a `TargetRoom`. Once revealed they become doors
+/// (`Door`, `DoorState::Unlocked` and an `Interactable`) and the discovery is
+/// recorded in `GameState::collected_secrets` and `MapState` (see
+/// `secret_passage` systems). A `PuzzleReward::RevealPassage` for the target
+/// room reveals the passage whatever its `reveal` method.
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct HiddenPassage {
+    /// How the player can find the passage
+    pub reveal: PassageReveal,
+    /// Whether the passage has been found
+    pub revealed: bool,
+    /// Seconds the player has been pushing against the passage
+    pub push_progress: f32,
+}
+
+impl HiddenPassage {
+    /// Creates a passage that is still hidden
+    pub fn new(reveal: PassageReveal) -> Self {
+        Self {
+            reveal,
+            revealed: false,
+            push_progress: 0.0,
+        }
+    }
+}
+
+/// Component giving a level-spawned entity an ID that survives despawning.
+///
+/// Combines the room with the entity's index in that room's level data, so
+/// per-room state (see `RoomStateStore`) can be re-applied when the player
+/// returns to the room or loads a save.
+#[derive(
+    Component,
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    serde::Deserialize,
+    serde::Serialize,
+)]
+pub struct LevelEntityId {
+    /// Room the entity belongs to
+    pub room: RoomId,
+    /// Index of the entity in the room's level data
+    pub index: usize,
+}
 
 #[cfg(test)]
 mod tests {
@@ -270,7 +447,7 @@ mod tests {
                 Door,
                 DoorState::Locked(KeyType::Brass),
                 TargetRoom(1),
-                Interactable,
+                Interactable::new(InteractionKind::OpenDoor),
             ))
             .id();
 
@@ -341,15 +518,19 @@ mod tests {
     }
 
     #[test]
-    fn interactable_marker_component() {
+    fn interactable_component_defaults() {
         let mut app = App::new();
         app.add_plugins(MinimalPlugins);
 
-        // Interactable is just a marker - test it can be added
-        let entity = app.world_mut().spawn(Interactable).id();
+        let entity = app
+            .world_mut()
+            .spawn(Interactable::new(InteractionKind::ReadNote))
+            .id();
 
-        let interactable = app.world().get::<Interactable>(entity);
-        assert!(interactable.is_some());
+        let interactable = app.world().get::<Interactable>(entity).unwrap();
+        assert_eq!(interactable.kind, InteractionKind::ReadNote);
+        assert_eq!(interactable.range, DEFAULT_INTERACT_RANGE);
+        assert_eq!(interactable.prompt, "Read");
     }
 
     #[test]
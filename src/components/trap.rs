@@ -1,3 +1,4 @@
+use crate::components::inventory::ToolType;
 use bevy::prelude::*;
 
 /// Component defining the type of trap.
@@ -16,6 +17,22 @@ pub enum Trap {
     Pendulum,
     /// Wall-mounted arrow launcher
     ArrowTrap,
+    /// Large blade sweeping across a corridor (see `SwingingBlade`)
+    SwingingBlade,
+    /// Room filled with toxic gas (see `PoisonGas`)
+    PoisonGas,
+}
+
+impl Trap {
+    /// Returns true if the trap is spent after triggering and never re-arms
+    /// on its own (e.g., a chandelier that has already fallen).
+    ///
+    /// One-shot traps ignore `ResetTimer` and only return to `Armed` when the
+    /// room is reset (see `TrapResetPolicy`). Collapsing floors with a
+    /// `CrumblingFloor` component restore themselves on their own timer.
+    pub fn is_one_shot(&self) -> bool {
+        matches!(self, Trap::FallingChandelier | Trap::CollapsingFloor)
+    }
 }
 
 /// Component defining how a trap is activated.
@@ -48,6 +65,168 @@ pub enum TrapState {
     Resetting,
 }
 
+/// Component controlling how long a triggered trap takes to re-arm.
+///
+/// Re-armable traps (spikes, pressure plates) with this component go
+/// `Triggered` -> `Resetting` -> `Armed`, staying in `Resetting` until the
+/// timer finishes. Traps without it stay `Triggered` until the room is reset.
+///
+/// # Fields
+/// * `0` - The Bevy Timer tracking the reset countdown
+#[derive(Component, Debug)]
+pub struct ResetTimer(pub Timer);
+
+impl ResetTimer {
+    /// Creates a reset timer that re-arms the trap after `seconds`
+    pub fn from_seconds(seconds: f32) -> Self {
+        Self(Timer::from_seconds(seconds, TimerMode::Once))
+    }
+}
+
+/// Default fraction of a blade's swing cycle during which it is lethal
+pub const DEFAULT_BLADE_LETHAL_WINDOW: (f32, f32) = (0.4, 0.6);
+
+/// Component driving a swinging blade's periodic sweep.
+///
+/// The blade only kills while it passes through the player's level, i.e.
+/// while the cycle position is inside `lethal_window`. Players can time
+/// their crossing to slip through between sweeps.
+#[derive(Component, Debug)]
+pub struct SwingingBlade {
+    /// Full swing cycle (repeating)
+    pub cycle: Timer,
+    /// Fraction (start, end) of the cycle during which the blade is lethal
+    pub lethal_window: (f32, f32),
+}
+
+impl SwingingBlade {
+    /// Creates a blade that completes a sweep every `period_secs` seconds
+    pub fn new(period_secs: f32) -> Self {
+        Self {
+            cycle: Timer::from_seconds(period_secs, TimerMode::Repeating),
+            lethal_window: DEFAULT_BLADE_LETHAL_WINDOW,
+        }
+    }
+
+    /// Returns true if the blade is currently sweeping through the lethal zone
+    pub fn is_lethal(&self) -> bool {
+        let t = self.cycle.fraction();
+        t >= self.lethal_window.0 && t <= self.lethal_window.1
+    }
+}
+
+/// Component for floor tiles that crumble under the player.
+///
+/// Standing on the tile continuously for the crumble duration collapses it
+/// (`TrapState::Triggered`), dropping anyone on it. The tile is restored to
+/// `Armed` once the respawn timer finishes.
+#[derive(Component, Debug)]
+pub struct CrumblingFloor {
+    /// Continuous standing time before the floor collapses
+    pub crumble_timer: Timer,
+    /// Time before a collapsed floor is restored
+    pub respawn_timer: Timer,
+}
+
+impl CrumblingFloor {
+    /// Creates a floor that collapses after `crumble_secs` and returns after `respawn_secs`
+    pub fn new(crumble_secs: f32, respawn_secs: f32) -> Self {
+        Self {
+            crumble_timer: Timer::from_seconds(crumble_secs, TimerMode::Once),
+            respawn_timer: Timer::from_seconds(respawn_secs, TimerMode::Once),
+        }
+    }
+}
+
+/// Component for a poison gas zone.
+///
+/// Players inside the zone build up `GasExposure` and succumb once it
+/// reaches `tolerance`, unless they carry the `protection` tool.
+#[derive(Component, Debug)]
+pub struct PoisonGas {
+    /// Seconds of exposure a player can withstand
+    pub tolerance: f32,
+    /// Tool in the player's inventory that protects from the gas
+    pub protection: ToolType,
+}
+
+/// Component tracking how much poison gas a player has breathed (in seconds).
+///
+/// Added when a player first enters gas and removed once it has fully
+/// decayed after leaving the zone.
+#[derive(Component, Debug, Default, PartialEq)]
+pub struct GasExposure(pub f32);
+
+/// Gravity (pixels per second squared) pulling a released chandelier down
+pub const CHANDELIER_GRAVITY: f32 = 900.0;
+
+/// Default height (pixels) a chandelier hangs above the spot it lands on
+pub const DEFAULT_CHANDELIER_DROP: f32 = 160.0;
+
+/// Component for a chandelier that drops onto its trap area when triggered.
+///
+/// The trap's `Collider` is the spot it lands on. Triggering the trap only
+/// releases the chandelier; it crushes whoever is still underneath the
+/// moment it lands, so a quick player can dive clear.
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct ChandelierFall {
+    /// Height above the landing spot it hangs at
+    pub drop_height: f32,
+    /// Sideways speed (pixels per second) it swings down with
+    pub drift: f32,
+    /// Seconds since it was released, or `None` while it still hangs
+    pub falling: Option<f32>,
+    /// Whether it has hit the floor
+    pub landed: bool,
+}
+
+impl ChandelierFall {
+    /// Creates a hanging chandelier `drop_height` pixels above its landing spot
+    pub fn new(drop_height: f32) -> Self {
+        Self {
+            drop_height,
+            drift: 0.0,
+            falling: None,
+            landed: false,
+        }
+    }
+
+    /// Seconds the fall takes from release to landing
+    pub fn fall_duration(&self) -> f32 {
+        (2.0 * self.drop_height.max(0.0) / CHANDELIER_GRAVITY).sqrt()
+    }
+
+    /// Offset of the chandelier from its landing spot
+    ///
+    /// While falling it follows a ballistic arc: steady sideways drift and
+    /// a drop that accelerates under `CHANDELIER_GRAVITY`.
+    pub fn offset(&self) -> Vec2 {
+        if self.landed {
+            return Vec2::ZERO;
+        }
+        let duration = self.fall_duration();
+        let t = self.falling.unwrap_or(0.0).min(duration);
+        Vec2::new(
+            -self.drift * (duration - t),
+            self.drop_height - 0.5 * CHANDELIER_GRAVITY * t * t,
+        )
+    }
+
+    /// Hoists a fallen chandelier back up
+    pub fn rehang(&mut self) {
+        self.falling = None;
+        self.landed = false;
+    }
+}
+
+/// Component giving a trap partial (non-lethal) damage.
+///
+/// Traps with this component subtract `0` hit points from the player
+/// instead of killing outright, unless they also have `InstantDeath`.
+/// Traps without either component remain lethal.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct TrapDamage(pub f32);
+
 /// Marker component indicating a trap causes instant death on contact.
 ///
 /// When present, collision with this trap immediately sets player health to `Dead`.
@@ -72,6 +251,29 @@ pub enum EnvironmentalHazard {
     SteamVent,
 }
 
+/// Component for a ceiling spot that periodically drips water.
+///
+/// Each time `interval` finishes, a drop falls from the spot. A drop that
+/// lands on the player's lit candle makes it sizzle (brief visibility
+/// shrink) and may extinguish it, rewarding players who watch for drips.
+#[derive(Component, Debug)]
+pub struct WaterDrip {
+    /// Time between drops (repeating)
+    pub interval: Timer,
+    /// Chance (0.0 to 1.0) that a drop hitting the flame extinguishes the candle
+    pub extinguish_chance: f32,
+}
+
+impl WaterDrip {
+    /// Creates a drip that releases a drop every `interval_secs` seconds
+    pub fn new(interval_secs: f32, extinguish_chance: f32) -> Self {
+        Self {
+            interval: Timer::from_seconds(interval_secs, TimerMode::Repeating),
+            extinguish_chance: extinguish_chance.clamp(0.0, 1.0),
+        }
+    }
+}
+
 /// Component defining the gameplay effect of an environmental hazard.
 ///
 /// Applied to the player when they interact with the associated
@@ -129,6 +331,42 @@ mod tests {
         let _floor = Trap::CollapsingFloor;
         let _pendulum = Trap::Pendulum;
         let _arrow = Trap::ArrowTrap;
+        let _blade = Trap::SwingingBlade;
+        let _gas = Trap::PoisonGas;
+    }
+
+    #[test]
+    fn swinging_blade_lethal_only_in_window() {
+        let mut blade = SwingingBlade::new(2.0);
+        assert!(!blade.is_lethal(), "Blade starts outside the lethal window");
+
+        blade.cycle.tick(std::time::Duration::from_secs_f32(1.0));
+        assert!(blade.is_lethal(), "Blade is lethal mid-swing");
+
+        blade.cycle.tick(std::time::Duration::from_secs_f32(0.5));
+        assert!(!blade.is_lethal());
+    }
+
+    #[test]
+    fn chandelier_falls_along_an_arc() {
+        let mut chandelier = ChandelierFall {
+            drift: 40.0,
+            ..ChandelierFall::new(CHANDELIER_GRAVITY / 2.0)
+        };
+        assert_eq!(chandelier.fall_duration(), 1.0);
+        assert_eq!(
+            chandelier.offset(),
+            Vec2::new(-40.0, CHANDELIER_GRAVITY / 2.0)
+        );
+
+        // Gravity: a quarter of the drop in the first half of the fall
+        chandelier.falling = Some(0.5);
+        let offset = chandelier.offset();
+        assert_eq!(offset.x, -20.0);
+        assert_eq!(offset.y, CHANDELIER_GRAVITY * 3.0 / 8.0);
+
+        chandelier.falling = Some(1.0);
+        assert_eq!(chandelier.offset(), Vec2::ZERO);
     }
 
     #[test]
@@ -145,6 +383,15 @@ mod tests {
         assert_ne!(TrapState::Triggered, TrapState::Resetting);
     }
 
+    #[test]
+    fn one_shot_traps_identified() {
+        assert!(Trap::FallingChandelier.is_one_shot());
+        assert!(Trap::CollapsingFloor.is_one_shot());
+        assert!(!Trap::Spikes.is_one_shot());
+        assert!(!Trap::Pendulum.is_one_shot());
+        assert!(!Trap::ArrowTrap.is_one_shot());
+    }
+
     #[test]
     fn environmental_hazards_definable() {
         // Test all hazard variants
@@ -162,6 +409,13 @@ mod tests {
         let _fall = HazardEffect::FallDamage;
     }
 
+    #[test]
+    fn water_drip_clamps_extinguish_chance() {
+        let drip = WaterDrip::new(2.0, 1.5);
+        assert_eq!(drip.extinguish_chance, 1.0);
+        assert_eq!(drip.interval.mode(), TimerMode::Repeating);
+    }
+
     #[test]
     fn can_create_hazard_entity() {
         let mut app = App::new();
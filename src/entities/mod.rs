@@ -1,6 +1,7 @@
 //! Entity spawning and initialization utilities.
 //!
-//! This module will contain helper functions for spawning common game entities
-//! like players, candles, traps, and items with their full component bundles.
-//!
-//! Currently empty - entity spawning is handled directly in level loading and systems.
+//! This module contains the registry that maps level entity types to the
+//! functions spawning them with their full component bundles.
+
+/// Registry of spawners keyed by level entity type
+pub mod registry;
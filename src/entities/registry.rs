@@ -0,0 +1,192 @@
+use bevy::prelude::*;
+use std::collections::HashMap;
+use std::sync::LazyLock;
+
+use crate::systems::level_loader::{
+    EntitySpawn, spawn_breakable_entity, spawn_burn_zone_entity, spawn_checkpoint_entity,
+    spawn_crate_entity, spawn_dialogue_trigger_entity, spawn_electrified_floor_entity,
+    spawn_exit_door_entity, spawn_grapple_anchor_entity, spawn_hidden_passage_entity,
+    spawn_hiding_spot_entity, spawn_live_wire_entity, spawn_metal_object_entity, spawn_trap_entity,
+    spawn_trigger_zone_entity, spawn_water_region_entity,
+};
+
+/// Something that spawns one level entity type with its full bundle
+///
+/// Implemented for any `Fn(&mut Commands, &EntitySpawn) -> Option<Entity>`,
+/// so the `spawn_*_entity` functions of `level_loader` register directly.
+/// Spawners must be `Send + Sync`: large rooms are assembled across the
+/// compute task pool.
+pub trait EntitySpawner: Send + Sync {
+    /// Spawns the entity described by `spawn`
+    ///
+    /// # Returns
+    /// The spawned entity, or `None` if `spawn` is missing data it needs
+    fn spawn(&self, commands: &mut Commands, spawn: &EntitySpawn) -> Option<Entity>;
+}
+
+impl<F> EntitySpawner for F
+where
+    F: Fn(&mut Commands, &EntitySpawn) -> Option<Entity> + Send + Sync,
+{
+    fn spawn(&self, commands: &mut Commands, spawn: &EntitySpawn) -> Option<Entity> {
+        self(commands, spawn)
+    }
+}
+
+/// Resource mapping level entity types (e.g. `"Spikes"`, `"Checkpoint"`) to
+/// their spawners
+///
+/// The default registry holds every entity type the level format supports.
+/// New entity types are added by registering a spawner rather than editing
+/// the level loader; insert a customised registry as a resource and
+/// `spawn_level_entities_batched` uses it in place of the standard one.
+#[derive(Resource)]
+pub struct SpawnRegistry {
+    spawners: HashMap<String, Box<dyn EntitySpawner>>,
+}
+
+impl SpawnRegistry {
+    /// A registry with no spawners
+    pub fn empty() -> Self {
+        Self {
+            spawners: HashMap::new(),
+        }
+    }
+
+    /// The shared registry of the standard level entity types
+    pub fn standard() -> &'static SpawnRegistry {
+        static STANDARD: LazyLock<SpawnRegistry> = LazyLock::new(SpawnRegistry::default);
+        &STANDARD
+    }
+
+    /// Registers `spawner` for `entity_type`, replacing any previous one
+    pub fn register(
+        &mut self,
+        entity_type: impl Into<String>,
+        spawner: impl EntitySpawner + 'static,
+    ) -> &mut Self {
+        self.spawners.insert(entity_type.into(), Box::new(spawner));
+        self
+    }
+
+    /// Whether a spawner is registered for `entity_type`
+    pub fn contains(&self, entity_type: &str) -> bool {
+        self.spawners.contains_key(entity_type)
+    }
+
+    /// Spawns `spawn` with the spawner registered for its entity type
+    ///
+    /// # Returns
+    /// * `Some(Entity)` - The spawned entity
+    /// * `None` - If no spawner is registered for the type, or the spawner
+    ///   declined (e.g. a trigger without its config)
+    pub fn spawn(&self, commands: &mut Commands, spawn: &EntitySpawn) -> Option<Entity> {
+        self.spawners
+            .get(&spawn.entity_type)?
+            .spawn(commands, spawn)
+    }
+}
+
+impl Default for SpawnRegistry {
+    fn default() -> Self {
+        let mut registry = Self::empty();
+        let traps = [
+            "Spikes",
+            "SwingingBlade",
+            "CollapsingFloor",
+            "PoisonGas",
+            "FallingChandelier",
+        ];
+        for trap in traps {
+            registry.register(trap, spawn_trap_entity);
+        }
+        registry
+            .register("Checkpoint", spawn_checkpoint_entity)
+            .register("HiddenPassage", spawn_hidden_passage_entity)
+            .register("ExitDoor", spawn_exit_door_entity)
+            .register("DialogueTrigger", spawn_dialogue_trigger_entity)
+            .register("TriggerZone", spawn_trigger_zone_entity)
+            .register("BurnZone", spawn_burn_zone_entity)
+            .register("WaterRegion", spawn_water_region_entity)
+            .register("Crate", spawn_crate_entity)
+            .register("GrappleAnchor", spawn_grapple_anchor_entity)
+            .register("Wardrobe", spawn_hiding_spot_entity)
+            .register("Bed", spawn_hiding_spot_entity)
+            .register("BoardedDoor", spawn_breakable_entity)
+            .register("WeakWall", spawn_breakable_entity)
+            .register("MetalObject", spawn_metal_object_entity)
+            .register("ElectrifiedFloor", spawn_electrified_floor_entity)
+            .register("LiveWire", spawn_live_wire_entity);
+        registry
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::lighting::{Candle, CandleState};
+    use crate::components::trap::Trap;
+    use bevy::ecs::world::CommandQueue;
+
+    fn entity_spawn(entity_type: &str) -> EntitySpawn {
+        EntitySpawn {
+            entity_type: entity_type.to_string(),
+            position: (100.0, 50.0),
+            target_room: None,
+            locked: None,
+            key_type: None,
+            trap_config: None,
+            reveal: None,
+            dialogue: None,
+            trigger: None,
+            water: None,
+            breakable: None,
+            item: None,
+            electric: None,
+            stuck: false,
+            burn: None,
+        }
+    }
+
+    fn spawn_sconce(commands: &mut Commands, spawn: &EntitySpawn) -> Option<Entity> {
+        let (x, y) = spawn.position;
+        Some(
+            commands
+                .spawn((Candle, CandleState::Lit, Transform::from_xyz(x, y, 0.0)))
+                .id(),
+        )
+    }
+
+    #[test]
+    fn registered_spawners_handle_their_entity_types() {
+        let mut world = World::new();
+        let mut queue = CommandQueue::default();
+        let mut commands = Commands::new(&mut queue, &world);
+
+        let mut registry = SpawnRegistry::default();
+        registry.register("Sconce", spawn_sconce);
+
+        let spikes = registry
+            .spawn(&mut commands, &entity_spawn("Spikes"))
+            .unwrap();
+        let sconce = registry
+            .spawn(&mut commands, &entity_spawn("Sconce"))
+            .unwrap();
+        assert!(
+            registry
+                .spawn(&mut commands, &entity_spawn("Match"))
+                .is_none()
+        );
+        // Registered, but declines without its trigger config
+        assert!(
+            registry
+                .spawn(&mut commands, &entity_spawn("TriggerZone"))
+                .is_none()
+        );
+        queue.apply(&mut world);
+
+        assert!(matches!(world.get::<Trap>(spikes), Some(Trap::Spikes)));
+        assert_eq!(world.get::<CandleState>(sconce), Some(&CandleState::Lit));
+        assert!(!SpawnRegistry::standard().contains("Sconce"));
+    }
+}
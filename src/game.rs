@@ -0,0 +1,434 @@
+use bevy::app::PluginGroupBuilder;
+use bevy::asset::AssetMetaCheck;
+use bevy::asset::io::AssetSourceBuilder;
+use bevy::prelude::*;
+
+use crate::audio::music::MusicPlugin;
+use crate::audio::sound_events::SoundEventsPlugin;
+use crate::resources::asset_handles::AssetHandlesPlugin;
+use crate::resources::game_rules::GameRulesPlugin;
+use crate::resources::input_config::InputConfigPlugin;
+use crate::resources::input_context::InputContextPlugin;
+use crate::resources::profiles::ProfilesPlugin;
+use crate::systems::accessibility::AccessibilityPlugin;
+use crate::systems::attract_mode::AttractModePlugin;
+use crate::systems::breakable::BreakablePlugin;
+use crate::systems::candle_burn::CandleBurnPlugin;
+use crate::systems::cloud_sync::CloudSyncPlugin;
+use crate::systems::daily::DailyPlugin;
+use crate::systems::death_stats::DeathStatsPlugin;
+use crate::systems::debug_draw::DebugDrawPlugin;
+use crate::systems::director::DirectorPlugin;
+use crate::systems::electrical::ElectricalPlugin;
+use crate::systems::fixed_timestep::FixedTimestepPlugin;
+use crate::systems::game_over::GameOverPlugin;
+use crate::systems::game_set::GameSetPlugin;
+use crate::systems::grapple::GrapplePlugin;
+use crate::systems::hiding::HidingPlugin;
+use crate::systems::hotbar::HotbarPlugin;
+use crate::systems::interaction::InteractionPlugin;
+use crate::systems::lighting::LightingPlugin;
+use crate::systems::magnet::MagnetPlugin;
+use crate::systems::mods::{MODS_ASSET_SOURCE, MODS_DIR, ModsPlugin};
+use crate::systems::oil_can::OilCanPlugin;
+use crate::systems::player::PlayerPlugin;
+use crate::systems::procgen::ProcgenPlugin;
+use crate::systems::prompt_label::PromptLabelPlugin;
+use crate::systems::puzzle::PuzzlePlugin;
+use crate::systems::remix::RemixPlugin;
+use crate::systems::render_layer::RenderLayerPlugin;
+use crate::systems::room_state::RoomStatePlugin;
+use crate::systems::room_transition::RoomTransitionPlugin;
+use crate::systems::save_load::SaveLoadPlugin;
+use crate::systems::scripting::ScriptingPlugin;
+use crate::systems::secret_passage::SecretPassagePlugin;
+use crate::systems::sprite_animation::SpriteAnimationPlugin;
+use crate::systems::telemetry::TelemetryPlugin;
+use crate::systems::trap::TrapPlugin;
+use crate::systems::trap_visuals::TrapVisualsPlugin;
+use crate::systems::trigger_zone::TriggerZonePlugin;
+use crate::systems::victory::VictoryPlugin;
+use crate::systems::water::WaterPlugin;
+use crate::systems::water_drip::WaterDripPlugin;
+use crate::ui::UiPlugin;
+
+/// Window title shown by native builds and the browser tab
+pub const GAME_TITLE: &str = "House Escape";
+
+/// Window resolution of native builds
+pub const WINDOW_RESOLUTION: (f32, f32) = (1920.0, 1080.0);
+
+/// CSS selector of the `<canvas>` the browser build renders into
+pub const WEB_CANVAS_SELECTOR: &str = "#bevy";
+
+/// Plugin that sets up the engine for the build target
+///
+/// Adds Bevy's `DefaultPlugins` configured for either a native window or a
+/// browser canvas. `GamePlugin::default()` picks the configuration for the
+/// current target, so `main` is the same for both builds. Mod content loads
+/// from the `mods://` asset source (see `mods::ModsPlugin`).
+///
+/// # Browser builds
+/// Build with `cargo build --release --target wasm32-unknown-unknown`, then
+/// run `wasm-bindgen --target web` on the output and serve it next to an
+/// `index.html` containing `<canvas id="bevy">` and the `assets/` directory
+/// (the layout itch.io expects in an HTML5 upload zip). Saves go to
+/// localStorage (see `save_storage::LocalStorage`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct GamePlugin {
+    /// Canvas to render into (`None` opens a native window)
+    pub canvas: Option<String>,
+    /// Window resolution (browser builds resize to the canvas' parent instead)
+    pub resolution: (f32, f32),
+}
+
+impl GamePlugin {
+    /// Configuration for a native desktop window
+    pub fn native() -> Self {
+        Self {
+            canvas: None,
+            resolution: WINDOW_RESOLUTION,
+        }
+    }
+
+    /// Configuration for a browser canvas (wasm/itch.io builds)
+    pub fn web() -> Self {
+        Self {
+            canvas: Some(WEB_CANVAS_SELECTOR.to_string()),
+            resolution: WINDOW_RESOLUTION,
+        }
+    }
+
+    /// Returns true if this configuration renders into a browser canvas
+    pub fn is_web(&self) -> bool {
+        self.canvas.is_some()
+    }
+
+    /// Builds the primary window for this configuration
+    pub fn window(&self) -> Window {
+        Window {
+            title: GAME_TITLE.to_string(),
+            resolution: self.resolution.into(),
+            canvas: self.canvas.clone(),
+            fit_canvas_to_parent: self.is_web(),
+            // Keep browser shortcuts (F5, F12, ...) working around the canvas
+            prevent_default_event_handling: !self.is_web(),
+            ..default()
+        }
+    }
+
+    /// Builds the asset plugin for this configuration
+    ///
+    /// Browser builds skip looking for `.meta` files: static hosts like
+    /// itch.io answer those requests with an error page, which fails the load.
+    pub fn asset_plugin(&self) -> AssetPlugin {
+        AssetPlugin {
+            meta_check: if self.is_web() {
+                AssetMetaCheck::Never
+            } else {
+                AssetMetaCheck::default()
+            },
+            ..default()
+        }
+    }
+}
+
+impl Default for GamePlugin {
+    /// Configuration for the current build target
+    fn default() -> Self {
+        if cfg!(target_arch = "wasm32") {
+            Self::web()
+        } else {
+            Self::native()
+        }
+    }
+}
+
+impl Plugin for GamePlugin {
+    fn build(&self, app: &mut App) {
+        // Asset sources have to exist before the asset plugin is added
+        app.register_asset_source(
+            MODS_ASSET_SOURCE,
+            AssetSourceBuilder::platform_default(MODS_DIR, None),
+        );
+        app.add_plugins(
+            DefaultPlugins
+                .set(WindowPlugin {
+                    primary_window: Some(self.window()),
+                    ..default()
+                })
+                .set(self.asset_plugin()),
+        );
+    }
+}
+
+/// Every plugin of the game itself, added by `main` after `GamePlugin`
+///
+/// Schedule sets, input and audio come first, then `CloudSyncPlugin` so
+/// the plugins that load settings at build time (profiles, accessibility,
+/// telemetry, achievements) already read through the synced storage, then the
+/// gameplay features and finally saving and the UI. Plugins that other
+/// plugins add for themselves (`PlayerMovementPlugin`, `PuzzleUndoPlugin`,
+/// the UI's sub-plugins, ...) are left to them.
+pub struct GameplayPlugins;
+
+impl PluginGroup for GameplayPlugins {
+    fn build(self) -> PluginGroupBuilder {
+        PluginGroupBuilder::start::<Self>()
+            // Schedule, input, audio and content
+            .add(GameSetPlugin)
+            .add(FixedTimestepPlugin)
+            .add(InputConfigPlugin)
+            .add(InputContextPlugin)
+            .add(SoundEventsPlugin)
+            .add(MusicPlugin)
+            .add(DebugDrawPlugin)
+            .add(ModsPlugin)
+            .add(AssetHandlesPlugin)
+            .add(RenderLayerPlugin)
+            .add(SpriteAnimationPlugin)
+            .add(LightingPlugin)
+            .add(GameRulesPlugin)
+            .add(CloudSyncPlugin)
+            .add(ProfilesPlugin)
+            .add(AccessibilityPlugin)
+            .add(TelemetryPlugin)
+            // Gameplay, each plugin registering its own events and systems
+            .add(PlayerPlugin)
+            .add(PuzzlePlugin)
+            .add(TrapPlugin)
+            .add(TrapVisualsPlugin)
+            .add(CandleBurnPlugin)
+            .add(WaterDripPlugin)
+            .add(WaterPlugin)
+            .add(InteractionPlugin)
+            .add(PromptLabelPlugin)
+            .add(RoomTransitionPlugin)
+            .add(RoomStatePlugin)
+            .add(SecretPassagePlugin)
+            .add(TriggerZonePlugin)
+            .add(BreakablePlugin)
+            .add(ElectricalPlugin)
+            .add(GrapplePlugin)
+            .add(HidingPlugin)
+            .add(MagnetPlugin)
+            .add(OilCanPlugin)
+            .add(HotbarPlugin)
+            .add(DirectorPlugin)
+            .add(ScriptingPlugin)
+            .add(VictoryPlugin)
+            .add(GameOverPlugin)
+            .add(DeathStatsPlugin)
+            .add(AttractModePlugin)
+            .add(RemixPlugin)
+            .add(ProcgenPlugin)
+            .add(DailyPlugin)
+            // Saving and the UI read state every other plugin sets up
+            .add(SaveLoadPlugin)
+            .add(UiPlugin)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::render::RenderPlugin;
+    use bevy::render::settings::{RenderCreation, WgpuSettings};
+    use bevy::window::ExitCondition;
+    use bevy::winit::WinitPlugin;
+
+    use crate::systems::save_storage::{MemoryStorage, SaveBackend};
+    use crate::systems::schedule_export::short_name;
+
+    /// The game's plugins on an engine without window, event loop or GPU,
+    /// saving to memory
+    fn headless_game_app() -> App {
+        let mut app = App::new();
+        app.insert_resource(SaveBackend::new(MemoryStorage::default()));
+        app.register_asset_source(
+            MODS_ASSET_SOURCE,
+            AssetSourceBuilder::platform_default(MODS_DIR, None),
+        );
+        app.add_plugins((
+            DefaultPlugins
+                .build()
+                .disable::<WinitPlugin>()
+                .set(WindowPlugin {
+                    primary_window: None,
+                    exit_condition: ExitCondition::DontExit,
+                    ..default()
+                })
+                .set(RenderPlugin {
+                    render_creation: RenderCreation::Automatic(WgpuSettings {
+                        backends: None,
+                        ..default()
+                    }),
+                    ..default()
+                }),
+            GameplayPlugins,
+        ));
+        app.finish();
+        app.cleanup();
+        app
+    }
+
+    /// Short names of every system added to any of the app's schedules
+    fn scheduled_systems(app: &App) -> Vec<String> {
+        app.world()
+            .resource::<Schedules>()
+            .iter()
+            .flat_map(|(_, schedule)| {
+                schedule
+                    .graph()
+                    .systems()
+                    .map(|(_, system, _)| short_name(&system.name()))
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn gameplay_plugins_add_every_feature_to_the_app() {
+        let mut app = headless_game_app();
+
+        let plugins = [
+            (
+                "AccessibilityPlugin",
+                app.is_plugin_added::<AccessibilityPlugin>(),
+            ),
+            (
+                "AssetHandlesPlugin",
+                app.is_plugin_added::<AssetHandlesPlugin>(),
+            ),
+            (
+                "AttractModePlugin",
+                app.is_plugin_added::<AttractModePlugin>(),
+            ),
+            ("BreakablePlugin", app.is_plugin_added::<BreakablePlugin>()),
+            (
+                "CandleBurnPlugin",
+                app.is_plugin_added::<CandleBurnPlugin>(),
+            ),
+            (
+                "DeathStatsPlugin",
+                app.is_plugin_added::<DeathStatsPlugin>(),
+            ),
+            ("DirectorPlugin", app.is_plugin_added::<DirectorPlugin>()),
+            (
+                "ElectricalPlugin",
+                app.is_plugin_added::<ElectricalPlugin>(),
+            ),
+            ("GameOverPlugin", app.is_plugin_added::<GameOverPlugin>()),
+            ("GameRulesPlugin", app.is_plugin_added::<GameRulesPlugin>()),
+            ("GrapplePlugin", app.is_plugin_added::<GrapplePlugin>()),
+            ("HidingPlugin", app.is_plugin_added::<HidingPlugin>()),
+            ("HotbarPlugin", app.is_plugin_added::<HotbarPlugin>()),
+            (
+                "InputContextPlugin",
+                app.is_plugin_added::<InputContextPlugin>(),
+            ),
+            (
+                "InteractionPlugin",
+                app.is_plugin_added::<InteractionPlugin>(),
+            ),
+            ("LightingPlugin", app.is_plugin_added::<LightingPlugin>()),
+            ("MagnetPlugin", app.is_plugin_added::<MagnetPlugin>()),
+            ("MusicPlugin", app.is_plugin_added::<MusicPlugin>()),
+            ("OilCanPlugin", app.is_plugin_added::<OilCanPlugin>()),
+            ("ProfilesPlugin", app.is_plugin_added::<ProfilesPlugin>()),
+            (
+                "PromptLabelPlugin",
+                app.is_plugin_added::<PromptLabelPlugin>(),
+            ),
+            ("RoomStatePlugin", app.is_plugin_added::<RoomStatePlugin>()),
+            (
+                "RoomTransitionPlugin",
+                app.is_plugin_added::<RoomTransitionPlugin>(),
+            ),
+            (
+                "SecretPassagePlugin",
+                app.is_plugin_added::<SecretPassagePlugin>(),
+            ),
+            (
+                "SpriteAnimationPlugin",
+                app.is_plugin_added::<SpriteAnimationPlugin>(),
+            ),
+            (
+                "TrapVisualsPlugin",
+                app.is_plugin_added::<TrapVisualsPlugin>(),
+            ),
+            (
+                "TriggerZonePlugin",
+                app.is_plugin_added::<TriggerZonePlugin>(),
+            ),
+            ("WaterDripPlugin", app.is_plugin_added::<WaterDripPlugin>()),
+            ("WaterPlugin", app.is_plugin_added::<WaterPlugin>()),
+        ];
+        let missing: Vec<&str> = plugins
+            .iter()
+            .filter(|(_, added)| !added)
+            .map(|(name, _)| *name)
+            .collect();
+        assert!(
+            missing.is_empty(),
+            "plugins missing from the app: {:?}",
+            missing
+        );
+
+        let systems = scheduled_systems(&app);
+        for system in [
+            "candle_burn_system",
+            "water_drip_spawn_system",
+            "new_game_system",
+            "update_lighting_system",
+            "interaction_system",
+            "room_transition_system",
+            "trigger_zone_system",
+            "break_system",
+            "circuit_power_system",
+            "grapple_fire_system",
+            "hide_system",
+            "director_spawn_system",
+            "game_over_trigger_system",
+            "music_room_system",
+            "input_context_system",
+        ] {
+            assert!(
+                systems.iter().any(|name| name == system),
+                "{} is not scheduled",
+                system
+            );
+        }
+
+        // Every schedule builds: no ordering cycles between the plugins
+        app.world_mut()
+            .resource_scope(|world, mut schedules: Mut<Schedules>| {
+                for (label, schedule) in schedules.iter_mut() {
+                    if let Err(error) = schedule.initialize(world) {
+                        panic!("{:?} does not build: {}", label, error);
+                    }
+                }
+            });
+    }
+
+    #[test]
+    fn web_configuration_targets_canvas_without_meta_files() {
+        let web = GamePlugin::web();
+        let window = web.window();
+        assert_eq!(window.canvas.as_deref(), Some(WEB_CANVAS_SELECTOR));
+        assert!(window.fit_canvas_to_parent);
+        assert!(!window.prevent_default_event_handling);
+        assert!(matches!(
+            web.asset_plugin().meta_check,
+            AssetMetaCheck::Never
+        ));
+
+        let native = GamePlugin::native();
+        assert!(native.window().canvas.is_none());
+        assert_eq!(native.window().title, GAME_TITLE);
+        assert!(matches!(
+            native.asset_plugin().meta_check,
+            AssetMetaCheck::Always
+        ));
+    }
+}
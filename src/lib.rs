@@ -19,7 +19,9 @@
 //! - [`systems`] - Game logic systems for movement, collision, puzzles, etc.
 //! - [`audio`] - Sound event handling and audio playback
 //! - [`ui`] - HUD and user interface components
-//! - [`entities`] - Entity spawning and initialization (currently empty)
+//! - [`entities`] - Entity spawning registry used by level loading
+//! - [`game`] - `GamePlugin` engine setup for native and browser (wasm) builds and
+//!   the `GameplayPlugins` group of every game plugin
 //!
 //! ## Technology Stack
 //!
@@ -39,6 +41,9 @@ pub mod components;
 /// Entity spawning and initialization utilities
 pub mod entities;
 
+/// Engine setup for native and browser builds
+pub mod game;
+
 /// Global game state and configuration resources
 pub mod resources;
 
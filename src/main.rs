@@ -1,14 +1,26 @@
 use bevy::prelude::*;
+use rust_game::game::{GamePlugin, GameplayPlugins};
+use rust_game::systems::level_dump::{dump_level_arg, dump_level_json};
+use rust_game::systems::schedule_export::{ScheduleExportPlugin, dump_schedule_arg};
 
 fn main() {
-    App::new()
-        .add_plugins(DefaultPlugins.set(WindowPlugin {
-            primary_window: Some(Window {
-                title: "House Escape".to_string(),
-                resolution: (1920.0, 1080.0).into(),
-                ..default()
-            }),
-            ..default()
-        }))
-        .run();
+    // Tool mode: print a level as JSON and exit without starting the game
+    if let Some(path) = dump_level_arg(std::env::args()) {
+        match dump_level_json(&path) {
+            Ok(json) => println!("{}", json),
+            Err(e) => {
+                eprintln!("{}", e);
+                std::process::exit(1);
+            }
+        }
+        return;
+    }
+
+    let mut app = App::new();
+    app.add_plugins((GamePlugin::default(), GameplayPlugins));
+    // Debug mode: also write the system schedules to a DOT file at startup
+    if let Some(path) = dump_schedule_arg(std::env::args()) {
+        app.add_plugins(ScheduleExportPlugin { path });
+    }
+    app.run();
 }
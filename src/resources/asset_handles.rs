@@ -1,8 +1,30 @@
-use crate::components::inventory::KeyType;
+use crate::components::inventory::{Item, KeyType};
 use crate::components::trap::Trap;
+use crate::systems::mods::ModContent;
+use bevy::asset::LoadState;
 use bevy::prelude::*;
 use bevy_kira_audio::AudioSource;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::fs;
+
+/// Default location of the sprite manifest, relative to the working directory
+pub const SPRITE_MANIFEST_PATH: &str = "assets/sprites/manifest.ron";
+
+/// Plugin that loads the sprite handles listed in the sprite manifest
+///
+/// Sprite sheets get their atlas layouts at startup; the manifest's
+/// `packed` sprites are packed into one shared atlas once their images
+/// have loaded.
+pub struct AssetHandlesPlugin;
+
+impl Plugin for AssetHandlesPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<AssetHandles>()
+            .add_systems(Startup, load_sprite_handles_system)
+            .add_systems(Update, pack_sprite_atlas_system);
+    }
+}
 
 /// Resource storing handles to all loaded game assets.
 ///
@@ -17,12 +39,58 @@ pub struct AssetHandles {
     pub audio: HashMap<SoundType, Handle<AudioSource>>,
     /// Map of font types to their font handles
     pub fonts: HashMap<FontType, Handle<Font>>,
+    /// Sprite drawn for sprite types without their own image
+    pub placeholder: Handle<Image>,
+    /// Sprites drawn from a sprite sheet or the packed atlas
+    pub atlases: HashMap<SpriteType, AtlasSprite>,
+    /// Sprites waiting for their images to load to be packed into one atlas
+    pub pending_pack: Vec<SpriteType>,
+}
+
+/// Where a sprite's frames are found in a texture atlas
+#[derive(Debug, Clone, PartialEq)]
+pub struct AtlasSprite {
+    /// Atlas image
+    pub image: Handle<Image>,
+    /// Layout of the frames in `image`
+    pub layout: Handle<TextureAtlasLayout>,
+    /// Index of the sprite's first frame
+    pub index: usize,
+    /// Size of a frame in pixels
+    pub frame_size: UVec2,
+}
+
+impl AssetHandles {
+    /// Handle of `sprite`'s image, or the placeholder if it has none
+    pub fn sprite(&self, sprite: SpriteType) -> Handle<Image> {
+        self.sprites
+            .get(&sprite)
+            .unwrap_or(&self.placeholder)
+            .clone()
+    }
+
+    /// `Sprite` showing frame `frame` of `sprite`
+    ///
+    /// Sprites in an atlas draw the frame `frame` places after their first;
+    /// others draw their whole image (or the placeholder) whatever `frame`.
+    pub fn sprite_frame(&self, sprite: SpriteType, frame: usize) -> Sprite {
+        match self.atlases.get(&sprite) {
+            Some(atlas) => Sprite::from_atlas_image(
+                atlas.image.clone(),
+                TextureAtlas {
+                    layout: atlas.layout.clone(),
+                    index: atlas.index + frame,
+                },
+            ),
+            None => Sprite::from_image(self.sprite(sprite)),
+        }
+    }
 }
 
 /// Enum identifying different sprite assets in the game.
 ///
 /// Used as keys in the sprite handle map to retrieve specific textures.
-#[derive(Clone, Copy, Hash, PartialEq, Eq, Debug)]
+#[derive(Clone, Copy, Hash, PartialEq, Eq, Debug, Deserialize, Serialize)]
 pub enum SpriteType {
     /// Player character sprite
     Player,
@@ -34,12 +102,28 @@ pub enum SpriteType {
     Key(KeyType),
     /// Trap sprite (variant for each trap type)
     Trap(TrapType),
+    /// Door sprite
+    Door,
+    /// Floor and wall tileset
+    Tileset,
+}
+
+impl SpriteType {
+    /// Sprite drawn for `item` lying in the world, if it has one
+    pub fn for_item(item: &Item) -> Option<Self> {
+        match item {
+            Item::Match => Some(SpriteType::Match),
+            Item::Key(key) => Some(SpriteType::Key(*key)),
+            Item::CandleStub(_) => Some(SpriteType::Candle),
+            _ => None,
+        }
+    }
 }
 
 /// Enum identifying different trap sprite variants.
 ///
 /// Separate from `Trap` component to allow serialization and use as HashMap key.
-#[derive(Clone, Copy, Hash, PartialEq, Eq, Debug)]
+#[derive(Clone, Copy, Hash, PartialEq, Eq, Debug, Deserialize, Serialize)]
 pub enum TrapType {
     /// Spikes trap sprite
     Spikes,
@@ -51,6 +135,10 @@ pub enum TrapType {
     Pendulum,
     /// Arrow trap sprite
     ArrowTrap,
+    /// Swinging blade trap sprite
+    SwingingBlade,
+    /// Poison gas cloud sprite
+    PoisonGas,
 }
 
 impl From<Trap> for TrapType {
@@ -61,8 +149,221 @@ impl From<Trap> for TrapType {
             Trap::CollapsingFloor => TrapType::CollapsingFloor,
             Trap::Pendulum => TrapType::Pendulum,
             Trap::ArrowTrap => TrapType::ArrowTrap,
+            Trap::SwingingBlade => TrapType::SwingingBlade,
+            Trap::PoisonGas => TrapType::PoisonGas,
+        }
+    }
+}
+
+/// Image paths of the game's sprites, loaded from a RON manifest
+///
+/// Paths are relative to the `assets` directory, as for `AssetServer::load`.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct SpriteManifest {
+    /// Image drawn for sprite types the manifest has no entry for
+    pub placeholder: String,
+    /// Image of each sprite type
+    #[serde(default)]
+    pub sprites: HashMap<SpriteType, String>,
+    /// Grid layouts of the images that are sprite sheets
+    #[serde(default)]
+    pub sheets: HashMap<SpriteType, SheetLayout>,
+    /// Sprites packed into one shared atlas once loaded (fewer texture binds)
+    #[serde(default)]
+    pub packed: Vec<SpriteType>,
+}
+
+/// Grid of equally sized frames in a sprite sheet
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq)]
+pub struct SheetLayout {
+    /// Size (width, height) of a frame in pixels
+    pub frame_size: (u32, u32),
+    /// Frames per row
+    pub columns: u32,
+    /// Rows of frames
+    pub rows: u32,
+}
+
+impl Default for SpriteManifest {
+    /// The sprites shipped with the game
+    fn default() -> Self {
+        let mut sprites = HashMap::from([
+            (SpriteType::Player, "sprites/player.png".to_string()),
+            (SpriteType::Candle, "sprites/candle.png".to_string()),
+            (SpriteType::Match, "sprites/match.png".to_string()),
+            (SpriteType::Tileset, "sprites/tileset.png".to_string()),
+        ]);
+        let keys = [
+            KeyType::Brass,
+            KeyType::Iron,
+            KeyType::Ornate,
+            KeyType::Master,
+        ]
+        .map(SpriteType::Key);
+        for key in keys {
+            sprites.insert(key, "sprites/key.png".to_string());
+        }
+        Self {
+            placeholder: "sprites/player.png".to_string(),
+            sprites,
+            sheets: HashMap::from([(
+                SpriteType::Tileset,
+                SheetLayout {
+                    frame_size: (16, 16),
+                    columns: 2,
+                    rows: 1,
+                },
+            )]),
+            packed: [SpriteType::Candle, SpriteType::Match]
+                .into_iter()
+                .chain(keys)
+                .collect(),
+        }
+    }
+}
+
+/// Load the sprite manifest from a RON file
+///
+/// # Arguments
+/// * `path` - Path to the RON file (e.g., "assets/sprites/manifest.ron")
+///
+/// # Errors
+/// Returns error string if the file cannot be read or parsed
+pub fn load_sprite_manifest(path: &str) -> Result<SpriteManifest, String> {
+    let content = fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read sprite manifest '{}': {}", path, e))?;
+
+    ron::from_str(&content).map_err(|e| format!("Failed to parse RON from '{}': {}", path, e))
+}
+
+/// Startup system that loads the sprite handles listed in the sprite manifest
+///
+/// Falls back to the built-in manifest (`SpriteManifest::default`) if the
+/// file is missing or malformed. Sprites replaced by mods load from the mods.
+///
+/// # System Dependencies
+/// - **Resources**: Reads `AssetServer` and `ModContent` (when present),
+///   writes `AssetHandles` and `Assets<TextureAtlasLayout>`
+/// - **Downstream**: `pack_sprite_atlas_system` packs the `packed` sprites;
+///   `setup_tilemap` draws the tileset sheet
+pub fn load_sprite_handles_system(
+    asset_server: Res<AssetServer>,
+    mut handles: ResMut<AssetHandles>,
+    mut layouts: ResMut<Assets<TextureAtlasLayout>>,
+    mods: Option<Res<ModContent>>,
+) {
+    let mut manifest = match load_sprite_manifest(SPRITE_MANIFEST_PATH) {
+        Ok(manifest) => {
+            info!(
+                "Loaded sprite manifest ({} sprites)",
+                manifest.sprites.len()
+            );
+            manifest
+        }
+        Err(e) => {
+            warn!("{}. Using the built-in sprite manifest.", e);
+            SpriteManifest::default()
         }
+    };
+    if let Some(mods) = mods {
+        mods.merge_sprites(&mut manifest);
+    }
+
+    handles.placeholder = asset_server.load(manifest.placeholder);
+    for (sprite, path) in manifest.sprites {
+        handles.sprites.insert(sprite, asset_server.load(path));
     }
+    for (sprite, sheet) in manifest.sheets {
+        let frame_size = UVec2::from(sheet.frame_size);
+        let atlas = AtlasSprite {
+            image: handles.sprite(sprite),
+            layout: layouts.add(TextureAtlasLayout::from_grid(
+                frame_size,
+                sheet.columns,
+                sheet.rows,
+                None,
+                None,
+            )),
+            index: 0,
+            frame_size,
+        };
+        handles.atlases.insert(sprite, atlas);
+    }
+    handles.pending_pack = manifest.packed;
+}
+
+/// System that packs the `pending_pack` sprites into one shared atlas
+///
+/// Waits until every pending image has loaded (dropping any that failed),
+/// then copies them into a single atlas image so they draw with one texture
+/// bind. Sprites sharing an image share its place in the atlas.
+///
+/// # System Dependencies
+/// - **Resources**: Reads `AssetServer`; writes `AssetHandles`,
+///   `Assets<Image>` and `Assets<TextureAtlasLayout>`
+/// - **Upstream**: `load_sprite_handles_system` lists the sprites to pack
+pub fn pack_sprite_atlas_system(
+    asset_server: Res<AssetServer>,
+    mut handles: ResMut<AssetHandles>,
+    mut images: ResMut<Assets<Image>>,
+    mut layouts: ResMut<Assets<TextureAtlasLayout>>,
+) {
+    if handles.pending_pack.is_empty() {
+        return;
+    }
+
+    let pending: Vec<(SpriteType, AssetId<Image>)> = handles
+        .pending_pack
+        .iter()
+        .map(|sprite| (*sprite, handles.sprite(*sprite).id()))
+        .filter(|(_, id)| !matches!(asset_server.get_load_state(*id), Some(LoadState::Failed(_))))
+        .collect();
+    if pending.iter().any(|(_, id)| !images.contains(*id)) {
+        return;
+    }
+    handles.pending_pack.clear();
+
+    let mut added = Vec::new();
+    let packed = {
+        let mut builder = TextureAtlasBuilder::default();
+        for (_, id) in &pending {
+            if !added.contains(id)
+                && let Some(image) = images.get(*id)
+            {
+                builder.add_texture(Some(*id), image);
+                added.push(*id);
+            }
+        }
+        builder.build()
+    };
+    let (layout, sources, image) = match packed {
+        Ok(atlas) => atlas,
+        Err(e) => {
+            warn!(
+                "Failed to pack sprite atlas: {}. Sprites keep their own images.",
+                e
+            );
+            return;
+        }
+    };
+
+    let frames = layout.textures.clone();
+    let image = images.add(image);
+    let layout = layouts.add(layout);
+    for (sprite, id) in pending {
+        if let Some(index) = sources.texture_index(id) {
+            handles.atlases.insert(
+                sprite,
+                AtlasSprite {
+                    image: image.clone(),
+                    layout: layout.clone(),
+                    index,
+                    frame_size: frames[index].size(),
+                },
+            );
+        }
+    }
+    info!("Packed {} sprite images into one atlas", added.len());
 }
 
 /// Enum identifying different sound effect assets.
@@ -289,6 +590,104 @@ mod tests {
         app.update();
     }
 
+    #[test]
+    fn sprites_without_an_image_use_the_placeholder() {
+        let mut handles = AssetHandles {
+            placeholder: Handle::weak_from_u128(1),
+            ..Default::default()
+        };
+        handles
+            .sprites
+            .insert(SpriteType::Key(KeyType::Brass), Handle::weak_from_u128(2));
+
+        assert_eq!(
+            handles.sprite(SpriteType::Key(KeyType::Brass)),
+            Handle::weak_from_u128(2)
+        );
+        assert_eq!(handles.sprite(SpriteType::Door), handles.placeholder);
+        assert_eq!(
+            handles.sprite(SpriteType::Key(KeyType::Iron)),
+            handles.placeholder
+        );
+    }
+
+    #[test]
+    fn loaded_sprites_are_packed_into_one_atlas() {
+        use bevy::asset::RenderAssetUsages;
+        use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, AssetPlugin::default()));
+        app.init_asset::<Image>();
+        app.init_asset::<TextureAtlasLayout>();
+        app.init_resource::<AssetHandles>();
+        app.add_systems(Update, pack_sprite_atlas_system);
+
+        let image = |size: u32| {
+            Image::new_fill(
+                Extent3d {
+                    width: size,
+                    height: size,
+                    depth_or_array_layers: 1,
+                },
+                TextureDimension::D2,
+                &[255, 255, 255, 255],
+                TextureFormat::Rgba8UnormSrgb,
+                RenderAssetUsages::default(),
+            )
+        };
+        let mut images = app.world_mut().resource_mut::<Assets<Image>>();
+        let candle = images.add(image(16));
+        let key = images.add(image(12));
+        let mut handles = app.world_mut().resource_mut::<AssetHandles>();
+        handles.sprites.insert(SpriteType::Candle, candle);
+        for key_type in [KeyType::Brass, KeyType::Iron] {
+            handles
+                .sprites
+                .insert(SpriteType::Key(key_type), key.clone());
+        }
+        handles.pending_pack = vec![
+            SpriteType::Candle,
+            SpriteType::Key(KeyType::Brass),
+            SpriteType::Key(KeyType::Iron),
+        ];
+
+        app.update();
+
+        let handles = app.world().resource::<AssetHandles>();
+        assert!(handles.pending_pack.is_empty());
+        let candle = &handles.atlases[&SpriteType::Candle];
+        let brass = &handles.atlases[&SpriteType::Key(KeyType::Brass)];
+        let iron = &handles.atlases[&SpriteType::Key(KeyType::Iron)];
+        assert_eq!(candle.image, brass.image);
+        assert_ne!(candle.index, brass.index);
+        assert_eq!(brass, iron);
+        assert_eq!(candle.frame_size, UVec2::splat(16));
+        assert_eq!(brass.frame_size, UVec2::splat(12));
+
+        let sprite = handles.sprite_frame(SpriteType::Candle, 0);
+        assert_eq!(sprite.image, candle.image);
+        assert_eq!(sprite.texture_atlas.unwrap().index, candle.index);
+    }
+
+    #[test]
+    fn sprite_manifest_parses_and_its_images_exist() {
+        let manifest = load_sprite_manifest(SPRITE_MANIFEST_PATH).expect("manifest should parse");
+        assert_eq!(manifest, SpriteManifest::default());
+
+        for path in manifest
+            .sprites
+            .values()
+            .chain(std::iter::once(&manifest.placeholder))
+        {
+            assert!(
+                std::path::Path::new("assets").join(path).exists(),
+                "Sprite '{}' is missing",
+                path
+            );
+        }
+    }
+
     #[test]
     fn hash_map_key_equality() {
         use std::collections::HashSet;
@@ -0,0 +1,38 @@
+use bevy::prelude::*;
+
+/// Resource holding difficulty-dependent tuning values.
+///
+/// Systems read their tunables from here instead of hard-coding them so a
+/// difficulty preset can adjust them in one place. What the player picks at
+/// New Game (burn rate, hints, ...) lives in `GameRules` instead.
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct DifficultyConfig {
+    /// Half-angle (radians) of the aimed candle's light cone
+    pub candle_cone_half_angle: f32,
+    /// Aimed cone reach as a multiple of the candle's `VisibilityRadius`
+    pub candle_cone_range_multiplier: f32,
+    /// Being stranded in darkness with a burnt-out candle ends the run
+    pub darkness_game_over: bool,
+}
+
+impl Default for DifficultyConfig {
+    fn default() -> Self {
+        Self {
+            candle_cone_half_angle: 30f32.to_radians(),
+            candle_cone_range_multiplier: 1.75,
+            darkness_game_over: true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_cone_is_narrow_but_longer() {
+        let config = DifficultyConfig::default();
+        assert!(config.candle_cone_half_angle < std::f32::consts::FRAC_PI_2);
+        assert!(config.candle_cone_range_multiplier > 1.0);
+    }
+}
@@ -0,0 +1,157 @@
+use crate::resources::game_state::{GameMode, GameState};
+use bevy::prelude::*;
+use std::time::Duration;
+
+/// Plugin that advances `GameClock` once per frame
+///
+/// Runs in `PreUpdate`, after Bevy has updated `Time`, so every `Update`
+/// system sees the same frame delta. Also ticks the in-game run time
+/// (`GameState::completion_time`).
+pub struct GameClockPlugin;
+
+impl Plugin for GameClockPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<GameClock>().add_systems(
+            PreUpdate,
+            (game_clock_system, completion_time_system).chain(),
+        );
+    }
+}
+
+/// Resource holding game time that stands still while the game is paused
+///
+/// Animations (tweens, door swings, fades, toasts) read their frame delta
+/// from here instead of `Time`, so they freeze with the game and play at
+/// the same speed at any frame rate.
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq)]
+pub struct GameClock {
+    /// Seconds the clock advanced this frame (0.0 while paused)
+    pub delta: f32,
+    /// Seconds the clock has advanced in total
+    pub elapsed: f32,
+    /// Whether the clock is stopped
+    pub paused: bool,
+}
+
+impl GameClock {
+    /// Advances the clock by `seconds` unless it is paused
+    pub fn advance(&mut self, seconds: f32) {
+        self.delta = if self.paused { 0.0 } else { seconds.max(0.0) };
+        self.elapsed += self.delta;
+    }
+
+    /// This frame's delta as a `Duration`, for ticking `Timer`s
+    pub fn delta_duration(&self) -> Duration {
+        Duration::from_secs_f32(self.delta)
+    }
+}
+
+/// System that advances `GameClock` by the frame delta
+///
+/// # System Dependencies
+/// - **Resources**: Reads `Time` and `GameState` (when present), writes `GameClock`
+///
+/// # Behavior
+/// The clock stops while the game mode is `Paused`.
+pub fn game_clock_system(
+    time: Res<Time>,
+    game_state: Option<Res<GameState>>,
+    mut clock: ResMut<GameClock>,
+) {
+    clock.paused = game_state.is_some_and(|state| state.game_mode == GameMode::Paused);
+    clock.advance(time.delta_secs());
+}
+
+/// Formats a run time as "h:mm:ss" (or "m:ss" under an hour)
+///
+/// Used for `GameState::completion_time` on the HUD and the ending screen.
+pub fn format_run_time(time: Duration) -> String {
+    let secs = time.as_secs();
+    let (hours, minutes, seconds) = (secs / 3600, secs / 60 % 60, secs % 60);
+    if hours > 0 {
+        format!("{}:{:02}:{:02}", hours, minutes, seconds)
+    } else {
+        format!("{}:{:02}", minutes, seconds)
+    }
+}
+
+/// System that adds the frame's game time to `GameState::completion_time`
+///
+/// # System Dependencies
+/// - **Upstream**: `game_clock_system` advances `GameClock`
+/// - **Resources**: Reads `GameClock`, writes `GameState` (when present)
+///
+/// # Behavior
+/// Only time spent playing counts: menus, the pause menu, game over and
+/// the ending screen don't.
+pub fn completion_time_system(clock: Res<GameClock>, game_state: Option<ResMut<GameState>>) {
+    if let Some(mut game_state) = game_state
+        && game_state.game_mode == GameMode::Playing
+    {
+        game_state.completion_time += clock.delta_duration();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn paused_clock_stands_still() {
+        let mut clock = GameClock::default();
+        clock.advance(0.5);
+        assert_eq!((clock.delta, clock.elapsed), (0.5, 0.5));
+
+        clock.paused = true;
+        clock.advance(0.5);
+        assert_eq!((clock.delta, clock.elapsed), (0.0, 0.5));
+        assert_eq!(clock.delta_duration(), Duration::ZERO);
+    }
+
+    #[test]
+    fn clock_follows_game_mode() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(GameClockPlugin);
+        app.insert_resource(GameState {
+            game_mode: GameMode::Paused,
+            ..default()
+        });
+
+        app.update();
+        app.update();
+        let clock = *app.world().resource::<GameClock>();
+        assert!(clock.paused);
+        assert_eq!(clock.elapsed, 0.0);
+    }
+
+    #[test]
+    fn run_time_formats_with_and_without_hours() {
+        assert_eq!(format_run_time(Duration::from_secs(75)), "1:15");
+        assert_eq!(format_run_time(Duration::from_secs(3600 + 62)), "1:01:02");
+    }
+
+    #[test]
+    fn completion_time_only_counts_play() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.insert_resource(GameClock {
+            delta: 0.5,
+            ..default()
+        });
+        app.insert_resource(GameState {
+            game_mode: GameMode::Playing,
+            ..default()
+        });
+        app.add_systems(Update, completion_time_system);
+
+        app.update();
+        app.world_mut().resource_mut::<GameState>().game_mode = GameMode::Menu;
+        app.update();
+
+        assert_eq!(
+            app.world().resource::<GameState>().completion_time,
+            Duration::from_millis(500)
+        );
+    }
+}
@@ -0,0 +1,293 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::resources::game_state::{GameMode, GameState};
+use crate::resources::game_stats::GameStats;
+
+/// Most matches a player may carry in Survival
+pub const SURVIVAL_MAX_MATCHES: usize = 5;
+
+/// Most matches a player may carry in the Daily Escape
+pub const DAILY_MAX_MATCHES: usize = 3;
+
+/// Candle burn multiplier of the Daily Escape (wax burns 50% faster)
+pub const DAILY_BURN_MULTIPLIER: f32 = 1.5;
+
+/// Plugin that registers the game rules and starting a new game with them
+pub struct GameRulesPlugin;
+
+impl Plugin for GameRulesPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<GameRules>()
+            .add_event::<NewGameEvent>()
+            .add_systems(Update, new_game_system);
+    }
+}
+
+/// Rule set picked when starting a new game
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum GameRuleSet {
+    /// The standard game
+    #[default]
+    Normal,
+    /// Few matches, no manual saves and a single life
+    Survival,
+    /// Candles never burn down, for exploring the house at leisure
+    Explorer,
+    /// The house of the day, shared by every player (see `daily`): fewer
+    /// matches, faster wax burn and no manual saves
+    Daily,
+}
+
+impl GameRuleSet {
+    /// Display name used in menus
+    pub fn name(self) -> &'static str {
+        match self {
+            GameRuleSet::Normal => "Normal",
+            GameRuleSet::Survival => "Survival",
+            GameRuleSet::Explorer => "Explorer",
+            GameRuleSet::Daily => "Daily Escape",
+        }
+    }
+}
+
+/// Difficulty picked when starting a new game
+///
+/// Every difficulty-dependent multiplier lives here, so systems read them
+/// from `GameRules::difficulty` rather than keeping their own constants.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Difficulty {
+    /// Slower candles, more matches, lazier traps
+    Easy,
+    /// The standard tuning
+    #[default]
+    Normal,
+    /// Faster candles, fewer matches, quicker traps and no hints
+    Hard,
+}
+
+impl Difficulty {
+    /// Display name used in menus
+    pub fn name(self) -> &'static str {
+        match self {
+            Difficulty::Easy => "Easy",
+            Difficulty::Normal => "Normal",
+            Difficulty::Hard => "Hard",
+        }
+    }
+
+    /// Multiplier applied to every candle's `BurnRate`
+    pub fn burn_multiplier(self) -> f32 {
+        match self {
+            Difficulty::Easy => 0.75,
+            Difficulty::Normal => 1.0,
+            Difficulty::Hard => 1.25,
+        }
+    }
+
+    /// Multiplier applied to the matches in each match pickup
+    pub fn match_multiplier(self) -> f32 {
+        match self {
+            Difficulty::Easy => 1.5,
+            Difficulty::Normal => 1.0,
+            Difficulty::Hard => 0.5,
+        }
+    }
+
+    /// Multiplier applied to trap `ResetTimer` durations (above 1.0 traps
+    /// take longer to re-arm)
+    pub fn trap_rearm_multiplier(self) -> f32 {
+        match self {
+            Difficulty::Easy => 1.5,
+            Difficulty::Normal => 1.0,
+            Difficulty::Hard => 0.5,
+        }
+    }
+
+    /// Whether helper readouts (e.g. the light remaining estimate) are shown
+    pub fn hints(self) -> bool {
+        self != Difficulty::Hard
+    }
+
+    /// Matches in a pickup authored with `count` matches
+    ///
+    /// Never below one, so no pickup a puzzle relies on disappears.
+    pub fn match_count(self, count: u32) -> u32 {
+        ((count as f32 * self.match_multiplier()).round() as u32).max(1)
+    }
+}
+
+/// Resource holding the rules of the current game
+///
+/// Set from a `GameRuleSet` and `Difficulty` at New Game and restored from
+/// `SaveData` on load. Systems check the flags rather than the rule set, so
+/// a flag can be shared by several rule sets.
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct GameRules {
+    /// Rule set the flags come from
+    pub rule_set: GameRuleSet,
+    /// Lit candles burn wax (see `candle_burn_system`)
+    pub candle_depletion: bool,
+    /// Dying ends the run instead of respawning
+    pub permadeath: bool,
+    /// Saving to a slot from the save menu is allowed (auto-saves always are)
+    pub manual_saves: bool,
+    /// Most matches the player may carry, if limited
+    pub max_matches: Option<usize>,
+    /// Multiplier the rule set applies to every candle's `BurnRate`
+    pub candle_burn: f32,
+    /// Difficulty scaling candles, matches, traps and hints
+    pub difficulty: Difficulty,
+}
+
+impl GameRules {
+    /// Rules of `rule_set`
+    pub fn for_rule_set(rule_set: GameRuleSet) -> Self {
+        let normal = Self {
+            rule_set,
+            candle_depletion: true,
+            permadeath: false,
+            manual_saves: true,
+            max_matches: None,
+            candle_burn: 1.0,
+            difficulty: Difficulty::Normal,
+        };
+        match rule_set {
+            GameRuleSet::Normal => normal,
+            GameRuleSet::Survival => Self {
+                permadeath: true,
+                manual_saves: false,
+                max_matches: Some(SURVIVAL_MAX_MATCHES),
+                ..normal
+            },
+            GameRuleSet::Explorer => Self {
+                candle_depletion: false,
+                ..normal
+            },
+            GameRuleSet::Daily => Self {
+                manual_saves: false,
+                max_matches: Some(DAILY_MAX_MATCHES),
+                candle_burn: DAILY_BURN_MULTIPLIER,
+                ..normal
+            },
+        }
+    }
+
+    /// These rules played at `difficulty`
+    ///
+    /// The Daily Escape has fixed rules so results compare, and is always
+    /// played at Normal.
+    pub fn with_difficulty(self, difficulty: Difficulty) -> Self {
+        if self.rule_set == GameRuleSet::Daily {
+            return self;
+        }
+        Self { difficulty, ..self }
+    }
+
+    /// Multiplier applied to every candle's `BurnRate`: the rule set's
+    /// `candle_burn` scaled by the difficulty
+    pub fn burn_multiplier(&self) -> f32 {
+        self.candle_burn * self.difficulty.burn_multiplier()
+    }
+}
+
+impl Default for GameRules {
+    fn default() -> Self {
+        Self::for_rule_set(GameRuleSet::Normal)
+    }
+}
+
+/// Event starting a new game with the chosen rules
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NewGameEvent {
+    /// Rules the game is played with
+    pub rules: GameRuleSet,
+    /// Difficulty the game is played at
+    pub difficulty: Difficulty,
+}
+
+/// System that applies the chosen rules and starts playing on `NewGameEvent`
+///
+/// # System Dependencies
+/// - **Upstream**: The main menu emits `NewGameEvent`
+/// - **Resources**: Writes `GameRules` and `GameState`, and resets
+///   `GameStats` when present
+pub fn new_game_system(
+    mut events: EventReader<NewGameEvent>,
+    mut rules: ResMut<GameRules>,
+    mut game_state: ResMut<GameState>,
+    stats: Option<ResMut<GameStats>>,
+) {
+    let Some(event) = events.read().last() else {
+        return;
+    };
+
+    *rules = GameRules::for_rule_set(event.rules).with_difficulty(event.difficulty);
+    if let Some(mut stats) = stats {
+        *stats = GameStats::default();
+    }
+    game_state.game_mode = GameMode::Playing;
+    info!(
+        "Starting a new {} game on {}",
+        event.rules.name(),
+        rules.difficulty.name()
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rule_sets_flip_their_flags() {
+        let normal = GameRules::default();
+        assert!(normal.candle_depletion && normal.manual_saves && !normal.permadeath);
+
+        let survival = GameRules::for_rule_set(GameRuleSet::Survival);
+        assert!(survival.permadeath && !survival.manual_saves);
+        assert_eq!(survival.max_matches, Some(SURVIVAL_MAX_MATCHES));
+
+        let explorer = GameRules::for_rule_set(GameRuleSet::Explorer);
+        assert!(!explorer.candle_depletion && !explorer.permadeath);
+
+        let daily = GameRules::for_rule_set(GameRuleSet::Daily);
+        assert!(daily.candle_depletion && !daily.manual_saves && !daily.permadeath);
+        assert_eq!(daily.max_matches, Some(DAILY_MAX_MATCHES));
+        assert_eq!(daily.burn_multiplier(), DAILY_BURN_MULTIPLIER);
+        assert_eq!(
+            daily.with_difficulty(Difficulty::Easy).difficulty,
+            Difficulty::Normal
+        );
+    }
+
+    #[test]
+    fn difficulty_scales_match_pickups_without_emptying_them() {
+        assert_eq!(Difficulty::Easy.match_count(4), 6);
+        assert_eq!(Difficulty::Normal.match_count(4), 4);
+        assert_eq!(Difficulty::Hard.match_count(4), 2);
+        assert_eq!(Difficulty::Hard.match_count(1), 1);
+        assert!(Difficulty::Normal.hints() && !Difficulty::Hard.hints());
+    }
+
+    #[test]
+    fn new_game_applies_chosen_rules() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(GameRulesPlugin);
+        app.init_resource::<GameState>();
+
+        app.world_mut().send_event(NewGameEvent {
+            rules: GameRuleSet::Explorer,
+            difficulty: Difficulty::Hard,
+        });
+        app.update();
+
+        let rules = *app.world().resource::<GameRules>();
+        assert_eq!(rules.rule_set, GameRuleSet::Explorer);
+        assert_eq!(rules.difficulty, Difficulty::Hard);
+        assert_eq!(
+            app.world().resource::<GameState>().game_mode,
+            GameMode::Playing
+        );
+    }
+}
@@ -0,0 +1,216 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashSet};
+
+use crate::components::inventory::Item;
+use crate::components::lighting::{CandleState, CarriedCandle};
+use crate::components::player::Player;
+use crate::components::room::RoomId;
+use crate::components::trap::TrapState;
+use crate::resources::game_state::{GameMode, GameState};
+use crate::systems::inventory::ItemUsedEvent;
+use crate::systems::puzzle::PuzzleSolvedEvent;
+
+/// Farthest (pixels) the player can walk in one frame
+///
+/// Longer jumps are respawns, loads and room changes, which don't count as
+/// distance walked.
+pub const MAX_WALK_STEP: f32 = 64.0;
+
+/// Plugin that registers the gameplay statistics of the current run
+pub struct GameStatsPlugin;
+
+impl Plugin for GameStatsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<GameStats>()
+            .add_event::<ItemUsedEvent>()
+            .add_event::<PuzzleSolvedEvent>()
+            .add_systems(Update, (distance_walked_system, game_stats_system));
+    }
+}
+
+/// Resource accumulating gameplay statistics of the current run
+///
+/// Saved in `SaveData`, shown on the ending screen and in the pause menu's
+/// Stats tab. Reset when a new game or New Game+ run starts.
+#[derive(Resource, Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct GameStats {
+    /// Distance the player walked, in pixels
+    pub distance_walked: f32,
+    /// Matches struck
+    pub matches_used: u32,
+    /// Times the player's candle was lit
+    pub candles_relit: u32,
+    /// Times a trap went off
+    pub traps_triggered: u32,
+    /// Puzzles solved
+    pub puzzles_solved: u32,
+    /// Rooms the player has been in
+    pub rooms_visited: BTreeSet<RoomId>,
+}
+
+impl GameStats {
+    /// Label and value of each statistic, in display order
+    ///
+    /// Distance is shown in tiles (32 pixels).
+    pub fn rows(&self) -> Vec<(&'static str, String)> {
+        vec![
+            (
+                "Distance walked",
+                format!("{:.0} tiles", self.distance_walked / 32.0),
+            ),
+            ("Matches used", self.matches_used.to_string()),
+            ("Candles relit", self.candles_relit.to_string()),
+            ("Traps triggered", self.traps_triggered.to_string()),
+            ("Puzzles solved", self.puzzles_solved.to_string()),
+            ("Rooms visited", self.rooms_visited.len().to_string()),
+        ]
+    }
+}
+
+/// System that adds up the distance walked and the rooms visited
+///
+/// # System Dependencies
+/// - **Components**: Reads the player's `Transform`
+/// - **Resources**: Reads `GameState`, writes `GameStats`
+///
+/// # Behavior
+/// Only counts while playing. Steps longer than `MAX_WALK_STEP` (respawns,
+/// loads, room changes) are skipped.
+pub fn distance_walked_system(
+    game_state: Res<GameState>,
+    mut stats: ResMut<GameStats>,
+    mut last_position: Local<Option<Vec2>>,
+    player_query: Query<&Transform, With<Player>>,
+) {
+    if game_state.game_mode != GameMode::Playing {
+        return;
+    }
+    if !stats.rooms_visited.contains(&game_state.current_room) {
+        stats.rooms_visited.insert(game_state.current_room);
+    }
+
+    let Ok(transform) = player_query.single() else {
+        return;
+    };
+    let position = transform.translation.truncate();
+    if let Some(last) = last_position.replace(position) {
+        let step = last.distance(position);
+        if step > 0.0 && step <= MAX_WALK_STEP {
+            stats.distance_walked += step;
+        }
+    }
+}
+
+/// System that counts matches, candle lightings, traps and puzzles
+///
+/// # System Dependencies
+/// - **Upstream**: The UI and hotbar emit `ItemUsedEvent`;
+///   `puzzle_interaction_system` emits `PuzzleSolvedEvent`
+/// - **Components**: Reads `TrapState` and the player's carried `CandleState`
+/// - **Resources**: Writes `GameStats`
+///
+/// # Behavior
+/// A trap counts each time it becomes `Triggered` and the player's candle
+/// each time it becomes `Lit`; staying in that state doesn't count again.
+pub fn game_stats_system(
+    mut stats: ResMut<GameStats>,
+    mut item_events: EventReader<ItemUsedEvent>,
+    mut puzzle_events: EventReader<PuzzleSolvedEvent>,
+    mut triggered_traps: Local<HashSet<Entity>>,
+    mut lit_candles: Local<HashSet<Entity>>,
+    trap_query: Query<(Entity, &TrapState), Changed<TrapState>>,
+    candle_query: Query<(Entity, &CandleState, &CarriedCandle), Changed<CandleState>>,
+) {
+    for event in item_events.read() {
+        if matches!(event.item, Item::Match) {
+            stats.matches_used += 1;
+        }
+    }
+    for _ in puzzle_events.read() {
+        stats.puzzles_solved += 1;
+    }
+
+    for (entity, state) in &trap_query {
+        if *state != TrapState::Triggered {
+            triggered_traps.remove(&entity);
+        } else if triggered_traps.insert(entity) {
+            stats.traps_triggered += 1;
+        }
+    }
+    for (entity, state, carried) in &candle_query {
+        if !carried.is_players() {
+            continue;
+        }
+        if *state != CandleState::Lit {
+            lit_candles.remove(&entity);
+        } else if lit_candles.insert(entity) {
+            stats.candles_relit += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::lighting::Candle;
+
+    fn stats_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(GameStatsPlugin);
+        app.insert_resource(GameState {
+            game_mode: GameMode::Playing,
+            current_room: 3,
+            ..default()
+        });
+        app
+    }
+
+    #[test]
+    fn walking_counts_but_teleports_do_not() {
+        let mut app = stats_app();
+        let player = app.world_mut().spawn((Player, Transform::default())).id();
+        app.update();
+
+        for x in [10.0, 20.0, 500.0] {
+            app.world_mut()
+                .get_mut::<Transform>(player)
+                .unwrap()
+                .translation
+                .x = x;
+            app.update();
+        }
+
+        let stats = app.world().resource::<GameStats>();
+        assert_eq!(stats.distance_walked, 20.0);
+        assert_eq!(stats.rooms_visited, BTreeSet::from([3]));
+    }
+
+    #[test]
+    fn state_changes_count_once() {
+        let mut app = stats_app();
+        let trap = app.world_mut().spawn(TrapState::Armed).id();
+        let candle = app
+            .world_mut()
+            .spawn((Candle, CandleState::Unlit, CarriedCandle::default()))
+            .id();
+        app.update();
+
+        app.world_mut().send_event(ItemUsedEvent {
+            item: Item::Match,
+            player: Entity::PLACEHOLDER,
+        });
+        *app.world_mut().get_mut::<TrapState>(trap).unwrap() = TrapState::Triggered;
+        *app.world_mut().get_mut::<CandleState>(candle).unwrap() = CandleState::Lit;
+        app.update();
+        // Writing the same state again isn't a new trigger
+        *app.world_mut().get_mut::<TrapState>(trap).unwrap() = TrapState::Triggered;
+        app.update();
+
+        let stats = app.world().resource::<GameStats>();
+        assert_eq!(stats.matches_used, 1);
+        assert_eq!(stats.traps_triggered, 1);
+        assert_eq!(stats.candles_relit, 1);
+    }
+}
@@ -1,3 +1,4 @@
+use crate::components::inventory::HOTBAR_SLOTS;
 use bevy::prelude::*;
 use leafwing_input_manager::prelude::*;
 
@@ -16,12 +17,26 @@ pub enum PlayerAction {
     Jump,
     /// Climb ladder (W or Up Arrow)
     Climb,
+    /// Sprint while held, spending stamina (Left or Right Shift)
+    Sprint,
     /// Interact with objects (F)
     Interact,
     /// Toggle candle lit/unlit state (E)
     ToggleCandle,
+    /// Switch the candle between auto-light and mouse aiming (M)
+    ToggleAim,
     /// Use selected item (U)
     UseItem,
+    /// Undo the last puzzle input (Z)
+    Undo,
+    /// Use the item in hotbar slot 1 (1)
+    Hotbar1,
+    /// Use the item in hotbar slot 2 (2)
+    Hotbar2,
+    /// Use the item in hotbar slot 3 (3)
+    Hotbar3,
+    /// Use the item in hotbar slot 4 (4)
+    Hotbar4,
     /// Open inventory screen (I)
     OpenInventory,
     /// Open map overlay (Tab)
@@ -30,6 +45,14 @@ pub enum PlayerAction {
     Pause,
 }
 
+/// Hotbar actions in slot order
+pub const HOTBAR_ACTIONS: [PlayerAction; HOTBAR_SLOTS] = [
+    PlayerAction::Hotbar1,
+    PlayerAction::Hotbar2,
+    PlayerAction::Hotbar3,
+    PlayerAction::Hotbar4,
+];
+
 /// Plugin to register input actions and provide default input map.
 ///
 /// Adds the input manager plugin and registers the `PlayerAction` type
@@ -54,9 +77,13 @@ impl Plugin for InputConfigPlugin {
 /// - Movement: A/D or Arrow Keys
 /// - Jump: Space
 /// - Climb: W or Up Arrow
+/// - Sprint: Left or Right Shift
 /// - Interact: F
 /// - Toggle Candle: E
+/// - Toggle Candle Aim: M
 /// - Use Item: U
+/// - Undo Puzzle Input: Z
+/// - Hotbar slots: 1-4
 /// - Inventory: I
 /// - Map: Tab
 /// - Pause: Escape
@@ -73,11 +100,21 @@ pub fn default_input_map() -> InputMap<PlayerAction> {
     input_map.insert(PlayerAction::Jump, KeyCode::Space);
     input_map.insert(PlayerAction::Climb, KeyCode::KeyW);
     input_map.insert(PlayerAction::Climb, KeyCode::ArrowUp);
+    input_map.insert(PlayerAction::Sprint, KeyCode::ShiftLeft);
+    input_map.insert(PlayerAction::Sprint, KeyCode::ShiftRight);
 
     // Interactions
     input_map.insert(PlayerAction::Interact, KeyCode::KeyF);
     input_map.insert(PlayerAction::ToggleCandle, KeyCode::KeyE);
+    input_map.insert(PlayerAction::ToggleAim, KeyCode::KeyM);
     input_map.insert(PlayerAction::UseItem, KeyCode::KeyU);
+    input_map.insert(PlayerAction::Undo, KeyCode::KeyZ);
+
+    // Hotbar
+    input_map.insert(PlayerAction::Hotbar1, KeyCode::Digit1);
+    input_map.insert(PlayerAction::Hotbar2, KeyCode::Digit2);
+    input_map.insert(PlayerAction::Hotbar3, KeyCode::Digit3);
+    input_map.insert(PlayerAction::Hotbar4, KeyCode::Digit4);
 
     // UI
     input_map.insert(PlayerAction::OpenInventory, KeyCode::KeyI);
@@ -113,11 +150,19 @@ mod tests {
         // Verify jump and climb
         assert!(input_map.get(&PlayerAction::Jump).is_some());
         assert!(input_map.get(&PlayerAction::Climb).is_some());
+        assert!(input_map.get(&PlayerAction::Sprint).is_some());
 
         // Verify interactions
         assert!(input_map.get(&PlayerAction::Interact).is_some());
         assert!(input_map.get(&PlayerAction::ToggleCandle).is_some());
+        assert!(input_map.get(&PlayerAction::ToggleAim).is_some());
         assert!(input_map.get(&PlayerAction::UseItem).is_some());
+        assert!(input_map.get(&PlayerAction::Undo).is_some());
+
+        // Verify hotbar
+        for action in HOTBAR_ACTIONS {
+            assert!(input_map.get(&action).is_some());
+        }
 
         // Verify UI
         assert!(input_map.get(&PlayerAction::OpenInventory).is_some());
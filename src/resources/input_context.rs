@@ -0,0 +1,261 @@
+use bevy::prelude::*;
+
+use crate::resources::game_state::{GameMode, GameState};
+use crate::systems::attract_mode::AttractModeState;
+use crate::systems::dialogue::Dialogue;
+use crate::systems::room_transition::{FadePhase, RoomFade};
+use crate::ui::puzzle_panel::PuzzlePanel;
+
+/// Plugin that keeps the `InputContextStack` in step with the game
+pub struct InputContextPlugin;
+
+impl Plugin for InputContextPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<InputContextStack>()
+            .add_systems(PreUpdate, input_context_system);
+    }
+}
+
+/// What the player's input currently drives
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InputContext {
+    /// Walking around the house (the bottom of every stack)
+    Gameplay,
+    /// A puzzle panel is open
+    PuzzlePanel,
+    /// A dialogue box is showing
+    Dialogue,
+    /// The main menu or a menu-like screen (game over, ending)
+    Menu,
+    /// The pause menu
+    Paused,
+    /// Fading between rooms
+    Transition,
+    /// Non-interactive playback such as attract mode
+    Cutscene,
+    /// Hiding in furniture; the only way out is interacting again
+    Hiding,
+    /// The developer console is open and takes every key
+    Console,
+}
+
+/// Kind of input a system wants to act on
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InputPermission {
+    /// Walking and jumping
+    Move,
+    /// Using interactables (doors, items, puzzles, notes)
+    Interact,
+    /// Entering symbols and placing fuses in a puzzle panel
+    PuzzleInput,
+    /// Advancing dialogue and picking its choices
+    DialogueInput,
+    /// Navigating menus
+    MenuInput,
+}
+
+/// Returns true if `permission` is granted in `context`
+///
+/// | Context     | Move | Interact | PuzzleInput | DialogueInput | MenuInput |
+/// |-------------|------|----------|-------------|---------------|-----------|
+/// | Gameplay    | yes  | yes      |             |               |           |
+/// | PuzzlePanel |      |          | yes         |               |           |
+/// | Dialogue    |      |          |             | yes           |           |
+/// | Menu        |      |          |             |               | yes       |
+/// | Paused      |      |          |             |               | yes       |
+/// | Transition  |      |          |             |               |           |
+/// | Cutscene    |      |          |             |               |           |
+/// | Hiding      |      | yes      |             |               |           |
+/// | Console     |      |          |             |               |           |
+pub fn context_allows(context: InputContext, permission: InputPermission) -> bool {
+    use InputContext::*;
+    use InputPermission::*;
+
+    matches!(
+        (context, permission),
+        (Gameplay, Move | Interact)
+            | (Hiding, Interact)
+            | (PuzzlePanel, PuzzleInput)
+            | (Dialogue, DialogueInput)
+            | (Menu | Paused, MenuInput)
+    )
+}
+
+/// Resource stacking the contexts that claim the player's input
+///
+/// Only the topmost context receives input (see `context_allows`); with
+/// nothing pushed the player is in `InputContext::Gameplay`.
+/// `input_context_system` pushes and pops the contexts that follow game
+/// state; other systems may push their own (e.g. `Cutscene`) and must pop
+/// them when done.
+#[derive(Resource, Debug, Default, Clone, PartialEq)]
+pub struct InputContextStack {
+    /// Pushed contexts, bottom first
+    pub contexts: Vec<InputContext>,
+}
+
+impl InputContextStack {
+    /// Pushes `context` on top (no-op if it is already on the stack)
+    pub fn push(&mut self, context: InputContext) {
+        if !self.contexts.contains(&context) {
+            self.contexts.push(context);
+        }
+    }
+
+    /// Removes `context` wherever it is in the stack
+    pub fn pop(&mut self, context: InputContext) {
+        self.contexts.retain(|pushed| *pushed != context);
+    }
+
+    /// Pushes `context` if `active`, pops it otherwise
+    pub fn set(&mut self, context: InputContext, active: bool) {
+        if active {
+            self.push(context);
+        } else {
+            self.pop(context);
+        }
+    }
+
+    /// Context currently receiving input
+    pub fn top(&self) -> InputContext {
+        self.contexts
+            .last()
+            .copied()
+            .unwrap_or(InputContext::Gameplay)
+    }
+
+    /// Returns true if the top context grants `permission`
+    pub fn allows(&self, permission: InputPermission) -> bool {
+        context_allows(self.top(), permission)
+    }
+}
+
+/// System that pushes and pops the contexts that follow game state
+///
+/// # System Dependencies
+/// - **Resources**: Reads `GameState`, `RoomFade`, `PuzzlePanel`, `Dialogue`
+///   and `AttractModeState` (each when present); writes `InputContextStack`
+/// - **Downstream**: `interaction_system`, `player_movement_system` and
+///   `open_puzzle_panel_system` check the stack
+///
+/// # Behavior
+/// A context is pushed the frame its condition starts and popped when it
+/// ends, so the most recently started one is on top:
+/// - **Menu**: Game mode is Menu, GameOver or Victory
+/// - **Paused**: Game mode is Paused
+/// - **PuzzlePanel**: A puzzle panel is open
+/// - **Dialogue**: A dialogue is showing
+/// - **Transition**: A room fade is running
+/// - **Cutscene**: Attract mode playback is showing
+///
+/// `InputContext::Hiding` is pushed and popped by `hide_system` instead, and
+/// `InputContext::Console` by the developer console.
+pub fn input_context_system(
+    game_state: Option<Res<GameState>>,
+    fade: Option<Res<RoomFade>>,
+    panel: Option<Res<PuzzlePanel>>,
+    dialogue: Option<Res<Dialogue>>,
+    attract: Option<Res<AttractModeState>>,
+    mut stack: ResMut<InputContextStack>,
+) {
+    let mode = game_state.map(|state| state.game_mode);
+    stack.set(
+        InputContext::PuzzlePanel,
+        panel.is_some_and(|panel| panel.puzzle.is_some()),
+    );
+    stack.set(
+        InputContext::Dialogue,
+        dialogue.is_some_and(|dialogue| dialogue.active.is_some()),
+    );
+    stack.set(
+        InputContext::Menu,
+        matches!(
+            mode,
+            Some(GameMode::Menu | GameMode::GameOver | GameMode::Victory)
+        ),
+    );
+    stack.set(InputContext::Paused, mode == Some(GameMode::Paused));
+    stack.set(
+        InputContext::Transition,
+        fade.is_some_and(|fade| fade.phase != FadePhase::Idle),
+    );
+    stack.set(
+        InputContext::Cutscene,
+        attract.is_some_and(|attract| attract.active),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matrix_gates_each_context() {
+        use InputPermission::*;
+
+        assert!(context_allows(InputContext::Gameplay, Move));
+        assert!(context_allows(InputContext::Gameplay, Interact));
+        assert!(!context_allows(InputContext::Gameplay, MenuInput));
+        assert!(!context_allows(InputContext::PuzzlePanel, Interact));
+        assert!(context_allows(InputContext::PuzzlePanel, PuzzleInput));
+        assert!(context_allows(InputContext::Paused, MenuInput));
+        assert!(!context_allows(InputContext::Menu, Move));
+        assert!(context_allows(InputContext::Dialogue, DialogueInput));
+        assert!(!context_allows(InputContext::Dialogue, Interact));
+        assert!(!context_allows(InputContext::Hiding, Move));
+        assert!(context_allows(InputContext::Hiding, Interact));
+        for permission in [Move, Interact, PuzzleInput, DialogueInput, MenuInput] {
+            assert!(!context_allows(InputContext::Transition, permission));
+            assert!(!context_allows(InputContext::Cutscene, permission));
+        }
+    }
+
+    #[test]
+    fn latest_context_is_on_top_until_popped() {
+        let mut stack = InputContextStack::default();
+        assert_eq!(stack.top(), InputContext::Gameplay);
+
+        stack.push(InputContext::PuzzlePanel);
+        stack.push(InputContext::Paused);
+        stack.push(InputContext::PuzzlePanel);
+        assert_eq!(stack.top(), InputContext::Paused);
+
+        stack.pop(InputContext::Paused);
+        assert_eq!(stack.top(), InputContext::PuzzlePanel);
+        assert!(stack.allows(InputPermission::PuzzleInput));
+    }
+
+    #[test]
+    fn stack_follows_game_mode_and_puzzle_panel() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(InputContextPlugin);
+        app.insert_resource(GameState {
+            game_mode: GameMode::Playing,
+            ..default()
+        });
+        app.init_resource::<PuzzlePanel>();
+
+        app.update();
+        assert_eq!(
+            app.world().resource::<InputContextStack>().top(),
+            InputContext::Gameplay
+        );
+
+        app.world_mut().resource_mut::<PuzzlePanel>().puzzle = Some(Entity::PLACEHOLDER);
+        app.update();
+        app.world_mut().resource_mut::<GameState>().game_mode = GameMode::Paused;
+        app.update();
+        assert_eq!(
+            app.world().resource::<InputContextStack>().contexts,
+            vec![InputContext::PuzzlePanel, InputContext::Paused]
+        );
+
+        app.world_mut().resource_mut::<GameState>().game_mode = GameMode::Playing;
+        app.update();
+        assert_eq!(
+            app.world().resource::<InputContextStack>().top(),
+            InputContext::PuzzlePanel
+        );
+    }
+}
@@ -1,12 +1,66 @@
 use crate::components::room::RoomId;
+use crate::components::trap::Trap;
 use bevy::prelude::*;
 use std::collections::HashMap;
 
-/// Resource for tracking explored rooms and their layout data
+/// Resource for tracking explored rooms, their layout data, player deaths
+/// and discovered secret passages
 #[derive(Resource, Default)]
 pub struct MapState {
     /// Map of room IDs to their exploration status
     pub explored_rooms: HashMap<RoomId, ExploredStatus>,
+    /// Map of room IDs to the deaths that happened there
+    pub death_records: HashMap<RoomId, Vec<DeathRecord>>,
+    /// Map of room IDs to the (x, y) positions of secret passages found there
+    pub secret_passages: HashMap<RoomId, Vec<(f32, f32)>>,
+}
+
+/// What killed the player
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, serde::Deserialize, serde::Serialize)]
+pub enum DeathCause {
+    /// Floor spikes
+    Spikes,
+    /// Falling chandelier
+    FallingChandelier,
+    /// Collapsing or crumbling floor
+    CollapsingFloor,
+    /// Pendulum
+    Pendulum,
+    /// Arrow trap
+    ArrowTrap,
+    /// Swinging blade
+    SwingingBlade,
+    /// Poison gas exposure
+    PoisonGas,
+    /// Running out of air under water
+    Drowning,
+    /// Shock from an electrified floor or door
+    Electrocution,
+    /// Cause not known (e.g., scripted death)
+    Unknown,
+}
+
+impl From<&Trap> for DeathCause {
+    fn from(trap: &Trap) -> Self {
+        match trap {
+            Trap::Spikes => DeathCause::Spikes,
+            Trap::FallingChandelier => DeathCause::FallingChandelier,
+            Trap::CollapsingFloor => DeathCause::CollapsingFloor,
+            Trap::Pendulum => DeathCause::Pendulum,
+            Trap::ArrowTrap => DeathCause::ArrowTrap,
+            Trap::SwingingBlade => DeathCause::SwingingBlade,
+            Trap::PoisonGas => DeathCause::PoisonGas,
+        }
+    }
+}
+
+/// A single player death, recorded for statistics and the death heatmap
+#[derive(Clone, Copy, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct DeathRecord {
+    /// Player's XY coordinates when they died
+    pub position: (f32, f32),
+    /// What killed the player
+    pub cause: DeathCause,
 }
 
 /// Status of a room's exploration
@@ -77,9 +131,69 @@ impl MapState {
             .count()
     }
 
-    /// Clear all exploration data
+    /// Record a player death in a room
+    pub fn record_death(&mut self, room_id: RoomId, position: Vec2, cause: DeathCause) {
+        self.death_records
+            .entry(room_id)
+            .or_default()
+            .push(DeathRecord {
+                position: (position.x, position.y),
+                cause,
+            });
+    }
+
+    /// Get the deaths recorded in a room
+    pub fn deaths_in_room(&self, room_id: RoomId) -> &[DeathRecord] {
+        self.death_records
+            .get(&room_id)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Get the number of deaths in a room grouped by cause
+    pub fn death_causes_in_room(&self, room_id: RoomId) -> HashMap<DeathCause, usize> {
+        let mut causes = HashMap::new();
+        for record in self.deaths_in_room(room_id) {
+            *causes.entry(record.cause).or_insert(0) += 1;
+        }
+        causes
+    }
+
+    /// Record a secret passage discovered in a room
+    ///
+    /// # Returns
+    /// `true` if the passage wasn't recorded before
+    pub fn record_secret(&mut self, room_id: RoomId, position: Vec2) -> bool {
+        if self.has_secret_at(room_id, position) {
+            return false;
+        }
+        self.secret_passages
+            .entry(room_id)
+            .or_default()
+            .push((position.x, position.y));
+        true
+    }
+
+    /// Check if a secret passage at `position` in a room has been discovered
+    pub fn has_secret_at(&self, room_id: RoomId, position: Vec2) -> bool {
+        self.secrets_in_room(room_id)
+            .iter()
+            .any(|(x, y)| Vec2::new(*x, *y).distance(position) < 1.0)
+    }
+
+    /// Get the positions of the secret passages discovered in a room
+    pub fn secrets_in_room(&self, room_id: RoomId) -> &[(f32, f32)] {
+        self.secret_passages
+            .get(&room_id)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Clear all exploration, death and secret data
     pub fn clear(&mut self) {
         self.explored_rooms.clear();
+        self.death_records.clear();
+        self.secret_passages.clear();
     }
 }
 
@@ -262,4 +376,37 @@ mod tests {
         // Now room should be marked as visited
         assert!(map_state.is_visited(0));
     }
+
+    #[test]
+    fn records_deaths_per_room() {
+        let mut map_state = MapState::default();
+
+        map_state.record_death(0, Vec2::new(10.0, 20.0), DeathCause::Spikes);
+        map_state.record_death(0, Vec2::new(12.0, 20.0), DeathCause::Spikes);
+        map_state.record_death(1, Vec2::new(5.0, 5.0), DeathCause::PoisonGas);
+
+        assert_eq!(map_state.deaths_in_room(0).len(), 2);
+        assert_eq!(map_state.deaths_in_room(1)[0].position, (5.0, 5.0));
+        assert!(map_state.deaths_in_room(2).is_empty());
+        assert_eq!(map_state.death_causes_in_room(0)[&DeathCause::Spikes], 2);
+
+        map_state.clear();
+        assert!(map_state.deaths_in_room(0).is_empty());
+    }
+
+    #[test]
+    fn records_each_secret_passage_once() {
+        let mut map_state = MapState::default();
+
+        assert!(map_state.record_secret(2, Vec2::new(64.0, 32.0)));
+        assert!(!map_state.record_secret(2, Vec2::new(64.0, 32.0)));
+        assert!(map_state.record_secret(2, Vec2::new(128.0, 32.0)));
+
+        assert_eq!(map_state.secrets_in_room(2).len(), 2);
+        assert!(map_state.has_secret_at(2, Vec2::new(64.0, 32.0)));
+        assert!(!map_state.has_secret_at(3, Vec2::new(64.0, 32.0)));
+
+        map_state.clear();
+        assert!(map_state.secrets_in_room(2).is_empty());
+    }
 }
@@ -7,11 +7,41 @@
 /// Asset handle management for sprites, audio, and fonts
 pub mod asset_handles;
 
+/// Difficulty-dependent tuning values
+pub mod difficulty;
+
+/// Pausable game clock that drives animations
+pub mod game_clock;
+
+/// Normal, Survival and Explorer rule sets chosen at New Game
+pub mod game_rules;
+
+/// Seeded random stream all gameplay randomness draws from
+pub mod game_rng;
+
+/// Gameplay statistics of the current run (distance, matches, traps, puzzles)
+pub mod game_stats;
+
 /// Global game state including current room, player status, and game mode
 pub mod game_state;
 
 /// Input action mapping and configuration
 pub mod input_config;
 
+/// Input context stack and the per-context input permission matrix
+pub mod input_context;
+
 /// Map exploration tracking and room layout data
 pub mod map_state;
+
+/// Walking speed, sprint and stamina tuning values
+pub mod movement_tuning;
+
+/// Player profiles, persisted to save storage
+pub mod profiles;
+
+/// House-wide puzzle prerequisite graph
+pub mod puzzle_graph;
+
+/// Per-room entity state kept while rooms are unloaded
+pub mod room_state;
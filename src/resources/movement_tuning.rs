@@ -0,0 +1,58 @@
+use bevy::prelude::*;
+
+/// Default maximum stamina of the player
+pub const DEFAULT_MAX_STAMINA: f32 = 100.0;
+
+/// Resource holding the player's movement and sprint tuning values.
+///
+/// `player_movement_system` reads walking speed and the sprint stamina
+/// budget from here instead of hard-coding them.
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct MovementTuning {
+    /// Horizontal walking speed in pixels per second
+    pub walk_speed: f32,
+    /// Walking speed multiplier while sprinting
+    pub sprint_multiplier: f32,
+    /// Stamina of a fully rested player
+    pub max_stamina: f32,
+    /// Stamina spent per second of sprinting
+    pub stamina_drain: f32,
+    /// Stamina recovered per second while not sprinting
+    pub stamina_regen: f32,
+    /// Stamina an exhausted player must recover before sprinting again
+    pub sprint_recovery: f32,
+}
+
+impl MovementTuning {
+    /// Horizontal sprinting speed in pixels per second
+    pub fn sprint_speed(&self) -> f32 {
+        self.walk_speed * self.sprint_multiplier
+    }
+}
+
+impl Default for MovementTuning {
+    fn default() -> Self {
+        Self {
+            walk_speed: 200.0,
+            sprint_multiplier: 1.6,
+            max_stamina: DEFAULT_MAX_STAMINA,
+            stamina_drain: 25.0,
+            stamina_regen: 15.0,
+            sprint_recovery: 30.0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sprinting_is_faster_than_walking_and_outlasts_a_room() {
+        let tuning = MovementTuning::default();
+        assert!(tuning.sprint_speed() > tuning.walk_speed);
+        // A full bar covers several seconds of sprinting
+        assert!(tuning.max_stamina / tuning.stamina_drain >= 3.0);
+        assert!(tuning.sprint_recovery < tuning.max_stamina);
+    }
+}
@@ -0,0 +1,131 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::systems::save_storage::{PROFILES_FILE_NAME, SaveBackend, SaveStorage};
+use crate::ui::text_input::NameError;
+
+/// Plugin that loads the player profiles from the `SaveBackend` and saves
+/// them when they change
+pub struct ProfilesPlugin;
+
+impl Plugin for ProfilesPlugin {
+    fn build(&self, app: &mut App) {
+        let profiles = Profiles::load(&*app.world_mut().get_resource_or_init::<SaveBackend>());
+        app.insert_resource(profiles)
+            .add_systems(Update, save_profiles_system);
+    }
+}
+
+/// Resource listing the player profiles and which one is playing
+#[derive(Resource, Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct Profiles {
+    /// Profile names in the order they were created
+    pub names: Vec<String>,
+    /// Index into `names` of the active profile
+    #[serde(default)]
+    pub active: Option<usize>,
+}
+
+impl Profiles {
+    /// Reads the profiles from `PROFILES_FILE_NAME`
+    ///
+    /// A missing or unreadable file gives no profiles.
+    pub fn load(storage: &impl SaveStorage) -> Self {
+        match storage.read(PROFILES_FILE_NAME) {
+            Ok(contents) => ron::from_str(&contents).unwrap_or_else(|e| {
+                warn!(
+                    "Ignoring unreadable profiles '{}': {}",
+                    storage.location(PROFILES_FILE_NAME),
+                    e
+                );
+                Self::default()
+            }),
+            Err(_) => Self::default(),
+        }
+    }
+
+    /// Writes the profiles to `PROFILES_FILE_NAME`
+    ///
+    /// # Errors
+    /// Returns error string if serialization or writing fails
+    pub fn save(&self, storage: &impl SaveStorage) -> Result<(), String> {
+        let contents = ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())
+            .map_err(|e| format!("Failed to serialize profiles: {}", e))?;
+        storage.write(PROFILES_FILE_NAME, &contents)
+    }
+
+    /// Name of the active profile
+    pub fn active_name(&self) -> Option<&str> {
+        self.active
+            .and_then(|index| self.names.get(index))
+            .map(String::as_str)
+    }
+
+    /// Adds a profile named `name` (already validated) and makes it active
+    ///
+    /// # Errors
+    /// Returns `NameError::Taken` if a profile has the same name, ignoring case
+    pub fn create(&mut self, name: String) -> Result<usize, NameError> {
+        if self
+            .names
+            .iter()
+            .any(|existing| existing.to_lowercase() == name.to_lowercase())
+        {
+            return Err(NameError::Taken);
+        }
+        self.names.push(name);
+        let index = self.names.len() - 1;
+        self.active = Some(index);
+        Ok(index)
+    }
+}
+
+/// System that writes the profiles to storage whenever they change
+///
+/// # System Dependencies
+/// - **Resources**: Reads `Profiles`, writes through `SaveBackend`
+/// - **Upstream**: The save management UI creates and switches profiles
+pub fn save_profiles_system(profiles: Res<Profiles>, storage: Res<SaveBackend>) {
+    if !profiles.is_changed() || profiles.is_added() {
+        return;
+    }
+
+    if let Err(e) = profiles.save(&*storage) {
+        error!("{}", e);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn create_activates_new_profile_and_refuses_duplicates() {
+        let mut profiles = Profiles::default();
+        assert_eq!(profiles.create("Ada".to_string()), Ok(0));
+        assert_eq!(profiles.create("Grace".to_string()), Ok(1));
+        assert_eq!(profiles.active_name(), Some("Grace"));
+
+        assert_eq!(profiles.create("ada".to_string()), Err(NameError::Taken));
+        assert_eq!(profiles.names.len(), 2);
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[test]
+    fn profiles_round_trip_through_storage() {
+        use crate::systems::save_storage::FileStorage;
+
+        let dir = std::env::temp_dir().join(format!("rust-game-profiles-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        let storage = FileStorage::new(&dir);
+        assert_eq!(Profiles::load(&storage), Profiles::default());
+
+        let mut profiles = Profiles::default();
+        profiles.create("Ada".to_string()).unwrap();
+        profiles.save(&storage).unwrap();
+        assert_eq!(Profiles::load(&storage), profiles);
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+}
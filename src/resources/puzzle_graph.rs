@@ -0,0 +1,83 @@
+use crate::components::puzzle::PuzzleId;
+use bevy::prelude::*;
+use std::collections::{HashMap, HashSet};
+
+/// Resource tracking the house-wide graph of puzzle prerequisites
+///
+/// Puzzles register their `PuzzleDependency` when they spawn, and solved
+/// puzzles are remembered by ID, so a prerequisite solved in one room still
+/// counts after the player leaves it.
+#[derive(Resource, Default, Debug)]
+pub struct PuzzleGraph {
+    /// Map of puzzle IDs to the puzzles they require
+    pub dependencies: HashMap<PuzzleId, Vec<PuzzleId>>,
+    /// Puzzles that have been solved
+    pub solved: HashSet<PuzzleId>,
+}
+
+impl PuzzleGraph {
+    /// Records the prerequisites of `puzzle`, replacing any earlier entry
+    pub fn add_puzzle(&mut self, puzzle: PuzzleId, requires: Vec<PuzzleId>) {
+        self.dependencies.insert(puzzle, requires);
+    }
+
+    /// Returns the prerequisites of `puzzle` that are not solved yet
+    pub fn missing_prerequisites(&self, puzzle: PuzzleId) -> Vec<PuzzleId> {
+        self.dependencies
+            .get(&puzzle)
+            .map(|requires| {
+                requires
+                    .iter()
+                    .copied()
+                    .filter(|required| !self.solved.contains(required))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Returns whether every prerequisite of `puzzle` is solved
+    pub fn is_unlocked(&self, puzzle: PuzzleId) -> bool {
+        self.missing_prerequisites(puzzle).is_empty()
+    }
+
+    /// Marks `puzzle` solved and returns the puzzles this unlocked
+    ///
+    /// Only puzzles that were locked before and are unlocked now are
+    /// returned, sorted by ID. Solving a puzzle twice unlocks nothing.
+    pub fn mark_solved(&mut self, puzzle: PuzzleId) -> Vec<PuzzleId> {
+        if !self.solved.insert(puzzle) {
+            return Vec::new();
+        }
+
+        let mut unlocked: Vec<PuzzleId> = self
+            .dependencies
+            .iter()
+            .filter(|(_, requires)| requires.contains(&puzzle))
+            .map(|(dependent, _)| *dependent)
+            .filter(|dependent| self.is_unlocked(*dependent))
+            .collect();
+        unlocked.sort_unstable();
+        unlocked
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn puzzle_unlocks_when_all_prerequisites_are_solved() {
+        let mut graph = PuzzleGraph::default();
+        graph.add_puzzle(3, vec![1, 2]);
+        graph.add_puzzle(4, vec![1]);
+
+        assert!(!graph.is_unlocked(3));
+        assert!(graph.is_unlocked(1), "Puzzles without entries are unlocked");
+
+        assert_eq!(graph.mark_solved(1), vec![4]);
+        assert_eq!(graph.missing_prerequisites(3), vec![2]);
+        assert_eq!(graph.mark_solved(2), vec![3]);
+        assert!(graph.is_unlocked(3));
+        assert!(graph.mark_solved(2).is_empty(), "Already solved");
+    }
+}
@@ -0,0 +1,52 @@
+use crate::components::lighting::CandleState;
+use crate::components::room::LevelEntityId;
+use bevy::prelude::*;
+use std::collections::{HashMap, HashSet};
+
+/// Resource holding the state of level entities across room visits
+///
+/// Rooms are despawned when the player leaves them, so anything the player
+/// changed (a lit sconce, a placed candle, a pushed crate, boards pried off a
+/// door, a cut wire, an oiled hinge) is recorded here by
+/// `LevelEntityId` and re-applied when the room is spawned again. The store
+/// is written to save files, so these states also survive a reload.
+#[derive(Resource, Default, Debug)]
+pub struct RoomStateStore {
+    /// Last known state of each level candle and sconce
+    pub light_states: HashMap<LevelEntityId, CandleState>,
+    /// Last known position of each pushable block that was moved
+    pub crate_positions: HashMap<LevelEntityId, Vec2>,
+    /// Breakable objects the player has broken
+    pub broken: HashSet<LevelEntityId>,
+    /// Names of wires the player has cut
+    pub cut_wires: HashSet<String>,
+    /// Stuck doors and levers the player has oiled
+    pub oiled: HashSet<LevelEntityId>,
+}
+
+impl RoomStateStore {
+    /// Returns the recorded state of a level light, if it was ever changed
+    pub fn light_state(&self, id: LevelEntityId) -> Option<CandleState> {
+        self.light_states.get(&id).copied()
+    }
+
+    /// Returns the recorded position of a pushable block, if it was ever moved
+    pub fn crate_position(&self, id: LevelEntityId) -> Option<Vec2> {
+        self.crate_positions.get(&id).copied()
+    }
+
+    /// Returns true if the player has broken the level object
+    pub fn is_broken(&self, id: LevelEntityId) -> bool {
+        self.broken.contains(&id)
+    }
+
+    /// Returns true if the player has cut the named wire
+    pub fn is_wire_cut(&self, wire: &str) -> bool {
+        self.cut_wires.contains(wire)
+    }
+
+    /// Returns true if the player has oiled the stuck level object
+    pub fn is_oiled(&self, id: LevelEntityId) -> bool {
+        self.oiled.contains(&id)
+    }
+}
@@ -0,0 +1,321 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+use crate::components::puzzle::Puzzle;
+use crate::components::render_layer::RenderLayer;
+use crate::components::room::{Collider, Interactable};
+use crate::systems::save_storage::{ACCESSIBILITY_FILE_NAME, SaveBackend, SaveStorage};
+
+/// Lowest light level (0.0-1.0) of the lighting overlay in high-contrast mode
+pub const HIGH_CONTRAST_DARKNESS_FLOOR: f32 = 0.35;
+
+/// Color of the frame drawn around interactables in high-contrast mode
+pub const OUTLINE_COLOR: Color = Color::srgb(1.0, 0.85, 0.2);
+
+/// Thickness (pixels) of the interactable frame
+pub const OUTLINE_WIDTH: f32 = 2.0;
+
+/// Size of the frame around interactables without a sprite size or collider
+pub const DEFAULT_OUTLINE_SIZE: Vec2 = Vec2::splat(32.0);
+
+/// Plugin that loads the accessibility options and applies high-contrast outlines
+///
+/// The options are kept in `ACCESSIBILITY_FILE_NAME` of the `SaveBackend`,
+/// apart from the save slots. The puzzle panel and lighting read them
+/// directly.
+pub struct AccessibilityPlugin;
+
+impl Plugin for AccessibilityPlugin {
+    fn build(&self, app: &mut App) {
+        let settings =
+            AccessibilitySettings::load(&*app.world_mut().get_resource_or_init::<SaveBackend>());
+        app.insert_resource(settings).add_systems(
+            Update,
+            (interactable_outline_system, save_accessibility_system),
+        );
+    }
+}
+
+/// Resource holding the player's accessibility options, persisted to storage
+#[derive(Resource, Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AccessibilitySettings {
+    /// Draw puzzle symbols with a distinct pattern each, not just a color
+    #[serde(default)]
+    pub colorblind_symbols: bool,
+    /// Raise the darkness floor and outline interactables
+    #[serde(default)]
+    pub high_contrast: bool,
+}
+
+impl AccessibilitySettings {
+    /// Reads the options from `ACCESSIBILITY_FILE_NAME`
+    ///
+    /// A missing or unreadable file gives the default options.
+    pub fn load(storage: &impl SaveStorage) -> Self {
+        match storage.read(ACCESSIBILITY_FILE_NAME) {
+            Ok(contents) => ron::from_str(&contents).unwrap_or_else(|e| {
+                warn!(
+                    "Ignoring unreadable accessibility options '{}': {}",
+                    storage.location(ACCESSIBILITY_FILE_NAME),
+                    e
+                );
+                Self::default()
+            }),
+            Err(_) => Self::default(),
+        }
+    }
+
+    /// Writes the options to `ACCESSIBILITY_FILE_NAME`
+    ///
+    /// # Errors
+    /// Returns error string if serialization or writing fails
+    pub fn save(&self, storage: &impl SaveStorage) -> Result<(), String> {
+        let contents = ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())
+            .map_err(|e| format!("Failed to serialize accessibility options: {}", e))?;
+        storage.write(ACCESSIBILITY_FILE_NAME, &contents)
+    }
+
+    /// Lowest light level of the lighting overlay
+    pub fn darkness_floor(&self) -> f32 {
+        if self.high_contrast {
+            HIGH_CONTRAST_DARKNESS_FLOOR
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Component on the frame drawn around an interactable in high-contrast mode
+///
+/// The frame is a separate entity on `RenderLayer::Ui`, above the lighting
+/// overlay, so it stays visible in the dark.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InteractableOutline {
+    /// The outlined entity
+    pub target: Entity,
+}
+
+/// Size of the frame around an entity: its sprite size, else its collider
+pub fn outline_size(sprite: Option<&Sprite>, collider: Option<&Collider>) -> Vec2 {
+    sprite
+        .and_then(|sprite| sprite.custom_size)
+        .or_else(|| collider.map(|collider| collider.max - collider.min))
+        .unwrap_or(DEFAULT_OUTLINE_SIZE)
+}
+
+/// Spawns a frame of `size` around `target`, four thin sprites as children
+fn spawn_outline(commands: &mut Commands, target: Entity, position: Vec2, size: Vec2) {
+    let half = (size + OUTLINE_WIDTH) / 2.0;
+    let edges = [
+        (
+            Vec2::new(0.0, half.y),
+            Vec2::new(size.x + OUTLINE_WIDTH * 2.0, OUTLINE_WIDTH),
+        ),
+        (
+            Vec2::new(0.0, -half.y),
+            Vec2::new(size.x + OUTLINE_WIDTH * 2.0, OUTLINE_WIDTH),
+        ),
+        (Vec2::new(-half.x, 0.0), Vec2::new(OUTLINE_WIDTH, size.y)),
+        (Vec2::new(half.x, 0.0), Vec2::new(OUTLINE_WIDTH, size.y)),
+    ];
+
+    commands
+        .spawn((
+            InteractableOutline { target },
+            RenderLayer::Ui,
+            Transform::from_xyz(position.x, position.y, RenderLayer::Ui.z()),
+            Visibility::default(),
+        ))
+        .with_children(|parent| {
+            for (offset, edge) in edges {
+                parent.spawn((
+                    Sprite {
+                        color: OUTLINE_COLOR,
+                        custom_size: Some(edge),
+                        ..default()
+                    },
+                    Transform::from_translation(offset.extend(0.0)),
+                ));
+            }
+        });
+}
+
+/// System that frames interactables and puzzles while high contrast is on
+///
+/// # System Dependencies
+/// - **Components**: Reads `Interactable`/`Puzzle` entities' `Transform`,
+///   `Sprite` and `Collider`; writes `InteractableOutline` frames
+/// - **Resources**: Reads `AccessibilitySettings` (when present)
+///
+/// # Behavior
+/// 1. Frames follow their target; frames whose target is gone, and every
+///    frame while high contrast is off, are despawned
+/// 2. While high contrast is on, each unframed target gets a frame sized by
+///    `outline_size`
+#[allow(clippy::type_complexity)]
+pub fn interactable_outline_system(
+    mut commands: Commands,
+    settings: Option<Res<AccessibilitySettings>>,
+    targets: Query<
+        (Entity, &Transform, Option<&Sprite>, Option<&Collider>),
+        (
+            Or<(With<Interactable>, With<Puzzle>)>,
+            Without<InteractableOutline>,
+        ),
+    >,
+    mut outlines: Query<(Entity, &InteractableOutline, &mut Transform)>,
+) {
+    let enabled = settings.is_some_and(|settings| settings.high_contrast);
+
+    let mut framed = HashSet::new();
+    for (entity, outline, mut transform) in &mut outlines {
+        let target = targets.get(outline.target).ok().filter(|_| enabled);
+        let Some((_, target_transform, _, _)) = target else {
+            commands.entity(entity).despawn();
+            continue;
+        };
+
+        framed.insert(outline.target);
+        let position = target_transform.translation.truncate();
+        if transform.translation.truncate() != position {
+            transform.translation.x = position.x;
+            transform.translation.y = position.y;
+        }
+    }
+
+    if !enabled {
+        return;
+    }
+    for (entity, transform, sprite, collider) in &targets {
+        if !framed.contains(&entity) {
+            spawn_outline(
+                &mut commands,
+                entity,
+                transform.translation.truncate(),
+                outline_size(sprite, collider),
+            );
+        }
+    }
+}
+
+/// System that writes the accessibility options to storage whenever they change
+///
+/// # System Dependencies
+/// - **Resources**: Reads `AccessibilitySettings`, writes through
+///   `SaveBackend`
+pub fn save_accessibility_system(settings: Res<AccessibilitySettings>, storage: Res<SaveBackend>) {
+    if !settings.is_changed() || settings.is_added() {
+        return;
+    }
+
+    if let Err(e) = settings.save(&*storage) {
+        error!("{}", e);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::room::InteractionKind;
+    use crate::systems::save_storage::MemoryStorage;
+
+    #[test]
+    fn plugin_loads_and_saves_options_through_the_save_backend() {
+        let storage = MemoryStorage::default();
+        AccessibilitySettings {
+            colorblind_symbols: true,
+            ..default()
+        }
+        .save(&storage)
+        .unwrap();
+
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.insert_resource(SaveBackend::new(storage.clone()));
+        app.add_plugins(AccessibilityPlugin);
+        assert!(
+            app.world()
+                .resource::<AccessibilitySettings>()
+                .colorblind_symbols
+        );
+
+        app.update();
+        app.world_mut()
+            .resource_mut::<AccessibilitySettings>()
+            .high_contrast = true;
+        app.update();
+        assert!(AccessibilitySettings::load(&storage).high_contrast);
+    }
+
+    #[test]
+    fn outlines_follow_the_high_contrast_option() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.init_resource::<AccessibilitySettings>();
+        app.add_systems(Update, interactable_outline_system);
+
+        let door = app
+            .world_mut()
+            .spawn((
+                Interactable::new(InteractionKind::OpenDoor),
+                Sprite {
+                    custom_size: Some(Vec2::new(32.0, 64.0)),
+                    ..default()
+                },
+                Transform::from_xyz(100.0, 50.0, 0.0),
+            ))
+            .id();
+        let outlines = |app: &mut App| {
+            app.world_mut()
+                .query::<(&InteractableOutline, &Transform)>()
+                .iter(app.world())
+                .map(|(outline, transform)| (outline.target, transform.translation.truncate()))
+                .collect::<Vec<_>>()
+        };
+
+        app.update();
+        assert!(outlines(&mut app).is_empty());
+
+        app.world_mut()
+            .resource_mut::<AccessibilitySettings>()
+            .high_contrast = true;
+        app.update();
+        assert_eq!(outlines(&mut app), vec![(door, Vec2::new(100.0, 50.0))]);
+
+        app.world_mut()
+            .get_mut::<Transform>(door)
+            .unwrap()
+            .translation
+            .x = 140.0;
+        app.update();
+        assert_eq!(outlines(&mut app), vec![(door, Vec2::new(140.0, 50.0))]);
+
+        app.world_mut()
+            .resource_mut::<AccessibilitySettings>()
+            .high_contrast = false;
+        app.update();
+        assert!(outlines(&mut app).is_empty());
+    }
+
+    #[test]
+    fn outline_size_prefers_sprite_then_collider() {
+        let sprite = Sprite {
+            custom_size: Some(Vec2::new(10.0, 20.0)),
+            ..default()
+        };
+        let collider = Collider {
+            min: Vec2::new(-8.0, -4.0),
+            max: Vec2::new(8.0, 4.0),
+        };
+        assert_eq!(
+            outline_size(Some(&sprite), Some(&collider)),
+            Vec2::new(10.0, 20.0)
+        );
+        assert_eq!(
+            outline_size(Some(&Sprite::default()), Some(&collider)),
+            Vec2::new(16.0, 8.0)
+        );
+        assert_eq!(outline_size(None, None), DEFAULT_OUTLINE_SIZE);
+    }
+}
@@ -0,0 +1,367 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+use crate::components::inventory::Inventory;
+use crate::components::player::Player;
+use crate::resources::game_state::{GameMode, GameState};
+use crate::systems::save_storage::{ACHIEVEMENTS_FILE_NAME, SaveBackend, SaveStorage};
+use crate::systems::victory::RunSummary;
+use crate::ui::journal::{NoteContent, collected_pages};
+use crate::ui::toast::ToastEvent;
+
+/// Longest run that still earns `Achievement::SwiftEscape`
+pub const SWIFT_ESCAPE_TIME: Duration = Duration::from_secs(30 * 60);
+
+/// Plugin that loads the unlocked achievements and unlocks new ones
+///
+/// Unlocks are kept in `ACHIEVEMENTS_FILE_NAME` of the `SaveBackend`, apart
+/// from the save slots, so they stay earned whichever save is loaded.
+pub struct AchievementsPlugin;
+
+impl Plugin for AchievementsPlugin {
+    fn build(&self, app: &mut App) {
+        let achievements =
+            Achievements::load(&*app.world_mut().get_resource_or_init::<SaveBackend>());
+        app.insert_resource(achievements)
+            .init_resource::<RunSummary>()
+            .add_event::<AchievementUnlockedEvent>()
+            .add_event::<ToastEvent>()
+            .add_systems(
+                Update,
+                (
+                    (diary_achievement_system, escape_achievements_system),
+                    achievement_toast_system,
+                    save_achievements_system,
+                )
+                    .chain(),
+            );
+    }
+}
+
+/// Achievements the player can unlock
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Achievement {
+    /// Escaped without dying
+    Deathless,
+    /// Collected every diary page
+    Archivist,
+    /// Escaped within `SWIFT_ESCAPE_TIME`
+    SwiftEscape,
+}
+
+impl Achievement {
+    /// Every achievement, in the order the list screen shows them
+    pub const ALL: [Achievement; 3] = [
+        Achievement::Deathless,
+        Achievement::Archivist,
+        Achievement::SwiftEscape,
+    ];
+
+    /// Display name
+    pub fn name(self) -> &'static str {
+        match self {
+            Achievement::Deathless => "Untouched",
+            Achievement::Archivist => "Archivist",
+            Achievement::SwiftEscape => "Swift Escape",
+        }
+    }
+
+    /// What unlocks the achievement
+    pub fn description(self) -> &'static str {
+        match self {
+            Achievement::Deathless => "Escape the house without dying.",
+            Achievement::Archivist => "Find every page of the diary.",
+            Achievement::SwiftEscape => "Escape the house in under 30 minutes.",
+        }
+    }
+}
+
+/// Resource listing the unlocked achievements, persisted to storage
+#[derive(Resource, Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct Achievements {
+    /// Unlocked achievements in the order they were earned
+    pub unlocked: Vec<Achievement>,
+}
+
+impl Achievements {
+    /// Reads the achievements from `ACHIEVEMENTS_FILE_NAME`
+    ///
+    /// A missing or unreadable file gives no achievements.
+    pub fn load(storage: &impl SaveStorage) -> Self {
+        match storage.read(ACHIEVEMENTS_FILE_NAME) {
+            Ok(contents) => ron::from_str(&contents).unwrap_or_else(|e| {
+                warn!(
+                    "Ignoring unreadable achievements '{}': {}",
+                    storage.location(ACHIEVEMENTS_FILE_NAME),
+                    e
+                );
+                Self::default()
+            }),
+            Err(_) => Self::default(),
+        }
+    }
+
+    /// Writes the achievements to `ACHIEVEMENTS_FILE_NAME`
+    ///
+    /// # Errors
+    /// Returns error string if serialization or writing fails
+    pub fn save(&self, storage: &impl SaveStorage) -> Result<(), String> {
+        let contents = ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())
+            .map_err(|e| format!("Failed to serialize achievements: {}", e))?;
+        storage.write(ACHIEVEMENTS_FILE_NAME, &contents)
+    }
+
+    /// Returns true if `achievement` has been unlocked
+    pub fn is_unlocked(&self, achievement: Achievement) -> bool {
+        self.unlocked.contains(&achievement)
+    }
+
+    /// Unlocks `achievement`, returning false if it already was
+    pub fn unlock(&mut self, achievement: Achievement) -> bool {
+        if self.is_unlocked(achievement) {
+            return false;
+        }
+        self.unlocked.push(achievement);
+        true
+    }
+}
+
+/// Event emitted the first time an achievement is unlocked
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AchievementUnlockedEvent {
+    /// The achievement earned
+    pub achievement: Achievement,
+}
+
+/// Achievements earned by the escape summarized in `summary`
+pub fn escape_achievements(summary: &RunSummary) -> Vec<Achievement> {
+    let mut earned = Vec::new();
+    if summary.deaths == 0 {
+        earned.push(Achievement::Deathless);
+    }
+    if summary.total_pages > 0 && summary.diary_pages >= summary.total_pages {
+        earned.push(Achievement::Archivist);
+    }
+    if summary.time < SWIFT_ESCAPE_TIME {
+        earned.push(Achievement::SwiftEscape);
+    }
+    earned
+}
+
+/// Unlocks each of `earned` not unlocked yet, emitting its event
+fn unlock_all(
+    achievements: &mut Achievements,
+    earned: impl IntoIterator<Item = Achievement>,
+    events: &mut EventWriter<AchievementUnlockedEvent>,
+) {
+    for achievement in earned {
+        if achievements.unlock(achievement) {
+            info!("Achievement unlocked: {}", achievement.name());
+            events.write(AchievementUnlockedEvent { achievement });
+        }
+    }
+}
+
+/// System that unlocks the escape achievements when the player escapes
+///
+/// # System Dependencies
+/// - **Resources**: Reads `GameState` and `RunSummary`, writes `Achievements`
+/// - **Upstream**: `victory::escape_system` records the `RunSummary`
+/// - **Downstream**: Emits `AchievementUnlockedEvent`
+pub fn escape_achievements_system(
+    game_state: Res<GameState>,
+    summary: Res<RunSummary>,
+    mut achievements: ResMut<Achievements>,
+    mut events: EventWriter<AchievementUnlockedEvent>,
+) {
+    if game_state.game_mode != GameMode::Victory || !summary.is_changed() {
+        return;
+    }
+
+    unlock_all(
+        &mut achievements,
+        escape_achievements(&summary),
+        &mut events,
+    );
+}
+
+/// System that unlocks `Achievement::Archivist` once every diary page is carried
+///
+/// # System Dependencies
+/// - **Components**: Reads the player's `Inventory` when it changes
+/// - **Resources**: Reads `NoteContent` (when present), writes `Achievements`
+/// - **Downstream**: Emits `AchievementUnlockedEvent`
+pub fn diary_achievement_system(
+    notes: Option<Res<NoteContent>>,
+    mut achievements: ResMut<Achievements>,
+    mut events: EventWriter<AchievementUnlockedEvent>,
+    inventory_query: Query<&Inventory, (With<Player>, Changed<Inventory>)>,
+) {
+    let Some(notes) = notes.filter(|notes| !notes.pages.is_empty()) else {
+        return;
+    };
+    if achievements.is_unlocked(Achievement::Archivist) {
+        return;
+    }
+
+    if inventory_query
+        .iter()
+        .any(|inventory| collected_pages(inventory).len() >= notes.pages.len())
+    {
+        unlock_all(&mut achievements, [Achievement::Archivist], &mut events);
+    }
+}
+
+/// System that shows a toast for each unlocked achievement
+///
+/// # System Dependencies
+/// - **Upstream**: The achievement systems emit `AchievementUnlockedEvent`
+/// - **Downstream**: Emits `ToastEvent`
+pub fn achievement_toast_system(
+    mut events: EventReader<AchievementUnlockedEvent>,
+    mut toasts: EventWriter<ToastEvent>,
+) {
+    for event in events.read() {
+        toasts.write(ToastEvent::info(format!(
+            "Achievement unlocked: {}",
+            event.achievement.name()
+        )));
+    }
+}
+
+/// System that writes the achievements to storage whenever they change
+///
+/// # System Dependencies
+/// - **Resources**: Reads `Achievements`, writes through `SaveBackend`
+pub fn save_achievements_system(achievements: Res<Achievements>, storage: Res<SaveBackend>) {
+    if !achievements.is_changed() || achievements.is_added() {
+        return;
+    }
+
+    if let Err(e) = achievements.save(&*storage) {
+        error!("{}", e);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::inventory::Item;
+    use crate::ui::journal::NotePage;
+
+    #[test]
+    fn escape_earns_achievements_by_run_summary() {
+        let summary = RunSummary {
+            time: Duration::from_secs(20 * 60),
+            deaths: 2,
+            diary_pages: 4,
+            total_pages: 4,
+            ..default()
+        };
+        assert_eq!(
+            escape_achievements(&summary),
+            vec![Achievement::Archivist, Achievement::SwiftEscape]
+        );
+
+        let slow_deathless = RunSummary {
+            time: SWIFT_ESCAPE_TIME,
+            diary_pages: 1,
+            total_pages: 4,
+            ..default()
+        };
+        assert_eq!(
+            escape_achievements(&slow_deathless),
+            vec![Achievement::Deathless]
+        );
+    }
+
+    #[test]
+    fn achievements_unlock_once_with_a_toast() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.init_resource::<Achievements>();
+        app.init_resource::<RunSummary>();
+        app.add_event::<AchievementUnlockedEvent>();
+        app.add_event::<ToastEvent>();
+        app.add_systems(
+            Update,
+            (
+                (diary_achievement_system, escape_achievements_system),
+                achievement_toast_system,
+            )
+                .chain(),
+        );
+        app.insert_resource(GameState {
+            game_mode: GameMode::Playing,
+            ..default()
+        });
+        let page = |title: &str| NotePage {
+            title: title.to_string(),
+            text: String::new(),
+        };
+        app.insert_resource(NoteContent {
+            pages: [(1, page("Arrival")), (2, page("The Cellar"))].into(),
+        });
+        let player = app
+            .world_mut()
+            .spawn((
+                Player,
+                Inventory {
+                    items: vec![Item::DiaryPage(1)],
+                    max_capacity: 10,
+                },
+            ))
+            .id();
+        app.update();
+        assert!(app.world().resource::<Achievements>().unlocked.is_empty());
+
+        app.world_mut()
+            .get_mut::<Inventory>(player)
+            .unwrap()
+            .items
+            .push(Item::DiaryPage(2));
+        app.update();
+        assert_eq!(
+            app.world().resource::<Achievements>().unlocked,
+            vec![Achievement::Archivist]
+        );
+        let toasts = app.world().resource::<Events<ToastEvent>>();
+        assert_eq!(toasts.len(), 1);
+
+        // Escaping with every page doesn't unlock Archivist again
+        app.world_mut().resource_mut::<GameState>().game_mode = GameMode::Victory;
+        *app.world_mut().resource_mut::<RunSummary>() = RunSummary {
+            time: SWIFT_ESCAPE_TIME * 2,
+            deaths: 1,
+            diary_pages: 2,
+            total_pages: 2,
+            ..default()
+        };
+        app.update();
+        assert_eq!(
+            app.world().resource::<Achievements>().unlocked,
+            vec![Achievement::Archivist]
+        );
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[test]
+    fn achievements_round_trip_through_storage() {
+        use crate::systems::save_storage::FileStorage;
+
+        let dir =
+            std::env::temp_dir().join(format!("rust-game-achievements-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        let storage = FileStorage::new(&dir);
+        assert_eq!(Achievements::load(&storage), Achievements::default());
+
+        let mut achievements = Achievements::default();
+        achievements.unlock(Achievement::SwiftEscape);
+        achievements.save(&storage).unwrap();
+        assert_eq!(Achievements::load(&storage), achievements);
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+}
@@ -0,0 +1,213 @@
+use crate::resources::game_state::{GameMode, GameState};
+use crate::systems::ghost::{GhostPlayback, ghost_playback_system, load_ghost_recording};
+use crate::ui::attract_overlay::attract_overlay_system;
+use bevy::prelude::*;
+
+/// Ghost run played back during attract mode
+pub const ATTRACT_RUN_PATH: &str = "assets/replays/attract_run.ron";
+
+/// Default seconds of menu inactivity before attract mode starts
+pub const DEFAULT_IDLE_THRESHOLD: f32 = 120.0;
+
+/// Plugin that plays a recorded ghost run when the main menu sits idle
+///
+/// After `AttractModeConfig::idle_threshold` seconds without keyboard or
+/// mouse input in `GameMode::Menu`, a ghost replays a recorded run under a
+/// title overlay. Any input ends attract mode and returns to the menu.
+///
+/// **NOTE**: The overlay uses bevy_egui; EguiPlugin must be added first.
+pub struct AttractModePlugin;
+
+impl Plugin for AttractModePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<AttractModeConfig>()
+            .init_resource::<AttractModeState>()
+            .add_systems(
+                Update,
+                (
+                    idle_detection_system,
+                    ghost_playback_system,
+                    attract_overlay_system,
+                )
+                    .chain(),
+            );
+    }
+}
+
+/// Resource configuring attract mode
+#[derive(Resource, Debug, Clone)]
+pub struct AttractModeConfig {
+    /// Seconds of inactivity on the menu before attract mode starts
+    pub idle_threshold: f32,
+    /// Path to the ghost recording played back
+    pub recording_path: String,
+}
+
+impl Default for AttractModeConfig {
+    fn default() -> Self {
+        Self {
+            idle_threshold: DEFAULT_IDLE_THRESHOLD,
+            recording_path: ATTRACT_RUN_PATH.to_string(),
+        }
+    }
+}
+
+/// Resource tracking menu inactivity and whether attract mode is running
+#[derive(Resource, Debug, Default)]
+pub struct AttractModeState {
+    /// Seconds since the last input
+    pub idle_time: f32,
+    /// Whether the attract playback is currently showing
+    pub active: bool,
+}
+
+/// Marker component for entities spawned by attract mode (despawned on exit)
+#[derive(Component)]
+pub struct AttractModeEntity;
+
+/// System that detects menu inactivity and starts/stops attract mode
+///
+/// # Behavior
+/// 1. Outside `GameMode::Menu`, resets the idle timer and stops attract mode
+/// 2. Any key or mouse button press resets the idle timer; if attract mode
+///    is running it is stopped and the menu is shown again
+/// 3. Once idle for `idle_threshold` seconds, loads the ghost recording and
+///    spawns a ghost entity that replays it on a loop
+///
+/// # System Dependencies
+/// - **Resources**: `ButtonInput<KeyCode>`, `ButtonInput<MouseButton>`, `GameState`
+/// - **Downstream**: `ghost_playback_system` moves the ghost,
+///   `attract_overlay_system` draws the title art
+#[allow(clippy::too_many_arguments)]
+pub fn idle_detection_system(
+    time: Res<Time>,
+    game_state: Res<GameState>,
+    config: Res<AttractModeConfig>,
+    keys: Res<ButtonInput<KeyCode>>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    mut state: ResMut<AttractModeState>,
+    mut commands: Commands,
+    attract_entities: Query<Entity, With<AttractModeEntity>>,
+) {
+    let input_received =
+        keys.get_just_pressed().next().is_some() || mouse.get_just_pressed().next().is_some();
+
+    if game_state.game_mode != GameMode::Menu || input_received {
+        state.idle_time = 0.0;
+        if state.active {
+            state.active = false;
+            for entity in &attract_entities {
+                commands.entity(entity).despawn();
+            }
+            info!("Attract mode ended");
+        }
+        return;
+    }
+
+    if state.active {
+        return;
+    }
+
+    state.idle_time += time.delta_secs();
+    if state.idle_time < config.idle_threshold {
+        return;
+    }
+
+    match load_ghost_recording(&config.recording_path) {
+        Ok(recording) => {
+            let start = recording.position_at(0.0).unwrap_or_default();
+            commands.spawn((
+                AttractModeEntity,
+                GhostPlayback::new(recording, true),
+                Sprite {
+                    color: Color::srgba(0.8, 0.9, 1.0, 0.5),
+                    custom_size: Some(Vec2::new(32.0, 48.0)),
+                    ..default()
+                },
+                Transform::from_translation(start.extend(10.0)),
+            ));
+            state.active = true;
+            info!("Attract mode started");
+        }
+        Err(e) => {
+            warn!("{}. Attract mode unavailable.", e);
+            // Avoid retrying the load every frame
+            state.idle_time = 0.0;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn setup_app(idle_threshold: f32) -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.init_resource::<GameState>();
+        app.init_resource::<ButtonInput<KeyCode>>();
+        app.init_resource::<ButtonInput<MouseButton>>();
+        app.insert_resource(AttractModeConfig {
+            idle_threshold,
+            ..Default::default()
+        });
+        app.init_resource::<AttractModeState>();
+        app.add_systems(Update, idle_detection_system);
+        app
+    }
+
+    fn ghost_count(app: &mut App) -> usize {
+        let mut query = app
+            .world_mut()
+            .query_filtered::<Entity, With<AttractModeEntity>>();
+        query.iter(app.world()).count()
+    }
+
+    #[test]
+    fn idle_menu_starts_attract_mode() {
+        let mut app = setup_app(0.0);
+
+        app.update();
+
+        assert!(app.world().resource::<AttractModeState>().active);
+        assert_eq!(ghost_count(&mut app), 1);
+    }
+
+    #[test]
+    fn input_returns_to_menu() {
+        let mut app = setup_app(0.0);
+        app.update();
+        assert!(app.world().resource::<AttractModeState>().active);
+
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::Space);
+        app.update();
+
+        assert!(!app.world().resource::<AttractModeState>().active);
+        assert_eq!(ghost_count(&mut app), 0);
+    }
+
+    #[test]
+    fn attract_mode_only_on_menu() {
+        let mut app = setup_app(0.0);
+        app.world_mut().resource_mut::<GameState>().game_mode = GameMode::Playing;
+
+        app.update();
+
+        assert!(!app.world().resource::<AttractModeState>().active);
+        assert_eq!(ghost_count(&mut app), 0);
+    }
+
+    #[test]
+    fn missing_recording_does_not_start() {
+        let mut app = setup_app(0.0);
+        app.world_mut()
+            .resource_mut::<AttractModeConfig>()
+            .recording_path = "assets/replays/nonexistent.ron".to_string();
+
+        app.update();
+
+        assert!(!app.world().resource::<AttractModeState>().active);
+    }
+}
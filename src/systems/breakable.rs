@@ -0,0 +1,276 @@
+use bevy::prelude::*;
+
+use crate::audio::sound_bank::{PlaySoundEvent, SoundCue};
+use crate::components::inventory::{Collectible, Inventory, Item, ToolType};
+use crate::components::player::Player;
+use crate::components::render_layer::RenderLayer;
+use crate::components::room::{Collider, LevelEntityId};
+use crate::resources::asset_handles::{AssetHandles, SpriteType};
+use crate::resources::room_state::RoomStateStore;
+use crate::systems::interaction::BreakEvent;
+use crate::systems::inventory::PLACED_ITEM_HALF_SIZE;
+
+/// Number of debris pieces a broken object bursts into
+pub const DEBRIS_PIECES: usize = 10;
+
+/// Seconds a piece of debris lasts before it's despawned
+pub const DEBRIS_LIFETIME: f32 = 0.8;
+
+/// Gravity (pixels per second squared) on debris
+const DEBRIS_GRAVITY: f32 = 980.0;
+
+/// Plugin for boarded doors, crates and weak walls broken open with tools
+///
+/// Breakables are `Interactable`s of kind `InteractionKind::Break`, so
+/// `interaction_system` dispatches the `BreakEvent` that breaks them.
+pub struct BreakablePlugin;
+
+impl Plugin for BreakablePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<BreakEvent>()
+            .add_event::<PlaySoundEvent>()
+            .add_systems(Update, (break_system, debris_particle_system).chain());
+    }
+}
+
+/// Component for an object the player can pry or smash open
+///
+/// Needs an `Interactable` of kind `InteractionKind::Break`. Objects that
+/// block the way are also `Solid`, and stop blocking once broken.
+#[derive(Component, Clone)]
+pub struct Breakable {
+    /// Tool the player must carry to break it
+    pub tool: ToolType,
+    /// Item hidden inside, dropped where the object stood
+    pub contains: Option<Item>,
+    /// Color of the debris it breaks into
+    pub debris_color: Color,
+}
+
+impl Default for Breakable {
+    fn default() -> Self {
+        Self {
+            tool: ToolType::Crowbar,
+            contains: None,
+            debris_color: Color::srgb(0.45, 0.32, 0.2),
+        }
+    }
+}
+
+/// Component for a piece of debris thrown out by a broken object
+#[derive(Component, Debug)]
+pub struct DebrisParticle {
+    /// Current velocity in pixels per second
+    pub velocity: Vec2,
+    /// Despawns the piece when it finishes
+    pub lifetime: Timer,
+}
+
+/// System that breaks objects open when the player has the right tool
+///
+/// # System Dependencies
+/// - **Upstream**: `interaction_system` emits `BreakEvent`
+/// - **Components**: Reads `Breakable`, `Transform`, `LevelEntityId` and the
+///   player's `Inventory`; despawns the object
+/// - **Resources**: Writes `RoomStateStore` (when present); reads
+///   `AssetHandles` (when present) for the dropped item's sprite
+/// - **Downstream**: `debris_particle_system` moves the debris; emits
+///   `PlaySoundEvent` (`SoundCue::WoodSplinter`)
+///
+/// # Behavior
+/// Without the required tool in the inventory the object doesn't budge.
+/// With it, the object is despawned in a burst of debris, drops any item
+/// hidden inside as a `Collectible`, and level objects are recorded as
+/// broken so they stay broken when the room is visited again.
+#[allow(clippy::too_many_arguments)]
+pub fn break_system(
+    mut commands: Commands,
+    mut events: EventReader<BreakEvent>,
+    mut store: Option<ResMut<RoomStateStore>>,
+    handles: Option<Res<AssetHandles>>,
+    inventory_query: Query<&Inventory, With<Player>>,
+    breakable_query: Query<(&Breakable, &Transform, Option<&LevelEntityId>)>,
+    mut sound_events: EventWriter<PlaySoundEvent>,
+) {
+    for event in events.read() {
+        let Ok((breakable, transform, level_id)) = breakable_query.get(event.target) else {
+            continue;
+        };
+        let Ok(inventory) = inventory_query.get(event.player) else {
+            continue;
+        };
+        let has_tool = inventory
+            .items
+            .iter()
+            .any(|item| matches!(item, Item::Tool(tool) if *tool == breakable.tool));
+        if !has_tool {
+            info!("It won't budge ({:?} needed)", breakable.tool);
+            continue;
+        }
+
+        let position = transform.translation.truncate();
+        commands.entity(event.target).despawn();
+        if let (Some(store), Some(id)) = (store.as_mut(), level_id) {
+            store.bypass_change_detection().broken.insert(*id);
+        }
+
+        // Burst the debris out in a fan, fastest straight up
+        for index in 0..DEBRIS_PIECES {
+            let t = index as f32 / (DEBRIS_PIECES - 1) as f32;
+            let angle = (0.1 + 0.8 * t) * std::f32::consts::PI;
+            let speed = 140.0 + 100.0 * (1.0 - (2.0 * t - 1.0).abs());
+            commands.spawn((
+                DebrisParticle {
+                    velocity: Vec2::from_angle(angle) * speed,
+                    lifetime: Timer::from_seconds(DEBRIS_LIFETIME, TimerMode::Once),
+                },
+                Sprite {
+                    color: breakable.debris_color,
+                    custom_size: Some(Vec2::new(6.0, 3.0)),
+                    ..default()
+                },
+                RenderLayer::Items,
+                Transform::from_translation(position.extend(RenderLayer::Items.z())),
+            ));
+        }
+
+        if let Some(item) = breakable.contains.clone() {
+            let sprite = handles
+                .as_ref()
+                .zip(SpriteType::for_item(&item))
+                .map(|(handles, sprite)| handles.sprite_frame(sprite, 0));
+            let mut dropped = commands.spawn((
+                item,
+                Collectible,
+                RenderLayer::Items,
+                Transform::from_translation(position.extend(RenderLayer::Items.z())),
+                Collider {
+                    min: -PLACED_ITEM_HALF_SIZE,
+                    max: PLACED_ITEM_HALF_SIZE,
+                },
+            ));
+            if let Some(sprite) = sprite {
+                dropped.insert(sprite);
+            }
+        }
+
+        sound_events.write(PlaySoundEvent {
+            cue: SoundCue::WoodSplinter,
+        });
+    }
+}
+
+/// System that tumbles debris under gravity and despawns it
+///
+/// # System Dependencies
+/// - **Upstream**: `break_system` spawns debris
+/// - **Components**: Writes `DebrisParticle` and `Transform`
+pub fn debris_particle_system(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut DebrisParticle, &mut Transform)>,
+) {
+    let delta = time.delta_secs();
+    for (entity, mut particle, mut transform) in &mut query {
+        particle.velocity.y -= DEBRIS_GRAVITY * delta;
+        transform.translation += (particle.velocity * delta).extend(0.0);
+        transform.rotate_z(particle.velocity.x.signum() * 8.0 * delta);
+
+        particle.lifetime.tick(time.delta());
+        if particle.lifetime.finished() {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BOARDS: LevelEntityId = LevelEntityId { room: 3, index: 2 };
+
+    fn setup_app() -> App {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, BreakablePlugin));
+        app.init_resource::<RoomStateStore>();
+        app
+    }
+
+    fn debris(app: &mut App) -> usize {
+        let mut query = app.world_mut().query::<&DebrisParticle>();
+        query.iter(app.world()).count()
+    }
+
+    #[test]
+    fn boards_only_come_off_with_a_crowbar() {
+        let mut app = setup_app();
+        let boards = app
+            .world_mut()
+            .spawn((
+                Breakable {
+                    contains: Some(Item::Match),
+                    ..default()
+                },
+                BOARDS,
+                Transform::from_xyz(64.0, 32.0, 20.0),
+            ))
+            .id();
+        let player = app
+            .world_mut()
+            .spawn((
+                Player,
+                Inventory {
+                    items: vec![],
+                    max_capacity: 10,
+                },
+            ))
+            .id();
+        let pry = BreakEvent {
+            target: boards,
+            player,
+        };
+
+        app.world_mut().send_event(pry);
+        app.update();
+        assert!(app.world().get_entity(boards).is_ok(), "No crowbar yet");
+        assert_eq!(debris(&mut app), 0);
+
+        app.world_mut()
+            .get_mut::<Inventory>(player)
+            .unwrap()
+            .items
+            .push(Item::Tool(ToolType::Crowbar));
+        app.world_mut().send_event(pry);
+        app.update();
+        assert!(app.world().get_entity(boards).is_err());
+        assert_eq!(debris(&mut app), DEBRIS_PIECES);
+        assert!(app.world().resource::<RoomStateStore>().is_broken(BOARDS));
+
+        let mut items = app
+            .world_mut()
+            .query_filtered::<(&Item, &Transform), With<Collectible>>();
+        let (item, transform) = items.single(app.world()).unwrap();
+        assert!(matches!(item, Item::Match));
+        assert_eq!(transform.translation.truncate(), Vec2::new(64.0, 32.0));
+    }
+
+    #[test]
+    fn spent_debris_is_cleaned_up() {
+        let mut app = setup_app();
+        let mut lifetime = Timer::from_seconds(DEBRIS_LIFETIME, TimerMode::Once);
+        lifetime.tick(std::time::Duration::from_secs_f32(DEBRIS_LIFETIME));
+        let piece = app
+            .world_mut()
+            .spawn((
+                DebrisParticle {
+                    velocity: Vec2::new(50.0, 120.0),
+                    lifetime,
+                },
+                Transform::default(),
+            ))
+            .id();
+
+        app.update();
+        assert!(app.world().get_entity(piece).is_err());
+    }
+}
@@ -0,0 +1,192 @@
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+use leafwing_input_manager::prelude::*;
+
+use crate::components::lighting::{Candle, CarriedCandle};
+use crate::components::player::Player;
+use crate::resources::difficulty::DifficultyConfig;
+use crate::resources::input_config::PlayerAction;
+use crate::systems::light_bake::LightCone;
+
+/// How the candle's light is shaped
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CandleAimMode {
+    /// Omnidirectional light using the candle's `VisibilityRadius`
+    #[default]
+    AutoLight,
+    /// Narrow, longer cone pointed toward the mouse cursor
+    MouseAim,
+}
+
+/// Resource holding the candle's aim mode and direction
+///
+/// Toggled at runtime with `PlayerAction::ToggleAim`. The lighting shader and
+/// the visibility map both read it through `CandleAim::light_shape`.
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct CandleAim {
+    /// Current light shape
+    pub mode: CandleAimMode,
+    /// Unit vector from the candle toward the cursor
+    pub direction: Vec2,
+}
+
+impl Default for CandleAim {
+    fn default() -> Self {
+        Self {
+            mode: CandleAimMode::AutoLight,
+            direction: Vec2::X,
+        }
+    }
+}
+
+impl CandleAim {
+    /// Returns the candle's light radius and cone for its base `radius`
+    ///
+    /// Auto-light keeps the radius and shines in every direction. Mouse aim
+    /// stretches the radius by `candle_cone_range_multiplier` and limits the
+    /// light to a cone of `candle_cone_half_angle` around `direction`.
+    pub fn light_shape(&self, radius: f32, config: &DifficultyConfig) -> (f32, Option<LightCone>) {
+        match self.mode {
+            CandleAimMode::AutoLight => (radius, None),
+            CandleAimMode::MouseAim => (
+                radius * config.candle_cone_range_multiplier,
+                Some(LightCone {
+                    direction: self.direction,
+                    half_angle: config.candle_cone_half_angle,
+                }),
+            ),
+        }
+    }
+
+    /// Returns the light radius and cone of a candle that may be carried
+    ///
+    /// Only the player's candle is aimed; candles carried by an NPC or a
+    /// lure keep their base `radius` and shine in every direction.
+    pub fn candle_light_shape(
+        &self,
+        radius: f32,
+        carried: Option<&CarriedCandle>,
+        config: &DifficultyConfig,
+    ) -> (f32, Option<LightCone>) {
+        if carried.is_none_or(CarriedCandle::is_players) {
+            self.light_shape(radius, config)
+        } else {
+            (radius, None)
+        }
+    }
+}
+
+/// System that switches between auto-light and mouse aim
+///
+/// # System Dependencies
+/// - **Components**: Reads `ActionState<PlayerAction>` on the player
+/// - **Resources**: Writes `CandleAim`
+pub fn toggle_candle_aim_system(
+    player_query: Query<&ActionState<PlayerAction>, With<Player>>,
+    mut aim: ResMut<CandleAim>,
+) {
+    for actions in &player_query {
+        if actions.just_pressed(&PlayerAction::ToggleAim) {
+            aim.mode = match aim.mode {
+                CandleAimMode::AutoLight => CandleAimMode::MouseAim,
+                CandleAimMode::MouseAim => CandleAimMode::AutoLight,
+            };
+            info!("Candle aim mode: {:?}", aim.mode);
+        }
+    }
+}
+
+/// System that points the aimed candle toward the mouse cursor
+///
+/// Converts the cursor position to world coordinates through the first
+/// camera and stores the direction from the player's candle in `CandleAim`.
+/// The last direction is kept while the cursor is outside the window or on
+/// the candle.
+///
+/// # System Dependencies
+/// - **Components**: Reads `Window`, `Camera`, `Candle` transforms
+/// - **Resources**: Writes `CandleAim`
+/// - **Downstream**: `update_lighting_system`, `composite_visibility_system`
+pub fn candle_aim_system(
+    windows: Query<&Window, With<PrimaryWindow>>,
+    cameras: Query<(&Camera, &GlobalTransform)>,
+    candles: Query<(&Transform, Option<&CarriedCandle>), With<Candle>>,
+    mut aim: ResMut<CandleAim>,
+) {
+    if aim.mode != CandleAimMode::MouseAim {
+        return;
+    }
+
+    let Some(cursor) = windows.iter().next().and_then(Window::cursor_position) else {
+        return;
+    };
+    let Some((camera, camera_transform)) = cameras.iter().next() else {
+        return;
+    };
+    let Ok(target) = camera.viewport_to_world_2d(camera_transform, cursor) else {
+        return;
+    };
+    let Some((candle, _)) = candles
+        .iter()
+        .find(|(_, carried)| carried.is_none_or(CarriedCandle::is_players))
+    else {
+        return;
+    };
+
+    if let Some(direction) = (target - candle.translation.truncate()).try_normalize() {
+        aim.direction = direction;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn aimed_light_is_narrow_but_longer() {
+        let config = DifficultyConfig::default();
+        let mut aim = CandleAim::default();
+        assert_eq!(aim.light_shape(100.0, &config), (100.0, None));
+
+        aim.mode = CandleAimMode::MouseAim;
+        aim.direction = Vec2::Y;
+        let (radius, cone) = aim.light_shape(100.0, &config);
+        assert_eq!(radius, 100.0 * config.candle_cone_range_multiplier);
+        assert_eq!(
+            cone,
+            Some(LightCone {
+                direction: Vec2::Y,
+                half_angle: config.candle_cone_half_angle,
+            })
+        );
+
+        // Candles carried by someone else aren't aimed
+        let survivor = CarriedCandle::held_by(Entity::from_raw(7));
+        assert_eq!(
+            aim.candle_light_shape(100.0, Some(&survivor), &config),
+            (100.0, None)
+        );
+        assert_eq!(
+            aim.candle_light_shape(100.0, Some(&CarriedCandle::default()), &config),
+            (radius, cone)
+        );
+    }
+
+    #[test]
+    fn toggle_action_switches_aim_mode() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.init_resource::<CandleAim>();
+        app.add_systems(Update, toggle_candle_aim_system);
+
+        let mut actions = ActionState::<PlayerAction>::default();
+        actions.press(&PlayerAction::ToggleAim);
+        app.world_mut().spawn((Player, actions));
+        app.update();
+
+        assert_eq!(
+            app.world().resource::<CandleAim>().mode,
+            CandleAimMode::MouseAim
+        );
+    }
+}
@@ -1,16 +1,247 @@
 use crate::components::lighting::*;
+use crate::components::player::{Player, Stamina, Velocity};
+use crate::components::room::Collider;
+use crate::resources::game_rules::GameRules;
 use crate::resources::game_state::{GameMode, GameState};
+use crate::systems::game_set::GameSet;
+use crate::systems::hiding::Hidden;
+use crate::systems::victory::NewGamePlus;
+use crate::systems::water::InWater;
+use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
 
+/// Visibility radius of a lit candle whose flame is sputtering (see `FlameSputter`)
+pub const SPUTTER_VISIBILITY_RADIUS: f32 = 3.5;
+
+/// Plugin that burns down lit candles
+///
+/// `candle_burn_system` runs in `GameSet::Gameplay`, applying the rule
+/// set, difficulty and every burn modifier to each lit candle.
+pub struct CandleBurnPlugin;
+
+impl Plugin for CandleBurnPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<GameState>()
+            .add_systems(Update, candle_burn_system.in_set(GameSet::Gameplay));
+    }
+}
+
+/// Burn rate multiplier while the candle's holder walks
+pub const MOVING_BURN_MULTIPLIER: f32 = 1.25;
+
+/// Burn rate multiplier while the candle's holder stands still
+pub const STILL_BURN_MULTIPLIER: f32 = 0.85;
+
+/// Burn rate multiplier while the holder sprints (see `Stamina`) or moves
+/// faster than `SPRINT_SPEED`
+pub const SPRINT_BURN_MULTIPLIER: f32 = 1.6;
+
+/// Horizontal speed (pixels per second) above walking pace that counts as
+/// sprinting (grapple swings, slides and knockback)
+pub const SPRINT_SPEED: f32 = 240.0;
+
+/// Burn rate multiplier while the holder wades in water (the flame gutters)
+pub const WET_BURN_MULTIPLIER: f32 = 1.3;
+
+/// Burn rate multiplier while the holder shelters in a hiding spot
+pub const SHELTERED_BURN_MULTIPLIER: f32 = 0.5;
+
+/// A candle's burn rate split into its base rate and modifiers
+///
+/// Returned by `burn_rate_breakdown` so the HUD (and debugging tools) can
+/// show how fast the candle is burning and why, using the same numbers as
+/// `candle_burn_system`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BurnRateBreakdown {
+    /// The candle's own `BurnRate` (wax percentage per second)
+    pub base: f32,
+    /// Multiplier from the holder standing still, moving or sprinting (see
+    /// `movement_burn_multiplier`)
+    pub movement: f32,
+    /// Multiplier from a `Draft` on the candle (1.0 without one)
+    pub draft: f32,
+    /// Product of the `BurnModifier`s and wet or sheltered multipliers that
+    /// apply to the candle (see `BurnContext`)
+    pub environment: f32,
+    /// Multiplier from `GameRules::burn_multiplier` (rule set and
+    /// difficulty) and the New Game+ cycle (`NewGamePlus::burn_multiplier`)
+    pub difficulty: f32,
+}
+
+impl BurnRateBreakdown {
+    /// Returns the wax percentage burned per second with all modifiers
+    pub fn effective(&self) -> f32 {
+        self.base * self.movement * self.draft * self.environment * self.difficulty
+    }
+
+    /// Returns the seconds until `wax` burns out at the effective rate
+    ///
+    /// `None` when the candle isn't burning down (zero or negative rate).
+    pub fn seconds_remaining(&self, wax: f32) -> Option<f32> {
+        let rate = self.effective();
+        (rate > 0.0).then(|| wax.max(0.0) / rate)
+    }
+}
+
+/// Context-dependent burn multipliers of one candle, from `BurnContext`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BurnConditions {
+    /// Multiplier from how fast the holder moves
+    pub movement: f32,
+    /// Product of every other multiplier from the candle's surroundings
+    pub environment: f32,
+}
+
+impl Default for BurnConditions {
+    fn default() -> Self {
+        Self {
+            movement: 1.0,
+            environment: 1.0,
+        }
+    }
+}
+
+/// Computes a candle's current burn rate and its modifiers
+///
+/// # Arguments
+/// * `burn_rate` - The candle's base `BurnRate`
+/// * `conditions` - Movement and surroundings of the candle
+/// * `draft` - The candle's `Draft`, if it is in one
+/// * `rules` - Game rules with the rule set's burn and the chosen
+///   `Difficulty` (Normal when `None`)
+/// * `new_game_plus` - New Game+ progress (the first run when `None`)
+pub fn burn_rate_breakdown(
+    burn_rate: &BurnRate,
+    conditions: BurnConditions,
+    draft: Option<&Draft>,
+    rules: Option<&GameRules>,
+    new_game_plus: Option<&NewGamePlus>,
+) -> BurnRateBreakdown {
+    BurnRateBreakdown {
+        base: burn_rate.0,
+        movement: conditions.movement,
+        draft: draft.map_or(1.0, |draft| draft.0),
+        environment: conditions.environment,
+        difficulty: rules.map_or(1.0, GameRules::burn_multiplier)
+            * new_game_plus.map_or(1.0, NewGamePlus::burn_multiplier),
+    }
+}
+
+/// Burn multiplier for a holder moving at `velocity`
+///
+/// `STILL_BURN_MULTIPLIER` at rest, `SPRINT_BURN_MULTIPLIER` above
+/// `SPRINT_SPEED`, otherwise `MOVING_BURN_MULTIPLIER`.
+pub fn movement_burn_multiplier(velocity: Vec2) -> f32 {
+    if velocity == Vec2::ZERO {
+        STILL_BURN_MULTIPLIER
+    } else if velocity.x.abs() > SPRINT_SPEED {
+        SPRINT_BURN_MULTIPLIER
+    } else {
+        MOVING_BURN_MULTIPLIER
+    }
+}
+
+/// System parameter gathering what changes how fast a candle burns
+///
+/// A candle's holder is the character carrying it (`CarriedCandle`), or the
+/// player for candles nobody carries. The holder's movement (sprinting, see
+/// `Stamina`, always counts as `SPRINT_BURN_MULTIPLIER`), wading (`InWater`)
+/// and sheltering (`Hidden`) modify the burn, and so does every
+/// `BurnModifier` on the candle, on the holder, and on `BurnZone`s the
+/// candle is in.
+#[derive(SystemParam)]
+pub struct BurnContext<'w, 's> {
+    players: Query<'w, 's, Entity, With<Player>>,
+    holders: Query<
+        'w,
+        's,
+        (
+            Option<&'static Velocity>,
+            Option<&'static Stamina>,
+            Has<InWater>,
+            Has<Hidden>,
+            Option<&'static BurnModifier>,
+        ),
+    >,
+    zones: Query<
+        'w,
+        's,
+        (
+            &'static BurnModifier,
+            Option<&'static Transform>,
+            Option<&'static Collider>,
+        ),
+        With<BurnZone>,
+    >,
+}
+
+impl BurnContext<'_, '_> {
+    /// Burn conditions of a candle at `position` with its own `modifier`
+    ///
+    /// Zones with a `Collider` only apply when the candle's position is
+    /// known and inside them; zones without one cover the whole room.
+    pub fn conditions(
+        &self,
+        position: Option<Vec2>,
+        modifier: Option<&BurnModifier>,
+        carried: Option<&CarriedCandle>,
+    ) -> BurnConditions {
+        let mut conditions = BurnConditions {
+            environment: modifier.map_or(1.0, |modifier| modifier.0),
+            ..default()
+        };
+
+        let holder = carried
+            .and_then(|carried| carried.holder)
+            .or_else(|| self.players.iter().next());
+        if let Some((velocity, stamina, in_water, hidden, holder_modifier)) =
+            holder.and_then(|holder| self.holders.get(holder).ok())
+        {
+            conditions.movement = if stamina.is_some_and(|stamina| stamina.sprinting) {
+                SPRINT_BURN_MULTIPLIER
+            } else {
+                velocity.map_or(1.0, |velocity| movement_burn_multiplier(velocity.0))
+            };
+            if in_water {
+                conditions.environment *= WET_BURN_MULTIPLIER;
+            }
+            if hidden {
+                conditions.environment *= SHELTERED_BURN_MULTIPLIER;
+            }
+            conditions.environment *= holder_modifier.map_or(1.0, |modifier| modifier.0);
+        }
+
+        for (zone, transform, collider) in &self.zones {
+            let inside = match (collider, transform, position) {
+                (None, ..) => true,
+                (Some(collider), Some(transform), Some(position)) => {
+                    let offset = position - transform.translation.truncate();
+                    offset.cmpge(collider.min).all() && offset.cmple(collider.max).all()
+                }
+                _ => false,
+            };
+            if inside {
+                conditions.environment *= zone.0;
+            }
+        }
+        conditions
+    }
+}
+
 /// System for candle wax depletion and state transitions
 ///
 /// Handles:
-/// - Wax depletion over time when candle is lit
+/// - Wax depletion over time when candle is lit, at the rate given by
+///   `burn_rate_breakdown` (movement, drafts, `BurnContext` surroundings,
+///   difficulty and the New Game+ cycle)
 /// - Automatic extinguishing when wax reaches 0.0
 /// - Visibility radius updates based on candle state
+/// - Reduced radius while the flame is sputtering (`FlameSputter`)
+/// - No wax is burned when `GameRules::candle_depletion` is off (Explorer)
 /// - Only runs when game is in Playing mode (not Paused/Menu)
 ///
 /// From quickstart.md Test Scenario 1: Candle and Lighting System
+#[allow(clippy::type_complexity)]
 pub fn candle_burn_system(
     time: Res<Time>,
     game_state: Res<GameState>,
@@ -20,20 +251,44 @@ pub fn candle_burn_system(
             &mut CandleState,
             &mut VisibilityRadius,
             &BurnRate,
+            Option<&FlameSputter>,
+            Option<&Draft>,
+            Option<&BurnModifier>,
+            Option<&CarriedCandle>,
+            Option<&Transform>,
         ),
         With<Candle>,
     >,
+    context: BurnContext,
+    rules: Option<Res<GameRules>>,
+    new_game_plus: Option<Res<NewGamePlus>>,
 ) {
     // Don't process candle burn if game is not in Playing mode
     if game_state.game_mode != GameMode::Playing {
         return;
     }
 
-    for (mut wax, mut state, mut radius, burn_rate) in &mut query {
-        // Only deplete wax when candle is lit
-        if *state == CandleState::Lit {
-            // Deplete wax based on burn rate and delta time
-            wax.0 -= burn_rate.0 * time.delta_secs();
+    let depletion = rules.is_none_or(|rules| rules.candle_depletion);
+
+    for (mut wax, mut state, mut radius, burn_rate, sputter, draft, modifier, carried, transform) in
+        &mut query
+    {
+        // Only deplete wax when candle is lit (and the rules burn candles)
+        if depletion && *state == CandleState::Lit {
+            // Deplete wax based on the modified burn rate and delta time
+            let conditions = context.conditions(
+                transform.map(|transform| transform.translation.truncate()),
+                modifier,
+                carried,
+            );
+            let rate = burn_rate_breakdown(
+                burn_rate,
+                conditions,
+                draft,
+                rules.as_deref(),
+                new_game_plus.as_deref(),
+            );
+            wax.0 -= rate.effective() * time.delta_secs();
             wax.0 = wax.0.max(0.0); // Clamp to 0, never negative
 
             // Check for auto-extinguish at 0 wax
@@ -47,6 +302,9 @@ pub fn candle_burn_system(
         // Update visibility radius based on candle state
         // This ensures radius is correct even if state changes externally
         match *state {
+            CandleState::Lit if sputter.is_some() => {
+                radius.0 = SPUTTER_VISIBILITY_RADIUS; // Flame shrinks while sputtering
+            }
             CandleState::Lit => {
                 radius.0 = 7.0; // Large visibility when lit
             }
@@ -60,6 +318,8 @@ pub fn candle_burn_system(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::resources::game_rules::Difficulty;
+    use crate::systems::victory::NEW_GAME_PLUS_BURN_STEP;
     use std::time::Duration;
 
     #[test]
@@ -282,4 +542,238 @@ mod tests {
 
         assert_eq!(radius, 1.5, "Unlit candle should have small radius");
     }
+
+    #[test]
+    fn sputtering_candle_has_reduced_radius() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+
+        app.insert_resource(GameState {
+            current_room: 0,
+            player_spawn_point: Vec2::new(100.0, 100.0),
+            completion_time: Duration::ZERO,
+            collected_secrets: std::collections::HashSet::new(),
+            game_mode: GameMode::Playing,
+            deaths: 0,
+        });
+
+        app.add_systems(Update, candle_burn_system);
+
+        let candle_entity = app
+            .world_mut()
+            .spawn((
+                Candle,
+                CandleWax(100.0),
+                CandleState::Lit,
+                BurnRate(1.0),
+                VisibilityRadius(7.0),
+                FlameSputter(Timer::from_seconds(1.0, TimerMode::Once)),
+            ))
+            .id();
+
+        app.update();
+
+        let radius = app.world().get::<VisibilityRadius>(candle_entity).unwrap();
+        assert_eq!(radius.0, SPUTTER_VISIBILITY_RADIUS);
+
+        // Radius recovers once the sputter ends
+        app.world_mut()
+            .entity_mut(candle_entity)
+            .remove::<FlameSputter>();
+        app.update();
+
+        let radius = app.world().get::<VisibilityRadius>(candle_entity).unwrap();
+        assert_eq!(radius.0, 7.0);
+    }
+
+    #[test]
+    fn explorer_rules_keep_candles_from_burning() {
+        use crate::resources::game_rules::GameRuleSet;
+
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.insert_resource(GameState {
+            game_mode: GameMode::Playing,
+            ..Default::default()
+        });
+        app.insert_resource(GameRules::for_rule_set(GameRuleSet::Explorer));
+        app.add_systems(Update, candle_burn_system);
+
+        let candle_entity = app
+            .world_mut()
+            .spawn((
+                Candle,
+                CandleWax(100.0),
+                CandleState::Lit,
+                BurnRate(50.0),
+                VisibilityRadius(7.0),
+            ))
+            .id();
+
+        for _ in 0..10 {
+            app.update();
+        }
+
+        assert_eq!(
+            app.world().get::<CandleWax>(candle_entity).unwrap().0,
+            100.0
+        );
+        assert_eq!(
+            *app.world().get::<CandleState>(candle_entity).unwrap(),
+            CandleState::Lit
+        );
+    }
+
+    #[test]
+    fn burn_rate_breakdown_applies_all_modifiers() {
+        let rules = GameRules::default().with_difficulty(Difficulty::Hard);
+        let conditions = BurnConditions {
+            movement: MOVING_BURN_MULTIPLIER,
+            environment: 0.5,
+        };
+        let rate = burn_rate_breakdown(
+            &BurnRate(1.0),
+            conditions,
+            Some(&Draft(1.5)),
+            Some(&rules),
+            Some(&NewGamePlus {
+                cycle: 2,
+                ..default()
+            }),
+        );
+
+        assert_eq!(
+            rate.effective(),
+            MOVING_BURN_MULTIPLIER
+                * 1.5
+                * 0.5
+                * Difficulty::Hard.burn_multiplier()
+                * (1.0 + 2.0 * NEW_GAME_PLUS_BURN_STEP)
+        );
+        assert_eq!(rate.seconds_remaining(60.0), Some(60.0 / rate.effective()));
+
+        let idle = burn_rate_breakdown(&BurnRate(0.0), default(), None, None, None);
+        assert_eq!(idle.effective(), 0.0);
+        assert_eq!(idle.seconds_remaining(60.0), None);
+    }
+
+    #[test]
+    fn burn_modifiers_and_zones_stack() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.insert_resource(GameState {
+            game_mode: GameMode::Playing,
+            ..default()
+        });
+        app.add_systems(Update, candle_burn_system);
+
+        // A windy corner and a drafty room, both covering the candle
+        app.world_mut().spawn((
+            BurnZone,
+            BurnModifier(2.0),
+            Transform::from_xyz(100.0, 100.0, 0.0),
+            Collider {
+                min: Vec2::splat(-50.0),
+                max: Vec2::splat(50.0),
+            },
+        ));
+        app.world_mut().spawn((BurnZone, BurnModifier(1.5)));
+        let candle = |transform: Transform, modifier: f32| {
+            (
+                Candle,
+                CandleWax(100.0),
+                CandleState::Lit,
+                BurnRate(1.0),
+                VisibilityRadius(7.0),
+                BurnModifier(modifier),
+                transform,
+            )
+        };
+        let outside = app
+            .world_mut()
+            .spawn(candle(Transform::from_xyz(500.0, 100.0, 0.0), 1.0))
+            .id();
+        let inside = app
+            .world_mut()
+            .spawn(candle(Transform::from_xyz(120.0, 90.0, 0.0), 0.5))
+            .id();
+
+        for _ in 0..10 {
+            app.update();
+        }
+
+        let burned = |entity| 100.0 - app.world().get::<CandleWax>(entity).unwrap().0;
+        assert!(burned(outside) > 0.0);
+        // 1.5 (room) outside; 1.5 (room) * 2.0 (corner) * 0.5 (candle) inside
+        assert!((burned(inside) - burned(outside)).abs() < 1e-3);
+    }
+
+    #[test]
+    fn movement_burns_slower_standing_and_faster_sprinting() {
+        assert_eq!(movement_burn_multiplier(Vec2::ZERO), STILL_BURN_MULTIPLIER);
+        assert_eq!(
+            movement_burn_multiplier(Vec2::new(200.0, 0.0)),
+            MOVING_BURN_MULTIPLIER
+        );
+        assert_eq!(
+            movement_burn_multiplier(Vec2::new(-300.0, 0.0)),
+            SPRINT_BURN_MULTIPLIER
+        );
+        assert!(STILL_BURN_MULTIPLIER < MOVING_BURN_MULTIPLIER);
+        assert!(MOVING_BURN_MULTIPLIER < SPRINT_BURN_MULTIPLIER);
+    }
+
+    #[test]
+    fn sprinting_holder_burns_at_the_sprint_rate() {
+        let mut world = World::new();
+        let player = world.spawn((Player, Velocity(Vec2::new(100.0, 0.0)))).id();
+        let mut context = bevy::ecs::system::SystemState::<BurnContext>::new(&mut world);
+        assert_eq!(
+            context.get(&world).conditions(None, None, None).movement,
+            MOVING_BURN_MULTIPLIER
+        );
+
+        world.get_mut::<Stamina>(player).unwrap().sprinting = true;
+        assert_eq!(
+            context.get(&world).conditions(None, None, None).movement,
+            SPRINT_BURN_MULTIPLIER
+        );
+    }
+
+    #[test]
+    fn plugin_burns_lit_candles_except_under_explorer_rules() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, CandleBurnPlugin));
+        app.insert_resource(bevy::time::TimeUpdateStrategy::ManualDuration(
+            Duration::from_millis(100),
+        ));
+        app.insert_resource(GameState {
+            game_mode: GameMode::Playing,
+            ..default()
+        });
+        let candle = app
+            .world_mut()
+            .spawn((
+                Candle,
+                CandleWax(100.0),
+                CandleState::Lit,
+                BurnRate(1.0),
+                VisibilityRadius(7.0),
+            ))
+            .id();
+
+        for _ in 0..5 {
+            app.update();
+        }
+        let burned = app.world().get::<CandleWax>(candle).unwrap().0;
+        assert!(burned < 100.0);
+
+        app.insert_resource(GameRules::for_rule_set(
+            crate::resources::game_rules::GameRuleSet::Explorer,
+        ));
+        for _ in 0..5 {
+            app.update();
+        }
+        assert_eq!(app.world().get::<CandleWax>(candle).unwrap().0, burned);
+    }
 }
@@ -0,0 +1,226 @@
+use crate::audio::sound_bank::{PlaySoundEvent, SoundCue};
+use crate::components::player::{Health, Player};
+use crate::components::room::{Checkpoint, Collider};
+use crate::resources::game_state::{GameMode, GameState};
+use crate::systems::collision::aabb_intersects;
+use crate::systems::save_load::AutoSaveEvent;
+use bevy::prelude::*;
+
+/// Sprite tint for a checkpoint the player has not reached (or has moved past)
+pub const CHECKPOINT_INACTIVE_COLOR: Color = Color::srgb(0.4, 0.4, 0.5);
+
+/// Sprite tint for the currently active checkpoint
+pub const CHECKPOINT_ACTIVE_COLOR: Color = Color::srgb(1.0, 0.85, 0.3);
+
+/// Event emitted when the player activates a checkpoint
+///
+/// Useful for UI feedback (e.g., a "Checkpoint reached" toast).
+#[derive(Event, Debug)]
+pub struct CheckpointReachedEvent {
+    /// The checkpoint that was activated
+    pub checkpoint: Entity,
+    /// The player who reached it
+    pub player: Entity,
+}
+
+/// System that activates checkpoints the player touches
+///
+/// # Behavior
+/// When a living player overlaps a checkpoint that is not already active:
+/// 1. `GameState::player_spawn_point` is moved to the checkpoint position
+/// 2. The checkpoint becomes the only active one (others are deactivated)
+/// 3. An `AutoSaveEvent` is emitted
+/// 4. The checkpoint sprite is tinted and `SoundCue::Checkpoint` plays
+/// 5. A `CheckpointReachedEvent` is emitted
+///
+/// # System Dependencies
+/// - **Components**: Reads `Player`, `Health`, `Collider`; writes `Checkpoint`, `Sprite`
+/// - **Resources**: Writes `GameState`
+/// - **Downstream**: `auto_save_system` persists the new spawn point,
+///   `respawn_system` uses it on the next death
+#[allow(clippy::type_complexity)]
+pub fn checkpoint_system(
+    mut game_state: ResMut<GameState>,
+    player_query: Query<(Entity, &Transform, &Collider, &Health), With<Player>>,
+    mut checkpoint_query: Query<
+        (
+            Entity,
+            &Transform,
+            &Collider,
+            &mut Checkpoint,
+            Option<&mut Sprite>,
+        ),
+        Without<Player>,
+    >,
+    mut auto_save_events: EventWriter<AutoSaveEvent>,
+    mut reached_events: EventWriter<CheckpointReachedEvent>,
+    mut sound_events: EventWriter<PlaySoundEvent>,
+) {
+    if game_state.game_mode != GameMode::Playing {
+        return;
+    }
+
+    for (player, player_transform, player_collider, health) in &player_query {
+        if *health != Health::Alive {
+            continue;
+        }
+
+        let player_pos = player_transform.translation.truncate();
+        let reached = checkpoint_query
+            .iter()
+            .find(|(_, transform, collider, checkpoint, _)| {
+                !checkpoint.activated
+                    && aabb_intersects(
+                        player_pos,
+                        player_collider,
+                        transform.translation.truncate(),
+                        collider,
+                    )
+            })
+            .map(|(entity, transform, ..)| (entity, transform.translation.truncate()));
+
+        let Some((reached, position)) = reached else {
+            continue;
+        };
+
+        for (entity, _, _, mut checkpoint, sprite) in &mut checkpoint_query {
+            checkpoint.activated = entity == reached;
+            if let Some(mut sprite) = sprite {
+                sprite.color = if checkpoint.activated {
+                    CHECKPOINT_ACTIVE_COLOR
+                } else {
+                    CHECKPOINT_INACTIVE_COLOR
+                };
+            }
+        }
+
+        game_state.player_spawn_point = position;
+        info!("Checkpoint reached at {:?}", position);
+
+        auto_save_events.write(AutoSaveEvent);
+        reached_events.write(CheckpointReachedEvent {
+            checkpoint: reached,
+            player,
+        });
+        sound_events.write(PlaySoundEvent {
+            cue: SoundCue::Checkpoint,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn setup_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_event::<AutoSaveEvent>();
+        app.add_event::<CheckpointReachedEvent>();
+        app.add_event::<PlaySoundEvent>();
+        app.insert_resource(GameState {
+            game_mode: GameMode::Playing,
+            ..Default::default()
+        });
+        app.add_systems(Update, checkpoint_system);
+        app
+    }
+
+    fn collider(half: f32) -> Collider {
+        Collider {
+            min: Vec2::splat(-half),
+            max: Vec2::splat(half),
+        }
+    }
+
+    fn spawn_checkpoint(app: &mut App, position: Vec2) -> Entity {
+        app.world_mut()
+            .spawn((
+                Checkpoint::default(),
+                Transform::from_translation(position.extend(0.0)),
+                collider(16.0),
+                Sprite::default(),
+            ))
+            .id()
+    }
+
+    fn spawn_player(app: &mut App, position: Vec2) -> Entity {
+        app.world_mut()
+            .spawn((
+                Player,
+                Health::Alive,
+                Transform::from_translation(position.extend(0.0)),
+                collider(8.0),
+            ))
+            .id()
+    }
+
+    #[test]
+    fn touching_checkpoint_updates_spawn_and_autosaves() {
+        let mut app = setup_app();
+        let checkpoint = spawn_checkpoint(&mut app, Vec2::new(200.0, 50.0));
+        spawn_player(&mut app, Vec2::new(205.0, 50.0));
+
+        app.update();
+
+        assert_eq!(
+            app.world().resource::<GameState>().player_spawn_point,
+            Vec2::new(200.0, 50.0)
+        );
+        assert!(app.world().get::<Checkpoint>(checkpoint).unwrap().activated);
+        assert_eq!(
+            app.world().get::<Sprite>(checkpoint).unwrap().color,
+            CHECKPOINT_ACTIVE_COLOR
+        );
+        let saves = app.world().resource::<Events<AutoSaveEvent>>();
+        assert_eq!(saves.len(), 1);
+    }
+
+    #[test]
+    fn active_checkpoint_does_not_retrigger() {
+        let mut app = setup_app();
+        spawn_checkpoint(&mut app, Vec2::ZERO);
+        spawn_player(&mut app, Vec2::ZERO);
+
+        app.update();
+        app.update();
+
+        let events = app.world().resource::<Events<CheckpointReachedEvent>>();
+        let mut cursor = events.get_cursor();
+        assert_eq!(cursor.read(events).count(), 1);
+    }
+
+    #[test]
+    fn new_checkpoint_deactivates_previous() {
+        let mut app = setup_app();
+        let first = spawn_checkpoint(&mut app, Vec2::ZERO);
+        let second = spawn_checkpoint(&mut app, Vec2::new(500.0, 0.0));
+        let player = spawn_player(&mut app, Vec2::ZERO);
+
+        app.update();
+        app.world_mut()
+            .get_mut::<Transform>(player)
+            .unwrap()
+            .translation = Vec3::new(500.0, 0.0, 0.0);
+        app.update();
+
+        assert!(!app.world().get::<Checkpoint>(first).unwrap().activated);
+        assert!(app.world().get::<Checkpoint>(second).unwrap().activated);
+        assert_eq!(
+            app.world().resource::<GameState>().player_spawn_point,
+            Vec2::new(500.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn dead_player_does_not_activate_checkpoint() {
+        let mut app = setup_app();
+        let checkpoint = spawn_checkpoint(&mut app, Vec2::ZERO);
+        let player = spawn_player(&mut app, Vec2::ZERO);
+        app.world_mut().entity_mut(player).insert(Health::Dead);
+
+        app.update();
+
+        assert!(!app.world().get::<Checkpoint>(checkpoint).unwrap().activated);
+    }
+}
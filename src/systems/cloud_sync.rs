@@ -0,0 +1,700 @@
+use bevy::prelude::*;
+use bevy::tasks::IoTaskPool;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use crate::systems::save_storage::{
+    CLOUD_SYNC_FILE_NAME, MemoryStorage, SaveBackend, SaveStorage, save_file_name,
+    save_meta_file_name,
+};
+
+/// Name of the manifest recording when each synced file last changed, kept
+/// both locally and on the remote
+pub const SYNC_MANIFEST_FILE_NAME: &str = "sync_manifest.ron";
+
+/// Seconds between syncs picking up saves made on other machines
+pub const CLOUD_SYNC_INTERVAL_SECS: f32 = 300.0;
+
+/// Plugin that mirrors the save slots to the endpoint in `CLOUD_SYNC_FILE_NAME`
+///
+/// The settings are read from the `SaveBackend` (the platform storage
+/// unless one was inserted earlier). With an endpoint configured, the
+/// `SaveBackend` becomes a `SyncedSaveStorage` over that storage and
+/// `CloudSync` is inserted; without one the backend is left as it was. Add
+/// it before the plugins that read the `SaveBackend` when they are built
+/// (profiles, accessibility, telemetry, achievements) and `SaveLoadPlugin`.
+///
+/// Players configure sync by editing `cloud_sync.ron` in the data
+/// directory, e.g. `(endpoint: Some("https://dav.example.com/rust-game"))`.
+/// HTTP remotes need the `cloud-sync` feature.
+pub struct CloudSyncPlugin;
+
+impl Plugin for CloudSyncPlugin {
+    fn build(&self, app: &mut App) {
+        let local = app
+            .world_mut()
+            .remove_resource::<SaveBackend>()
+            .unwrap_or_default();
+        let settings = CloudSyncSettings::load(&local);
+        let backend = match settings.remote() {
+            Ok(Some(remote)) => {
+                let storage = SyncedSaveStorage::new(local, remote);
+                app.insert_resource(CloudSync::new(storage.clone()));
+                SaveBackend::new(storage)
+            }
+            Ok(None) => local,
+            Err(e) => {
+                warn!("{}", e);
+                local
+            }
+        };
+        app.insert_resource(backend)
+            .insert_resource(settings)
+            .add_systems(Update, cloud_sync_system);
+    }
+}
+
+/// Resource holding where save slots are synced to, read from
+/// `CLOUD_SYNC_FILE_NAME`
+#[derive(Resource, Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct CloudSyncSettings {
+    /// Base URL of an HTTP or WebDAV directory the slots are stored in
+    /// (`GET`/`PUT`/`DELETE` of `{endpoint}/{file name}`); `None` turns
+    /// sync off
+    #[serde(default)]
+    pub endpoint: Option<String>,
+    /// `Authorization` header sent with every request, e.g. `"Basic ..."` or
+    /// `"Bearer ..."`
+    #[serde(default)]
+    pub authorization: Option<String>,
+}
+
+impl CloudSyncSettings {
+    /// Reads the settings from `CLOUD_SYNC_FILE_NAME`
+    ///
+    /// A missing or unreadable file turns sync off.
+    pub fn load(storage: &impl SaveStorage) -> Self {
+        match storage.read(CLOUD_SYNC_FILE_NAME) {
+            Ok(contents) => ron::from_str(&contents).unwrap_or_else(|e| {
+                warn!(
+                    "Ignoring unreadable cloud sync settings '{}': {}",
+                    storage.location(CLOUD_SYNC_FILE_NAME),
+                    e
+                );
+                Self::default()
+            }),
+            Err(_) => Self::default(),
+        }
+    }
+
+    /// The remote to sync with, if an endpoint is set
+    ///
+    /// # Errors
+    /// Returns error string if an endpoint is set but this build can't reach
+    /// it (no `cloud-sync` feature, or a browser build)
+    pub fn remote(&self) -> Result<Option<Arc<dyn SyncRemote>>, String> {
+        self.endpoint
+            .as_deref()
+            .map(|endpoint| http_remote(endpoint, self.authorization.clone()))
+            .transpose()
+    }
+}
+
+#[cfg(all(feature = "cloud-sync", not(target_arch = "wasm32")))]
+fn http_remote(
+    endpoint: &str,
+    authorization: Option<String>,
+) -> Result<Arc<dyn SyncRemote>, String> {
+    Ok(Arc::new(HttpRemote::new(endpoint, authorization)))
+}
+
+#[cfg(not(all(feature = "cloud-sync", not(target_arch = "wasm32"))))]
+fn http_remote(
+    endpoint: &str,
+    _authorization: Option<String>,
+) -> Result<Arc<dyn SyncRemote>, String> {
+    Err(format!(
+        "Not syncing saves to '{}': this build has no cloud-sync support",
+        endpoint
+    ))
+}
+
+/// Remote copy of the synced save files, addressed by file name
+///
+/// Calls block on the network; `SyncedSaveStorage` only makes them from
+/// background tasks.
+pub trait SyncRemote: Send + Sync {
+    /// Fetches file `name`, or `None` if the remote doesn't have it
+    ///
+    /// # Errors
+    /// Returns error string if the remote can't be reached
+    fn get(&self, name: &str) -> Result<Option<String>, String>;
+
+    /// Stores `contents` as file `name`
+    ///
+    /// # Errors
+    /// Returns error string if the remote can't be reached or refuses the file
+    fn put(&self, name: &str, contents: &str) -> Result<(), String>;
+
+    /// Removes file `name` (removing a missing file is not an error)
+    ///
+    /// # Errors
+    /// Returns error string if the remote can't be reached or refuses
+    fn delete(&self, name: &str) -> Result<(), String>;
+}
+
+/// In-memory remote, for tests
+impl SyncRemote for MemoryStorage {
+    fn get(&self, name: &str) -> Result<Option<String>, String> {
+        Ok(self.read(name).ok())
+    }
+
+    fn put(&self, name: &str, contents: &str) -> Result<(), String> {
+        self.write(name, contents)
+    }
+
+    fn delete(&self, name: &str) -> Result<(), String> {
+        self.remove(name)
+    }
+}
+
+/// Remote speaking plain HTTP, which WebDAV servers accept too
+///
+/// Files live at `{endpoint}/{name}`; the directory must already exist.
+#[cfg(all(feature = "cloud-sync", not(target_arch = "wasm32")))]
+#[derive(Debug, Clone)]
+pub struct HttpRemote {
+    endpoint: String,
+    authorization: Option<String>,
+}
+
+#[cfg(all(feature = "cloud-sync", not(target_arch = "wasm32")))]
+impl HttpRemote {
+    /// Creates a remote for the directory at `endpoint`
+    pub fn new(endpoint: &str, authorization: Option<String>) -> Self {
+        Self {
+            endpoint: endpoint.trim_end_matches('/').to_string(),
+            authorization,
+        }
+    }
+
+    fn request(&self, method: &str, name: &str) -> ureq::Request {
+        let request = ureq::request(method, &format!("{}/{}", self.endpoint, name));
+        match &self.authorization {
+            Some(authorization) => request.set("Authorization", authorization),
+            None => request,
+        }
+    }
+}
+
+#[cfg(all(feature = "cloud-sync", not(target_arch = "wasm32")))]
+impl SyncRemote for HttpRemote {
+    fn get(&self, name: &str) -> Result<Option<String>, String> {
+        match self.request("GET", name).call() {
+            Ok(response) => response
+                .into_string()
+                .map(Some)
+                .map_err(|e| format!("Failed to download '{}': {}", name, e)),
+            Err(ureq::Error::Status(404, _)) => Ok(None),
+            Err(e) => Err(format!("Failed to download '{}': {}", name, e)),
+        }
+    }
+
+    fn put(&self, name: &str, contents: &str) -> Result<(), String> {
+        self.request("PUT", name)
+            .send_string(contents)
+            .map(|_| ())
+            .map_err(|e| format!("Failed to upload '{}': {}", name, e))
+    }
+
+    fn delete(&self, name: &str) -> Result<(), String> {
+        match self.request("DELETE", name).call() {
+            Ok(_) | Err(ureq::Error::Status(404, _)) => Ok(()),
+            Err(e) => Err(format!("Failed to delete remote '{}': {}", name, e)),
+        }
+    }
+}
+
+/// When a synced file last changed, and whether that change removed it
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SyncEntry {
+    /// Milliseconds since the Unix epoch
+    pub modified: u64,
+    /// Whether the file was deleted (kept so deletions sync too)
+    #[serde(default)]
+    pub deleted: bool,
+}
+
+/// Contents of `SYNC_MANIFEST_FILE_NAME`
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct SyncManifest {
+    /// Entry of each synced file, by file name
+    #[serde(default)]
+    pub files: BTreeMap<String, SyncEntry>,
+}
+
+/// What a sync does to one file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SyncAction {
+    /// The local file is newer: send it to the remote
+    Upload(String),
+    /// The remote file is newer: replace the local one
+    Download(String),
+    /// The file was deleted locally since the remote copy was written
+    DeleteRemote(String),
+    /// The file was deleted on another machine since the local copy was written
+    DeleteLocal(String),
+}
+
+/// Decides, file by file, which side of a sync wins
+///
+/// Conflicts are settled by timestamp: whichever side changed the file last
+/// wins, and files changed at the same moment are left alone.
+pub fn plan_sync(local: &SyncManifest, remote: &SyncManifest) -> Vec<SyncAction> {
+    let names: BTreeSet<&String> = local.files.keys().chain(remote.files.keys()).collect();
+    names
+        .into_iter()
+        .filter_map(|name| {
+            let local = local.files.get(name);
+            let remote = remote.files.get(name);
+            let live = |entry: Option<&SyncEntry>| entry.is_some_and(|entry| !entry.deleted);
+            let local_newer =
+                local.map(|entry| entry.modified) > remote.map(|entry| entry.modified);
+            let remote_newer =
+                remote.map(|entry| entry.modified) > local.map(|entry| entry.modified);
+            if local_newer && live(local) {
+                Some(SyncAction::Upload(name.clone()))
+            } else if local_newer && live(remote) {
+                Some(SyncAction::DeleteRemote(name.clone()))
+            } else if remote_newer && live(remote) {
+                Some(SyncAction::Download(name.clone()))
+            } else if remote_newer && live(local) {
+                Some(SyncAction::DeleteLocal(name.clone()))
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+/// Returns true for the files that are synced: each slot's save and metadata
+pub fn is_synced_file(name: &str) -> bool {
+    name.starts_with("save") && name.ends_with(".ron")
+}
+
+/// Where the last sync got to
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum SyncStatus {
+    /// No sync has run yet
+    #[default]
+    NotSynced,
+    /// A sync is running in the background
+    Syncing,
+    /// The last sync finished
+    Synced,
+    /// The last sync failed, with why
+    Failed(String),
+}
+
+/// Sync progress shared by a `SyncedSaveStorage` and its background syncs
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SyncState {
+    /// Where the last sync got to
+    pub status: SyncStatus,
+    /// Whether saves were written or deleted since the last sync started
+    pub pending: bool,
+}
+
+/// Save storage that keeps saves locally and mirrors the slots to a remote
+///
+/// Reads and writes go to the local storage straight away, recording the
+/// time of each change in the local `SYNC_MANIFEST_FILE_NAME`. `spawn_sync`
+/// then exchanges changed files with the remote on the IO task pool, so the
+/// game never waits on the network. Clones share the same sync state.
+#[derive(Clone)]
+pub struct SyncedSaveStorage {
+    local: Arc<dyn SaveStorage>,
+    remote: Arc<dyn SyncRemote>,
+    state: Arc<Mutex<SyncState>>,
+    clock: fn() -> u64,
+}
+
+impl SyncedSaveStorage {
+    /// Mirrors the slots of `local` to `remote`
+    pub fn new(local: impl SaveStorage + 'static, remote: Arc<dyn SyncRemote>) -> Self {
+        Self {
+            local: Arc::new(local),
+            remote,
+            state: Arc::new(Mutex::new(SyncState::default())),
+            clock: unix_millis,
+        }
+    }
+
+    /// Timestamps changes with `clock` (milliseconds) instead of the system
+    /// clock
+    pub fn with_clock(mut self, clock: fn() -> u64) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Current sync progress
+    pub fn state(&self) -> SyncState {
+        self.lock().clone()
+    }
+
+    /// Starts a sync on the IO task pool (or runs it right away when there
+    /// is no task pool), unless one is already running
+    pub fn spawn_sync(&self) {
+        {
+            let mut state = self.lock();
+            if state.status == SyncStatus::Syncing {
+                return;
+            }
+            state.status = SyncStatus::Syncing;
+            state.pending = false;
+        }
+
+        let storage = self.clone();
+        let sync = move || {
+            let result = storage.sync();
+            let mut state = storage.lock();
+            state.status = match result {
+                Ok(()) => SyncStatus::Synced,
+                Err(e) => {
+                    warn!("Cloud sync failed: {}", e);
+                    // Local changes are still to be sent
+                    state.pending = true;
+                    SyncStatus::Failed(e)
+                }
+            };
+        };
+        match IoTaskPool::try_get() {
+            Some(pool) => pool.spawn(async move { sync() }).detach(),
+            None => sync(),
+        }
+    }
+
+    /// Exchanges changed files with the remote, blocking on the network
+    ///
+    /// # Errors
+    /// Returns error string if the remote can't be reached or a file can't
+    /// be transferred; files already transferred stay in sync
+    pub fn sync(&self) -> Result<(), String> {
+        let local = {
+            let _state = self.lock();
+            self.local_manifest()
+        };
+        let mut remote = match self.remote.get(SYNC_MANIFEST_FILE_NAME)? {
+            Some(contents) => ron::from_str(&contents)
+                .map_err(|e| format!("Unreadable remote sync manifest: {}", e))?,
+            None => SyncManifest::default(),
+        };
+
+        let plan = plan_sync(&local, &remote);
+        let mut remote_changed = false;
+        for action in &plan {
+            match action {
+                SyncAction::Upload(name) => {
+                    let contents = {
+                        let _state = self.lock();
+                        self.local.read(name)?
+                    };
+                    self.remote.put(name, &contents)?;
+                    remote.files.insert(name.clone(), local.files[name]);
+                    remote_changed = true;
+                }
+                SyncAction::DeleteRemote(name) => {
+                    self.remote.delete(name)?;
+                    remote.files.insert(name.clone(), local.files[name]);
+                    remote_changed = true;
+                }
+                SyncAction::Download(name) => {
+                    let contents = self.remote.get(name)?.ok_or_else(|| {
+                        format!("Synced file '{}' is missing from the remote", name)
+                    })?;
+                    self.apply_remote(name, remote.files[name], Some(&contents), &local)?;
+                }
+                SyncAction::DeleteLocal(name) => {
+                    self.apply_remote(name, remote.files[name], None, &local)?;
+                }
+            }
+        }
+
+        if remote_changed {
+            let contents = ron::ser::to_string_pretty(&remote, ron::ser::PrettyConfig::default())
+                .map_err(|e| format!("Failed to serialize sync manifest: {}", e))?;
+            self.remote.put(SYNC_MANIFEST_FILE_NAME, &contents)?;
+        }
+        Ok(())
+    }
+
+    /// Writes (or with no `contents`, removes) local file `name` as the
+    /// remote has it, unless it changed locally since `seen` was read
+    fn apply_remote(
+        &self,
+        name: &str,
+        entry: SyncEntry,
+        contents: Option<&str>,
+        seen: &SyncManifest,
+    ) -> Result<(), String> {
+        let _state = self.lock();
+        let mut manifest = self.local_manifest();
+        // A save written while the sync ran is newer than the remote's copy
+        if manifest.files.get(name) != seen.files.get(name) {
+            return Ok(());
+        }
+        match contents {
+            Some(contents) => self.local.write(name, contents)?,
+            None => self.local.remove(name)?,
+        }
+        manifest.files.insert(name.to_string(), entry);
+        self.write_local_manifest(&manifest)
+    }
+
+    /// Local manifest, with synced files it doesn't list yet (saves made
+    /// before sync was set up) counted as changed at the epoch, so any
+    /// remote copy wins over them
+    fn local_manifest(&self) -> SyncManifest {
+        let mut manifest: SyncManifest = self
+            .local
+            .read(SYNC_MANIFEST_FILE_NAME)
+            .ok()
+            .and_then(|contents| ron::from_str(&contents).ok())
+            .unwrap_or_default();
+        for name in self.local.file_names() {
+            if is_synced_file(&name) {
+                manifest.files.entry(name).or_default();
+            }
+        }
+        manifest
+    }
+
+    fn write_local_manifest(&self, manifest: &SyncManifest) -> Result<(), String> {
+        let contents = ron::ser::to_string_pretty(manifest, ron::ser::PrettyConfig::default())
+            .map_err(|e| format!("Failed to serialize sync manifest: {}", e))?;
+        self.local.write(SYNC_MANIFEST_FILE_NAME, &contents)
+    }
+
+    /// Records that synced file `name` changed now; call with the state
+    /// locked
+    fn touch(&self, state: &mut SyncState, name: &str) -> Result<(), String> {
+        let mut manifest = self.local_manifest();
+        manifest.files.insert(
+            name.to_string(),
+            SyncEntry {
+                modified: (self.clock)(),
+                deleted: !self.local.exists(name),
+            },
+        );
+        state.pending = true;
+        self.write_local_manifest(&manifest)
+    }
+
+    fn lock(&self) -> MutexGuard<'_, SyncState> {
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl SaveStorage for SyncedSaveStorage {
+    fn read(&self, name: &str) -> Result<String, String> {
+        self.local.read(name)
+    }
+
+    fn write(&self, name: &str, contents: &str) -> Result<(), String> {
+        let mut state = self.lock();
+        self.local.write(name, contents)?;
+        if is_synced_file(name) {
+            self.touch(&mut state, name)?;
+        }
+        Ok(())
+    }
+
+    fn exists(&self, name: &str) -> bool {
+        self.local.exists(name)
+    }
+
+    fn location(&self, name: &str) -> String {
+        self.local.location(name)
+    }
+
+    fn remove(&self, name: &str) -> Result<(), String> {
+        let mut state = self.lock();
+        self.local.remove(name)?;
+        if is_synced_file(name) {
+            self.touch(&mut state, name)?;
+        }
+        Ok(())
+    }
+
+    fn file_names(&self) -> Vec<String> {
+        let mut names = self.local.file_names();
+        names.retain(|name| name != SYNC_MANIFEST_FILE_NAME);
+        names
+    }
+
+    fn copy_slot(&self, from: usize, to: usize) -> Result<(), String> {
+        let mut state = self.lock();
+        self.local.copy_slot(from, to)?;
+        for name in [save_file_name(to), save_meta_file_name(to)] {
+            self.touch(&mut state, &name)?;
+        }
+        Ok(())
+    }
+}
+
+/// Milliseconds since the Unix epoch, from the system clock
+fn unix_millis() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |since_epoch| since_epoch.as_millis() as u64)
+}
+
+/// Resource through which systems and the UI reach the synced save storage
+#[derive(Resource)]
+pub struct CloudSync {
+    /// The storage that is also the `SaveBackend`
+    pub storage: SyncedSaveStorage,
+    /// Time until the next periodic sync
+    pub timer: Timer,
+}
+
+impl CloudSync {
+    /// Syncs `storage` every `CLOUD_SYNC_INTERVAL_SECS`
+    pub fn new(storage: SyncedSaveStorage) -> Self {
+        Self {
+            storage,
+            timer: Timer::from_seconds(CLOUD_SYNC_INTERVAL_SECS, TimerMode::Repeating),
+        }
+    }
+}
+
+/// System that starts background syncs
+///
+/// # System Dependencies
+/// - **Resources**: Reads `Time`; writes `CloudSync` (when present)
+/// - **Upstream**: Save systems writing through the `SaveBackend` mark
+///   changes pending
+///
+/// # Behavior
+/// Syncs at startup, soon after saves change and every
+/// `CLOUD_SYNC_INTERVAL_SECS`. After a failed sync, pending changes wait for
+/// the next periodic sync instead of retrying every frame.
+pub fn cloud_sync_system(time: Res<Time>, cloud_sync: Option<ResMut<CloudSync>>) {
+    let Some(mut cloud_sync) = cloud_sync else {
+        return;
+    };
+    cloud_sync.timer.tick(time.delta());
+
+    let state = cloud_sync.storage.state();
+    let due = match state.status {
+        SyncStatus::NotSynced => true,
+        SyncStatus::Syncing => false,
+        SyncStatus::Synced => state.pending,
+        SyncStatus::Failed(_) => false,
+    };
+    if due || cloud_sync.timer.just_finished() {
+        cloud_sync.storage.spawn_sync();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(modified: u64, deleted: bool) -> SyncEntry {
+        SyncEntry { modified, deleted }
+    }
+
+    #[test]
+    fn plugin_keeps_the_save_backend_without_an_endpoint() {
+        let storage = MemoryStorage::default();
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.insert_resource(SaveBackend::new(storage.clone()));
+        app.add_plugins(CloudSyncPlugin);
+
+        assert!(app.world().get_resource::<CloudSync>().is_none());
+        assert_eq!(
+            *app.world().resource::<CloudSyncSettings>(),
+            CloudSyncSettings::default()
+        );
+        app.world()
+            .resource::<SaveBackend>()
+            .write("save1.ron", "()")
+            .unwrap();
+        assert!(storage.exists("save1.ron"));
+    }
+
+    #[test]
+    fn the_side_that_changed_last_wins() {
+        let local = SyncManifest {
+            files: BTreeMap::from([
+                ("save1.ron".to_string(), entry(200, false)),
+                ("save2.ron".to_string(), entry(100, false)),
+                ("save3.ron".to_string(), entry(300, true)),
+                ("save4.ron".to_string(), entry(50, false)),
+            ]),
+        };
+        let remote = SyncManifest {
+            files: BTreeMap::from([
+                ("save1.ron".to_string(), entry(100, false)),
+                ("save2.ron".to_string(), entry(150, false)),
+                ("save3.ron".to_string(), entry(250, false)),
+                ("save4.ron".to_string(), entry(50, false)),
+                ("save5.ron".to_string(), entry(400, true)),
+            ]),
+        };
+
+        assert_eq!(
+            plan_sync(&local, &remote),
+            vec![
+                SyncAction::Upload("save1.ron".to_string()),
+                SyncAction::Download("save2.ron".to_string()),
+                SyncAction::DeleteRemote("save3.ron".to_string()),
+            ]
+        );
+    }
+
+    /// Clock shared by both machines, so every change is later than the last
+    fn tick() -> u64 {
+        static CLOCK: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(1);
+        CLOCK.fetch_add(1, std::sync::atomic::Ordering::SeqCst)
+    }
+
+    #[test]
+    fn saves_follow_the_player_between_machines() {
+        let remote = MemoryStorage::default();
+        let desktop_files = MemoryStorage::default();
+        let desktop = SyncedSaveStorage::new(desktop_files.clone(), Arc::new(remote.clone()))
+            .with_clock(tick);
+        let laptop = SyncedSaveStorage::new(MemoryStorage::default(), Arc::new(remote.clone()))
+            .with_clock(tick);
+
+        desktop.write_slot(1, "(current_room: 1)").unwrap();
+        desktop.write("profiles.ron", "not synced").unwrap();
+        assert!(desktop.state().pending);
+        desktop.sync().unwrap();
+        assert_eq!(remote.read_slot(1).unwrap(), "(current_room: 1)");
+        assert!(!remote.exists("profiles.ron"));
+
+        // The laptop picks the save up and plays on
+        laptop.sync().unwrap();
+        assert_eq!(laptop.read_slot(1).unwrap(), "(current_room: 1)");
+        assert!(!laptop.state().pending, "Downloads aren't local changes");
+        laptop.write_slot(1, "(current_room: 4)").unwrap();
+        laptop.sync().unwrap();
+
+        desktop.sync().unwrap();
+        assert_eq!(desktop.read_slot(1).unwrap(), "(current_room: 4)");
+        assert_eq!(desktop.list_slots(), vec![1]);
+        assert!(desktop_files.exists(SYNC_MANIFEST_FILE_NAME));
+
+        laptop.delete_slot(1).unwrap();
+        laptop.sync().unwrap();
+        desktop.sync().unwrap();
+        assert!(desktop.list_slots().is_empty(), "Deletions sync too");
+    }
+}
@@ -1,10 +1,424 @@
 use crate::components::inventory::Collectible;
-use crate::components::player::Player;
-use crate::components::room::Collider;
+use crate::components::player::{CharacterController, JumpState, Player, Velocity};
+use crate::components::room::{Collider, Solid};
 use crate::components::trap::Trap;
+use crate::systems::fixed_timestep::PhysicsSet;
 use crate::systems::inventory::ItemCollectedEvent;
 use crate::systems::trap::TrapTriggeredEvent;
 use bevy::prelude::*;
+use std::collections::HashMap;
+
+/// Distance (pixels) the player's feet may be from a solid's top and still
+/// stand on it (and likewise for head and wall contacts)
+pub const STANDING_TOLERANCE: f32 = 0.5;
+
+/// Distance (pixels) around the player searched for solids in `SolidGrid`
+///
+/// Being pushed out of one solid can move the player into the next, so
+/// solids just beyond the player's collider are candidates too.
+pub const SOLID_QUERY_PADDING: f32 = 16.0;
+
+/// Size (pixels) of a `CollisionGrid` cell
+///
+/// About two tiles: a player overlaps at most four cells, and a standard
+/// trap or item sits in one to four.
+pub const COLLISION_CELL_SIZE: f32 = 64.0;
+
+/// Plugin that registers collision detection with its broad-phase grids
+///
+/// `solid_grid_system` keeps `SolidGrid` current and
+/// `solid_collision_system` pushes the player out of the solids near them,
+/// then `collision_grid_system` keeps `CollisionGrid` current before
+/// `collision_detection_system` runs, so the player is only tested against
+/// the traps and items near them. All three run in `FixedUpdate`
+/// (`PhysicsSet::Collision`), after player movement.
+pub struct CollisionPlugin;
+
+impl Plugin for CollisionPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<CollisionGrid>()
+            .init_resource::<SolidGrid>()
+            .add_event::<TrapTriggeredEvent>()
+            .add_event::<ItemCollectedEvent>()
+            .add_systems(
+                FixedUpdate,
+                (
+                    solid_grid_system,
+                    solid_collision_system,
+                    collision_grid_system,
+                    collision_detection_system,
+                )
+                    .chain()
+                    .in_set(PhysicsSet::Collision),
+            );
+    }
+}
+
+/// Resource bucketing trap and item colliders by `COLLISION_CELL_SIZE` cell
+///
+/// A spatial hash used as the broad phase of `collision_detection_system`.
+/// Entities are added to every cell their collider overlaps and are only
+/// re-bucketed when their `Transform` or `Collider` changes, so static
+/// traps and items cost nothing per frame however many a room holds.
+#[derive(Resource, Debug, Default)]
+pub struct CollisionGrid {
+    /// Entities whose collider overlaps each cell
+    cells: HashMap<IVec2, Vec<Entity>>,
+    /// Cell range (inclusive) each entity was added to
+    entries: HashMap<Entity, (IVec2, IVec2)>,
+}
+
+impl CollisionGrid {
+    /// Cell range (inclusive) a collider at `pos` overlaps
+    pub fn cell_range(pos: Vec2, collider: &Collider) -> (IVec2, IVec2) {
+        let cell = |point: Vec2| (point / COLLISION_CELL_SIZE).floor().as_ivec2();
+        (cell(pos + collider.min), cell(pos + collider.max))
+    }
+
+    /// Adds `entity` (or moves it) to the cells its collider at `pos` overlaps
+    pub fn insert(&mut self, entity: Entity, pos: Vec2, collider: &Collider) {
+        let range = Self::cell_range(pos, collider);
+        if self.entries.get(&entity) == Some(&range) {
+            return;
+        }
+        self.remove(entity);
+        for y in range.0.y..=range.1.y {
+            for x in range.0.x..=range.1.x {
+                self.cells.entry(IVec2::new(x, y)).or_default().push(entity);
+            }
+        }
+        self.entries.insert(entity, range);
+    }
+
+    /// Removes `entity` from every cell (no-op if it isn't in the grid)
+    pub fn remove(&mut self, entity: Entity) {
+        let Some((min, max)) = self.entries.remove(&entity) else {
+            return;
+        };
+        for y in min.y..=max.y {
+            for x in min.x..=max.x {
+                let cell = IVec2::new(x, y);
+                if let Some(entities) = self.cells.get_mut(&cell) {
+                    entities.retain(|other| *other != entity);
+                    if entities.is_empty() {
+                        self.cells.remove(&cell);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Entities sharing a cell with a collider at `pos`, each listed once
+    ///
+    /// Candidates only: callers still test the colliders for overlap.
+    pub fn nearby(&self, pos: Vec2, collider: &Collider) -> Vec<Entity> {
+        let (min, max) = Self::cell_range(pos, collider);
+        let mut entities = Vec::new();
+        for y in min.y..=max.y {
+            for x in min.x..=max.x {
+                if let Some(cell) = self.cells.get(&IVec2::new(x, y)) {
+                    entities.extend(cell);
+                }
+            }
+        }
+        entities.sort_unstable();
+        entities.dedup();
+        entities
+    }
+
+    /// Number of entities in the grid
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns true if the grid holds no entities
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+/// Resource bucketing `Solid` colliders by `COLLISION_CELL_SIZE` cell
+///
+/// The broad phase of `solid_collision_system`, kept apart from
+/// `CollisionGrid` so walls and ledges aren't candidates for trap and item
+/// checks (and the other way round).
+#[derive(Resource, Debug, Default, Deref, DerefMut)]
+pub struct SolidGrid(pub CollisionGrid);
+
+/// System that keeps `SolidGrid` in step with solid colliders
+///
+/// # System Dependencies
+/// - **Components**: Reads `Transform` and `Collider` of `Solid` entities
+/// - **Resources**: Writes `SolidGrid`
+/// - **Upstream**: `push_system` moves blocks first
+/// - **Downstream**: `solid_collision_system` reads the grid
+///
+/// # Behavior
+/// Like `collision_grid_system`: solids are re-bucketed when their
+/// transform or collider changes (pushed crates) and leave the grid when
+/// they lose their collider or `Solid` marker (broken doors and walls) or
+/// are despawned.
+#[allow(clippy::type_complexity)]
+pub fn solid_grid_system(
+    mut grid: ResMut<SolidGrid>,
+    changed: Query<
+        (Entity, &Transform, &Collider),
+        (
+            With<Solid>,
+            Or<(Changed<Transform>, Changed<Collider>, Added<Solid>)>,
+        ),
+    >,
+    mut removed_colliders: RemovedComponents<Collider>,
+    mut removed_solids: RemovedComponents<Solid>,
+) {
+    for entity in removed_colliders.read().chain(removed_solids.read()) {
+        grid.remove(entity);
+    }
+
+    for (entity, transform, collider) in &changed {
+        grid.insert(entity, transform.translation.truncate(), collider);
+    }
+}
+
+/// System that moves the player through the world as a kinematic character
+///
+/// # System Dependencies
+/// - **Components**: Reads `Transform` and `Collider` of `Solid` entities;
+///   writes the player's `Transform`, `Velocity`, `JumpState` and
+///   `CharacterController`
+/// - **Resources**: Reads `SolidGrid` (when present)
+/// - **Upstream**: `player_movement_system` moves the player and records
+///   the motion, `push_system` moves blocks out of the way first and
+///   `solid_grid_system` keeps `SolidGrid` current
+/// - **Downstream**: `collision_detection_system` sees the resolved
+///   position; `player_movement_system` jumps from the updated `JumpState`
+///
+/// # Behavior
+/// The player's collider is swept from where the tick started along the
+/// recorded `CharacterController::motion`, one axis at a time (horizontal,
+/// then vertical), and stops at the first solid in the way. However far
+/// the player moved in one tick they can't pass through a wall or floor,
+/// and sliding along one axis never catches on the corner of a solid only
+/// touched along the other. Solids the player was already inside (a block
+/// pushed onto them) push them out along the axis with the least
+/// penetration.
+///
+/// The contact flags are then refreshed from the solids touching each side
+/// of the player, and drive `JumpState`:
+/// - Ceiling contact stops upward motion
+/// - Ground contact while not rising grounds the player (so crates and
+///   ledges can be stood on)
+/// - Wall contact stops horizontal motion into the wall
+/// - A grounded player above the floor without ground contact (they walked
+///   off a ledge) starts falling
+///
+/// # Performance
+/// With `SolidGrid` only the solids within `SOLID_QUERY_PADDING` of the
+/// cells the player swept through are tested, however many a room holds;
+/// without it every solid is.
+#[allow(clippy::type_complexity)]
+pub fn solid_collision_system(
+    grid: Option<Res<SolidGrid>>,
+    mut player_query: Query<
+        (
+            &mut Transform,
+            &mut Velocity,
+            &mut JumpState,
+            &mut CharacterController,
+            &Collider,
+        ),
+        (With<Player>, Without<Solid>),
+    >,
+    solid_query: Query<(&Transform, &Collider), (With<Solid>, Without<Player>)>,
+) {
+    for (mut transform, mut velocity, mut jump_state, mut controller, collider) in &mut player_query
+    {
+        let end = transform.translation.truncate();
+        let motion = std::mem::take(&mut controller.motion);
+
+        let solids: Vec<(Vec2, &Collider)> = match grid.as_deref() {
+            Some(grid) => {
+                // The whole swept path, relative to where the player ended up
+                let padding = Vec2::splat(SOLID_QUERY_PADDING);
+                let search = Collider {
+                    min: collider.min + (-motion).min(Vec2::ZERO) - padding,
+                    max: collider.max + (-motion).max(Vec2::ZERO) + padding,
+                };
+                solid_query
+                    .iter_many(grid.nearby(end, &search))
+                    .map(|(transform, collider)| (transform.translation.truncate(), collider))
+                    .collect()
+            }
+            None => solid_query
+                .iter()
+                .map(|(transform, collider)| (transform.translation.truncate(), collider))
+                .collect(),
+        };
+
+        let mut pos = end - motion;
+        for axis in [0, 1] {
+            pos[axis] += sweep_axis(pos, collider, motion[axis], axis, &solids);
+        }
+
+        for &(solid_pos, solid_collider) in &solids {
+            if !aabb_intersects(pos, collider, solid_pos, solid_collider) {
+                continue;
+            }
+
+            let (min, max) = (pos + collider.min, pos + collider.max);
+            let (solid_min, solid_max) = (
+                solid_pos + solid_collider.min,
+                solid_pos + solid_collider.max,
+            );
+            let push_left = max.x - solid_min.x;
+            let push_right = solid_max.x - min.x;
+            let push_down = max.y - solid_min.y;
+            let push_up = solid_max.y - min.y;
+
+            if push_left.min(push_right) < push_down.min(push_up) {
+                pos.x += if push_left < push_right {
+                    -push_left
+                } else {
+                    push_right
+                };
+            } else if push_up <= push_down {
+                pos.y += push_up;
+            } else {
+                pos.y -= push_down;
+            }
+        }
+
+        *controller = contacts(pos, collider, &solids);
+        if controller.on_ceiling {
+            velocity.0.y = velocity.0.y.min(0.0);
+        }
+        if controller.on_ground && velocity.0.y <= 0.0 {
+            velocity.0.y = 0.0;
+            *jump_state = JumpState::Grounded;
+        }
+        if (controller.on_wall_left && velocity.0.x < 0.0)
+            || (controller.on_wall_right && velocity.0.x > 0.0)
+        {
+            velocity.0.x = 0.0;
+        }
+
+        // Walked off a ledge: the ground at y = 0 is handled by movement
+        if *jump_state == JumpState::Grounded && !controller.on_ground && pos.y > 0.0 {
+            *jump_state = JumpState::Falling;
+        }
+
+        if transform.translation.truncate() != pos {
+            transform.translation = pos.extend(transform.translation.z);
+        }
+    }
+}
+
+/// How far a collider at `pos` can move by `motion` along `axis` (0 for x,
+/// 1 for y) before touching one of `solids`
+///
+/// Only solids ahead of the collider that it overlaps on the other axis can
+/// stop it; ones it's already inside are left to the penetration pass.
+fn sweep_axis(
+    pos: Vec2,
+    collider: &Collider,
+    motion: f32,
+    axis: usize,
+    solids: &[(Vec2, &Collider)],
+) -> f32 {
+    if motion == 0.0 {
+        return 0.0;
+    }
+    let other = 1 - axis;
+    let (min, max) = (pos + collider.min, pos + collider.max);
+
+    let mut allowed = motion.abs();
+    for &(solid_pos, solid_collider) in solids {
+        let (solid_min, solid_max) = (
+            solid_pos + solid_collider.min,
+            solid_pos + solid_collider.max,
+        );
+        if min[other] >= solid_max[other] || max[other] <= solid_min[other] {
+            continue;
+        }
+        let gap = if motion > 0.0 {
+            solid_min[axis] - max[axis]
+        } else {
+            min[axis] - solid_max[axis]
+        };
+        if gap >= -STANDING_TOLERANCE && gap < allowed {
+            allowed = gap.max(0.0);
+        }
+    }
+    allowed * motion.signum()
+}
+
+/// Which sides of a collider at `pos` are within `STANDING_TOLERANCE` of
+/// one of `solids` (with no motion left to sweep)
+fn contacts(pos: Vec2, collider: &Collider, solids: &[(Vec2, &Collider)]) -> CharacterController {
+    let (min, max) = (pos + collider.min, pos + collider.max);
+    let touching = |a: f32, b: f32| (a - b).abs() <= STANDING_TOLERANCE;
+
+    let mut contacts = CharacterController::default();
+    for &(solid_pos, solid_collider) in solids {
+        let (solid_min, solid_max) = (
+            solid_pos + solid_collider.min,
+            solid_pos + solid_collider.max,
+        );
+        let beside_x = min.x < solid_max.x && max.x > solid_min.x;
+        let beside_y = min.y < solid_max.y && max.y > solid_min.y;
+
+        contacts.on_ground |= beside_x && touching(min.y, solid_max.y);
+        contacts.on_ceiling |= beside_x && touching(max.y, solid_min.y);
+        contacts.on_wall_left |= beside_y && touching(min.x, solid_max.x);
+        contacts.on_wall_right |= beside_y && touching(max.x, solid_min.x);
+    }
+    contacts
+}
+
+/// System that keeps `CollisionGrid` in step with trap and item colliders
+///
+/// # System Dependencies
+/// - **Components**: Reads `Transform` and `Collider` of `Trap` and
+///   `Collectible` entities
+/// - **Resources**: Writes `CollisionGrid`
+/// - **Downstream**: `collision_detection_system` reads the grid
+///
+/// # Behavior
+/// Entities are re-bucketed when their transform or collider changes, or
+/// when they become a trap or collectible (placed items turn collectible
+/// after their pickup cooldown). Entities losing their collider, trap or
+/// collectible marker (or despawned) leave the grid.
+#[allow(clippy::type_complexity)]
+pub fn collision_grid_system(
+    mut grid: ResMut<CollisionGrid>,
+    changed: Query<
+        (Entity, &Transform, &Collider),
+        (
+            Or<(With<Trap>, With<Collectible>)>,
+            Or<(
+                Changed<Transform>,
+                Changed<Collider>,
+                Added<Trap>,
+                Added<Collectible>,
+            )>,
+        ),
+    >,
+    mut removed_colliders: RemovedComponents<Collider>,
+    mut removed_traps: RemovedComponents<Trap>,
+    mut removed_items: RemovedComponents<Collectible>,
+) {
+    for entity in removed_colliders
+        .read()
+        .chain(removed_traps.read())
+        .chain(removed_items.read())
+    {
+        grid.remove(entity);
+    }
+
+    for (entity, transform, collider) in &changed {
+        grid.insert(entity, transform.translation.truncate(), collider);
+    }
+}
 
 /// System for AABB (Axis-Aligned Bounding Box) collision detection
 ///
@@ -18,17 +432,20 @@ use bevy::prelude::*;
 /// - `ItemCollectedEvent` when player collides with collectible
 ///
 /// # System Dependencies
+/// - **Upstream**: `collision_grid_system` keeps `CollisionGrid` current (when present)
 /// - **Downstream**: `trap_activation_system` consumes `TrapTriggeredEvent`
 /// - **Downstream**: `inventory_collection_system` consumes `ItemCollectedEvent`
 /// - **Related**: Works with `Collider` component for spatial queries
 ///
 /// # Performance
-/// - O(n*m) complexity where n=players, m=traps+items
-/// - Expected: 1 player, 5-10 traps/items per room
-/// - Frame impact: <0.05% of 16ms budget
+/// - With `CollisionGrid`: each player is only tested against the traps and
+///   items sharing a grid cell, so cost doesn't grow with room size (the
+///   1000-entity stress room tests a handful per frame)
+/// - Without it: O(n*m) where n=players, m=traps+items
 ///
 /// From tasks.md T026: CollisionDetectionSystem (updated for T027 integration)
 pub fn collision_detection_system(
+    grid: Option<Res<CollisionGrid>>,
     player_query: Query<(Entity, &Transform, &Collider), With<Player>>,
     trap_query: Query<(Entity, &Transform, &Collider), With<Trap>>,
     item_query: Query<(Entity, &Transform, &Collider), With<Collectible>>,
@@ -38,6 +455,29 @@ pub fn collision_detection_system(
     for (player_entity, player_transform, player_collider) in &player_query {
         let player_pos = player_transform.translation.truncate();
 
+        if let Some(grid) = grid.as_deref() {
+            let nearby = grid.nearby(player_pos, player_collider);
+            for (trap_entity, trap_transform, trap_collider) in trap_query.iter_many(&nearby) {
+                let trap_pos = trap_transform.translation.truncate();
+                if aabb_intersects(player_pos, player_collider, trap_pos, trap_collider) {
+                    trap_events.write(TrapTriggeredEvent {
+                        trap: trap_entity,
+                        player: player_entity,
+                    });
+                }
+            }
+            for (item_entity, item_transform, item_collider) in item_query.iter_many(&nearby) {
+                let item_pos = item_transform.translation.truncate();
+                if aabb_intersects(player_pos, player_collider, item_pos, item_collider) {
+                    item_events.write(ItemCollectedEvent {
+                        item: item_entity,
+                        player: player_entity,
+                    });
+                }
+            }
+            continue;
+        }
+
         // Check trap collisions
         for (trap_entity, trap_transform, trap_collider) in &trap_query {
             let trap_pos = trap_transform.translation.truncate();
@@ -80,7 +520,12 @@ pub fn collision_detection_system(
 /// # Algorithm
 /// AABB intersection test: Two boxes intersect if they overlap on both axes.
 /// For each axis, check if max_a > min_b AND min_a < max_b.
-fn aabb_intersects(pos_a: Vec2, collider_a: &Collider, pos_b: Vec2, collider_b: &Collider) -> bool {
+pub(crate) fn aabb_intersects(
+    pos_a: Vec2,
+    collider_a: &Collider,
+    pos_b: Vec2,
+    collider_b: &Collider,
+) -> bool {
     let a_min = pos_a + collider_a.min;
     let a_max = pos_a + collider_a.max;
     let b_min = pos_b + collider_b.min;
@@ -93,6 +538,7 @@ fn aabb_intersects(pos_a: Vec2, collider_a: &Collider, pos_b: Vec2, collider_b:
 mod tests {
     use super::*;
     use crate::components::player::Player;
+    use crate::systems::fixed_timestep::advance_fixed_timestep;
 
     #[test]
     fn collision_detection_system_compiles() {
@@ -543,4 +989,296 @@ mod tests {
         assert!(trap_entities.contains(&trap2), "Should include trap2");
         assert!(trap_entities.contains(&trap3), "Should include trap3");
     }
+
+    #[test]
+    fn grid_only_returns_nearby_entities() {
+        let tile = Collider {
+            min: Vec2::splat(-16.0),
+            max: Vec2::splat(16.0),
+        };
+        let mut grid = CollisionGrid::default();
+        let near = Entity::from_raw(1);
+        let far = Entity::from_raw(2);
+        grid.insert(near, Vec2::new(40.0, 0.0), &tile);
+        grid.insert(far, Vec2::new(1000.0, 1000.0), &tile);
+
+        assert_eq!(grid.nearby(Vec2::ZERO, &tile), vec![near]);
+
+        grid.insert(far, Vec2::new(10.0, 10.0), &tile);
+        assert_eq!(grid.nearby(Vec2::ZERO, &tile), vec![near, far]);
+
+        grid.remove(near);
+        assert_eq!(grid.nearby(Vec2::ZERO, &tile), vec![far]);
+        assert_eq!(grid.len(), 1);
+    }
+
+    #[test]
+    fn collision_plugin_tracks_spawned_and_despawned_traps() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(CollisionPlugin);
+
+        let collider = || Collider {
+            min: Vec2::splat(-16.0),
+            max: Vec2::splat(16.0),
+        };
+        let player = app
+            .world_mut()
+            .spawn((Player, Transform::default(), collider()))
+            .id();
+        let trap = app
+            .world_mut()
+            .spawn((Trap::Spikes, Transform::from_xyz(8.0, 0.0, 0.0), collider()))
+            .id();
+        for x in 0..100 {
+            app.world_mut().spawn((
+                Trap::ArrowTrap,
+                Transform::from_xyz(500.0 + x as f32 * 32.0, 0.0, 0.0),
+                collider(),
+            ));
+        }
+
+        advance_fixed_timestep(&mut app);
+        assert_eq!(app.world().resource::<CollisionGrid>().len(), 101);
+        let events = app.world().resource::<Events<TrapTriggeredEvent>>();
+        let triggered: Vec<_> = events
+            .get_cursor()
+            .read(events)
+            .map(|event| (event.trap, event.player))
+            .collect();
+        assert_eq!(triggered, vec![(trap, player)]);
+
+        app.world_mut().despawn(trap);
+        advance_fixed_timestep(&mut app);
+        assert_eq!(app.world().resource::<CollisionGrid>().len(), 100);
+    }
+
+    #[test]
+    fn solids_block_the_player_and_can_be_stood_on() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_systems(Update, solid_collision_system);
+
+        let collider = || Collider {
+            min: Vec2::splat(-16.0),
+            max: Vec2::splat(16.0),
+        };
+        // A ledge topping out at y = 48 and a wall to its right
+        app.world_mut()
+            .spawn((Solid, Transform::from_xyz(0.0, 32.0, 0.0), collider()));
+        app.world_mut()
+            .spawn((Solid, Transform::from_xyz(100.0, 32.0, 0.0), collider()));
+        let player = app
+            .world_mut()
+            .spawn((
+                Player,
+                Transform::from_xyz(4.0, 60.0, 0.0),
+                Velocity(Vec2::new(0.0, -200.0)),
+                JumpState::Falling,
+                collider(),
+            ))
+            .id();
+
+        app.update();
+        assert_eq!(
+            app.world().get::<Transform>(player).unwrap().translation.y,
+            64.0
+        );
+        assert_eq!(
+            *app.world().get::<JumpState>(player).unwrap(),
+            JumpState::Grounded
+        );
+
+        // Walking into the wall stops at its side
+        *app.world_mut().get_mut::<Transform>(player).unwrap() =
+            Transform::from_xyz(80.0, 30.0, 0.0);
+        app.world_mut().get_mut::<Velocity>(player).unwrap().0 = Vec2::new(200.0, 0.0);
+        app.update();
+        assert_eq!(
+            app.world().get::<Transform>(player).unwrap().translation.x,
+            68.0
+        );
+        assert_eq!(app.world().get::<Velocity>(player).unwrap().0.x, 0.0);
+
+        // Standing in mid-air (walked off the ledge) starts a fall
+        *app.world_mut().get_mut::<Transform>(player).unwrap() =
+            Transform::from_xyz(-40.0, 64.0, 0.0);
+        *app.world_mut().get_mut::<JumpState>(player).unwrap() = JumpState::Grounded;
+        app.update();
+        assert_eq!(
+            *app.world().get::<JumpState>(player).unwrap(),
+            JumpState::Falling
+        );
+    }
+
+    #[test]
+    fn solid_grid_tracks_solids_across_a_large_room() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, CollisionPlugin));
+
+        let collider = || Collider {
+            min: Vec2::splat(-16.0),
+            max: Vec2::splat(16.0),
+        };
+        // A long row of ledges well below the player, and a wall far along
+        for x in 0..2000 {
+            app.world_mut().spawn((
+                Solid,
+                Transform::from_xyz(x as f32 * 32.0, -200.0, 0.0),
+                collider(),
+            ));
+        }
+        let wall = app
+            .world_mut()
+            .spawn((Solid, Transform::from_xyz(5000.0, 16.0, 0.0), collider()))
+            .id();
+        let player = app
+            .world_mut()
+            .spawn((
+                Player,
+                Transform::from_xyz(4990.0, 16.0, 0.0),
+                Velocity(Vec2::new(200.0, 0.0)),
+                JumpState::Grounded,
+                collider(),
+            ))
+            .id();
+        let x = |app: &App| app.world().get::<Transform>(player).unwrap().translation.x;
+
+        advance_fixed_timestep(&mut app);
+        assert_eq!(app.world().resource::<SolidGrid>().len(), 2001);
+        assert_eq!(x(&app), 4968.0, "Stopped at the wall's side");
+
+        // A broken wall no longer blocks
+        app.world_mut().entity_mut(wall).remove::<Solid>();
+        app.world_mut()
+            .get_mut::<Transform>(player)
+            .unwrap()
+            .translation
+            .x = 4990.0;
+        advance_fixed_timestep(&mut app);
+        assert_eq!(app.world().resource::<SolidGrid>().len(), 2000);
+        assert_eq!(x(&app), 4990.0);
+    }
+
+    #[test]
+    fn swept_player_cannot_tunnel_through_thin_solids() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_systems(Update, solid_collision_system);
+
+        let square = |half: f32| Collider {
+            min: Vec2::splat(-half),
+            max: Vec2::splat(half),
+        };
+        // A 4px wall at x = 100 and a 4px floor topping out at y = 102
+        app.world_mut().spawn((
+            Solid,
+            Transform::from_xyz(100.0, 200.0, 0.0),
+            Collider {
+                min: Vec2::new(-2.0, -100.0),
+                max: Vec2::new(2.0, 100.0),
+            },
+        ));
+        app.world_mut().spawn((
+            Solid,
+            Transform::from_xyz(0.0, 100.0, 0.0),
+            Collider {
+                min: Vec2::new(-100.0, -2.0),
+                max: Vec2::new(98.0, 2.0),
+            },
+        ));
+        // One slow frame carried the player from (0, 150) clean past both
+        let player = app
+            .world_mut()
+            .spawn((
+                Player,
+                Transform::from_xyz(300.0, -50.0, 0.0),
+                Velocity(Vec2::new(200.0, -900.0)),
+                JumpState::Falling,
+                CharacterController {
+                    motion: Vec2::new(300.0, -200.0),
+                    ..default()
+                },
+                square(16.0),
+            ))
+            .id();
+
+        app.update();
+        let world = app.world();
+        assert_eq!(
+            world
+                .get::<Transform>(player)
+                .unwrap()
+                .translation
+                .truncate(),
+            Vec2::new(82.0, 118.0),
+            "Stopped at the wall, then landed on the floor"
+        );
+        assert_eq!(world.get::<Velocity>(player).unwrap().0, Vec2::ZERO);
+        assert_eq!(
+            *world.get::<JumpState>(player).unwrap(),
+            JumpState::Grounded
+        );
+        let contacts = world.get::<CharacterController>(player).unwrap();
+        assert!(contacts.on_ground && contacts.on_wall_right);
+        assert!(!contacts.on_ceiling && !contacts.on_wall_left);
+        assert_eq!(contacts.motion, Vec2::ZERO, "The motion was used up");
+    }
+
+    #[test]
+    fn swept_player_slides_over_seams_and_bumps_ceilings() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_systems(Update, solid_collision_system);
+
+        let tile = || Collider {
+            min: Vec2::splat(-16.0),
+            max: Vec2::splat(16.0),
+        };
+        // Two floor tiles side by side and a low ceiling over the second
+        for x in [0.0, 32.0] {
+            app.world_mut()
+                .spawn((Solid, Transform::from_xyz(x, 0.0, 0.0), tile()));
+        }
+        app.world_mut()
+            .spawn((Solid, Transform::from_xyz(32.0, 64.0, 0.0), tile()));
+        let player = app
+            .world_mut()
+            .spawn((
+                Player,
+                Transform::from_xyz(20.0, 32.0, 0.0),
+                Velocity(Vec2::new(200.0, 0.0)),
+                JumpState::Grounded,
+                CharacterController {
+                    motion: Vec2::new(20.0, 0.0),
+                    ..default()
+                },
+                tile(),
+            ))
+            .id();
+
+        app.update();
+        assert_eq!(
+            app.world().get::<Transform>(player).unwrap().translation.x,
+            20.0,
+            "The seam between the tiles doesn't catch the player"
+        );
+        let contacts = *app.world().get::<CharacterController>(player).unwrap();
+        assert!(contacts.on_ground && contacts.on_ceiling && !contacts.on_wall_right);
+
+        // Jumping into the ceiling stops the jump
+        let mut entity = app.world_mut().entity_mut(player);
+        entity.get_mut::<Velocity>().unwrap().0 = Vec2::new(0.0, 400.0);
+        *entity.get_mut::<JumpState>().unwrap() = JumpState::Jumping;
+        entity.get_mut::<Transform>().unwrap().translation.y = 40.0;
+        entity.get_mut::<CharacterController>().unwrap().motion = Vec2::new(0.0, 8.0);
+        app.update();
+        let world = app.world();
+        assert_eq!(world.get::<Transform>(player).unwrap().translation.y, 32.0);
+        assert_eq!(world.get::<Velocity>(player).unwrap().0.y, 0.0);
+        assert_eq!(
+            *world.get::<JumpState>(player).unwrap(),
+            JumpState::Grounded
+        );
+    }
 }
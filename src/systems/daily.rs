@@ -0,0 +1,381 @@
+use bevy::prelude::*;
+
+use crate::resources::game_rng::GameRng;
+use crate::resources::game_rules::{GameRuleSet, NewGameEvent};
+use crate::resources::game_state::{GameMode, GameState};
+use crate::systems::ghost::{
+    DEFAULT_SAMPLE_INTERVAL, GhostRecorder, GhostRecording, ghost_recording_system,
+};
+use crate::systems::save_storage::{SaveBackend, SaveStorage};
+use crate::systems::victory::RunSummary;
+use crate::ui::photo_mode::civil_date;
+
+/// Remix cycle the daily house is shuffled with (the key and item shuffle
+/// and trap density of a first New Game+)
+pub const DAILY_REMIX_CYCLE: u32 = 1;
+
+/// Seconds a result's time may differ from its replay's length beyond one
+/// sample interval
+pub const DAILY_TIME_TOLERANCE: f32 = 1.0;
+
+/// Prefix of every result token
+pub const DAILY_TOKEN_PREFIX: &str = "DAILY";
+
+/// Plugin that runs the Daily Escape
+///
+/// Starting a new game with `GameRuleSet::Daily` seeds the run from the
+/// date, so every player gets the same side rooms and the same key and
+/// item shuffle that day (applied by `remix::remix_house_system`), and
+/// records a ghost replay of the run. Escaping
+/// the house produces a `DailyResult` whose token players share along with
+/// the replay, which `DailyResult::verify` checks the token against.
+pub struct DailyPlugin;
+
+impl Plugin for DailyPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DailyChallenge>()
+            .init_resource::<GameRng>()
+            .init_resource::<GhostRecorder>()
+            .init_resource::<RunSummary>()
+            .init_resource::<SaveBackend>()
+            .add_event::<NewGameEvent>()
+            .add_systems(
+                Update,
+                (
+                    daily_start_system,
+                    ghost_recording_system,
+                    daily_result_system,
+                )
+                    .chain(),
+            );
+    }
+}
+
+/// Resource tracking the Daily Escape being played
+///
+/// Saved with the run, so a loaded Daily Escape keeps its day and remix.
+#[derive(Resource, Debug, Clone, Default, PartialEq)]
+pub struct DailyChallenge {
+    /// Day (since 1970-01-01) of the challenge, `None` outside the Daily Escape
+    pub day: Option<u64>,
+    /// Result of the escape, once the player got out
+    pub result: Option<DailyResult>,
+}
+
+/// Finished Daily Escape run, shared as a token
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DailyResult {
+    /// Day (since 1970-01-01) of the challenge
+    pub day: u64,
+    /// In-game time of the run in milliseconds
+    pub time_ms: u64,
+    /// Times the player died
+    pub deaths: u32,
+}
+
+impl DailyResult {
+    /// Shareable token, e.g. `DAILY-2026-10-17-754210-2-1f3a9c07`
+    ///
+    /// The date, time and deaths followed by a checksum. The checksum only
+    /// catches typos and casual edits; `verify` checks the run itself.
+    pub fn token(&self) -> String {
+        let (year, month, day) = civil_date(self.day);
+        format!(
+            "{}-{:04}-{:02}-{:02}-{}-{}-{:08x}",
+            DAILY_TOKEN_PREFIX,
+            year,
+            month,
+            day,
+            self.time_ms,
+            self.deaths,
+            self.checksum()
+        )
+    }
+
+    /// Reads a token made by `token`
+    ///
+    /// # Errors
+    /// Returns error string if the token is malformed or its checksum
+    /// doesn't match
+    pub fn parse(token: &str) -> Result<Self, String> {
+        let parts: Vec<&str> = token.trim().split('-').collect();
+        let [prefix, year, month, day, time_ms, deaths, checksum] = parts[..] else {
+            return Err(format!("'{}' is not a daily result token", token));
+        };
+        if prefix != DAILY_TOKEN_PREFIX {
+            return Err(format!("'{}' is not a daily result token", token));
+        }
+
+        let number = |part: &str| {
+            part.parse::<u64>()
+                .map_err(|e| format!("Invalid number '{}' in token: {}", part, e))
+        };
+        let result = Self {
+            day: days_from_civil(number(year)?, number(month)?, number(day)?)?,
+            time_ms: number(time_ms)?,
+            deaths: deaths
+                .parse()
+                .map_err(|e| format!("Invalid death count '{}' in token: {}", deaths, e))?,
+        };
+        let checksum = u32::from_str_radix(checksum, 16)
+            .map_err(|e| format!("Invalid checksum '{}' in token: {}", checksum, e))?;
+        if checksum != result.checksum() {
+            return Err(format!("Token '{}' has been altered", token));
+        }
+        Ok(result)
+    }
+
+    /// Checks the result against the ghost replay of its run
+    ///
+    /// The replay must have been played with the day's seed and last as
+    /// long as the result's time (within one sample interval plus
+    /// `DAILY_TIME_TOLERANCE`). Deaths aren't in the replay and can't be
+    /// checked.
+    ///
+    /// # Errors
+    /// Returns error string describing the first mismatch
+    pub fn verify(&self, recording: &GhostRecording) -> Result<(), String> {
+        let seed = daily_seed(self.day);
+        if recording.seed != Some(seed) {
+            return Err(format!(
+                "Replay wasn't played with the daily seed {:016x}",
+                seed
+            ));
+        }
+
+        let time = self.time_ms as f32 / 1000.0;
+        let tolerance = recording.sample_interval + DAILY_TIME_TOLERANCE;
+        if (recording.duration() - time).abs() > tolerance {
+            return Err(format!(
+                "Replay lasts {:.1}s but the result claims {:.1}s",
+                recording.duration(),
+                time
+            ));
+        }
+        Ok(())
+    }
+
+    fn checksum(&self) -> u32 {
+        let mixed = mix(mix(mix(daily_seed(self.day)) ^ self.time_ms) ^ u64::from(self.deaths));
+        (mixed >> 32) as u32
+    }
+}
+
+/// `GameRng` seed of the Daily Escape on `day` (days since 1970-01-01)
+pub fn daily_seed(day: u64) -> u64 {
+    mix(day ^ 0xDA11_E5CA_9E00_0000)
+}
+
+/// Today's day number (days since 1970-01-01, UTC), from the system clock
+pub fn today() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |since_epoch| since_epoch.as_secs() / 86_400)
+}
+
+/// System that sets up the Daily Escape on `NewGameEvent`
+///
+/// # System Dependencies
+/// - **Upstream**: The main menu emits `NewGameEvent`
+/// - **Resources**: Writes `DailyChallenge`, `GameRng` and `GhostRecorder`
+/// - **Downstream**: `remix_house_system` remixes the house for the day;
+///   rooms loaded afterwards use the side rooms of the reseeded `GameRng`
+///
+/// # Behavior
+/// A Daily game reseeds `GameRng` with `daily_seed` of today and starts
+/// recording a ghost replay. Any other new game after a Daily one clears
+/// `DailyChallenge`, which drops the daily remix. The faster burn
+/// comes from the Daily `GameRules` (`DAILY_BURN_MULTIPLIER`).
+pub fn daily_start_system(
+    mut events: EventReader<NewGameEvent>,
+    mut daily: ResMut<DailyChallenge>,
+    mut rng: ResMut<GameRng>,
+    mut recorder: ResMut<GhostRecorder>,
+) {
+    let Some(event) = events.read().last() else {
+        return;
+    };
+
+    if event.rules != GameRuleSet::Daily {
+        if daily.day.is_some() {
+            *daily = DailyChallenge::default();
+        }
+        return;
+    }
+
+    let day = today();
+    let seed = daily_seed(day);
+    *daily = DailyChallenge {
+        day: Some(day),
+        result: None,
+    };
+    rng.reseed(seed);
+    recorder.start(DEFAULT_SAMPLE_INTERVAL, seed);
+
+    let (year, month, day) = civil_date(day);
+    info!(
+        "Starting the Daily Escape of {:04}-{:02}-{:02} (seed {:016x})",
+        year, month, day, seed
+    );
+}
+
+/// System that records the Daily Escape result when the player escapes
+///
+/// # System Dependencies
+/// - **Upstream**: `victory::escape_system` switches to Victory and writes
+///   `RunSummary`
+/// - **Resources**: Reads `GameState` and `RunSummary`, writes
+///   `DailyChallenge` and `GhostRecorder`; writes through `SaveBackend`
+/// - **Downstream**: The ending screen shows the token
+///
+/// # Behavior
+/// Stops the replay and exports the token and replay next to the saves
+/// (`daily_file_name`), so players can share both.
+pub fn daily_result_system(
+    game_state: Res<GameState>,
+    summary: Res<RunSummary>,
+    mut daily: ResMut<DailyChallenge>,
+    mut recorder: ResMut<GhostRecorder>,
+    storage: Res<SaveBackend>,
+) {
+    if game_state.game_mode != GameMode::Victory || daily.result.is_some() {
+        return;
+    }
+    let Some(day) = daily.day else {
+        return;
+    };
+
+    let result = DailyResult {
+        day,
+        time_ms: summary.time.as_millis() as u64,
+        deaths: summary.deaths,
+    };
+    let recording = recorder.stop();
+    info!("Daily Escape result: {}", result.token());
+    if let Err(e) = export_daily_result(&*storage, &result, &recording) {
+        error!("Failed to export the Daily Escape result: {}", e);
+    }
+    daily.result = Some(result);
+}
+
+/// File the token (`txt`) or replay (`ron`) of the Daily Escape on `day` is
+/// exported to, e.g. `daily-2026-10-17.txt`
+pub fn daily_file_name(day: u64, extension: &str) -> String {
+    let (year, month, day) = civil_date(day);
+    format!("daily-{:04}-{:02}-{:02}.{}", year, month, day, extension)
+}
+
+/// Writes the token and replay of `result` to `storage`
+///
+/// # Errors
+/// Returns error string if serialization or a write fails
+pub fn export_daily_result(
+    storage: &impl SaveStorage,
+    result: &DailyResult,
+    recording: &GhostRecording,
+) -> Result<(), String> {
+    let replay = ron::ser::to_string_pretty(recording, ron::ser::PrettyConfig::default())
+        .map_err(|e| format!("Failed to serialize ghost recording: {}", e))?;
+    storage.write(&daily_file_name(result.day, "txt"), &result.token())?;
+    storage.write(&daily_file_name(result.day, "ron"), &replay)
+}
+
+/// Days since 1970-01-01 of a calendar date (the inverse of `civil_date`)
+fn days_from_civil(year: u64, month: u64, day: u64) -> Result<u64, String> {
+    let invalid = || format!("Invalid date {:04}-{:02}-{:02}", year, month, day);
+    if !(1970..=9999).contains(&year) || !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return Err(invalid());
+    }
+
+    // Years counted from March, so the leap day falls at the end
+    let march_year = if month <= 2 { year - 1 } else { year };
+    let era = march_year / 400;
+    let year_of_era = march_year % 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    let days = era * 146_097 + day_of_era - 719_468;
+    // Rejects days past the end of the month (e.g. February 30th)
+    if civil_date(days) != (year, month, day) {
+        return Err(invalid());
+    }
+    Ok(days)
+}
+
+/// SplitMix64 finalizer, spreading every input bit over the output
+fn mix(value: u64) -> u64 {
+    let mut z = value.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::systems::save_storage::MemoryStorage;
+    use std::time::Duration;
+
+    #[test]
+    fn tokens_round_trip_and_reject_edits() {
+        // 2026-10-17
+        let result = DailyResult {
+            day: 20_743,
+            time_ms: 754_210,
+            deaths: 2,
+        };
+        let token = result.token();
+        assert!(token.starts_with("DAILY-2026-10-17-754210-2-"), "{}", token);
+        assert_eq!(DailyResult::parse(&token), Ok(result));
+
+        let faster = token.replace("-754210-", "-354210-");
+        assert!(DailyResult::parse(&faster).is_err());
+        assert!(DailyResult::parse("DAILY-2026-02-30-1-0-00000000").is_err());
+        assert!(DailyResult::parse("not a token").is_err());
+
+        let mut recording = GhostRecording::new(0.5);
+        recording.positions = vec![(0.0, 0.0); 1_509]; // 754 seconds
+        recording.seed = Some(daily_seed(result.day));
+        assert_eq!(result.verify(&recording), Ok(()));
+
+        recording.seed = Some(daily_seed(result.day + 1));
+        assert!(result.verify(&recording).is_err(), "Another day's seed");
+        recording.seed = Some(daily_seed(result.day));
+        recording.positions.truncate(100);
+        assert!(result.verify(&recording).is_err(), "Replay too short");
+    }
+
+    #[test]
+    fn escaping_exports_the_token_and_replay() {
+        // Starting a Daily game seeds the run from today's date, so the run
+        // is set up by hand here
+        let day = 20_743;
+        let storage = MemoryStorage::default();
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.insert_resource(SaveBackend::new(storage.clone()));
+        app.add_plugins(DailyPlugin);
+        app.init_resource::<GameState>();
+        app.insert_resource(DailyChallenge {
+            day: Some(day),
+            result: None,
+        });
+        app.world_mut()
+            .resource_mut::<GhostRecorder>()
+            .start(DEFAULT_SAMPLE_INTERVAL, daily_seed(day));
+
+        app.world_mut().resource_mut::<RunSummary>().time = Duration::from_secs(90);
+        app.world_mut().resource_mut::<GameState>().game_mode = GameMode::Victory;
+        app.update();
+
+        let result = app.world().resource::<DailyChallenge>().result.unwrap();
+        assert_eq!(result.time_ms, 90_000);
+        assert!(!app.world().resource::<GhostRecorder>().active);
+        assert_eq!(
+            storage.read(&daily_file_name(day, "txt")),
+            Ok(result.token())
+        );
+        let replay: GhostRecording =
+            ron::from_str(&storage.read(&daily_file_name(day, "ron")).unwrap()).unwrap();
+        assert_eq!(replay.seed, Some(daily_seed(day)));
+    }
+}
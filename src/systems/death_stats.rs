@@ -0,0 +1,463 @@
+use crate::components::player::Player;
+use crate::components::render_layer::RenderLayer;
+use crate::components::room::RoomId;
+use crate::resources::game_state::GameState;
+use crate::resources::map_state::{DeathCause, DeathRecord, MapState};
+use crate::systems::trap::PlayerDeathEvent;
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+/// Size (pixels) of a death heatmap cell, matching the tilemap tile size
+pub const HEATMAP_CELL_SIZE: f32 = 32.0;
+
+/// Key that shows and hides the death heatmap debug overlay
+pub const HEATMAP_TOGGLE_KEY: KeyCode = KeyCode::F3;
+
+/// Key that shows and hides the gravestone decals
+pub const GRAVESTONE_TOGGLE_KEY: KeyCode = KeyCode::F4;
+
+/// Deaths to the same trap needed before a gravestone appears
+pub const GRAVESTONE_MIN_DEATHS: usize = 3;
+
+/// Deaths of the same cause within this distance (pixels) count as the same trap
+pub const GRAVESTONE_GROUP_RADIUS: f32 = 96.0;
+
+/// Z layer of heatmap cells, above the room sprites and player but below the lighting
+const HEATMAP_Z: f32 = RenderLayer::Player.z() + 20.0;
+
+/// Z layer of gravestone decals, just above the floor tiles
+const GRAVESTONE_Z: f32 = RenderLayer::Tiles.z() + 1.0;
+
+/// Plugin that records death statistics and provides the heatmap overlay
+pub struct DeathStatsPlugin;
+
+impl Plugin for DeathStatsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DeathHeatmap>()
+            .init_resource::<Gravestones>()
+            .add_systems(
+                Update,
+                (
+                    death_statistics_system,
+                    toggle_death_heatmap_system,
+                    death_heatmap_system,
+                    toggle_gravestones_system,
+                    gravestone_system,
+                )
+                    .chain(),
+            );
+    }
+}
+
+/// Resource controlling the death heatmap debug overlay
+#[derive(Resource, Default, Debug)]
+pub struct DeathHeatmap {
+    /// Whether the heatmap is drawn over the current room
+    pub visible: bool,
+}
+
+/// Marker component for sprites drawn by the death heatmap overlay
+#[derive(Component)]
+pub struct DeathHeatmapCell;
+
+/// Resource controlling the cosmetic gravestone decals
+///
+/// Off by default; toggled with `GRAVESTONE_TOGGLE_KEY`.
+#[derive(Resource, Default, Debug)]
+pub struct Gravestones {
+    /// Whether gravestones are drawn in the current room
+    pub visible: bool,
+}
+
+/// Component on a gravestone decal marking a repeatedly deadly trap
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct Gravestone {
+    /// What killed the player here
+    pub cause: DeathCause,
+    /// Number of deaths tallied on the gravestone
+    pub deaths: usize,
+}
+
+/// Deaths to one trap, grouped for a gravestone
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GravestoneSite {
+    /// Average death position
+    pub position: Vec2,
+    /// What killed the player
+    pub cause: DeathCause,
+    /// Number of deaths in the group
+    pub deaths: usize,
+}
+
+/// System that records every player death for statistics
+///
+/// # Behavior
+/// For each `PlayerDeathEvent`:
+/// 1. Increments `GameState::deaths`
+/// 2. Records the death position and cause in `MapState` under the current room
+///
+/// # System Dependencies
+/// - **Upstream**: Trap systems emit `PlayerDeathEvent`
+/// - **Resources**: Writes `GameState`, `MapState`
+/// - **Downstream**: Save systems persist `MapState::death_records`
+pub fn death_statistics_system(
+    mut death_events: EventReader<PlayerDeathEvent>,
+    mut game_state: ResMut<GameState>,
+    mut map_state: ResMut<MapState>,
+    player_query: Query<&Transform, With<Player>>,
+) {
+    for event in death_events.read() {
+        let Ok(transform) = player_query.get(event.player) else {
+            continue;
+        };
+
+        game_state.deaths += 1;
+        map_state.record_death(
+            game_state.current_room,
+            transform.translation.truncate(),
+            event.cause,
+        );
+    }
+}
+
+/// System that shows or hides the death heatmap with `HEATMAP_TOGGLE_KEY`
+pub fn toggle_death_heatmap_system(
+    keyboard: Option<Res<ButtonInput<KeyCode>>>,
+    mut heatmap: ResMut<DeathHeatmap>,
+) {
+    if let Some(keyboard) = keyboard
+        && keyboard.just_pressed(HEATMAP_TOGGLE_KEY)
+    {
+        heatmap.visible = !heatmap.visible;
+    }
+}
+
+/// Groups death records into heatmap cells
+///
+/// # Returns
+/// Map of cell coordinates (death position divided by `cell_size`, floored)
+/// to the number of deaths in that cell
+pub fn death_heatmap_cells(records: &[DeathRecord], cell_size: f32) -> HashMap<(i32, i32), usize> {
+    let mut cells = HashMap::new();
+    for record in records {
+        let cell = (
+            (record.position.0 / cell_size).floor() as i32,
+            (record.position.1 / cell_size).floor() as i32,
+        );
+        *cells.entry(cell).or_insert(0) += 1;
+    }
+    cells
+}
+
+/// System that draws the death heatmap over the current room
+///
+/// Each cell with at least one death becomes a translucent red square whose
+/// opacity scales with its share of the room's deadliest cell. The overlay
+/// is rebuilt only when it is toggled, the room changes or a death is added.
+///
+/// # System Dependencies
+/// - **Resources**: Reads `DeathHeatmap`, `MapState`, `GameState`
+/// - **Upstream**: `death_statistics_system` records deaths
+pub fn death_heatmap_system(
+    mut commands: Commands,
+    heatmap: Res<DeathHeatmap>,
+    map_state: Res<MapState>,
+    game_state: Res<GameState>,
+    cell_query: Query<Entity, With<DeathHeatmapCell>>,
+    mut shown: Local<Option<(RoomId, usize)>>,
+) {
+    let room = game_state.current_room;
+    let wanted = heatmap
+        .visible
+        .then(|| (room, map_state.deaths_in_room(room).len()));
+    if *shown == wanted {
+        return;
+    }
+    *shown = wanted;
+
+    for entity in &cell_query {
+        commands.entity(entity).despawn();
+    }
+
+    if wanted.is_none() {
+        return;
+    }
+
+    let cells = death_heatmap_cells(map_state.deaths_in_room(room), HEATMAP_CELL_SIZE);
+    let max = cells.values().copied().max().unwrap_or(1) as f32;
+    for ((x, y), count) in cells {
+        let alpha = 0.15 + 0.55 * (count as f32 / max);
+        commands.spawn((
+            DeathHeatmapCell,
+            Sprite {
+                color: Color::srgba(1.0, 0.1, 0.05, alpha),
+                custom_size: Some(Vec2::splat(HEATMAP_CELL_SIZE)),
+                ..default()
+            },
+            Transform::from_xyz(
+                (x as f32 + 0.5) * HEATMAP_CELL_SIZE,
+                (y as f32 + 0.5) * HEATMAP_CELL_SIZE,
+                HEATMAP_Z,
+            ),
+        ));
+    }
+}
+
+/// System that shows or hides gravestones with `GRAVESTONE_TOGGLE_KEY`
+pub fn toggle_gravestones_system(
+    keyboard: Option<Res<ButtonInput<KeyCode>>>,
+    mut gravestones: ResMut<Gravestones>,
+) {
+    if let Some(keyboard) = keyboard
+        && keyboard.just_pressed(GRAVESTONE_TOGGLE_KEY)
+    {
+        gravestones.visible = !gravestones.visible;
+    }
+}
+
+/// Groups death records into per-trap gravestone sites
+///
+/// Trap entities are not persistent, so deaths are attributed to the same
+/// trap when they share a cause and lie within `radius` of the group's
+/// average position.
+///
+/// # Returns
+/// Sites with at least `min_deaths` deaths
+pub fn gravestone_sites(
+    records: &[DeathRecord],
+    min_deaths: usize,
+    radius: f32,
+) -> Vec<GravestoneSite> {
+    // (cause, position sum, count)
+    let mut groups: Vec<(DeathCause, Vec2, usize)> = Vec::new();
+    for record in records {
+        let position = Vec2::new(record.position.0, record.position.1);
+        let group = groups.iter_mut().find(|(cause, sum, count)| {
+            *cause == record.cause && (*sum / *count as f32).distance(position) <= radius
+        });
+        match group {
+            Some((_, sum, count)) => {
+                *sum += position;
+                *count += 1;
+            }
+            None => groups.push((record.cause, position, 1)),
+        }
+    }
+
+    groups
+        .into_iter()
+        .filter(|(_, _, count)| *count >= min_deaths)
+        .map(|(cause, sum, count)| GravestoneSite {
+            position: sum / count as f32,
+            cause,
+            deaths: count,
+        })
+        .collect()
+}
+
+/// System that places gravestone decals where the player keeps dying
+///
+/// Purely cosmetic: a small stone sprite with a death tally at the average
+/// position of every trap that killed the player `GRAVESTONE_MIN_DEATHS`
+/// times in the current room. Rebuilt only when toggled, when the room
+/// changes or when a death is added.
+///
+/// # System Dependencies
+/// - **Resources**: Reads `Gravestones`, `MapState`, `GameState`
+/// - **Upstream**: `death_statistics_system` records deaths
+pub fn gravestone_system(
+    mut commands: Commands,
+    gravestones: Res<Gravestones>,
+    map_state: Res<MapState>,
+    game_state: Res<GameState>,
+    gravestone_query: Query<Entity, With<Gravestone>>,
+    mut shown: Local<Option<(RoomId, usize)>>,
+) {
+    let room = game_state.current_room;
+    let wanted = gravestones
+        .visible
+        .then(|| (room, map_state.deaths_in_room(room).len()));
+    if *shown == wanted {
+        return;
+    }
+    *shown = wanted;
+
+    for entity in &gravestone_query {
+        commands.entity(entity).despawn();
+    }
+
+    if wanted.is_none() {
+        return;
+    }
+
+    for site in gravestone_sites(
+        map_state.deaths_in_room(room),
+        GRAVESTONE_MIN_DEATHS,
+        GRAVESTONE_GROUP_RADIUS,
+    ) {
+        commands.spawn((
+            Gravestone {
+                cause: site.cause,
+                deaths: site.deaths,
+            },
+            Sprite {
+                color: Color::srgb(0.45, 0.45, 0.5),
+                custom_size: Some(Vec2::new(16.0, 20.0)),
+                ..default()
+            },
+            Transform::from_translation(site.position.extend(GRAVESTONE_Z)),
+            children![(
+                Text2d::new(format!("x{}", site.deaths)),
+                TextFont {
+                    font_size: 10.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.9, 0.9, 0.85)),
+                Transform::from_xyz(0.0, 16.0, 0.1),
+            )],
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::player::Health;
+
+    #[test]
+    fn deaths_are_recorded_per_room() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_event::<PlayerDeathEvent>();
+        app.insert_resource(GameState {
+            current_room: 3,
+            ..Default::default()
+        });
+        app.init_resource::<MapState>();
+        app.add_systems(Update, death_statistics_system);
+
+        let player = app
+            .world_mut()
+            .spawn((Player, Health::Dead, Transform::from_xyz(64.0, 32.0, 0.0)))
+            .id();
+        app.world_mut().send_event(PlayerDeathEvent {
+            player,
+            cause: DeathCause::SwingingBlade,
+        });
+
+        app.update();
+
+        assert_eq!(app.world().resource::<GameState>().deaths, 1);
+        let map_state = app.world().resource::<MapState>();
+        assert_eq!(
+            map_state.deaths_in_room(3),
+            &[DeathRecord {
+                position: (64.0, 32.0),
+                cause: DeathCause::SwingingBlade,
+            }]
+        );
+    }
+
+    #[test]
+    fn heatmap_groups_nearby_deaths() {
+        let record = |x, y| DeathRecord {
+            position: (x, y),
+            cause: DeathCause::Spikes,
+        };
+        let records = [record(5.0, 5.0), record(30.0, 1.0), record(40.0, 5.0)];
+
+        let cells = death_heatmap_cells(&records, 32.0);
+
+        assert_eq!(cells[&(0, 0)], 2);
+        assert_eq!(cells[&(1, 0)], 1);
+    }
+
+    #[test]
+    fn heatmap_overlay_spawns_and_clears_cells() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.init_resource::<GameState>();
+        let mut map_state = MapState::default();
+        map_state.record_death(0, Vec2::new(5.0, 5.0), DeathCause::Spikes);
+        map_state.record_death(0, Vec2::new(100.0, 5.0), DeathCause::Spikes);
+        app.insert_resource(map_state);
+        app.insert_resource(DeathHeatmap { visible: true });
+        app.add_systems(Update, death_heatmap_system);
+
+        app.update();
+        let count = |app: &mut App| {
+            app.world_mut()
+                .query_filtered::<(), With<DeathHeatmapCell>>()
+                .iter(app.world())
+                .count()
+        };
+        assert_eq!(count(&mut app), 2);
+
+        app.world_mut().resource_mut::<DeathHeatmap>().visible = false;
+        app.update();
+        assert_eq!(count(&mut app), 0);
+    }
+
+    #[test]
+    fn gravestones_group_repeated_deaths_per_trap() {
+        let record = |x, cause| DeathRecord {
+            position: (x, 0.0),
+            cause,
+        };
+        let records = [
+            record(100.0, DeathCause::Spikes),
+            record(110.0, DeathCause::Spikes),
+            record(120.0, DeathCause::Spikes),
+            // Same cause but a different trap across the room
+            record(900.0, DeathCause::Spikes),
+            // Different trap at the same spot
+            record(110.0, DeathCause::Pendulum),
+        ];
+
+        let sites = gravestone_sites(&records, 3, 96.0);
+
+        assert_eq!(
+            sites,
+            vec![GravestoneSite {
+                position: Vec2::new(110.0, 0.0),
+                cause: DeathCause::Spikes,
+                deaths: 3,
+            }]
+        );
+    }
+
+    #[test]
+    fn gravestones_are_spawned_only_when_enabled() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.init_resource::<GameState>();
+        app.init_resource::<Gravestones>();
+        let mut map_state = MapState::default();
+        for _ in 0..GRAVESTONE_MIN_DEATHS {
+            map_state.record_death(0, Vec2::new(64.0, 32.0), DeathCause::ArrowTrap);
+        }
+        app.insert_resource(map_state);
+        app.add_systems(Update, gravestone_system);
+
+        let gravestones = |app: &mut App| {
+            app.world_mut()
+                .query::<&Gravestone>()
+                .iter(app.world())
+                .copied()
+                .collect::<Vec<_>>()
+        };
+
+        app.update();
+        assert!(gravestones(&mut app).is_empty());
+
+        app.world_mut().resource_mut::<Gravestones>().visible = true;
+        app.update();
+        assert_eq!(
+            gravestones(&mut app),
+            vec![Gravestone {
+                cause: DeathCause::ArrowTrap,
+                deaths: GRAVESTONE_MIN_DEATHS,
+            }]
+        );
+    }
+}
@@ -0,0 +1,283 @@
+use bevy::prelude::*;
+
+use crate::components::lighting::VisibilityRadius;
+use crate::components::room::{Collider, Interactable, RoomBounds, Solid};
+use crate::components::trap::TrapTrigger;
+use crate::systems::game_set::GameSet;
+
+/// Key that turns every debug drawing category on, or all of them off
+pub const DEBUG_DRAW_KEY: KeyCode = KeyCode::F8;
+
+/// Plugin that outlines colliders, trigger ranges, interaction radii, light
+/// radii and room bounds with gizmos
+///
+/// Categories are toggled one at a time from the developer console (e.g.
+/// `toggle collision-debug`) or all at once with `DEBUG_DRAW_KEY`.
+///
+/// **NOTE**: Bevy's `GizmoPlugin` (part of `DefaultPlugins`) must be added
+/// to the app before this plugin.
+pub struct DebugDrawPlugin;
+
+impl Plugin for DebugDrawPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DebugDraw>()
+            .add_systems(Update, toggle_debug_draw_system.in_set(GameSet::Input))
+            .add_systems(Update, debug_draw_system.in_set(GameSet::Presentation));
+    }
+}
+
+/// Kind of shape the debug drawing can show
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DebugCategory {
+    /// Collider boxes (solids in red, overlap-only colliders in orange)
+    Colliders,
+    /// Proximity trap trigger ranges
+    Triggers,
+    /// Interactable reach
+    Interaction,
+    /// Light visibility radii
+    Lights,
+    /// Room bounds
+    Rooms,
+}
+
+impl DebugCategory {
+    /// Every category, in the order they are listed
+    pub const ALL: [DebugCategory; 5] = [
+        DebugCategory::Colliders,
+        DebugCategory::Triggers,
+        DebugCategory::Interaction,
+        DebugCategory::Lights,
+        DebugCategory::Rooms,
+    ];
+
+    /// Name the console toggles the category by (as `<name>-debug`)
+    pub fn name(self) -> &'static str {
+        match self {
+            DebugCategory::Colliders => "collision",
+            DebugCategory::Triggers => "trigger",
+            DebugCategory::Interaction => "interaction",
+            DebugCategory::Lights => "light",
+            DebugCategory::Rooms => "room",
+        }
+    }
+
+    /// Looks up the category called `name`
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|category| category.name() == name)
+    }
+
+    /// Color the category's shapes are drawn in
+    pub fn color(self) -> Color {
+        match self {
+            DebugCategory::Colliders => Color::srgb(0.9, 0.2, 0.2),
+            DebugCategory::Triggers => Color::srgb(0.9, 0.2, 0.9),
+            DebugCategory::Interaction => Color::srgb(0.2, 0.8, 0.9),
+            DebugCategory::Lights => Color::srgb(1.0, 0.85, 0.3),
+            DebugCategory::Rooms => Color::srgb(0.3, 0.9, 0.3),
+        }
+    }
+}
+
+/// Resource holding which debug drawing categories are shown
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct DebugDraw {
+    /// Collider boxes
+    pub colliders: bool,
+    /// Proximity trap trigger ranges
+    pub triggers: bool,
+    /// Interactable reach
+    pub interaction: bool,
+    /// Light visibility radii
+    pub lights: bool,
+    /// Room bounds
+    pub rooms: bool,
+}
+
+impl DebugDraw {
+    /// Whether `category` is shown
+    pub fn shows(&self, category: DebugCategory) -> bool {
+        match category {
+            DebugCategory::Colliders => self.colliders,
+            DebugCategory::Triggers => self.triggers,
+            DebugCategory::Interaction => self.interaction,
+            DebugCategory::Lights => self.lights,
+            DebugCategory::Rooms => self.rooms,
+        }
+    }
+
+    /// Shows or hides `category`
+    pub fn set(&mut self, category: DebugCategory, shown: bool) {
+        let flag = match category {
+            DebugCategory::Colliders => &mut self.colliders,
+            DebugCategory::Triggers => &mut self.triggers,
+            DebugCategory::Interaction => &mut self.interaction,
+            DebugCategory::Lights => &mut self.lights,
+            DebugCategory::Rooms => &mut self.rooms,
+        };
+        *flag = shown;
+    }
+
+    /// Flips `category`, returning whether it is now shown
+    pub fn toggle(&mut self, category: DebugCategory) -> bool {
+        let shown = !self.shows(category);
+        self.set(category, shown);
+        shown
+    }
+
+    /// Whether any category is shown
+    pub fn any(&self) -> bool {
+        DebugCategory::ALL
+            .into_iter()
+            .any(|category| self.shows(category))
+    }
+}
+
+/// System that toggles debug drawing with `DEBUG_DRAW_KEY`
+///
+/// # System Dependencies
+/// - **Resources**: Reads `ButtonInput<KeyCode>` (when present); writes
+///   `DebugDraw`
+///
+/// # Behavior
+/// Hides every category if any is shown, and shows them all otherwise.
+pub fn toggle_debug_draw_system(
+    keyboard: Option<Res<ButtonInput<KeyCode>>>,
+    mut draw: ResMut<DebugDraw>,
+) {
+    if keyboard.is_some_and(|keyboard| keyboard.just_pressed(DEBUG_DRAW_KEY)) {
+        let shown = !draw.any();
+        for category in DebugCategory::ALL {
+            draw.set(category, shown);
+        }
+    }
+}
+
+/// System that draws the shown debug categories with gizmos
+///
+/// # System Dependencies
+/// - **Resources**: Reads `DebugDraw`, `Gizmos` (from `GizmoPlugin`)
+/// - **Components**: Reads `Transform` with `Collider` (and `Solid`),
+///   `TrapTrigger`, `Interactable` or `VisibilityRadius`, and `RoomBounds`
+///
+/// # Behavior
+/// Colliders and room bounds are outlined as boxes; trigger ranges,
+/// interaction reach and light radii as circles around the entity. Only
+/// `TrapTrigger::Proximity` traps have a range to draw.
+pub fn debug_draw_system(
+    mut gizmos: Gizmos,
+    draw: Res<DebugDraw>,
+    collider_query: Query<(&Transform, &Collider, Has<Solid>)>,
+    trigger_query: Query<(&Transform, &TrapTrigger)>,
+    interactable_query: Query<(&Transform, &Interactable)>,
+    light_query: Query<(&Transform, &VisibilityRadius)>,
+    room_query: Query<&RoomBounds>,
+) {
+    if draw.colliders {
+        for (transform, collider, solid) in &collider_query {
+            let pos = transform.translation.truncate();
+            let color = if solid {
+                DebugCategory::Colliders.color()
+            } else {
+                Color::srgb(0.95, 0.6, 0.2)
+            };
+            gizmos.rect_2d(
+                pos + (collider.min + collider.max) / 2.0,
+                collider.max - collider.min,
+                color,
+            );
+        }
+    }
+
+    if draw.triggers {
+        for (transform, trigger) in &trigger_query {
+            if let TrapTrigger::Proximity(range) = trigger {
+                gizmos.circle_2d(
+                    transform.translation.truncate(),
+                    *range,
+                    DebugCategory::Triggers.color(),
+                );
+            }
+        }
+    }
+
+    if draw.interaction {
+        for (transform, interactable) in &interactable_query {
+            gizmos.circle_2d(
+                transform.translation.truncate(),
+                interactable.range,
+                DebugCategory::Interaction.color(),
+            );
+        }
+    }
+
+    if draw.lights {
+        for (transform, radius) in &light_query {
+            gizmos.circle_2d(
+                transform.translation.truncate(),
+                radius.0,
+                DebugCategory::Lights.color(),
+            );
+        }
+    }
+
+    if draw.rooms {
+        for bounds in &room_query {
+            gizmos.rect_2d(
+                (bounds.min + bounds.max) / 2.0,
+                bounds.max - bounds.min,
+                DebugCategory::Rooms.color(),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn categories_round_trip_through_their_names() {
+        for category in DebugCategory::ALL {
+            assert_eq!(DebugCategory::from_name(category.name()), Some(category));
+        }
+        assert_eq!(DebugCategory::from_name("gravity"), None);
+
+        let mut draw = DebugDraw::default();
+        assert!(draw.toggle(DebugCategory::Lights));
+        assert!(draw.lights && !draw.colliders);
+    }
+
+    #[test]
+    fn debug_draw_key_shows_all_then_hides_all() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.insert_resource(DebugDraw {
+            rooms: true,
+            ..default()
+        });
+        app.init_resource::<ButtonInput<KeyCode>>();
+        app.add_systems(Update, toggle_debug_draw_system);
+
+        // One category shown counts as on, so the key hides it
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(DEBUG_DRAW_KEY);
+        app.update();
+        assert_eq!(*app.world().resource::<DebugDraw>(), DebugDraw::default());
+
+        let mut keyboard = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+        keyboard.release(DEBUG_DRAW_KEY);
+        keyboard.clear();
+        keyboard.press(DEBUG_DRAW_KEY);
+        app.update();
+        let draw = app.world().resource::<DebugDraw>();
+        assert!(
+            DebugCategory::ALL
+                .into_iter()
+                .all(|category| draw.shows(category))
+        );
+    }
+}
@@ -0,0 +1,727 @@
+use bevy::prelude::*;
+use leafwing_input_manager::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fs;
+
+use crate::components::player::Player;
+use crate::components::room::{Collider, RoomId};
+use crate::resources::game_clock::GameClock;
+use crate::resources::game_state::{GameMode, GameState};
+use crate::resources::input_config::PlayerAction;
+use crate::resources::input_context::{InputContextStack, InputPermission};
+use crate::systems::interaction::ExamineEvent;
+use crate::systems::trigger_zone::GameplayTrigger;
+
+/// Default location of the dialogue scripts, relative to the working directory
+pub const DIALOGUE_PATH: &str = "assets/dialogue/narration.ron";
+
+/// Characters the dialogue box reveals per second of game time
+pub const TYPEWRITER_CHARS_PER_SEC: f32 = 40.0;
+
+/// Plugin that loads the dialogue scripts and plays them when triggered
+///
+/// Scripts start from level-placed `DialogueTrigger`s (walking into an
+/// area or examining an object) or from their own `ScriptTrigger`. The
+/// box itself is drawn by `ui::dialogue_box::DialogueBoxPlugin`.
+pub struct DialoguePlugin;
+
+impl Plugin for DialoguePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DialogueScripts>()
+            .init_resource::<Dialogue>()
+            .init_resource::<GameClock>()
+            .add_event::<StartDialogueEvent>()
+            .add_event::<AdvanceDialogueEvent>()
+            .add_event::<ExamineEvent>()
+            .add_event::<GameplayTrigger>()
+            .add_systems(Startup, load_dialogue_system)
+            .add_systems(
+                Update,
+                (
+                    (
+                        room_dialogue_system,
+                        gameplay_dialogue_system,
+                        dialogue_area_system,
+                        dialogue_examine_system,
+                        dialogue_input_system,
+                    ),
+                    start_dialogue_system,
+                    advance_dialogue_system,
+                    typewriter_system,
+                )
+                    .chain(),
+            );
+    }
+}
+
+/// One line of a dialogue script
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct DialogueLine {
+    /// Who is speaking (narration when omitted)
+    #[serde(default)]
+    pub speaker: Option<String>,
+    /// Text revealed by the typewriter
+    pub text: String,
+    /// Answers the player picks from once the line is shown (none: the
+    /// line simply continues to the next one)
+    #[serde(default)]
+    pub choices: Vec<DialogueChoice>,
+}
+
+/// Answer the player can pick after a line
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct DialogueChoice {
+    /// Button text
+    pub text: String,
+    /// Index of the line the script continues at (ends the script when omitted)
+    #[serde(default)]
+    pub goto: Option<usize>,
+}
+
+/// Condition that starts a script without a level-placed trigger
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+pub enum ScriptTrigger {
+    /// The player enters the room with this ID
+    EnterRoom(RoomId),
+    /// A `GameplayTrigger` with this name fires
+    Gameplay(String),
+}
+
+/// A dialogue or narration script
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct DialogueScript {
+    /// Lines in order; choices may jump between them
+    pub lines: Vec<DialogueLine>,
+    /// Starts the script on its own (level `DialogueTrigger`s may also start it)
+    #[serde(default)]
+    pub trigger: Option<ScriptTrigger>,
+    /// Play only the first time the script is triggered
+    #[serde(default = "default_once")]
+    pub once: bool,
+}
+
+fn default_once() -> bool {
+    true
+}
+
+/// Resource holding every dialogue script, keyed by script ID
+///
+/// Loaded from `assets/dialogue/narration.ron` at startup.
+#[derive(Resource, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct DialogueScripts {
+    /// Scripts by the ID triggers refer to them with
+    pub scripts: HashMap<String, DialogueScript>,
+}
+
+/// Load the dialogue scripts from a RON file
+///
+/// # Arguments
+/// * `path` - Path to the RON file (e.g., "assets/dialogue/narration.ron")
+///
+/// # Errors
+/// Returns error string if the file cannot be read or parsed
+pub fn load_dialogue_scripts(path: &str) -> Result<DialogueScripts, String> {
+    let content = fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read dialogue '{}': {}", path, e))?;
+
+    ron::from_str(&content).map_err(|e| format!("Failed to parse RON from '{}': {}", path, e))
+}
+
+/// Startup system that loads the dialogue scripts from disk
+///
+/// Falls back to no scripts (triggers do nothing) if the file is missing
+/// or malformed.
+pub fn load_dialogue_system(mut commands: Commands) {
+    match load_dialogue_scripts(DIALOGUE_PATH) {
+        Ok(scripts) => {
+            info!("Loaded {} dialogue scripts", scripts.scripts.len());
+            commands.insert_resource(scripts);
+        }
+        Err(e) => {
+            warn!("{}. Dialogue disabled.", e);
+            commands.insert_resource(DialogueScripts::default());
+        }
+    }
+}
+
+/// How a `DialogueTrigger` is set off
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TriggerOn {
+    /// The player examines the entity (it needs an `InteractionKind::Examine`
+    /// `Interactable`)
+    #[default]
+    Examine,
+    /// The player walks into the entity's `Collider`
+    Enter,
+}
+
+/// Component starting a dialogue script, placed in levels as `"DialogueTrigger"`
+#[derive(Component, Debug, Clone, PartialEq, Eq)]
+pub struct DialogueTrigger {
+    /// ID of the script in `DialogueScripts`
+    pub script: String,
+    /// What sets the trigger off
+    pub on: TriggerOn,
+}
+
+/// Event requesting that a script plays (queued behind any dialogue showing)
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+pub struct StartDialogueEvent {
+    /// ID of the script in `DialogueScripts`
+    pub script: String,
+}
+
+/// Event asking the dialogue box to move on
+///
+/// While a line is still being typed out, any advance shows it in full.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AdvanceDialogueEvent {
+    /// Index of the picked choice (`None` continues a line without choices)
+    pub choice: Option<usize>,
+}
+
+/// Script being shown and how far it has got
+#[derive(Debug, Clone, PartialEq)]
+pub struct ActiveDialogue {
+    /// ID of the script in `DialogueScripts`
+    pub script: String,
+    /// Index of the line shown
+    pub line: usize,
+    /// Characters of the line revealed so far by the typewriter
+    pub revealed: f32,
+}
+
+impl ActiveDialogue {
+    /// Shows the first line of `script`
+    pub fn start(script: String) -> Self {
+        Self {
+            script,
+            line: 0,
+            revealed: 0.0,
+        }
+    }
+}
+
+/// Resource holding the dialogue state
+#[derive(Resource, Debug, Clone, Default, PartialEq)]
+pub struct Dialogue {
+    /// Script showing in the box
+    pub active: Option<ActiveDialogue>,
+    /// Scripts waiting for the box, in trigger order
+    pub queued: VecDeque<String>,
+    /// Scripts started this session (`DialogueScript::once` scripts don't replay)
+    pub played: HashSet<String>,
+    /// Room whose `ScriptTrigger::EnterRoom` scripts were last triggered
+    pub entered_room: Option<RoomId>,
+}
+
+impl Dialogue {
+    /// Line showing in the box and how many of its characters are revealed
+    pub fn current<'a>(&self, scripts: &'a DialogueScripts) -> Option<(&'a DialogueLine, usize)> {
+        let active = self.active.as_ref()?;
+        let line = scripts
+            .scripts
+            .get(&active.script)?
+            .lines
+            .get(active.line)?;
+        Some((line, active.revealed as usize))
+    }
+}
+
+/// Text of `line` revealed after `chars` characters
+pub fn revealed_text(line: &DialogueLine, chars: usize) -> &str {
+    line.text
+        .char_indices()
+        .nth(chars)
+        .map_or(line.text.as_str(), |(end, _)| &line.text[..end])
+}
+
+/// System that starts the scripts of each room the player enters
+///
+/// # System Dependencies
+/// - **Resources**: Reads `GameState` and `DialogueScripts`, writes `Dialogue`
+/// - **Upstream**: `room_transition_system` changes the current room
+/// - **Downstream**: Emits `StartDialogueEvent` for `ScriptTrigger::EnterRoom`
+///   scripts (in ID order)
+pub fn room_dialogue_system(
+    game_state: Res<GameState>,
+    scripts: Res<DialogueScripts>,
+    mut dialogue: ResMut<Dialogue>,
+    mut events: EventWriter<StartDialogueEvent>,
+) {
+    if game_state.game_mode != GameMode::Playing
+        || dialogue.entered_room == Some(game_state.current_room)
+    {
+        return;
+    }
+    dialogue.entered_room = Some(game_state.current_room);
+
+    let mut entered: Vec<&String> = scripts
+        .scripts
+        .iter()
+        .filter(|(_, script)| {
+            script.trigger == Some(ScriptTrigger::EnterRoom(game_state.current_room))
+        })
+        .map(|(id, _)| id)
+        .collect();
+    entered.sort();
+    for id in entered {
+        events.write(StartDialogueEvent { script: id.clone() });
+    }
+}
+
+/// System that starts the scripts of each gameplay trigger that fires
+///
+/// # System Dependencies
+/// - **Resources**: Reads `DialogueScripts`
+/// - **Upstream**: `trigger_zone_system` emits `GameplayTrigger`
+/// - **Downstream**: Emits `StartDialogueEvent` for `ScriptTrigger::Gameplay`
+///   scripts with the trigger's name (in ID order)
+pub fn gameplay_dialogue_system(
+    mut triggers: EventReader<GameplayTrigger>,
+    scripts: Res<DialogueScripts>,
+    mut events: EventWriter<StartDialogueEvent>,
+) {
+    for trigger in triggers.read() {
+        let mut triggered: Vec<&String> = scripts
+            .scripts
+            .iter()
+            .filter(|(_, script)| match &script.trigger {
+                Some(ScriptTrigger::Gameplay(name)) => *name == trigger.name,
+                _ => false,
+            })
+            .map(|(id, _)| id)
+            .collect();
+        triggered.sort();
+        for id in triggered {
+            events.write(StartDialogueEvent { script: id.clone() });
+        }
+    }
+}
+
+/// System that starts `TriggerOn::Enter` scripts when the player walks in
+///
+/// # System Dependencies
+/// - **Components**: Reads `Player` and `DialogueTrigger` `Transform`s and
+///   the triggers' `Collider`s
+/// - **Downstream**: Emits `StartDialogueEvent`
+///
+/// # Behavior
+/// A trigger fires when the player's position enters its collider and
+/// again only after they have left it.
+pub fn dialogue_area_system(
+    player_query: Query<&Transform, With<Player>>,
+    trigger_query: Query<(Entity, &DialogueTrigger, &Transform, &Collider)>,
+    mut inside: Local<HashSet<Entity>>,
+    mut events: EventWriter<StartDialogueEvent>,
+) {
+    let player = player_query
+        .single()
+        .ok()
+        .map(|transform| transform.translation.truncate());
+
+    for (entity, trigger, transform, collider) in &trigger_query {
+        if trigger.on != TriggerOn::Enter {
+            continue;
+        }
+        let offset = player.map(|player| player - transform.translation.truncate());
+        let contains = offset.is_some_and(|offset| {
+            offset.cmpge(collider.min).all() && offset.cmple(collider.max).all()
+        });
+
+        if !contains {
+            inside.remove(&entity);
+        } else if inside.insert(entity) {
+            events.write(StartDialogueEvent {
+                script: trigger.script.clone(),
+            });
+        }
+    }
+}
+
+/// System that starts `TriggerOn::Examine` scripts of examined entities
+///
+/// # System Dependencies
+/// - **Components**: Reads `DialogueTrigger`
+/// - **Upstream**: `interaction_system` emits `ExamineEvent`
+/// - **Downstream**: Emits `StartDialogueEvent`
+pub fn dialogue_examine_system(
+    mut examine_events: EventReader<ExamineEvent>,
+    trigger_query: Query<&DialogueTrigger>,
+    mut events: EventWriter<StartDialogueEvent>,
+) {
+    for event in examine_events.read() {
+        if let Ok(trigger) = trigger_query.get(event.target)
+            && trigger.on == TriggerOn::Examine
+        {
+            events.write(StartDialogueEvent {
+                script: trigger.script.clone(),
+            });
+        }
+    }
+}
+
+/// System that advances the dialogue with the interact action (F)
+///
+/// # System Dependencies
+/// - **Components**: Reads the player's `ActionState<PlayerAction>`
+/// - **Resources**: Reads `InputContextStack` (when present)
+/// - **Downstream**: Emits `AdvanceDialogueEvent` (choices are picked in
+///   the dialogue box)
+pub fn dialogue_input_system(
+    input: Option<Res<InputContextStack>>,
+    player_query: Query<&ActionState<PlayerAction>, With<Player>>,
+    mut events: EventWriter<AdvanceDialogueEvent>,
+) {
+    if input.is_some_and(|input| !input.allows(InputPermission::DialogueInput)) {
+        return;
+    }
+    if player_query
+        .iter()
+        .any(|actions| actions.just_pressed(&PlayerAction::Interact))
+    {
+        events.write(AdvanceDialogueEvent { choice: None });
+    }
+}
+
+/// System that shows requested scripts, one at a time
+///
+/// # System Dependencies
+/// - **Resources**: Reads `DialogueScripts`, writes `Dialogue`
+/// - **Upstream**: The trigger systems emit `StartDialogueEvent`
+///
+/// # Behavior
+/// Unknown scripts and `once` scripts already played are ignored. A
+/// request while a script is showing waits in `Dialogue::queued`.
+pub fn start_dialogue_system(
+    scripts: Res<DialogueScripts>,
+    mut dialogue: ResMut<Dialogue>,
+    mut events: EventReader<StartDialogueEvent>,
+) {
+    for event in events.read() {
+        let Some(script) = scripts.scripts.get(&event.script) else {
+            warn!("Unknown dialogue script '{}'", event.script);
+            continue;
+        };
+        if script.lines.is_empty()
+            || (script.once && dialogue.played.contains(&event.script))
+            || dialogue.queued.contains(&event.script)
+        {
+            continue;
+        }
+        dialogue.played.insert(event.script.clone());
+        dialogue.queued.push_back(event.script.clone());
+    }
+
+    if dialogue.active.is_none()
+        && let Some(script) = dialogue.queued.pop_front()
+    {
+        dialogue.active = Some(ActiveDialogue::start(script));
+    }
+}
+
+/// System that moves the dialogue on when asked
+///
+/// # System Dependencies
+/// - **Resources**: Reads `DialogueScripts`, writes `Dialogue`
+/// - **Upstream**: `dialogue_input_system` and the dialogue box emit
+///   `AdvanceDialogueEvent`
+///
+/// # Behavior
+/// 1. A line still being typed is revealed in full
+/// 2. A line with choices jumps to the picked choice's `goto` line (a
+///    missing choice is ignored)
+/// 3. Any other line continues to the next one
+/// 4. Moving past the last line (or a choice without `goto`) ends the
+///    script, and the next queued script starts
+pub fn advance_dialogue_system(
+    scripts: Res<DialogueScripts>,
+    mut dialogue: ResMut<Dialogue>,
+    mut events: EventReader<AdvanceDialogueEvent>,
+) {
+    for event in events.read() {
+        let Some(active) = dialogue.active.clone() else {
+            break;
+        };
+        let Some(script) = scripts.scripts.get(&active.script) else {
+            break;
+        };
+        let Some(line) = script.lines.get(active.line) else {
+            break;
+        };
+
+        let length = line.text.chars().count();
+        let next = if (active.revealed as usize) < length {
+            dialogue.active = Some(ActiveDialogue {
+                revealed: length as f32,
+                ..active
+            });
+            continue;
+        } else if line.choices.is_empty() {
+            Some(active.line + 1)
+        } else {
+            match event.choice.and_then(|choice| line.choices.get(choice)) {
+                Some(choice) => choice.goto,
+                None => continue,
+            }
+        };
+
+        dialogue.active = match next.filter(|next| *next < script.lines.len()) {
+            Some(next) => Some(ActiveDialogue {
+                line: next,
+                revealed: 0.0,
+                ..active
+            }),
+            None => dialogue.queued.pop_front().map(ActiveDialogue::start),
+        };
+    }
+}
+
+/// System that types out the dialogue line at `TYPEWRITER_CHARS_PER_SEC`
+///
+/// # System Dependencies
+/// - **Resources**: Reads `GameClock` and `DialogueScripts`, writes `Dialogue`
+pub fn typewriter_system(
+    clock: Res<GameClock>,
+    scripts: Res<DialogueScripts>,
+    mut dialogue: ResMut<Dialogue>,
+) {
+    let Some(length) = dialogue
+        .current(&scripts)
+        .map(|(line, _)| line.text.chars().count() as f32)
+    else {
+        return;
+    };
+    if let Some(active) = dialogue.active.as_mut()
+        && active.revealed < length
+    {
+        active.revealed = (active.revealed + clock.delta * TYPEWRITER_CHARS_PER_SEC).min(length);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn line(text: &str, choices: Vec<DialogueChoice>) -> DialogueLine {
+        DialogueLine {
+            speaker: None,
+            text: text.to_string(),
+            choices,
+        }
+    }
+
+    fn setup_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.init_resource::<Dialogue>();
+        app.insert_resource(GameClock {
+            delta: 0.1,
+            ..default()
+        });
+        app.add_event::<StartDialogueEvent>();
+        app.add_event::<AdvanceDialogueEvent>();
+        app.add_systems(
+            Update,
+            (
+                start_dialogue_system,
+                advance_dialogue_system,
+                typewriter_system,
+            )
+                .chain(),
+        );
+        let choice = |text: &str, goto| DialogueChoice {
+            text: text.to_string(),
+            goto,
+        };
+        app.insert_resource(DialogueScripts {
+            scripts: [
+                (
+                    "portrait".to_string(),
+                    DialogueScript {
+                        lines: vec![
+                            line("A stern woman.", vec![]),
+                            line(
+                                "Look closer?",
+                                vec![choice("Yes", Some(2)), choice("No", None)],
+                            ),
+                            line("Her eyes follow you.", vec![]),
+                        ],
+                        trigger: None,
+                        once: true,
+                    },
+                ),
+                (
+                    "draft".to_string(),
+                    DialogueScript {
+                        lines: vec![line("A cold draft.", vec![])],
+                        trigger: None,
+                        once: false,
+                    },
+                ),
+            ]
+            .into(),
+        });
+        app
+    }
+
+    fn shown(app: &App) -> Option<(String, usize)> {
+        app.world()
+            .resource::<Dialogue>()
+            .active
+            .as_ref()
+            .map(|active| (active.script.clone(), active.line))
+    }
+
+    fn advance(app: &mut App, choice: Option<usize>) {
+        app.world_mut().send_event(AdvanceDialogueEvent { choice });
+        app.update();
+    }
+
+    #[test]
+    fn scripts_follow_choices_and_queue() {
+        let mut app = setup_app();
+        for script in ["portrait", "draft", "portrait"] {
+            app.world_mut().send_event(StartDialogueEvent {
+                script: script.to_string(),
+            });
+        }
+        app.update();
+        assert_eq!(shown(&app), Some(("portrait".to_string(), 0)));
+        assert_eq!(app.world().resource::<Dialogue>().queued, ["draft"]);
+
+        // The first advance only finishes the typewriter
+        advance(&mut app, None);
+        assert_eq!(shown(&app), Some(("portrait".to_string(), 0)));
+        advance(&mut app, None);
+        assert_eq!(shown(&app), Some(("portrait".to_string(), 1)));
+
+        // Lines with choices wait for one
+        advance(&mut app, None);
+        advance(&mut app, None);
+        assert_eq!(shown(&app), Some(("portrait".to_string(), 1)));
+        advance(&mut app, Some(0));
+        assert_eq!(shown(&app), Some(("portrait".to_string(), 2)));
+
+        advance(&mut app, None);
+        advance(&mut app, None);
+        assert_eq!(shown(&app), Some(("draft".to_string(), 0)));
+
+        // `once` scripts don't replay; others do
+        for script in ["portrait", "draft"] {
+            app.world_mut().send_event(StartDialogueEvent {
+                script: script.to_string(),
+            });
+        }
+        advance(&mut app, None);
+        advance(&mut app, None);
+        assert_eq!(shown(&app), Some(("draft".to_string(), 0)));
+        advance(&mut app, None);
+        advance(&mut app, None);
+        assert_eq!(shown(&app), None);
+    }
+
+    #[test]
+    fn typewriter_reveals_text_over_time() {
+        let mut app = setup_app();
+        app.world_mut().send_event(StartDialogueEvent {
+            script: "draft".to_string(),
+        });
+        app.update();
+        app.update();
+
+        let dialogue = app.world().resource::<Dialogue>();
+        let scripts = app.world().resource::<DialogueScripts>();
+        let (line, revealed) = dialogue.current(scripts).unwrap();
+        assert_eq!(revealed, 8);
+        assert_eq!(revealed_text(line, revealed), "A cold d");
+        assert_eq!(revealed_text(line, 100), "A cold draft.");
+    }
+
+    #[test]
+    fn walking_into_an_area_triggers_once_per_entry() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_event::<StartDialogueEvent>();
+        app.add_systems(Update, dialogue_area_system);
+        app.world_mut().spawn((
+            DialogueTrigger {
+                script: "draft".to_string(),
+                on: TriggerOn::Enter,
+            },
+            Transform::from_xyz(100.0, 0.0, 0.0),
+            Collider {
+                min: Vec2::splat(-32.0),
+                max: Vec2::splat(32.0),
+            },
+        ));
+        let player = app
+            .world_mut()
+            .spawn((Player, Transform::from_xyz(0.0, 0.0, 0.0)))
+            .id();
+
+        let mut triggered = 0;
+        for x in [0.0, 80.0, 100.0, 200.0, 90.0] {
+            app.world_mut()
+                .get_mut::<Transform>(player)
+                .unwrap()
+                .translation
+                .x = x;
+            app.update();
+            triggered += app
+                .world_mut()
+                .resource_mut::<Events<StartDialogueEvent>>()
+                .drain()
+                .count();
+        }
+        assert_eq!(triggered, 2);
+    }
+
+    #[test]
+    fn gameplay_triggers_start_their_scripts() {
+        let mut app = setup_app();
+        app.add_event::<GameplayTrigger>();
+        app.add_systems(
+            Update,
+            gameplay_dialogue_system.before(start_dialogue_system),
+        );
+        app.world_mut()
+            .resource_mut::<DialogueScripts>()
+            .scripts
+            .get_mut("draft")
+            .unwrap()
+            .trigger = Some(ScriptTrigger::Gameplay("cellar_stairs".to_string()));
+
+        let zone = app.world_mut().spawn_empty().id();
+        app.world_mut().send_event(GameplayTrigger {
+            name: "attic_door".to_string(),
+            zone,
+        });
+        app.update();
+        assert_eq!(shown(&app), None);
+
+        app.world_mut().send_event(GameplayTrigger {
+            name: "cellar_stairs".to_string(),
+            zone,
+        });
+        app.update();
+        assert_eq!(shown(&app), Some(("draft".to_string(), 0)));
+    }
+
+    #[test]
+    fn dialogue_file_parses() {
+        let scripts = load_dialogue_scripts(DIALOGUE_PATH).expect("dialogue should parse");
+        assert!(!scripts.scripts.is_empty());
+        for (id, script) in &scripts.scripts {
+            for choice in script.lines.iter().flat_map(|line| &line.choices) {
+                assert!(
+                    choice.goto.is_none_or(|goto| goto < script.lines.len()),
+                    "Choice '{}' in '{}' jumps past the last line",
+                    choice.text,
+                    id
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,503 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::components::enemy::Stalker;
+use crate::components::lighting::{CandleState, CandleWax, CarriedCandle};
+use crate::components::player::Player;
+use crate::components::render_layer::RenderLayer;
+use crate::components::room::RoomId;
+use crate::resources::game_clock::GameClock;
+use crate::resources::game_state::GameState;
+use crate::systems::level_loader::{LevelRegistry, RoomLevels};
+use crate::systems::trap::PlayerDeathEvent;
+
+/// Wax percentage below which a low candle adds to stress
+pub const LOW_WAX_STRESS_THRESHOLD: f32 = 30.0;
+
+/// Seconds without candle light after which darkness adds full stress
+pub const DARKNESS_STRESS_SECS: f32 = 30.0;
+
+/// Seconds a death keeps counting as recent
+pub const RECENT_DEATH_WINDOW: f32 = 180.0;
+
+/// Recent deaths that add full stress
+pub const RECENT_DEATHS_FOR_FULL_STRESS: usize = 3;
+
+/// Share of the stress level from a low candle
+pub const LOW_WAX_STRESS_WEIGHT: f32 = 0.4;
+
+/// Share of the stress level from time spent in darkness
+pub const DARKNESS_STRESS_WEIGHT: f32 = 0.35;
+
+/// Share of the stress level from recent deaths
+pub const DEATH_STRESS_WEIGHT: f32 = 0.25;
+
+/// Stress level above which the director holds back to let the player recover
+pub const PEAK_STRESS: f32 = 0.9;
+
+/// Closest (pixels) to the player a hazard may appear
+pub const MIN_SPAWN_DISTANCE: f32 = 300.0;
+
+/// Sprite size (pixels) of a spawned stalker
+pub const STALKER_SIZE: Vec2 = Vec2::new(32.0, 64.0);
+
+/// Placeholder sprite color of a spawned stalker
+pub const STALKER_COLOR: Color = Color::srgb(0.15, 0.1, 0.15);
+
+/// Plugin that registers the director
+///
+/// The director paces hazards like the stalker: it measures how stressed
+/// the player is and spawns hazards within the current room's
+/// `DirectorBudget` only while stress is building, never at its peak.
+pub struct DirectorPlugin;
+
+impl Plugin for DirectorPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Director>()
+            .init_resource::<GameClock>()
+            .init_resource::<LevelRegistry>()
+            .add_event::<PlayerDeathEvent>()
+            .add_systems(
+                Update,
+                (director_stress_system, director_spawn_system).chain(),
+            );
+    }
+}
+
+/// Hazards the director can spawn
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Hazard {
+    /// The stalker that hunts the player
+    Stalker,
+}
+
+/// Per-room limits on what the director may spawn, from the level file
+///
+/// ```ron
+/// director: Some((
+///     max_spawns: 1,
+///     cooldown: 90.0,
+///     stress_threshold: 0.5,
+///     spawn_points: [(120.0, 540.0)],
+/// )),
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct DirectorBudget {
+    /// Most hazards spawned per visit to the room
+    pub max_spawns: u32,
+    /// Minimum seconds between spawns (and before the first one)
+    #[serde(default = "default_cooldown")]
+    pub cooldown: f32,
+    /// Stress level (0.0-1.0) the player must reach before anything spawns
+    #[serde(default = "default_stress_threshold")]
+    pub stress_threshold: f32,
+    /// Positions (x, y) hazards may appear at, in world space
+    pub spawn_points: Vec<(f32, f32)>,
+    /// Hazards that may appear, spawned in turn
+    #[serde(default = "default_hazards")]
+    pub hazards: Vec<Hazard>,
+}
+
+fn default_cooldown() -> f32 {
+    60.0
+}
+
+fn default_stress_threshold() -> f32 {
+    0.5
+}
+
+fn default_hazards() -> Vec<Hazard> {
+    vec![Hazard::Stalker]
+}
+
+/// Marker for hazards spawned by the director
+///
+/// They belong to the room they were spawned in and are removed when the
+/// player leaves it.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DirectorSpawned(pub Hazard);
+
+/// Resource holding the director's view of the player and the room budget
+#[derive(Resource, Default, Debug)]
+pub struct Director {
+    /// Room the budget was loaded for
+    pub room: Option<RoomId>,
+    /// Budget of the current room (`None` spawns nothing)
+    pub budget: Option<DirectorBudget>,
+    /// Hazards spawned since entering the room
+    pub spawned: u32,
+    /// Seconds since entering the room or the last spawn
+    pub since_last_spawn: f32,
+    /// Seconds the player has gone without candle light
+    pub darkness_secs: f32,
+    /// `GameClock` times of deaths within `RECENT_DEATH_WINDOW`
+    pub recent_deaths: Vec<f32>,
+    /// Current stress level (0.0-1.0)
+    pub stress: f32,
+}
+
+/// Inputs to the stress level
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct StressSignals {
+    /// Wax percentage of the player's candle (`None` without a candle)
+    pub wax: Option<f32>,
+    /// Seconds the player has gone without candle light
+    pub darkness_secs: f32,
+    /// Deaths within `RECENT_DEATH_WINDOW`
+    pub recent_deaths: usize,
+}
+
+/// Combines the stress signals into a level from 0.0 (calm) to 1.0
+///
+/// A candle below `LOW_WAX_STRESS_THRESHOLD`, darkness and recent deaths
+/// each add up to their weight, growing linearly.
+pub fn stress_level(signals: &StressSignals) -> f32 {
+    let wax = signals.wax.map_or(0.0, |wax| {
+        ((LOW_WAX_STRESS_THRESHOLD - wax) / LOW_WAX_STRESS_THRESHOLD).clamp(0.0, 1.0)
+    });
+    let darkness = (signals.darkness_secs / DARKNESS_STRESS_SECS).clamp(0.0, 1.0);
+    let deaths =
+        (signals.recent_deaths as f32 / RECENT_DEATHS_FOR_FULL_STRESS as f32).clamp(0.0, 1.0);
+
+    (wax * LOW_WAX_STRESS_WEIGHT + darkness * DARKNESS_STRESS_WEIGHT + deaths * DEATH_STRESS_WEIGHT)
+        .clamp(0.0, 1.0)
+}
+
+impl Director {
+    /// Whether a hazard may spawn now
+    ///
+    /// Requires a budget with spawns left, its cooldown passed since the
+    /// last spawn, and stress between the budget's threshold and
+    /// `PEAK_STRESS`.
+    pub fn should_spawn(&self) -> bool {
+        let Some(budget) = &self.budget else {
+            return false;
+        };
+        self.spawned < budget.max_spawns
+            && !budget.spawn_points.is_empty()
+            && !budget.hazards.is_empty()
+            && self.since_last_spawn >= budget.cooldown
+            && self.stress >= budget.stress_threshold
+            && self.stress <= PEAK_STRESS
+    }
+
+    /// Hazard the next spawn will be (budget hazards taken in turn)
+    pub fn next_hazard(&self) -> Option<Hazard> {
+        let hazards = &self.budget.as_ref()?.hazards;
+        hazards
+            .get(self.spawned as usize % hazards.len().max(1))
+            .copied()
+    }
+
+    /// Switches to the budget of `room`, starting its spawn count afresh
+    pub fn enter_room(&mut self, room: RoomId, budget: Option<DirectorBudget>) {
+        self.room = Some(room);
+        self.budget = budget;
+        self.spawned = 0;
+        self.since_last_spawn = 0.0;
+    }
+}
+
+/// Picks where a hazard appears
+///
+/// Prefers the spawn point farthest from the player, so hazards approach
+/// rather than appear on top of them.
+///
+/// # Returns
+/// `None` when every spawn point is within `MIN_SPAWN_DISTANCE` of the player
+pub fn pick_spawn_point(points: &[(f32, f32)], player: Option<Vec2>) -> Option<Vec2> {
+    let mut points = points.iter().map(|(x, y)| Vec2::new(*x, *y));
+    let Some(player) = player else {
+        return points.next();
+    };
+    points
+        .filter(|point| point.distance(player) >= MIN_SPAWN_DISTANCE)
+        .max_by(|a, b| a.distance(player).total_cmp(&b.distance(player)))
+}
+
+/// Spawns `hazard` at `position`
+pub fn spawn_hazard(commands: &mut Commands, hazard: Hazard, position: Vec2) -> Entity {
+    match hazard {
+        Hazard::Stalker => commands
+            .spawn((
+                Stalker,
+                DirectorSpawned(hazard),
+                RenderLayer::Player,
+                Sprite::from_color(STALKER_COLOR, STALKER_SIZE),
+                Transform::from_xyz(position.x, position.y, RenderLayer::Player.z()),
+            ))
+            .id(),
+    }
+}
+
+/// System that measures the player's stress
+///
+/// # System Dependencies
+/// - **Components**: Reads the player's carried `CandleState` and `CandleWax`
+/// - **Resources**: Reads `GameClock`, writes `Director`
+/// - **Upstream**: `trap_activation_system` emits `PlayerDeathEvent`
+/// - **Downstream**: `director_spawn_system` spawns by the stress level
+///
+/// # Behavior
+/// Darkness accumulates while the player's candle isn't lit (or they have
+/// none) and resets once it is. Deaths count as recent for
+/// `RECENT_DEATH_WINDOW` seconds of game time.
+pub fn director_stress_system(
+    clock: Res<GameClock>,
+    mut director: ResMut<Director>,
+    mut death_events: EventReader<PlayerDeathEvent>,
+    candle_query: Query<(&CandleState, &CandleWax, &CarriedCandle)>,
+) {
+    let now = clock.elapsed;
+    for _ in death_events.read() {
+        director.recent_deaths.push(now);
+    }
+    director
+        .recent_deaths
+        .retain(|time| now - time < RECENT_DEATH_WINDOW);
+
+    let candle = candle_query
+        .iter()
+        .find(|(_, _, carried)| carried.is_players());
+    if candle.is_some_and(|(state, _, _)| *state == CandleState::Lit) {
+        director.darkness_secs = 0.0;
+    } else {
+        director.darkness_secs += clock.delta;
+    }
+
+    director.stress = stress_level(&StressSignals {
+        wax: candle.map(|(_, wax, _)| wax.0),
+        darkness_secs: director.darkness_secs,
+        recent_deaths: director.recent_deaths.len(),
+    });
+}
+
+/// System that spawns hazards within the current room's budget
+///
+/// # System Dependencies
+/// - **Components**: Reads the player's `Transform`; despawns `DirectorSpawned`
+/// - **Resources**: Reads `GameState`, `GameClock` and `LevelRegistry`,
+///   writes `Director`
+/// - **Upstream**: `director_stress_system` sets the stress level;
+///   `room_transition_system` changes the current room
+///
+/// # Behavior
+/// 1. **Room change**: loads the new room's budget from its level file
+///    and despawns the hazards spawned in the old room
+/// 2. **Spawn**: when `Director::should_spawn` allows, spawns the next
+///    hazard at `pick_spawn_point` and restarts the cooldown
+pub fn director_spawn_system(
+    mut commands: Commands,
+    clock: Res<GameClock>,
+    game_state: Option<Res<GameState>>,
+    levels: RoomLevels,
+    mut director: ResMut<Director>,
+    player_query: Query<&Transform, With<Player>>,
+    spawned_query: Query<Entity, With<DirectorSpawned>>,
+) {
+    let Some(game_state) = game_state else {
+        return;
+    };
+
+    if director.room != Some(game_state.current_room) {
+        for entity in &spawned_query {
+            commands.entity(entity).despawn();
+        }
+        let budget = match levels.load(game_state.current_room) {
+            Ok(level) => level.and_then(|level| level.director),
+            Err(e) => {
+                warn!(
+                    "No director budget for room {}: {}",
+                    game_state.current_room, e
+                );
+                None
+            }
+        };
+        director.enter_room(game_state.current_room, budget);
+    }
+
+    director.since_last_spawn += clock.delta;
+    if !director.should_spawn() {
+        return;
+    }
+
+    let player = player_query
+        .single()
+        .ok()
+        .map(|transform| transform.translation.truncate());
+    let (Some(hazard), Some(position)) = (
+        director.next_hazard(),
+        director
+            .budget
+            .as_ref()
+            .and_then(|budget| pick_spawn_point(&budget.spawn_points, player)),
+    ) else {
+        return;
+    };
+
+    spawn_hazard(&mut commands, hazard, position);
+    director.spawned += 1;
+    director.since_last_spawn = 0.0;
+    info!("Director spawned {:?} at {}", hazard, position);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn budget() -> DirectorBudget {
+        DirectorBudget {
+            max_spawns: 2,
+            cooldown: 10.0,
+            stress_threshold: 0.3,
+            spawn_points: vec![(100.0, 100.0), (1000.0, 100.0)],
+            hazards: vec![Hazard::Stalker],
+        }
+    }
+
+    #[test]
+    fn stress_grows_with_each_signal() {
+        let calm = StressSignals {
+            wax: Some(100.0),
+            ..default()
+        };
+        assert_eq!(stress_level(&calm), 0.0);
+
+        let low_wax = StressSignals {
+            wax: Some(0.0),
+            ..calm
+        };
+        assert_eq!(stress_level(&low_wax), LOW_WAX_STRESS_WEIGHT);
+
+        let dark = StressSignals {
+            darkness_secs: DARKNESS_STRESS_SECS * 2.0,
+            ..calm
+        };
+        assert_eq!(stress_level(&dark), DARKNESS_STRESS_WEIGHT);
+
+        let everything = StressSignals {
+            wax: Some(0.0),
+            darkness_secs: DARKNESS_STRESS_SECS,
+            recent_deaths: RECENT_DEATHS_FOR_FULL_STRESS,
+        };
+        assert!((stress_level(&everything) - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn director_spawns_only_within_budget_and_stress_band() {
+        let mut director = Director {
+            stress: 0.5,
+            ..default()
+        };
+        assert!(!director.should_spawn(), "No budget, no spawns");
+
+        director.enter_room(0, Some(budget()));
+        assert!(!director.should_spawn(), "Cooldown runs on entering");
+
+        director.since_last_spawn = 10.0;
+        assert!(director.should_spawn());
+
+        director.stress = 0.1;
+        assert!(!director.should_spawn(), "Player too calm");
+        director.stress = 0.95;
+        assert!(!director.should_spawn(), "Player at peak stress");
+
+        director.stress = 0.5;
+        director.spawned = 2;
+        assert!(!director.should_spawn(), "Budget spent");
+    }
+
+    #[test]
+    fn spawn_point_is_farthest_from_player() {
+        let points = [(100.0, 100.0), (1000.0, 100.0)];
+
+        assert_eq!(
+            pick_spawn_point(&points, Some(Vec2::new(200.0, 100.0))),
+            Some(Vec2::new(1000.0, 100.0))
+        );
+        assert_eq!(
+            pick_spawn_point(&points[..1], Some(Vec2::new(200.0, 100.0))),
+            None,
+            "Too close to the player"
+        );
+    }
+
+    #[test]
+    fn director_budget_parses_with_defaults() {
+        let budget: DirectorBudget =
+            ron::from_str("(max_spawns: 1, spawn_points: [(120.0, 540.0)])").unwrap();
+
+        assert_eq!(budget.cooldown, 60.0);
+        assert_eq!(budget.stress_threshold, 0.5);
+        assert_eq!(budget.hazards, vec![Hazard::Stalker]);
+    }
+
+    #[test]
+    fn darkness_and_deaths_raise_stress() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.init_resource::<Director>();
+        app.insert_resource(GameClock {
+            delta: 5.0,
+            elapsed: 5.0,
+            paused: false,
+        });
+        app.add_event::<PlayerDeathEvent>();
+        app.add_systems(Update, director_stress_system);
+
+        let player = app.world_mut().spawn(Player).id();
+        app.world_mut().send_event(PlayerDeathEvent {
+            player,
+            cause: crate::resources::map_state::DeathCause::Unknown,
+        });
+        app.update();
+
+        let director = app.world().resource::<Director>();
+        assert_eq!(director.darkness_secs, 5.0, "No candle counts as darkness");
+        assert_eq!(director.recent_deaths.len(), 1);
+        assert!(director.stress > 0.0);
+    }
+
+    #[test]
+    fn director_spawns_stalker_and_clears_it_on_room_change() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.init_resource::<GameState>();
+        app.insert_resource(GameClock {
+            delta: 1.0,
+            elapsed: 1.0,
+            paused: false,
+        });
+        let mut director = Director {
+            stress: 0.5,
+            ..default()
+        };
+        director.enter_room(0, Some(budget()));
+        director.since_last_spawn = 10.0;
+        app.insert_resource(director);
+        app.init_resource::<LevelRegistry>();
+        app.add_systems(Update, director_spawn_system);
+        app.world_mut()
+            .spawn((Player, Transform::from_xyz(200.0, 100.0, 0.0)));
+
+        app.update();
+
+        let mut stalkers = app
+            .world_mut()
+            .query_filtered::<&Transform, With<Stalker>>();
+        let positions: Vec<Vec2> = stalkers
+            .iter(app.world())
+            .map(|transform| transform.translation.truncate())
+            .collect();
+        assert_eq!(positions, vec![Vec2::new(1000.0, 100.0)]);
+        assert_eq!(app.world().resource::<Director>().spawned, 1);
+
+        app.world_mut().resource_mut::<GameState>().current_room = 9999;
+        app.update();
+
+        assert_eq!(stalkers.iter(app.world()).count(), 0);
+        let director = app.world().resource::<Director>();
+        assert_eq!(director.room, Some(9999));
+        assert!(director.budget.is_none());
+    }
+}
@@ -0,0 +1,407 @@
+use bevy::prelude::*;
+use std::collections::HashSet;
+
+use crate::audio::sound_bank::{PlaySoundEvent, SoundCue};
+use crate::components::inventory::{Inventory, Item, ToolType};
+use crate::components::player::{Health, HitPoints, Invincibility, Player};
+use crate::components::puzzle::PuzzleState;
+use crate::components::room::{Collider, Door, Interactable};
+use crate::resources::map_state::DeathCause;
+use crate::resources::room_state::RoomStateStore;
+use crate::systems::collision::aabb_intersects;
+use crate::systems::interaction::{CutWireEvent, OpenDoorEvent};
+use crate::systems::trap::PlayerDeathEvent;
+
+/// Hit points taken by one electric shock
+pub const ELECTRIC_SHOCK_DAMAGE: f32 = 35.0;
+
+/// Default electrified floor size (width, height) in pixels
+pub const DEFAULT_ELECTRIFIED_FLOOR_SIZE: (f32, f32) = (64.0, 8.0);
+
+/// Size (pixels) of a cuttable wire's sprite
+pub const LIVE_WIRE_SIZE: Vec2 = Vec2::new(6.0, 48.0);
+
+/// Color of an electrified floor while powered
+pub const LIVE_FLOOR_COLOR: Color = Color::srgb(0.55, 0.75, 1.0);
+
+/// Color of an electrified floor without power
+pub const DEAD_FLOOR_COLOR: Color = Color::srgb(0.3, 0.3, 0.32);
+
+/// Color of an intact wire
+pub const WIRE_COLOR: Color = Color::srgb(0.75, 0.2, 0.15);
+
+/// Color of a wire that has been cut
+pub const CUT_WIRE_COLOR: Color = Color::srgb(0.25, 0.1, 0.08);
+
+/// Plugin for electrified floors and doors, their power and cutting wires
+///
+/// Hazards are powered by the circuit breaker puzzles named by their
+/// `Electrified::circuit`, until the wire feeding them is cut.
+pub struct ElectricalPlugin;
+
+impl Plugin for ElectricalPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<RoomStateStore>()
+            .add_event::<CutWireEvent>()
+            .add_event::<OpenDoorEvent>()
+            .add_event::<PlayerDeathEvent>()
+            .add_event::<PlaySoundEvent>()
+            .add_systems(
+                Update,
+                (wire_cut_system, circuit_power_system, electric_shock_system).chain(),
+            );
+    }
+}
+
+/// Component for a hazard that shocks the player while powered (floors,
+/// door handles)
+///
+/// Floors shock a player touching their `Collider`; `Door`s shock a player
+/// trying to open them, and stay shut while powered.
+#[derive(Component, Debug, Clone, Default, PartialEq)]
+pub struct Electrified {
+    /// Circuit the hazard is on; always live when `None`
+    pub circuit: Option<String>,
+    /// Wire feeding the hazard; cutting it disables the hazard for good
+    pub wire: Option<String>,
+    /// Whether current is flowing (kept up to date by `circuit_power_system`)
+    pub powered: bool,
+}
+
+/// Component naming the circuit a circuit breaker puzzle switches
+///
+/// The circuit is live once the puzzle is solved (all fuses in).
+#[derive(Component, Debug, Clone, PartialEq, Eq)]
+pub struct BreakerCircuit(pub String);
+
+/// Component for a wire the player can cut with wire cutters
+///
+/// Needs an `Interactable` of kind `InteractionKind::CutWire`.
+#[derive(Component, Debug, Clone, PartialEq, Eq)]
+pub struct LiveWire {
+    /// Name the hazards it feeds refer to in `Electrified::wire`
+    pub name: String,
+}
+
+/// System that cuts wires with wire cutters
+///
+/// # System Dependencies
+/// - **Upstream**: `interaction_system` emits `CutWireEvent`
+/// - **Components**: Reads `LiveWire` and the player's `Inventory`; writes
+///   the wire's `Sprite`, removes its `Interactable`
+/// - **Resources**: Writes `RoomStateStore`
+/// - **Downstream**: `circuit_power_system` cuts power to the wire's hazards
+///
+/// # Behavior
+/// Without wire cutters in the inventory nothing happens. Cut wires are
+/// recorded by name, so they stay cut across room visits and saves; wires
+/// spawned already cut are shown cut and can't be interacted with.
+pub fn wire_cut_system(
+    mut commands: Commands,
+    mut events: EventReader<CutWireEvent>,
+    mut store: ResMut<RoomStateStore>,
+    inventory_query: Query<&Inventory, With<Player>>,
+    mut wire_query: Query<(Entity, &LiveWire, Option<&mut Sprite>), With<Interactable>>,
+    mut sound_events: EventWriter<PlaySoundEvent>,
+) {
+    for event in events.read() {
+        let Ok((_, wire, _)) = wire_query.get(event.wire) else {
+            continue;
+        };
+        let has_cutters = inventory_query.get(event.player).is_ok_and(|inventory| {
+            inventory
+                .items
+                .iter()
+                .any(|item| matches!(item, Item::Tool(ToolType::WireCutters)))
+        });
+        if !has_cutters {
+            info!("The wire is too thick to pull loose (wire cutters needed)");
+            continue;
+        }
+
+        store
+            .bypass_change_detection()
+            .cut_wires
+            .insert(wire.name.clone());
+        sound_events.write(PlaySoundEvent {
+            cue: SoundCue::WireSnip,
+        });
+    }
+
+    for (entity, wire, sprite) in &mut wire_query {
+        if !store.is_wire_cut(&wire.name) {
+            continue;
+        }
+        commands.entity(entity).remove::<Interactable>();
+        if let Some(mut sprite) = sprite {
+            sprite.color = CUT_WIRE_COLOR;
+        }
+    }
+}
+
+/// System that works out which electrified hazards are powered
+///
+/// # System Dependencies
+/// - **Components**: Reads `BreakerCircuit` and `PuzzleState` of circuit
+///   breaker puzzles; writes `Electrified` and the `Sprite` of electrified
+///   floors
+/// - **Resources**: Reads `RoomStateStore` (cut wires)
+/// - **Upstream**: `wire_cut_system`
+/// - **Downstream**: `electric_shock_system`
+///
+/// # Behavior
+/// A hazard is powered unless its wire has been cut or its circuit's
+/// breaker isn't solved. Floors light up while powered and go dark when
+/// the power is off.
+pub fn circuit_power_system(
+    store: Res<RoomStateStore>,
+    breaker_query: Query<(&BreakerCircuit, &PuzzleState)>,
+    mut hazard_query: Query<(&mut Electrified, Option<&mut Sprite>, Has<Door>)>,
+) {
+    let live: HashSet<&str> = breaker_query
+        .iter()
+        .filter(|(_, state)| **state == PuzzleState::Solved)
+        .map(|(circuit, _)| circuit.0.as_str())
+        .collect();
+
+    for (mut electrified, sprite, door) in &mut hazard_query {
+        let wire_cut = electrified
+            .wire
+            .as_deref()
+            .is_some_and(|wire| store.is_wire_cut(wire));
+        let circuit_live = electrified
+            .circuit
+            .as_deref()
+            .is_none_or(|circuit| live.contains(circuit));
+        let powered = circuit_live && !wire_cut;
+        if electrified.powered == powered && !electrified.is_added() {
+            continue;
+        }
+        electrified.powered = powered;
+
+        if let Some(mut sprite) = sprite.filter(|_| !door) {
+            sprite.color = if powered {
+                LIVE_FLOOR_COLOR
+            } else {
+                DEAD_FLOOR_COLOR
+            };
+        }
+    }
+}
+
+/// System that shocks players touching powered hazards
+///
+/// # System Dependencies
+/// - **Upstream**: `circuit_power_system`; `interaction_system` emits
+///   `OpenDoorEvent`
+/// - **Components**: Reads `Electrified`, `Transform` and `Collider` of
+///   hazards and the player; writes the player's `Health` and `HitPoints`,
+///   inserts `Invincibility`
+/// - **Downstream**: Emits `PlayerDeathEvent` (`DeathCause::Electrocution`)
+///   and `PlaySoundEvent` (`SoundCue::ElectricZap`)
+///
+/// # Behavior
+/// Touching a powered floor, or trying to open a powered door, deals
+/// `ELECTRIC_SHOCK_DAMAGE` followed by invincibility frames, so standing
+/// on a live floor shocks again each time they run out. Players without
+/// `HitPoints` are killed outright.
+#[allow(clippy::type_complexity)]
+pub fn electric_shock_system(
+    mut commands: Commands,
+    mut door_events: EventReader<OpenDoorEvent>,
+    hazard_query: Query<(&Electrified, &Transform, Option<&Collider>, Has<Door>)>,
+    mut player_query: Query<
+        (
+            Entity,
+            &Transform,
+            &Collider,
+            &mut Health,
+            Option<&mut HitPoints>,
+        ),
+        (With<Player>, Without<Invincibility>),
+    >,
+    mut death_events: EventWriter<PlayerDeathEvent>,
+    mut sound_events: EventWriter<PlaySoundEvent>,
+) {
+    let mut shocked: HashSet<Entity> = door_events
+        .read()
+        .filter(|event| {
+            hazard_query
+                .get(event.door)
+                .is_ok_and(|(electrified, _, _, door)| door && electrified.powered)
+        })
+        .map(|event| event.player)
+        .collect();
+
+    for (player, transform, collider, _, _) in &player_query {
+        let pos = transform.translation.truncate();
+        let touching = hazard_query
+            .iter()
+            .any(|(electrified, hazard, hazard_collider, _)| {
+                electrified.powered
+                    && hazard_collider.is_some_and(|hazard_collider| {
+                        aabb_intersects(
+                            pos,
+                            collider,
+                            hazard.translation.truncate(),
+                            hazard_collider,
+                        )
+                    })
+            });
+        if touching {
+            shocked.insert(player);
+        }
+    }
+
+    for player in shocked {
+        let Ok((_, _, _, mut health, hit_points)) = player_query.get_mut(player) else {
+            continue;
+        };
+        if *health == Health::Dead {
+            continue;
+        }
+
+        let killed = match hit_points {
+            Some(mut hit_points) => hit_points.apply_damage(ELECTRIC_SHOCK_DAMAGE),
+            None => true,
+        };
+        sound_events.write(PlaySoundEvent {
+            cue: SoundCue::ElectricZap,
+        });
+        if killed {
+            *health = Health::Dead;
+            death_events.write(PlayerDeathEvent {
+                player,
+                cause: DeathCause::Electrocution,
+            });
+        } else {
+            commands.entity(player).insert(Invincibility::default());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::room::{DoorState, InteractionKind};
+
+    fn setup_app() -> App {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, ElectricalPlugin));
+        app
+    }
+
+    fn spawn_player(app: &mut App, items: Vec<Item>) -> Entity {
+        app.world_mut()
+            .spawn((
+                Player,
+                Health::Alive,
+                HitPoints::new(100.0),
+                Inventory {
+                    items,
+                    max_capacity: 10,
+                },
+                Transform::from_xyz(0.0, 16.0, 30.0),
+                Collider {
+                    min: Vec2::splat(-16.0),
+                    max: Vec2::splat(16.0),
+                },
+            ))
+            .id()
+    }
+
+    fn floor(circuit: Option<&str>, wire: Option<&str>) -> impl Bundle {
+        (
+            Electrified {
+                circuit: circuit.map(str::to_string),
+                wire: wire.map(str::to_string),
+                ..default()
+            },
+            Transform::default(),
+            Collider {
+                min: Vec2::new(-32.0, -4.0),
+                max: Vec2::new(32.0, 4.0),
+            },
+        )
+    }
+
+    #[test]
+    fn floors_shock_only_while_their_breaker_is_on() {
+        let mut app = setup_app();
+        let breaker = app
+            .world_mut()
+            .spawn((BreakerCircuit("cellar".to_string()), PuzzleState::Unsolved))
+            .id();
+        let floor = app.world_mut().spawn(floor(Some("cellar"), None)).id();
+        let player = spawn_player(&mut app, vec![]);
+
+        app.update();
+        assert!(!app.world().get::<Electrified>(floor).unwrap().powered);
+        assert_eq!(app.world().get::<HitPoints>(player).unwrap().current, 100.0);
+
+        *app.world_mut().get_mut::<PuzzleState>(breaker).unwrap() = PuzzleState::Solved;
+        app.update();
+        assert!(app.world().get::<Electrified>(floor).unwrap().powered);
+        assert_eq!(
+            app.world().get::<HitPoints>(player).unwrap().current,
+            100.0 - ELECTRIC_SHOCK_DAMAGE
+        );
+        assert!(app.world().get::<Invincibility>(player).is_some());
+    }
+
+    #[test]
+    fn cutting_the_wire_makes_its_door_safe_for_good() {
+        let mut app = setup_app();
+        let wire = app
+            .world_mut()
+            .spawn((
+                LiveWire {
+                    name: "study_door".to_string(),
+                },
+                Interactable::new(InteractionKind::CutWire),
+            ))
+            .id();
+        let door = app
+            .world_mut()
+            .spawn((
+                Door,
+                DoorState::Unlocked,
+                Electrified {
+                    wire: Some("study_door".to_string()),
+                    ..default()
+                },
+                Transform::from_xyz(200.0, 0.0, 20.0),
+            ))
+            .id();
+        let player = spawn_player(&mut app, vec![]);
+        app.update();
+
+        app.world_mut().send_event(OpenDoorEvent { door, player });
+        app.world_mut().send_event(CutWireEvent { wire, player });
+        app.update();
+        assert_eq!(
+            app.world().get::<HitPoints>(player).unwrap().current,
+            100.0 - ELECTRIC_SHOCK_DAMAGE,
+            "Grabbed a live handle"
+        );
+        assert!(
+            app.world().get::<Interactable>(wire).is_some(),
+            "No cutters"
+        );
+
+        app.world_mut()
+            .get_mut::<Inventory>(player)
+            .unwrap()
+            .items
+            .push(Item::Tool(ToolType::WireCutters));
+        app.world_mut().send_event(CutWireEvent { wire, player });
+        app.update();
+        assert!(!app.world().get::<Electrified>(door).unwrap().powered);
+        assert!(app.world().get::<Interactable>(wire).is_none());
+        assert!(
+            app.world()
+                .resource::<RoomStateStore>()
+                .is_wire_cut("study_door")
+        );
+    }
+}
@@ -1,9 +1,17 @@
 use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+
+use crate::components::player::Velocity;
+use crate::components::room::Pushable;
+
+/// Default fixed timestep rate (ticks per second) for physics
+pub const DEFAULT_PHYSICS_HZ: f64 = 60.0;
 
 /// Plugin that configures fixed timestep scheduling for deterministic game logic
 ///
-/// Implements a fixed 60Hz update rate for physics, collision detection, and gameplay
-/// systems to ensure deterministic behavior across different frame rates and platforms.
+/// Implements a fixed update rate (`PhysicsRate`, 60Hz by default) for physics and
+/// collision detection to ensure deterministic behavior across different frame rates
+/// and platforms.
 ///
 /// # Why Fixed Timestep?
 ///
@@ -14,12 +22,19 @@ use bevy::prelude::*;
 ///
 /// # Systems Scheduled on Fixed Timestep
 ///
-/// The following systems run on `FixedUpdate` schedule at 60Hz:
-/// 1. Player movement and physics
-/// 2. Collision detection
-/// 3. Trap activation
-/// 4. Candle wax depletion
-/// 5. Respawn logic
+/// The following systems run on the `FixedUpdate` schedule, in `PhysicsSet` order:
+/// 1. Player movement and gravity (`PlayerMovementPlugin`)
+/// 2. Block pushing, solid collision, trap and item collision and pressure
+///    plates (`CollisionPlugin`, `PushablePlugin`)
+///
+/// # Interpolation
+///
+/// Between ticks, entities with `PhysicsInterpolation` (added to anything
+/// with `Velocity` or `Pushable`) are drawn between their last two physics
+/// positions, so movement looks smooth at any frame rate. The true position
+/// is put back before the next tick. A position changed outside physics
+/// (respawn, room transition, save loading) is taken as is, without
+/// interpolating towards it.
 ///
 /// # Systems on Variable Timestep
 ///
@@ -45,13 +60,15 @@ use bevy::prelude::*;
 ///
 /// # Configuration
 ///
-/// The fixed timestep rate is set to 60Hz (16.67ms per tick). To modify:
+/// The fixed timestep rate is read from `PhysicsRate` (60Hz, 16.67ms per tick, if
+/// absent). To modify it, insert the resource before adding the plugin or change
+/// it at runtime:
 ///
 /// ```rust,no_run
 /// # use bevy::prelude::*;
-/// # use bevy::time::Fixed;
+/// # use rust_game::systems::fixed_timestep::PhysicsRate;
 /// # let mut app = App::new();
-/// app.insert_resource(Time::<Fixed>::from_hz(120.0)); // 120Hz
+/// app.insert_resource(PhysicsRate { hz: 120.0 }); // 120Hz
 /// ```
 ///
 /// # Performance Considerations
@@ -65,16 +82,156 @@ pub struct FixedTimestepPlugin;
 
 impl Plugin for FixedTimestepPlugin {
     fn build(&self, app: &mut App) {
-        // Configure fixed timestep at 60Hz (60 updates per second)
-        // This provides deterministic game logic across all platforms
-        app.insert_resource(Time::<Fixed>::from_hz(60.0));
+        // Configure the fixed timestep (60 updates per second unless
+        // configured otherwise). This provides deterministic game logic
+        // across all platforms
+        let hz = app
+            .world()
+            .get_resource::<PhysicsRate>()
+            .map_or(DEFAULT_PHYSICS_HZ, |rate| rate.hz);
+        app.insert_resource(PhysicsRate { hz })
+            .insert_resource(Time::<Fixed>::from_hz(hz))
+            .configure_sets(
+                FixedUpdate,
+                (PhysicsSet::Movement, PhysicsSet::Collision).chain(),
+            )
+            .add_systems(
+                First,
+                physics_rate_system.run_if(resource_changed::<PhysicsRate>),
+            )
+            .add_systems(FixedFirst, record_physics_translation_system)
+            .add_systems(
+                RunFixedMainLoop,
+                (
+                    (
+                        track_interpolation_system,
+                        restore_physics_translation_system,
+                    )
+                        .chain()
+                        .in_set(RunFixedMainLoopSystem::BeforeFixedMainLoop),
+                    interpolate_translation_system
+                        .in_set(RunFixedMainLoopSystem::AfterFixedMainLoop),
+                ),
+            );
 
         // Note: Individual game systems are added to FixedUpdate schedule
         // in their respective plugin modules (e.g., PlayerMovementPlugin,
-        // CollisionPlugin, etc.)
-        //
-        // This plugin only configures the timestep rate. Systems should
-        // use app.add_systems(FixedUpdate, system_fn) in their own plugins.
+        // CollisionPlugin, etc.), in a `PhysicsSet`.
+    }
+}
+
+/// Resource holding the fixed timestep rate (ticks per second)
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct PhysicsRate {
+    /// Fixed updates per second
+    pub hz: f64,
+}
+
+impl Default for PhysicsRate {
+    fn default() -> Self {
+        Self {
+            hz: DEFAULT_PHYSICS_HZ,
+        }
+    }
+}
+
+/// System sets ordering the physics systems within `FixedUpdate`
+#[derive(SystemSet, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PhysicsSet {
+    /// Velocity, gravity and position updates
+    Movement,
+    /// Resolving collisions of the moved entities
+    Collision,
+}
+
+/// Component smoothing an entity's drawn position between physics ticks
+///
+/// Only x and y are interpolated; z (render layering) is left alone.
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq)]
+pub struct PhysicsInterpolation {
+    /// Position before the latest tick
+    pub previous: Vec2,
+    /// Position after the latest tick
+    pub current: Vec2,
+    /// Position drawn this frame, to notice moves made outside physics
+    rendered: Option<Vec2>,
+}
+
+/// System that applies a changed `PhysicsRate` to `Time<Fixed>`
+pub fn physics_rate_system(rate: Res<PhysicsRate>, mut fixed_time: ResMut<Time<Fixed>>) {
+    fixed_time.set_timestep_hz(rate.hz);
+}
+
+/// System that adds `PhysicsInterpolation` to moving physics entities
+///
+/// # System Dependencies
+/// - **Components**: Reads `Velocity`, `Pushable`, `Transform`; inserts
+///   `PhysicsInterpolation`
+#[allow(clippy::type_complexity)]
+pub fn track_interpolation_system(
+    mut commands: Commands,
+    query: Query<
+        (Entity, &Transform),
+        (
+            Or<(With<Velocity>, With<Pushable>)>,
+            Without<PhysicsInterpolation>,
+        ),
+    >,
+) {
+    for (entity, transform) in &query {
+        let position = transform.translation.truncate();
+        commands.entity(entity).insert(PhysicsInterpolation {
+            previous: position,
+            current: position,
+            rendered: None,
+        });
+    }
+}
+
+/// System that puts interpolated entities back at their physics position
+///
+/// Runs before the fixed timestep loop. An entity that was moved since it
+/// was drawn keeps its new position instead, which also ends interpolation
+/// from its old one.
+pub fn restore_physics_translation_system(
+    mut query: Query<(&mut Transform, &mut PhysicsInterpolation)>,
+) {
+    for (mut transform, mut interpolation) in &mut query {
+        let position = transform.translation.truncate();
+        if interpolation.rendered == Some(position) {
+            transform.translation.x = interpolation.current.x;
+            transform.translation.y = interpolation.current.y;
+        } else {
+            interpolation.previous = position;
+            interpolation.current = position;
+        }
+    }
+}
+
+/// System that remembers each entity's position before a physics tick
+pub fn record_physics_translation_system(
+    mut query: Query<(&Transform, &mut PhysicsInterpolation)>,
+) {
+    for (transform, mut interpolation) in &mut query {
+        interpolation.previous = transform.translation.truncate();
+    }
+}
+
+/// System that draws entities between their last two physics positions
+///
+/// Runs after the fixed timestep loop, using how far the clock got into
+/// the next tick (`Time<Fixed>::overstep_fraction`).
+pub fn interpolate_translation_system(
+    fixed_time: Res<Time<Fixed>>,
+    mut query: Query<(&mut Transform, &mut PhysicsInterpolation)>,
+) {
+    let fraction = fixed_time.overstep_fraction();
+    for (mut transform, mut interpolation) in &mut query {
+        interpolation.current = transform.translation.truncate();
+        let drawn = interpolation.previous.lerp(interpolation.current, fraction);
+        transform.translation.x = drawn.x;
+        transform.translation.y = drawn.y;
+        interpolation.rendered = Some(drawn);
     }
 }
 
@@ -105,7 +262,10 @@ pub fn get_fixed_timestep(app: &App) -> f32 {
 
 /// Helper function to advance fixed timestep by one tick in tests
 ///
-/// Useful for deterministic testing of game logic.
+/// Useful for deterministic testing of game logic. Runs a frame that is
+/// exactly one fixed timestep long, so `FixedUpdate` runs exactly once.
+/// An app that hasn't updated yet first gets a frame that only starts the
+/// clock. The app's frame time stays pinned to the timestep afterwards.
 ///
 /// # Examples
 ///
@@ -120,7 +280,16 @@ pub fn get_fixed_timestep(app: &App) -> f32 {
 /// advance_fixed_timestep(&mut app);
 /// ```
 pub fn advance_fixed_timestep(app: &mut App) {
-    // Run fixed update schedule once
+    let timestep = app.world().resource::<Time<Fixed>>().timestep();
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(timestep));
+    if app
+        .world()
+        .resource::<Time<Real>>()
+        .first_update()
+        .is_none()
+    {
+        app.update();
+    }
     app.update();
 }
 
@@ -236,4 +405,70 @@ mod tests {
             "Fixed timestep should remain constant regardless of frame updates"
         );
     }
+
+    #[test]
+    fn physics_rate_configures_the_timestep() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.insert_resource(PhysicsRate { hz: 30.0 });
+        app.add_plugins(FixedTimestepPlugin);
+        assert!((get_fixed_timestep(&app) - 1.0 / 30.0).abs() < 0.0001);
+
+        app.insert_resource(PhysicsRate { hz: 120.0 });
+        app.update();
+        assert!((get_fixed_timestep(&app) - 1.0 / 120.0).abs() < 0.0001);
+    }
+
+    #[test]
+    fn drawn_position_is_interpolated_between_ticks() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.insert_resource(PhysicsRate { hz: 50.0 });
+        app.add_plugins(FixedTimestepPlugin);
+        // Half a 20ms tick per frame
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(
+            std::time::Duration::from_millis(10),
+        ));
+        app.add_systems(
+            FixedUpdate,
+            |time: Res<Time>, mut query: Query<(&mut Transform, &Velocity)>| {
+                for (mut transform, velocity) in &mut query {
+                    transform.translation += velocity.0.extend(0.0) * time.delta_secs();
+                }
+            },
+        );
+        let mover = app
+            .world_mut()
+            .spawn((Transform::default(), Velocity(Vec2::new(60.0, 0.0))))
+            .id();
+        let x = |app: &App| app.world().get::<Transform>(mover).unwrap().translation.x;
+
+        // Clock start, then half a tick: nothing has moved yet
+        app.update();
+        app.update();
+        assert_eq!(x(&app), 0.0);
+        assert!(app.world().get::<PhysicsInterpolation>(mover).is_some());
+
+        // First tick moves it to 1.2, drawn from where it was before...
+        app.update();
+        assert_eq!(x(&app), 0.0);
+        // ...and half way there half a tick later
+        app.update();
+        assert!((x(&app) - 0.6).abs() < 0.001);
+
+        // A teleport outside physics isn't interpolated towards or undone
+        app.world_mut()
+            .get_mut::<Transform>(mover)
+            .unwrap()
+            .translation
+            .x = 100.0;
+        app.update();
+        assert_eq!(x(&app), 100.0);
+        let current = app
+            .world()
+            .get::<PhysicsInterpolation>(mover)
+            .unwrap()
+            .current;
+        assert!((current.x - 101.2).abs() < 0.001);
+    }
 }
@@ -0,0 +1,358 @@
+use bevy::prelude::*;
+
+use crate::components::lighting::{CandleState, CandleWax, CarriedCandle};
+use crate::components::player::{Health, HitPoints, Invincibility, Player};
+use crate::resources::difficulty::DifficultyConfig;
+use crate::resources::game_clock::GameClock;
+use crate::resources::game_rules::GameRules;
+use crate::resources::game_state::{GameMode, GameState};
+use crate::systems::light_bake::VisibilityMap;
+use crate::systems::respawn::DeathTimer;
+use crate::systems::save_load::LoadGameEvent;
+use crate::systems::trap::PlayerDeathEvent;
+
+/// Save slot loaded by "Load Last Save" (the auto-save written on room
+/// changes and at checkpoints)
+pub const LAST_SAVE_SLOT: usize = 0;
+
+/// Light level below which the player counts as standing in darkness
+pub const DARKNESS_LIGHT_LEVEL: f32 = 0.1;
+
+/// Seconds in darkness with a burnt-out candle before the run is over
+pub const DARKNESS_GAME_OVER_SECS: f32 = 5.0;
+
+/// Wax a burnt-out candle is given back when the room is restarted
+pub const RESTART_ROOM_WAX: f32 = 25.0;
+
+/// Plugin that registers the failure modes that end a run
+///
+/// With `GameRules::permadeath` the first death ends the run, and
+/// with `DifficultyConfig::darkness_game_over` so does being stranded in the
+/// dark with a burnt-out candle. Both switch to `GameMode::GameOver`, where
+/// the game over screen offers the `RetryOption`s.
+pub struct GameOverPlugin;
+
+impl Plugin for GameOverPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<GameOver>()
+            .init_resource::<DifficultyConfig>()
+            .init_resource::<GameRules>()
+            .init_resource::<GameClock>()
+            .add_event::<PlayerDeathEvent>()
+            .add_event::<RetryEvent>()
+            .add_event::<LoadGameEvent>()
+            .add_systems(Update, (game_over_trigger_system, retry_system).chain());
+    }
+}
+
+/// Why the run ended
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GameOverReason {
+    /// The player died with permadeath enabled
+    Permadeath,
+    /// The candle burnt out and the player was left in darkness
+    Darkness,
+}
+
+/// Resource holding why the current run ended (`None` while playing)
+#[derive(Resource, Debug, Default)]
+pub struct GameOver {
+    /// Failure that switched the game to `GameMode::GameOver`
+    pub reason: Option<GameOverReason>,
+}
+
+/// What the player chose on the game over screen
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RetryOption {
+    /// Load `LAST_SAVE_SLOT`
+    LoadLastSave,
+    /// Go back to the room's spawn point with the player revived
+    RestartRoom,
+    /// Give up and return to the main menu
+    Quit,
+}
+
+/// Event emitted by the game over screen with the player's choice
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryEvent(pub RetryOption);
+
+/// Returns true if a candle in `state` with `wax` left can never light the
+/// way again
+pub fn candle_burnt_out(state: CandleState, wax: f32) -> bool {
+    state == CandleState::Extinguished || wax <= 0.0
+}
+
+/// System that ends the run when a failure mode triggers
+///
+/// # System Dependencies
+/// - **Upstream**: `trap_activation_system` emits `PlayerDeathEvent`;
+///   `composite_visibility_system` fills `VisibilityMap`
+/// - **Components**: Reads the player's `Transform` and carried candle
+/// - **Resources**: Reads `GameRules`, `DifficultyConfig`, `GameClock`, `VisibilityMap`
+///   (when present); writes `GameState` and `GameOver`
+/// - **Downstream**: `respawn_system` holds off while game over; the game
+///   over screen reads `GameOver`
+///
+/// # Behavior
+/// While playing, a death ends the run with permadeath on. Standing where
+/// the light is below `DARKNESS_LIGHT_LEVEL` with a burnt-out candle for
+/// `DARKNESS_GAME_OVER_SECS` of game time ends it too (without a visibility
+/// map the player counts as in the dark).
+#[allow(clippy::too_many_arguments)]
+pub fn game_over_trigger_system(
+    clock: Res<GameClock>,
+    rules: Res<GameRules>,
+    difficulty: Res<DifficultyConfig>,
+    visibility: Option<Res<VisibilityMap>>,
+    mut game_state: ResMut<GameState>,
+    mut game_over: ResMut<GameOver>,
+    mut death_events: EventReader<PlayerDeathEvent>,
+    mut darkness_secs: Local<f32>,
+    player_query: Query<&Transform, With<Player>>,
+    candle_query: Query<(&CandleState, &CandleWax, &CarriedCandle)>,
+) {
+    let died = death_events.read().count() > 0;
+    if game_state.game_mode != GameMode::Playing {
+        *darkness_secs = 0.0;
+        return;
+    }
+
+    if died && rules.permadeath {
+        game_over.reason = Some(GameOverReason::Permadeath);
+    }
+
+    let stranded = difficulty.darkness_game_over
+        && candle_query
+            .iter()
+            .find(|(_, _, carried)| carried.is_players())
+            .is_some_and(|(state, wax, _)| candle_burnt_out(*state, wax.0))
+        && player_query.single().is_ok_and(|transform| {
+            visibility.as_ref().is_none_or(|map| {
+                map.grid.sample(transform.translation.truncate()) < DARKNESS_LIGHT_LEVEL
+            })
+        });
+    *darkness_secs = if stranded {
+        *darkness_secs + clock.delta
+    } else {
+        0.0
+    };
+    if game_over.reason.is_none() && *darkness_secs >= DARKNESS_GAME_OVER_SECS {
+        game_over.reason = Some(GameOverReason::Darkness);
+    }
+
+    if let Some(reason) = game_over.reason {
+        game_state.game_mode = GameMode::GameOver;
+        *darkness_secs = 0.0;
+        info!("Game over: {:?}", reason);
+    }
+}
+
+/// System that carries out the player's choice on the game over screen
+///
+/// # System Dependencies
+/// - **Upstream**: The game over screen emits `RetryEvent`
+/// - **Components**: Writes the player's `Transform`, `Health`, `HitPoints`
+///   and carried candle; removes `DeathTimer` and `Invincibility`
+/// - **Resources**: Writes `GameState` and `GameOver`
+/// - **Downstream**: Emits `LoadGameEvent` for `load_game_system`
+///
+/// # Behavior
+/// - **Load Last Save**: Revives the player and loads `LAST_SAVE_SLOT`
+/// - **Restart Room**: Revives the player at the spawn point; a burnt-out
+///   candle gets `RESTART_ROOM_WAX` back, unlit
+/// - **Quit**: Returns to the main menu
+#[allow(clippy::type_complexity)]
+pub fn retry_system(
+    mut commands: Commands,
+    mut events: EventReader<RetryEvent>,
+    mut game_state: ResMut<GameState>,
+    mut game_over: ResMut<GameOver>,
+    mut load_events: EventWriter<LoadGameEvent>,
+    mut player_query: Query<
+        (Entity, &mut Transform, &mut Health, Option<&mut HitPoints>),
+        With<Player>,
+    >,
+    mut candle_query: Query<(&mut CandleState, &mut CandleWax, &CarriedCandle)>,
+) {
+    let Some(RetryEvent(option)) = events.read().last().copied() else {
+        return;
+    };
+    if game_state.game_mode != GameMode::GameOver {
+        return;
+    }
+    game_over.reason = None;
+
+    if option == RetryOption::Quit {
+        game_state.game_mode = GameMode::Menu;
+        return;
+    }
+
+    for (entity, mut transform, mut health, hit_points) in &mut player_query {
+        *health = Health::Alive;
+        if let Some(mut hit_points) = hit_points {
+            hit_points.restore();
+        }
+        commands
+            .entity(entity)
+            .remove::<(DeathTimer, Invincibility)>();
+        if option == RetryOption::RestartRoom {
+            transform.translation = game_state.player_spawn_point.extend(0.0);
+        }
+    }
+    game_state.game_mode = GameMode::Playing;
+
+    match option {
+        RetryOption::LoadLastSave => {
+            load_events.write(LoadGameEvent {
+                slot: LAST_SAVE_SLOT,
+            });
+        }
+        RetryOption::RestartRoom => {
+            for (mut state, mut wax, carried) in &mut candle_query {
+                if carried.is_players() && candle_burnt_out(*state, wax.0) {
+                    *state = CandleState::Unlit;
+                    wax.0 = wax.0.max(RESTART_ROOM_WAX);
+                }
+            }
+        }
+        RetryOption::Quit => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::resources::map_state::DeathCause;
+
+    fn setup_app(rules: GameRules) -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.insert_resource(rules);
+        app.add_plugins(GameOverPlugin);
+        app.insert_resource(GameState {
+            game_mode: GameMode::Playing,
+            player_spawn_point: Vec2::new(10.0, 20.0),
+            ..default()
+        });
+        app
+    }
+
+    fn spawn_player(app: &mut App, candle: CandleState, wax: f32) -> Entity {
+        app.world_mut()
+            .spawn((candle, CandleWax(wax), CarriedCandle::default()));
+        app.world_mut()
+            .spawn((
+                Player,
+                Health::Alive,
+                HitPoints::new(100.0),
+                Transform::from_xyz(300.0, 300.0, 0.0),
+            ))
+            .id()
+    }
+
+    fn game_mode(app: &App) -> GameMode {
+        app.world().resource::<GameState>().game_mode
+    }
+
+    #[test]
+    fn death_only_ends_the_run_with_permadeath() {
+        for permadeath in [false, true] {
+            let mut app = setup_app(GameRules {
+                permadeath,
+                ..default()
+            });
+            let player = spawn_player(&mut app, CandleState::Lit, 50.0);
+
+            app.world_mut().send_event(PlayerDeathEvent {
+                player,
+                cause: DeathCause::Unknown,
+            });
+            app.update();
+
+            let expected = if permadeath {
+                GameMode::GameOver
+            } else {
+                GameMode::Playing
+            };
+            assert_eq!(game_mode(&app), expected);
+        }
+    }
+
+    #[test]
+    fn burnt_out_candle_in_darkness_ends_the_run() {
+        let mut app = setup_app(GameRules::default());
+        spawn_player(&mut app, CandleState::Extinguished, 0.0);
+
+        app.world_mut().resource_mut::<GameClock>().delta = DARKNESS_GAME_OVER_SECS / 2.0;
+        app.update();
+        assert_eq!(game_mode(&app), GameMode::Playing);
+        app.update();
+
+        assert_eq!(game_mode(&app), GameMode::GameOver);
+        assert_eq!(
+            app.world().resource::<GameOver>().reason,
+            Some(GameOverReason::Darkness)
+        );
+    }
+
+    #[test]
+    fn restart_room_revives_player_with_some_wax() {
+        let mut app = setup_app(GameRules::default());
+        let player = spawn_player(&mut app, CandleState::Extinguished, 0.0);
+        app.world_mut().entity_mut(player).insert((
+            Health::Dead,
+            DeathTimer(Timer::from_seconds(1.0, TimerMode::Once)),
+        ));
+        app.world_mut().resource_mut::<GameState>().game_mode = GameMode::GameOver;
+
+        app.world_mut()
+            .send_event(RetryEvent(RetryOption::RestartRoom));
+        app.update();
+
+        assert_eq!(game_mode(&app), GameMode::Playing);
+        let world = app.world();
+        assert_eq!(world.get::<Health>(player), Some(&Health::Alive));
+        assert!(world.get::<DeathTimer>(player).is_none());
+        assert_eq!(
+            world.get::<Transform>(player).unwrap().translation,
+            Vec3::new(10.0, 20.0, 0.0)
+        );
+
+        let mut candles = app
+            .world_mut()
+            .query::<(&CandleState, &CandleWax, &CarriedCandle)>();
+        let (state, wax, _) = candles.single(app.world()).unwrap();
+        assert_eq!(*state, CandleState::Unlit);
+        assert_eq!(wax.0, RESTART_ROOM_WAX);
+    }
+
+    #[test]
+    fn load_last_save_requests_the_auto_save() {
+        let mut app = setup_app(GameRules::default());
+        spawn_player(&mut app, CandleState::Lit, 50.0);
+        app.world_mut().resource_mut::<GameState>().game_mode = GameMode::GameOver;
+
+        app.world_mut()
+            .send_event(RetryEvent(RetryOption::LoadLastSave));
+        app.update();
+
+        let events = app.world().resource::<Events<LoadGameEvent>>();
+        let slots: Vec<usize> = events
+            .get_cursor()
+            .read(events)
+            .map(|event| event.slot)
+            .collect();
+        assert_eq!(slots, vec![LAST_SAVE_SLOT]);
+    }
+
+    #[test]
+    fn quit_returns_to_menu() {
+        let mut app = setup_app(GameRules::default());
+        app.world_mut().resource_mut::<GameState>().game_mode = GameMode::GameOver;
+
+        app.world_mut().send_event(RetryEvent(RetryOption::Quit));
+        app.update();
+
+        assert_eq!(game_mode(&app), GameMode::Menu);
+    }
+}
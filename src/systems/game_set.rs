@@ -0,0 +1,85 @@
+use bevy::prelude::*;
+
+use crate::systems::fixed_timestep::PhysicsSet;
+
+/// Plugin that orders the stages of a frame
+///
+/// `Update` runs `GameSet::Input`, `GameSet::Physics`, `GameSet::Gameplay`
+/// and `GameSet::Presentation` in that order, and the fixed-timestep
+/// `PhysicsSet`s run inside `GameSet::Physics` in `FixedUpdate`. Game
+/// plugins place their systems in these sets instead of ordering them
+/// against each other's systems; without this plugin the sets are simply
+/// unordered.
+pub struct GameSetPlugin;
+
+impl Plugin for GameSetPlugin {
+    fn build(&self, app: &mut App) {
+        app.configure_sets(
+            Update,
+            (
+                GameSet::Input,
+                GameSet::Physics,
+                GameSet::Gameplay,
+                GameSet::Presentation,
+            )
+                .chain(),
+        )
+        .configure_sets(
+            FixedUpdate,
+            (PhysicsSet::Movement, PhysicsSet::Collision).in_set(GameSet::Physics),
+        );
+    }
+}
+
+/// Stages of a frame, in the order `GameSetPlugin` runs them
+#[derive(SystemSet, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GameSet {
+    /// Reading actions and turning them into events (interact, undo, toggles)
+    Input,
+    /// Movement and collision (`PhysicsSet` in `FixedUpdate`)
+    Physics,
+    /// Game rules reacting to input and physics: traps, puzzles, inventory,
+    /// death, checkpoints and saves
+    Gameplay,
+    /// Animation and visuals showing the frame's results
+    Presentation,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Resource, Default)]
+    struct Order(Vec<GameSet>);
+
+    #[test]
+    fn sets_run_in_frame_order() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, GameSetPlugin))
+            .init_resource::<Order>();
+
+        // Added in reverse, so only the set order puts them right
+        for set in [
+            GameSet::Presentation,
+            GameSet::Gameplay,
+            GameSet::Physics,
+            GameSet::Input,
+        ] {
+            app.add_systems(
+                Update,
+                (move |mut order: ResMut<Order>| order.0.push(set)).in_set(set),
+            );
+        }
+
+        app.update();
+        assert_eq!(
+            app.world().resource::<Order>().0,
+            vec![
+                GameSet::Input,
+                GameSet::Physics,
+                GameSet::Gameplay,
+                GameSet::Presentation
+            ]
+        );
+    }
+}
@@ -0,0 +1,266 @@
+use crate::components::player::Player;
+use crate::resources::game_state::{GameMode, GameState};
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::fs;
+
+/// Default seconds between recorded ghost samples
+pub const DEFAULT_SAMPLE_INTERVAL: f32 = 0.1;
+
+/// A recorded run: player positions sampled at a fixed interval
+///
+/// Stored as RON (e.g., `assets/replays/attract_run.ron`) and played back
+/// by attaching a `GhostPlayback` component to an entity.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct GhostRecording {
+    /// Seconds between consecutive samples
+    pub sample_interval: f32,
+    /// Recorded (x, y) positions in world space
+    pub positions: Vec<(f32, f32)>,
+    /// `GameRng` seed the run was played with, `None` for older recordings
+    #[serde(default)]
+    pub seed: Option<u64>,
+}
+
+impl GhostRecording {
+    /// Creates an empty recording with the given sample interval
+    pub fn new(sample_interval: f32) -> Self {
+        Self {
+            sample_interval,
+            positions: Vec::new(),
+            seed: None,
+        }
+    }
+
+    /// Total playback length in seconds
+    pub fn duration(&self) -> f32 {
+        self.sample_interval * self.positions.len().saturating_sub(1) as f32
+    }
+
+    /// Interpolated position at `time` seconds into the run
+    ///
+    /// Clamps to the first/last sample outside the recorded range.
+    /// Returns `None` if the recording is empty.
+    pub fn position_at(&self, time: f32) -> Option<Vec2> {
+        let first = *self.positions.first()?;
+        if self.positions.len() == 1 || self.sample_interval <= 0.0 || time <= 0.0 {
+            return Some(Vec2::from(first));
+        }
+
+        let sample = time / self.sample_interval;
+        let index = sample.floor() as usize;
+        if index + 1 >= self.positions.len() {
+            return self.positions.last().map(|p| Vec2::from(*p));
+        }
+
+        let a = Vec2::from(self.positions[index]);
+        let b = Vec2::from(self.positions[index + 1]);
+        Some(a.lerp(b, sample.fract()))
+    }
+}
+
+/// Load a ghost recording from a RON file
+///
+/// # Errors
+/// Returns error string if the file cannot be read or parsed
+pub fn load_ghost_recording(path: &str) -> Result<GhostRecording, String> {
+    let content = fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read ghost recording '{}': {}", path, e))?;
+
+    ron::from_str(&content).map_err(|e| format!("Failed to parse RON from '{}': {}", path, e))
+}
+
+/// Save a ghost recording to a RON file
+///
+/// # Errors
+/// Returns error string if serialization or file writing fails
+pub fn save_ghost_recording(recording: &GhostRecording, path: &str) -> Result<(), String> {
+    let content = ron::ser::to_string_pretty(recording, ron::ser::PrettyConfig::default())
+        .map_err(|e| format!("Failed to serialize ghost recording: {}", e))?;
+
+    fs::write(path, content)
+        .map_err(|e| format!("Failed to write ghost recording '{}': {}", path, e))
+}
+
+/// Resource that records the player's path while active
+///
+/// Call `start` to begin a fresh recording; `ghost_recording_system`
+/// appends a sample every `sample_interval` seconds of gameplay.
+#[derive(Resource, Debug)]
+pub struct GhostRecorder {
+    /// The recording being built
+    pub recording: GhostRecording,
+    /// Whether samples are currently being taken
+    pub active: bool,
+    /// Seconds since the last sample
+    since_last_sample: f32,
+}
+
+impl Default for GhostRecorder {
+    fn default() -> Self {
+        Self {
+            recording: GhostRecording::new(DEFAULT_SAMPLE_INTERVAL),
+            active: false,
+            since_last_sample: 0.0,
+        }
+    }
+}
+
+impl GhostRecorder {
+    /// Discards any previous samples and starts recording a run played
+    /// with the `GameRng` seed `seed`
+    pub fn start(&mut self, sample_interval: f32, seed: u64) {
+        self.recording = GhostRecording {
+            seed: Some(seed),
+            ..GhostRecording::new(sample_interval)
+        };
+        self.since_last_sample = sample_interval; // sample immediately
+        self.active = true;
+    }
+
+    /// Stops recording and returns the finished run
+    pub fn stop(&mut self) -> GhostRecording {
+        self.active = false;
+        std::mem::replace(
+            &mut self.recording,
+            GhostRecording::new(DEFAULT_SAMPLE_INTERVAL),
+        )
+    }
+}
+
+/// Component that moves an entity along a recorded ghost run
+#[derive(Component, Debug)]
+pub struct GhostPlayback {
+    /// The run being played back
+    pub recording: GhostRecording,
+    /// Seconds into the run
+    pub elapsed: f32,
+    /// Restart from the beginning when the run ends
+    pub looping: bool,
+}
+
+impl GhostPlayback {
+    /// Starts playback of `recording` from the beginning
+    pub fn new(recording: GhostRecording, looping: bool) -> Self {
+        Self {
+            recording,
+            elapsed: 0.0,
+            looping,
+        }
+    }
+}
+
+/// System that samples the player's position into the `GhostRecorder`
+///
+/// Only records while the recorder is active and the game is in Playing mode.
+pub fn ghost_recording_system(
+    time: Res<Time>,
+    game_state: Res<GameState>,
+    mut recorder: ResMut<GhostRecorder>,
+    player_query: Query<&Transform, With<Player>>,
+) {
+    if !recorder.active || game_state.game_mode != GameMode::Playing {
+        return;
+    }
+
+    let Ok(transform) = player_query.single() else {
+        return;
+    };
+
+    recorder.since_last_sample += time.delta_secs();
+    if recorder.since_last_sample >= recorder.recording.sample_interval {
+        recorder.since_last_sample = 0.0;
+        let position = transform.translation.truncate();
+        recorder.recording.positions.push((position.x, position.y));
+    }
+}
+
+/// System that advances ghost playback and moves ghost entities
+///
+/// Runs regardless of game mode so ghosts can play behind menus
+/// (e.g., attract mode).
+pub fn ghost_playback_system(
+    time: Res<Time>,
+    mut query: Query<(&mut GhostPlayback, &mut Transform)>,
+) {
+    for (mut playback, mut transform) in &mut query {
+        playback.elapsed += time.delta_secs();
+
+        let duration = playback.recording.duration();
+        if playback.looping && duration > 0.0 && playback.elapsed > duration {
+            playback.elapsed %= duration;
+        }
+
+        if let Some(position) = playback.recording.position_at(playback.elapsed) {
+            transform.translation.x = position.x;
+            transform.translation.y = position.y;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_recording() -> GhostRecording {
+        GhostRecording {
+            sample_interval: 1.0,
+            positions: vec![(0.0, 0.0), (10.0, 0.0), (10.0, 20.0)],
+            seed: Some(7),
+        }
+    }
+
+    #[test]
+    fn position_at_interpolates_between_samples() {
+        let recording = sample_recording();
+        assert_eq!(recording.duration(), 2.0);
+        assert_eq!(recording.position_at(0.5), Some(Vec2::new(5.0, 0.0)));
+        assert_eq!(recording.position_at(1.5), Some(Vec2::new(10.0, 10.0)));
+        assert_eq!(recording.position_at(5.0), Some(Vec2::new(10.0, 20.0)));
+        assert_eq!(GhostRecording::new(1.0).position_at(0.0), None);
+    }
+
+    #[test]
+    fn attract_run_asset_parses() {
+        let recording =
+            load_ghost_recording("assets/replays/attract_run.ron").expect("attract run parses");
+        assert!(recording.positions.len() > 1);
+        assert!(recording.duration() > 0.0);
+    }
+
+    #[test]
+    fn ghost_recording_round_trips() {
+        let path = std::env::temp_dir().join("rust_game_ghost_round_trip.ron");
+        let path = path.to_str().unwrap();
+
+        save_ghost_recording(&sample_recording(), path).unwrap();
+        let loaded = load_ghost_recording(path).unwrap();
+        assert_eq!(loaded, sample_recording());
+
+        let _ = fs::remove_file(path);
+    }
+
+    #[test]
+    fn recorder_samples_player_while_playing() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.insert_resource(GameState {
+            game_mode: GameMode::Playing,
+            ..Default::default()
+        });
+        app.init_resource::<GhostRecorder>();
+        app.add_systems(Update, ghost_recording_system);
+
+        app.world_mut()
+            .spawn((Player, Transform::from_xyz(12.0, 34.0, 0.0)));
+        app.world_mut()
+            .resource_mut::<GhostRecorder>()
+            .start(DEFAULT_SAMPLE_INTERVAL, 42);
+
+        app.update();
+
+        let recording = app.world_mut().resource_mut::<GhostRecorder>().stop();
+        assert_eq!(recording.positions, vec![(12.0, 34.0)]);
+        assert_eq!(recording.seed, Some(42));
+    }
+}
@@ -0,0 +1,413 @@
+use bevy::prelude::*;
+use leafwing_input_manager::prelude::*;
+use std::collections::HashSet;
+
+use crate::audio::sound_bank::{PlaySoundEvent, SoundCue};
+use crate::components::inventory::{Item, ToolType};
+use crate::components::player::{JumpState, Player, Velocity};
+use crate::components::render_layer::RenderLayer;
+use crate::resources::game_state::{GameMode, GameState};
+use crate::resources::input_config::PlayerAction;
+use crate::systems::candle_aim::{CandleAim, CandleAimMode};
+use crate::systems::inventory::ItemUsedEvent;
+
+/// Furthest (pixels) an anchor can be from the player and still catch the rope
+pub const GRAPPLE_RANGE: f32 = 320.0;
+
+/// Half-angle (radians) of the cone around the mouse aim that anchors must be in
+pub const GRAPPLE_AIM_HALF_ANGLE: f32 = 0.6;
+
+/// Shortest (pixels) the rope can be reeled in to
+pub const MIN_ROPE_LENGTH: f32 = 32.0;
+
+/// Speed (pixels per second) the rope is reeled in while climbing
+pub const GRAPPLE_REEL_SPEED: f32 = 180.0;
+
+/// Horizontal acceleration (pixels per second squared) from pumping a swing
+pub const GRAPPLE_SWING_ACCEL: f32 = 400.0;
+
+/// Upward speed (pixels per second) given when letting go of the rope
+pub const GRAPPLE_RELEASE_SPEED: f32 = 250.0;
+
+/// Size (pixels) of an anchor's sprite
+pub const GRAPPLE_ANCHOR_SIZE: Vec2 = Vec2::new(12.0, 12.0);
+
+/// Color of an anchor's sprite
+pub const GRAPPLE_ANCHOR_COLOR: Color = Color::srgb(0.5, 0.5, 0.55);
+
+const ROPE_COLOR: Color = Color::srgb(0.62, 0.5, 0.32);
+const ROPE_THICKNESS: f32 = 2.0;
+
+/// Z offset of the rope behind the player layer
+const ROPE_Z_OFFSET: f32 = -0.5;
+
+/// Plugin for the grappling rope: catching anchors, letting go and drawing
+/// the rope
+///
+/// The swing itself is simulated by `player_movement_system` while the
+/// player has `Grappling`.
+pub struct GrapplePlugin;
+
+impl Plugin for GrapplePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<ItemUsedEvent>()
+            .add_event::<PlaySoundEvent>()
+            .add_systems(
+                Update,
+                (
+                    grapple_fire_system,
+                    grapple_release_system,
+                    grapple_rope_system,
+                )
+                    .chain(),
+            );
+    }
+}
+
+/// Component for a point the grappling rope catches on (beams, iron rings)
+#[derive(Component, Debug, Default)]
+pub struct GrappleAnchor;
+
+/// Component on a player hanging from the grappling rope
+///
+/// Added by `grapple_fire_system` and removed when the player lets go.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct Grappling {
+    /// Anchor the rope is caught on
+    pub anchor: Entity,
+    /// World position of the anchor
+    pub anchor_pos: Vec2,
+    /// Current rope length; shortened by reeling in
+    pub length: f32,
+}
+
+impl Grappling {
+    /// Holds `position` within the rope's length of the anchor
+    ///
+    /// # Returns
+    /// The constrained position and `velocity` without the part that would
+    /// stretch the rope, so the player swings around the anchor
+    pub fn constrain(&self, position: Vec2, velocity: Vec2) -> (Vec2, Vec2) {
+        let offset = position - self.anchor_pos;
+        let distance = offset.length();
+        if distance <= self.length || distance == 0.0 {
+            return (position, velocity);
+        }
+
+        let normal = offset / distance;
+        let outward = velocity.dot(normal).max(0.0);
+        (
+            self.anchor_pos + normal * self.length,
+            velocity - normal * outward,
+        )
+    }
+}
+
+/// Component for the sprite drawing a player's rope
+#[derive(Component, Debug)]
+pub struct GrappleRope {
+    /// Player holding the rope
+    pub player: Entity,
+}
+
+/// Picks the anchor a rope thrown from `from` catches
+///
+/// Only anchors above the player within `GRAPPLE_RANGE` can be caught. With
+/// an `aim` direction (mouse aim) the anchor closest to it within
+/// `GRAPPLE_AIM_HALF_ANGLE` is picked, otherwise the nearest.
+///
+/// # Returns
+/// The anchor and its position, or `None` if nothing is in reach
+pub fn pick_anchor(
+    from: Vec2,
+    aim: Option<Vec2>,
+    anchors: impl IntoIterator<Item = (Entity, Vec2)>,
+) -> Option<(Entity, Vec2)> {
+    let min_alignment = GRAPPLE_AIM_HALF_ANGLE.cos();
+    anchors
+        .into_iter()
+        .filter(|(_, pos)| pos.y > from.y && from.distance(*pos) <= GRAPPLE_RANGE)
+        .filter_map(|(anchor, pos)| {
+            let score = match aim {
+                Some(aim) => {
+                    let alignment = aim.dot((pos - from).normalize_or_zero());
+                    if alignment < min_alignment {
+                        return None;
+                    }
+                    alignment
+                }
+                None => -from.distance(pos),
+            };
+            Some((anchor, pos, score))
+        })
+        .max_by(|a, b| a.2.total_cmp(&b.2))
+        .map(|(anchor, pos, _)| (anchor, pos))
+}
+
+/// System that throws the grappling rope when the tool is used
+///
+/// # System Dependencies
+/// - **Upstream**: `hotbar_use_system` emits `ItemUsedEvent` for the rope
+/// - **Components**: Reads the player's `Transform` and `GrappleAnchor`
+///   positions; inserts or removes `Grappling`
+/// - **Resources**: Reads `GameState` and `CandleAim` (optional)
+/// - **Downstream**: `player_movement_system` swings players with `Grappling`
+///
+/// # Behavior
+/// - The rope catches the anchor picked by `pick_anchor`, aimed with the
+///   mouse while the candle is in mouse-aim mode, and starts at the
+///   player's distance from it (`SoundCue::RopeThrow`)
+/// - With nothing in reach the throw misses (`SoundCue::UiError`)
+/// - Using the rope while hanging from it lets go
+pub fn grapple_fire_system(
+    game_state: Res<GameState>,
+    mut commands: Commands,
+    mut events: EventReader<ItemUsedEvent>,
+    aim: Option<Res<CandleAim>>,
+    player_query: Query<(&Transform, Has<Grappling>), With<Player>>,
+    anchor_query: Query<(Entity, &Transform), With<GrappleAnchor>>,
+    mut sound_events: EventWriter<PlaySoundEvent>,
+) {
+    if game_state.game_mode != GameMode::Playing {
+        events.clear();
+        return;
+    }
+
+    for event in events.read() {
+        if !matches!(event.item, Item::Tool(ToolType::GrapplingRope)) {
+            continue;
+        }
+        let Ok((transform, grappling)) = player_query.get(event.player) else {
+            continue;
+        };
+        if grappling {
+            commands.entity(event.player).remove::<Grappling>();
+            continue;
+        }
+
+        let from = transform.translation.truncate();
+        let aim = aim
+            .as_deref()
+            .filter(|aim| aim.mode == CandleAimMode::MouseAim)
+            .map(|aim| aim.direction);
+        let anchors = anchor_query
+            .iter()
+            .map(|(anchor, transform)| (anchor, transform.translation.truncate()));
+
+        let cue = match pick_anchor(from, aim, anchors) {
+            Some((anchor, anchor_pos)) => {
+                commands.entity(event.player).insert(Grappling {
+                    anchor,
+                    anchor_pos,
+                    length: from.distance(anchor_pos).max(MIN_ROPE_LENGTH),
+                });
+                SoundCue::RopeThrow
+            }
+            None => SoundCue::UiError,
+        };
+        sound_events.write(PlaySoundEvent { cue });
+    }
+}
+
+/// System that lets go of the rope
+///
+/// # System Dependencies
+/// - **Components**: Reads `ActionState<PlayerAction>` and `Grappling`;
+///   writes `Velocity` and `JumpState`; removes `Grappling`
+/// - **Upstream**: `grapple_fire_system`
+///
+/// # Behavior
+/// Pressing jump lets go with a hop (at least `GRAPPLE_RELEASE_SPEED`
+/// upward) that keeps the swing's momentum, so the player can fling
+/// themselves across a gap. The rope also drops when its anchor is
+/// despawned (e.g., the player left the room).
+pub fn grapple_release_system(
+    game_state: Res<GameState>,
+    mut commands: Commands,
+    mut player_query: Query<
+        (
+            Entity,
+            &ActionState<PlayerAction>,
+            &Grappling,
+            &mut Velocity,
+            &mut JumpState,
+        ),
+        With<Player>,
+    >,
+    anchor_query: Query<(), With<GrappleAnchor>>,
+) {
+    if game_state.game_mode != GameMode::Playing {
+        return;
+    }
+
+    for (player, actions, grappling, mut velocity, mut jump_state) in &mut player_query {
+        if actions.just_pressed(&PlayerAction::Jump) {
+            velocity.0.y = velocity.0.y.max(GRAPPLE_RELEASE_SPEED);
+            *jump_state = JumpState::Jumping;
+        } else if anchor_query.contains(grappling.anchor) {
+            continue;
+        }
+        commands.entity(player).remove::<Grappling>();
+    }
+}
+
+/// System that draws each player's rope from their hand to the anchor
+///
+/// # System Dependencies
+/// - **Components**: Reads the player's `Transform` and `Grappling`;
+///   spawns, moves and despawns `GrappleRope` sprites
+/// - **Upstream**: `grapple_fire_system` and `grapple_release_system`
+///
+/// # Behavior
+/// The rope is a thin sprite stretched between the player and the anchor,
+/// just behind the player layer. It has no `RenderLayer`, so
+/// `render_layer_system` leaves that offset alone.
+pub fn grapple_rope_system(
+    mut commands: Commands,
+    player_query: Query<(Entity, &Transform, &Grappling), With<Player>>,
+    mut rope_query: Query<(Entity, &GrappleRope, &mut Transform, &mut Sprite), Without<Player>>,
+) {
+    let mut roped = HashSet::new();
+    for (rope, holder, mut transform, mut sprite) in &mut rope_query {
+        let Ok((_, player_transform, grappling)) = player_query.get(holder.player) else {
+            commands.entity(rope).despawn();
+            continue;
+        };
+        roped.insert(holder.player);
+        *transform = rope_transform(player_transform.translation.truncate(), grappling);
+        sprite.custom_size = Some(rope_size(
+            player_transform.translation.truncate(),
+            grappling,
+        ));
+    }
+
+    for (player, transform, grappling) in &player_query {
+        if roped.contains(&player) {
+            continue;
+        }
+        let from = transform.translation.truncate();
+        commands.spawn((
+            GrappleRope { player },
+            Sprite {
+                color: ROPE_COLOR,
+                custom_size: Some(rope_size(from, grappling)),
+                ..default()
+            },
+            rope_transform(from, grappling),
+        ));
+    }
+}
+
+/// Rope sprite size for a player at `from`
+fn rope_size(from: Vec2, grappling: &Grappling) -> Vec2 {
+    Vec2::new(from.distance(grappling.anchor_pos), ROPE_THICKNESS)
+}
+
+/// Rope sprite transform: centred between the player at `from` and the
+/// anchor, turned to point at it
+fn rope_transform(from: Vec2, grappling: &Grappling) -> Transform {
+    let span = grappling.anchor_pos - from;
+    Transform::from_translation((from + span / 2.0).extend(RenderLayer::Player.z() + ROPE_Z_OFFSET))
+        .with_rotation(Quat::from_rotation_z(span.to_angle()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rope_catches_the_aimed_or_nearest_anchor_in_reach() {
+        let near = Entity::from_raw(1);
+        let far = Entity::from_raw(2);
+        let below = Entity::from_raw(3);
+        let anchors = [
+            (near, Vec2::new(-100.0, 100.0)),
+            (far, Vec2::new(150.0, 200.0)),
+            (below, Vec2::new(0.0, -50.0)),
+        ];
+
+        assert_eq!(pick_anchor(Vec2::ZERO, None, anchors).unwrap().0, near);
+        assert_eq!(
+            pick_anchor(Vec2::ZERO, Some(Vec2::new(1.0, 1.0).normalize()), anchors)
+                .unwrap()
+                .0,
+            far
+        );
+        // Nothing lies straight to the right
+        assert!(pick_anchor(Vec2::ZERO, Some(Vec2::X), anchors).is_none());
+        assert!(pick_anchor(Vec2::new(0.0, -400.0), None, anchors).is_none());
+    }
+
+    #[test]
+    fn rope_only_pulls_when_taut() {
+        let rope = Grappling {
+            anchor: Entity::PLACEHOLDER,
+            anchor_pos: Vec2::new(0.0, 100.0),
+            length: 50.0,
+        };
+
+        let slack = (Vec2::new(0.0, 80.0), Vec2::new(0.0, -100.0));
+        assert_eq!(rope.constrain(slack.0, slack.1), slack);
+
+        let (position, velocity) = rope.constrain(Vec2::new(0.0, 20.0), Vec2::new(30.0, -100.0));
+        assert_eq!(position, Vec2::new(0.0, 50.0));
+        assert_eq!(
+            velocity,
+            Vec2::new(30.0, 0.0),
+            "The swing keeps its sideways speed"
+        );
+    }
+
+    #[test]
+    fn using_the_rope_catches_an_anchor_and_jumping_lets_go() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, GrapplePlugin));
+        app.insert_resource(GameState {
+            game_mode: GameMode::Playing,
+            ..default()
+        });
+        let anchor = app
+            .world_mut()
+            .spawn((GrappleAnchor, Transform::from_xyz(0.0, 200.0, 0.0)))
+            .id();
+        let player = app
+            .world_mut()
+            .spawn((
+                Player,
+                Transform::default(),
+                Velocity(Vec2::new(120.0, 0.0)),
+                JumpState::Falling,
+                ActionState::<PlayerAction>::default(),
+            ))
+            .id();
+
+        app.world_mut().send_event(ItemUsedEvent {
+            item: Item::Tool(ToolType::GrapplingRope),
+            player,
+        });
+        app.update();
+        assert_eq!(
+            app.world().get::<Grappling>(player),
+            Some(&Grappling {
+                anchor,
+                anchor_pos: Vec2::new(0.0, 200.0),
+                length: 200.0,
+            })
+        );
+        app.update();
+        let mut ropes = app.world_mut().query::<&GrappleRope>();
+        assert_eq!(ropes.iter(app.world()).count(), 1);
+
+        let mut actions = ActionState::<PlayerAction>::default();
+        actions.press(&PlayerAction::Jump);
+        app.world_mut().entity_mut(player).insert(actions);
+        app.update();
+        app.update();
+        assert!(app.world().get::<Grappling>(player).is_none());
+        assert_eq!(
+            app.world().get::<Velocity>(player).unwrap().0,
+            Vec2::new(120.0, GRAPPLE_RELEASE_SPEED)
+        );
+        assert_eq!(ropes.iter(app.world()).count(), 0);
+    }
+}
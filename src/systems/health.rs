@@ -0,0 +1,101 @@
+use crate::components::player::{Health, HitPoints, Invincibility, Player};
+use crate::systems::save_load::{AutoSaveEvent, ManualSaveEvent};
+use bevy::prelude::*;
+
+/// System that counts down invincibility frames
+///
+/// Ticks each `Invincibility` timer and removes the component once it
+/// finishes, making the player vulnerable to partial damage again.
+///
+/// # System Dependencies
+/// - **Upstream**: `trap_activation_system` adds `Invincibility` after damage
+pub fn invincibility_system(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut Invincibility)>,
+) {
+    for (entity, mut invincibility) in &mut query {
+        invincibility.0.tick(time.delta());
+        if invincibility.0.finished() {
+            commands.entity(entity).remove::<Invincibility>();
+        }
+    }
+}
+
+/// System that restores the player's hit points at save points
+///
+/// Any save (auto-save at checkpoints/room transitions or a manual save)
+/// fully heals a living player, rewarding players who reach save points.
+///
+/// # System Dependencies
+/// - **Upstream**: Emitters of `AutoSaveEvent` and `ManualSaveEvent`
+/// - **Components**: Writes `HitPoints`, reads `Health`
+pub fn save_point_regeneration_system(
+    mut auto_save_events: EventReader<AutoSaveEvent>,
+    mut manual_save_events: EventReader<ManualSaveEvent>,
+    mut query: Query<(&Health, &mut HitPoints), With<Player>>,
+) {
+    let saved = auto_save_events.read().count() + manual_save_events.read().count() > 0;
+    if !saved {
+        return;
+    }
+
+    for (health, mut hit_points) in &mut query {
+        if *health == Health::Alive {
+            hit_points.restore();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn invincibility_expires() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_systems(Update, invincibility_system);
+
+        let player = app
+            .world_mut()
+            .spawn((
+                Player,
+                Invincibility(Timer::from_seconds(0.0, TimerMode::Once)),
+            ))
+            .id();
+
+        app.update();
+
+        assert!(app.world().get::<Invincibility>(player).is_none());
+    }
+
+    #[test]
+    fn saving_restores_hit_points() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_event::<AutoSaveEvent>();
+        app.add_event::<ManualSaveEvent>();
+        app.add_systems(Update, save_point_regeneration_system);
+
+        let player = app
+            .world_mut()
+            .spawn((
+                Player,
+                Health::Alive,
+                HitPoints {
+                    current: 25.0,
+                    max: 100.0,
+                },
+            ))
+            .id();
+
+        // No save yet: hit points unchanged
+        app.update();
+        assert_eq!(app.world().get::<HitPoints>(player).unwrap().current, 25.0);
+
+        app.world_mut().send_event(AutoSaveEvent);
+        app.update();
+        assert_eq!(app.world().get::<HitPoints>(player).unwrap().current, 100.0);
+    }
+}
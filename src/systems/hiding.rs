@@ -0,0 +1,317 @@
+use bevy::asset::RenderAssetUsages;
+use bevy::prelude::*;
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+
+use crate::components::player::{Player, Velocity};
+use crate::components::render_layer::RenderLayer;
+use crate::components::room::Interactable;
+use crate::resources::input_context::{InputContext, InputContextStack};
+use crate::systems::interaction::HideEvent;
+
+/// Prompt shown on a hiding spot while the player is inside it
+pub const DEFAULT_EXIT_PROMPT: &str = "Come out";
+
+/// Opacity of the vignette's dark edges while hidden
+pub const VIGNETTE_OPACITY: f32 = 0.9;
+
+/// Seconds the vignette takes to fade in or out
+pub const VIGNETTE_FADE_SECS: f32 = 0.4;
+
+/// Distance from the screen centre (1.0 at the edge midpoints) where the
+/// vignette starts darkening
+const VIGNETTE_INNER_RADIUS: f32 = 0.35;
+
+/// Distance from the screen centre where the vignette is fully dark
+const VIGNETTE_OUTER_RADIUS: f32 = 1.2;
+
+/// Width and height (texels) of the generated vignette texture
+const VIGNETTE_TEXTURE_SIZE: u32 = 128;
+
+/// Plugin for furniture the player hides in and the vignette drawn meanwhile
+///
+/// Hiding spots are `Interactable`s of kind `InteractionKind::Hide`, so
+/// `interaction_system` dispatches the `HideEvent` that goes in or out.
+pub struct HidingPlugin;
+
+impl Plugin for HidingPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<HideEvent>()
+            .add_systems(Update, (hide_system, hiding_vignette_system).chain());
+    }
+}
+
+/// Component for furniture the player can hide in (wardrobes, beds)
+///
+/// Needs an `Interactable` of kind `InteractionKind::Hide`. Its prompt and
+/// `exit_prompt` trade places while the player is inside.
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct HidingSpot {
+    /// Prompt shown while the player is inside
+    pub exit_prompt: String,
+}
+
+impl Default for HidingSpot {
+    fn default() -> Self {
+        Self {
+            exit_prompt: DEFAULT_EXIT_PROMPT.to_string(),
+        }
+    }
+}
+
+/// Component on a player hiding in a `HidingSpot`
+///
+/// Hidden players are invisible and can't move; enemy AI should treat them
+/// as not there.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct Hidden {
+    /// Spot the player is hiding in
+    pub spot: Entity,
+    /// Where the player stood before hiding, and steps back out to
+    pub exit_position: Vec2,
+}
+
+/// Component for the full-screen vignette sprite drawn while hidden
+#[derive(Component, Debug, Default)]
+pub struct HidingVignette {
+    /// Current opacity of the dark edges
+    pub opacity: f32,
+}
+
+/// Generates the vignette texture: clear in the middle, black at the edges
+pub fn vignette_image(size: u32) -> Image {
+    let half = size as f32 / 2.0;
+    let mut data = Vec::with_capacity((size * size * 4) as usize);
+    for y in 0..size {
+        for x in 0..size {
+            let offset = (Vec2::new(x as f32, y as f32) + 0.5 - half) / half;
+            let t = ((offset.length() - VIGNETTE_INNER_RADIUS)
+                / (VIGNETTE_OUTER_RADIUS - VIGNETTE_INNER_RADIUS))
+                .clamp(0.0, 1.0);
+            let alpha = t * t * (3.0 - 2.0 * t);
+            data.extend_from_slice(&[0, 0, 0, (alpha * 255.0).round() as u8]);
+        }
+    }
+
+    Image::new(
+        Extent3d {
+            width: size,
+            height: size,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        data,
+        TextureFormat::Rgba8UnormSrgb,
+        RenderAssetUsages::default(),
+    )
+}
+
+/// System that moves players into and out of hiding spots
+///
+/// # System Dependencies
+/// - **Upstream**: `interaction_system` emits `HideEvent`
+/// - **Components**: Writes the player's `Transform`, `Velocity` and
+///   `Visibility`, inserts or removes `Hidden`; writes the spot's
+///   `Interactable` prompt
+/// - **Resources**: Writes `InputContextStack` (when present)
+///
+/// # Behavior
+/// - Going in: the player is moved into the spot, stopped and made
+///   invisible, and `InputContext::Hiding` is pushed so the only input left
+///   is interacting to come back out
+/// - Coming out: the player steps back to where they stood and the
+///   context is popped
+/// - Interacting with another spot while hidden is ignored
+#[allow(clippy::type_complexity)]
+pub fn hide_system(
+    mut commands: Commands,
+    mut events: EventReader<HideEvent>,
+    mut input: Option<ResMut<InputContextStack>>,
+    mut player_query: Query<
+        (&mut Transform, Option<&mut Velocity>, Option<&Hidden>),
+        (With<Player>, Without<HidingSpot>),
+    >,
+    mut spot_query: Query<(&Transform, &mut HidingSpot, &mut Interactable)>,
+) {
+    for event in events.read() {
+        let Ok((mut transform, velocity, hidden)) = player_query.get_mut(event.player) else {
+            continue;
+        };
+        let Ok((spot_transform, mut spot, mut interactable)) = spot_query.get_mut(event.spot)
+        else {
+            continue;
+        };
+
+        match hidden {
+            Some(hidden) if hidden.spot == event.spot => {
+                let z = transform.translation.z;
+                transform.translation = hidden.exit_position.extend(z);
+                commands
+                    .entity(event.player)
+                    .remove::<Hidden>()
+                    .insert(Visibility::Inherited);
+                if let Some(input) = input.as_mut() {
+                    input.pop(InputContext::Hiding);
+                }
+            }
+            Some(_) => continue,
+            None => {
+                commands.entity(event.player).insert((
+                    Hidden {
+                        spot: event.spot,
+                        exit_position: transform.translation.truncate(),
+                    },
+                    Visibility::Hidden,
+                ));
+                let z = transform.translation.z;
+                transform.translation = spot_transform.translation.truncate().extend(z);
+                if let Some(mut velocity) = velocity {
+                    velocity.0 = Vec2::ZERO;
+                }
+                if let Some(input) = input.as_mut() {
+                    input.push(InputContext::Hiding);
+                }
+            }
+        }
+        std::mem::swap(&mut interactable.prompt, &mut spot.exit_prompt);
+    }
+}
+
+/// System that fades the vignette in while the player hides
+///
+/// # System Dependencies
+/// - **Components**: Reads `Hidden` players; writes `HidingVignette` and
+///   its `Sprite`
+/// - **Resources**: Reads `Time`; writes `Assets<Image>` (when present) to
+///   create the vignette texture
+/// - **Upstream**: `hide_system`
+///
+/// # Behavior
+/// The vignette sprite is spawned on the UI render layer the first time a
+/// player hides, and fades between clear and `VIGNETTE_OPACITY` over
+/// `VIGNETTE_FADE_SECS` as they go in and come out.
+pub fn hiding_vignette_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    images: Option<ResMut<Assets<Image>>>,
+    hidden_query: Query<(), (With<Player>, With<Hidden>)>,
+    mut overlay_query: Query<(&mut HidingVignette, &mut Sprite)>,
+) {
+    let target = if hidden_query.is_empty() {
+        0.0
+    } else {
+        VIGNETTE_OPACITY
+    };
+
+    let Ok((mut vignette, mut sprite)) = overlay_query.single_mut() else {
+        if target > 0.0
+            && let Some(mut images) = images
+        {
+            commands.spawn((
+                HidingVignette::default(),
+                Sprite {
+                    image: images.add(vignette_image(VIGNETTE_TEXTURE_SIZE)),
+                    color: Color::WHITE.with_alpha(0.0),
+                    custom_size: Some(Vec2::new(1920.0, 1080.0)),
+                    ..default()
+                },
+                RenderLayer::Ui,
+                Transform::from_xyz(0.0, 0.0, RenderLayer::Ui.z()),
+            ));
+        }
+        return;
+    };
+
+    if vignette.opacity == target {
+        return;
+    }
+    let step = VIGNETTE_OPACITY / VIGNETTE_FADE_SECS * time.delta_secs();
+    vignette.opacity = if vignette.opacity < target {
+        (vignette.opacity + step).min(target)
+    } else {
+        (vignette.opacity - step).max(target)
+    };
+    sprite.color = Color::WHITE.with_alpha(vignette.opacity);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::room::InteractionKind;
+
+    #[test]
+    fn vignette_is_clear_in_the_middle_and_dark_in_the_corners() {
+        let image = vignette_image(64);
+        let alpha = |x: u32, y: u32| image.data.as_ref().unwrap()[((y * 64 + x) * 4 + 3) as usize];
+
+        assert_eq!(alpha(32, 32), 0);
+        assert_eq!(alpha(0, 0), 255);
+        assert!(alpha(0, 32) > alpha(16, 32));
+    }
+
+    #[test]
+    fn hiding_and_coming_back_out() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, HidingPlugin));
+        app.init_resource::<InputContextStack>();
+
+        let wardrobe = app
+            .world_mut()
+            .spawn((
+                HidingSpot::default(),
+                Interactable {
+                    prompt: "Hide in the wardrobe".to_string(),
+                    ..Interactable::new(InteractionKind::Hide)
+                },
+                Transform::from_xyz(200.0, 48.0, 20.0),
+            ))
+            .id();
+        let player = app
+            .world_mut()
+            .spawn((
+                Player,
+                Transform::from_xyz(180.0, 0.0, 30.0),
+                Velocity(Vec2::new(200.0, 0.0)),
+            ))
+            .id();
+        let hide = HideEvent {
+            spot: wardrobe,
+            player,
+        };
+
+        app.world_mut().send_event(hide);
+        app.update();
+        assert_eq!(
+            app.world().get::<Transform>(player).unwrap().translation,
+            Vec3::new(200.0, 48.0, 30.0)
+        );
+        assert_eq!(app.world().get::<Velocity>(player).unwrap().0, Vec2::ZERO);
+        assert_eq!(
+            app.world().get::<Visibility>(player),
+            Some(&Visibility::Hidden)
+        );
+        assert_eq!(
+            app.world().resource::<InputContextStack>().top(),
+            InputContext::Hiding
+        );
+        assert_eq!(
+            app.world().get::<Interactable>(wardrobe).unwrap().prompt,
+            DEFAULT_EXIT_PROMPT
+        );
+
+        app.world_mut().send_event(hide);
+        app.update();
+        assert!(app.world().get::<Hidden>(player).is_none());
+        assert_eq!(
+            app.world().get::<Transform>(player).unwrap().translation,
+            Vec3::new(180.0, 0.0, 30.0)
+        );
+        assert_eq!(
+            app.world().resource::<InputContextStack>().top(),
+            InputContext::Gameplay
+        );
+        assert_eq!(
+            app.world().get::<Interactable>(wardrobe).unwrap().prompt,
+            "Hide in the wardrobe"
+        );
+    }
+}
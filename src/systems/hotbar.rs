@@ -0,0 +1,207 @@
+use crate::audio::sound_bank::{PlaySoundEvent, SoundCue};
+use crate::components::inventory::{Hotbar, Inventory, Item};
+use crate::components::lighting::{CandleState, CandleWax, CarriedCandle};
+use crate::components::player::Player;
+use crate::resources::input_config::{HOTBAR_ACTIONS, PlayerAction};
+use crate::systems::inventory::{ItemUsedEvent, can_light_candle};
+use bevy::prelude::*;
+use leafwing_input_manager::prelude::*;
+
+/// Plugin that registers the hotbar quick-use and assignment systems
+pub struct HotbarPlugin;
+
+impl Plugin for HotbarPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<AssignHotbarSlotEvent>()
+            .add_event::<ItemUsedEvent>()
+            .add_event::<PlaySoundEvent>()
+            .add_systems(Update, (hotbar_assign_system, hotbar_use_system).chain());
+    }
+}
+
+/// Event emitted to change what a hotbar slot holds
+#[derive(Event, Clone)]
+pub struct AssignHotbarSlotEvent {
+    /// Entity ID of the player whose hotbar changes
+    pub player: Entity,
+    /// Slot index (0 for the slot bound to key 1)
+    pub slot: usize,
+    /// Item to assign (`None` empties the slot)
+    pub item: Option<Item>,
+}
+
+/// System that assigns items to hotbar slots
+///
+/// # System Dependencies
+/// - **Upstream**: Inventory screen emits `AssignHotbarSlotEvent`
+/// - **Components**: Writes `Hotbar` (inserted on first assignment)
+///
+/// # Behavior
+/// Items that aren't quick-usable and out of range slots are ignored.
+pub fn hotbar_assign_system(
+    mut events: EventReader<AssignHotbarSlotEvent>,
+    mut commands: Commands,
+    mut hotbar_query: Query<Option<&mut Hotbar>>,
+) {
+    for event in events.read() {
+        let Ok(hotbar) = hotbar_query.get_mut(event.player) else {
+            continue;
+        };
+
+        match (hotbar, event.item.clone()) {
+            (Some(mut hotbar), Some(item)) => {
+                hotbar.assign(event.slot, item);
+            }
+            (Some(mut hotbar), None) => hotbar.clear(event.slot),
+            (None, Some(item)) => {
+                let mut hotbar = Hotbar::default();
+                if hotbar.assign(event.slot, item) {
+                    commands.entity(event.player).insert(hotbar);
+                }
+            }
+            (None, None) => {}
+        }
+    }
+}
+
+/// System that uses hotbar items when their number key is pressed
+///
+/// # System Dependencies
+/// - **Components**: Reads `Player`, `ActionState<PlayerAction>`, `Hotbar`,
+///   `Inventory` and the `CarriedCandle` state
+/// - **Downstream**: `match_use_system` and `inventory_usage_system` read
+///   `ItemUsedEvent`
+///
+/// # Behavior
+/// - Emits `ItemUsedEvent` for the slot's item if the player carries one
+/// - A match is only struck when it would light a carried candle, so the
+///   hotbar never wastes one
+/// - Empty slots, missing items and pointless matches play `SoundCue::UiError`
+#[allow(clippy::type_complexity)]
+pub fn hotbar_use_system(
+    player_query: Query<(Entity, &ActionState<PlayerAction>, &Hotbar, &Inventory), With<Player>>,
+    candle_query: Query<(&CandleState, &CandleWax), With<CarriedCandle>>,
+    mut item_used_events: EventWriter<ItemUsedEvent>,
+    mut sound_events: EventWriter<PlaySoundEvent>,
+) {
+    for (player, actions, hotbar, inventory) in &player_query {
+        for (slot, action) in HOTBAR_ACTIONS.iter().enumerate() {
+            if !actions.just_pressed(action) {
+                continue;
+            }
+
+            let usable = hotbar.get(slot).filter(|item| {
+                inventory.count(item) > 0
+                    && (!matches!(item, Item::Match)
+                        || candle_query
+                            .iter()
+                            .any(|(state, wax)| can_light_candle(state, wax)))
+            });
+            match usable {
+                Some(item) => {
+                    item_used_events.write(ItemUsedEvent {
+                        item: item.clone(),
+                        player,
+                    });
+                }
+                None => {
+                    sound_events.write(PlaySoundEvent {
+                        cue: SoundCue::UiError,
+                    });
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::inventory::ToolType;
+
+    fn setup_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(HotbarPlugin);
+        app
+    }
+
+    fn press(app: &mut App, player: Entity, action: PlayerAction) {
+        let mut actions = ActionState::<PlayerAction>::default();
+        actions.press(&action);
+        app.world_mut().entity_mut(player).insert(actions);
+    }
+
+    fn used_items(app: &App) -> Vec<Item> {
+        app.world()
+            .resource::<Events<ItemUsedEvent>>()
+            .iter_current_update_events()
+            .map(|event| event.item.clone())
+            .collect()
+    }
+
+    #[test]
+    fn assigning_inserts_hotbar_and_number_key_uses_item() {
+        let mut app = setup_app();
+        let player = app
+            .world_mut()
+            .spawn((
+                Player,
+                Inventory {
+                    items: vec![Item::Tool(ToolType::Crowbar)],
+                    max_capacity: 10,
+                },
+            ))
+            .id();
+
+        app.world_mut().send_event(AssignHotbarSlotEvent {
+            player,
+            slot: 1,
+            item: Some(Item::Tool(ToolType::Crowbar)),
+        });
+        app.update();
+        assert!(matches!(
+            app.world().get::<Hotbar>(player).unwrap().get(1),
+            Some(Item::Tool(ToolType::Crowbar))
+        ));
+
+        press(&mut app, player, PlayerAction::Hotbar2);
+        app.update();
+        let used = used_items(&app);
+        assert_eq!(used.len(), 1);
+        assert!(matches!(used[0], Item::Tool(ToolType::Crowbar)));
+    }
+
+    #[test]
+    fn hotbar_match_needs_a_candle_to_light() {
+        let mut app = setup_app();
+        let mut hotbar = Hotbar::default();
+        hotbar.assign(0, Item::Match);
+        let player = app
+            .world_mut()
+            .spawn((
+                Player,
+                hotbar,
+                Inventory {
+                    items: vec![Item::Match],
+                    max_capacity: 10,
+                },
+            ))
+            .id();
+        let candle = app
+            .world_mut()
+            .spawn((CarriedCandle::default(), CandleState::Lit, CandleWax(50.0)))
+            .id();
+
+        press(&mut app, player, PlayerAction::Hotbar1);
+        app.update();
+        assert!(used_items(&app).is_empty(), "Candle is already lit");
+
+        app.world_mut()
+            .entity_mut(candle)
+            .insert(CandleState::Unlit);
+        press(&mut app, player, PlayerAction::Hotbar1);
+        app.update();
+        assert_eq!(used_items(&app).len(), 1);
+    }
+}
@@ -0,0 +1,312 @@
+use crate::components::player::Player;
+use crate::components::room::{Interactable, InteractionKind};
+use crate::resources::game_state::{GameMode, GameState};
+use crate::resources::input_config::PlayerAction;
+use crate::resources::input_context::{InputContextStack, InputPermission};
+use bevy::prelude::*;
+use leafwing_input_manager::prelude::*;
+
+/// Plugin that registers the generic interaction system and its events
+pub struct InteractionPlugin;
+
+impl Plugin for InteractionPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<InteractionPrompt>()
+            .add_event::<OpenDoorEvent>()
+            .add_event::<CollectEvent>()
+            .add_event::<TogglePuzzleEvent>()
+            .add_event::<ReadNoteEvent>()
+            .add_event::<ExamineEvent>()
+            .add_event::<HideEvent>()
+            .add_event::<BreakEvent>()
+            .add_event::<CutWireEvent>()
+            .add_systems(Update, interaction_system);
+    }
+}
+
+/// Event emitted when the player interacts with an `InteractionKind::OpenDoor` entity
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OpenDoorEvent {
+    /// The door entity
+    pub door: Entity,
+    /// The player that interacted
+    pub player: Entity,
+}
+
+/// Event emitted when the player interacts with an `InteractionKind::Collect` entity
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CollectEvent {
+    /// The item entity
+    pub item: Entity,
+    /// The player that interacted
+    pub player: Entity,
+}
+
+/// Event emitted when the player interacts with an `InteractionKind::TogglePuzzle` entity
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TogglePuzzleEvent {
+    /// The puzzle entity
+    pub puzzle: Entity,
+    /// The player that interacted
+    pub player: Entity,
+}
+
+/// Event emitted when the player interacts with an `InteractionKind::ReadNote` entity
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReadNoteEvent {
+    /// The note entity
+    pub note: Entity,
+    /// The player that interacted
+    pub player: Entity,
+}
+
+/// Event emitted when the player interacts with an `InteractionKind::Examine` entity
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExamineEvent {
+    /// The examined entity
+    pub target: Entity,
+    /// The player that interacted
+    pub player: Entity,
+}
+
+/// Event emitted when the player interacts with an `InteractionKind::Hide` entity
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HideEvent {
+    /// The hiding spot entity
+    pub spot: Entity,
+    /// The player that interacted
+    pub player: Entity,
+}
+
+/// Event emitted when the player interacts with an `InteractionKind::Break` entity
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BreakEvent {
+    /// The object to break
+    pub target: Entity,
+    /// The player that interacted
+    pub player: Entity,
+}
+
+/// Event emitted when the player interacts with an `InteractionKind::CutWire` entity
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CutWireEvent {
+    /// The wire entity
+    pub wire: Entity,
+    /// The player that interacted
+    pub player: Entity,
+}
+
+/// Resource describing the interactable the player would use right now
+///
+/// Written by `interaction_system` every frame; the HUD shows `prompt` and
+/// `prompt_label_system` floats it above `target`.
+#[derive(Resource, Default, Debug, Clone, PartialEq)]
+pub struct InteractionPrompt {
+    /// Nearest in-range interactable
+    pub target: Option<Entity>,
+    /// Its prompt text
+    pub prompt: Option<String>,
+}
+
+/// System that finds the nearest interactable and dispatches interactions
+///
+/// # System Dependencies
+/// - **Components**: Reads `Player`, `ActionState<PlayerAction>`, `Interactable`, `Transform`
+/// - **Resources**: Reads `GameState` and `InputContextStack` (when present),
+///   writes `InteractionPrompt`
+/// - **Downstream**: Emits `OpenDoorEvent`, `CollectEvent`, `TogglePuzzleEvent`,
+///   `ReadNoteEvent`, `ExamineEvent`, `HideEvent`, `BreakEvent` or
+///   `CutWireEvent`
+///
+/// # Behavior
+/// 1. Finds the nearest `Interactable` within its own `range` of the player
+/// 2. Stores it and its prompt in `InteractionPrompt` (cleared when nothing
+///    is in range, the game isn't in Playing mode or the top input context
+///    doesn't allow `InputPermission::Interact`)
+/// 3. When the interact action (F) was just pressed, emits the event for
+///    the interactable's `kind`
+#[allow(clippy::too_many_arguments)]
+pub fn interaction_system(
+    game_state: Res<GameState>,
+    input: Option<Res<InputContextStack>>,
+    mut prompt: ResMut<InteractionPrompt>,
+    player_query: Query<(Entity, &Transform, &ActionState<PlayerAction>), With<Player>>,
+    interactables: Query<(Entity, &Transform, &Interactable), Without<Player>>,
+    mut open_door_events: EventWriter<OpenDoorEvent>,
+    mut collect_events: EventWriter<CollectEvent>,
+    mut toggle_puzzle_events: EventWriter<TogglePuzzleEvent>,
+    mut read_note_events: EventWriter<ReadNoteEvent>,
+    mut examine_events: EventWriter<ExamineEvent>,
+    mut hide_events: EventWriter<HideEvent>,
+    mut break_events: EventWriter<BreakEvent>,
+    mut cut_wire_events: EventWriter<CutWireEvent>,
+) {
+    let mut nearest = None;
+
+    if game_state.game_mode == GameMode::Playing
+        && input.is_none_or(|input| input.allows(InputPermission::Interact))
+        && let Ok((player, player_transform, actions)) = player_query.single()
+    {
+        let player_pos = player_transform.translation.truncate();
+        nearest = interactables
+            .iter()
+            .map(|(entity, transform, interactable)| {
+                let distance = transform.translation.truncate().distance(player_pos);
+                (entity, interactable, distance)
+            })
+            .filter(|(_, interactable, distance)| *distance <= interactable.range)
+            .min_by(|a, b| a.2.total_cmp(&b.2))
+            .map(|(entity, interactable, _)| (entity, interactable));
+
+        if let Some((target, interactable)) = nearest
+            && actions.just_pressed(&PlayerAction::Interact)
+        {
+            match interactable.kind {
+                InteractionKind::OpenDoor => {
+                    open_door_events.write(OpenDoorEvent {
+                        door: target,
+                        player,
+                    });
+                }
+                InteractionKind::Collect => {
+                    collect_events.write(CollectEvent {
+                        item: target,
+                        player,
+                    });
+                }
+                InteractionKind::TogglePuzzle => {
+                    toggle_puzzle_events.write(TogglePuzzleEvent {
+                        puzzle: target,
+                        player,
+                    });
+                }
+                InteractionKind::ReadNote => {
+                    read_note_events.write(ReadNoteEvent {
+                        note: target,
+                        player,
+                    });
+                }
+                InteractionKind::Examine => {
+                    examine_events.write(ExamineEvent { target, player });
+                }
+                InteractionKind::Hide => {
+                    hide_events.write(HideEvent {
+                        spot: target,
+                        player,
+                    });
+                }
+                InteractionKind::Break => {
+                    break_events.write(BreakEvent { target, player });
+                }
+                InteractionKind::CutWire => {
+                    cut_wire_events.write(CutWireEvent {
+                        wire: target,
+                        player,
+                    });
+                }
+            }
+        }
+    }
+
+    prompt.set_if_neq(InteractionPrompt {
+        target: nearest.map(|(target, _)| target),
+        prompt: nearest.map(|(_, interactable)| interactable.prompt.clone()),
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::resources::input_context::InputContext;
+
+    fn setup_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(InteractionPlugin);
+        app.init_resource::<GameState>();
+        app.world_mut().resource_mut::<GameState>().game_mode = GameMode::Playing;
+        app
+    }
+
+    #[test]
+    fn interacting_dispatches_event_for_nearest_interactable() {
+        let mut app = setup_app();
+        app.world_mut().spawn((
+            Interactable::new(InteractionKind::OpenDoor),
+            Transform::from_xyz(40.0, 0.0, 0.0),
+        ));
+        let note = app
+            .world_mut()
+            .spawn((
+                Interactable::new(InteractionKind::ReadNote),
+                Transform::from_xyz(-20.0, 0.0, 0.0),
+            ))
+            .id();
+
+        let mut actions = ActionState::<PlayerAction>::default();
+        actions.press(&PlayerAction::Interact);
+        let player = app
+            .world_mut()
+            .spawn((Player, Transform::default(), actions))
+            .id();
+        app.update();
+
+        assert_eq!(
+            *app.world().resource::<InteractionPrompt>(),
+            InteractionPrompt {
+                target: Some(note),
+                prompt: Some("Read".to_string()),
+            }
+        );
+        let notes: Vec<ReadNoteEvent> = app
+            .world_mut()
+            .resource_mut::<Events<ReadNoteEvent>>()
+            .drain()
+            .collect();
+        assert_eq!(notes, vec![ReadNoteEvent { note, player }]);
+        assert!(app.world().resource::<Events<OpenDoorEvent>>().is_empty());
+    }
+
+    #[test]
+    fn prompt_clears_when_out_of_range() {
+        let mut app = setup_app();
+        let mut chest = Interactable::new(InteractionKind::Collect);
+        chest.range = 16.0;
+        app.world_mut()
+            .spawn((chest, Transform::from_xyz(30.0, 0.0, 0.0)));
+        app.world_mut().spawn((
+            Player,
+            Transform::default(),
+            ActionState::<PlayerAction>::default(),
+        ));
+        app.update();
+
+        assert_eq!(
+            *app.world().resource::<InteractionPrompt>(),
+            InteractionPrompt::default()
+        );
+    }
+
+    #[test]
+    fn no_interactions_while_puzzle_panel_has_input() {
+        let mut app = setup_app();
+        app.insert_resource(InputContextStack {
+            contexts: vec![InputContext::PuzzlePanel],
+        });
+        app.world_mut().spawn((
+            Interactable::new(InteractionKind::OpenDoor),
+            Transform::from_xyz(10.0, 0.0, 0.0),
+        ));
+        let mut actions = ActionState::<PlayerAction>::default();
+        actions.press(&PlayerAction::Interact);
+        app.world_mut()
+            .spawn((Player, Transform::default(), actions));
+        app.update();
+
+        assert_eq!(
+            *app.world().resource::<InteractionPrompt>(),
+            InteractionPrompt::default()
+        );
+        assert!(app.world().resource::<Events<OpenDoorEvent>>().is_empty());
+    }
+}
@@ -1,6 +1,19 @@
-use crate::components::inventory::{Inventory, Item, StackableItem};
+use crate::components::inventory::{
+    Collectible, Inventory, InventoryFullReason, Item, ItemCategory, PickupCooldown, StackableItem,
+};
+use crate::components::lighting::{CandleState, CandleWax, CarriedCandle, FULL_CANDLE_WAX};
+use crate::components::render_layer::RenderLayer;
+use crate::components::room::Collider;
+use crate::resources::asset_handles::{AssetHandles, SpriteType};
+use crate::resources::game_rules::GameRules;
 use bevy::prelude::*;
 
+/// Half-size (pixels) of the pickup collider on a placed item
+pub const PLACED_ITEM_HALF_SIZE: Vec2 = Vec2::new(8.0, 8.0);
+
+/// Seconds before a placed item can be picked up again
+pub const PICKUP_COOLDOWN_SECS: f32 = 1.0;
+
 /// Event emitted when a player collects an item
 ///
 /// This event is typically emitted by the collision detection system when
@@ -43,49 +56,162 @@ pub struct ItemUsedEvent {
     pub player: Entity,
 }
 
+/// Event emitted when a player places an inventory item into the world
+///
+/// The item is dropped at the player's position, e.g. onto a pressure plate
+/// or altar that requires a specific object.
+#[derive(Event)]
+pub struct PlaceItemEvent {
+    /// The item to place
+    pub item: Item,
+    /// Entity ID of the player placing the item
+    pub player: Entity,
+}
+
+/// Event emitted when a player pours one candle stub into another
+///
+/// The stub with `wax` is poured into the best other stub (see
+/// `candle_stub_merge_target`).
+#[derive(Event)]
+pub struct MergeCandleStubsEvent {
+    /// Wax of the stub being poured
+    pub wax: f32,
+    /// Entity ID of the player carrying the stubs
+    pub player: Entity,
+}
+
+/// Event emitted when a player can't pick up an item
+///
+/// Emitted by `inventory_collection_system`; the item stays in the world
+/// (a stackable pickup keeps the units that didn't fit).
+#[derive(Event, Clone)]
+pub struct InventoryFullEvent {
+    /// The item that didn't fit
+    pub item: Item,
+    /// Entity ID of the player whose inventory is full
+    pub player: Entity,
+    /// Which limit refused the item
+    pub reason: InventoryFullReason,
+}
+
+/// Returns true when two items are the same kind (and key, tool or page)
+pub fn same_item(a: &Item, b: &Item) -> bool {
+    a.same_kind(b)
+}
+
 /// System that handles item collection and inventory management
 ///
 /// Listens for `ItemCollectedEvent` and:
-/// - Checks inventory capacity
-/// - Handles stackable items (matches) by incrementing count
+/// - Adds every unit of a `StackableItem` pickup (matches) to its stack
 /// - Adds unique items to separate slots
+/// - Refuses items over `max_capacity` or their category limit, emitting
+///   `InventoryFullEvent`
+/// - Refuses matches over `GameRules::max_matches` (Survival) as a full
+///   consumable category
 /// - Despawns collected items from the world
 ///
 /// # System Dependencies
 /// - **Upstream**: Requires `collision_detection_system` to emit `ItemCollectedEvent`
 /// - **Components**: Reads `Item`, `StackableItem`, `Collectible`; Writes `Inventory`
-/// - **Commands**: Despawns collected item entities
+/// - **Resources**: Reads `GameRules` (if present)
+/// - **Commands**: Despawns collected item entities, shrinks partly collected stacks
+/// - **Downstream**: `inventory_full_toast_system` shows the refusal
 ///
 /// From tasks.md T029: InventorySystem
 pub fn inventory_collection_system(
     mut events: EventReader<ItemCollectedEvent>,
+    mut full_events: EventWriter<InventoryFullEvent>,
     mut commands: Commands,
     mut inventory_query: Query<&mut Inventory>,
     item_query: Query<(&Item, Option<&StackableItem>)>,
+    rules: Option<Res<GameRules>>,
 ) {
+    let max_matches = rules.and_then(|rules| rules.max_matches);
     for event in events.read() {
-        if let Ok(mut inventory) = inventory_query.get_mut(event.player) {
-            // Check capacity
-            if inventory.items.len() >= inventory.max_capacity {
-                // TODO: Emit InventoryFullEvent for UI notification
-                continue; // Inventory full, cannot pick up
-            }
+        let Ok(mut inventory) = inventory_query.get_mut(event.player) else {
+            continue;
+        };
+        let Ok((item, stackable)) = item_query.get(event.item) else {
+            continue;
+        };
 
-            if let Ok((item, stackable)) = item_query.get(event.item) {
-                // Handle stackable vs unique items
-                if stackable.is_some() {
-                    // For stackable items (like matches), increment count if already in inventory
-                    // For now, we use simple duplication approach
-                    // TODO: Implement proper stacking with HashMap<Item, count>
-                    inventory.items.push(item.clone());
-                } else {
-                    // Unique items occupy separate slots
-                    inventory.items.push(item.clone());
+        let units = stackable.map_or(1, |stack| stack.0.max(1));
+        let mut added = 0;
+        let mut refused = None;
+        while added < units {
+            if let Some(max) = max_matches
+                && matches!(item, Item::Match)
+                && inventory.count(item) >= max
+            {
+                refused = Some(InventoryFullReason::CategoryFull(ItemCategory::Consumable));
+                break;
+            }
+            match inventory.add(item.clone()) {
+                Ok(()) => added += 1,
+                Err(reason) => {
+                    refused = Some(reason);
+                    break;
                 }
+            }
+        }
 
+        match refused {
+            None => {
                 // Despawn item from world
                 commands.entity(event.item).despawn();
             }
+            Some(reason) => {
+                if added > 0 {
+                    commands
+                        .entity(event.item)
+                        .insert(StackableItem(units - added));
+                }
+                full_events.write(InventoryFullEvent {
+                    item: item.clone(),
+                    player: event.player,
+                    reason,
+                });
+            }
+        }
+    }
+}
+
+/// System that scales match pickups by the game's `Difficulty`
+///
+/// # System Dependencies
+/// - **Upstream**: Level loading spawns `Collectible` match pickups
+/// - **Components**: Reads `Item`; writes or inserts `StackableItem`
+/// - **Resources**: Reads `GameRules` (if present)
+/// - **Downstream**: `inventory_collection_system` adds the scaled stack
+///
+/// # Behavior
+/// Each newly spawned match pickup holds `Difficulty::match_count` of its
+/// matches instead (one without a `StackableItem`). Items the player
+/// placed (with a `PickupCooldown`) are left alone, so dropping matches
+/// can't multiply them.
+#[allow(clippy::type_complexity)]
+pub fn match_pickup_difficulty_system(
+    mut commands: Commands,
+    rules: Option<Res<GameRules>>,
+    mut query: Query<
+        (Entity, &Item, Option<&mut StackableItem>),
+        (Added<Item>, With<Collectible>, Without<PickupCooldown>),
+    >,
+) {
+    let Some(rules) = rules else {
+        return;
+    };
+    for (entity, item, stack) in &mut query {
+        if !matches!(item, Item::Match) {
+            continue;
+        }
+        match stack {
+            Some(mut stack) => stack.0 = rules.difficulty.match_count(stack.0),
+            None => {
+                commands
+                    .entity(entity)
+                    .insert(StackableItem(rules.difficulty.match_count(1)));
+            }
         }
     }
 }
@@ -93,6 +219,7 @@ pub fn inventory_collection_system(
 /// System that handles item usage from inventory
 ///
 /// Listens for `ItemUsedEvent` and removes the consumed item from the player's inventory.
+/// Only consumables (matches) are used up; tools stay in the inventory.
 ///
 /// # System Dependencies
 /// - **Upstream**: Input system or UI system emits `ItemUsedEvent`
@@ -104,21 +231,15 @@ pub fn inventory_usage_system(
     mut inventory_query: Query<&mut Inventory>,
 ) {
     for event in events.read() {
+        if event.item.category() != ItemCategory::Consumable {
+            continue;
+        }
         if let Ok(mut inventory) = inventory_query.get_mut(event.player) {
             // Find and remove the first matching item
-            if let Some(pos) =
-                inventory
-                    .items
-                    .iter()
-                    .position(|inv_item| match (&event.item, inv_item) {
-                        (Item::Match, Item::Match) => true,
-                        (Item::Key(k1), Item::Key(k2)) => k1 == k2,
-                        (Item::Tool(t1), Item::Tool(t2)) => t1 == t2,
-                        (Item::PuzzleItem(p1), Item::PuzzleItem(p2)) => p1 == p2,
-                        (Item::DoubleJumpItem, Item::DoubleJumpItem) => true,
-                        (Item::DiaryPage(n1), Item::DiaryPage(n2)) => n1 == n2,
-                        _ => false,
-                    })
+            if let Some(pos) = inventory
+                .items
+                .iter()
+                .position(|inv_item| same_item(&event.item, inv_item))
             {
                 inventory.items.remove(pos);
             }
@@ -126,10 +247,213 @@ pub fn inventory_usage_system(
     }
 }
 
+/// System that lights the player's carried candle when a match is used
+///
+/// # System Dependencies
+/// - **Upstream**: Input system or UI system emits `ItemUsedEvent`
+/// - **Components**: Reads `CandleWax`, writes `CandleState` of the `CarriedCandle`
+///
+/// # Behavior
+/// Only an `Unlit` candle with wax left catches; extinguished candles
+/// can't be relit. The match is consumed by `inventory_usage_system`
+/// either way, so callers should check `can_light_candle` first.
+pub fn match_use_system(
+    mut events: EventReader<ItemUsedEvent>,
+    mut candle_query: Query<(&mut CandleState, &CandleWax), With<CarriedCandle>>,
+) {
+    for event in events.read() {
+        if !matches!(event.item, Item::Match) {
+            continue;
+        }
+
+        for (mut state, wax) in &mut candle_query {
+            if can_light_candle(&state, wax) {
+                *state = CandleState::Lit;
+            }
+        }
+    }
+}
+
+/// Returns true if a match would light a candle in this state
+pub fn can_light_candle(state: &CandleState, wax: &CandleWax) -> bool {
+    *state == CandleState::Unlit && wax.0 > 0.0
+}
+
+/// Returns true if a candle stub's wax can be poured into this candle
+///
+/// Extinguished candles can't be relit, so they aren't worth topping up.
+pub fn can_refill_candle(state: &CandleState, wax: &CandleWax) -> bool {
+    *state != CandleState::Extinguished && wax.0 < FULL_CANDLE_WAX
+}
+
+/// Picks the stub to pour the stub at `from` into
+///
+/// Prefers the fullest other stub that isn't full yet, so merging builds
+/// whole candles rather than evening stubs out.
+///
+/// # Returns
+/// Index into `inventory.items` of the target stub, or `None` if `from`
+/// isn't a stub or there's no other stub with room
+pub fn candle_stub_merge_target(inventory: &Inventory, from: usize) -> Option<usize> {
+    if !matches!(inventory.items.get(from), Some(Item::CandleStub(_))) {
+        return None;
+    }
+    inventory
+        .items
+        .iter()
+        .enumerate()
+        .filter_map(|(index, item)| match item {
+            Item::CandleStub(wax) if index != from && *wax < FULL_CANDLE_WAX => Some((index, *wax)),
+            _ => None,
+        })
+        .max_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(index, _)| index)
+}
+
+/// System that pours candle stubs into one another
+///
+/// # System Dependencies
+/// - **Upstream**: The inventory screen emits `MergeCandleStubsEvent`
+/// - **Components**: Writes `Inventory`
+///
+/// # Behavior
+/// The stub is poured into the target picked by `candle_stub_merge_target`
+/// with `Inventory::merge_candle_stubs`. Events without a target are ignored.
+pub fn candle_stub_merge_system(
+    mut events: EventReader<MergeCandleStubsEvent>,
+    mut inventory_query: Query<&mut Inventory>,
+) {
+    for event in events.read() {
+        let Ok(mut inventory) = inventory_query.get_mut(event.player) else {
+            continue;
+        };
+        let stub = Item::CandleStub(event.wax);
+        let Some(from) = inventory
+            .items
+            .iter()
+            .position(|item| same_item(&stub, item))
+        else {
+            continue;
+        };
+        if let Some(into) = candle_stub_merge_target(&inventory, from)
+            && let Some(wax) = inventory.merge_candle_stubs(into, from)
+        {
+            info!("Merged candle stubs into one with {:.0} wax", wax);
+        }
+    }
+}
+
+/// System that pours a used candle stub into the player's carried candle
+///
+/// # System Dependencies
+/// - **Upstream**: Input system or UI system emits `ItemUsedEvent`
+/// - **Components**: Writes `CandleWax` of the `CarriedCandle`
+///
+/// # Behavior
+/// The candle fills up to `FULL_CANDLE_WAX` if `can_refill_candle`; its
+/// state is left alone. The stub is consumed by `inventory_usage_system`
+/// either way (wax that doesn't fit is lost), so callers should check
+/// `can_refill_candle` first.
+pub fn candle_stub_use_system(
+    mut events: EventReader<ItemUsedEvent>,
+    mut candle_query: Query<(&CandleState, &mut CandleWax), With<CarriedCandle>>,
+) {
+    for event in events.read() {
+        let Item::CandleStub(stub_wax) = event.item else {
+            continue;
+        };
+
+        for (state, mut wax) in &mut candle_query {
+            if can_refill_candle(state, &wax) {
+                wax.0 = (wax.0 + stub_wax).min(FULL_CANDLE_WAX);
+            }
+        }
+    }
+}
+
+/// System that places inventory items into the world
+///
+/// Listens for `PlaceItemEvent` and:
+/// - Removes the first matching item from the player's inventory
+/// - Spawns it at the player's position with a pickup `Collider`, drawn
+///   with its sprite from `AssetHandles` (when present)
+/// - Adds `PickupCooldown` so it isn't collected again straight away
+///
+/// Events for items the player doesn't carry are ignored.
+///
+/// # System Dependencies
+/// - **Upstream**: Input system or UI system emits `PlaceItemEvent`
+/// - **Components**: Writes `Inventory`, reads `Transform`
+/// - **Resources**: Reads `AssetHandles` (optional)
+/// - **Downstream**: `pickup_cooldown_system` makes the item `Collectible`
+pub fn item_placement_system(
+    mut events: EventReader<PlaceItemEvent>,
+    mut commands: Commands,
+    mut inventory_query: Query<(&mut Inventory, &Transform)>,
+    handles: Option<Res<AssetHandles>>,
+) {
+    for event in events.read() {
+        let Ok((mut inventory, transform)) = inventory_query.get_mut(event.player) else {
+            continue;
+        };
+        let Some(pos) = inventory
+            .items
+            .iter()
+            .position(|inv_item| same_item(&event.item, inv_item))
+        else {
+            continue;
+        };
+
+        let item = inventory.items.remove(pos);
+        let sprite = handles
+            .as_ref()
+            .zip(SpriteType::for_item(&item))
+            .map(|(handles, sprite)| handles.sprite_frame(sprite, 0));
+        let mut placed = commands.spawn((
+            item,
+            RenderLayer::Items,
+            Transform::from_translation(
+                transform
+                    .translation
+                    .truncate()
+                    .extend(RenderLayer::Items.z()),
+            ),
+            Collider {
+                min: -PLACED_ITEM_HALF_SIZE,
+                max: PLACED_ITEM_HALF_SIZE,
+            },
+            PickupCooldown(Timer::from_seconds(PICKUP_COOLDOWN_SECS, TimerMode::Once)),
+        ));
+        if let Some(sprite) = sprite {
+            placed.insert(sprite);
+        }
+    }
+}
+
+/// System that makes placed items collectible once their cooldown ends
+///
+/// # System Dependencies
+/// - **Upstream**: `item_placement_system` adds `PickupCooldown`
+/// - **Components**: Writes `PickupCooldown`; inserts `Collectible`
+pub fn pickup_cooldown_system(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut PickupCooldown)>,
+) {
+    for (entity, mut cooldown) in &mut query {
+        if cooldown.0.tick(time.delta()).finished() {
+            commands
+                .entity(entity)
+                .remove::<PickupCooldown>()
+                .insert(Collectible);
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::components::inventory::{Collectible, KeyType};
+    use crate::components::inventory::KeyType;
     use crate::components::player::Player;
 
     #[test]
@@ -169,6 +493,200 @@ mod tests {
         assert!(!events.is_empty());
     }
 
+    #[test]
+    fn full_inventory_keeps_leftover_matches_and_reports() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_event::<ItemCollectedEvent>();
+        app.add_event::<InventoryFullEvent>();
+        app.add_systems(Update, inventory_collection_system);
+
+        let player = app
+            .world_mut()
+            .spawn((
+                Player,
+                Inventory {
+                    items: vec![Item::Match; 97],
+                    max_capacity: 1,
+                },
+            ))
+            .id();
+        let matches = app
+            .world_mut()
+            .spawn((Item::Match, StackableItem(5), Collectible))
+            .id();
+
+        app.world_mut().send_event(ItemCollectedEvent {
+            item: matches,
+            player,
+        });
+        app.update();
+
+        let inventory = app.world().get::<Inventory>(player).unwrap();
+        assert_eq!(inventory.count(&Item::Match), 99);
+        assert_eq!(app.world().get::<StackableItem>(matches).unwrap().0, 3);
+
+        let events = app.world().resource::<Events<InventoryFullEvent>>();
+        let full: Vec<InventoryFullEvent> = events.iter_current_update_events().cloned().collect();
+        assert_eq!(full.len(), 1);
+        assert!(matches!(full[0].item, Item::Match));
+        assert_eq!(full[0].reason, InventoryFullReason::NoFreeSlots);
+    }
+
+    #[test]
+    fn survival_rules_cap_carried_matches() {
+        use crate::resources::game_rules::{GameRuleSet, SURVIVAL_MAX_MATCHES};
+
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_event::<ItemCollectedEvent>();
+        app.add_event::<InventoryFullEvent>();
+        app.add_systems(Update, inventory_collection_system);
+        app.insert_resource(GameRules::for_rule_set(GameRuleSet::Survival));
+
+        let player = app
+            .world_mut()
+            .spawn((
+                Player,
+                Inventory {
+                    items: vec![Item::Match; 3],
+                    max_capacity: 10,
+                },
+            ))
+            .id();
+        let matches = app
+            .world_mut()
+            .spawn((Item::Match, StackableItem(5), Collectible))
+            .id();
+
+        app.world_mut().send_event(ItemCollectedEvent {
+            item: matches,
+            player,
+        });
+        app.update();
+
+        let inventory = app.world().get::<Inventory>(player).unwrap();
+        assert_eq!(inventory.count(&Item::Match), SURVIVAL_MAX_MATCHES);
+        assert_eq!(app.world().get::<StackableItem>(matches).unwrap().0, 3);
+
+        let events = app.world().resource::<Events<InventoryFullEvent>>();
+        let full: Vec<InventoryFullEvent> = events.iter_current_update_events().cloned().collect();
+        assert_eq!(
+            full[0].reason,
+            InventoryFullReason::CategoryFull(ItemCategory::Consumable)
+        );
+    }
+
+    #[test]
+    fn difficulty_scales_spawned_match_pickups_but_not_placed_ones() {
+        use crate::resources::game_rules::Difficulty;
+
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_systems(Update, match_pickup_difficulty_system);
+        app.insert_resource(GameRules::default().with_difficulty(Difficulty::Easy));
+
+        let stack = app
+            .world_mut()
+            .spawn((Item::Match, StackableItem(4), Collectible))
+            .id();
+        let single = app.world_mut().spawn((Item::Match, Collectible)).id();
+        let placed = app
+            .world_mut()
+            .spawn((
+                Item::Match,
+                Collectible,
+                PickupCooldown(Timer::from_seconds(1.0, TimerMode::Once)),
+            ))
+            .id();
+        app.update();
+        app.update();
+
+        assert_eq!(app.world().get::<StackableItem>(stack).unwrap().0, 6);
+        assert_eq!(app.world().get::<StackableItem>(single).unwrap().0, 2);
+        assert!(app.world().get::<StackableItem>(placed).is_none());
+    }
+
+    #[test]
+    fn merge_event_pours_stub_into_fullest_other_stub() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_event::<MergeCandleStubsEvent>();
+        app.add_systems(Update, candle_stub_merge_system);
+
+        let player = app
+            .world_mut()
+            .spawn((
+                Player,
+                Inventory {
+                    items: vec![
+                        Item::CandleStub(10.0),
+                        Item::CandleStub(FULL_CANDLE_WAX),
+                        Item::CandleStub(70.0),
+                        Item::CandleStub(40.0),
+                    ],
+                    max_capacity: 10,
+                },
+            ))
+            .id();
+
+        app.world_mut()
+            .send_event(MergeCandleStubsEvent { wax: 40.0, player });
+        app.update();
+
+        let inventory = app.world().get::<Inventory>(player).unwrap();
+        let wax: Vec<f32> = inventory
+            .items
+            .iter()
+            .filter_map(|item| match item {
+                Item::CandleStub(wax) => Some(*wax),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(wax, vec![10.0, FULL_CANDLE_WAX, FULL_CANDLE_WAX, 10.0]);
+    }
+
+    #[test]
+    fn using_a_stub_tops_up_the_carried_candle() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_event::<ItemUsedEvent>();
+        app.add_systems(Update, (candle_stub_use_system, inventory_usage_system));
+
+        let player = app
+            .world_mut()
+            .spawn((
+                Player,
+                Inventory {
+                    items: vec![Item::CandleStub(30.0)],
+                    max_capacity: 10,
+                },
+            ))
+            .id();
+        let candle = app
+            .world_mut()
+            .spawn((CarriedCandle::default(), CandleState::Lit, CandleWax(80.0)))
+            .id();
+
+        app.world_mut().send_event(ItemUsedEvent {
+            item: Item::CandleStub(30.0),
+            player,
+        });
+        app.update();
+
+        assert_eq!(
+            app.world().get::<CandleWax>(candle).unwrap().0,
+            FULL_CANDLE_WAX
+        );
+        assert!(
+            app.world()
+                .get::<Inventory>(player)
+                .unwrap()
+                .items
+                .is_empty()
+        );
+    }
+
     #[test]
     fn item_usage_system_removes_item_from_inventory() {
         let mut app = App::new();
@@ -208,4 +726,120 @@ mod tests {
         assert!(matches!(inventory.items[0], Item::Key(KeyType::Brass)));
         assert!(matches!(inventory.items[1], Item::Match));
     }
+
+    #[test]
+    fn using_a_match_lights_an_unlit_carried_candle() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_event::<ItemUsedEvent>();
+        app.add_systems(Update, match_use_system);
+
+        let player = app.world_mut().spawn(Player).id();
+        let candle = app
+            .world_mut()
+            .spawn((
+                CarriedCandle::default(),
+                CandleState::Unlit,
+                CandleWax(50.0),
+            ))
+            .id();
+        let burnt_out = app
+            .world_mut()
+            .spawn((
+                CarriedCandle::default(),
+                CandleState::Extinguished,
+                CandleWax(0.0),
+            ))
+            .id();
+
+        app.world_mut().send_event(ItemUsedEvent {
+            item: Item::Key(KeyType::Brass),
+            player,
+        });
+        app.update();
+        assert_eq!(
+            *app.world().get::<CandleState>(candle).unwrap(),
+            CandleState::Unlit
+        );
+
+        app.world_mut().send_event(ItemUsedEvent {
+            item: Item::Match,
+            player,
+        });
+        app.update();
+        assert_eq!(
+            *app.world().get::<CandleState>(candle).unwrap(),
+            CandleState::Lit
+        );
+        assert_eq!(
+            *app.world().get::<CandleState>(burnt_out).unwrap(),
+            CandleState::Extinguished
+        );
+    }
+
+    #[test]
+    fn placing_item_spawns_it_at_player_without_immediate_pickup() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_event::<PlaceItemEvent>();
+        app.add_systems(Update, item_placement_system);
+
+        let player = app
+            .world_mut()
+            .spawn((
+                Player,
+                Transform::from_xyz(120.0, 64.0, 5.0),
+                Inventory {
+                    items: vec![Item::Match, Item::Key(KeyType::Iron)],
+                    max_capacity: 10,
+                },
+            ))
+            .id();
+
+        app.world_mut().send_event(PlaceItemEvent {
+            item: Item::Key(KeyType::Iron),
+            player,
+        });
+        // Not carried: ignored
+        app.world_mut().send_event(PlaceItemEvent {
+            item: Item::DoubleJumpItem,
+            player,
+        });
+        app.update();
+
+        let inventory = app.world().get::<Inventory>(player).unwrap();
+        assert_eq!(inventory.items.len(), 1);
+
+        let placed: Vec<(Vec3, bool)> = app
+            .world_mut()
+            .query_filtered::<(&Transform, Has<Collectible>), (With<Item>, With<PickupCooldown>)>()
+            .iter(app.world())
+            .map(|(transform, collectible)| (transform.translation, collectible))
+            .collect();
+        assert_eq!(
+            placed,
+            vec![(Vec3::new(120.0, 64.0, RenderLayer::Items.z()), false)]
+        );
+    }
+
+    #[test]
+    fn pickup_cooldown_makes_item_collectible() {
+        use std::time::Duration;
+
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_systems(Update, pickup_cooldown_system);
+
+        let mut timer = Timer::from_seconds(PICKUP_COOLDOWN_SECS, TimerMode::Once);
+        timer.tick(Duration::from_secs_f32(PICKUP_COOLDOWN_SECS));
+        let item = app
+            .world_mut()
+            .spawn((Item::Match, PickupCooldown(timer)))
+            .id();
+
+        app.update();
+
+        assert!(app.world().get::<Collectible>(item).is_some());
+        assert!(app.world().get::<PickupCooldown>(item).is_none());
+    }
 }
@@ -0,0 +1,356 @@
+use std::collections::HashSet;
+use std::fs;
+use std::path::Path;
+
+use crate::components::inventory::{Item, KeyType};
+use crate::entities::registry::SpawnRegistry;
+use crate::resources::asset_handles::{SpriteManifest, SpriteType};
+use crate::systems::level_loader::{LevelData, parse_level_data, validate_level_data};
+use crate::systems::save_load::{SerializedItem, deserialize_item};
+use crate::systems::tilemap::WALL_TILE;
+
+/// Directory the `level_check` tool checks by default
+pub const LEVELS_DIR: &str = "assets/levels";
+
+/// Entity types set up by the room rather than a `SpawnRegistry` spawner
+/// (the player start, the player's candle, item pickups and doors)
+pub const ROOM_ENTITY_TYPES: [&str; 5] = ["PlayerSpawn", "Candle", "Match", "Key", "Door"];
+
+/// Number of tiles in the tileset the sprite manifest describes
+///
+/// A tileset without a sheet layout holds just the floor and wall tiles.
+pub fn tileset_tile_count(manifest: &SpriteManifest) -> u32 {
+    manifest
+        .sheets
+        .get(&SpriteType::Tileset)
+        .map_or(WALL_TILE + 1, |sheet| sheet.columns * sheet.rows)
+}
+
+/// Checks one level on its own
+///
+/// # Returns
+/// Every problem found:
+/// - Whatever `validate_level_data` rejects
+/// - Tile indices past the end of a `tile_count`-tile tileset
+/// - Entity types that neither `registry` nor the room (`ROOM_ENTITY_TYPES`)
+///   spawns
+pub fn check_level(level: &LevelData, tile_count: u32, registry: &SpawnRegistry) -> Vec<String> {
+    let mut problems = Vec::new();
+    if let Err(e) = validate_level_data(level) {
+        problems.push(e);
+    }
+
+    for (row, tiles) in level.tiles.iter().enumerate() {
+        for (column, tile) in tiles.iter().enumerate() {
+            if *tile >= tile_count {
+                problems.push(format!(
+                    "Tile {} at row {}, column {} is outside the {}-tile tileset",
+                    tile, row, column, tile_count
+                ));
+            }
+        }
+    }
+
+    for spawn in &level.entities {
+        let entity_type = spawn.entity_type.as_str();
+        if !registry.contains(entity_type) && !ROOM_ENTITY_TYPES.contains(&entity_type) {
+            problems.push(format!(
+                "Entity type '{}' at {:?} has no spawner",
+                entity_type, spawn.position
+            ));
+        }
+    }
+    problems
+}
+
+/// Checks that every connection leads to a level that connects back
+///
+/// # Returns
+/// A problem for each room ID used by several levels, each connection to a
+/// room with no level, and each connection the target room has no
+/// connection back for
+pub fn check_connections(levels: &[LevelData]) -> Vec<String> {
+    let mut problems = Vec::new();
+    let mut ids = HashSet::new();
+    for level in levels {
+        if !ids.insert(level.id) {
+            problems.push(format!("Room ID {} is used by several levels", level.id));
+        }
+    }
+
+    for level in levels {
+        for connection in &level.connections {
+            match levels
+                .iter()
+                .find(|target| target.id == connection.target_room)
+            {
+                None => problems.push(format!(
+                    "{}: connection to room {} leads to a room with no level",
+                    room_label(level),
+                    connection.target_room
+                )),
+                Some(target)
+                    if !target
+                        .connections
+                        .iter()
+                        .any(|back| back.target_room == level.id) =>
+                {
+                    problems.push(format!(
+                        "{}: connection to {} has no connection back",
+                        room_label(level),
+                        room_label(target)
+                    ))
+                }
+                Some(_) => {}
+            }
+        }
+    }
+    problems
+}
+
+/// Checks that the player can reach every room and open every lock
+///
+/// Starting from the room with the `"PlayerSpawn"`, rooms are explored
+/// through unlocked connections and through locked ones whose key (or the
+/// Master key) lies in a room already reached. Keys are counted whether
+/// they lie loose, are a metal object or are hidden in a breakable.
+///
+/// # Returns
+/// A problem for a missing player spawn, each lock in a reachable room
+/// whose key can't be reached, and each room that can't be reached
+pub fn check_key_reachability(levels: &[LevelData]) -> Vec<String> {
+    let Some(start) = levels.iter().find(|level| {
+        level
+            .entities
+            .iter()
+            .any(|spawn| spawn.entity_type == "PlayerSpawn")
+    }) else {
+        return vec!["No level has a PlayerSpawn".to_string()];
+    };
+
+    let mut reached = HashSet::from([start.id]);
+    let mut keys = HashSet::new();
+    loop {
+        let before = (reached.len(), keys.len());
+        for level in levels.iter().filter(|level| reached.contains(&level.id)) {
+            keys.extend(level_keys(level));
+        }
+        let opens = |lock: Option<KeyType>| {
+            lock.is_none_or(|key| keys.contains(&key) || keys.contains(&KeyType::Master))
+        };
+        let newly_reached: Vec<usize> = levels
+            .iter()
+            .filter(|level| reached.contains(&level.id))
+            .flat_map(|level| &level.connections)
+            .filter(|connection| opens(connection.locked))
+            .map(|connection| connection.target_room)
+            .collect();
+        reached.extend(newly_reached);
+        if (reached.len(), keys.len()) == before {
+            break;
+        }
+    }
+
+    let mut problems = Vec::new();
+    for level in levels.iter().filter(|level| reached.contains(&level.id)) {
+        for connection in &level.connections {
+            if let Some(key) = connection.locked
+                && !keys.contains(&key)
+                && !keys.contains(&KeyType::Master)
+            {
+                problems.push(format!(
+                    "{}: {:?} lock on the connection to room {} has no reachable key",
+                    room_label(level),
+                    key,
+                    connection.target_room
+                ));
+            }
+        }
+    }
+    for level in levels.iter().filter(|level| !reached.contains(&level.id)) {
+        problems.push(format!(
+            "{} can't be reached from the player spawn",
+            room_label(level)
+        ));
+    }
+    problems
+}
+
+/// Checks every level RON file in `dir`, on its own and as a house
+///
+/// # Returns
+/// Every problem found, those of a single file prefixed with its path
+///
+/// # Errors
+/// Returns error string if `dir` can't be read
+pub fn check_level_dir(dir: &Path, manifest: &SpriteManifest) -> Result<Vec<String>, String> {
+    let mut paths: Vec<_> = fs::read_dir(dir)
+        .map_err(|e| format!("Failed to read level directory '{}': {}", dir.display(), e))?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.extension().is_some_and(|extension| extension == "ron"))
+        .collect();
+    paths.sort();
+
+    let tile_count = tileset_tile_count(manifest);
+    let mut problems = Vec::new();
+    let mut levels = Vec::new();
+    for path in paths {
+        let name = path.display().to_string();
+        let level = fs::read_to_string(&path)
+            .map_err(|e| format!("Failed to read level file: {}", e))
+            .and_then(|content| parse_level_data(&content, &name));
+        match level {
+            Ok(level) => {
+                for problem in check_level(&level, tile_count, SpawnRegistry::standard()) {
+                    problems.push(format!("{}: {}", name, problem));
+                }
+                levels.push(level);
+            }
+            Err(e) => problems.push(format!("{}: {}", name, e)),
+        }
+    }
+
+    problems.extend(check_connections(&levels));
+    problems.extend(check_key_reachability(&levels));
+    Ok(problems)
+}
+
+/// Keys the player can pick up in `level`
+fn level_keys(level: &LevelData) -> impl Iterator<Item = KeyType> + '_ {
+    level.entities.iter().flat_map(|spawn| {
+        let loose = spawn.key_type.filter(|_| spawn.entity_type == "Key");
+        let carried = [
+            spawn.item.as_ref(),
+            spawn
+                .breakable
+                .as_ref()
+                .and_then(|breakable| breakable.contains.as_ref()),
+        ]
+        .into_iter()
+        .flatten()
+        .filter_map(item_key);
+        loose.into_iter().chain(carried)
+    })
+}
+
+fn item_key(item: &SerializedItem) -> Option<KeyType> {
+    match deserialize_item(item) {
+        Item::Key(key) => Some(key),
+        _ => None,
+    }
+}
+
+fn room_label(level: &LevelData) -> String {
+    format!("Room {} ({})", level.id, level.name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn level(id: usize, body: &str) -> LevelData {
+        parse_level_data(
+            &format!(
+                "(id: {}, floor: Ground, name: \"Room {}\", \
+                 bounds: (min: (0.0, 0.0), max: (640.0, 480.0)), {})",
+                id, id, body
+            ),
+            "test",
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn level_check_reports_bad_tiles_and_unknown_entity_types() {
+        let level = level(
+            0,
+            r#"tiles: [[0, 1], [1, 7]],
+            entities: [
+                (entity_type: "PlayerSpawn", position: (10.0, 10.0)),
+                (entity_type: "Spikes", position: (20.0, 10.0)),
+                (entity_type: "Ghost", position: (30.0, 10.0)),
+            ],
+            connections: []"#,
+        );
+
+        let problems = check_level(&level, 2, SpawnRegistry::standard());
+        assert_eq!(problems.len(), 2, "{:?}", problems);
+        assert!(problems[0].contains("Tile 7 at row 1, column 1"));
+        assert!(problems[1].contains("'Ghost'"));
+        assert_eq!(tileset_tile_count(&SpriteManifest::default()), 2);
+    }
+
+    #[test]
+    fn connections_must_lead_somewhere_and_back() {
+        let levels = [
+            level(
+                0,
+                "tiles: [], entities: [], connections: [
+                    (target_room: 1, connection_type: Door, position: (0.0, 0.0), locked: None),
+                    (target_room: 2, connection_type: Door, position: (0.0, 0.0), locked: None),
+                ]",
+            ),
+            level(1, "tiles: [], entities: [], connections: []"),
+        ];
+
+        assert_eq!(
+            check_connections(&levels),
+            vec![
+                "Room 0 (Room 0): connection to Room 1 (Room 1) has no connection back",
+                "Room 0 (Room 0): connection to room 2 leads to a room with no level",
+            ]
+        );
+    }
+
+    #[test]
+    fn locks_need_a_key_reachable_before_them() {
+        let door = |target: usize, lock: &str| {
+            format!(
+                "(target_room: {}, connection_type: Door, position: (0.0, 0.0), locked: {})",
+                target, lock
+            )
+        };
+        // The brass key is behind the brass door; the iron key in a
+        // breakable before the iron door
+        let levels = [
+            level(
+                0,
+                &format!(
+                    r#"tiles: [], connections: [{}, {}],
+                    entities: [
+                        (entity_type: "PlayerSpawn", position: (10.0, 10.0)),
+                        (entity_type: "Crate", position: (20.0, 10.0),
+                         breakable: Some((contains: Some(Key(Iron))))),
+                    ]"#,
+                    door(1, "Some(Iron)"),
+                    door(2, "Some(Brass)")
+                ),
+            ),
+            level(
+                1,
+                &format!(
+                    "tiles: [], entities: [], connections: [{}]",
+                    door(0, "None")
+                ),
+            ),
+            level(
+                2,
+                &format!(
+                    r#"tiles: [], connections: [{}],
+                    entities: [
+                        (entity_type: "Key", position: (10.0, 10.0), key_type: Some(Brass)),
+                    ]"#,
+                    door(0, "None")
+                ),
+            ),
+        ];
+
+        assert_eq!(
+            check_key_reachability(&levels),
+            vec![
+                "Room 0 (Room 0): Brass lock on the connection to room 2 has no reachable key",
+                "Room 2 (Room 2) can't be reached from the player spawn",
+            ]
+        );
+    }
+}
@@ -0,0 +1,91 @@
+use crate::systems::level_loader::{LevelData, load_level_file, validate_level_data};
+
+/// Command-line flag that dumps a level as JSON instead of starting the game
+pub const DUMP_LEVEL_FLAG: &str = "--dump-level";
+
+/// Returns the level path given after `--dump-level`, if present
+pub fn dump_level_arg(args: impl IntoIterator<Item = String>) -> Option<String> {
+    let mut args = args.into_iter();
+    args.find(|arg| arg == DUMP_LEVEL_FLAG)?;
+    args.next()
+}
+
+/// Puts level data in a stable order for diffing and external tools
+///
+/// Entities are sorted by type then position and connections by target
+/// room then position, so reordering a RON file doesn't change the dump.
+pub fn normalize_level(mut level: LevelData) -> LevelData {
+    level.entities.sort_by(|a, b| {
+        a.entity_type
+            .cmp(&b.entity_type)
+            .then(a.position.0.total_cmp(&b.position.0))
+            .then(a.position.1.total_cmp(&b.position.1))
+    });
+    level.connections.sort_by(|a, b| {
+        a.target_room
+            .cmp(&b.target_room)
+            .then(a.position.0.total_cmp(&b.position.0))
+            .then(a.position.1.total_cmp(&b.position.1))
+    });
+    level
+}
+
+/// Loads, validates and normalizes a level RON file as pretty-printed JSON
+///
+/// The JSON mirrors `LevelData`: room metadata, bounds, tiles, entities and
+/// connections. Levels have no prefabs to resolve yet, so entities are
+/// emitted exactly as authored.
+///
+/// # Arguments
+/// * `path` - Path to the RON file (not resolved against assets/)
+///
+/// # Errors
+/// Returns error string if the file can't be read or parsed, fails
+/// `validate_level_data`, or can't be serialized
+pub fn dump_level_json(path: &str) -> Result<String, String> {
+    let level = load_level_file(path)?;
+    validate_level_data(&level)?;
+
+    serde_json::to_string_pretty(&normalize_level(level))
+        .map_err(|e| format!("Failed to serialize level '{}': {}", path, e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dump_level_arg_reads_path_after_flag() {
+        let args = ["rust-game", "--dump-level", "assets/levels/a.ron"].map(String::from);
+        assert_eq!(
+            dump_level_arg(args),
+            Some("assets/levels/a.ron".to_string())
+        );
+        assert_eq!(dump_level_arg(["rust-game".to_string()]), None);
+    }
+
+    #[test]
+    fn dumps_entry_hall_as_normalized_json() {
+        let json = dump_level_json("assets/levels/ground_floor_entry.ron").unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(value["id"], 0);
+        assert_eq!(value["name"], "Entry Hall");
+        assert_eq!(value["connections"][0]["target_room"], 1);
+        let types: Vec<&str> = value["entities"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|entity| entity["entity_type"].as_str().unwrap())
+            .collect();
+        let mut sorted = types.clone();
+        sorted.sort();
+        assert_eq!(types, sorted);
+    }
+
+    #[test]
+    fn dump_fails_for_missing_file() {
+        let error = dump_level_json("assets/levels/missing.ron").unwrap_err();
+        assert!(error.starts_with("Failed to read level file"));
+    }
+}
@@ -1,9 +1,47 @@
+use bevy::ecs::system::SystemParam;
+use bevy::ecs::world::CommandQueue;
 use bevy::prelude::*;
-use serde::Deserialize;
+use bevy::tasks::{ComputeTaskPool, TaskPool};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fs;
 
-use crate::components::inventory::KeyType;
-use crate::components::room::{ConnectionType, Floor};
+use crate::audio::music::RoomAudio;
+use crate::components::inventory::{Collectible, KeyType, ToolType};
+use crate::components::lighting::{BurnModifier, BurnZone};
+use crate::components::render_layer::{RenderLayer, SortOffset, YSort, y_sort_z};
+use crate::components::room::{
+    Checkpoint, Collider, ConnectionType, Door, DoorState, ExitDoor, Floor, HiddenPassage,
+    Interactable, InteractionKind, LevelEntityId, PassageReveal, Pushable, RoomId, Solid,
+    TargetRoom,
+};
+use crate::components::trap::{
+    ChandelierFall, CrumblingFloor, DEFAULT_CHANDELIER_DROP, InstantDeath, PoisonGas, ResetTimer,
+    SwingingBlade, Trap, TrapState, TrapTrigger,
+};
+use crate::entities::registry::SpawnRegistry;
+use crate::resources::game_rng::GameRng;
+use crate::systems::breakable::Breakable;
+use crate::systems::checkpoint::CHECKPOINT_INACTIVE_COLOR;
+use crate::systems::dialogue::{DialogueTrigger, TriggerOn};
+use crate::systems::director::DirectorBudget;
+use crate::systems::electrical::{
+    DEFAULT_ELECTRIFIED_FLOOR_SIZE, Electrified, LIVE_WIRE_SIZE, LiveWire, WIRE_COLOR,
+};
+use crate::systems::grapple::{GRAPPLE_ANCHOR_COLOR, GRAPPLE_ANCHOR_SIZE, GrappleAnchor};
+use crate::systems::hiding::HidingSpot;
+use crate::systems::magnet::{METAL_OBJECT_COLOR, METAL_OBJECT_SIZE, MetalObject};
+use crate::systems::oil_can::Stuck;
+use crate::systems::procgen::{
+    MAX_SIDE_ROOMS, SideRoomTemplate, attach_side_rooms, generate_side_room, side_room_parent,
+};
+use crate::systems::remix::{ActiveRemix, HouseRemix};
+use crate::systems::save_load::{SerializedItem, deserialize_item};
+use crate::systems::scripting::LevelScript;
+use crate::systems::secret_passage::HIDDEN_PASSAGE_WALL_COLOR;
+use crate::systems::tilemap::WALL_TILE;
+use crate::systems::trigger_zone::{TriggerFilter, TriggerZone};
+use crate::systems::water::{WATER_COLOR, WATER_Z_OFFSET, WaterRegion};
 
 /// Level data structures matching RON file format
 /// From tasks.md T039: Load room data from RON files and spawn entities
@@ -14,7 +52,7 @@ use crate::components::room::{ConnectionType, Floor};
 /// entity spawns, and connections to other rooms.
 ///
 /// From tasks.md T039: "Load room data from RON files"
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct LevelData {
     /// Unique room identifier
     pub id: usize,
@@ -30,13 +68,25 @@ pub struct LevelData {
     pub entities: Vec<EntitySpawn>,
     /// List of connections to other rooms (doors, stairs, etc.)
     pub connections: Vec<RoomConnection>,
+    /// Hazards the director may spawn here (none when omitted)
+    #[serde(default)]
+    pub director: Option<DirectorBudget>,
+    /// Ambience and tension of the room (silent, calm room when omitted)
+    #[serde(default)]
+    pub audio: Option<RoomAudio>,
+    /// Scripts run on the room's events (see `scripting::ScriptingPlugin`)
+    #[serde(default)]
+    pub scripts: Vec<LevelScript>,
+    /// Optional side rooms generated off this room (see `procgen`)
+    #[serde(default)]
+    pub side_rooms: Vec<SideRoomTemplate>,
 }
 
 /// Room boundary coordinates
 ///
 /// Defines the min and max coordinates of a room in world space.
 /// Used for collision detection and camera bounds.
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct Bounds {
     /// Minimum (bottom-left) corner coordinates (x, y)
     pub min: (f32, f32),
@@ -48,7 +98,7 @@ pub struct Bounds {
 ///
 /// Represents an entity to be spawned in the room with its type and position.
 /// Optional fields support different entity types (doors, keys, etc.).
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
 pub struct EntitySpawn {
     /// String identifier for entity type (e.g., "Match", "Key", "Door")
     pub entity_type: String,
@@ -63,13 +113,248 @@ pub struct EntitySpawn {
     /// Optional key type identifier for key entities
     #[serde(default)]
     pub key_type: Option<KeyType>,
+    /// Optional tuning for trap entities
+    #[serde(default)]
+    pub trap_config: Option<TrapConfig>,
+    /// Optional reveal method for hidden passages (defaults to puzzle reward)
+    #[serde(default)]
+    pub reveal: Option<PassageReveal>,
+    /// Script and trigger for dialogue trigger entities
+    #[serde(default)]
+    pub dialogue: Option<DialogueConfig>,
+    /// Name and behavior for trigger zone entities
+    #[serde(default)]
+    pub trigger: Option<TriggerConfig>,
+    /// Size and physics for water regions
+    #[serde(default)]
+    pub water: Option<WaterConfig>,
+    /// Tool and hidden item for breakable objects (also makes a crate breakable)
+    #[serde(default)]
+    pub breakable: Option<BreakableConfig>,
+    /// Item a metal object is (a key or fuse); a plain iron weight when omitted
+    #[serde(default)]
+    pub item: Option<SerializedItem>,
+    /// Circuit and wire for electrified hazards and the wires feeding them
+    #[serde(default)]
+    pub electric: Option<ElectricConfig>,
+    /// Whether a door is rusted stuck until oiled
+    #[serde(default)]
+    pub stuck: bool,
+    /// Multiplier and size for burn zones
+    #[serde(default)]
+    pub burn: Option<BurnConfig>,
+}
+
+/// Dialogue trigger settings from level data
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct DialogueConfig {
+    /// ID of the script in `DialogueScripts`
+    pub script: String,
+    /// What sets the trigger off (examining it by default)
+    #[serde(default)]
+    pub on: TriggerOn,
+    /// Size (width, height) of the area or object in pixels
+    #[serde(default)]
+    pub size: Option<(f32, f32)>,
+    /// Prompt shown when examining (defaults to "Look closer")
+    #[serde(default)]
+    pub prompt: Option<String>,
+}
+
+/// Trigger zone settings from level data
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct TriggerConfig {
+    /// Name of the `GameplayTrigger` the zone fires
+    pub name: String,
+    /// Size (width, height) of the zone in pixels
+    #[serde(default)]
+    pub size: Option<(f32, f32)>,
+    /// Fire only the first time the player enters (true when omitted)
+    #[serde(default)]
+    pub once: Option<bool>,
+    /// Condition the player must meet for the zone to fire
+    #[serde(default)]
+    pub filter: TriggerFilter,
+}
+
+/// Water region settings from level data
+///
+/// Every field falls back to a default when omitted. The region's top edge
+/// is the water surface.
+#[derive(Deserialize, Serialize, Debug, Clone, Default, PartialEq)]
+pub struct WaterConfig {
+    /// Size (width, height) of the water in pixels
+    #[serde(default)]
+    pub size: Option<(f32, f32)>,
+    /// Upward acceleration (pixels per second squared) on a submerged player
+    #[serde(default)]
+    pub buoyancy: Option<f32>,
+    /// Multiplier on the player's horizontal speed while in the water
+    #[serde(default)]
+    pub speed_factor: Option<f32>,
 }
 
+/// Burn zone settings from level data
+///
+/// A `"BurnZone"` scales how fast candles inside it burn (see
+/// `BurnModifier`): above 1.0 for drafty or damp rooms, below for shelter.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct BurnConfig {
+    /// Burn rate multiplier inside the zone
+    pub multiplier: f32,
+    /// Size (width, height) of the zone in pixels; the whole room when omitted
+    #[serde(default)]
+    pub size: Option<(f32, f32)>,
+}
+
+/// Electrical settings from level data
+///
+/// `"ElectrifiedFloor"`s are on `circuit` (always live when omitted) and
+/// fed by `wire`; a `"LiveWire"` is the wire named `wire`. An `"ExitDoor"`
+/// with these settings is electrified too.
+#[derive(Deserialize, Serialize, Debug, Clone, Default, PartialEq)]
+pub struct ElectricConfig {
+    /// Name of the `BreakerCircuit` powering the hazard
+    #[serde(default)]
+    pub circuit: Option<String>,
+    /// Name of the wire feeding the hazard, or of the wire itself
+    #[serde(default)]
+    pub wire: Option<String>,
+    /// Size (width, height) of an electrified floor in pixels
+    #[serde(default)]
+    pub size: Option<(f32, f32)>,
+}
+
+impl ElectricConfig {
+    /// The `Electrified` component for a hazard with these settings
+    fn electrified(&self) -> Electrified {
+        Electrified {
+            circuit: self.circuit.clone(),
+            wire: self.wire.clone(),
+            ..default()
+        }
+    }
+}
+
+/// Breakable object settings from level data
+///
+/// Boarded doors and weak walls are always breakable; a `"Crate"` only
+/// when this is given.
+#[derive(Deserialize, Serialize, Debug, Clone, Default, PartialEq)]
+pub struct BreakableConfig {
+    /// Tool needed to break it (a crowbar when omitted)
+    #[serde(default)]
+    pub tool: Option<ToolType>,
+    /// Item hidden inside, dropped when it breaks
+    #[serde(default)]
+    pub contains: Option<SerializedItem>,
+}
+
+/// Optional trap tuning from level data
+///
+/// Every field falls back to a sensible default when omitted, so levels
+/// only need to specify what differs from the standard trap.
+#[derive(Deserialize, Serialize, Debug, Clone, Default, PartialEq)]
+pub struct TrapConfig {
+    /// Collider size (width, height) in pixels
+    #[serde(default)]
+    pub size: Option<(f32, f32)>,
+    /// Swing period in seconds (SwingingBlade)
+    #[serde(default)]
+    pub period: Option<f32>,
+    /// Seconds of standing before the floor collapses (CollapsingFloor)
+    #[serde(default)]
+    pub crumble_after: Option<f32>,
+    /// Seconds before a collapsed floor is restored (CollapsingFloor)
+    #[serde(default)]
+    pub respawn_after: Option<f32>,
+    /// Seconds of exposure a player can withstand (PoisonGas)
+    #[serde(default)]
+    pub tolerance: Option<f32>,
+    /// Tool that protects from the hazard (PoisonGas)
+    #[serde(default)]
+    pub protection: Option<ToolType>,
+    /// Seconds before a re-armable trap is armed again (Spikes, SwingingBlade)
+    #[serde(default)]
+    pub reset_after: Option<f32>,
+    /// Height in pixels it falls from onto its collider (FallingChandelier)
+    #[serde(default)]
+    pub drop_height: Option<f32>,
+}
+
+/// Default trap collider size (width, height) in pixels
+pub const DEFAULT_TRAP_SIZE: (f32, f32) = (32.0, 32.0);
+
+/// Default poison gas zone size (width, height) in pixels
+pub const DEFAULT_GAS_ZONE_SIZE: (f32, f32) = (256.0, 192.0);
+
+/// Entity types `spawn_trap_entity` spawns
+pub const TRAP_ENTITY_TYPES: [&str; 5] = [
+    "Spikes",
+    "SwingingBlade",
+    "CollapsingFloor",
+    "PoisonGas",
+    "FallingChandelier",
+];
+
+/// Size (pixels) of a checkpoint's trigger area and marker sprite
+pub const CHECKPOINT_SIZE: Vec2 = Vec2::new(32.0, 48.0);
+
+/// Size (pixels) of a hidden passage's wall section
+pub const HIDDEN_PASSAGE_SIZE: Vec2 = Vec2::new(32.0, 64.0);
+
+/// Size (pixels) of the exit door sprite
+pub const EXIT_DOOR_SIZE: Vec2 = Vec2::new(48.0, 96.0);
+
+/// Color of the exit door sprite
+pub const EXIT_DOOR_COLOR: Color = Color::srgb(0.4, 0.26, 0.14);
+
+/// Default dialogue trigger size (width, height) in pixels
+pub const DEFAULT_DIALOGUE_TRIGGER_SIZE: (f32, f32) = (32.0, 32.0);
+
+/// Default trigger zone size (width, height) in pixels
+pub const DEFAULT_TRIGGER_ZONE_SIZE: (f32, f32) = (64.0, 64.0);
+
+/// Default water region size (width, height) in pixels
+pub const DEFAULT_WATER_SIZE: (f32, f32) = (256.0, 128.0);
+
+/// Size (pixels) of a wardrobe the player can hide in
+pub const WARDROBE_SIZE: Vec2 = Vec2::new(48.0, 96.0);
+
+/// Size (pixels) of a bed the player can hide under
+pub const BED_SIZE: Vec2 = Vec2::new(96.0, 40.0);
+
+/// Color of hiding furniture sprites
+pub const HIDING_FURNITURE_COLOR: Color = Color::srgb(0.3, 0.2, 0.13);
+
+/// Size (pixels) of a boarded-up doorway
+pub const BOARDED_DOOR_SIZE: Vec2 = Vec2::new(32.0, 64.0);
+
+/// Color of boarded-up doorways
+pub const BOARDED_DOOR_COLOR: Color = Color::srgb(0.42, 0.3, 0.18);
+
+/// Size (pixels) of a weak wall section
+pub const WEAK_WALL_SIZE: Vec2 = Vec2::new(32.0, 64.0);
+
+/// Color of weak wall sections
+pub const WEAK_WALL_COLOR: Color = Color::srgb(0.45, 0.42, 0.4);
+
+/// Size (pixels) of a pushable crate
+pub const CRATE_SIZE: Vec2 = Vec2::new(32.0, 32.0);
+
+/// Color of a pushable crate sprite
+pub const CRATE_COLOR: Color = Color::srgb(0.45, 0.3, 0.16);
+
+/// Spawn records assembled per compute task by `spawn_level_entities_batched`
+///
+/// Rooms with no more spawns than this are assembled on the calling thread.
+pub const SPAWN_BATCH_SIZE: usize = 256;
+
 /// Room connection definition
 ///
 /// Represents a connection to another room (door, staircase, etc.).
 /// Used to build the room graph for navigation.
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct RoomConnection {
     /// ID of the room this connection leads to
     pub target_room: usize,
@@ -109,15 +394,216 @@ pub fn load_level_data(level_path: &str) -> Result<LevelData, String> {
     // Construct full path to assets directory
     let full_path = format!("assets/{}", level_path);
 
+    load_level_file(&full_path)
+}
+
+/// Load level data from a RON file at any path
+///
+/// Like `load_level_data`, but `path` is used as-is instead of being
+/// resolved against assets/ (used by the `--dump-level` CLI mode).
+pub fn load_level_file(path: &str) -> Result<LevelData, String> {
     // Read file contents
-    let content = fs::read_to_string(&full_path)
-        .map_err(|e| format!("Failed to read level file '{}': {}", full_path, e))?;
+    let content = fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read level file '{}': {}", path, e))?;
 
-    // Parse RON format
-    let level_data: LevelData = ron::from_str(&content)
-        .map_err(|e| format!("Failed to parse RON from '{}': {}", full_path, e))?;
+    parse_level_data(&content, path)
+}
 
-    Ok(level_data)
+/// Parse level data from RON text
+///
+/// `path` only names the source in error messages.
+///
+/// # Errors
+/// Returns error string with the line and column where parsing stopped
+pub fn parse_level_data(content: &str, path: &str) -> Result<LevelData, String> {
+    ron::from_str(content).map_err(|e| {
+        format!(
+            "Failed to parse RON from '{}' at line {}, column {}: {}",
+            path, e.position.line, e.position.col, e.code
+        )
+    })
+}
+
+/// Load and validate the level file of `room_id` for a room transition
+///
+/// The file is the one `registry` has for the room (a mod's), otherwise
+/// the room's file in assets/ (see `LevelRegistry::level_file`).
+///
+/// The room is remixed with `remix` (a New Game+ one, see
+/// `remix::RemixPlugin`) when given. With the run's `side_room_seed`, the
+/// room gets doors to the side rooms generated for the run, and IDs of
+/// generated side rooms (see `procgen::side_room_id`) load the generated
+/// room. Rooms without a level file yet are not an error (the room is
+/// entered without authored content), but a file that exists and is unreadable,
+/// corrupted, partially written or invalid is, so the caller can keep the
+/// player where they are instead of entering a broken room.
+///
+/// # Returns
+/// * `Ok(Some(level))` - The room's level loaded and validated
+/// * `Ok(None)` - The room has no level file
+///
+/// # Errors
+/// Returns error string (including the parse error location) if the level
+/// file cannot be read, parsed or validated
+pub fn load_room_level(
+    room_id: usize,
+    registry: &LevelRegistry,
+    remix: Option<&HouseRemix>,
+    side_room_seed: Option<u64>,
+) -> Result<Option<LevelData>, String> {
+    if let Some((parent, index)) = side_room_parent(room_id) {
+        return Ok(load_room_level(parent, registry, remix, side_room_seed)?
+            .zip(side_room_seed)
+            .and_then(|(parent, seed)| generate_side_room(&parent, index, seed)));
+    }
+
+    let full_path = registry.level_file(room_id);
+    if !std::path::Path::new(&full_path).exists() {
+        return Ok(None);
+    }
+
+    let mut level = load_level_file(&full_path)?;
+    if let Some(remix) = remix {
+        remix.apply(&mut level);
+    }
+    if let Some(seed) = side_room_seed {
+        attach_side_rooms(&mut level, seed);
+    }
+    validate_level_data(&level)?;
+    Ok(Some(level))
+}
+
+/// Checks level data for mistakes the RON format can't catch
+///
+/// # Errors
+/// Returns every problem found, separated by "; ":
+/// - Bounds whose min corner isn't below and left of the max corner
+/// - Tile rows of different lengths
+/// - Entities or connections positioned outside the room bounds
+/// - Connections leading back to the same room
+/// - Hidden passages without a target room
+/// - Director spawn points outside the room bounds
+/// - More side room templates than `MAX_SIDE_ROOMS`
+pub fn validate_level_data(level: &LevelData) -> Result<(), String> {
+    let mut errors = Vec::new();
+    let (min, max) = (level.bounds.min, level.bounds.max);
+
+    if min.0 >= max.0 || min.1 >= max.1 {
+        errors.push(format!("Invalid bounds {:?} to {:?}", min, max));
+    }
+
+    if let Some(first) = level.tiles.first()
+        && let Some(row) = level.tiles.iter().position(|row| row.len() != first.len())
+    {
+        errors.push(format!(
+            "Tile row {} has {} tiles, expected {}",
+            row,
+            level.tiles[row].len(),
+            first.len()
+        ));
+    }
+
+    let inside = |(x, y): (f32, f32)| x >= min.0 && x <= max.0 && y >= min.1 && y <= max.1;
+    for spawn in level
+        .entities
+        .iter()
+        .filter(|spawn| !inside(spawn.position))
+    {
+        errors.push(format!(
+            "Entity '{}' at {:?} is outside the room bounds",
+            spawn.entity_type, spawn.position
+        ));
+    }
+    for spawn in level
+        .entities
+        .iter()
+        .filter(|spawn| spawn.entity_type == "HiddenPassage" && spawn.target_room.is_none())
+    {
+        errors.push(format!(
+            "Hidden passage at {:?} has no target room",
+            spawn.position
+        ));
+    }
+    for spawn in level
+        .entities
+        .iter()
+        .filter(|spawn| spawn.entity_type == "DialogueTrigger" && spawn.dialogue.is_none())
+    {
+        errors.push(format!(
+            "Dialogue trigger at {:?} has no dialogue script",
+            spawn.position
+        ));
+    }
+    for spawn in level
+        .entities
+        .iter()
+        .filter(|spawn| spawn.entity_type == "TriggerZone" && spawn.trigger.is_none())
+    {
+        errors.push(format!(
+            "Trigger zone at {:?} has no trigger name",
+            spawn.position
+        ));
+    }
+    for spawn in level
+        .entities
+        .iter()
+        .filter(|spawn| spawn.entity_type == "BurnZone")
+    {
+        match &spawn.burn {
+            None => errors.push(format!(
+                "Burn zone at {:?} has no burn multiplier",
+                spawn.position
+            )),
+            Some(burn) if burn.multiplier < 0.0 => errors.push(format!(
+                "Burn zone at {:?} has a negative multiplier {}",
+                spawn.position, burn.multiplier
+            )),
+            Some(_) => {}
+        }
+    }
+    for connection in &level.connections {
+        if !inside(connection.position) {
+            errors.push(format!(
+                "Connection to room {} at {:?} is outside the room bounds",
+                connection.target_room, connection.position
+            ));
+        }
+        if connection.target_room == level.id {
+            errors.push(format!(
+                "Connection at {:?} leads back to room {}",
+                connection.position, level.id
+            ));
+        }
+    }
+    for point in level
+        .director
+        .iter()
+        .flat_map(|budget| &budget.spawn_points)
+        .filter(|point| !inside(**point))
+    {
+        errors.push(format!(
+            "Director spawn point {:?} is outside the room bounds",
+            point
+        ));
+    }
+    if level.side_rooms.len() > MAX_SIDE_ROOMS {
+        errors.push(format!(
+            "{} side room templates, more than the {} a room may have",
+            level.side_rooms.len(),
+            MAX_SIDE_ROOMS
+        ));
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(format!(
+            "Level '{}' (ID: {}) is invalid: {}",
+            level.name,
+            level.id,
+            errors.join("; ")
+        ))
+    }
 }
 
 /// System to load a level and spawn entities
@@ -132,15 +618,16 @@ pub fn load_level_data(level_path: &str) -> Result<LevelData, String> {
 /// # Behavior
 /// 1. Load level data from RON file
 /// 2. Parse level structure
-/// 3. Spawn entities based on level data
-/// 4. Set up room connections
+/// 3. Spawn merged wall colliders from the tile grid
+/// 4. Spawn entities based on level data
+/// 5. Set up room connections
 ///
 /// From tasks.md T039: "Spawn entities based on LevelData"
 ///
 /// **Note**: This is a demonstration system. In production, level loading would be
 /// triggered by room transition events and integrated with the room_transition system.
 pub fn load_level_system(
-    _commands: Commands,
+    mut commands: Commands,
     _asset_server: Res<AssetServer>,
     // In a full implementation, this would read from a resource or event
 ) {
@@ -161,12 +648,31 @@ pub fn load_level_system(
                 level_data.tiles.first().map(|r| r.len()).unwrap_or(0)
             );
 
-            // TODO: Spawn entities based on level_data.entities
             // TODO: Set up room connections based on level_data.connections
             // TODO: Configure tilemap based on level_data.tiles
+            let walls = spawn_wall_colliders(&mut commands, &level_data);
+            info!("  Wall colliders: {}", walls.len());
+
+            // Tag spawned entities so RoomStateStore can re-apply what the
+            // player changed (lit sconces, pushed crates) on later visits
+            let room = level_data.id;
+            if level_data.entities.len() > SPAWN_BATCH_SIZE {
+                queue_level_entities(&mut commands, room, level_data.entities);
+                return;
+            }
+
+            for (index, entity_spawn) in level_data.entities.iter().enumerate() {
+                if let Some(entity) = spawn_level_entity(&mut commands, entity_spawn) {
+                    commands
+                        .entity(entity)
+                        .insert(LevelEntityId { room, index });
+                    info!(
+                        "  Spawned {} at ({}, {})",
+                        entity_spawn.entity_type, entity_spawn.position.0, entity_spawn.position.1
+                    );
+                    continue;
+                }
 
-            // Example: Log entity spawns
-            for entity_spawn in &level_data.entities {
                 info!(
                     "  Would spawn {} at ({}, {})",
                     entity_spawn.entity_type, entity_spawn.position.0, entity_spawn.position.1
@@ -179,6 +685,846 @@ pub fn load_level_system(
     }
 }
 
+/// Spawn a trap entity described by level data
+///
+/// Supports `"Spikes"`, `"SwingingBlade"`, `"CollapsingFloor"`,
+/// `"PoisonGas"` and `"FallingChandelier"` entity types, applying any
+/// `TrapConfig` overrides.
+///
+/// # Returns
+/// * `Some(Entity)` - The spawned trap
+/// * `None` - If `entity_type` is not a trap type
+pub fn spawn_trap_entity(commands: &mut Commands, spawn: &EntitySpawn) -> Option<Entity> {
+    let config = spawn.trap_config.clone().unwrap_or_default();
+    let (x, y) = spawn.position;
+
+    let default_size = match spawn.entity_type.as_str() {
+        "PoisonGas" => DEFAULT_GAS_ZONE_SIZE,
+        _ => DEFAULT_TRAP_SIZE,
+    };
+    let (width, height) = config.size.unwrap_or(default_size);
+    let collider = Collider {
+        min: Vec2::new(-width / 2.0, -height / 2.0),
+        max: Vec2::new(width / 2.0, height / 2.0),
+    };
+    let base = (
+        TrapState::Armed,
+        RenderLayer::Items,
+        Transform::from_xyz(x, y, RenderLayer::Items.z()),
+        collider,
+    );
+
+    let entity = match spawn.entity_type.as_str() {
+        "Spikes" => commands
+            .spawn((
+                Trap::Spikes,
+                TrapTrigger::PressurePlate,
+                InstantDeath,
+                ResetTimer::from_seconds(config.reset_after.unwrap_or(2.0)),
+                base,
+            ))
+            .id(),
+        "SwingingBlade" => commands
+            .spawn((
+                Trap::SwingingBlade,
+                TrapTrigger::Timed(config.period.unwrap_or(2.0)),
+                InstantDeath,
+                SwingingBlade::new(config.period.unwrap_or(2.0)),
+                ResetTimer::from_seconds(config.reset_after.unwrap_or(0.5)),
+                base,
+            ))
+            .id(),
+        "CollapsingFloor" => commands
+            .spawn((
+                Trap::CollapsingFloor,
+                TrapTrigger::PressurePlate,
+                CrumblingFloor::new(
+                    config.crumble_after.unwrap_or(0.5),
+                    config.respawn_after.unwrap_or(5.0),
+                ),
+                base,
+            ))
+            .id(),
+        "PoisonGas" => commands
+            .spawn((
+                Trap::PoisonGas,
+                TrapTrigger::Proximity(width.max(height) / 2.0),
+                PoisonGas {
+                    tolerance: config.tolerance.unwrap_or(5.0),
+                    protection: config.protection.unwrap_or(ToolType::GasMask),
+                },
+                base,
+            ))
+            .id(),
+        "FallingChandelier" => commands
+            .spawn((
+                Trap::FallingChandelier,
+                TrapTrigger::Proximity(width.max(height) / 2.0),
+                ChandelierFall::new(config.drop_height.unwrap_or(DEFAULT_CHANDELIER_DROP)),
+                base,
+            ))
+            .id(),
+        _ => return None,
+    };
+
+    Some(entity)
+}
+
+/// Spawn a checkpoint entity described by level data
+///
+/// # Returns
+/// * `Some(Entity)` - The spawned checkpoint
+/// * `None` - If `entity_type` is not `"Checkpoint"`
+pub fn spawn_checkpoint_entity(commands: &mut Commands, spawn: &EntitySpawn) -> Option<Entity> {
+    if spawn.entity_type != "Checkpoint" {
+        return None;
+    }
+
+    let (x, y) = spawn.position;
+    let entity = commands
+        .spawn((
+            Checkpoint::default(),
+            Sprite {
+                color: CHECKPOINT_INACTIVE_COLOR,
+                custom_size: Some(CHECKPOINT_SIZE),
+                ..default()
+            },
+            RenderLayer::Items,
+            Transform::from_xyz(x, y, RenderLayer::Items.z()),
+            Collider {
+                min: -CHECKPOINT_SIZE / 2.0,
+                max: CHECKPOINT_SIZE / 2.0,
+            },
+        ))
+        .id();
+
+    Some(entity)
+}
+
+/// Spawn a hidden passage entity described by level data
+///
+/// The passage is drawn as part of the wall until revealed. Its reveal
+/// method comes from `reveal` (puzzle reward if omitted).
+///
+/// # Returns
+/// * `Some(Entity)` - The spawned passage
+/// * `None` - If `entity_type` is not `"HiddenPassage"` or there is no `target_room`
+pub fn spawn_hidden_passage_entity(commands: &mut Commands, spawn: &EntitySpawn) -> Option<Entity> {
+    if spawn.entity_type != "HiddenPassage" {
+        return None;
+    }
+    let target_room = spawn.target_room?;
+
+    let (x, y) = spawn.position;
+    let entity = commands
+        .spawn((
+            HiddenPassage::new(spawn.reveal.unwrap_or(PassageReveal::PuzzleReward)),
+            TargetRoom(target_room),
+            Sprite {
+                color: HIDDEN_PASSAGE_WALL_COLOR,
+                custom_size: Some(HIDDEN_PASSAGE_SIZE),
+                ..default()
+            },
+            RenderLayer::Tiles,
+            Transform::from_xyz(x, y, RenderLayer::Tiles.z()),
+            Collider {
+                min: -HIDDEN_PASSAGE_SIZE / 2.0,
+                max: HIDDEN_PASSAGE_SIZE / 2.0,
+            },
+        ))
+        .id();
+
+    Some(entity)
+}
+
+/// Spawn a dialogue trigger described by level data
+///
+/// `TriggerOn::Enter` triggers are invisible areas; `TriggerOn::Examine`
+/// ones are objects with an `InteractionKind::Examine` interactable.
+///
+/// # Returns
+/// * `Some(Entity)` - The spawned trigger
+/// * `None` - If `entity_type` is not `"DialogueTrigger"` or there is no `dialogue`
+pub fn spawn_dialogue_trigger_entity(
+    commands: &mut Commands,
+    spawn: &EntitySpawn,
+) -> Option<Entity> {
+    if spawn.entity_type != "DialogueTrigger" {
+        return None;
+    }
+    let config = spawn.dialogue.as_ref()?;
+
+    let (x, y) = spawn.position;
+    let size = Vec2::from(config.size.unwrap_or(DEFAULT_DIALOGUE_TRIGGER_SIZE));
+    let mut entity = commands.spawn((
+        DialogueTrigger {
+            script: config.script.clone(),
+            on: config.on,
+        },
+        RenderLayer::Items,
+        Transform::from_xyz(x, y, RenderLayer::Items.z()),
+        Collider {
+            min: -size / 2.0,
+            max: size / 2.0,
+        },
+    ));
+    if config.on == TriggerOn::Examine {
+        let mut interactable = Interactable::new(InteractionKind::Examine);
+        if let Some(prompt) = &config.prompt {
+            interactable.prompt = prompt.clone();
+        }
+        entity.insert(interactable);
+    }
+
+    Some(entity.id())
+}
+
+/// Spawn an invisible trigger zone described by level data
+///
+/// # Returns
+/// * `Some(Entity)` - The spawned zone
+/// * `None` - If `entity_type` is not `"TriggerZone"` or there is no `trigger`
+pub fn spawn_trigger_zone_entity(commands: &mut Commands, spawn: &EntitySpawn) -> Option<Entity> {
+    if spawn.entity_type != "TriggerZone" {
+        return None;
+    }
+    let config = spawn.trigger.as_ref()?;
+
+    let (x, y) = spawn.position;
+    let size = Vec2::from(config.size.unwrap_or(DEFAULT_TRIGGER_ZONE_SIZE));
+    let entity = commands
+        .spawn((
+            TriggerZone {
+                once: config.once.unwrap_or(true),
+                filter: config.filter,
+                ..TriggerZone::new(config.name.clone())
+            },
+            RenderLayer::Items,
+            Transform::from_xyz(x, y, RenderLayer::Items.z()),
+            Collider {
+                min: -size / 2.0,
+                max: size / 2.0,
+            },
+        ))
+        .id();
+
+    Some(entity)
+}
+
+/// Spawn an invisible burn zone described by level data
+///
+/// # Returns
+/// * `Some(Entity)` - The spawned zone
+/// * `None` - If `entity_type` is not `"BurnZone"` or there is no `burn`
+pub fn spawn_burn_zone_entity(commands: &mut Commands, spawn: &EntitySpawn) -> Option<Entity> {
+    if spawn.entity_type != "BurnZone" {
+        return None;
+    }
+    let config = spawn.burn.as_ref()?;
+
+    let (x, y) = spawn.position;
+    let mut entity = commands.spawn((
+        BurnZone,
+        BurnModifier(config.multiplier),
+        Transform::from_xyz(x, y, 0.0),
+    ));
+    if let Some(size) = config.size {
+        let size = Vec2::from(size);
+        entity.insert(Collider {
+            min: -size / 2.0,
+            max: size / 2.0,
+        });
+    }
+
+    Some(entity.id())
+}
+
+/// Spawn a water region described by level data
+///
+/// The water is drawn as a translucent body just in front of the player
+/// layer, applying any `WaterConfig` overrides.
+///
+/// # Returns
+/// * `Some(Entity)` - The spawned water region
+/// * `None` - If `entity_type` is not `"WaterRegion"`
+pub fn spawn_water_region_entity(commands: &mut Commands, spawn: &EntitySpawn) -> Option<Entity> {
+    if spawn.entity_type != "WaterRegion" {
+        return None;
+    }
+    let config = spawn.water.clone().unwrap_or_default();
+
+    let (x, y) = spawn.position;
+    let size = Vec2::from(config.size.unwrap_or(DEFAULT_WATER_SIZE));
+    let defaults = WaterRegion::default();
+    let entity = commands
+        .spawn((
+            WaterRegion {
+                buoyancy: config.buoyancy.unwrap_or(defaults.buoyancy),
+                speed_factor: config.speed_factor.unwrap_or(defaults.speed_factor),
+            },
+            Sprite {
+                color: WATER_COLOR,
+                custom_size: Some(size),
+                ..default()
+            },
+            // In front of the player (and candle) so they look submerged
+            Transform::from_xyz(x, y, RenderLayer::Player.z() + WATER_Z_OFFSET),
+            Collider {
+                min: -size / 2.0,
+                max: size / 2.0,
+            },
+        ))
+        .id();
+
+    Some(entity)
+}
+
+/// Spawn a pushable crate described by level data
+///
+/// Crates are solid, so the player can climb onto them to reach high
+/// ledges, and heavy enough to hold down pressure plates.
+///
+/// # Returns
+/// * `Some(Entity)` - The spawned crate
+/// * `None` - If `entity_type` is not `"Crate"`
+pub fn spawn_crate_entity(commands: &mut Commands, spawn: &EntitySpawn) -> Option<Entity> {
+    if spawn.entity_type != "Crate" {
+        return None;
+    }
+
+    let (x, y) = spawn.position;
+    let mut entity = commands.spawn((
+        Pushable::default(),
+        Solid,
+        Sprite {
+            color: CRATE_COLOR,
+            custom_size: Some(CRATE_SIZE),
+            ..default()
+        },
+        RenderLayer::Items,
+        YSort,
+        SortOffset(-CRATE_SIZE.y / 2.0),
+        Transform::from_xyz(x, y, y_sort_z(y - CRATE_SIZE.y / 2.0)),
+        Collider {
+            min: -CRATE_SIZE / 2.0,
+            max: CRATE_SIZE / 2.0,
+        },
+    ));
+    if let Some(config) = &spawn.breakable {
+        entity.insert((
+            breakable_from_config(config, CRATE_COLOR),
+            Interactable {
+                prompt: "Pry the crate open".to_string(),
+                ..Interactable::new(InteractionKind::Break)
+            },
+        ));
+    }
+
+    Some(entity.id())
+}
+
+/// Builds the `Breakable` for level breakable settings
+fn breakable_from_config(config: &BreakableConfig, debris_color: Color) -> Breakable {
+    Breakable {
+        tool: config.tool.unwrap_or(ToolType::Crowbar),
+        contains: config.contains.as_ref().map(deserialize_item),
+        debris_color,
+    }
+}
+
+/// Spawn a boarded doorway or weak wall described by level data
+///
+/// Supports `"BoardedDoor"` and `"WeakWall"` entity types. Both block the
+/// way as solids until broken open (with a crowbar unless
+/// `BreakableConfig::tool` says otherwise).
+///
+/// # Returns
+/// * `Some(Entity)` - The spawned obstacle
+/// * `None` - If `entity_type` is not a breakable obstacle type
+pub fn spawn_breakable_entity(commands: &mut Commands, spawn: &EntitySpawn) -> Option<Entity> {
+    let (size, color, prompt) = match spawn.entity_type.as_str() {
+        "BoardedDoor" => (BOARDED_DOOR_SIZE, BOARDED_DOOR_COLOR, "Pry the boards off"),
+        "WeakWall" => (WEAK_WALL_SIZE, WEAK_WALL_COLOR, "Break through the wall"),
+        _ => return None,
+    };
+    let config = spawn.breakable.clone().unwrap_or_default();
+
+    let (x, y) = spawn.position;
+    let entity = commands
+        .spawn((
+            breakable_from_config(&config, color),
+            Solid,
+            Interactable {
+                prompt: prompt.to_string(),
+                ..Interactable::new(InteractionKind::Break)
+            },
+            Sprite {
+                color,
+                custom_size: Some(size),
+                ..default()
+            },
+            RenderLayer::Items,
+            Transform::from_xyz(x, y, RenderLayer::Items.z()),
+            Collider {
+                min: -size / 2.0,
+                max: size / 2.0,
+            },
+        ))
+        .id();
+
+    Some(entity)
+}
+
+/// Spawn a metal object described by level data
+///
+/// `"MetalObject"`s are pulled in by the magnet. One with an `item` is that
+/// item lying in the world, picked up once pulled to the player; without
+/// one it is an iron weight, heavy enough to hold down pressure plates.
+///
+/// # Returns
+/// * `Some(Entity)` - The spawned metal object
+/// * `None` - If `entity_type` is not `"MetalObject"`
+pub fn spawn_metal_object_entity(commands: &mut Commands, spawn: &EntitySpawn) -> Option<Entity> {
+    if spawn.entity_type != "MetalObject" {
+        return None;
+    }
+
+    let (x, y) = spawn.position;
+    let mut entity = commands.spawn((
+        MetalObject,
+        Sprite {
+            color: METAL_OBJECT_COLOR,
+            custom_size: Some(METAL_OBJECT_SIZE),
+            ..default()
+        },
+        RenderLayer::Items,
+        Transform::from_xyz(x, y, RenderLayer::Items.z()),
+        Collider {
+            min: -METAL_OBJECT_SIZE / 2.0,
+            max: METAL_OBJECT_SIZE / 2.0,
+        },
+    ));
+    if let Some(item) = &spawn.item {
+        entity.insert((deserialize_item(item), Collectible));
+    }
+
+    Some(entity.id())
+}
+
+/// Spawn furniture the player can hide in described by level data
+///
+/// Supports `"Wardrobe"` and `"Bed"` entity types.
+///
+/// # Returns
+/// * `Some(Entity)` - The spawned hiding spot
+/// * `None` - If `entity_type` is not a hiding furniture type
+pub fn spawn_hiding_spot_entity(commands: &mut Commands, spawn: &EntitySpawn) -> Option<Entity> {
+    let (size, prompt) = match spawn.entity_type.as_str() {
+        "Wardrobe" => (WARDROBE_SIZE, "Hide in the wardrobe"),
+        "Bed" => (BED_SIZE, "Hide under the bed"),
+        _ => return None,
+    };
+
+    let (x, y) = spawn.position;
+    // Furniture sorts with the player by where it stands
+    let sort_offset = -size.y / 2.0;
+    let entity = commands
+        .spawn((
+            HidingSpot::default(),
+            Interactable {
+                prompt: prompt.to_string(),
+                ..Interactable::new(InteractionKind::Hide)
+            },
+            Sprite {
+                color: HIDING_FURNITURE_COLOR,
+                custom_size: Some(size),
+                ..default()
+            },
+            RenderLayer::Items,
+            YSort,
+            SortOffset(sort_offset),
+            Transform::from_xyz(x, y, y_sort_z(y + sort_offset)),
+        ))
+        .id();
+
+    Some(entity)
+}
+
+/// Spawn a grappling rope anchor described by level data
+///
+/// # Returns
+/// * `Some(Entity)` - The spawned anchor
+/// * `None` - If `entity_type` is not `"GrappleAnchor"`
+pub fn spawn_grapple_anchor_entity(commands: &mut Commands, spawn: &EntitySpawn) -> Option<Entity> {
+    if spawn.entity_type != "GrappleAnchor" {
+        return None;
+    }
+
+    let (x, y) = spawn.position;
+    let entity = commands
+        .spawn((
+            GrappleAnchor,
+            Sprite {
+                color: GRAPPLE_ANCHOR_COLOR,
+                custom_size: Some(GRAPPLE_ANCHOR_SIZE),
+                ..default()
+            },
+            RenderLayer::Items,
+            Transform::from_xyz(x, y, RenderLayer::Items.z()),
+        ))
+        .id();
+
+    Some(entity)
+}
+
+/// Spawn the exit door described by level data
+///
+/// The door is locked with `locked` if given, and rusted `Stuck` until
+/// oiled if `stuck` is set. It has no target room:
+/// walking through it once open ends the game (see `escape_system`).
+///
+/// # Returns
+/// * `Some(Entity)` - The spawned exit door
+/// * `None` - If `entity_type` is not `"ExitDoor"`
+pub fn spawn_exit_door_entity(commands: &mut Commands, spawn: &EntitySpawn) -> Option<Entity> {
+    if spawn.entity_type != "ExitDoor" {
+        return None;
+    }
+
+    let (x, y) = spawn.position;
+    let mut entity = commands.spawn((
+        Door,
+        ExitDoor,
+        spawn.locked.map_or(DoorState::Unlocked, DoorState::Locked),
+        Interactable {
+            prompt: "Open the front door".to_string(),
+            ..Interactable::new(InteractionKind::OpenDoor)
+        },
+        Sprite {
+            color: EXIT_DOOR_COLOR,
+            custom_size: Some(EXIT_DOOR_SIZE),
+            ..default()
+        },
+        RenderLayer::Items,
+        Transform::from_xyz(x, y, RenderLayer::Items.z()),
+    ));
+    if let Some(config) = &spawn.electric {
+        entity.insert(config.electrified());
+    }
+    if spawn.stuck {
+        entity.insert(Stuck);
+    }
+
+    Some(entity.id())
+}
+
+/// Spawn an electrified floor described by level data
+///
+/// The floor shocks the player while its circuit is powered and its wire
+/// is intact (see `circuit_power_system`).
+///
+/// # Returns
+/// * `Some(Entity)` - The spawned floor
+/// * `None` - If `entity_type` is not `"ElectrifiedFloor"`
+pub fn spawn_electrified_floor_entity(
+    commands: &mut Commands,
+    spawn: &EntitySpawn,
+) -> Option<Entity> {
+    if spawn.entity_type != "ElectrifiedFloor" {
+        return None;
+    }
+    let config = spawn.electric.clone().unwrap_or_default();
+
+    let (x, y) = spawn.position;
+    let size = Vec2::from(config.size.unwrap_or(DEFAULT_ELECTRIFIED_FLOOR_SIZE));
+    let entity = commands
+        .spawn((
+            config.electrified(),
+            Sprite {
+                custom_size: Some(size),
+                ..default()
+            },
+            RenderLayer::Items,
+            Transform::from_xyz(x, y, RenderLayer::Items.z()),
+            Collider {
+                min: -size / 2.0,
+                max: size / 2.0,
+            },
+        ))
+        .id();
+
+    Some(entity)
+}
+
+/// Spawn a wire the player can cut, described by level data
+///
+/// # Returns
+/// * `Some(Entity)` - The spawned wire
+/// * `None` - If `entity_type` is not `"LiveWire"` or the wire has no name
+pub fn spawn_live_wire_entity(commands: &mut Commands, spawn: &EntitySpawn) -> Option<Entity> {
+    if spawn.entity_type != "LiveWire" {
+        return None;
+    }
+    let name = spawn.electric.as_ref()?.wire.clone()?;
+
+    let (x, y) = spawn.position;
+    let entity = commands
+        .spawn((
+            LiveWire { name },
+            Interactable::new(InteractionKind::CutWire),
+            Sprite {
+                color: WIRE_COLOR,
+                custom_size: Some(LIVE_WIRE_SIZE),
+                ..default()
+            },
+            RenderLayer::Items,
+            Transform::from_xyz(x, y, RenderLayer::Items.z()),
+        ))
+        .id();
+
+    Some(entity)
+}
+
+/// Run of wall tiles merged into one rectangle, in tile coordinates
+///
+/// Columns count from the left and rows from the top, as tiles are written
+/// in level files.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TileRect {
+    /// Leftmost column
+    pub column: usize,
+    /// Top row
+    pub row: usize,
+    /// Width in tiles
+    pub width: usize,
+    /// Height in tiles
+    pub height: usize,
+}
+
+/// Merges the wall tiles of a tile grid into as few rectangles as it can
+///
+/// Greedy: scanning from the top-left, each wall tile not yet covered
+/// starts a rectangle that grows right as far as the run of walls goes,
+/// then down while every tile below that run is an uncovered wall. Every
+/// wall tile ends up in exactly one rectangle.
+pub fn merge_wall_tiles(tiles: &[Vec<u32>]) -> Vec<TileRect> {
+    let is_wall = |row: usize, column: usize| {
+        tiles.get(row).and_then(|tiles| tiles.get(column)) == Some(&WALL_TILE)
+    };
+    let mut covered: Vec<Vec<bool>> = tiles.iter().map(|row| vec![false; row.len()]).collect();
+    let mut rects = Vec::new();
+
+    for (row, row_tiles) in tiles.iter().enumerate() {
+        for column in 0..row_tiles.len() {
+            if covered[row][column] || !is_wall(row, column) {
+                continue;
+            }
+
+            let width = (column..row_tiles.len())
+                .take_while(|&c| is_wall(row, c) && !covered[row][c])
+                .count();
+            let height = (row..tiles.len())
+                .take_while(|&r| (column..column + width).all(|c| is_wall(r, c) && !covered[r][c]))
+                .count();
+
+            for covered_row in &mut covered[row..row + height] {
+                covered_row[column..column + width].fill(true);
+            }
+            rects.push(TileRect {
+                column,
+                row,
+                width,
+                height,
+            });
+        }
+    }
+
+    rects
+}
+
+/// Spawn solid colliders for the walls in a level's tile grid
+///
+/// The tile grid is stretched over the room bounds, with row 0 along the
+/// top edge. Wall tiles are merged with `merge_wall_tiles`, so the
+/// collision system tests a few large boxes instead of one per tile.
+///
+/// # Returns
+/// The spawned wall colliders, one per merged rectangle
+pub fn spawn_wall_colliders(commands: &mut Commands, level: &LevelData) -> Vec<Entity> {
+    let columns = level.tiles.iter().map(Vec::len).max().unwrap_or(0);
+    let rows = level.tiles.len();
+    if columns == 0 {
+        return Vec::new();
+    }
+
+    let min = Vec2::from(level.bounds.min);
+    let max = Vec2::from(level.bounds.max);
+    let tile = (max - min) / Vec2::new(columns as f32, rows as f32);
+
+    merge_wall_tiles(&level.tiles)
+        .into_iter()
+        .map(|rect| {
+            let size = tile * Vec2::new(rect.width as f32, rect.height as f32);
+            let top_left = Vec2::new(
+                min.x + rect.column as f32 * tile.x,
+                max.y - rect.row as f32 * tile.y,
+            );
+            let center = top_left + Vec2::new(size.x, -size.y) / 2.0;
+            commands
+                .spawn((
+                    Solid,
+                    Transform::from_xyz(center.x, center.y, RenderLayer::Tiles.z()),
+                    Collider {
+                        min: -size / 2.0,
+                        max: size / 2.0,
+                    },
+                ))
+                .id()
+        })
+        .collect()
+}
+
+/// Spawn any entity type registered in the standard `SpawnRegistry`
+///
+/// # Returns
+/// * `Some(Entity)` - The spawned trap, checkpoint, hidden passage, exit
+///   door, dialogue trigger, trigger zone, water region, crate, grapple
+///   anchor, hiding furniture, breakable obstacle, metal object,
+///   electrified floor or live wire
+/// * `None` - If `entity_type` has no spawner yet
+pub fn spawn_level_entity(commands: &mut Commands, spawn: &EntitySpawn) -> Option<Entity> {
+    SpawnRegistry::standard().spawn(commands, spawn)
+}
+
+/// Queues the entities of `room` to spawn with `spawn_level_entities_batched`
+///
+/// The spawn runs when `commands` are applied. Each spawned entity is tagged
+/// with its `LevelEntityId` (its index in `spawns`), so `RoomStateStore`
+/// can re-apply what the player changed on later visits.
+pub fn queue_level_entities(commands: &mut Commands, room: RoomId, spawns: Vec<EntitySpawn>) {
+    commands.queue(move |world: &mut World| {
+        let spawned = spawn_level_entities_batched(world, &spawns);
+        for (index, entity) in spawned.iter().enumerate() {
+            if let Some(entity) = entity {
+                world
+                    .entity_mut(*entity)
+                    .insert(LevelEntityId { room, index });
+            }
+        }
+        info!(
+            "  Spawned {} of {} entities in batches",
+            spawned.iter().flatten().count(),
+            spawns.len()
+        );
+    });
+}
+
+/// Spawn level entities, assembling large rooms across the compute task pool
+///
+/// Spawn records are split into chunks of `SPAWN_BATCH_SIZE`. Each chunk
+/// assembles its components into its own `CommandQueue` on a compute task,
+/// then the queues are applied to the world in chunk order. Components are
+/// therefore inserted in the same order as a serial spawn; only the entity
+/// IDs reserved by concurrently running chunks may differ between runs.
+///
+/// Entities are spawned with the world's `SpawnRegistry` resource if it has
+/// one, else the standard registry.
+///
+/// # Returns
+/// The spawned entity for each record, in the same order as `spawns`
+/// (`None` for entity types the registry doesn't handle)
+pub fn spawn_level_entities_batched(
+    world: &mut World,
+    spawns: &[EntitySpawn],
+) -> Vec<Option<Entity>> {
+    let registry = world
+        .get_resource::<SpawnRegistry>()
+        .unwrap_or(SpawnRegistry::standard());
+    let assemble = |world: &World, chunk: &[EntitySpawn]| {
+        let mut queue = CommandQueue::default();
+        let mut commands = Commands::new(&mut queue, world);
+        let entities: Vec<Option<Entity>> = chunk
+            .iter()
+            .map(|spawn| registry.spawn(&mut commands, spawn))
+            .collect();
+        (queue, entities)
+    };
+
+    let batches = if spawns.len() <= SPAWN_BATCH_SIZE {
+        vec![assemble(world, spawns)]
+    } else {
+        let shared: &World = world;
+        ComputeTaskPool::get_or_init(TaskPool::default).scope(|scope| {
+            for chunk in spawns.chunks(SPAWN_BATCH_SIZE) {
+                scope.spawn(async move { assemble(shared, chunk) });
+            }
+        })
+    };
+
+    let mut entities = Vec::with_capacity(spawns.len());
+    for (mut queue, chunk_entities) in batches {
+        queue.apply(world);
+        entities.extend(chunk_entities);
+    }
+    entities
+}
+
+/// Resource holding the level files registered for rooms (by mods),
+/// replacing `get_level_path`
+///
+/// `mods::ModsPlugin` fills it from the scanned `ModContent`.
+#[derive(Resource, Debug, Clone, Default, PartialEq)]
+pub struct LevelRegistry {
+    files: HashMap<usize, String>,
+}
+
+impl LevelRegistry {
+    /// Registers `path` (relative to the working directory) as the level
+    /// file of `room_id`, replacing the room's file in assets/ if it has one
+    pub fn register(&mut self, room_id: usize, path: String) {
+        self.files.insert(room_id, path);
+    }
+
+    /// Path of the level file of `room_id`, relative to the working directory
+    ///
+    /// The registered file if there is one, otherwise `get_level_path` in
+    /// assets/.
+    pub fn level_file(&self, room_id: usize) -> String {
+        self.files
+            .get(&room_id)
+            .cloned()
+            .unwrap_or_else(|| format!("assets/{}", get_level_path(room_id)))
+    }
+}
+
+/// System parameter loading room levels from the `LevelRegistry`, with
+/// the `ActiveRemix` if there is one and the side rooms of the run's
+/// `GameRng` seed
+///
+/// Lets systems call `load_room_level` without threading the registry,
+/// remix and seed through themselves.
+#[derive(SystemParam)]
+pub struct RoomLevels<'w> {
+    registry: Res<'w, LevelRegistry>,
+    remix: Option<Res<'w, ActiveRemix>>,
+    rng: Option<Res<'w, GameRng>>,
+}
+
+impl RoomLevels<'_> {
+    /// Loads the level of `room_id` (see `load_room_level`)
+    ///
+    /// # Errors
+    /// Returns error string if the room's level file is broken
+    pub fn load(&self, room_id: usize) -> Result<Option<LevelData>, String> {
+        load_room_level(
+            room_id,
+            &self.registry,
+            self.remix.as_deref().map(|remix| &remix.0),
+            self.rng.as_deref().map(GameRng::seed),
+        )
+    }
+}
+
 /// Helper function to get level path by room ID
 ///
 /// Maps room IDs to their corresponding RON file paths.
@@ -205,6 +1551,7 @@ pub fn get_level_path(room_id: usize) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::components::inventory::Item;
 
     #[test]
     fn level_data_structures_deserialize() {
@@ -220,6 +1567,10 @@ mod tests {
             tiles: vec![vec![0, 1, 0], vec![1, 0, 1]],
             entities: vec![],
             connections: vec![],
+            director: None,
+            audio: None,
+            scripts: vec![],
+            side_rooms: vec![],
         };
 
         assert_eq!(level_data.id, 0);
@@ -234,6 +1585,16 @@ mod tests {
             target_room: None,
             locked: None,
             key_type: None,
+            trap_config: None,
+            reveal: None,
+            dialogue: None,
+            trigger: None,
+            water: None,
+            breakable: None,
+            item: None,
+            electric: None,
+            stuck: false,
+            burn: None,
         };
 
         assert_eq!(entity.entity_type, "Match");
@@ -249,6 +1610,16 @@ mod tests {
             target_room: Some(1),
             locked: Some(KeyType::Brass),
             key_type: None,
+            trap_config: None,
+            reveal: None,
+            dialogue: None,
+            trigger: None,
+            water: None,
+            breakable: None,
+            item: None,
+            electric: None,
+            stuck: false,
+            burn: None,
         };
 
         assert_eq!(door.entity_type, "Door");
@@ -353,4 +1724,734 @@ mod tests {
         // System should compile and be addable
         assert!(true, "load_level_system compiles and can be added");
     }
+
+    #[test]
+    fn entity_spawn_parses_trap_config() {
+        let spawn: EntitySpawn = ron::from_str(
+            r#"(
+                entity_type: "PoisonGas",
+                position: (400.0, 300.0),
+                trap_config: Some((
+                    tolerance: Some(3.0),
+                    protection: Some(GasMask),
+                )),
+            )"#,
+        )
+        .unwrap();
+
+        let config = spawn.trap_config.unwrap();
+        assert_eq!(config.tolerance, Some(3.0));
+        assert_eq!(config.protection, Some(ToolType::GasMask));
+        assert!(config.period.is_none());
+    }
+
+    #[test]
+    fn spawn_trap_entity_spawns_new_trap_types() {
+        use bevy::ecs::world::CommandQueue;
+
+        let mut world = World::new();
+        let mut queue = CommandQueue::default();
+        let mut commands = Commands::new(&mut queue, &world);
+
+        let spawn = |entity_type: &str| EntitySpawn {
+            entity_type: entity_type.to_string(),
+            position: (100.0, 50.0),
+            target_room: None,
+            locked: None,
+            key_type: None,
+            trap_config: None,
+            reveal: None,
+            dialogue: None,
+            trigger: None,
+            water: None,
+            breakable: None,
+            item: None,
+            electric: None,
+            stuck: false,
+            burn: None,
+        };
+
+        let blade = spawn_trap_entity(&mut commands, &spawn("SwingingBlade")).unwrap();
+        let floor = spawn_trap_entity(&mut commands, &spawn("CollapsingFloor")).unwrap();
+        let gas = spawn_trap_entity(&mut commands, &spawn("PoisonGas")).unwrap();
+        let chandelier = spawn_trap_entity(&mut commands, &spawn("FallingChandelier")).unwrap();
+        assert!(spawn_trap_entity(&mut commands, &spawn("Match")).is_none());
+        queue.apply(&mut world);
+
+        assert!(world.get::<SwingingBlade>(blade).is_some());
+        assert!(world.get::<CrumblingFloor>(floor).is_some());
+        assert_eq!(
+            world.get::<PoisonGas>(gas).unwrap().protection,
+            ToolType::GasMask
+        );
+        assert_eq!(*world.get::<TrapState>(gas).unwrap(), TrapState::Armed);
+        assert_eq!(
+            world.get::<ChandelierFall>(chandelier).unwrap().drop_height,
+            DEFAULT_CHANDELIER_DROP
+        );
+    }
+
+    #[test]
+    fn spawn_checkpoint_entity_spawns_inactive_checkpoint() {
+        use bevy::ecs::world::CommandQueue;
+
+        let mut world = World::new();
+        let mut queue = CommandQueue::default();
+        let mut commands = Commands::new(&mut queue, &world);
+
+        let mut spawn = EntitySpawn {
+            entity_type: "Checkpoint".to_string(),
+            position: (300.0, 80.0),
+            target_room: None,
+            locked: None,
+            key_type: None,
+            trap_config: None,
+            reveal: None,
+            dialogue: None,
+            trigger: None,
+            water: None,
+            breakable: None,
+            item: None,
+            electric: None,
+            stuck: false,
+            burn: None,
+        };
+
+        let checkpoint = spawn_checkpoint_entity(&mut commands, &spawn).unwrap();
+        spawn.entity_type = "Spikes".to_string();
+        assert!(spawn_checkpoint_entity(&mut commands, &spawn).is_none());
+        queue.apply(&mut world);
+
+        assert!(!world.get::<Checkpoint>(checkpoint).unwrap().activated);
+        assert_eq!(
+            world.get::<Transform>(checkpoint).unwrap().translation,
+            Vec3::new(300.0, 80.0, RenderLayer::Items.z())
+        );
+    }
+
+    #[test]
+    fn hidden_passage_spawns_as_wall_until_revealed() {
+        let mut world = World::new();
+        let mut queue = CommandQueue::default();
+        let mut commands = Commands::new(&mut queue, &world);
+
+        let mut spawn = EntitySpawn {
+            entity_type: "HiddenPassage".to_string(),
+            position: (640.0, 96.0),
+            target_room: Some(7),
+            locked: None,
+            key_type: None,
+            trap_config: None,
+            reveal: Some(PassageReveal::Push { seconds: 2.0 }),
+            dialogue: None,
+            trigger: None,
+            water: None,
+            breakable: None,
+            item: None,
+            electric: None,
+            stuck: false,
+            burn: None,
+        };
+
+        let passage = spawn_hidden_passage_entity(&mut commands, &spawn).unwrap();
+        spawn.target_room = None;
+        assert!(spawn_hidden_passage_entity(&mut commands, &spawn).is_none());
+        queue.apply(&mut world);
+
+        assert_eq!(
+            *world.get::<HiddenPassage>(passage).unwrap(),
+            HiddenPassage::new(PassageReveal::Push { seconds: 2.0 })
+        );
+        assert_eq!(world.get::<TargetRoom>(passage).unwrap().0, 7);
+        assert_eq!(
+            world.get::<Sprite>(passage).unwrap().color,
+            HIDDEN_PASSAGE_WALL_COLOR
+        );
+    }
+
+    #[test]
+    fn dialogue_triggers_spawn_as_areas_or_examinable_objects() {
+        let mut world = World::new();
+        let mut queue = CommandQueue::default();
+        let mut commands = Commands::new(&mut queue, &world);
+
+        let mut spawn = EntitySpawn {
+            entity_type: "DialogueTrigger".to_string(),
+            position: (400.0, 300.0),
+            target_room: None,
+            locked: None,
+            key_type: None,
+            trap_config: None,
+            reveal: None,
+            dialogue: Some(DialogueConfig {
+                script: "portrait".to_string(),
+                on: TriggerOn::Examine,
+                size: None,
+                prompt: Some("Study the portrait".to_string()),
+            }),
+            trigger: None,
+            water: None,
+            breakable: None,
+            item: None,
+            electric: None,
+            stuck: false,
+            burn: None,
+        };
+        let portrait = spawn_dialogue_trigger_entity(&mut commands, &spawn).unwrap();
+        spawn.dialogue = Some(DialogueConfig {
+            script: "draft".to_string(),
+            on: TriggerOn::Enter,
+            size: Some((128.0, 64.0)),
+            prompt: None,
+        });
+        let area = spawn_dialogue_trigger_entity(&mut commands, &spawn).unwrap();
+        spawn.dialogue = None;
+        assert!(spawn_dialogue_trigger_entity(&mut commands, &spawn).is_none());
+        queue.apply(&mut world);
+
+        let interactable = world.get::<Interactable>(portrait).unwrap();
+        assert_eq!(interactable.kind, InteractionKind::Examine);
+        assert_eq!(interactable.prompt, "Study the portrait");
+        assert!(world.get::<Interactable>(area).is_none());
+        assert_eq!(
+            world.get::<Collider>(area).unwrap().max,
+            Vec2::new(64.0, 32.0)
+        );
+        assert_eq!(
+            world.get::<DialogueTrigger>(area).unwrap().on,
+            TriggerOn::Enter
+        );
+    }
+
+    #[test]
+    fn trigger_zones_spawn_with_their_settings() {
+        let mut world = World::new();
+        let mut queue = CommandQueue::default();
+        let mut commands = Commands::new(&mut queue, &world);
+
+        let mut spawn = EntitySpawn {
+            entity_type: "TriggerZone".to_string(),
+            position: (400.0, 300.0),
+            target_room: None,
+            locked: None,
+            key_type: None,
+            trap_config: None,
+            reveal: None,
+            dialogue: None,
+            trigger: Some(TriggerConfig {
+                name: "cellar_stairs".to_string(),
+                size: Some((128.0, 64.0)),
+                once: Some(false),
+                filter: TriggerFilter::CandleOut,
+            }),
+            water: None,
+            breakable: None,
+            item: None,
+            electric: None,
+            stuck: false,
+            burn: None,
+        };
+        let zone = spawn_level_entity(&mut commands, &spawn).unwrap();
+        spawn.trigger = None;
+        assert!(spawn_trigger_zone_entity(&mut commands, &spawn).is_none());
+        queue.apply(&mut world);
+
+        let trigger = world.get::<TriggerZone>(zone).unwrap();
+        assert_eq!(trigger.name, "cellar_stairs");
+        assert!(!trigger.once);
+        assert_eq!(trigger.filter, TriggerFilter::CandleOut);
+        assert_eq!(
+            world.get::<Collider>(zone).unwrap().max,
+            Vec2::new(64.0, 32.0)
+        );
+    }
+
+    #[test]
+    fn water_regions_spawn_with_their_settings() {
+        let mut world = World::new();
+        let mut queue = CommandQueue::default();
+        let mut commands = Commands::new(&mut queue, &world);
+
+        let mut spawn = EntitySpawn {
+            entity_type: "WaterRegion".to_string(),
+            position: (640.0, 64.0),
+            target_room: None,
+            locked: None,
+            key_type: None,
+            trap_config: None,
+            reveal: None,
+            dialogue: None,
+            trigger: None,
+            water: Some(WaterConfig {
+                size: Some((512.0, 128.0)),
+                speed_factor: Some(0.3),
+                ..default()
+            }),
+            breakable: None,
+            item: None,
+            electric: None,
+            stuck: false,
+            burn: None,
+        };
+        let flooded = spawn_level_entity(&mut commands, &spawn).unwrap();
+        spawn.water = None;
+        let puddle = spawn_level_entity(&mut commands, &spawn).unwrap();
+        queue.apply(&mut world);
+
+        let water = world.get::<WaterRegion>(flooded).unwrap();
+        assert_eq!(water.speed_factor, 0.3);
+        assert_eq!(water.buoyancy, WaterRegion::default().buoyancy);
+        assert_eq!(
+            world.get::<Collider>(flooded).unwrap().max,
+            Vec2::new(256.0, 64.0)
+        );
+        assert_eq!(
+            world.get::<Collider>(puddle).unwrap().max,
+            Vec2::from(DEFAULT_WATER_SIZE) / 2.0
+        );
+    }
+
+    #[test]
+    fn crates_spawn_solid_and_pushable() {
+        let mut world = World::new();
+        let mut queue = CommandQueue::default();
+        let mut commands = Commands::new(&mut queue, &world);
+
+        let spawn = EntitySpawn {
+            entity_type: "Crate".to_string(),
+            position: (320.0, 16.0),
+            target_room: None,
+            locked: None,
+            key_type: None,
+            trap_config: None,
+            reveal: None,
+            dialogue: None,
+            trigger: None,
+            water: None,
+            breakable: None,
+            item: None,
+            electric: None,
+            stuck: false,
+            burn: None,
+        };
+        let block = spawn_level_entity(&mut commands, &spawn).unwrap();
+        queue.apply(&mut world);
+
+        assert_eq!(world.get::<Pushable>(block), Some(&Pushable::default()));
+        assert!(world.get::<Solid>(block).is_some());
+        assert_eq!(world.get::<Collider>(block).unwrap().max, CRATE_SIZE / 2.0);
+    }
+
+    #[test]
+    fn wall_tiles_merge_into_few_rectangles() {
+        let tiles = vec![
+            vec![1, 1, 1, 1],
+            vec![1, 0, 0, 1],
+            vec![1, 0, 1, 1],
+            vec![1, 1, 1, 1],
+        ];
+        let rects = merge_wall_tiles(&tiles);
+
+        let rect = |column, row, width, height| TileRect {
+            column,
+            row,
+            width,
+            height,
+        };
+        assert_eq!(
+            rects,
+            vec![
+                rect(0, 0, 4, 1),
+                rect(0, 1, 1, 3),
+                rect(3, 1, 1, 3),
+                rect(2, 2, 1, 2),
+                rect(1, 3, 1, 1),
+            ]
+        );
+        let covered: usize = rects.iter().map(|r| r.width * r.height).sum();
+        let walls = tiles.iter().flatten().filter(|&&t| t == WALL_TILE).count();
+        assert_eq!(covered, walls, "Every wall tile is covered exactly once");
+
+        let entry_hall = load_level_data("levels/ground_floor_entry.ron").unwrap();
+        assert_eq!(merge_wall_tiles(&entry_hall.tiles).len(), 4);
+    }
+
+    #[test]
+    fn wall_colliders_span_the_room_bounds() {
+        let mut world = World::new();
+        let mut queue = CommandQueue::default();
+        let mut commands = Commands::new(&mut queue, &world);
+
+        let level: LevelData = ron::from_str(
+            r#"(
+                id: 3,
+                floor: Ground,
+                name: "Closet",
+                bounds: (min: (0.0, 0.0), max: (96.0, 64.0)),
+                tiles: [[1, 1, 1], [0, 0, 1]],
+                entities: [],
+                connections: [],
+            )"#,
+        )
+        .unwrap();
+        let walls = spawn_wall_colliders(&mut commands, &level);
+        queue.apply(&mut world);
+
+        // Top row, then the rest of the right-hand column
+        let boxes: Vec<(Vec2, Vec2)> = walls
+            .iter()
+            .map(|&wall| {
+                assert!(world.get::<Solid>(wall).is_some());
+                let center = world.get::<Transform>(wall).unwrap().translation.truncate();
+                let collider = world.get::<Collider>(wall).unwrap();
+                (center + collider.min, center + collider.max)
+            })
+            .collect();
+        assert_eq!(
+            boxes,
+            vec![
+                (Vec2::new(0.0, 32.0), Vec2::new(96.0, 64.0)),
+                (Vec2::new(64.0, 0.0), Vec2::new(96.0, 32.0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn breakables_take_their_tool_and_contents_from_level_data() {
+        let mut world = World::new();
+        let mut queue = CommandQueue::default();
+        let mut commands = Commands::new(&mut queue, &world);
+
+        let mut spawn: EntitySpawn = ron::from_str(
+            r#"(
+                entity_type: "Crate",
+                position: (96.0, 16.0),
+                breakable: Some((contains: Some(Key(Iron)))),
+            )"#,
+        )
+        .unwrap();
+        let crate_entity = spawn_level_entity(&mut commands, &spawn).unwrap();
+        spawn.entity_type = "WeakWall".to_string();
+        spawn.breakable = Some(BreakableConfig {
+            tool: Some(ToolType::Wrench),
+            contains: None,
+        });
+        let wall = spawn_level_entity(&mut commands, &spawn).unwrap();
+        spawn.entity_type = "BoardedDoor".to_string();
+        spawn.breakable = None;
+        let boards = spawn_level_entity(&mut commands, &spawn).unwrap();
+        queue.apply(&mut world);
+
+        let breakable = world.get::<Breakable>(crate_entity).unwrap();
+        assert_eq!(breakable.tool, ToolType::Crowbar);
+        assert!(matches!(breakable.contains, Some(Item::Key(KeyType::Iron))));
+        assert!(world.get::<Pushable>(crate_entity).is_some());
+        assert_eq!(world.get::<Breakable>(wall).unwrap().tool, ToolType::Wrench);
+        for obstacle in [wall, boards] {
+            assert!(world.get::<Solid>(obstacle).is_some());
+            assert_eq!(
+                world.get::<Interactable>(obstacle).unwrap().kind,
+                InteractionKind::Break
+            );
+        }
+    }
+
+    #[test]
+    fn metal_objects_are_keys_or_iron_weights() {
+        let mut world = World::new();
+        let mut queue = CommandQueue::default();
+        let mut commands = Commands::new(&mut queue, &world);
+
+        let mut spawn: EntitySpawn = ron::from_str(
+            r#"(
+                entity_type: "MetalObject",
+                position: (480.0, 8.0),
+                item: Some(Key(Brass)),
+            )"#,
+        )
+        .unwrap();
+        let key = spawn_level_entity(&mut commands, &spawn).unwrap();
+        spawn.item = None;
+        let weight = spawn_level_entity(&mut commands, &spawn).unwrap();
+        queue.apply(&mut world);
+
+        assert!(world.get::<MetalObject>(key).is_some());
+        assert!(matches!(
+            world.get::<Item>(key),
+            Some(Item::Key(KeyType::Brass))
+        ));
+        assert!(world.get::<Collectible>(key).is_some());
+        assert!(world.get::<MetalObject>(weight).is_some());
+        assert!(world.get::<Item>(weight).is_none());
+    }
+
+    #[test]
+    fn electrical_hazards_and_wires_spawn_from_level_data() {
+        let mut world = World::new();
+        let mut queue = CommandQueue::default();
+        let mut commands = Commands::new(&mut queue, &world);
+
+        let mut spawn: EntitySpawn = ron::from_str(
+            r#"(
+                entity_type: "ElectrifiedFloor",
+                position: (256.0, 4.0),
+                electric: Some((circuit: Some("cellar"), wire: Some("cellar_floor"))),
+            )"#,
+        )
+        .unwrap();
+        let floor = spawn_level_entity(&mut commands, &spawn).unwrap();
+        spawn.entity_type = "LiveWire".to_string();
+        let wire = spawn_level_entity(&mut commands, &spawn).unwrap();
+        spawn.entity_type = "ExitDoor".to_string();
+        let door = spawn_level_entity(&mut commands, &spawn).unwrap();
+        spawn.entity_type = "LiveWire".to_string();
+        spawn.electric = None;
+        assert!(spawn_level_entity(&mut commands, &spawn).is_none());
+        queue.apply(&mut world);
+
+        let electrified = world.get::<Electrified>(floor).unwrap();
+        assert_eq!(electrified.circuit.as_deref(), Some("cellar"));
+        assert_eq!(electrified.wire.as_deref(), Some("cellar_floor"));
+        assert_eq!(
+            world.get::<Collider>(floor).unwrap().max,
+            Vec2::from(DEFAULT_ELECTRIFIED_FLOOR_SIZE) / 2.0
+        );
+        assert_eq!(world.get::<LiveWire>(wire).unwrap().name, "cellar_floor");
+        assert_eq!(
+            world.get::<Interactable>(wire).unwrap().kind,
+            InteractionKind::CutWire
+        );
+        assert!(world.get::<Electrified>(door).is_some());
+    }
+
+    #[test]
+    fn exit_doors_can_be_rusted_stuck() {
+        let mut world = World::new();
+        let mut queue = CommandQueue::default();
+        let mut commands = Commands::new(&mut queue, &world);
+
+        let mut spawn: EntitySpawn = ron::from_str(
+            r#"(
+                entity_type: "ExitDoor",
+                position: (480.0, 48.0),
+                stuck: true,
+            )"#,
+        )
+        .unwrap();
+        let stuck = spawn_level_entity(&mut commands, &spawn).unwrap();
+        spawn.stuck = false;
+        let free = spawn_level_entity(&mut commands, &spawn).unwrap();
+        queue.apply(&mut world);
+
+        assert!(world.get::<Stuck>(stuck).is_some());
+        assert!(world.get::<Stuck>(free).is_none());
+    }
+
+    #[test]
+    fn wardrobes_and_beds_are_hiding_spots() {
+        let mut world = World::new();
+        let mut queue = CommandQueue::default();
+        let mut commands = Commands::new(&mut queue, &world);
+
+        let mut spawn = EntitySpawn {
+            entity_type: "Wardrobe".to_string(),
+            position: (160.0, 48.0),
+            target_room: None,
+            locked: None,
+            key_type: None,
+            trap_config: None,
+            reveal: None,
+            dialogue: None,
+            trigger: None,
+            water: None,
+            breakable: None,
+            item: None,
+            electric: None,
+            stuck: false,
+            burn: None,
+        };
+        let wardrobe = spawn_level_entity(&mut commands, &spawn).unwrap();
+        spawn.entity_type = "Bed".to_string();
+        let bed = spawn_level_entity(&mut commands, &spawn).unwrap();
+        queue.apply(&mut world);
+
+        for spot in [wardrobe, bed] {
+            assert!(world.get::<HidingSpot>(spot).is_some());
+            assert_eq!(
+                world.get::<Interactable>(spot).unwrap().kind,
+                InteractionKind::Hide
+            );
+        }
+        assert_eq!(
+            world.get::<Interactable>(bed).unwrap().prompt,
+            "Hide under the bed"
+        );
+    }
+
+    #[test]
+    fn grapple_anchors_spawn_where_placed() {
+        let mut world = World::new();
+        let mut queue = CommandQueue::default();
+        let mut commands = Commands::new(&mut queue, &world);
+
+        let spawn = EntitySpawn {
+            entity_type: "GrappleAnchor".to_string(),
+            position: (480.0, 320.0),
+            target_room: None,
+            locked: None,
+            key_type: None,
+            trap_config: None,
+            reveal: None,
+            dialogue: None,
+            trigger: None,
+            water: None,
+            breakable: None,
+            item: None,
+            electric: None,
+            stuck: false,
+            burn: None,
+        };
+        let anchor = spawn_level_entity(&mut commands, &spawn).unwrap();
+        queue.apply(&mut world);
+
+        assert!(world.get::<GrappleAnchor>(anchor).is_some());
+        assert_eq!(
+            world
+                .get::<Transform>(anchor)
+                .unwrap()
+                .translation
+                .truncate(),
+            Vec2::new(480.0, 320.0)
+        );
+    }
+
+    #[test]
+    fn exit_door_spawns_locked_with_its_key() {
+        let mut world = World::new();
+        let mut queue = CommandQueue::default();
+        let mut commands = Commands::new(&mut queue, &world);
+
+        let spawn = EntitySpawn {
+            entity_type: "ExitDoor".to_string(),
+            position: (960.0, 64.0),
+            target_room: None,
+            locked: Some(KeyType::Master),
+            key_type: None,
+            trap_config: None,
+            reveal: None,
+            dialogue: None,
+            trigger: None,
+            water: None,
+            breakable: None,
+            item: None,
+            electric: None,
+            stuck: false,
+            burn: None,
+        };
+
+        let door = spawn_level_entity(&mut commands, &spawn).unwrap();
+        queue.apply(&mut world);
+
+        assert!(world.get::<ExitDoor>(door).is_some());
+        assert!(world.get::<TargetRoom>(door).is_none());
+        assert_eq!(
+            *world.get::<DoorState>(door).unwrap(),
+            DoorState::Locked(KeyType::Master)
+        );
+        assert_eq!(
+            world.get::<Interactable>(door).unwrap().kind,
+            InteractionKind::OpenDoor
+        );
+    }
+
+    #[test]
+    fn validate_level_data_reports_every_problem() {
+        let mut level = load_level_data("levels/ground_floor_entry.ron").unwrap();
+        assert!(validate_level_data(&level).is_ok());
+
+        level.entities[0].position = (5000.0, 540.0);
+        level.connections[0].target_room = level.id;
+        level.tiles[1].pop();
+        level.director.as_mut().unwrap().spawn_points[0] = (-50.0, 540.0);
+
+        let error = validate_level_data(&level).unwrap_err();
+        assert!(error.contains("Tile row 1"), "{}", error);
+        assert!(error.contains("outside the room bounds"), "{}", error);
+        assert!(error.contains("leads back to room 0"), "{}", error);
+        assert!(error.contains("Director spawn point"), "{}", error);
+    }
+
+    #[test]
+    fn batched_spawning_preserves_spawn_order() {
+        let spawn = |entity_type: &str, x: f32| EntitySpawn {
+            entity_type: entity_type.to_string(),
+            position: (x, 0.0),
+            target_room: None,
+            locked: None,
+            key_type: None,
+            trap_config: None,
+            reveal: None,
+            dialogue: None,
+            trigger: None,
+            water: None,
+            breakable: None,
+            item: None,
+            electric: None,
+            stuck: false,
+            burn: None,
+        };
+        let mut spawns: Vec<EntitySpawn> = (0..SPAWN_BATCH_SIZE * 3 + 7)
+            .map(|i| spawn(if i % 3 == 0 { "Spikes" } else { "Checkpoint" }, i as f32))
+            .collect();
+        spawns.push(spawn("Match", 0.0));
+
+        let mut world = World::new();
+        let entities = spawn_level_entities_batched(&mut world, &spawns);
+
+        assert_eq!(entities.len(), spawns.len());
+        assert_eq!(entities.last(), Some(&None), "Match has no spawner yet");
+        for (i, entity) in entities[..entities.len() - 1].iter().enumerate() {
+            let entity = entity.expect("Traps and checkpoints should spawn");
+            assert_eq!(
+                world.get::<Transform>(entity).unwrap().translation.x,
+                i as f32
+            );
+            assert_eq!(world.get::<Trap>(entity).is_some(), i % 3 == 0);
+        }
+    }
+
+    #[test]
+    fn scheduled_systems_queue_batched_room_spawns() {
+        let spawns: Vec<EntitySpawn> = (0..SPAWN_BATCH_SIZE * 2 + 1)
+            .map(|i| EntitySpawn {
+                entity_type: "Spikes".to_string(),
+                position: (i as f32, 0.0),
+                ..default()
+            })
+            .collect();
+
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        let queued = spawns.clone();
+        app.add_systems(Update, move |mut commands: Commands| {
+            queue_level_entities(&mut commands, 5, queued.clone());
+        });
+        app.update();
+
+        let mut query = app
+            .world_mut()
+            .query_filtered::<(&LevelEntityId, &Transform), With<Trap>>();
+        let mut spawned: Vec<(usize, f32)> = query
+            .iter(app.world())
+            .map(|(id, transform)| {
+                assert_eq!(id.room, 5);
+                (id.index, transform.translation.x)
+            })
+            .collect();
+        spawned.sort_by_key(|(index, _)| *index);
+        assert_eq!(spawned.len(), spawns.len());
+        for (index, x) in spawned {
+            assert_eq!(x, index as f32);
+        }
+    }
 }
@@ -0,0 +1,456 @@
+use bevy::prelude::*;
+
+use crate::components::lighting::*;
+use crate::components::room::{RoomBounds, RoomId};
+use crate::resources::difficulty::DifficultyConfig;
+use crate::resources::game_state::GameState;
+use crate::systems::candle_aim::CandleAim;
+
+/// Size (pixels) of a visibility map cell
+pub const LIGHT_MAP_CELL_SIZE: f32 = 16.0;
+
+/// Area covered by the visibility map when the room has no `RoomBounds`
+pub const DEFAULT_LIGHT_MAP_BOUNDS: Rect = Rect {
+    min: Vec2::ZERO,
+    max: Vec2::new(1920.0, 1080.0),
+};
+
+/// Light intensity at `point` from a light at `light_pos`
+///
+/// Same smoothstep falloff as the lighting.wgsl fragment shader:
+/// 1.0 at the light, fading to 0.0 at `radius`.
+pub fn light_falloff(point: Vec2, light_pos: Vec2, radius: f32) -> f32 {
+    if radius <= 0.0 {
+        return 0.0;
+    }
+    let t = (point.distance(light_pos) / radius).clamp(0.0, 1.0);
+    1.0 - (3.0 * t * t - 2.0 * t * t * t)
+}
+
+/// Directional limit on a light, used by the aimed candle
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LightCone {
+    /// Unit vector the cone points along
+    pub direction: Vec2,
+    /// Angle (radians) between the cone's axis and its edge
+    pub half_angle: f32,
+}
+
+impl LightCone {
+    /// Brightness factor for a point at `offset` from the light
+    ///
+    /// Same soft edge as the lighting.wgsl fragment shader: 1.0 inside the
+    /// inner 80% of the cone, fading to 0.0 at `half_angle`.
+    pub fn factor(&self, offset: Vec2) -> f32 {
+        if offset == Vec2::ZERO {
+            return 1.0;
+        }
+        let angle = self.direction.angle_to(offset).abs();
+        let edge = self.half_angle * 0.8;
+        let t = ((angle - edge) / (self.half_angle - edge)).clamp(0.0, 1.0);
+        1.0 - (3.0 * t * t - 2.0 * t * t * t)
+    }
+}
+
+/// A point light contributing to the visibility map
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LightContribution {
+    /// Light position in world coordinates
+    pub position: Vec2,
+    /// Distance (pixels) at which the light fades out completely
+    pub radius: f32,
+    /// Brightness multiplier
+    pub intensity: f32,
+    /// Restricts the light to a cone (`None` shines in every direction)
+    pub cone: Option<LightCone>,
+}
+
+impl LightContribution {
+    /// Adds this light to every cell of `grid` within its radius
+    ///
+    /// Only cells inside the light's bounding box are visited, so the cost
+    /// depends on the light's size rather than the whole room.
+    pub fn apply(&self, grid: &mut LightGrid) {
+        if grid.width == 0 || grid.height == 0 {
+            return;
+        }
+
+        let min = ((self.position - self.radius - grid.origin) / grid.cell_size).floor();
+        let max = ((self.position + self.radius - grid.origin) / grid.cell_size).ceil();
+        let x_range = (min.x.max(0.0) as usize)..(max.x.max(0.0) as usize).min(grid.width);
+        let y_range = (min.y.max(0.0) as usize)..(max.y.max(0.0) as usize).min(grid.height);
+
+        for y in y_range {
+            for x in x_range.clone() {
+                let center = grid.cell_center(x, y);
+                let cone = self
+                    .cone
+                    .map_or(1.0, |cone| cone.factor(center - self.position));
+                let contribution =
+                    light_falloff(center, self.position, self.radius) * self.intensity * cone;
+                let value = &mut grid.values[y * grid.width + x];
+                *value = (*value + contribution).min(1.0);
+            }
+        }
+    }
+}
+
+/// Grid of light levels (0.0 = dark, 1.0 = fully lit) covering a room
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LightGrid {
+    /// World position of the grid's bottom-left corner
+    pub origin: Vec2,
+    /// Size (pixels) of a cell
+    pub cell_size: f32,
+    /// Number of columns
+    pub width: usize,
+    /// Number of rows
+    pub height: usize,
+    /// Row-major light levels
+    pub values: Vec<f32>,
+}
+
+impl LightGrid {
+    /// Creates a dark grid covering `bounds`
+    pub fn new(bounds: Rect, cell_size: f32) -> Self {
+        let size = bounds.size();
+        let width = (size.x / cell_size).ceil().max(0.0) as usize;
+        let height = (size.y / cell_size).ceil().max(0.0) as usize;
+        Self {
+            origin: bounds.min,
+            cell_size,
+            width,
+            height,
+            values: vec![0.0; width * height],
+        }
+    }
+
+    /// Bakes the combined contribution of static lights over `bounds`
+    pub fn bake(bounds: Rect, cell_size: f32, lights: &[LightContribution]) -> Self {
+        let mut grid = Self::new(bounds, cell_size);
+        for light in lights {
+            light.apply(&mut grid);
+        }
+        grid
+    }
+
+    /// World position of the center of cell (`x`, `y`)
+    pub fn cell_center(&self, x: usize, y: usize) -> Vec2 {
+        self.origin + (Vec2::new(x as f32, y as f32) + 0.5) * self.cell_size
+    }
+
+    /// Light level of the cell containing `position` (0.0 outside the grid)
+    pub fn sample(&self, position: Vec2) -> f32 {
+        let cell = ((position - self.origin) / self.cell_size).floor();
+        if cell.x < 0.0 || cell.y < 0.0 {
+            return 0.0;
+        }
+        let (x, y) = (cell.x as usize, cell.y as usize);
+        if x >= self.width || y >= self.height {
+            return 0.0;
+        }
+        self.values[y * self.width + x]
+    }
+}
+
+/// Resource caching the static light contribution for the current room
+///
+/// Rebuilt by `bake_static_lights_system` when the room changes or a static
+/// light is added, moved or removed.
+#[derive(Resource, Debug, Default)]
+pub struct BakedLightMap {
+    /// Room the cache was baked for (`None` until the first bake)
+    pub room: Option<RoomId>,
+    /// Baked static light levels
+    pub grid: LightGrid,
+}
+
+/// Resource holding the per-frame visibility map (baked lights plus candle)
+#[derive(Resource, Debug, Default)]
+pub struct VisibilityMap {
+    /// Combined light levels for the current frame
+    pub grid: LightGrid,
+}
+
+/// System that bakes static light sources into `BakedLightMap`
+///
+/// # Behavior
+/// Rebakes only when the current room differs from the cached one or when a
+/// `StaticLight` was added, moved, changed or removed. The grid covers the
+/// first `RoomBounds` found, or `DEFAULT_LIGHT_MAP_BOUNDS`.
+///
+/// # System Dependencies
+/// - **Components**: Reads `StaticLight`, `Transform`, `LightSource`, `VisibilityRadius`, `RoomBounds`
+/// - **Resources**: Reads `GameState`, writes `BakedLightMap`
+/// - **Downstream**: `composite_visibility_system` reads the baked map
+#[allow(clippy::type_complexity)]
+pub fn bake_static_lights_system(
+    game_state: Res<GameState>,
+    mut baked: ResMut<BakedLightMap>,
+    lights: Query<(&Transform, &LightSource, &VisibilityRadius), With<StaticLight>>,
+    changed: Query<
+        (),
+        (
+            With<StaticLight>,
+            Or<(
+                Changed<Transform>,
+                Changed<LightSource>,
+                Changed<VisibilityRadius>,
+            )>,
+        ),
+    >,
+    mut removed: RemovedComponents<StaticLight>,
+    bounds_query: Query<&RoomBounds>,
+) {
+    let removed_any = removed.read().count() > 0;
+    if baked.room == Some(game_state.current_room) && changed.is_empty() && !removed_any {
+        return;
+    }
+
+    let bounds = bounds_query
+        .iter()
+        .next()
+        .map(|bounds| Rect::from_corners(bounds.min, bounds.max))
+        .unwrap_or(DEFAULT_LIGHT_MAP_BOUNDS);
+    let contributions: Vec<LightContribution> = lights
+        .iter()
+        .map(|(transform, light, radius)| LightContribution {
+            position: transform.translation.truncate(),
+            radius: radius.0,
+            intensity: light.intensity,
+            cone: None,
+        })
+        .collect();
+
+    baked.grid = LightGrid::bake(bounds, LIGHT_MAP_CELL_SIZE, &contributions);
+    baked.room = Some(game_state.current_room);
+    debug!(
+        "Baked {} static lights for room {}",
+        contributions.len(),
+        game_state.current_room
+    );
+}
+
+/// System that composites the candles' dynamic light over the baked map
+///
+/// Copies the baked static lighting and adds the contribution of every lit
+/// candle, whoever carries it, so per-frame cost no longer grows with the
+/// number of sconces. The player's aimed candle contributes a cone instead
+/// of a circle (see `CandleAim::candle_light_shape`).
+///
+/// # System Dependencies
+/// - **Upstream**: `bake_static_lights_system` keeps `BakedLightMap` current
+/// - **Components**: Reads `Candle`, `Transform`, `CandleState`, `CandleWax`,
+///   `VisibilityRadius`, `CarriedCandle`
+/// - **Resources**: Reads `CandleAim`, `DifficultyConfig`; writes `VisibilityMap`
+#[allow(clippy::type_complexity)]
+pub fn composite_visibility_system(
+    baked: Res<BakedLightMap>,
+    mut visibility: ResMut<VisibilityMap>,
+    aim: Option<Res<CandleAim>>,
+    difficulty: Option<Res<DifficultyConfig>>,
+    candles: Query<
+        (
+            &Transform,
+            &CandleState,
+            &CandleWax,
+            &VisibilityRadius,
+            Option<&CarriedCandle>,
+        ),
+        With<Candle>,
+    >,
+) {
+    visibility.grid.clone_from(&baked.grid);
+
+    let aim = aim.map(|aim| *aim).unwrap_or_default();
+    let difficulty = difficulty.map(|config| config.clone()).unwrap_or_default();
+
+    for (transform, state, wax, radius, carried) in &candles {
+        if *state != CandleState::Lit {
+            continue;
+        }
+        let (radius, cone) = aim.candle_light_shape(radius.0, carried, &difficulty);
+        LightContribution {
+            position: transform.translation.truncate(),
+            radius,
+            // Same wax dimming as update_lighting_system
+            intensity: (wax.0 / 100.0).clamp(0.3, 1.0),
+            cone,
+        }
+        .apply(&mut visibility.grid);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sconce(position: Vec2) -> LightContribution {
+        LightContribution {
+            position,
+            radius: 64.0,
+            intensity: 1.0,
+            cone: None,
+        }
+    }
+
+    #[test]
+    fn falloff_matches_shader_curve() {
+        assert_eq!(light_falloff(Vec2::ZERO, Vec2::ZERO, 100.0), 1.0);
+        assert_eq!(light_falloff(Vec2::new(100.0, 0.0), Vec2::ZERO, 100.0), 0.0);
+        assert!((light_falloff(Vec2::new(50.0, 0.0), Vec2::ZERO, 100.0) - 0.5).abs() < 0.001);
+    }
+
+    #[test]
+    fn bake_lights_cells_near_static_lights_only() {
+        let bounds = Rect::new(0.0, 0.0, 320.0, 160.0);
+        let grid = LightGrid::bake(bounds, 16.0, &[sconce(Vec2::new(40.0, 40.0))]);
+
+        assert_eq!((grid.width, grid.height), (20, 10));
+        assert!(grid.sample(Vec2::new(40.0, 40.0)) > 0.9);
+        assert_eq!(grid.sample(Vec2::new(300.0, 150.0)), 0.0);
+        assert_eq!(grid.sample(Vec2::new(-10.0, 40.0)), 0.0);
+    }
+
+    #[test]
+    fn overlapping_lights_saturate_at_full_brightness() {
+        let bounds = Rect::new(0.0, 0.0, 128.0, 128.0);
+        let position = Vec2::new(64.0, 64.0);
+        let grid = LightGrid::bake(bounds, 16.0, &[sconce(position), sconce(position)]);
+
+        assert!(grid.values.iter().all(|value| *value <= 1.0));
+    }
+
+    #[test]
+    fn cone_only_lights_cells_it_points_at() {
+        let bounds = Rect::new(0.0, 0.0, 320.0, 320.0);
+        let light = LightContribution {
+            cone: Some(LightCone {
+                direction: Vec2::X,
+                half_angle: 0.5,
+            }),
+            ..sconce(Vec2::new(160.0, 160.0))
+        };
+        let grid = LightGrid::bake(bounds, 16.0, &[light]);
+
+        assert!(grid.sample(Vec2::new(190.0, 160.0)) > 0.5);
+        assert_eq!(grid.sample(Vec2::new(130.0, 160.0)), 0.0);
+        assert_eq!(grid.sample(Vec2::new(160.0, 190.0)), 0.0);
+    }
+
+    #[test]
+    fn bake_only_reruns_on_room_change() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.init_resource::<GameState>();
+        app.init_resource::<BakedLightMap>();
+        app.add_systems(Update, bake_static_lights_system);
+
+        app.world_mut().spawn((
+            StaticLight,
+            Transform::from_xyz(100.0, 100.0, 0.0),
+            LightSource {
+                color: Color::srgb(1.0, 0.8, 0.5),
+                intensity: 1.0,
+            },
+            VisibilityRadius(64.0),
+        ));
+        app.update();
+        assert_eq!(app.world().resource::<BakedLightMap>().room, Some(0));
+
+        // Tampering with the cache survives frames where nothing changed
+        app.world_mut().resource_mut::<BakedLightMap>().grid.values[0] = 0.42;
+        app.update();
+        assert_eq!(app.world().resource::<BakedLightMap>().grid.values[0], 0.42);
+
+        app.world_mut().resource_mut::<GameState>().current_room = 1;
+        app.update();
+        let baked = app.world().resource::<BakedLightMap>();
+        assert_eq!(baked.room, Some(1));
+        assert_eq!(baked.grid.values[0], 0.0);
+    }
+
+    #[test]
+    fn composite_adds_lit_candle_to_baked_lights() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.insert_resource(BakedLightMap {
+            room: Some(0),
+            grid: LightGrid::bake(
+                Rect::new(0.0, 0.0, 640.0, 320.0),
+                16.0,
+                &[sconce(Vec2::new(40.0, 40.0))],
+            ),
+        });
+        app.init_resource::<VisibilityMap>();
+        app.add_systems(Update, composite_visibility_system);
+
+        let candle = app
+            .world_mut()
+            .spawn((
+                Candle,
+                CandleState::Lit,
+                CandleWax(100.0),
+                VisibilityRadius(64.0),
+                Transform::from_xyz(500.0, 200.0, 0.0),
+            ))
+            .id();
+        app.update();
+
+        let grid = &app.world().resource::<VisibilityMap>().grid;
+        assert!(grid.sample(Vec2::new(40.0, 40.0)) > 0.9, "Sconce is baked");
+        assert!(grid.sample(Vec2::new(500.0, 200.0)) > 0.9, "Candle is lit");
+
+        app.world_mut()
+            .entity_mut(candle)
+            .insert(CandleState::Extinguished);
+        app.update();
+
+        let grid = &app.world().resource::<VisibilityMap>().grid;
+        assert_eq!(grid.sample(Vec2::new(500.0, 200.0)), 0.0);
+    }
+
+    #[test]
+    fn composite_adds_every_lit_carrier() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.insert_resource(BakedLightMap {
+            room: Some(0),
+            grid: LightGrid::new(Rect::new(0.0, 0.0, 640.0, 320.0), 16.0),
+        });
+        app.init_resource::<VisibilityMap>();
+        app.insert_resource(CandleAim {
+            mode: crate::systems::candle_aim::CandleAimMode::MouseAim,
+            direction: Vec2::X,
+        });
+        app.add_systems(Update, composite_visibility_system);
+
+        let survivor = app.world_mut().spawn_empty().id();
+        for (carried, x) in [
+            (CarriedCandle::default(), 100.0),
+            (CarriedCandle::held_by(survivor), 500.0),
+        ] {
+            app.world_mut().spawn((
+                Candle,
+                carried,
+                CandleState::Lit,
+                CandleWax(100.0),
+                VisibilityRadius(64.0),
+                Transform::from_xyz(x, 160.0, 0.0),
+            ));
+        }
+        app.update();
+
+        let grid = &app.world().resource::<VisibilityMap>().grid;
+        assert!(grid.sample(Vec2::new(130.0, 160.0)) > 0.5, "Aimed ahead");
+        assert_eq!(
+            grid.sample(Vec2::new(60.0, 160.0)),
+            0.0,
+            "Player's candle is aimed away"
+        );
+        assert!(
+            grid.sample(Vec2::new(480.0, 160.0)) > 0.5,
+            "Survivor's candle shines in every direction"
+        );
+    }
+}
@@ -4,6 +4,14 @@ use bevy::render::render_resource::{AsBindGroup, ShaderRef};
 use bevy::sprite::{Material2d, Material2dPlugin};
 
 use crate::components::lighting::*;
+use crate::components::render_layer::RenderLayer;
+use crate::resources::difficulty::DifficultyConfig;
+use crate::systems::accessibility::AccessibilitySettings;
+use crate::systems::candle_aim::{CandleAim, candle_aim_system, toggle_candle_aim_system};
+use crate::systems::light_bake::{
+    BakedLightMap, VisibilityMap, bake_static_lights_system, composite_visibility_system,
+};
+use crate::systems::victory::NewGamePlus;
 
 /// Custom material for dynamic 2D lighting effects
 ///
@@ -14,6 +22,9 @@ use crate::components::lighting::*;
 /// - `light_position`: World position of the light source (e.g., candle, player)
 /// - `light_radius`: Visibility radius in pixels
 /// - `light_color`: RGBA color with intensity in alpha channel
+/// - `light_direction`: Unit vector the light cone points along
+/// - `cone_half_angle`: Cone half-angle in radians (PI or more = omnidirectional)
+/// - `darkness_floor`: Lowest light level anywhere (raised in high-contrast mode)
 ///
 /// From tasks.md T035: Lighting material system with Material2d
 #[derive(Asset, TypePath, AsBindGroup, Debug, Clone)]
@@ -29,6 +40,18 @@ pub struct LightingMaterial {
     /// Color and intensity of light (RGB + alpha for brightness)
     #[uniform(2)]
     pub light_color: LinearRgba,
+
+    /// Direction of the light cone (ignored when omnidirectional)
+    #[uniform(3)]
+    pub light_direction: Vec2,
+
+    /// Half-angle of the light cone in radians
+    #[uniform(4)]
+    pub cone_half_angle: f32,
+
+    /// Lowest light level (0.0-1.0), so the dark is never fully black
+    #[uniform(5)]
+    pub darkness_floor: f32,
 }
 
 impl Material2d for LightingMaterial {
@@ -44,6 +67,9 @@ impl Default for LightingMaterial {
             light_position: Vec2::ZERO,
             light_radius: 100.0,
             light_color: LinearRgba::new(1.0, 0.9, 0.7, 1.0), // Warm candlelight color
+            light_direction: Vec2::X,
+            cone_half_angle: std::f32::consts::PI, // Omnidirectional
+            darkness_floor: 0.0,
         }
     }
 }
@@ -51,13 +77,29 @@ impl Default for LightingMaterial {
 /// Plugin that registers the lighting material system
 ///
 /// Adds Material2dPlugin for LightingMaterial and sets up the lighting
-/// update system that syncs candle states with lighting materials.
+/// update system that syncs candle states with lighting materials, plus the
+/// visibility map that caches static sconce light per room, and the
+/// mouse-aimed candle cone.
 pub struct LightingPlugin;
 
 impl Plugin for LightingPlugin {
     fn build(&self, app: &mut App) {
         app.add_plugins(Material2dPlugin::<LightingMaterial>::default())
-            .add_systems(Update, update_lighting_system);
+            .init_resource::<BakedLightMap>()
+            .init_resource::<VisibilityMap>()
+            .init_resource::<CandleAim>()
+            .init_resource::<DifficultyConfig>()
+            .add_systems(
+                Update,
+                (
+                    (toggle_candle_aim_system, candle_aim_system),
+                    (
+                        update_lighting_system,
+                        (bake_static_lights_system, composite_visibility_system).chain(),
+                    ),
+                )
+                    .chain(),
+            );
     }
 }
 
@@ -67,40 +109,88 @@ impl Plugin for LightingPlugin {
 /// Updates in real-time as candles burn, move, or change state.
 ///
 /// # System Dependencies
-/// - **Components**: Reads Transform, CandleState, CandleWax, VisibilityRadius
-/// - **Resources**: Reads GameState to determine active lights
+/// - **Components**: Reads Transform, CandleState, CandleWax, VisibilityRadius, CarriedCandle
+/// - **Resources**: Reads CandleAim and DifficultyConfig for the light shape,
+///   AccessibilitySettings for the darkness floor, NewGamePlus for the
+///   player's flame cosmetic
 /// - **Materials**: Writes LightingMaterial uniforms
 ///
 /// # Behavior
 /// 1. Query all entities with lighting materials
-/// 2. For each light source (candle), read current state
-/// 3. Update material uniforms (position, radius, color)
+/// 2. Pick the candle that drives the overlay (see `overlay_light_priority`)
+/// 3. Update material uniforms (position, radius, color, cone)
 /// 4. Adjust brightness based on wax level and state
+/// 5. Raise the darkness floor in high-contrast mode
+///
+/// Other carried candles still light the world through the visibility map
+/// (see `composite_visibility_system`).
 ///
 /// From tasks.md T035: Dynamic visibility radius updates
+#[allow(clippy::type_complexity)]
 pub fn update_lighting_system(
     mut materials: ResMut<Assets<LightingMaterial>>,
-    candles: Query<(&Transform, &CandleState, &CandleWax, &VisibilityRadius)>,
+    aim: Option<Res<CandleAim>>,
+    difficulty: Option<Res<DifficultyConfig>>,
+    accessibility: Option<Res<AccessibilitySettings>>,
+    new_game_plus: Option<Res<NewGamePlus>>,
+    candles: Query<(
+        &Transform,
+        &CandleState,
+        &CandleWax,
+        &VisibilityRadius,
+        Option<&CarriedCandle>,
+    )>,
     lights: Query<&MeshMaterial2d<LightingMaterial>>,
 ) {
+    let aim = aim.map(|aim| *aim).unwrap_or_default();
+    let difficulty = difficulty.map(|config| config.clone()).unwrap_or_default();
+    let darkness_floor = accessibility.map_or(0.0, |settings| settings.darkness_floor());
+    let flame = new_game_plus.and_then(|new_game_plus| new_game_plus.flame());
+
+    // The overlay shows a single light, so prefer a lit candle in the player's hand
+    let mut candle = None;
+    for entry in candles.iter() {
+        let priority = overlay_light_priority(entry.1, entry.4);
+        if candle.is_none_or(|(best, _)| priority > best) {
+            candle = Some((priority, entry));
+        }
+    }
+
     // Update each lighting material based on candle state
     for material_handle in lights.iter() {
         if let Some(material) = materials.get_mut(material_handle) {
-            // Find corresponding candle (in a full implementation, would use marker component)
-            // For now, update all lights based on first candle (placeholder)
-            if let Some((transform, state, wax, radius)) = candles.iter().next() {
+            if material.darkness_floor != darkness_floor {
+                material.darkness_floor = darkness_floor;
+            }
+
+            if let Some((_, (transform, state, wax, radius, carried))) = candle {
                 // Update light position from candle transform
                 material.light_position = transform.translation.truncate();
 
-                // Update light radius from candle visibility
-                material.light_radius = radius.0;
+                // Update light radius and cone from candle visibility and aim
+                let (light_radius, cone) = aim.candle_light_shape(radius.0, carried, &difficulty);
+                material.light_radius = light_radius;
+                match cone {
+                    Some(cone) => {
+                        material.light_direction = cone.direction;
+                        material.cone_half_angle = cone.half_angle;
+                    }
+                    None => material.cone_half_angle = std::f32::consts::PI,
+                }
 
                 // Update light color and intensity based on candle state
                 material.light_color = match *state {
                     CandleState::Lit => {
-                        // Bright warm light when lit, dimming as wax depletes
+                        // Bright warm light when lit (or the player's
+                        // unlocked flame), dimming as wax depletes
                         let intensity = (wax.0 / 100.0).clamp(0.3, 1.0);
-                        LinearRgba::new(1.0, 0.9, 0.7, intensity)
+                        let color = match flame {
+                            Some(flame) if carried.is_some_and(CarriedCandle::is_players) => {
+                                flame.flame_color()
+                            }
+                            _ => LinearRgba::new(1.0, 0.9, 0.7, 1.0),
+                        };
+                        color.with_alpha(intensity)
                     }
                     CandleState::Unlit => {
                         // Very dim ambient light when unlit
@@ -116,6 +206,20 @@ pub fn update_lighting_system(
     }
 }
 
+/// Ranks candles for the single-light overlay
+///
+/// Lit candles beat unlit ones, and the player's candle beats one carried
+/// by an NPC or lure. Ties go to the first candle found.
+pub fn overlay_light_priority(
+    state: &CandleState,
+    carried: Option<&CarriedCandle>,
+) -> (bool, bool) {
+    (
+        *state == CandleState::Lit,
+        carried.is_none_or(CarriedCandle::is_players),
+    )
+}
+
 /// Helper function to spawn a lighting overlay mesh
 ///
 /// Creates a full-screen quad with the lighting material applied.
@@ -144,7 +248,8 @@ pub fn spawn_lighting_overlay(
         .spawn((
             Mesh2d(mesh),
             MeshMaterial2d(material),
-            Transform::from_xyz(0.0, 0.0, 100.0), // High Z to render above game elements
+            RenderLayer::CandleGlow,
+            Transform::from_xyz(0.0, 0.0, RenderLayer::CandleGlow.z()),
         ))
         .id()
 }
@@ -152,6 +257,7 @@ pub fn spawn_lighting_overlay(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::systems::victory::Cosmetic;
 
     #[test]
     fn lighting_material_implements_material2d() {
@@ -233,6 +339,43 @@ mod tests {
         assert!(material.light_color.alpha > 0.5);
     }
 
+    #[test]
+    fn players_candle_burns_with_the_unlocked_flame() {
+        let mut app = App::new();
+        app.add_plugins((
+            MinimalPlugins,
+            bevy::asset::AssetPlugin::default(),
+            Material2dPlugin::<LightingMaterial>::default(),
+        ));
+        app.insert_resource(NewGamePlus {
+            cycle: 2,
+            cosmetics: vec![Cosmetic::GoldenFlame, Cosmetic::EmberFlame],
+        });
+        app.add_systems(Update, update_lighting_system);
+
+        app.world_mut().spawn((
+            Transform::default(),
+            CandleState::Lit,
+            CandleWax(80.0),
+            VisibilityRadius(120.0),
+            CarriedCandle::default(),
+        ));
+        let material_handle = app
+            .world_mut()
+            .resource_mut::<Assets<LightingMaterial>>()
+            .add(LightingMaterial::default());
+        app.world_mut()
+            .spawn(MeshMaterial2d(material_handle.clone()));
+
+        app.update();
+
+        let materials = app.world().resource::<Assets<LightingMaterial>>();
+        assert_eq!(
+            materials.get(&material_handle).unwrap().light_color,
+            Cosmetic::GoldenFlame.flame_color().with_alpha(0.8)
+        );
+    }
+
     #[test]
     fn lighting_updates_for_unlit_candle() {
         let mut app = App::new();
@@ -310,11 +453,112 @@ mod tests {
             light_position: Vec2::new(100.0, 200.0),
             light_radius: 150.0,
             light_color: LinearRgba::new(1.0, 1.0, 1.0, 1.0),
+            light_direction: Vec2::Y,
+            cone_half_angle: 0.5,
+            darkness_floor: 0.25,
         };
 
         assert_eq!(material.light_position, Vec2::new(100.0, 200.0));
         assert_eq!(material.light_radius, 150.0);
         assert_eq!(material.light_color.alpha, 1.0);
+        assert_eq!(material.light_direction, Vec2::Y);
+        assert_eq!(material.cone_half_angle, 0.5);
+        assert_eq!(material.darkness_floor, 0.25);
+    }
+
+    #[test]
+    fn aimed_candle_narrows_and_extends_material_light() {
+        let mut app = App::new();
+        app.add_plugins((
+            MinimalPlugins,
+            bevy::asset::AssetPlugin::default(),
+            Material2dPlugin::<LightingMaterial>::default(),
+        ));
+        app.insert_resource(CandleAim {
+            mode: crate::systems::candle_aim::CandleAimMode::MouseAim,
+            direction: Vec2::NEG_X,
+        });
+        app.init_resource::<DifficultyConfig>();
+        app.add_systems(Update, update_lighting_system);
+
+        app.world_mut().spawn((
+            Transform::from_xyz(0.0, 0.0, 0.0),
+            CandleState::Lit,
+            CandleWax(100.0),
+            VisibilityRadius(100.0),
+        ));
+        let material_handle = app
+            .world_mut()
+            .resource_mut::<Assets<LightingMaterial>>()
+            .add(LightingMaterial::default());
+        app.world_mut()
+            .spawn(MeshMaterial2d(material_handle.clone()));
+
+        app.update();
+
+        let config = DifficultyConfig::default();
+        let materials = app.world().resource::<Assets<LightingMaterial>>();
+        let material = materials.get(&material_handle).unwrap();
+        assert_eq!(
+            material.light_radius,
+            100.0 * config.candle_cone_range_multiplier
+        );
+        assert_eq!(material.light_direction, Vec2::NEG_X);
+        assert_eq!(material.cone_half_angle, config.candle_cone_half_angle);
+    }
+
+    #[test]
+    fn overlay_follows_players_lit_candle_over_npc_candle() {
+        let mut app = App::new();
+        app.add_plugins((
+            MinimalPlugins,
+            bevy::asset::AssetPlugin::default(),
+            Material2dPlugin::<LightingMaterial>::default(),
+        ));
+        app.add_systems(Update, update_lighting_system);
+
+        let survivor = app.world_mut().spawn_empty().id();
+        app.world_mut().spawn((
+            CarriedCandle::held_by(survivor),
+            Transform::from_xyz(-300.0, 0.0, 0.0),
+            CandleState::Lit,
+            CandleWax(100.0),
+            VisibilityRadius(100.0),
+        ));
+        let player_candle = app
+            .world_mut()
+            .spawn((
+                CarriedCandle::default(),
+                Transform::from_xyz(40.0, 0.0, 0.0),
+                CandleState::Unlit,
+                CandleWax(100.0),
+                VisibilityRadius(100.0),
+            ))
+            .id();
+        let material_handle = app
+            .world_mut()
+            .resource_mut::<Assets<LightingMaterial>>()
+            .add(LightingMaterial::default());
+        app.world_mut()
+            .spawn(MeshMaterial2d(material_handle.clone()));
+
+        // While the player's candle is unlit the survivor's light is shown
+        app.update();
+        let materials = app.world().resource::<Assets<LightingMaterial>>();
+        assert_eq!(
+            materials.get(&material_handle).unwrap().light_position,
+            Vec2::new(-300.0, 0.0)
+        );
+
+        app.world_mut()
+            .entity_mut(player_candle)
+            .insert(CandleState::Lit);
+        app.update();
+        let materials = app.world().resource::<Assets<LightingMaterial>>();
+        assert_eq!(
+            materials.get(&material_handle).unwrap().light_position,
+            Vec2::new(40.0, 0.0)
+        );
     }
 
     #[test]
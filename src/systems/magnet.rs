@@ -0,0 +1,293 @@
+use bevy::prelude::*;
+use std::collections::HashSet;
+
+use crate::audio::sound_bank::{PlaySoundEvent, SoundCue};
+use crate::components::inventory::{Item, ToolType};
+use crate::components::player::Player;
+use crate::components::render_layer::RenderLayer;
+use crate::resources::game_clock::GameClock;
+use crate::resources::game_state::{GameMode, GameState};
+use crate::systems::inventory::ItemUsedEvent;
+use crate::systems::tween::{Ease, Tween};
+
+/// Furthest (pixels) a metal object can be from the player and still be pulled
+pub const MAGNET_RANGE: f32 = 256.0;
+
+/// Average speed (pixels per second) of a pulled object
+pub const MAGNET_PULL_SPEED: f32 = 240.0;
+
+/// How far (pixels) from the player a pulled object stops
+///
+/// Close enough for a pulled key or fuse to overlap the player and be
+/// picked up.
+pub const MAGNET_STOP_DISTANCE: f32 = 16.0;
+
+/// Size (pixels) of a metal object's sprite and collider
+pub const METAL_OBJECT_SIZE: Vec2 = Vec2::new(16.0, 16.0);
+
+/// Color of a metal object's sprite
+pub const METAL_OBJECT_COLOR: Color = Color::srgb(0.6, 0.62, 0.68);
+
+const BEAM_COLOR: Color = Color::srgba(0.55, 0.75, 1.0, 0.6);
+const BEAM_THICKNESS: f32 = 3.0;
+
+/// Z offset of the beam in front of the items layer
+const BEAM_Z_OFFSET: f32 = 0.5;
+
+/// Plugin for the magnet tool: pulling metal objects in and drawing the beam
+pub struct MagnetPlugin;
+
+impl Plugin for MagnetPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<ItemUsedEvent>()
+            .add_event::<PlaySoundEvent>()
+            .add_systems(
+                Update,
+                (magnet_use_system, magnet_pull_system, magnet_beam_system).chain(),
+            );
+    }
+}
+
+/// Component for an object the magnet can pull (keys behind grates, fuses,
+/// iron weights)
+///
+/// Metal keys and fuses are also `Collectible` items, picked up once pulled
+/// to the player. Metal objects hold down pressure plates.
+#[derive(Component, Debug, Default)]
+pub struct MetalObject;
+
+/// Component on a metal object being pulled toward the player
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct MagnetPull {
+    /// Player holding the magnet
+    pub player: Entity,
+    /// Where the object started
+    pub from: Vec2,
+    /// Where the object stops, just short of the player
+    pub to: Vec2,
+    /// Progress (0.0-1.0) from `from` to `to`
+    pub tween: Tween,
+}
+
+/// Component for the sprite drawing the beam to a pulled object
+#[derive(Component, Debug)]
+pub struct MagnetBeam {
+    /// Object the beam reaches
+    pub object: Entity,
+}
+
+/// System that starts pulling metal objects when the magnet is used
+///
+/// # System Dependencies
+/// - **Upstream**: `hotbar_use_system` emits `ItemUsedEvent` for the magnet
+/// - **Components**: Reads the player's `Transform` and `MetalObject`
+///   positions; inserts `MagnetPull`
+/// - **Resources**: Reads `GameState`
+/// - **Downstream**: `magnet_pull_system` moves the objects; emits
+///   `PlaySoundEvent`
+///
+/// # Behavior
+/// Every metal object within `MAGNET_RANGE` that isn't already moving is
+/// pulled toward the player, speeding up as it comes
+/// (`SoundCue::MagnetHum`). With nothing in range the magnet does nothing
+/// (`SoundCue::UiError`).
+#[allow(clippy::type_complexity)]
+pub fn magnet_use_system(
+    game_state: Res<GameState>,
+    mut commands: Commands,
+    mut events: EventReader<ItemUsedEvent>,
+    player_query: Query<&Transform, With<Player>>,
+    metal_query: Query<(Entity, &Transform), (With<MetalObject>, Without<MagnetPull>)>,
+    mut sound_events: EventWriter<PlaySoundEvent>,
+) {
+    if game_state.game_mode != GameMode::Playing {
+        events.clear();
+        return;
+    }
+
+    for event in events.read() {
+        if !matches!(event.item, Item::Tool(ToolType::Magnet)) {
+            continue;
+        }
+        let Ok(player_transform) = player_query.get(event.player) else {
+            continue;
+        };
+
+        let player_pos = player_transform.translation.truncate();
+        let mut pulled = false;
+        for (object, transform) in &metal_query {
+            let from = transform.translation.truncate();
+            let distance = from.distance(player_pos);
+            if distance > MAGNET_RANGE || distance <= MAGNET_STOP_DISTANCE {
+                continue;
+            }
+
+            let to = player_pos + (from - player_pos) / distance * MAGNET_STOP_DISTANCE;
+            let duration = from.distance(to) / MAGNET_PULL_SPEED;
+            commands.entity(object).insert(MagnetPull {
+                player: event.player,
+                from,
+                to,
+                tween: Tween::new(0.0, 1.0, duration, Ease::QuadIn),
+            });
+            pulled = true;
+        }
+
+        let cue = if pulled {
+            SoundCue::MagnetHum
+        } else {
+            SoundCue::UiError
+        };
+        sound_events.write(PlaySoundEvent { cue });
+    }
+}
+
+/// System that tweens pulled metal objects toward the player
+///
+/// # System Dependencies
+/// - **Upstream**: `magnet_use_system` inserts `MagnetPull`
+/// - **Components**: Writes `MagnetPull` and `Transform`; removes
+///   `MagnetPull` once the object arrives
+/// - **Resources**: Reads `GameClock`
+pub fn magnet_pull_system(
+    clock: Res<GameClock>,
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut MagnetPull, &mut Transform)>,
+) {
+    for (entity, mut pull, mut transform) in &mut query {
+        let progress = pull.tween.tick(clock.delta).value();
+        let position = pull.from.lerp(pull.to, progress);
+        transform.translation = position.extend(transform.translation.z);
+
+        if pull.tween.finished() {
+            commands.entity(entity).remove::<MagnetPull>();
+        }
+    }
+}
+
+/// System that draws a beam from the player to each pulled object
+///
+/// # System Dependencies
+/// - **Components**: Reads `MagnetPull` and `Transform` of pulled objects
+///   and the player; spawns, moves and despawns `MagnetBeam` sprites
+/// - **Upstream**: `magnet_pull_system`
+///
+/// # Behavior
+/// Like the grappling rope, the beam is a thin sprite stretched between
+/// the two ends. It has no `RenderLayer`, so `render_layer_system` leaves
+/// its offset in front of the items layer alone.
+pub fn magnet_beam_system(
+    mut commands: Commands,
+    pull_query: Query<(Entity, &MagnetPull, &Transform), Without<MagnetBeam>>,
+    player_query: Query<&Transform, (With<Player>, Without<MagnetBeam>)>,
+    mut beam_query: Query<(Entity, &MagnetBeam, &mut Transform, &mut Sprite)>,
+) {
+    let beam_ends = |object: Entity| {
+        let (_, pull, transform) = pull_query.get(object).ok()?;
+        let player = player_query.get(pull.player).ok()?;
+        Some((
+            player.translation.truncate(),
+            transform.translation.truncate(),
+        ))
+    };
+
+    let mut beamed = HashSet::new();
+    for (beam, target, mut transform, mut sprite) in &mut beam_query {
+        let Some((from, to)) = beam_ends(target.object) else {
+            commands.entity(beam).despawn();
+            continue;
+        };
+        beamed.insert(target.object);
+        *transform = beam_transform(from, to);
+        sprite.custom_size = Some(Vec2::new(from.distance(to), BEAM_THICKNESS));
+    }
+
+    for (object, _, _) in &pull_query {
+        if beamed.contains(&object) {
+            continue;
+        }
+        let Some((from, to)) = beam_ends(object) else {
+            continue;
+        };
+        commands.spawn((
+            MagnetBeam { object },
+            Sprite {
+                color: BEAM_COLOR,
+                custom_size: Some(Vec2::new(from.distance(to), BEAM_THICKNESS)),
+                ..default()
+            },
+            beam_transform(from, to),
+        ));
+    }
+}
+
+/// Beam sprite transform between `from` and `to`
+fn beam_transform(from: Vec2, to: Vec2) -> Transform {
+    let offset = to - from;
+    Transform::from_translation(((from + to) / 2.0).extend(RenderLayer::Items.z() + BEAM_Z_OFFSET))
+        .with_rotation(Quat::from_rotation_z(offset.y.atan2(offset.x)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn setup_app() -> App {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, MagnetPlugin));
+        app.insert_resource(GameState {
+            game_mode: GameMode::Playing,
+            ..default()
+        });
+        app.init_resource::<GameClock>();
+        app
+    }
+
+    fn beams(app: &mut App) -> usize {
+        let mut query = app.world_mut().query::<&MagnetBeam>();
+        query.iter(app.world()).count()
+    }
+
+    #[test]
+    fn magnet_pulls_metal_in_range_up_to_the_player() {
+        let mut app = setup_app();
+        let player = app
+            .world_mut()
+            .spawn((Player, Transform::from_xyz(0.0, 0.0, 30.0)))
+            .id();
+        let key = app
+            .world_mut()
+            .spawn((MetalObject, Transform::from_xyz(-200.0, 0.0, 20.0)))
+            .id();
+        let far = app
+            .world_mut()
+            .spawn((MetalObject, Transform::from_xyz(400.0, 0.0, 20.0)))
+            .id();
+
+        app.world_mut().send_event(ItemUsedEvent {
+            item: Item::Tool(ToolType::Magnet),
+            player,
+        });
+        app.update();
+        assert!(app.world().get::<MagnetPull>(key).is_some());
+        assert!(app.world().get::<MagnetPull>(far).is_none(), "Out of range");
+        app.update();
+        assert_eq!(beams(&mut app), 1);
+
+        // Halfway through the pull the object has only just got going
+        let duration = app.world().get::<MagnetPull>(key).unwrap().tween.duration;
+        app.world_mut().resource_mut::<GameClock>().delta = duration / 2.0;
+        app.update();
+        let x = app.world().get::<Transform>(key).unwrap().translation.x;
+        assert!(x < -100.0, "Pull eases in, got {x}");
+
+        app.update();
+        assert_eq!(
+            app.world().get::<Transform>(key).unwrap().translation,
+            Vec3::new(-MAGNET_STOP_DISTANCE, 0.0, 20.0)
+        );
+        assert!(app.world().get::<MagnetPull>(key).is_none());
+        app.update();
+        assert_eq!(beams(&mut app), 0);
+    }
+}
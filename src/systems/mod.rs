@@ -3,41 +3,190 @@
 //! Systems contain the core game logic that operates on components and resources.
 //! Each module focuses on a specific aspect of gameplay.
 
+/// Accessibility options: colorblind-safe symbols and high-contrast outlines
+pub mod accessibility;
+
+/// Achievements unlocked from game events, kept apart from save slots
+pub mod achievements;
+
+/// Idle detection and attract mode ghost playback
+pub mod attract_mode;
+
+/// Mouse-aimed candle light cone and aim mode toggling
+pub mod candle_aim;
+
+/// Boarded doors, crates and weak walls broken open with tools
+pub mod breakable;
+
 /// Candle wax depletion and state management system
 pub mod candle_burn;
 
+/// Checkpoint activation, spawn point updates and auto-save triggers
+pub mod checkpoint;
+
+/// Mirroring save slots to a remote endpoint, newest change winning
+pub mod cloud_sync;
+
 /// Collision detection and physics system
 pub mod collision;
 
+/// Daily Escape: date-seeded runs with fixed rules and shareable results
+pub mod daily;
+
+/// Death statistics and the per-room death heatmap overlay
+pub mod death_stats;
+
+/// Gizmo outlines of colliders, trigger ranges, light radii and room bounds
+pub mod debug_draw;
+
+/// Scripted dialogue and narration with level-placed triggers
+pub mod dialogue;
+
+/// Stress-paced hazard spawning within per-room budgets
+pub mod director;
+
+/// Electrified floors and doors, circuit power and cutting wires
+pub mod electrical;
+
 /// Fixed timestep scheduling for deterministic physics
 pub mod fixed_timestep;
 
+/// Permadeath and burnt-out-candle failure modes and retry handling
+pub mod game_over;
+
+/// Frame stages (input, physics, gameplay, presentation) game plugins run in
+pub mod game_set;
+
+/// Ghost run recording and playback
+pub mod ghost;
+
+/// Grappling rope: catching anchors and drawing the rope
+pub mod grapple;
+
+/// Hit points, invincibility frames and save-point healing
+pub mod health;
+
+/// Furniture the player hides in and the vignette drawn meanwhile
+pub mod hiding;
+
+/// Hotbar slot assignment and number-key quick use
+pub mod hotbar;
+
+/// Nearest-interactable prompts and typed interaction events
+pub mod interaction;
+
 /// Inventory management and item collection systems
 pub mod inventory;
 
+/// Checks of level files on their own and as a house, for the level_check tool
+pub mod level_check;
+
+/// Normalized JSON export of level data for external tools
+pub mod level_dump;
+
 /// Level loading from RON files
 pub mod level_loader;
 
+/// Baked static light cache and per-frame visibility map
+pub mod light_bake;
+
 /// Dynamic lighting and visibility systems
 pub mod lighting;
 
+/// Magnet tool pulling metal objects toward the player
+pub mod magnet;
+
+/// Content packs in mods/: extra levels, sprites, audio and string tables
+pub mod mods;
+
+/// Oil can freeing rusted doors and levers
+pub mod oil_can;
+
+/// Plugin bundling the player's movement, inventory, health and respawning
+pub mod player;
+
 /// Player movement, jumping, and physics
 pub mod player_movement;
 
+/// Optional side rooms generated off authored rooms from level templates
+pub mod procgen;
+
+/// Floating world-space labels for the active interactable's prompt
+pub mod prompt_label;
+
 /// Puzzle interaction and solving systems
 pub mod puzzle;
 
+/// Puzzle inputs applied as commands, with per-puzzle undo history
+pub mod puzzle_undo;
+
+/// Pushable blocks and the pressure plates they weigh down
+pub mod pushable;
+
+/// New Game+ remixes of key and item placement and trap density
+pub mod remix;
+
+/// Render layer z-ordering and carried candle placement
+pub mod render_layer;
+
 /// Player death and respawn system
 pub mod respawn;
 
+/// Saving and re-applying per-room entity state across room visits
+pub mod room_state;
+
 /// Room transition and door interaction systems
 pub mod room_transition;
 
+/// Graphviz DOT export of the system schedules (the --dump-schedule mode)
+pub mod schedule_export;
+
 /// Save and load game state systems
 pub mod save_load;
 
+/// Save file migration, validation and repair (used by the save-tool binary)
+pub mod save_migration;
+
+/// Save file storage backends (filesystem natively, localStorage in the browser)
+pub mod save_storage;
+
+/// Sandboxed Rhai scripts level files run on room events
+pub mod scripting;
+
+/// Hidden passages revealed by puzzles, candle light or pushing
+pub mod secret_passage;
+
+/// Looping sprite sheet animations such as flickering sconces
+pub mod sprite_animation;
+
+/// 1000-entity stress-test fixture room for performance work
+pub mod stress_room;
+
+/// Opt-in anonymous gameplay telemetry logged as JSONL
+pub mod telemetry;
+
 /// Tilemap rendering and management
 pub mod tilemap;
 
 /// Trap triggering and hazard systems
 pub mod trap;
+/// Trap sprites animated between armed and sprung states
+pub mod trap_visuals;
+
+/// Level-placed trigger zones that fire named gameplay events
+pub mod trigger_zone;
+
+/// Ease curves, tweens and tween sequences for clock-driven animations
+pub mod tween;
+
+/// Escaping through the exit door, run summary and New Game+
+pub mod victory;
+
+/// Headless room screenshots compared against golden images (dev test mode)
+#[cfg(feature = "visual-regression")]
+pub mod visual_regression;
+
+/// Water regions: swimming, drowning and splashes
+pub mod water;
+/// Ceiling water drips that can sputter or extinguish the candle
+pub mod water_drip;
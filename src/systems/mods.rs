@@ -0,0 +1,391 @@
+use bevy::prelude::*;
+use serde::Deserialize;
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+use crate::audio::sound_bank::{SoundBank, SoundBankEntry, SoundCue};
+use crate::resources::asset_handles::{SpriteManifest, SpriteType};
+use crate::systems::level_loader::LevelRegistry;
+
+/// Directory scanned for content packs, one sub-directory per mod
+pub const MODS_DIR: &str = "mods";
+
+/// Manifest file every mod directory declares itself with
+pub const MOD_MANIFEST_FILE_NAME: &str = "mod.ron";
+
+/// Asset source mod sprites and audio load from (`mods://<mod dir>/...`)
+///
+/// Registered by `GamePlugin` before the asset plugin, rooted at `MODS_DIR`.
+pub const MODS_ASSET_SOURCE: &str = "mods";
+
+/// Plugin that loads the content packs in `MODS_DIR`
+///
+/// Mods are scanned while the app is built, so their levels are in the
+/// `LevelRegistry` and their sprites and sounds are merged into the sprite
+/// manifest and sound bank by the startup systems loading those. The load
+/// order and any conflicts between mods are logged and kept in `ModContent`.
+pub struct ModsPlugin;
+
+impl Plugin for ModsPlugin {
+    fn build(&self, app: &mut App) {
+        let content = ModContent::scan(Path::new(MODS_DIR));
+        if !content.mods.is_empty() {
+            let order: Vec<String> = content.mods.iter().map(ModInfo::label).collect();
+            info!("Loaded {} mods in order: {}", order.len(), order.join(", "));
+        }
+        for problem in &content.problems {
+            warn!("{}", problem);
+        }
+        content.register_levels(&mut app.world_mut().get_resource_or_init::<LevelRegistry>());
+        app.insert_resource(content);
+    }
+}
+
+/// A content pack's `mod.ron` manifest
+///
+/// All paths are relative to the mod's directory.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct ModManifest {
+    /// Name shown in the load order and conflict reports
+    pub name: String,
+    /// Free-form version string
+    #[serde(default)]
+    pub version: String,
+    /// Mods load lowest first; later mods win conflicts (ties load by
+    /// directory name)
+    #[serde(default)]
+    pub load_order: i32,
+    /// Level files replacing or adding rooms, by room ID
+    #[serde(default)]
+    pub levels: BTreeMap<usize, String>,
+    /// Images replacing the game's sprites
+    #[serde(default)]
+    pub sprites: HashMap<SpriteType, String>,
+    /// Sound bank entries replacing the game's cues (variation paths
+    /// relative to the mod's directory)
+    #[serde(default)]
+    pub sounds: HashMap<SoundCue, SoundBankEntry>,
+    /// String table files, each a RON map of keys to text
+    #[serde(default)]
+    pub strings: Vec<String>,
+}
+
+/// A loaded mod, in load order
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModInfo {
+    /// Name of the mod's directory in `MODS_DIR`
+    pub dir_name: String,
+    /// The mod's manifest
+    pub manifest: ModManifest,
+}
+
+impl ModInfo {
+    /// Name and version for the load order log
+    pub fn label(&self) -> String {
+        if self.manifest.version.is_empty() {
+            self.manifest.name.clone()
+        } else {
+            format!("{} {}", self.manifest.name, self.manifest.version)
+        }
+    }
+}
+
+/// Resource holding the content of every loaded mod, merged in load order
+#[derive(Resource, Debug, Default, Clone, PartialEq)]
+pub struct ModContent {
+    /// Loaded mods, in load order
+    pub mods: Vec<ModInfo>,
+    /// Level file paths by room ID
+    pub levels: BTreeMap<usize, PathBuf>,
+    /// Sprite asset paths (in `MODS_ASSET_SOURCE`)
+    pub sprites: HashMap<SpriteType, String>,
+    /// Sound bank entries with asset paths in `MODS_ASSET_SOURCE`
+    pub sounds: HashMap<SoundCue, SoundBankEntry>,
+    /// Merged string tables
+    pub strings: BTreeMap<String, String>,
+    /// Mods that failed to load and content several mods replace
+    pub problems: Vec<String>,
+    /// Name of the mod providing each piece of content, for reporting
+    /// conflicts
+    owners: HashMap<String, String>,
+}
+
+impl ModContent {
+    /// Loads every mod directory in `dir`
+    ///
+    /// A missing `dir` means no mods. Directories without a readable
+    /// `mod.ron`, and files a mod lists but doesn't ship or that point
+    /// outside its directory, are reported in `problems` and skipped.
+    pub fn scan(dir: &Path) -> Self {
+        let mut content = Self::default();
+        let Ok(entries) = fs::read_dir(dir) else {
+            return content;
+        };
+        let mut dirs: Vec<PathBuf> = entries
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| path.is_dir())
+            .collect();
+        dirs.sort();
+
+        let mut mods = Vec::new();
+        for mod_dir in dirs {
+            let manifest_path = mod_dir.join(MOD_MANIFEST_FILE_NAME);
+            match load_mod_manifest(&manifest_path) {
+                Ok(manifest) => mods.push((mod_dir, manifest)),
+                Err(e) => content.problems.push(e),
+            }
+        }
+        // Stable, so equal load orders keep the directory name order
+        mods.sort_by_key(|(_, manifest)| manifest.load_order);
+
+        for (mod_dir, manifest) in mods {
+            content.add_mod(&mod_dir, manifest);
+        }
+        content
+    }
+
+    /// Merges the mod `manifest` from `mod_dir` over the mods before it
+    fn add_mod(&mut self, mod_dir: &Path, manifest: ModManifest) {
+        let dir_name = mod_dir
+            .file_name()
+            .map_or_else(String::new, |name| name.to_string_lossy().into_owned());
+
+        for (&room_id, file) in &manifest.levels {
+            let Some(path) = self.mod_file(mod_dir, &manifest.name, file) else {
+                continue;
+            };
+            self.claim(&manifest.name, format!("level of room {}", room_id));
+            self.levels.insert(room_id, path);
+        }
+        for (&sprite, file) in &manifest.sprites {
+            if self.mod_file(mod_dir, &manifest.name, file).is_none() {
+                continue;
+            }
+            self.claim(&manifest.name, format!("sprite {:?}", sprite));
+            self.sprites.insert(sprite, mod_asset_path(&dir_name, file));
+        }
+        for (&cue, entry) in &manifest.sounds {
+            let mut entry = entry.clone();
+            entry
+                .variations
+                .retain(|file| self.mod_file(mod_dir, &manifest.name, file).is_some());
+            if entry.variations.is_empty() {
+                continue;
+            }
+            for file in &mut entry.variations {
+                *file = mod_asset_path(&dir_name, file);
+            }
+            self.claim(&manifest.name, format!("sound {:?}", cue));
+            self.sounds.insert(cue, entry);
+        }
+        for file in &manifest.strings {
+            let Some(path) = self.mod_file(mod_dir, &manifest.name, file) else {
+                continue;
+            };
+            match load_string_table(&path) {
+                Ok(table) => {
+                    for (key, text) in table {
+                        self.claim(&manifest.name, format!("string '{}'", key));
+                        self.strings.insert(key, text);
+                    }
+                }
+                Err(e) => self
+                    .problems
+                    .push(format!("Mod '{}': {}", manifest.name, e)),
+            }
+        }
+
+        self.mods.push(ModInfo { dir_name, manifest });
+    }
+
+    /// Records `mod_name` as providing `what`, reporting the mod it
+    /// overrides
+    fn claim(&mut self, mod_name: &str, what: String) {
+        if let Some(earlier) = self.owners.insert(what.clone(), mod_name.to_string())
+            && earlier != mod_name
+        {
+            self.problems.push(format!(
+                "Mod '{}' overrides the {} of mod '{}'",
+                mod_name, what, earlier
+            ));
+        }
+    }
+
+    /// Path of `file` in `mod_dir`, or `None` (with a problem reported) if
+    /// it points outside the mod or doesn't exist
+    fn mod_file(&mut self, mod_dir: &Path, mod_name: &str, file: &str) -> Option<PathBuf> {
+        let inside = Path::new(file)
+            .components()
+            .all(|component| matches!(component, Component::Normal(_)));
+        let path = mod_dir.join(file);
+        if !inside {
+            self.problems.push(format!(
+                "Mod '{}': '{}' points outside the mod directory",
+                mod_name, file
+            ));
+            None
+        } else if !path.is_file() {
+            self.problems.push(format!(
+                "Mod '{}': '{}' does not exist",
+                mod_name,
+                path.display()
+            ));
+            None
+        } else {
+            Some(path)
+        }
+    }
+
+    /// Adds the mods' level files to `registry`
+    pub fn register_levels(&self, registry: &mut LevelRegistry) {
+        for (&room_id, path) in &self.levels {
+            registry.register(room_id, path.to_string_lossy().into_owned());
+        }
+    }
+
+    /// Replaces the sprites of `manifest` the mods provide
+    pub fn merge_sprites(&self, manifest: &mut SpriteManifest) {
+        for (&sprite, path) in &self.sprites {
+            manifest.sprites.insert(sprite, path.clone());
+        }
+    }
+
+    /// Replaces the cues of `bank` the mods provide
+    pub fn merge_sounds(&self, bank: &mut SoundBank) {
+        for (&cue, entry) in &self.sounds {
+            bank.entries.insert(cue, entry.clone());
+        }
+    }
+
+    /// Text of the string table entry `key`, if a mod provides one
+    pub fn string(&self, key: &str) -> Option<&str> {
+        self.strings.get(key).map(String::as_str)
+    }
+}
+
+/// Load a mod manifest from a `mod.ron` file
+///
+/// # Errors
+/// Returns error string if the file cannot be read or parsed
+pub fn load_mod_manifest(path: &Path) -> Result<ModManifest, String> {
+    let content = fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read mod manifest '{}': {}", path.display(), e))?;
+
+    ron::from_str(&content)
+        .map_err(|e| format!("Failed to parse RON from '{}': {}", path.display(), e))
+}
+
+/// Load a string table from a RON map of keys to text
+///
+/// # Errors
+/// Returns error string if the file cannot be read or parsed
+pub fn load_string_table(path: &Path) -> Result<BTreeMap<String, String>, String> {
+    let content = fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read string table '{}': {}", path.display(), e))?;
+
+    ron::from_str(&content)
+        .map_err(|e| format!("Failed to parse RON from '{}': {}", path.display(), e))
+}
+
+/// Asset path of `file` in the mod directory `dir_name`
+pub fn mod_asset_path(dir_name: &str, file: &str) -> String {
+    format!("{}://{}/{}", MODS_ASSET_SOURCE, dir_name, file)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write(path: &Path, content: &str) {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    }
+
+    #[test]
+    fn mods_merge_in_load_order_and_report_conflicts() {
+        let dir = std::env::temp_dir().join(format!("rust-game-mods-{}", std::process::id()));
+        fs::remove_dir_all(&dir).ok();
+        // "b_night" loads first despite its name
+        write(
+            &dir.join("a_gloom/mod.ron"),
+            r#"(name: "Gloom", version: "1.2", load_order: 5,
+                sprites: {Player: "sprites/ghost.png"},
+                sounds: {Save: (variations: ["audio/save.ogg", "audio/missing.ogg"])},
+                strings: ["strings.ron"])"#,
+        );
+        write(&dir.join("a_gloom/sprites/ghost.png"), "");
+        write(&dir.join("a_gloom/audio/save.ogg"), "");
+        write(
+            &dir.join("a_gloom/strings.ron"),
+            r#"{"door.locked": "Sealed."}"#,
+        );
+        write(
+            &dir.join("b_night/mod.ron"),
+            r#"(name: "Night",
+                levels: {9200: "levels/attic.ron", 9201: "../escape.ron"},
+                sprites: {Player: "sprites/night.png", Candle: "sprites/candle.png"},
+                strings: ["strings.ron"])"#,
+        );
+        write(&dir.join("b_night/levels/attic.ron"), "");
+        write(&dir.join("b_night/sprites/night.png"), "");
+        write(&dir.join("b_night/sprites/candle.png"), "");
+        write(
+            &dir.join("b_night/strings.ron"),
+            r#"{"door.locked": "Locked.", "note.title": "Diary"}"#,
+        );
+        write(&dir.join("c_broken/mod.ron"), "(name: ");
+
+        let content = ModContent::scan(&dir);
+        fs::remove_dir_all(&dir).ok();
+
+        let order: Vec<String> = content.mods.iter().map(ModInfo::label).collect();
+        assert_eq!(order, vec!["Night", "Gloom 1.2"]);
+        assert_eq!(content.levels[&9200], dir.join("b_night/levels/attic.ron"));
+        assert!(!content.levels.contains_key(&9201));
+        assert_eq!(content.string("door.locked"), Some("Sealed."));
+        assert_eq!(content.string("note.title"), Some("Diary"));
+
+        let mut manifest = SpriteManifest::default();
+        content.merge_sprites(&mut manifest);
+        assert_eq!(
+            manifest.sprites[&SpriteType::Player],
+            "mods://a_gloom/sprites/ghost.png"
+        );
+        assert_eq!(
+            manifest.sprites[&SpriteType::Candle],
+            "mods://b_night/sprites/candle.png"
+        );
+        let mut bank = SoundBank::default();
+        content.merge_sounds(&mut bank);
+        assert_eq!(
+            bank.entries[&SoundCue::Save].variations,
+            vec!["mods://a_gloom/audio/save.ogg"]
+        );
+
+        let reported = |text: &str| content.problems.iter().any(|p| p.contains(text));
+        assert!(reported("c_broken"), "{:?}", content.problems);
+        assert!(reported("'../escape.ron' points outside"));
+        assert!(reported("missing.ogg' does not exist"));
+        assert!(reported(
+            "Mod 'Gloom' overrides the sprite Player of mod 'Night'"
+        ));
+        assert!(reported(
+            "Mod 'Gloom' overrides the string 'door.locked' of mod 'Night'"
+        ));
+        assert_eq!(content.problems.len(), 5, "{:?}", content.problems);
+    }
+
+    #[test]
+    fn registered_mod_levels_replace_the_room_file() {
+        let content = ModContent {
+            levels: BTreeMap::from([(9_210, PathBuf::from("mods/night/levels/attic.ron"))]),
+            ..default()
+        };
+        let mut registry = LevelRegistry::default();
+        assert_eq!(registry.level_file(9_210), "assets/levels/room_9210.ron");
+        content.register_levels(&mut registry);
+        assert_eq!(registry.level_file(9_210), "mods/night/levels/attic.ron");
+        assert!(ModContent::scan(Path::new("no/such/mods")).mods.is_empty());
+    }
+}
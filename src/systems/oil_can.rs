@@ -0,0 +1,140 @@
+use bevy::prelude::*;
+
+use crate::audio::sound_bank::{PlaySoundEvent, SoundCue};
+use crate::components::inventory::Inventory;
+use crate::components::player::Player;
+use crate::components::room::LevelEntityId;
+use crate::resources::room_state::RoomStateStore;
+
+/// Plugin for the oil can: freeing rusted doors and levers
+///
+/// Door and lever systems send an `OilStuckEvent` instead of acting when
+/// the player uses something `Stuck`.
+pub struct OilCanPlugin;
+
+impl Plugin for OilCanPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<RoomStateStore>()
+            .add_event::<OilStuckEvent>()
+            .add_event::<PlaySoundEvent>()
+            .add_systems(Update, oil_system);
+    }
+}
+
+/// Component for a door or lever rusted stuck until it's oiled
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Stuck;
+
+/// Event sent when the player tries to use something `Stuck`
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OilStuckEvent {
+    /// Stuck door or lever
+    pub target: Entity,
+    /// Player trying to use it
+    pub player: Entity,
+}
+
+/// System that frees stuck doors and levers with a charge of oil
+///
+/// # System Dependencies
+/// - **Upstream**: `door_use_system` and `lever_interaction_system` emit
+///   `OilStuckEvent`
+/// - **Components**: Writes the player's `Inventory`; reads `LevelEntityId`
+///   and removes `Stuck`
+/// - **Resources**: Writes `RoomStateStore`
+/// - **Downstream**: Emits `PlaySoundEvent` (`SoundCue::OilSquirt`)
+///
+/// # Behavior
+/// Without oil left in a can nothing happens. Otherwise one charge is used
+/// and the object comes free; the next use opens or flips it. Oiled level
+/// objects are recorded, so objects spawned already oiled (after leaving
+/// the room or loading a save) come free at once.
+pub fn oil_system(
+    mut commands: Commands,
+    mut events: EventReader<OilStuckEvent>,
+    mut store: ResMut<RoomStateStore>,
+    mut inventory_query: Query<&mut Inventory, With<Player>>,
+    stuck_query: Query<(Entity, Option<&LevelEntityId>), With<Stuck>>,
+    mut sound_events: EventWriter<PlaySoundEvent>,
+) {
+    for event in events.read() {
+        let Ok((_, level_id)) = stuck_query.get(event.target) else {
+            continue;
+        };
+        let Ok(mut inventory) = inventory_query.get_mut(event.player) else {
+            continue;
+        };
+        let Some(left) = inventory.use_oil_charge() else {
+            info!("It's rusted stuck (an oil can is needed)");
+            continue;
+        };
+
+        commands.entity(event.target).remove::<Stuck>();
+        if let Some(id) = level_id {
+            store.bypass_change_detection().oiled.insert(*id);
+        }
+        info!("The oil frees it up ({} charges left)", left);
+        sound_events.write(PlaySoundEvent {
+            cue: SoundCue::OilSquirt,
+        });
+    }
+
+    for (entity, level_id) in &stuck_query {
+        if level_id.is_some_and(|id| store.is_oiled(*id)) {
+            commands.entity(entity).remove::<Stuck>();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::inventory::{Item, ToolType};
+
+    const HINGE: LevelEntityId = LevelEntityId { room: 4, index: 1 };
+
+    #[test]
+    fn oiling_uses_a_charge_and_stays_oiled() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, OilCanPlugin));
+
+        let door = app.world_mut().spawn((Stuck, HINGE)).id();
+        let player = app
+            .world_mut()
+            .spawn((
+                Player,
+                Inventory {
+                    items: vec![],
+                    max_capacity: 10,
+                },
+            ))
+            .id();
+        let oil = OilStuckEvent {
+            target: door,
+            player,
+        };
+
+        app.world_mut().send_event(oil);
+        app.update();
+        assert!(app.world().get::<Stuck>(door).is_some(), "No oil can yet");
+
+        app.world_mut()
+            .get_mut::<Inventory>(player)
+            .unwrap()
+            .items
+            .push(Item::Tool(ToolType::OilCan(2)));
+        app.world_mut().send_event(oil);
+        app.update();
+        assert!(app.world().get::<Stuck>(door).is_none());
+        assert!(matches!(
+            app.world().get::<Inventory>(player).unwrap().items[..],
+            [Item::Tool(ToolType::OilCan(1))]
+        ));
+        assert!(app.world().resource::<RoomStateStore>().is_oiled(HINGE));
+
+        // The same door spawned again on a later visit is already free
+        let respawned = app.world_mut().spawn((Stuck, HINGE)).id();
+        app.update();
+        assert!(app.world().get::<Stuck>(respawned).is_none());
+    }
+}
@@ -0,0 +1,110 @@
+use bevy::prelude::*;
+
+use crate::audio::sound_bank::PlaySoundEvent;
+use crate::resources::game_state::GameState;
+use crate::systems::checkpoint::{CheckpointReachedEvent, checkpoint_system};
+use crate::systems::collision::CollisionPlugin;
+use crate::systems::game_set::GameSet;
+use crate::systems::health::{invincibility_system, save_point_regeneration_system};
+use crate::systems::inventory::{
+    InventoryFullEvent, ItemCollectedEvent, ItemUsedEvent, MergeCandleStubsEvent, PlaceItemEvent,
+    candle_stub_merge_system, candle_stub_use_system, inventory_collection_system,
+    inventory_usage_system, item_placement_system, match_pickup_difficulty_system,
+    match_use_system, pickup_cooldown_system,
+};
+use crate::systems::player_movement::PlayerMovementPlugin;
+use crate::systems::pushable::PushablePlugin;
+use crate::systems::respawn::respawn_system;
+use crate::systems::save_load::{AutoSaveEvent, ManualSaveEvent};
+use crate::systems::trap::{PlayerDeathEvent, trap_activation_system};
+
+/// Plugin for everything the player does and carries
+///
+/// Adds movement, solid collision and block pushing (on the fixed
+/// timestep, in `GameSet::Physics`), then in `GameSet::Gameplay`:
+/// - Picking up, using, merging and placing inventory items
+/// - Hit point invincibility and regeneration at save points
+/// - Checkpoints and respawning after death
+///
+/// Add `FixedTimestepPlugin` for the tick rate and `GameSetPlugin` to
+/// order the sets.
+pub struct PlayerPlugin;
+
+impl Plugin for PlayerPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<PlayerMovementPlugin>() {
+            app.add_plugins(PlayerMovementPlugin);
+        }
+        if !app.is_plugin_added::<CollisionPlugin>() {
+            app.add_plugins(CollisionPlugin);
+        }
+        if !app.is_plugin_added::<PushablePlugin>() {
+            app.add_plugins(PushablePlugin);
+        }
+
+        app.init_resource::<GameState>()
+            .add_event::<ItemCollectedEvent>()
+            .add_event::<ItemUsedEvent>()
+            .add_event::<MergeCandleStubsEvent>()
+            .add_event::<PlaceItemEvent>()
+            .add_event::<InventoryFullEvent>()
+            .add_event::<PlayerDeathEvent>()
+            .add_event::<CheckpointReachedEvent>()
+            .add_event::<AutoSaveEvent>()
+            .add_event::<ManualSaveEvent>()
+            .add_event::<PlaySoundEvent>()
+            .add_systems(
+                Update,
+                (
+                    (
+                        (match_pickup_difficulty_system, inventory_collection_system).chain(),
+                        inventory_usage_system,
+                        match_use_system,
+                        candle_stub_use_system,
+                        candle_stub_merge_system,
+                        (item_placement_system, pickup_cooldown_system).chain(),
+                    ),
+                    invincibility_system,
+                    respawn_system.after(trap_activation_system),
+                    (checkpoint_system, save_point_regeneration_system).chain(),
+                )
+                    .in_set(GameSet::Gameplay),
+            );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::inventory::{Collectible, Inventory, Item};
+    use crate::components::player::Player;
+
+    #[test]
+    fn player_plugin_registers_its_events_and_systems() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, PlayerPlugin));
+
+        let player = app
+            .world_mut()
+            .spawn((
+                Player,
+                Inventory {
+                    items: vec![],
+                    max_capacity: 10,
+                },
+            ))
+            .id();
+        let item = app
+            .world_mut()
+            .spawn((Collectible, Item::Match, Transform::default()))
+            .id();
+
+        app.world_mut()
+            .send_event(ItemCollectedEvent { item, player });
+        app.update();
+        assert!(matches!(
+            app.world().get::<Inventory>(player).unwrap().items[..],
+            [Item::Match]
+        ));
+    }
+}
@@ -1,39 +1,100 @@
 use crate::components::player::*;
 use crate::resources::game_state::{GameMode, GameState};
 use crate::resources::input_config::PlayerAction;
+use crate::resources::input_context::{InputContextStack, InputPermission};
+use crate::resources::movement_tuning::MovementTuning;
+use crate::systems::fixed_timestep::PhysicsSet;
+use crate::systems::grapple::{
+    GRAPPLE_REEL_SPEED, GRAPPLE_SWING_ACCEL, Grappling, MIN_ROPE_LENGTH,
+};
+use crate::systems::water::{InWater, SWIM_STROKE_SPEED, WATER_DRAG};
 use bevy::prelude::*;
 use leafwing_input_manager::prelude::*;
 
+/// Plugin that runs player movement on the fixed timestep
+///
+/// `player_movement_system` runs in `FixedUpdate` (`PhysicsSet::Movement`),
+/// so jumps and falls cover the same distance at any frame rate. Add
+/// `FixedTimestepPlugin` for the tick rate and render interpolation.
+pub struct PlayerMovementPlugin;
+
+impl Plugin for PlayerMovementPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<MovementTuning>().add_systems(
+            FixedUpdate,
+            player_movement_system.in_set(PhysicsSet::Movement),
+        );
+    }
+}
+
 /// Query type for player movement system to reduce type complexity
 type PlayerMovementQuery<'a> = (
     &'a mut Transform,
     &'a mut Velocity,
     &'a mut JumpState,
+    &'a mut CharacterController,
     &'a ActionState<PlayerAction>,
     Option<&'a DoubleJumpUnlocked>,
+    Option<&'a InWater>,
+    Option<&'a mut Grappling>,
+    Option<&'a mut Stamina>,
 );
 
 /// System for player movement, jump physics, and horizontal velocity
 ///
 /// Handles:
-/// - Horizontal movement (A/D or Arrow keys)
+/// - Horizontal movement (A/D or Arrow keys) at `MovementTuning::walk_speed`
+/// - Sprinting (Shift) on the ground at `MovementTuning::sprint_speed`,
+///   spending `Stamina` until exhausted; stamina recovers whenever the
+///   player isn't sprinting
 /// - Jump mechanics (Space when grounded)
 /// - Double jump (if DoubleJumpUnlocked component present)
 /// - Gravity application
-/// - Position updates based on velocity
+/// - Swimming while `InWater`: slowed movement, buoyancy, drag, and jump
+///   presses as swim strokes
+/// - Swinging while `Grappling`: left/right pump the swing, climbing reels
+///   the rope in, and the rope holds the player within its length of the
+///   anchor
+/// - Position updates based on velocity, recorded in the
+///   `CharacterController` for `solid_collision_system` to sweep
+///
+/// Nothing moves outside Playing mode or while the top input context (see
+/// `InputContextStack`) doesn't allow `InputPermission::Move`.
+///
+/// Runs in `FixedUpdate`, where `Time` is the fixed clock (see
+/// `PlayerMovementPlugin`).
 ///
 /// From quickstart.md Test Scenario 2: Player Movement and Jump Mechanics
 pub fn player_movement_system(
     time: Res<Time>,
     game_state: Res<GameState>,
+    input: Option<Res<InputContextStack>>,
+    tuning: Option<Res<MovementTuning>>,
     mut query: Query<PlayerMovementQuery, With<Player>>,
 ) {
     // Don't process movement if game is not in Playing mode (paused, menu, etc.)
     if game_state.game_mode != GameMode::Playing {
         return;
     }
-
-    for (mut transform, mut velocity, mut jump_state, actions, double_jump_unlocked) in &mut query {
+    // ...or while a transition, cutscene or puzzle panel has the input
+    if input.is_some_and(|input| !input.allows(InputPermission::Move)) {
+        return;
+    }
+    let default_tuning = MovementTuning::default();
+    let tuning = tuning.as_deref().unwrap_or(&default_tuning);
+
+    for (
+        mut transform,
+        mut velocity,
+        mut jump_state,
+        mut controller,
+        actions,
+        double_jump_unlocked,
+        in_water,
+        mut grappling,
+        stamina,
+    ) in &mut query
+    {
         // Horizontal movement
         let mut move_dir = 0.0;
         if actions.pressed(&PlayerAction::MoveLeft) {
@@ -43,40 +104,105 @@ pub fn player_movement_system(
             move_dir += 1.0;
         }
 
-        // Set horizontal velocity (200 pixels per second)
-        velocity.0.x = move_dir * 200.0;
+        // Sprinting needs solid footing: not on the rope or in water
+        let wants_sprint = actions.pressed(&PlayerAction::Sprint)
+            && move_dir != 0.0
+            && grappling.is_none()
+            && in_water.is_none();
+        let mut sprinting = false;
+        if let Some(mut stamina) = stamina {
+            sprinting = wants_sprint && !stamina.exhausted;
+            if sprinting {
+                stamina.drain(tuning.stamina_drain * time.delta_secs());
+            } else {
+                stamina.regen(
+                    tuning.stamina_regen * time.delta_secs(),
+                    tuning.max_stamina,
+                    tuning.sprint_recovery,
+                );
+            }
+            stamina.sprinting = sprinting;
+        }
 
-        // Jump logic
-        if actions.just_pressed(&PlayerAction::Jump) {
-            match *jump_state {
-                JumpState::Grounded => {
-                    // Single jump from ground
-                    velocity.0.y = 400.0; // upward velocity
-                    *jump_state = JumpState::Jumping;
-                }
-                JumpState::Jumping | JumpState::Falling => {
-                    // Double jump if unlocked
-                    if double_jump_unlocked.is_some() {
-                        velocity.0.y = 400.0;
-                        *jump_state = JumpState::DoubleJumping;
+        // Set horizontal velocity (slower in water); on the rope the swing
+        // carries the player instead
+        if grappling.is_none() {
+            let speed = if sprinting {
+                tuning.sprint_speed()
+            } else {
+                tuning.walk_speed
+            };
+            let speed_factor = in_water.map_or(1.0, |water| water.speed_factor);
+            velocity.0.x = move_dir * speed * speed_factor;
+        }
+
+        if let Some(rope) = grappling.as_mut() {
+            // Pumping accelerates the swing; jumping lets go (see
+            // grapple_release_system)
+            velocity.0.x += move_dir * GRAPPLE_SWING_ACCEL * time.delta_secs();
+            if actions.pressed(&PlayerAction::Climb) {
+                rope.length =
+                    (rope.length - GRAPPLE_REEL_SPEED * time.delta_secs()).max(MIN_ROPE_LENGTH);
+            }
+            velocity.0.y -= 980.0 * time.delta_secs();
+            *jump_state = JumpState::Falling;
+        } else if let Some(water) = in_water {
+            // Every jump press is a swim stroke, wherever the player is
+            if actions.just_pressed(&PlayerAction::Jump) {
+                velocity.0.y = velocity.0.y.max(SWIM_STROKE_SPEED);
+                *jump_state = JumpState::Jumping;
+            }
+
+            // Buoyancy floats a submerged player back up to the surface,
+            // where gravity takes over again, and drag slows both
+            let lift = if water.submerged { water.buoyancy } else { 0.0 };
+            velocity.0.y += (lift - 980.0) * time.delta_secs();
+            velocity.0.y *= (1.0 - WATER_DRAG * time.delta_secs()).max(0.0);
+            if velocity.0.y > 0.0 && *jump_state == JumpState::Grounded {
+                *jump_state = JumpState::Falling;
+            }
+        } else {
+            // Jump logic
+            if actions.just_pressed(&PlayerAction::Jump) {
+                match *jump_state {
+                    JumpState::Grounded => {
+                        // Single jump from ground
+                        velocity.0.y = 400.0; // upward velocity
+                        *jump_state = JumpState::Jumping;
+                    }
+                    JumpState::Jumping | JumpState::Falling => {
+                        // Double jump if unlocked
+                        if double_jump_unlocked.is_some() {
+                            velocity.0.y = 400.0;
+                            *jump_state = JumpState::DoubleJumping;
+                        }
+                        // Otherwise, ignore jump input (not grounded, no double jump)
+                    }
+                    JumpState::DoubleJumping => {
+                        // Already used double jump, ignore further jump inputs
                     }
-                    // Otherwise, ignore jump input (not grounded, no double jump)
-                }
-                JumpState::DoubleJumping => {
-                    // Already used double jump, ignore further jump inputs
                 }
             }
-        }
 
-        // Apply gravity to vertical velocity
-        if *jump_state != JumpState::Grounded {
-            velocity.0.y -= 980.0 * time.delta_secs(); // 980 px/s^2 gravity
+            // Apply gravity to vertical velocity
+            if *jump_state != JumpState::Grounded {
+                velocity.0.y -= 980.0 * time.delta_secs(); // 980 px/s^2 gravity
+            }
         }
 
         // Update position based on velocity
+        let start = transform.translation.truncate();
         transform.translation.x += velocity.0.x * time.delta_secs();
         transform.translation.y += velocity.0.y * time.delta_secs();
 
+        // A taut rope pulls the player back, turning the fall into a swing
+        if let Some(rope) = grappling.as_deref() {
+            let (position, rope_velocity) =
+                rope.constrain(transform.translation.truncate(), velocity.0);
+            transform.translation = position.extend(transform.translation.z);
+            velocity.0 = rope_velocity;
+        }
+
         // TODO: Collision detection and ground check
         // This will be implemented in collision system (T028)
         // Collision system will:
@@ -92,6 +218,9 @@ pub fn player_movement_system(
             velocity.0.y = 0.0;
             *jump_state = JumpState::Grounded;
         }
+
+        // Left for solid_collision_system to sweep the collider along
+        controller.motion += transform.translation.truncate() - start;
     }
 }
 
@@ -275,4 +404,142 @@ mod tests {
             "Player should be grounded"
         );
     }
+
+    #[test]
+    fn buoyancy_lifts_a_submerged_player() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+
+        app.insert_resource(GameState {
+            game_mode: GameMode::Playing,
+            ..default()
+        });
+
+        app.add_systems(Update, player_movement_system);
+
+        // Player resting on the bottom of a flooded room
+        let player_entity = app
+            .world_mut()
+            .spawn((
+                Player,
+                Transform::from_xyz(0.0, 0.0, 0.0),
+                Velocity(Vec2::ZERO),
+                JumpState::Grounded,
+                InputMap::<PlayerAction>::default(),
+                ActionState::<PlayerAction>::default(),
+                InWater {
+                    region: Entity::PLACEHOLDER,
+                    surface: 200.0,
+                    submerged: true,
+                    buoyancy: crate::systems::water::DEFAULT_BUOYANCY,
+                    speed_factor: 0.5,
+                },
+            ))
+            .id();
+
+        app.update();
+        app.update();
+        app.update();
+
+        let velocity = app.world().get::<Velocity>(player_entity).unwrap();
+        assert!(velocity.0.y > 0.0, "Buoyancy should push the player up");
+        assert_ne!(
+            *app.world().get::<JumpState>(player_entity).unwrap(),
+            JumpState::Grounded
+        );
+    }
+
+    #[test]
+    fn taut_rope_holds_a_grappling_player() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+
+        app.insert_resource(GameState {
+            game_mode: GameMode::Playing,
+            ..default()
+        });
+
+        app.add_systems(Update, player_movement_system);
+
+        // Hanging 150px below an anchor on a 100px rope
+        let player_entity = app
+            .world_mut()
+            .spawn((
+                Player,
+                Transform::from_xyz(0.0, 150.0, 0.0),
+                Velocity(Vec2::new(50.0, -200.0)),
+                JumpState::Falling,
+                InputMap::<PlayerAction>::default(),
+                ActionState::<PlayerAction>::default(),
+                Grappling {
+                    anchor: Entity::PLACEHOLDER,
+                    anchor_pos: Vec2::new(0.0, 300.0),
+                    length: 100.0,
+                },
+            ))
+            .id();
+
+        app.update();
+        app.update();
+
+        let transform = app.world().get::<Transform>(player_entity).unwrap();
+        let distance = transform
+            .translation
+            .truncate()
+            .distance(Vec2::new(0.0, 300.0));
+        assert!((distance - 100.0).abs() < 0.01, "Rope should be taut");
+        let velocity = app.world().get::<Velocity>(player_entity).unwrap();
+        assert!(velocity.0.x > 0.0, "The fall should turn into a swing");
+    }
+
+    #[test]
+    fn sprinting_spends_stamina_until_exhausted() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+
+        app.insert_resource(GameState {
+            game_mode: GameMode::Playing,
+            ..default()
+        });
+        app.insert_resource(MovementTuning {
+            stamina_drain: f32::MAX,
+            ..default()
+        });
+        app.add_systems(Update, player_movement_system);
+
+        let player_entity = app
+            .world_mut()
+            .spawn((
+                Player,
+                Transform::from_xyz(0.0, 0.0, 0.0),
+                Velocity(Vec2::ZERO),
+                JumpState::Grounded,
+                InputMap::<PlayerAction>::default(),
+                ActionState::<PlayerAction>::default(),
+            ))
+            .id();
+        {
+            let mut action_state = app
+                .world_mut()
+                .get_mut::<ActionState<PlayerAction>>(player_entity)
+                .unwrap();
+            action_state.press(&PlayerAction::MoveRight);
+            action_state.press(&PlayerAction::Sprint);
+        }
+
+        // The first tick may have no elapsed time; sprint until the bar runs dry
+        for _ in 0..3 {
+            app.update();
+        }
+        let stamina = *app.world().get::<Stamina>(player_entity).unwrap();
+        assert_eq!(stamina.current, 0.0);
+        assert!(stamina.exhausted);
+
+        // Exhausted, the player is back to walking pace
+        app.update();
+        let tuning = MovementTuning::default();
+        let velocity = app.world().get::<Velocity>(player_entity).unwrap();
+        assert_eq!(velocity.0.x, tuning.walk_speed);
+        assert!(!app.world().get::<Stamina>(player_entity).unwrap().sprinting);
+    }
 }
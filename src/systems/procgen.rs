@@ -0,0 +1,367 @@
+use bevy::prelude::*;
+use rand::Rng;
+use rand::seq::SliceRandom;
+use serde::{Deserialize, Serialize};
+
+use crate::components::inventory::Item;
+use crate::components::room::{ConnectionType, RoomId};
+use crate::resources::game_rng::GameRng;
+use crate::systems::level_loader::{
+    Bounds, BreakableConfig, EntitySpawn, LevelData, RoomConnection,
+};
+use crate::systems::save_load::{SerializedItem, deserialize_item};
+use crate::systems::tilemap::{DEFAULT_TILE_SIZE, FLOOR_TILE, WALL_TILE};
+
+/// Room ID of the first generated side room; authored rooms stay below it
+pub const SIDE_ROOM_ID_BASE: RoomId = 10_000;
+
+/// Most side room templates one authored room may have
+pub const MAX_SIDE_ROOMS: usize = 8;
+
+/// Plugin that generates the optional side rooms of authored rooms
+///
+/// Side rooms are generated from the `side_rooms` templates of a level,
+/// seeded by the run's `GameRng` seed, so a save always gets the same side
+/// rooms while new runs get new ones. `level_loader::load_room_level` adds
+/// the doors to them to their authored room and generates them on demand
+/// (see `side_room_id`); without a `GameRng`, no side rooms exist.
+pub struct ProcgenPlugin;
+
+impl Plugin for ProcgenPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<GameRng>();
+    }
+}
+
+/// Kind of side room, naming it after its authored room
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SideRoomKind {
+    /// A small walk-in closet
+    #[default]
+    Closet,
+    /// A low crawl space under the floor or behind a wall
+    CrawlSpace,
+}
+
+/// One entry of a side room's loot table
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct LootEntry {
+    /// Item placed (matches and keys lie loose; anything else is hidden in
+    /// a breakable crate)
+    pub item: SerializedItem,
+    /// Relative chance of the entry being picked
+    #[serde(default = "default_weight")]
+    pub weight: u32,
+}
+
+fn default_weight() -> u32 {
+    1
+}
+
+/// Constraints a side room is generated within, from level data
+///
+/// ```ron
+/// side_rooms: [(
+///     kind: Closet,
+///     door: (1800.0, 200.0),
+///     min_size: (4, 3),
+///     max_size: (6, 5),
+///     loot: [(item: Match, weight: 3), (item: Key(Iron))],
+///     loot_count: (1, 2),
+///     trap_budget: 1,
+/// )],
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct SideRoomTemplate {
+    /// Kind of room
+    #[serde(default)]
+    pub kind: SideRoomKind,
+    /// Position (x, y) of the door to the side room in the authored room
+    pub door: (f32, f32),
+    /// Smallest size (columns, rows) in tiles, walls included
+    pub min_size: (u32, u32),
+    /// Largest size (columns, rows) in tiles, walls included
+    pub max_size: (u32, u32),
+    /// Items the room's loot is drawn from
+    #[serde(default)]
+    pub loot: Vec<LootEntry>,
+    /// Fewest and most loot items placed
+    #[serde(default)]
+    pub loot_count: (u32, u32),
+    /// Traps placed
+    #[serde(default)]
+    pub trap_budget: u32,
+    /// Trap entity types the traps are drawn from
+    #[serde(default = "default_traps")]
+    pub traps: Vec<String>,
+    /// Chance (0.0-1.0) of the room existing in a given run
+    #[serde(default = "default_chance")]
+    pub chance: f32,
+}
+
+fn default_traps() -> Vec<String> {
+    vec!["Spikes".to_string()]
+}
+
+fn default_chance() -> f32 {
+    1.0
+}
+
+/// Room ID of the side room generated from template `index` of `parent`
+///
+/// Every authored room gets its own `MAX_SIDE_ROOMS` IDs, so no two side
+/// rooms share one.
+///
+/// # Returns
+/// `None` for templates past the first `MAX_SIDE_ROOMS`, and for a
+/// `parent` that is itself a side room
+pub fn side_room_id(parent: RoomId, index: usize) -> Option<RoomId> {
+    if index >= MAX_SIDE_ROOMS || parent >= SIDE_ROOM_ID_BASE {
+        return None;
+    }
+    Some(SIDE_ROOM_ID_BASE + parent * MAX_SIDE_ROOMS + index)
+}
+
+/// Authored room and template index of a generated side room's ID (the
+/// inverse of `side_room_id`)
+///
+/// # Returns
+/// `None` for IDs of authored rooms and IDs no side room gets
+pub fn side_room_parent(room_id: RoomId) -> Option<(RoomId, usize)> {
+    let offset = room_id.checked_sub(SIDE_ROOM_ID_BASE)?;
+    Some((offset / MAX_SIDE_ROOMS, offset % MAX_SIDE_ROOMS))
+        .filter(|&(parent, _)| parent < SIDE_ROOM_ID_BASE)
+}
+
+/// Generates the side room of template `index` of `parent` for a run
+/// seeded `seed`
+///
+/// The room is a walled grid within the template's size range, with a door
+/// back to `parent`, the loot drawn from its loot table and its trap budget
+/// spent on the floor tiles left.
+///
+/// # Returns
+/// `None` if `parent` has no such template, the template has no
+/// `side_room_id`, or the room didn't come up in this run (see
+/// `SideRoomTemplate::chance`)
+pub fn generate_side_room(parent: &LevelData, index: usize, seed: u64) -> Option<LevelData> {
+    let template = parent.side_rooms.get(index)?;
+    let id = side_room_id(parent.id, index)?;
+    let mut rng = GameRng::from_seed(room_stream_seed(seed, id));
+    if rng.random::<f32>() >= template.chance {
+        return None;
+    }
+
+    let size = |rng: &mut GameRng, min: u32, max: u32| rng.random_range(min..=max.max(min)).max(3);
+    let columns = size(&mut rng, template.min_size.0, template.max_size.0);
+    let rows = size(&mut rng, template.min_size.1, template.max_size.1);
+    let tiles: Vec<Vec<u32>> = (0..rows)
+        .map(|row| {
+            (0..columns)
+                .map(|column| {
+                    let edge = row == 0 || column == 0 || row == rows - 1 || column == columns - 1;
+                    if edge { WALL_TILE } else { FLOOR_TILE }
+                })
+                .collect()
+        })
+        .collect();
+
+    let tile = DEFAULT_TILE_SIZE.x;
+    let center =
+        |(column, row): (u32, u32)| ((column as f32 + 0.5) * tile, (row as f32 + 0.5) * tile);
+    // The door back takes the floor tile by the middle of the west wall
+    let door = (1, rows / 2);
+    let mut free: Vec<(u32, u32)> = (1..rows - 1)
+        .flat_map(|row| (1..columns - 1).map(move |column| (column, row)))
+        .filter(|&cell| cell != door)
+        .collect();
+    free.shuffle(&mut rng);
+
+    let mut entities = Vec::new();
+    let total_weight: u32 = template.loot.iter().map(|entry| entry.weight).sum();
+    let (fewest, most) = template.loot_count;
+    let loot_count = if total_weight == 0 {
+        0
+    } else {
+        rng.random_range(fewest..=most.max(fewest))
+    };
+    for _ in 0..loot_count {
+        let Some(cell) = free.pop() else {
+            break;
+        };
+        let mut pick = rng.random_range(0..total_weight);
+        let Some(entry) = template.loot.iter().find(|entry| {
+            let found = pick < entry.weight;
+            pick = pick.saturating_sub(entry.weight);
+            found
+        }) else {
+            break;
+        };
+        entities.push(loot_spawn(&entry.item, center(cell)));
+    }
+    for _ in 0..template.trap_budget {
+        let (Some(cell), Some(trap)) = (free.pop(), template.traps.choose(&mut rng)) else {
+            break;
+        };
+        entities.push(EntitySpawn {
+            entity_type: trap.clone(),
+            position: center(cell),
+            ..default()
+        });
+    }
+
+    let name = match template.kind {
+        SideRoomKind::Closet => format!("Closet off the {}", parent.name),
+        SideRoomKind::CrawlSpace => format!("Crawl space off the {}", parent.name),
+    };
+    Some(LevelData {
+        id,
+        floor: parent.floor,
+        name,
+        bounds: Bounds {
+            min: (0.0, 0.0),
+            max: (columns as f32 * tile, rows as f32 * tile),
+        },
+        tiles,
+        entities,
+        connections: vec![RoomConnection {
+            target_room: parent.id,
+            connection_type: ConnectionType::Door,
+            position: center(door),
+            locked: None,
+        }],
+        director: None,
+        audio: parent.audio.clone(),
+        scripts: Vec::new(),
+        side_rooms: Vec::new(),
+    })
+}
+
+/// Adds to `level` a door to each of its side rooms that came up in the
+/// run seeded `seed`
+pub fn attach_side_rooms(level: &mut LevelData, seed: u64) {
+    let doors: Vec<RoomConnection> = (0..level.side_rooms.len())
+        .filter_map(|index| Some((generate_side_room(level, index, seed)?, index)))
+        .map(|(room, index)| RoomConnection {
+            target_room: room.id,
+            connection_type: ConnectionType::Door,
+            position: level.side_rooms[index].door,
+            locked: None,
+        })
+        .collect();
+    level.connections.extend(doors);
+}
+
+/// Level entity placing loot `item` at `position`
+fn loot_spawn(item: &SerializedItem, position: (f32, f32)) -> EntitySpawn {
+    match deserialize_item(item) {
+        Item::Match => EntitySpawn {
+            entity_type: "Match".to_string(),
+            position,
+            ..default()
+        },
+        Item::Key(key) => EntitySpawn {
+            entity_type: "Key".to_string(),
+            position,
+            key_type: Some(key),
+            ..default()
+        },
+        _ => EntitySpawn {
+            entity_type: "Crate".to_string(),
+            position,
+            breakable: Some(BreakableConfig {
+                tool: None,
+                contains: Some(item.clone()),
+            }),
+            ..default()
+        },
+    }
+}
+
+/// Seed of the stream side room `room_id` is generated with
+fn room_stream_seed(seed: u64, room_id: RoomId) -> u64 {
+    let slot = (room_id - SIDE_ROOM_ID_BASE) as u64 + 1;
+    seed ^ slot.wrapping_mul(0xD1B5_4A32_D192_ED03)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::systems::level_check::check_connections;
+    use crate::systems::level_loader::{parse_level_data, validate_level_data};
+
+    fn parent() -> LevelData {
+        parse_level_data(
+            r#"(id: 3, floor: First, name: "Study",
+                bounds: (min: (0.0, 0.0), max: (1920.0, 1080.0)),
+                tiles: [], entities: [], connections: [],
+                side_rooms: [
+                    (door: (1800.0, 200.0), min_size: (4, 3), max_size: (7, 6),
+                     loot: [(item: Match, weight: 3), (item: Key(Iron)), (item: Tool(Crowbar))],
+                     loot_count: (2, 3), trap_budget: 1),
+                    (kind: CrawlSpace, door: (100.0, 100.0), min_size: (3, 3),
+                     max_size: (3, 3), chance: 0.0),
+                ])"#,
+            "test",
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn side_rooms_follow_their_template_and_round_trip_as_level_data() {
+        let parent = parent();
+        for seed in 0..20 {
+            let room = generate_side_room(&parent, 0, seed).unwrap();
+            let again = generate_side_room(&parent, 0, seed).unwrap();
+            let ron = ron::to_string(&room).unwrap();
+            assert_eq!(ron, ron::to_string(&again).unwrap(), "Same seed, same room");
+
+            assert_eq!(Some(room.id), side_room_id(3, 0));
+            assert_eq!(side_room_parent(room.id), Some((3, 0)));
+            let (columns, rows) = (room.tiles[0].len(), room.tiles.len());
+            assert!((4..=7).contains(&columns) && (3..=6).contains(&rows));
+            let traps = room.entities.iter().filter(|e| e.entity_type == "Spikes");
+            assert!(traps.count() <= 1);
+            assert!(room.entities.len() <= 4);
+
+            let round_trip = parse_level_data(&ron, "generated").unwrap();
+            validate_level_data(&round_trip).unwrap();
+            assert_eq!(ron::to_string(&round_trip).unwrap(), ron);
+        }
+        // A chance of 0.0 never generates the room
+        assert!(generate_side_room(&parent, 1, 7).is_none());
+        assert_eq!(side_room_parent(3), None);
+    }
+
+    #[test]
+    fn attached_side_rooms_connect_both_ways() {
+        let mut parent = parent();
+        attach_side_rooms(&mut parent, 42);
+        assert_eq!(parent.connections.len(), 1);
+        assert_eq!(Some(parent.connections[0].target_room), side_room_id(3, 0));
+        validate_level_data(&parent).unwrap();
+
+        let side_room = generate_side_room(&parent, 0, 42).unwrap();
+        assert!(check_connections(&[parent, side_room]).is_empty());
+    }
+
+    #[test]
+    fn side_room_ids_never_collide() {
+        let mut ids = std::collections::HashSet::new();
+        for parent in [0, 1, 2, 3, SIDE_ROOM_ID_BASE - 1] {
+            for index in 0..MAX_SIDE_ROOMS {
+                let id = side_room_id(parent, index).unwrap();
+                assert!(ids.insert(id), "Room {} template {}", parent, index);
+                assert_eq!(side_room_parent(id), Some((parent, index)));
+            }
+            assert_eq!(side_room_id(parent, MAX_SIDE_ROOMS), None);
+        }
+        assert_eq!(side_room_id(side_room_id(3, 0).unwrap(), 0), None);
+
+        // Templates past the last ID get no room
+        let mut parent = parent();
+        parent.side_rooms = vec![parent.side_rooms[0].clone(); MAX_SIDE_ROOMS + 1];
+        assert!(generate_side_room(&parent, MAX_SIDE_ROOMS, 1).is_none());
+        assert!(validate_level_data(&parent).is_err());
+    }
+}
@@ -0,0 +1,263 @@
+use bevy::prelude::*;
+
+use crate::components::render_layer::RenderLayer;
+use crate::components::room::{Collider, Interactable};
+use crate::resources::game_clock::GameClock;
+use crate::systems::interaction::{InteractionPrompt, interaction_system};
+
+/// Seconds a prompt label takes to fade fully in or out
+pub const PROMPT_FADE_DURATION: f32 = 0.2;
+
+/// Gap (pixels) between the top of an interactable and its prompt label
+pub const PROMPT_LABEL_MARGIN: f32 = 6.0;
+
+/// Height assumed for interactables without a sprite size or collider
+pub const DEFAULT_PROMPT_TARGET_HEIGHT: f32 = 32.0;
+
+/// Font size of prompt labels
+pub const PROMPT_FONT_SIZE: f32 = 10.0;
+
+/// Padding (pixels) between a prompt's text and the edge of its background
+pub const PROMPT_PADDING: Vec2 = Vec2::new(4.0, 2.0);
+
+/// Opacity of a fully shown prompt's background
+const BACKGROUND_ALPHA: f32 = 0.7;
+
+/// Plugin that floats the active interactable's prompt above it in the world
+///
+/// The HUD still shows the prompt too; the label makes it clear which
+/// object it belongs to when several are close together.
+pub struct PromptLabelPlugin;
+
+impl Plugin for PromptLabelPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<InteractionPrompt>()
+            .init_resource::<GameClock>()
+            .add_systems(Update, prompt_label_system.after(interaction_system));
+    }
+}
+
+/// Component on a world-space text label floating above an entity
+///
+/// The label is its own entity on `RenderLayer::Ui` (so the lighting
+/// overlay never darkens it) rather than a child of its target, so it stays
+/// upright and unscaled whatever the target's transform. Its children are a
+/// `Sprite` background and the `Text2d`, both faded by `opacity`.
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct WorldLabel {
+    /// Entity the label floats above
+    pub target: Entity,
+    /// Current opacity, from 0.0 (hidden) to 1.0
+    pub opacity: f32,
+}
+
+/// Size of the background behind `text`
+///
+/// Estimated from the character count, as the text isn't laid out until
+/// after the label is spawned.
+pub fn label_background_size(text: &str) -> Vec2 {
+    Vec2::new(
+        text.chars().count() as f32 * PROMPT_FONT_SIZE * 0.6,
+        PROMPT_FONT_SIZE,
+    ) + PROMPT_PADDING * 2.0
+}
+
+/// Height of an entity: its sprite size, else its collider
+fn target_height(sprite: Option<&Sprite>, collider: Option<&Collider>) -> f32 {
+    sprite
+        .and_then(|sprite| sprite.custom_size)
+        .map(|size| size.y)
+        .or_else(|| collider.map(|collider| collider.max.y - collider.min.y))
+        .unwrap_or(DEFAULT_PROMPT_TARGET_HEIGHT)
+}
+
+/// Spawns a hidden label reading `text`
+fn spawn_label(commands: &mut Commands, target: Entity, position: Vec2, text: &str) {
+    commands.spawn((
+        WorldLabel {
+            target,
+            opacity: 0.0,
+        },
+        RenderLayer::Ui,
+        Transform::from_xyz(position.x, position.y, RenderLayer::Ui.z()),
+        Visibility::default(),
+        children![
+            (
+                Sprite {
+                    color: Color::BLACK.with_alpha(0.0),
+                    custom_size: Some(label_background_size(text)),
+                    ..default()
+                },
+                Transform::default(),
+            ),
+            (
+                Text2d::new(text),
+                TextFont {
+                    font_size: PROMPT_FONT_SIZE,
+                    ..default()
+                },
+                TextColor(Color::WHITE.with_alpha(0.0)),
+                Transform::from_xyz(0.0, 0.0, 0.1),
+            ),
+        ],
+    ));
+}
+
+/// System that shows the active interactable's prompt as a floating label
+///
+/// # System Dependencies
+/// - **Components**: Reads `Interactable` entities' `Transform`, `Sprite` and
+///   `Collider`; writes `WorldLabel` labels and their children's `Sprite`
+///   and `TextColor`
+/// - **Resources**: Reads `InteractionPrompt` and `GameClock`
+/// - **Upstream**: `interaction_system` picks the active interactable
+///
+/// # Behavior
+/// 1. Spawns a label for the prompt's target if it has none
+/// 2. Fades the target's label in and every other label out over
+///    `PROMPT_FADE_DURATION`, keeping each `PROMPT_LABEL_MARGIN` above its
+///    target
+/// 3. Despawns labels once faded out, or when their target is gone
+#[allow(clippy::type_complexity)]
+pub fn prompt_label_system(
+    mut commands: Commands,
+    prompt: Res<InteractionPrompt>,
+    clock: Res<GameClock>,
+    targets: Query<
+        (&Transform, Option<&Sprite>, Option<&Collider>),
+        (With<Interactable>, Without<WorldLabel>),
+    >,
+    mut labels: Query<(Entity, &mut WorldLabel, &mut Transform, &Children)>,
+    mut backgrounds: Query<&mut Sprite, Without<Interactable>>,
+    mut texts: Query<&mut TextColor>,
+) {
+    let above =
+        |(transform, sprite, collider): (&Transform, Option<&Sprite>, Option<&Collider>)| {
+            transform.translation.truncate()
+                + Vec2::Y
+                    * (target_height(sprite, collider) / 2.0
+                        + PROMPT_LABEL_MARGIN
+                        + label_background_size("").y / 2.0)
+        };
+    let active = prompt.target.zip(prompt.prompt.as_deref());
+    let step = clock.delta / PROMPT_FADE_DURATION;
+
+    let mut labelled = false;
+    for (entity, mut label, mut transform, children) in &mut labels {
+        let Ok(target) = targets.get(label.target) else {
+            commands.entity(entity).despawn();
+            continue;
+        };
+
+        let shown = active.is_some_and(|(target, _)| target == label.target);
+        labelled |= shown;
+        let opacity = if shown {
+            (label.opacity + step).min(1.0)
+        } else {
+            (label.opacity - step).max(0.0)
+        };
+        if !shown && opacity == 0.0 {
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        let position = above(target);
+        if transform.translation.truncate() != position {
+            transform.translation.x = position.x;
+            transform.translation.y = position.y;
+        }
+        if label.opacity == opacity {
+            continue;
+        }
+        label.opacity = opacity;
+        for child in children {
+            if let Ok(mut sprite) = backgrounds.get_mut(*child) {
+                sprite.color.set_alpha(opacity * BACKGROUND_ALPHA);
+            }
+            if let Ok(mut color) = texts.get_mut(*child) {
+                color.0.set_alpha(opacity);
+            }
+        }
+    }
+
+    if !labelled
+        && let Some((target, text)) = active
+        && let Ok(target_data) = targets.get(target)
+    {
+        spawn_label(&mut commands, target, above(target_data), text);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::room::InteractionKind;
+
+    fn labels(app: &mut App) -> Vec<(Entity, f32, Vec2)> {
+        app.world_mut()
+            .query::<(&WorldLabel, &Transform)>()
+            .iter(app.world())
+            .map(|(label, transform)| {
+                (
+                    label.target,
+                    label.opacity,
+                    transform.translation.truncate(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn label_fades_in_above_the_target_and_out_when_left() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.init_resource::<InteractionPrompt>();
+        app.insert_resource(GameClock {
+            delta: PROMPT_FADE_DURATION / 2.0,
+            ..default()
+        });
+        app.add_systems(Update, prompt_label_system);
+
+        let door = app
+            .world_mut()
+            .spawn((
+                Interactable::new(InteractionKind::OpenDoor),
+                Sprite {
+                    custom_size: Some(Vec2::new(32.0, 64.0)),
+                    ..default()
+                },
+                Transform::from_xyz(100.0, 0.0, 0.0),
+            ))
+            .id();
+        app.update();
+        assert!(labels(&mut app).is_empty());
+
+        *app.world_mut().resource_mut::<InteractionPrompt>() = InteractionPrompt {
+            target: Some(door),
+            prompt: Some("Open".to_string()),
+        };
+        let y = 32.0 + PROMPT_LABEL_MARGIN + label_background_size("").y / 2.0;
+        app.update();
+        assert_eq!(labels(&mut app), vec![(door, 0.0, Vec2::new(100.0, y))]);
+        app.update();
+        assert_eq!(labels(&mut app), vec![(door, 0.5, Vec2::new(100.0, y))]);
+        app.update();
+        app.update();
+        assert_eq!(labels(&mut app), vec![(door, 1.0, Vec2::new(100.0, y))]);
+
+        *app.world_mut().resource_mut::<InteractionPrompt>() = InteractionPrompt::default();
+        app.update();
+        assert_eq!(labels(&mut app), vec![(door, 0.5, Vec2::new(100.0, y))]);
+        app.update();
+        assert!(labels(&mut app).is_empty());
+    }
+
+    #[test]
+    fn background_fits_the_text() {
+        let short = label_background_size("Open");
+        let long = label_background_size("Read the note");
+        assert!(long.x > short.x);
+        assert_eq!(long.y, short.y);
+        assert!(short.y > PROMPT_FONT_SIZE);
+    }
+}
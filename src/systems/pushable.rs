@@ -0,0 +1,328 @@
+use bevy::prelude::*;
+
+use crate::audio::sound_bank::{PlaySoundEvent, SoundCue};
+use crate::components::player::{Player, Velocity};
+use crate::components::puzzle::{PressurePlate, Puzzle};
+use crate::components::room::{Collider, Pushable, Solid};
+use crate::resources::game_state::{GameMode, GameState};
+use crate::systems::collision::{aabb_intersects, solid_collision_system, solid_grid_system};
+use crate::systems::fixed_timestep::PhysicsSet;
+use crate::systems::magnet::MetalObject;
+use crate::systems::puzzle::PuzzleInteractEvent;
+
+/// Plugin for blocks the player pushes and the pressure plates they weigh down
+///
+/// `push_system` runs before `solid_grid_system`, so blocks move (and are
+/// re-bucketed) out of the player's way before the player is pushed back
+/// out of them, and
+/// `pressure_plate_system` runs after everything has settled. Both run in
+/// `FixedUpdate` with the collision systems.
+pub struct PushablePlugin;
+
+impl Plugin for PushablePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<PuzzleInteractEvent>()
+            .add_event::<PlaySoundEvent>()
+            .add_systems(
+                FixedUpdate,
+                (
+                    push_system.before(solid_grid_system),
+                    pressure_plate_system.after(solid_collision_system),
+                )
+                    .in_set(PhysicsSet::Collision),
+            );
+    }
+}
+
+/// World-space bounds of a collider at `pos`
+fn bounds(pos: Vec2, collider: &Collider) -> Rect {
+    Rect::from_corners(pos + collider.min, pos + collider.max)
+}
+
+/// System that slides pushable blocks along the floor ahead of the player
+///
+/// # System Dependencies
+/// - **Components**: Reads the player's `Transform`, `Velocity` and
+///   `Collider`; reads `Collider` and writes `Transform` of `Solid` blocks
+///   with `Pushable`
+/// - **Resources**: Reads `GameState` (blocks only move while Playing)
+/// - **Upstream**: `player_movement_system` walks the player into blocks
+/// - **Downstream**: `solid_collision_system` pushes the player back to the
+///   block's side
+///
+/// # Behavior
+/// A block moves when the player walks into its side (not when standing
+/// on it), by `Pushable::speed_factor` of how far the player got into it.
+/// The player is then stopped at the block's new side, so they push at
+/// that fraction of their walking speed. Blocks stop against other solids,
+/// including other blocks.
+pub fn push_system(
+    game_state: Res<GameState>,
+    player_query: Query<(&Transform, &Velocity, &Collider), (With<Player>, Without<Solid>)>,
+    mut solid_query: Query<
+        (Entity, &mut Transform, &Collider, Option<&Pushable>),
+        (With<Solid>, Without<Player>),
+    >,
+) {
+    if game_state.game_mode != GameMode::Playing {
+        return;
+    }
+
+    // Solids are moved as blocks are pushed, so later blocks stop against
+    // where earlier ones ended up
+    let mut solids: Vec<(Entity, Rect)> = solid_query
+        .iter()
+        .map(|(entity, transform, collider, _)| {
+            (entity, bounds(transform.translation.truncate(), collider))
+        })
+        .collect();
+
+    for (player_transform, velocity, player_collider) in &player_query {
+        if velocity.0.x == 0.0 {
+            continue;
+        }
+        let direction = velocity.0.x.signum();
+        let player = bounds(player_transform.translation.truncate(), player_collider);
+
+        for index in 0..solids.len() {
+            let (entity, block) = solids[index];
+            let Ok((_, mut transform, _, Some(pushable))) = solid_query.get_mut(entity) else {
+                continue;
+            };
+
+            // Only a side contact in the direction of travel pushes
+            let overlap = player.intersect(block);
+            if overlap.is_empty()
+                || overlap.width() >= overlap.height()
+                || (block.center().x - player.center().x).signum() != direction
+            {
+                continue;
+            }
+
+            let mut shift = overlap.width() * pushable.speed_factor;
+            for (other, solid) in &solids {
+                if *other == entity || block.max.y <= solid.min.y || block.min.y >= solid.max.y {
+                    continue;
+                }
+                let gap = if direction > 0.0 {
+                    solid.min.x - block.max.x
+                } else {
+                    block.min.x - solid.max.x
+                };
+                if gap >= 0.0 {
+                    shift = shift.min(gap);
+                }
+            }
+            if shift <= 0.0 {
+                continue;
+            }
+
+            let offset = Vec2::new(shift * direction, 0.0);
+            transform.translation += offset.extend(0.0);
+            solids[index].1 = Rect::from_corners(block.min + offset, block.max + offset);
+        }
+    }
+}
+
+/// System that presses pressure plates under the player, pushable blocks or
+/// metal objects
+///
+/// # System Dependencies
+/// - **Components**: Writes `PressurePlate`; reads `Transform` and
+///   `Collider` of plates, the player, `Pushable` blocks and `MetalObject`s,
+///   and `Puzzle`
+/// - **Upstream**: `push_system` and `solid_collision_system` settle
+///   positions first
+/// - **Downstream**: Emits `PuzzleInteractEvent` for `puzzle_interaction_system`
+///   and `PlaySoundEvent` (`SoundCue::PlateClick`)
+///
+/// # Behavior
+/// A plate is pressed while anything overlaps it, clicking as it goes
+/// down. Whenever a plate is pressed or released, each pressure plate
+/// puzzle using it is re-checked, so a puzzle is solved the moment its
+/// last plate is weighed down.
+pub fn pressure_plate_system(
+    mut plate_query: Query<(Entity, &mut PressurePlate, &Transform, &Collider)>,
+    weight_query: Query<
+        (&Transform, &Collider),
+        Or<(With<Player>, With<Pushable>, With<MetalObject>)>,
+    >,
+    puzzle_query: Query<(Entity, &Puzzle)>,
+    mut interact_events: EventWriter<PuzzleInteractEvent>,
+    mut sound_events: EventWriter<PlaySoundEvent>,
+) {
+    for (plate_entity, mut plate, transform, collider) in &mut plate_query {
+        let pos = transform.translation.truncate();
+        let pressed = weight_query
+            .iter()
+            .any(|(weight_transform, weight_collider)| {
+                aabb_intersects(
+                    pos,
+                    collider,
+                    weight_transform.translation.truncate(),
+                    weight_collider,
+                )
+            });
+        if plate.pressed == pressed {
+            continue;
+        }
+        plate.pressed = pressed;
+
+        if pressed {
+            sound_events.write(PlaySoundEvent {
+                cue: SoundCue::PlateClick,
+            });
+        }
+        let puzzles = puzzle_query.iter().filter(|(_, puzzle)| match puzzle {
+            Puzzle::PressurePlate(plate_puzzle) => plate_puzzle.plates.contains(&plate_entity),
+            _ => false,
+        });
+        for (puzzle, _) in puzzles {
+            interact_events.write(PuzzleInteractEvent { puzzle });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::player::JumpState;
+    use crate::components::puzzle::{PressurePlatePuzzle, PuzzleReward, PuzzleState};
+    use crate::systems::collision::CollisionPlugin;
+    use crate::systems::fixed_timestep::advance_fixed_timestep;
+    use crate::systems::puzzle::{PuzzleSolvedEvent, puzzle_interaction_system};
+
+    fn square(half: f32) -> Collider {
+        Collider {
+            min: Vec2::splat(-half),
+            max: Vec2::splat(half),
+        }
+    }
+
+    fn push_app() -> App {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, CollisionPlugin, PushablePlugin));
+        app.insert_resource(GameState {
+            game_mode: GameMode::Playing,
+            ..default()
+        });
+        app
+    }
+
+    fn spawn_crate(app: &mut App, x: f32) -> Entity {
+        app.world_mut()
+            .spawn((
+                Pushable::default(),
+                Solid,
+                Transform::from_xyz(x, 16.0, 0.0),
+                square(16.0),
+            ))
+            .id()
+    }
+
+    /// Puts the player at `x` walking right, as if movement just ran
+    fn walk_into(app: &mut App, player: Entity, x: f32) {
+        let mut entity = app.world_mut().entity_mut(player);
+        entity.get_mut::<Transform>().unwrap().translation.x = x;
+        entity.get_mut::<Velocity>().unwrap().0.x = 200.0;
+    }
+
+    fn x_of(app: &App, entity: Entity) -> f32 {
+        app.world().get::<Transform>(entity).unwrap().translation.x
+    }
+
+    #[test]
+    fn crates_move_at_the_push_speed_until_they_hit_a_wall() {
+        let mut app = push_app();
+        let block = spawn_crate(&mut app, 32.0);
+        app.world_mut()
+            .spawn((Solid, Transform::from_xyz(92.0, 32.0, 0.0), square(32.0)));
+        // Walked 8px into the crate's left side
+        let player = app
+            .world_mut()
+            .spawn((
+                Player,
+                Transform::from_xyz(8.0, 16.0, 0.0),
+                Velocity(Vec2::new(200.0, 0.0)),
+                JumpState::Grounded,
+                square(16.0),
+            ))
+            .id();
+
+        advance_fixed_timestep(&mut app);
+        assert_eq!(x_of(&app, block), 36.0);
+        assert_eq!(x_of(&app, player), 4.0, "Player stops at the crate's side");
+
+        // Shove it up against the wall at x = 60, then keep pushing
+        for _ in 0..2 {
+            walk_into(&mut app, player, 30.0);
+            advance_fixed_timestep(&mut app);
+            assert_eq!(x_of(&app, block), 44.0);
+            assert_eq!(x_of(&app, player), 12.0);
+        }
+    }
+
+    #[test]
+    fn crate_on_a_plate_solves_its_puzzle() {
+        let mut app = push_app();
+        app.add_event::<PuzzleSolvedEvent>()
+            .add_systems(Update, puzzle_interaction_system);
+
+        let plates: Vec<Entity> = [0.0, 200.0]
+            .into_iter()
+            .map(|x| {
+                app.world_mut()
+                    .spawn((
+                        PressurePlate::default(),
+                        Transform::from_xyz(x, 2.0, 0.0),
+                        square(2.0),
+                    ))
+                    .id()
+            })
+            .collect();
+        let puzzle = app
+            .world_mut()
+            .spawn((
+                Puzzle::PressurePlate(PressurePlatePuzzle {
+                    plates: plates.clone(),
+                    required_items: vec![],
+                }),
+                PuzzleState::Unsolved,
+                PuzzleReward::UnlockDoor(2),
+            ))
+            .id();
+
+        let block = spawn_crate(&mut app, 0.0);
+        advance_fixed_timestep(&mut app);
+        assert!(app.world().get::<PressurePlate>(plates[0]).unwrap().pressed);
+        assert_eq!(
+            *app.world().get::<PuzzleState>(puzzle).unwrap(),
+            PuzzleState::Unsolved
+        );
+
+        // Crate slid off the first plate and onto the second
+        app.world_mut()
+            .get_mut::<Transform>(block)
+            .unwrap()
+            .translation
+            .x = 200.0;
+        advance_fixed_timestep(&mut app);
+        assert!(!app.world().get::<PressurePlate>(plates[0]).unwrap().pressed);
+
+        // Standing on the first plate while the crate holds the second
+        app.world_mut()
+            .spawn((Player, Transform::from_xyz(0.0, 16.0, 0.0), square(16.0)));
+        advance_fixed_timestep(&mut app);
+        assert_eq!(
+            *app.world().get::<PuzzleState>(puzzle).unwrap(),
+            PuzzleState::Solved
+        );
+        let sounds: Vec<_> = app
+            .world_mut()
+            .resource_mut::<Events<PlaySoundEvent>>()
+            .drain()
+            .filter(|event| event.cue == SoundCue::PlateClick)
+            .collect();
+        assert_eq!(sounds.len(), 3);
+    }
+}
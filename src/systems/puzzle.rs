@@ -1,7 +1,68 @@
+use crate::components::player::Player;
 use crate::components::puzzle::*;
 use crate::components::room::{Door, DoorState};
 use crate::resources::game_state::{GameMode, GameState};
+use crate::resources::input_config::PlayerAction;
+use crate::resources::puzzle_graph::PuzzleGraph;
+use crate::systems::game_set::GameSet;
+use crate::systems::oil_can::{OilStuckEvent, Stuck};
+use crate::systems::puzzle_undo::{
+    PuzzleCommand, PuzzleCommandEvent, PuzzleUndoPlugin, puzzle_command_system,
+};
 use bevy::prelude::*;
+use leafwing_input_manager::prelude::*;
+
+/// Maximum distance (pixels) between the player and a lever to flip it
+pub const LEVER_INTERACT_RANGE: f32 = 48.0;
+
+/// Seconds the lever handle takes to swing to its new position
+pub const LEVER_FLIP_DURATION: f32 = 0.25;
+
+/// Handle tilt (radians) of a lever in the `Up` position (`Down` is mirrored)
+pub const LEVER_TILT: f32 = std::f32::consts::FRAC_PI_6;
+
+/// Plugin for puzzles, levers and the house-wide puzzle graph
+///
+/// Registers the puzzle events and runs, in order:
+/// - `GameSet::Input`: `lever_interaction_system` (flipping the nearest
+///   lever)
+/// - `GameSet::Gameplay`: registering new puzzles in `PuzzleGraph`,
+///   checking solutions after puzzle commands are applied, then unlocking
+///   dependent puzzles and applying rewards
+/// - `GameSet::Presentation`: `lever_animation_system`
+///
+/// Adds `PuzzleUndoPlugin` (puzzle commands and undo) if it isn't already.
+pub struct PuzzlePlugin;
+
+impl Plugin for PuzzlePlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<PuzzleUndoPlugin>() {
+            app.add_plugins(PuzzleUndoPlugin);
+        }
+
+        app.init_resource::<GameState>()
+            .init_resource::<PuzzleGraph>()
+            .add_event::<PuzzleInteractEvent>()
+            .add_event::<PuzzleSolvedEvent>()
+            .add_event::<PuzzleUnlockedEvent>()
+            .add_event::<PuzzleCommandEvent>()
+            .add_event::<OilStuckEvent>()
+            .add_systems(
+                Update,
+                (
+                    lever_interaction_system.in_set(GameSet::Input),
+                    (
+                        puzzle_dependency_system,
+                        puzzle_interaction_system.after(puzzle_command_system),
+                        (puzzle_unlock_system, puzzle_reward_system),
+                    )
+                        .chain()
+                        .in_set(GameSet::Gameplay),
+                    lever_animation_system.in_set(GameSet::Presentation),
+                ),
+            );
+    }
+}
 
 /// Event emitted when a player interacts with a puzzle
 ///
@@ -57,6 +118,17 @@ pub struct PuzzleSolvedEvent {
     pub reward: PuzzleReward,
 }
 
+/// Event emitted when solving a prerequisite unlocks a dependent puzzle
+///
+/// Emitted once per newly unlocked puzzle, whether or not that puzzle's room
+/// is currently spawned. UI and audio can use it to tell the player that
+/// something elsewhere in the house changed.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PuzzleUnlockedEvent {
+    /// The puzzle that can now be solved
+    pub puzzle: PuzzleId,
+}
+
 /// System that handles puzzle interaction and solution validation
 ///
 /// This system processes `PuzzleInteractEvent` and checks if puzzles are solved
@@ -65,7 +137,7 @@ pub struct PuzzleSolvedEvent {
 /// # Supported Puzzle Types
 /// - **SymbolMatch**: Validates that input sequence matches correct sequence
 /// - **CircuitBreaker**: Checks if fuses are in correct slots in correct order
-/// - **PressurePlate**: Verifies all required items are placed on plates
+/// - **PressurePlate**: Checks every plate is weighed down at once
 /// - **LeverCombination**: Validates lever states match correct combination
 /// - **MirrorReflection**: Basic setup (validation logic to be implemented)
 ///
@@ -81,11 +153,16 @@ pub struct PuzzleSolvedEvent {
 /// 4. Updates `PuzzleState` to `InProgress` if partially solved, `Solved` if complete
 /// 5. Emits `PuzzleSolvedEvent` when puzzle is solved
 ///
+/// Puzzles marked `PuzzleLocked` ignore interaction until their
+/// prerequisites are solved.
+///
 /// From tasks.md T032: PuzzleInteractionSystem
 pub fn puzzle_interaction_system(
     mut interact_events: EventReader<PuzzleInteractEvent>,
     game_state: Res<GameState>,
-    mut puzzle_query: Query<(&mut PuzzleState, &Puzzle, &PuzzleReward)>,
+    mut puzzle_query: Query<(&mut PuzzleState, &Puzzle, &PuzzleReward, Has<PuzzleLocked>)>,
+    lever_query: Query<&LeverState>,
+    plate_query: Query<&PressurePlate>,
     mut solved_events: EventWriter<PuzzleSolvedEvent>,
 ) {
     // Only process puzzles when game is in Playing mode
@@ -94,12 +171,17 @@ pub fn puzzle_interaction_system(
     }
 
     for event in interact_events.read() {
-        if let Ok((mut state, puzzle, reward)) = puzzle_query.get_mut(event.puzzle) {
+        if let Ok((mut state, puzzle, reward, locked)) = puzzle_query.get_mut(event.puzzle) {
             // Skip if already solved
             if *state == PuzzleState::Solved {
                 continue;
             }
 
+            if locked {
+                info!("Puzzle is locked until its prerequisites are solved");
+                continue;
+            }
+
             // Check puzzle solution based on type
             let solved = match puzzle {
                 Puzzle::SymbolMatch(symbol_puzzle) => {
@@ -109,10 +191,10 @@ pub fn puzzle_interaction_system(
                     validate_circuit_breaker_puzzle(circuit_puzzle, &mut state)
                 }
                 Puzzle::PressurePlate(pressure_puzzle) => {
-                    validate_pressure_plate_puzzle(pressure_puzzle, &mut state)
+                    validate_pressure_plate_puzzle(pressure_puzzle, &plate_query, &mut state)
                 }
                 Puzzle::LeverCombination(lever_puzzle) => {
-                    validate_lever_combination_puzzle(lever_puzzle, &mut state)
+                    validate_lever_combination_puzzle(lever_puzzle, &lever_query, &mut state)
                 }
                 Puzzle::MirrorReflection => {
                     // TODO: Implement mirror reflection logic
@@ -196,20 +278,31 @@ fn validate_circuit_breaker_puzzle(puzzle: &CircuitBreakerPuzzle, state: &mut Pu
 
 /// Validates a pressure plate puzzle
 ///
-/// Checks if all required items are placed on the corresponding plates.
-/// Note: This is a simplified implementation. A full implementation would
-/// need to track which items are on which plates.
-fn validate_pressure_plate_puzzle(puzzle: &PressurePlatePuzzle, state: &mut PuzzleState) -> bool {
-    // Simplified: Check if number of plates matches required items
-    // In a real implementation, this would check actual plate activation states
+/// Solved once every plate's `PressurePlate` is pressed at the same time,
+/// whether by the player or by pushed blocks (see `pressure_plate_system`).
+/// Note: `required_items` isn't checked against what is on the plates yet;
+/// a puzzle with one required item per plate is treated as InProgress
+/// until its plates are all pressed.
+fn validate_pressure_plate_puzzle(
+    puzzle: &PressurePlatePuzzle,
+    plate_query: &Query<&PressurePlate>,
+    state: &mut PuzzleState,
+) -> bool {
+    let all_pressed = !puzzle.plates.is_empty()
+        && puzzle.plates.iter().all(|plate| {
+            plate_query
+                .get(*plate)
+                .is_ok_and(|pressure_plate| pressure_plate.pressed)
+        });
+    if all_pressed {
+        return true;
+    }
+
     let plates_count = puzzle.plates.len();
     let required_count = puzzle.required_items.len();
 
     if plates_count == required_count && plates_count > 0 {
-        // For now, assume puzzle is solvable if structure is valid
-        // Real implementation would check plate activation states
         *state = PuzzleState::InProgress;
-        return false; // Require actual plate activation system
     }
 
     false
@@ -217,19 +310,237 @@ fn validate_pressure_plate_puzzle(puzzle: &PressurePlatePuzzle, state: &mut Puzz
 
 /// Validates a lever combination puzzle
 ///
-/// Checks if all levers are in the correct states.
-/// Note: This requires lever entities to have LeverState components.
+/// Checks if every lever entity's `LeverState` matches the correct state.
+/// Updates state to InProgress while the combination is still wrong.
+/// Puzzles referencing levers without a `LeverState` can't be solved.
 fn validate_lever_combination_puzzle(
     puzzle: &LeverCombinationPuzzle,
+    lever_query: &Query<&LeverState>,
     state: &mut PuzzleState,
 ) -> bool {
-    // Simplified: Check if structure is valid
-    // Real implementation would query lever entities for their current states
-    if puzzle.levers.len() == puzzle.correct_states.len() {
-        *state = PuzzleState::InProgress;
+    if puzzle.levers.len() != puzzle.correct_states.len() {
+        return false;
+    }
+
+    let Ok(lever_states) = puzzle
+        .levers
+        .iter()
+        .map(|lever| lever_query.get(*lever).copied())
+        .collect::<Result<Vec<_>, _>>()
+    else {
+        warn!("Lever puzzle references a lever without a LeverState");
+        return false;
+    };
+
+    if lever_states == puzzle.correct_states {
+        return true;
+    }
+
+    *state = PuzzleState::InProgress;
+    false
+}
+
+/// Component animating a lever handle swinging to its new position
+///
+/// Added by `lever_interaction_system` and removed by
+/// `lever_animation_system` once the swing finishes.
+#[derive(Component, Debug)]
+pub struct LeverFlip {
+    /// Tracks the swing progress
+    pub timer: Timer,
+    /// Handle tilt (radians) when the swing started
+    pub from_angle: f32,
+}
+
+/// Returns the handle tilt (radians) for a lever state
+pub fn lever_angle(state: LeverState) -> f32 {
+    match state {
+        LeverState::Up => LEVER_TILT,
+        LeverState::Down => -LEVER_TILT,
     }
+}
 
-    false // Require actual lever state query system
+/// System that flips levers the player interacts with
+///
+/// # Behavior
+/// When the player presses interact (F):
+/// 1. Finds the closest lever within `LEVER_INTERACT_RANGE`
+/// 2. If a lever puzzle uses the lever, emits `PuzzleCommandEvent` to flip
+///    it (so the flip can be undone); otherwise flips it straight away
+///    (see `flip_lever`)
+///
+/// A `Stuck` lever doesn't move; `OilStuckEvent` is emitted for it instead.
+///
+/// # System Dependencies
+/// - **Components**: Reads `Player`, `ActionState<PlayerAction>`, `Stuck`,
+///   `Puzzle`; writes `LeverState`
+/// - **Downstream**: `puzzle_command_system` flips puzzle levers and has the
+///   puzzle validated, `lever_animation_system` animates the handle,
+///   `oil_system` frees stuck levers
+#[allow(clippy::type_complexity)]
+pub fn lever_interaction_system(
+    game_state: Res<GameState>,
+    mut commands: Commands,
+    player_query: Query<(Entity, &Transform, &ActionState<PlayerAction>), With<Player>>,
+    mut lever_query: Query<(Entity, &Transform, &mut LeverState, Has<Stuck>), Without<Player>>,
+    puzzle_query: Query<(Entity, &Puzzle)>,
+    mut command_events: EventWriter<PuzzleCommandEvent>,
+    mut stuck_events: EventWriter<OilStuckEvent>,
+) {
+    if game_state.game_mode != GameMode::Playing {
+        return;
+    }
+
+    for (player, player_transform, actions) in &player_query {
+        if !actions.just_pressed(&PlayerAction::Interact) {
+            continue;
+        }
+
+        let player_pos = player_transform.translation.truncate();
+        let closest = lever_query
+            .iter()
+            .map(|(entity, transform, _, _)| {
+                (
+                    entity,
+                    transform.translation.truncate().distance(player_pos),
+                )
+            })
+            .filter(|(_, distance)| *distance <= LEVER_INTERACT_RANGE)
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(entity, _)| entity);
+
+        let Some(lever) = closest else {
+            continue;
+        };
+        let Ok((_, _, mut lever_state, stuck)) = lever_query.get_mut(lever) else {
+            continue;
+        };
+        if stuck {
+            stuck_events.write(OilStuckEvent {
+                target: lever,
+                player,
+            });
+            continue;
+        }
+
+        // Levers of a puzzle are flipped as puzzle commands, so they can be undone
+        match lever_puzzle(&puzzle_query, lever) {
+            Some(puzzle) => {
+                command_events.write(PuzzleCommandEvent {
+                    puzzle,
+                    command: PuzzleCommand::FlipLever(lever),
+                });
+            }
+            None => flip_lever(&mut commands, lever, &mut lever_state),
+        }
+    }
+}
+
+/// Returns the first lever combination puzzle that uses `lever`
+pub fn lever_puzzle(puzzle_query: &Query<(Entity, &Puzzle)>, lever: Entity) -> Option<Entity> {
+    puzzle_query.iter().find_map(|(puzzle, kind)| match kind {
+        Puzzle::LeverCombination(levers) if levers.levers.contains(&lever) => Some(puzzle),
+        _ => None,
+    })
+}
+
+/// Flips `lever` and starts its `LeverFlip` swing animation
+pub fn flip_lever(commands: &mut Commands, lever: Entity, state: &mut LeverState) {
+    let from_angle = lever_angle(*state);
+    *state = state.flipped();
+    commands.entity(lever).insert(LeverFlip {
+        timer: Timer::from_seconds(LEVER_FLIP_DURATION, TimerMode::Once),
+        from_angle,
+    });
+}
+
+/// System that swings lever handles toward their current state
+///
+/// Interpolates the `Transform` rotation from `LeverFlip::from_angle` to the
+/// angle for the lever's `LeverState`, then removes `LeverFlip`.
+pub fn lever_animation_system(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut lever_query: Query<(Entity, &mut Transform, &LeverState, &mut LeverFlip)>,
+) {
+    for (entity, mut transform, state, mut flip) in &mut lever_query {
+        flip.timer.tick(time.delta());
+        let target = lever_angle(*state);
+        let angle = flip.from_angle + (target - flip.from_angle) * flip.timer.fraction();
+        transform.rotation = Quat::from_rotation_z(angle);
+
+        if flip.timer.finished() {
+            commands.entity(entity).remove::<LeverFlip>();
+        }
+    }
+}
+
+/// System that registers spawned puzzles in the house-wide puzzle graph
+///
+/// # System Dependencies
+/// - **Components**: Reads newly added `PuzzleNode` and `PuzzleDependency`
+/// - **Resources**: Writes `PuzzleGraph`
+/// - **Downstream**: `puzzle_interaction_system` skips `PuzzleLocked` puzzles
+///
+/// # Behavior
+/// - Records each puzzle's prerequisites in `PuzzleGraph`
+/// - Inserts `PuzzleLocked` on puzzles with unsolved prerequisites, so a
+///   puzzle whose prerequisites were solved before its room spawned starts
+///   unlocked
+pub fn puzzle_dependency_system(
+    mut commands: Commands,
+    mut graph: ResMut<PuzzleGraph>,
+    new_puzzles: Query<(Entity, &PuzzleNode, Option<&PuzzleDependency>), Added<PuzzleNode>>,
+) {
+    for (entity, node, dependency) in &new_puzzles {
+        let Some(dependency) = dependency else {
+            continue;
+        };
+
+        graph.add_puzzle(node.0, dependency.requires.clone());
+        if !graph.is_unlocked(node.0) {
+            commands.entity(entity).insert(PuzzleLocked);
+        }
+    }
+}
+
+/// System that propagates solved puzzles through the puzzle graph
+///
+/// # System Dependencies
+/// - **Upstream**: `puzzle_interaction_system` emits `PuzzleSolvedEvent`
+/// - **Components**: Reads `PuzzleNode`, removes `PuzzleLocked`
+/// - **Resources**: Writes `PuzzleGraph`
+/// - **Downstream**: Emits `PuzzleUnlockedEvent`
+///
+/// # Behavior
+/// 1. Marks each solved puzzle's `PuzzleNode` solved in `PuzzleGraph`
+/// 2. Emits `PuzzleUnlockedEvent` for every dependent puzzle whose last
+///    prerequisite this was
+/// 3. Removes `PuzzleLocked` from unlocked puzzles that are currently spawned
+pub fn puzzle_unlock_system(
+    mut commands: Commands,
+    mut solved_events: EventReader<PuzzleSolvedEvent>,
+    mut graph: ResMut<PuzzleGraph>,
+    node_query: Query<&PuzzleNode>,
+    locked_query: Query<(Entity, &PuzzleNode), With<PuzzleLocked>>,
+    mut unlocked_events: EventWriter<PuzzleUnlockedEvent>,
+) {
+    for event in solved_events.read() {
+        let Ok(node) = node_query.get(event.puzzle) else {
+            continue;
+        };
+
+        for puzzle in graph.mark_solved(node.0) {
+            info!("Puzzle {} unlocked", puzzle);
+            unlocked_events.write(PuzzleUnlockedEvent { puzzle });
+
+            for (entity, locked_node) in &locked_query {
+                if locked_node.0 == puzzle {
+                    commands.entity(entity).remove::<PuzzleLocked>();
+                }
+            }
+        }
+    }
 }
 
 /// System that applies puzzle rewards when puzzles are solved
@@ -273,6 +584,7 @@ pub fn puzzle_reward_system(
 mod tests {
     use super::*;
     use crate::components::room::TargetRoom;
+    use crate::systems::game_set::GameSetPlugin;
 
     #[test]
     fn puzzle_interaction_system_compiles() {
@@ -536,4 +848,142 @@ mod tests {
         let state = app.world().get::<PuzzleState>(puzzle_entity).unwrap();
         assert_eq!(*state, PuzzleState::Unsolved);
     }
+
+    #[test]
+    fn interact_flips_nearest_lever_and_solves_puzzle() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, GameSetPlugin, PuzzlePlugin));
+        app.world_mut().resource_mut::<GameState>().game_mode = GameMode::Playing;
+
+        let near = app
+            .world_mut()
+            .spawn((LeverState::Up, Transform::from_xyz(20.0, 0.0, 0.0)))
+            .id();
+        let far = app
+            .world_mut()
+            .spawn((LeverState::Up, Transform::from_xyz(200.0, 0.0, 0.0)))
+            .id();
+        let puzzle_entity = app
+            .world_mut()
+            .spawn((
+                Puzzle::LeverCombination(LeverCombinationPuzzle {
+                    levers: vec![near, far],
+                    correct_states: vec![LeverState::Down, LeverState::Up],
+                }),
+                PuzzleState::Unsolved,
+                PuzzleReward::UnlockDoor(1),
+            ))
+            .id();
+
+        let mut actions = ActionState::<PlayerAction>::default();
+        actions.press(&PlayerAction::Interact);
+        app.world_mut()
+            .spawn((Player, Transform::from_xyz(0.0, 0.0, 0.0), actions));
+        app.update();
+
+        assert_eq!(app.world().get::<LeverState>(near), Some(&LeverState::Down));
+        assert_eq!(app.world().get::<LeverState>(far), Some(&LeverState::Up));
+        assert!(app.world().get::<LeverFlip>(near).is_some());
+        assert_eq!(
+            app.world().get::<PuzzleState>(puzzle_entity),
+            Some(&PuzzleState::Solved)
+        );
+    }
+
+    #[test]
+    fn lever_puzzle_with_missing_lever_state_stays_unsolved() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_event::<PuzzleInteractEvent>();
+        app.add_event::<PuzzleSolvedEvent>();
+        app.init_resource::<GameState>();
+        app.world_mut().resource_mut::<GameState>().game_mode = GameMode::Playing;
+        app.add_systems(Update, puzzle_interaction_system);
+
+        let lever = app.world_mut().spawn_empty().id();
+        let puzzle_entity = app
+            .world_mut()
+            .spawn((
+                Puzzle::LeverCombination(LeverCombinationPuzzle {
+                    levers: vec![lever],
+                    correct_states: vec![LeverState::Up],
+                }),
+                PuzzleState::Unsolved,
+                PuzzleReward::UnlockDoor(1),
+            ))
+            .id();
+
+        app.world_mut().send_event(PuzzleInteractEvent {
+            puzzle: puzzle_entity,
+        });
+        app.update();
+
+        assert_eq!(
+            app.world().get::<PuzzleState>(puzzle_entity),
+            Some(&PuzzleState::Unsolved)
+        );
+    }
+
+    #[test]
+    fn solving_prerequisite_unlocks_dependent_puzzle() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, GameSetPlugin, PuzzlePlugin));
+        app.world_mut().resource_mut::<GameState>().game_mode = GameMode::Playing;
+
+        let breaker = app
+            .world_mut()
+            .spawn((
+                Puzzle::SymbolMatch(SymbolMatchPuzzle {
+                    input_sequence: vec![Symbol::Star],
+                    correct_sequence: vec![Symbol::Star],
+                }),
+                PuzzleState::Unsolved,
+                PuzzleReward::UnlockDoor(1),
+                PuzzleNode(1),
+            ))
+            .id();
+        let panel = app
+            .world_mut()
+            .spawn((
+                Puzzle::SymbolMatch(SymbolMatchPuzzle {
+                    input_sequence: vec![Symbol::Circle],
+                    correct_sequence: vec![Symbol::Circle],
+                }),
+                PuzzleState::Unsolved,
+                PuzzleReward::UnlockDoor(2),
+                PuzzleNode(2),
+                PuzzleDependency { requires: vec![1] },
+            ))
+            .id();
+        app.update();
+        assert!(app.world().get::<PuzzleLocked>(panel).is_some());
+
+        // A locked puzzle can't be solved even with the right input
+        app.world_mut()
+            .send_event(PuzzleInteractEvent { puzzle: panel });
+        app.update();
+        assert_eq!(
+            *app.world().get::<PuzzleState>(panel).unwrap(),
+            PuzzleState::Unsolved
+        );
+
+        app.world_mut()
+            .send_event(PuzzleInteractEvent { puzzle: breaker });
+        app.update();
+        assert!(app.world().get::<PuzzleLocked>(panel).is_none());
+        let unlocked: Vec<PuzzleUnlockedEvent> = app
+            .world_mut()
+            .resource_mut::<Events<PuzzleUnlockedEvent>>()
+            .drain()
+            .collect();
+        assert_eq!(unlocked, vec![PuzzleUnlockedEvent { puzzle: 2 }]);
+
+        app.world_mut()
+            .send_event(PuzzleInteractEvent { puzzle: panel });
+        app.update();
+        assert_eq!(
+            *app.world().get::<PuzzleState>(panel).unwrap(),
+            PuzzleState::Solved
+        );
+    }
 }
@@ -0,0 +1,483 @@
+use bevy::prelude::*;
+use leafwing_input_manager::prelude::*;
+
+use crate::audio::sound_bank::{PlaySoundEvent, SoundCue};
+use crate::components::inventory::Inventory;
+use crate::components::player::Player;
+use crate::components::puzzle::*;
+use crate::resources::game_state::{GameMode, GameState};
+use crate::resources::input_config::PlayerAction;
+use crate::resources::input_context::{InputContextStack, InputPermission};
+use crate::systems::game_set::GameSet;
+use crate::systems::puzzle::{LEVER_INTERACT_RANGE, PuzzleInteractEvent, flip_lever, lever_puzzle};
+use crate::ui::puzzle_panel::{PuzzlePanel, SymbolInput, enter_symbol, place_fuse, remove_fuse};
+
+/// Number of inputs a puzzle remembers for undo unless given its own limit
+pub const DEFAULT_UNDO_LIMIT: usize = 20;
+
+/// Plugin applying puzzle inputs as commands that can be undone
+///
+/// Symbol entries, fuse placements and lever flips are sent as
+/// `PuzzleCommandEvent`s. Puzzles with a `PuzzleHistory` remember how to
+/// reverse them, and `PlayerAction::Undo` (Z) steps back one input. Undo
+/// presses are read in `GameSet::Input` and commands applied in
+/// `GameSet::Gameplay`.
+pub struct PuzzleUndoPlugin;
+
+impl Plugin for PuzzleUndoPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<PuzzleCommandEvent>()
+            .add_event::<UndoPuzzleEvent>()
+            .add_event::<PuzzleInteractEvent>()
+            .add_event::<PlaySoundEvent>()
+            .add_systems(
+                Update,
+                (
+                    puzzle_undo_input_system.in_set(GameSet::Input),
+                    puzzle_command_system.in_set(GameSet::Gameplay),
+                )
+                    .chain(),
+            );
+    }
+}
+
+/// A single reversible puzzle input
+#[derive(Debug, Clone, PartialEq)]
+pub enum PuzzleCommand {
+    /// Enter a symbol into a symbol-match puzzle
+    EnterSymbol(Symbol),
+    /// Replace a symbol-match puzzle's input (reset, or undoing an entry)
+    SetSymbols(Vec<Symbol>),
+    /// Move a fuse from the player's inventory into a breaker slot
+    PlaceFuse(usize),
+    /// Return a breaker slot's fuse to the player's inventory
+    RemoveFuse(usize),
+    /// Flip a lever of a lever combination puzzle
+    FlipLever(Entity),
+}
+
+/// Outcome of applying a `PuzzleCommand`
+#[derive(Debug, Clone, PartialEq)]
+pub struct AppliedCommand {
+    /// Command that reverses the applied one
+    pub inverse: PuzzleCommand,
+    /// True if a symbol broke the sequence (and the input was cleared)
+    pub wrong: bool,
+}
+
+impl PuzzleCommand {
+    /// Applies the command to `puzzle`
+    ///
+    /// # Returns
+    /// How to reverse it, or `None` if the command doesn't fit the puzzle or
+    /// can't be carried out (no fuse to place, no room to take one back)
+    pub fn apply(
+        &self,
+        commands: &mut Commands,
+        puzzle: &mut Puzzle,
+        levers: &mut Query<&mut LeverState>,
+        inventory: Option<&mut Inventory>,
+    ) -> Option<AppliedCommand> {
+        let applied = |inverse| AppliedCommand {
+            inverse,
+            wrong: false,
+        };
+
+        match (self, puzzle) {
+            (PuzzleCommand::EnterSymbol(symbol), Puzzle::SymbolMatch(symbols)) => {
+                let before = symbols.input_sequence.clone();
+                let wrong = enter_symbol(symbols, *symbol) == SymbolInput::Wrong;
+                Some(AppliedCommand {
+                    inverse: PuzzleCommand::SetSymbols(before),
+                    wrong,
+                })
+            }
+            (PuzzleCommand::SetSymbols(input), Puzzle::SymbolMatch(symbols)) => {
+                let before = std::mem::replace(&mut symbols.input_sequence, input.clone());
+                Some(applied(PuzzleCommand::SetSymbols(before)))
+            }
+            (PuzzleCommand::PlaceFuse(slot), Puzzle::CircuitBreaker(breaker)) => {
+                place_fuse(commands, breaker, *slot, inventory?)
+                    .then(|| applied(PuzzleCommand::RemoveFuse(*slot)))
+            }
+            (PuzzleCommand::RemoveFuse(slot), Puzzle::CircuitBreaker(breaker)) => {
+                remove_fuse(commands, breaker, *slot, inventory?)
+                    .then(|| applied(PuzzleCommand::PlaceFuse(*slot)))
+            }
+            (PuzzleCommand::FlipLever(lever), Puzzle::LeverCombination(combination))
+                if combination.levers.contains(lever) =>
+            {
+                let mut state = levers.get_mut(*lever).ok()?;
+                flip_lever(commands, *lever, &mut state);
+                Some(applied(PuzzleCommand::FlipLever(*lever)))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Event requesting a puzzle input
+#[derive(Event, Debug, Clone, PartialEq)]
+pub struct PuzzleCommandEvent {
+    /// Puzzle the input is for
+    pub puzzle: Entity,
+    /// Input to apply
+    pub command: PuzzleCommand,
+}
+
+/// Event requesting that a puzzle's last input is undone
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UndoPuzzleEvent {
+    /// Puzzle to step back
+    pub puzzle: Entity,
+}
+
+/// Component enabling undo for a puzzle
+///
+/// Holds the inverses of the puzzle's latest inputs, newest last. Puzzles
+/// without it can't be undone.
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct PuzzleHistory {
+    /// Most inputs remembered; older ones are forgotten
+    pub limit: usize,
+    /// Commands reversing the remembered inputs, newest last
+    pub undo: Vec<PuzzleCommand>,
+}
+
+impl Default for PuzzleHistory {
+    fn default() -> Self {
+        Self::with_limit(DEFAULT_UNDO_LIMIT)
+    }
+}
+
+impl PuzzleHistory {
+    /// Creates an empty history remembering up to `limit` inputs
+    pub fn with_limit(limit: usize) -> Self {
+        Self {
+            limit,
+            undo: Vec::new(),
+        }
+    }
+
+    /// Remembers how to reverse an input, forgetting the oldest past the limit
+    pub fn record(&mut self, inverse: PuzzleCommand) {
+        self.undo.push(inverse);
+        if self.undo.len() > self.limit {
+            let excess = self.undo.len() - self.limit;
+            self.undo.drain(..excess);
+        }
+    }
+
+    /// Returns true if there's an input to undo
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+}
+
+/// System that undoes a puzzle input when the player presses Undo (Z)
+///
+/// # System Dependencies
+/// - **Components**: Reads `Player`, `ActionState<PlayerAction>`,
+///   `LeverState`, `Puzzle`
+/// - **Resources**: Reads `GameState`, `PuzzlePanel` and
+///   `InputContextStack` (when present)
+/// - **Downstream**: `puzzle_command_system` reads `UndoPuzzleEvent`
+///
+/// # Behavior
+/// With the puzzle panel open, its puzzle is stepped back. Otherwise, while
+/// playing, the puzzle of the nearest lever within `LEVER_INTERACT_RANGE`
+/// is.
+#[allow(clippy::type_complexity)]
+pub fn puzzle_undo_input_system(
+    game_state: Res<GameState>,
+    input: Option<Res<InputContextStack>>,
+    panel: Option<Res<PuzzlePanel>>,
+    player_query: Query<(&Transform, &ActionState<PlayerAction>), With<Player>>,
+    lever_query: Query<(Entity, &Transform), (With<LeverState>, Without<Player>)>,
+    puzzle_query: Query<(Entity, &Puzzle)>,
+    mut undo_events: EventWriter<UndoPuzzleEvent>,
+) {
+    let allows = |permission| input.as_ref().is_none_or(|input| input.allows(permission));
+
+    for (player_transform, actions) in &player_query {
+        if !actions.just_pressed(&PlayerAction::Undo) {
+            continue;
+        }
+
+        if let Some(puzzle) = panel.as_ref().and_then(|panel| panel.puzzle) {
+            if allows(InputPermission::PuzzleInput) {
+                undo_events.write(UndoPuzzleEvent { puzzle });
+            }
+            continue;
+        }
+
+        if game_state.game_mode != GameMode::Playing || !allows(InputPermission::Interact) {
+            continue;
+        }
+        let player_pos = player_transform.translation.truncate();
+        let puzzle = lever_query
+            .iter()
+            .map(|(lever, transform)| {
+                (lever, transform.translation.truncate().distance(player_pos))
+            })
+            .filter(|(_, distance)| *distance <= LEVER_INTERACT_RANGE)
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .and_then(|(lever, _)| lever_puzzle(&puzzle_query, lever));
+        if let Some(puzzle) = puzzle {
+            undo_events.write(UndoPuzzleEvent { puzzle });
+        }
+    }
+}
+
+/// System that applies puzzle commands and undoes them
+///
+/// # System Dependencies
+/// - **Upstream**: `puzzle_panel_system` and `lever_interaction_system`
+///   emit `PuzzleCommandEvent`; `puzzle_panel_system` and
+///   `puzzle_undo_input_system` emit `UndoPuzzleEvent`
+/// - **Components**: Writes `Puzzle`, `PuzzleHistory`, `LeverState` and the
+///   player's `Inventory`; reads `PuzzleState`
+/// - **Resources**: Writes `PuzzlePanel` feedback (when present)
+/// - **Downstream**: `puzzle_interaction_system` reads `PuzzleInteractEvent`;
+///   `play_sound_requests` plays the confirm/cancel/error cues
+///
+/// # Behavior
+/// Solved puzzles take no more input. Each applied command's inverse is
+/// recorded in the puzzle's `PuzzleHistory` (if it has one); an undo
+/// applies the newest inverse instead. Every change emits
+/// `PuzzleInteractEvent` so the puzzle is validated again (for a lever,
+/// every lever puzzle using it is).
+#[allow(clippy::too_many_arguments)]
+pub fn puzzle_command_system(
+    mut commands: Commands,
+    mut command_events: EventReader<PuzzleCommandEvent>,
+    mut undo_events: EventReader<UndoPuzzleEvent>,
+    mut panel: Option<ResMut<PuzzlePanel>>,
+    mut puzzle_query: Query<(
+        Entity,
+        &mut Puzzle,
+        &PuzzleState,
+        Option<&mut PuzzleHistory>,
+    )>,
+    mut lever_query: Query<&mut LeverState>,
+    mut inventory_query: Query<&mut Inventory, With<Player>>,
+    mut interact_events: EventWriter<PuzzleInteractEvent>,
+    mut sound_events: EventWriter<PlaySoundEvent>,
+) {
+    let requests: Vec<(Entity, Option<PuzzleCommand>)> = command_events
+        .read()
+        .map(|event| (event.puzzle, Some(event.command.clone())))
+        .chain(undo_events.read().map(|event| (event.puzzle, None)))
+        .collect();
+
+    for (entity, requested) in requests {
+        let Ok((_, mut puzzle, state, mut history)) = puzzle_query.get_mut(entity) else {
+            continue;
+        };
+        if *state == PuzzleState::Solved {
+            continue;
+        }
+
+        let undoing = requested.is_none();
+        let Some(command) =
+            requested.or_else(|| history.as_mut().and_then(|history| history.undo.pop()))
+        else {
+            continue;
+        };
+
+        let mut inventory = inventory_query.single_mut().ok();
+        let applied = command.apply(
+            &mut commands,
+            &mut puzzle,
+            &mut lever_query,
+            inventory.as_deref_mut(),
+        );
+        let Some(applied) = applied else {
+            // An undo that can't be carried out yet stays for another try
+            if undoing && let Some(history) = history.as_mut() {
+                history.undo.push(command);
+            }
+            sound_events.write(PlaySoundEvent {
+                cue: SoundCue::UiError,
+            });
+            continue;
+        };
+        if !undoing && let Some(history) = history.as_mut() {
+            history.record(applied.inverse);
+        }
+
+        if let Some(panel) = panel.as_mut() {
+            panel.feedback = applied
+                .wrong
+                .then(|| "The symbols fade. That was wrong.".to_string());
+        }
+        let cue = match command {
+            _ if applied.wrong => Some(SoundCue::UiError),
+            _ if undoing => Some(SoundCue::UiCancel),
+            PuzzleCommand::SetSymbols(_) => Some(SoundCue::UiCancel),
+            PuzzleCommand::FlipLever(_) => None,
+            _ => Some(SoundCue::UiConfirm),
+        };
+        if let Some(cue) = cue {
+            sound_events.write(PlaySoundEvent { cue });
+        }
+
+        if let PuzzleCommand::FlipLever(lever) = command {
+            for (puzzle, kind, _, _) in &puzzle_query {
+                if let Puzzle::LeverCombination(combination) = kind
+                    && combination.levers.contains(&lever)
+                {
+                    interact_events.write(PuzzleInteractEvent { puzzle });
+                }
+            }
+        } else {
+            interact_events.write(PuzzleInteractEvent { puzzle: entity });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::inventory::{Item, PuzzleItemType};
+
+    fn undo_app() -> App {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, PuzzleUndoPlugin));
+        app.init_resource::<GameState>();
+        app.world_mut().resource_mut::<GameState>().game_mode = GameMode::Playing;
+        app.init_resource::<PuzzlePanel>();
+        app
+    }
+
+    fn symbol_puzzle(app: &mut App, history: Option<PuzzleHistory>) -> Entity {
+        let mut puzzle = app.world_mut().spawn((
+            Puzzle::SymbolMatch(SymbolMatchPuzzle {
+                input_sequence: vec![],
+                correct_sequence: vec![Symbol::Star, Symbol::Circle, Symbol::Square],
+            }),
+            PuzzleState::Unsolved,
+        ));
+        if let Some(history) = history {
+            puzzle.insert(history);
+        }
+        puzzle.id()
+    }
+
+    fn entered(app: &App, puzzle: Entity) -> Vec<Symbol> {
+        match app.world().get::<Puzzle>(puzzle) {
+            Some(Puzzle::SymbolMatch(symbols)) => symbols.input_sequence.clone(),
+            _ => panic!("Not a symbol puzzle"),
+        }
+    }
+
+    fn send(app: &mut App, puzzle: Entity, command: PuzzleCommand) {
+        app.world_mut()
+            .send_event(PuzzleCommandEvent { puzzle, command });
+        app.update();
+    }
+
+    #[test]
+    fn undo_restores_input_cleared_by_a_wrong_symbol() {
+        let mut app = undo_app();
+        let puzzle = symbol_puzzle(&mut app, Some(PuzzleHistory::default()));
+
+        send(&mut app, puzzle, PuzzleCommand::EnterSymbol(Symbol::Star));
+        send(&mut app, puzzle, PuzzleCommand::EnterSymbol(Symbol::Circle));
+        send(&mut app, puzzle, PuzzleCommand::EnterSymbol(Symbol::Star));
+        assert!(
+            entered(&app, puzzle).is_empty(),
+            "Wrong symbol clears input"
+        );
+        assert!(app.world().resource::<PuzzlePanel>().feedback.is_some());
+
+        app.world_mut().send_event(UndoPuzzleEvent { puzzle });
+        app.update();
+        assert_eq!(entered(&app, puzzle), vec![Symbol::Star, Symbol::Circle]);
+        assert!(app.world().resource::<PuzzlePanel>().feedback.is_none());
+
+        app.world_mut().send_event(UndoPuzzleEvent { puzzle });
+        app.update();
+        assert_eq!(entered(&app, puzzle), vec![Symbol::Star]);
+    }
+
+    #[test]
+    fn puzzles_without_history_ignore_undo() {
+        let mut app = undo_app();
+        let puzzle = symbol_puzzle(&mut app, None);
+
+        send(&mut app, puzzle, PuzzleCommand::EnterSymbol(Symbol::Star));
+        app.world_mut().send_event(UndoPuzzleEvent { puzzle });
+        app.update();
+
+        assert_eq!(entered(&app, puzzle), vec![Symbol::Star]);
+    }
+
+    #[test]
+    fn history_forgets_inputs_past_its_limit() {
+        let mut app = undo_app();
+        let puzzle = symbol_puzzle(&mut app, Some(PuzzleHistory::with_limit(2)));
+
+        send(&mut app, puzzle, PuzzleCommand::EnterSymbol(Symbol::Star));
+        send(&mut app, puzzle, PuzzleCommand::EnterSymbol(Symbol::Circle));
+        send(&mut app, puzzle, PuzzleCommand::SetSymbols(vec![]));
+        let history = app.world().get::<PuzzleHistory>(puzzle).unwrap();
+        assert_eq!(history.undo.len(), 2);
+
+        for _ in 0..3 {
+            app.world_mut().send_event(UndoPuzzleEvent { puzzle });
+            app.update();
+        }
+        assert_eq!(
+            entered(&app, puzzle),
+            vec![Symbol::Star],
+            "The first entry was forgotten"
+        );
+    }
+
+    #[test]
+    fn undoing_a_fuse_returns_it_to_the_inventory() {
+        let mut app = undo_app();
+        let puzzle = app
+            .world_mut()
+            .spawn((
+                Puzzle::CircuitBreaker(CircuitBreakerPuzzle {
+                    fuse_slots: vec![None, None],
+                    correct_sequence: vec![0, 1],
+                }),
+                PuzzleState::Unsolved,
+                PuzzleHistory::default(),
+            ))
+            .id();
+        let player = app
+            .world_mut()
+            .spawn((
+                Player,
+                Inventory {
+                    items: vec![Item::PuzzleItem(PuzzleItemType::Fuse)],
+                    max_capacity: 10,
+                },
+            ))
+            .id();
+
+        send(&mut app, puzzle, PuzzleCommand::PlaceFuse(1));
+        assert!(
+            app.world()
+                .get::<Inventory>(player)
+                .unwrap()
+                .items
+                .is_empty()
+        );
+
+        app.world_mut().send_event(UndoPuzzleEvent { puzzle });
+        app.update();
+        let Some(Puzzle::CircuitBreaker(breaker)) = app.world().get::<Puzzle>(puzzle) else {
+            panic!("Not a breaker");
+        };
+        assert!(breaker.fuse_slots.iter().all(Option::is_none));
+        assert!(matches!(
+            app.world().get::<Inventory>(player).unwrap().items[..],
+            [Item::PuzzleItem(PuzzleItemType::Fuse)]
+        ));
+    }
+}
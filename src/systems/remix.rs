@@ -0,0 +1,560 @@
+use bevy::prelude::*;
+use rand::seq::SliceRandom;
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use crate::components::inventory::{Item, KeyType};
+use crate::components::room::RoomId;
+use crate::resources::game_rng::GameRng;
+use crate::systems::daily::{DAILY_REMIX_CYCLE, DailyChallenge, daily_seed};
+use crate::systems::level_check::{LEVELS_DIR, check_key_reachability};
+use crate::systems::level_loader::{LevelData, TRAP_ENTITY_TYPES, load_level_file};
+use crate::systems::save_load::{SerializedItem, deserialize_item, serialize_item};
+use crate::systems::tilemap::{DEFAULT_TILE_SIZE, WALL_TILE};
+use crate::systems::victory::NewGamePlus;
+
+/// Shuffles tried before a remix gives up and keeps the authored placement
+pub const REMIX_ATTEMPTS: usize = 200;
+
+/// Extra traps and director hazards per New Game+ cycle (0.5 places 50%
+/// more of a room's traps, and allows 50% more director spawns, 50% more
+/// often, each cycle)
+pub const NEW_GAME_PLUS_TRAP_STEP: f32 = 0.5;
+
+/// Closest (pixels) an extra New Game+ trap is placed to another entity or
+/// a connection
+pub const TRAP_CLEARANCE: f32 = 64.0;
+
+/// Plugin that remixes the house for New Game+ runs and the Daily Escape
+///
+/// Whenever the New Game+ cycle or the Daily Escape changes (a new cycle or
+/// daily starts, or a save is loaded), the house's keys and hidden items
+/// are reshuffled from the run's seed, so a save always reloads the same
+/// remix. The remix is
+/// kept in `ActiveRemix`, which rooms loaded through
+/// `level_loader::load_room_level` afterwards use.
+pub struct RemixPlugin;
+
+impl Plugin for RemixPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<NewGamePlus>()
+            .init_resource::<DailyChallenge>()
+            .init_resource::<GameRng>()
+            .add_systems(Update, remix_house_system);
+    }
+}
+
+/// Key and item placement and trap density of a New Game+ house
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct HouseRemix {
+    /// New Game+ cycle the remix is for
+    pub cycle: u32,
+    /// Item placed at each pickup, by room and entity index
+    pub placements: HashMap<(RoomId, usize), SerializedItem>,
+    /// Extra traps placed in each room
+    pub traps: HashMap<RoomId, Vec<TrapCopy>>,
+}
+
+/// An extra trap of a remix: a copy of one of the room's authored traps
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TrapCopy {
+    /// Entity index of the authored trap copied
+    pub source: usize,
+    /// Position (x, y) of the copy in world space
+    pub position: (f32, f32),
+}
+
+impl HouseRemix {
+    /// Remixes `levels` for New Game+ `cycle` with a stream seeded by `seed`
+    ///
+    /// Loose keys and the items hidden in breakables trade places at
+    /// random, keys anywhere and other items only into breakables. A
+    /// shuffle is kept only if `check_key_reachability` finds no problem
+    /// at all, so no key ends up behind its own door; after
+    /// `REMIX_ATTEMPTS` failed shuffles, or if the authored house already
+    /// has problems, the authored placement is kept.
+    ///
+    /// Each room with traps gets `trap_density` times as many, the extra
+    /// ones copies of its traps on free floor tiles (at least
+    /// `TRAP_CLEARANCE` from the room's entities and connections).
+    pub fn new(levels: &[LevelData], seed: u64, cycle: u32) -> Self {
+        let mut rng = GameRng::from_seed(remix_seed(seed, cycle));
+        let placements = shuffle_pickups(levels, &mut rng);
+        let traps = levels
+            .iter()
+            .map(|level| (level.id, trap_copies(level, cycle, &mut rng)))
+            .filter(|(_, copies)| !copies.is_empty())
+            .collect();
+        Self {
+            cycle,
+            placements,
+            traps,
+        }
+    }
+
+    /// Remixes the level files in `dir` (see `HouseRemix::new`)
+    ///
+    /// Files that can't be loaded are left out of the remix.
+    pub fn for_level_dir(dir: &Path, seed: u64, cycle: u32) -> Self {
+        let mut paths: Vec<_> = fs::read_dir(dir)
+            .into_iter()
+            .flatten()
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| path.extension().is_some_and(|extension| extension == "ron"))
+            .collect();
+        paths.sort();
+
+        let levels: Vec<LevelData> = paths
+            .iter()
+            .filter_map(|path| load_level_file(&path.to_string_lossy()).ok())
+            .collect();
+        Self::new(&levels, seed, cycle)
+    }
+
+    /// Applies the remix to `level`: moves its pickups, adds its extra
+    /// traps and raises its director budget by `trap_density`
+    pub fn apply(&self, level: &mut LevelData) {
+        for (index, spawn) in level.entities.iter_mut().enumerate() {
+            let Some(item) = self.placements.get(&(level.id, index)) else {
+                continue;
+            };
+            if spawn.entity_type == "Key" {
+                spawn.key_type = item_key(item);
+            } else if let Some(breakable) = spawn.breakable.as_mut() {
+                breakable.contains = Some(item.clone());
+            }
+        }
+
+        let copies: Vec<_> = self
+            .traps
+            .get(&level.id)
+            .into_iter()
+            .flatten()
+            .filter_map(|copy| {
+                let mut trap = level.entities.get(copy.source)?.clone();
+                trap.position = copy.position;
+                Some(trap)
+            })
+            .collect();
+        level.entities.extend(copies);
+
+        if let Some(budget) = level.director.as_mut() {
+            let density = trap_density(self.cycle);
+            budget.max_spawns = (budget.max_spawns as f32 * density).ceil() as u32;
+            budget.cooldown /= density;
+        }
+    }
+}
+
+/// Resource holding the remix rooms are loaded with
+///
+/// Inserted when a New Game+ cycle or a Daily Escape starts, and removed
+/// when a run goes back to the authored house.
+#[derive(Resource, Debug, Clone, Default, PartialEq)]
+pub struct ActiveRemix(pub HouseRemix);
+
+/// Traps a New Game+ `cycle` places for each authored one
+pub fn trap_density(cycle: u32) -> f32 {
+    1.0 + NEW_GAME_PLUS_TRAP_STEP * cycle as f32
+}
+
+/// System that remixes the house when the New Game+ cycle or the Daily
+/// Escape changes
+///
+/// # System Dependencies
+/// - **Upstream**: `new_game_plus_system` starts a cycle; `daily_start_system`
+///   starts a Daily Escape; `load_game_system` restores the cycle, daily
+///   day and `GameRng` seed of a save
+/// - **Resources**: Reads `NewGamePlus`, `DailyChallenge` and `GameRng`;
+///   inserts or removes `ActiveRemix`
+/// - **Downstream**: Rooms loaded afterwards use the remix
+///
+/// # Behavior
+/// A Daily Escape remixes the level files in `LEVELS_DIR` with the day's
+/// seed at `DAILY_REMIX_CYCLE`. Otherwise cycle 0 (a first run) plays the
+/// authored house and later cycles remix with the run's seed.
+pub fn remix_house_system(
+    mut commands: Commands,
+    new_game_plus: Res<NewGamePlus>,
+    daily: Res<DailyChallenge>,
+    rng: Res<GameRng>,
+) {
+    if !new_game_plus.is_changed() && !daily.is_changed() {
+        return;
+    }
+
+    let (seed, cycle) = match daily.day {
+        Some(day) => (daily_seed(day), DAILY_REMIX_CYCLE),
+        None if new_game_plus.cycle > 0 => (rng.seed(), new_game_plus.cycle),
+        None => {
+            commands.remove_resource::<ActiveRemix>();
+            return;
+        }
+    };
+    let remix = HouseRemix::for_level_dir(Path::new(LEVELS_DIR), seed, cycle);
+    info!(
+        "Remixed {} pickups and added {} traps (seed {:016x}, cycle {})",
+        remix.placements.len(),
+        remix.traps.values().map(Vec::len).sum::<usize>(),
+        seed,
+        remix.cycle
+    );
+    commands.insert_resource(ActiveRemix(remix));
+}
+
+/// Shuffles the pickups of `levels` (see `HouseRemix::new`)
+///
+/// # Returns
+/// The item placed at each pickup, or nothing to keep the authored placement
+fn shuffle_pickups(
+    levels: &[LevelData],
+    rng: &mut GameRng,
+) -> HashMap<(RoomId, usize), SerializedItem> {
+    let slots: Vec<PickupSlot> = levels.iter().flat_map(pickup_slots).collect();
+    if slots.len() < 2 {
+        return HashMap::new();
+    }
+    let authored_problems = check_key_reachability(levels);
+    if !authored_problems.is_empty() {
+        warn!(
+            "Not remixing a house with key problems: {}",
+            authored_problems.join("; ")
+        );
+        return HashMap::new();
+    }
+
+    let mut items: Vec<SerializedItem> = slots.iter().map(|slot| slot.item.clone()).collect();
+    for _ in 0..REMIX_ATTEMPTS {
+        items.shuffle(rng);
+        let fits = slots
+            .iter()
+            .zip(&items)
+            .all(|(slot, item)| slot.hidden || item_key(item).is_some());
+        if !fits {
+            continue;
+        }
+
+        let remix = HouseRemix {
+            placements: slots
+                .iter()
+                .zip(&items)
+                .map(|(slot, item)| ((slot.room, slot.index), item.clone()))
+                .collect(),
+            ..default()
+        };
+        let mut remixed = levels.to_vec();
+        for level in &mut remixed {
+            remix.apply(level);
+        }
+        if check_key_reachability(&remixed).is_empty() {
+            return remix.placements;
+        }
+    }
+
+    warn!(
+        "No reachable remix found in {} shuffles; keeping the authored placement",
+        REMIX_ATTEMPTS
+    );
+    HashMap::new()
+}
+
+/// Extra traps of `level` in New Game+ `cycle` (see `HouseRemix::new`)
+fn trap_copies(level: &LevelData, cycle: u32, rng: &mut GameRng) -> Vec<TrapCopy> {
+    let sources: Vec<usize> = level
+        .entities
+        .iter()
+        .enumerate()
+        .filter(|(_, spawn)| TRAP_ENTITY_TYPES.contains(&spawn.entity_type.as_str()))
+        .map(|(index, _)| index)
+        .collect();
+    let wanted = (sources.len() as f32 * trap_density(cycle)).ceil() as usize - sources.len();
+    if wanted == 0 {
+        return Vec::new();
+    }
+
+    let tile = DEFAULT_TILE_SIZE.x;
+    let (min, max) = (level.bounds.min, level.bounds.max);
+    let columns = ((max.0 - min.0) / tile) as usize;
+    let rows = ((max.1 - min.1) / tile) as usize;
+    let clear = |position: (f32, f32)| {
+        let position = Vec2::from(position);
+        level
+            .entities
+            .iter()
+            .map(|spawn| spawn.position)
+            .chain(
+                level
+                    .connections
+                    .iter()
+                    .map(|connection| connection.position),
+            )
+            .all(|other| position.distance(Vec2::from(other)) >= TRAP_CLEARANCE)
+    };
+    // Floor tiles inside the outer walls
+    let mut free: Vec<(f32, f32)> = (1..rows.saturating_sub(1))
+        .flat_map(|row| (1..columns.saturating_sub(1)).map(move |column| (column, row)))
+        .filter(|&(column, row)| {
+            level
+                .tiles
+                .get(row)
+                .and_then(|tiles| tiles.get(column))
+                .is_none_or(|&index| index != WALL_TILE)
+        })
+        .map(|(column, row)| {
+            (
+                min.0 + (column as f32 + 0.5) * tile,
+                min.1 + (row as f32 + 0.5) * tile,
+            )
+        })
+        .filter(|&position| clear(position))
+        .collect();
+    free.shuffle(rng);
+
+    sources
+        .iter()
+        .cycle()
+        .zip(free)
+        .take(wanted)
+        .map(|(&source, position)| TrapCopy { source, position })
+        .collect()
+}
+
+/// A pickup whose item a remix may move
+struct PickupSlot {
+    room: RoomId,
+    index: usize,
+    /// Hidden in a breakable (any item) rather than lying loose (keys only)
+    hidden: bool,
+    item: SerializedItem,
+}
+
+fn pickup_slots(level: &LevelData) -> Vec<PickupSlot> {
+    let mut slots = Vec::new();
+    for (index, spawn) in level.entities.iter().enumerate() {
+        let (hidden, item) = if spawn.entity_type == "Key" {
+            match spawn.key_type {
+                Some(key) => (false, serialize_item(&Item::Key(key))),
+                None => continue,
+            }
+        } else {
+            match spawn
+                .breakable
+                .as_ref()
+                .and_then(|breakable| breakable.contains.clone())
+            {
+                Some(item) => (true, item),
+                None => continue,
+            }
+        };
+        slots.push(PickupSlot {
+            room: level.id,
+            index,
+            hidden,
+            item,
+        });
+    }
+    slots
+}
+
+fn item_key(item: &SerializedItem) -> Option<KeyType> {
+    match deserialize_item(item) {
+        Item::Key(key) => Some(key),
+        _ => None,
+    }
+}
+
+/// Seed of the remix stream for `cycle` of a run seeded `seed`
+fn remix_seed(seed: u64, cycle: u32) -> u64 {
+    seed ^ u64::from(cycle).wrapping_mul(0x9E37_79B9_7F4A_7C15)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::systems::level_loader::{parse_level_data, validate_level_data};
+
+    fn level(id: usize, body: &str) -> LevelData {
+        parse_level_data(
+            &format!(
+                "(id: {}, floor: Ground, name: \"Room {}\", \
+                 bounds: (min: (0.0, 0.0), max: (640.0, 480.0)), tiles: [], {})",
+                id, id, body
+            ),
+            "test",
+        )
+        .unwrap()
+    }
+
+    fn house() -> Vec<LevelData> {
+        let door = |target: usize, lock: &str| {
+            format!(
+                "(target_room: {}, connection_type: Door, position: (0.0, 0.0), locked: {})",
+                target, lock
+            )
+        };
+        vec![
+            level(
+                0,
+                &format!(
+                    r#"connections: [{}, {}],
+                    entities: [
+                        (entity_type: "PlayerSpawn", position: (10.0, 10.0)),
+                        (entity_type: "Key", position: (20.0, 10.0), key_type: Some(Brass)),
+                        (entity_type: "Crate", position: (30.0, 10.0),
+                         breakable: Some((contains: Some(Tool(Crowbar))))),
+                    ]"#,
+                    door(1, "Some(Brass)"),
+                    door(2, "None")
+                ),
+            ),
+            level(
+                1,
+                &format!(
+                    r#"connections: [{}],
+                    entities: [
+                        (entity_type: "Key", position: (20.0, 10.0), key_type: Some(Iron)),
+                    ]"#,
+                    door(0, "None")
+                ),
+            ),
+            level(
+                2,
+                &format!(
+                    r#"connections: [{}],
+                    entities: [
+                        (entity_type: "Crate", position: (30.0, 10.0),
+                         breakable: Some((contains: Some(Match)))),
+                        (entity_type: "Spikes", position: (320.0, 240.0)),
+                    ],
+                    director: Some((max_spawns: 2, cooldown: 30.0, spawn_points: []))"#,
+                    door(0, "None")
+                ),
+            ),
+        ]
+    }
+
+    #[test]
+    fn remixes_keep_every_key_reachable_and_only_keys_lie_loose() {
+        let levels = house();
+        for seed in 0..20 {
+            let remix = HouseRemix::new(&levels, seed, 1);
+            assert_eq!(
+                remix,
+                HouseRemix::new(&levels, seed, 1),
+                "Same seed, same remix"
+            );
+            assert_eq!(remix.placements.len(), 4);
+
+            let mut remixed = levels.clone();
+            for level in &mut remixed {
+                remix.apply(level);
+            }
+            assert!(check_key_reachability(&remixed).is_empty(), "Seed {}", seed);
+            // The brass key can't be locked behind the brass door
+            assert_ne!(
+                remix.placements[&(1, 0)],
+                SerializedItem::Key(crate::systems::save_load::SerializedKeyType::Brass)
+            );
+            for level in &remixed {
+                for spawn in level
+                    .entities
+                    .iter()
+                    .filter(|spawn| spawn.entity_type == "Key")
+                {
+                    assert!(spawn.key_type.is_some(), "Loose pickups stay keys");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn new_game_plus_cycles_raise_the_director_budget() {
+        let mut level = house().remove(2);
+        HouseRemix {
+            cycle: 2,
+            ..default()
+        }
+        .apply(&mut level);
+
+        let budget = level.director.unwrap();
+        assert_eq!(budget.max_spawns, 4);
+        assert_eq!(budget.cooldown, 15.0);
+    }
+
+    #[test]
+    fn houses_with_a_key_behind_its_own_door_are_not_remixed() {
+        let mut levels = house();
+        // The brass key lies behind the brass door
+        levels[0].entities[1].key_type = Some(KeyType::Iron);
+        levels[1].entities[0].key_type = Some(KeyType::Brass);
+
+        for seed in 0..5 {
+            assert!(HouseRemix::new(&levels, seed, 1).placements.is_empty());
+        }
+    }
+
+    #[test]
+    fn new_game_plus_cycles_place_more_traps() {
+        let levels = house();
+        for cycle in 1..=3 {
+            let remix = HouseRemix::new(&levels, 7, cycle);
+            assert!(
+                !remix.traps.contains_key(&0),
+                "Rooms without traps get none"
+            );
+
+            let mut level = levels[2].clone();
+            remix.apply(&mut level);
+            let traps: Vec<Vec2> = level
+                .entities
+                .iter()
+                .filter(|spawn| spawn.entity_type == "Spikes")
+                .map(|spawn| Vec2::from(spawn.position))
+                .collect();
+            assert_eq!(traps.len(), trap_density(cycle).ceil() as usize);
+            assert!(validate_level_data(&level).is_ok());
+            for extra in &traps[1..] {
+                for authored in &levels[2].entities {
+                    assert!(extra.distance(Vec2::from(authored.position)) >= TRAP_CLEARANCE);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn active_remix_follows_the_new_game_plus_cycle() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, RemixPlugin));
+        app.update();
+        assert!(!app.world().contains_resource::<ActiveRemix>());
+
+        app.world_mut().resource_mut::<NewGamePlus>().cycle = 1;
+        app.update();
+        assert_eq!(app.world().resource::<ActiveRemix>().0.cycle, 1);
+
+        // Going back to the authored house
+        app.world_mut().resource_mut::<NewGamePlus>().cycle = 0;
+        app.update();
+        assert!(!app.world().contains_resource::<ActiveRemix>());
+    }
+
+    #[test]
+    fn daily_escape_keeps_its_remix_on_load() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, RemixPlugin));
+        app.world_mut().resource_mut::<DailyChallenge>().day = Some(20_743);
+        app.update();
+        let daily = app.world().resource::<ActiveRemix>().clone();
+        assert_eq!(daily.0.cycle, DAILY_REMIX_CYCLE);
+
+        // Loading a save restores the (zero) cycle along with the day
+        app.world_mut().resource_mut::<NewGamePlus>().cycle = 0;
+        app.update();
+        assert_eq!(*app.world().resource::<ActiveRemix>(), daily);
+
+        app.world_mut().resource_mut::<DailyChallenge>().day = None;
+        app.update();
+        assert!(!app.world().contains_resource::<ActiveRemix>());
+    }
+}
@@ -0,0 +1,347 @@
+use crate::components::lighting::{Candle, CarriedCandle};
+use crate::components::player::{Player, Velocity};
+use crate::components::render_layer::{RenderLayer, SortOffset, YSort, y_sort_z};
+use crate::components::room::Collider;
+use bevy::prelude::*;
+use bevy::transform::TransformSystem;
+
+/// Z offset of a carried candle in front of its holder (less than a pixel
+/// of y-sorting, so furniture in front of the holder hides the candle too)
+pub const CARRIED_CANDLE_Z_OFFSET: f32 = 0.0001;
+
+/// Plugin that keeps sprites on their render layers and the candle in hand
+///
+/// Runs in `PostUpdate`, after gameplay has moved entities and before
+/// transforms are propagated, so nothing is drawn a frame late. The player's
+/// sort offset follows their collider before sorting, and the candle is
+/// carried after sorting, so it takes its holder's depth.
+pub struct RenderLayerPlugin;
+
+impl Plugin for RenderLayerPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            PostUpdate,
+            (
+                player_sort_offset_system,
+                render_layer_system,
+                candle_carry_system,
+            )
+                .chain()
+                .before(TransformSystem::TransformPropagate),
+        );
+    }
+}
+
+/// System that sorts the player by their feet
+///
+/// Sets the player's `SortOffset` to minus half their collider's height
+/// whenever the collider is added or resized, so the player walks in front
+/// of furniture whose base is above their feet even while their center is
+/// higher.
+///
+/// # System Dependencies
+/// - **Components**: Reads the `Player`'s `Collider`, writes `SortOffset`
+/// - **Downstream**: `render_layer_system` sorts by the new offset
+pub fn player_sort_offset_system(
+    mut query: Query<(&Collider, &mut SortOffset), (With<Player>, Changed<Collider>)>,
+) {
+    for (collider, mut offset) in &mut query {
+        let feet = -(collider.max.y - collider.min.y) / 2.0;
+        if offset.0 != feet {
+            offset.0 = feet;
+        }
+    }
+}
+
+/// System that snaps each entity's z to its render layer
+///
+/// Only entities whose layer, sort offset or transform changed this frame
+/// are touched, so systems that reset a whole translation (respawn, save
+/// loading) cannot leave a sprite on the wrong layer. `YSort` entities
+/// are placed at `y_sort_z` of their y plus `SortOffset` instead of their
+/// layer's z.
+///
+/// # System Dependencies
+/// - **Components**: Reads `RenderLayer`, `YSort`, `SortOffset`, writes
+///   `Transform`
+/// - **Downstream**: `candle_carry_system` draws carried candles in front of
+///   their holder's sorted z
+#[allow(clippy::type_complexity)]
+pub fn render_layer_system(
+    mut query: Query<
+        (
+            &RenderLayer,
+            &mut Transform,
+            Has<YSort>,
+            Option<&SortOffset>,
+        ),
+        Or<(
+            Changed<RenderLayer>,
+            Changed<Transform>,
+            Changed<SortOffset>,
+        )>,
+    >,
+) {
+    for (layer, mut transform, y_sorted, sort_offset) in &mut query {
+        let z = if y_sorted {
+            y_sort_z(transform.translation.y + sort_offset.map_or(0.0, |offset| offset.0))
+        } else {
+            layer.z()
+        };
+        if transform.translation.z != z {
+            transform.translation.z = z;
+        }
+    }
+}
+
+/// System that keeps each carried candle in its holder's hand
+///
+/// # System Dependencies
+/// - **Components**: Reads the holder's (or `Player`'s) `Transform` and
+///   `Velocity`, writes `CarriedCandle` and candle `Transform`
+///
+/// # Behavior
+/// - The candle follows its holder at `CarriedCandle::offset`; candles
+///   without a holder follow the player
+/// - Horizontal movement flips the candle to the side the holder faces;
+///   standing still keeps the last side
+/// - The candle is drawn `CARRIED_CANDLE_Z_OFFSET` in front of its holder
+/// - A candle whose holder is gone stays where it was
+pub fn candle_carry_system(
+    player_query: Query<Entity, With<Player>>,
+    holder_query: Query<(&Transform, Option<&Velocity>), Without<Candle>>,
+    mut candle_query: Query<(&mut CarriedCandle, &mut Transform), With<Candle>>,
+) {
+    let player = player_query.single().ok();
+
+    for (mut carried, mut transform) in &mut candle_query {
+        let Some(holder) = carried.holder.or(player) else {
+            continue;
+        };
+        let Ok((holder_transform, velocity)) = holder_query.get(holder) else {
+            continue;
+        };
+
+        if let Some(Velocity(velocity)) = velocity
+            && velocity.x != 0.0
+        {
+            let facing_left = velocity.x < 0.0;
+            if carried.facing_left != facing_left {
+                carried.facing_left = facing_left;
+            }
+        }
+
+        let mut offset = carried.offset;
+        if carried.facing_left {
+            offset.x = -offset.x;
+        }
+
+        transform.translation = (holder_transform.translation.truncate() + offset)
+            .extend(holder_transform.translation.z + CARRIED_CANDLE_Z_OFFSET);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::lighting::CANDLE_CARRY_OFFSET;
+
+    fn setup_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_systems(
+            Update,
+            (
+                player_sort_offset_system,
+                render_layer_system,
+                candle_carry_system,
+            )
+                .chain(),
+        );
+        app
+    }
+
+    #[test]
+    fn moved_sprites_return_to_their_layer() {
+        let mut app = setup_app();
+        let item = app
+            .world_mut()
+            .spawn((RenderLayer::Items, Transform::from_xyz(5.0, 5.0, 0.0)))
+            .id();
+        app.update();
+        assert_eq!(
+            app.world().get::<Transform>(item).unwrap().translation,
+            Vec3::new(5.0, 5.0, RenderLayer::Items.z())
+        );
+
+        app.world_mut()
+            .get_mut::<Transform>(item)
+            .unwrap()
+            .translation = Vec3::new(8.0, 5.0, 0.0);
+        app.update();
+        assert_eq!(
+            app.world().get::<Transform>(item).unwrap().translation.z,
+            RenderLayer::Items.z()
+        );
+    }
+
+    #[test]
+    fn player_sorts_behind_furniture_below_and_in_front_above() {
+        let mut app = setup_app();
+        let player = app
+            .world_mut()
+            .spawn((Player, Transform::from_xyz(0.0, 100.0, 0.0)))
+            .id();
+        // A wardrobe 96px tall standing at y = 72
+        let wardrobe = app
+            .world_mut()
+            .spawn((
+                RenderLayer::Items,
+                YSort,
+                SortOffset(-48.0),
+                Transform::from_xyz(0.0, 120.0, 0.0),
+            ))
+            .id();
+        app.update();
+
+        let z = |app: &App, entity| app.world().get::<Transform>(entity).unwrap().translation.z;
+        assert!(z(&app, player) < z(&app, wardrobe), "Above: behind");
+
+        app.world_mut()
+            .get_mut::<Transform>(player)
+            .unwrap()
+            .translation
+            .y = 50.0;
+        app.update();
+        assert!(z(&app, player) > z(&app, wardrobe), "Below: in front");
+        assert!(z(&app, player) > RenderLayer::Items.z());
+    }
+
+    #[test]
+    fn player_with_feet_below_furniture_base_draws_in_front() {
+        let mut app = setup_app();
+        // A wardrobe 96px tall whose base is at y = 72
+        let wardrobe = app
+            .world_mut()
+            .spawn((
+                RenderLayer::Items,
+                YSort,
+                SortOffset(-48.0),
+                Transform::from_xyz(0.0, 120.0, 0.0),
+            ))
+            .id();
+        // A 48px tall player centered above the base, feet below it
+        let player = app
+            .world_mut()
+            .spawn((
+                Player,
+                Collider {
+                    min: Vec2::new(-12.0, -24.0),
+                    max: Vec2::new(12.0, 24.0),
+                },
+                Transform::from_xyz(0.0, 90.0, 0.0),
+            ))
+            .id();
+        app.update();
+
+        assert_eq!(app.world().get::<SortOffset>(player).unwrap().0, -24.0);
+        let z = |app: &App, entity| app.world().get::<Transform>(entity).unwrap().translation.z;
+        assert!(z(&app, player) > z(&app, wardrobe), "Feet below: in front");
+
+        // Feet above the base too: behind
+        app.world_mut()
+            .get_mut::<Transform>(player)
+            .unwrap()
+            .translation
+            .y = 100.0;
+        app.update();
+        assert!(z(&app, player) < z(&app, wardrobe), "Feet above: behind");
+    }
+
+    #[test]
+    fn carried_candle_follows_player_facing() {
+        let mut app = setup_app();
+        let player = app
+            .world_mut()
+            .spawn((
+                Player,
+                RenderLayer::Player,
+                Velocity(Vec2::new(-50.0, 0.0)),
+                Transform::from_xyz(100.0, 40.0, 0.0),
+            ))
+            .id();
+        let candle = app
+            .world_mut()
+            .spawn((Candle, CarriedCandle::default(), Transform::default()))
+            .id();
+        app.update();
+
+        let expected_z = y_sort_z(40.0) + CARRIED_CANDLE_Z_OFFSET;
+        assert_eq!(
+            app.world().get::<Transform>(candle).unwrap().translation,
+            Vec3::new(
+                100.0 - CANDLE_CARRY_OFFSET.x,
+                40.0 + CANDLE_CARRY_OFFSET.y,
+                expected_z
+            )
+        );
+
+        // Stopping keeps the candle on the left
+        app.world_mut().get_mut::<Velocity>(player).unwrap().0 = Vec2::ZERO;
+        app.update();
+        assert!(
+            app.world()
+                .get::<CarriedCandle>(candle)
+                .unwrap()
+                .facing_left
+        );
+        assert!(
+            app.world().get::<Transform>(player).unwrap().translation.z < expected_z,
+            "Candle should draw in front of the player"
+        );
+    }
+
+    #[test]
+    fn candle_follows_npc_holder_instead_of_player() {
+        let mut app = setup_app();
+        app.world_mut()
+            .spawn((Player, Transform::from_xyz(100.0, 40.0, 0.0)));
+        let survivor = app
+            .world_mut()
+            .spawn((
+                Velocity(Vec2::new(30.0, 0.0)),
+                Transform::from_xyz(-200.0, 0.0, 0.0),
+            ))
+            .id();
+        let candle = app
+            .world_mut()
+            .spawn((
+                Candle,
+                CarriedCandle::held_by(survivor),
+                Transform::default(),
+            ))
+            .id();
+        app.update();
+
+        assert_eq!(
+            app.world()
+                .get::<Transform>(candle)
+                .unwrap()
+                .translation
+                .truncate(),
+            Vec2::new(-200.0, 0.0) + CANDLE_CARRY_OFFSET
+        );
+
+        // The candle stays put once its holder is gone
+        app.world_mut().despawn(survivor);
+        app.update();
+        assert_eq!(
+            app.world()
+                .get::<Transform>(candle)
+                .unwrap()
+                .translation
+                .truncate(),
+            Vec2::new(-200.0, 0.0) + CANDLE_CARRY_OFFSET
+        );
+    }
+}
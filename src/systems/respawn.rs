@@ -1,5 +1,6 @@
-use crate::components::player::{Health, Player};
-use crate::resources::game_state::GameState;
+use crate::components::player::{Health, HitPoints, Invincibility, Player};
+use crate::resources::game_rules::GameRules;
+use crate::resources::game_state::{GameMode, GameState};
 use crate::systems::trap::PlayerDeathEvent;
 use bevy::prelude::*;
 
@@ -16,6 +17,15 @@ pub const RESPAWN_DELAY: f32 = 1.0;
 #[derive(Component)]
 pub struct DeathTimer(pub Timer);
 
+// Type alias for complex player query
+type PlayerRespawnQuery<'a> = (
+    Entity,
+    &'a mut Transform,
+    &'a mut Health,
+    Option<&'a mut DeathTimer>,
+    Option<&'a mut HitPoints>,
+);
+
 /// System that handles player respawn after death
 ///
 /// This system manages the complete respawn cycle:
@@ -26,8 +36,12 @@ pub struct DeathTimer(pub Timer);
 /// 3. **Countdown**: Ticks timer each frame based on delta time
 /// 4. **Respawn**: When timer expires:
 ///    - Resets player position to spawn point
-///    - Sets health to `Health::Alive`
-///    - Removes `DeathTimer` component
+///    - Sets health to `Health::Alive` and restores `HitPoints` to full
+///    - Removes `DeathTimer` and any leftover `Invincibility`
+///
+/// With `GameRules::permadeath` no countdown starts, and the countdown holds
+/// while the game is over (see `game_over`); the game over screen's retry
+/// options revive the player instead.
 ///
 /// # System Dependencies
 /// - **Upstream**: `trap_activation_system` emits `PlayerDeathEvent`
@@ -60,13 +74,18 @@ pub struct DeathTimer(pub Timer);
 pub fn respawn_system(
     time: Res<Time>,
     game_state: Res<GameState>,
+    rules: Option<Res<GameRules>>,
     mut death_events: EventReader<PlayerDeathEvent>,
     mut commands: Commands,
-    mut query: Query<(Entity, &mut Transform, &mut Health, Option<&mut DeathTimer>), With<Player>>,
+    mut query: Query<PlayerRespawnQuery, With<Player>>,
 ) {
-    // Add death timer on death event
+    // Add death timer on death event (there's no coming back with permadeath)
+    let permadeath = rules.is_some_and(|rules| rules.permadeath);
     for event in death_events.read() {
-        if let Ok((entity, _, _, _)) = query.get_mut(event.player) {
+        if permadeath {
+            continue;
+        }
+        if let Ok((entity, _, _, _, _)) = query.get_mut(event.player) {
             commands
                 .entity(entity)
                 .insert(DeathTimer(Timer::from_seconds(
@@ -76,15 +95,24 @@ pub fn respawn_system(
         }
     }
 
+    if game_state.game_mode == GameMode::GameOver {
+        return;
+    }
+
     // Tick timers and respawn when complete
-    for (entity, mut transform, mut health, timer) in &mut query {
+    for (entity, mut transform, mut health, timer, hit_points) in &mut query {
         if let Some(mut timer) = timer {
             timer.0.tick(time.delta());
             if timer.0.finished() {
                 // Respawn
                 transform.translation = game_state.player_spawn_point.extend(0.0);
                 *health = Health::Alive;
-                commands.entity(entity).remove::<DeathTimer>();
+                if let Some(mut hit_points) = hit_points {
+                    hit_points.restore();
+                }
+                commands
+                    .entity(entity)
+                    .remove::<(DeathTimer, Invincibility)>();
             }
         }
     }
@@ -93,6 +121,7 @@ pub fn respawn_system(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::resources::map_state::DeathCause;
     use std::time::Duration;
 
     #[test]
@@ -133,7 +162,10 @@ mod tests {
         assert!(app.world().get::<DeathTimer>(player).is_none());
 
         // Send death event
-        app.world_mut().send_event(PlayerDeathEvent { player });
+        app.world_mut().send_event(PlayerDeathEvent {
+            player,
+            cause: DeathCause::Unknown,
+        });
 
         // Run system
         app.update();
@@ -210,7 +242,10 @@ mod tests {
             .id();
 
         // Send death event
-        app.world_mut().send_event(PlayerDeathEvent { player });
+        app.world_mut().send_event(PlayerDeathEvent {
+            player,
+            cause: DeathCause::Unknown,
+        });
 
         // Update to add timer
         app.update();
@@ -320,13 +355,19 @@ mod tests {
             .id();
 
         // Send first death event
-        app.world_mut().send_event(PlayerDeathEvent { player });
+        app.world_mut().send_event(PlayerDeathEvent {
+            player,
+            cause: DeathCause::Unknown,
+        });
         app.update();
 
         assert!(app.world().get::<DeathTimer>(player).is_some());
 
         // Send another death event while timer is active
-        app.world_mut().send_event(PlayerDeathEvent { player });
+        app.world_mut().send_event(PlayerDeathEvent {
+            player,
+            cause: DeathCause::Unknown,
+        });
         app.update();
 
         // Should still have death timer (no duplicate)
@@ -353,6 +394,7 @@ mod tests {
         let fake_player = Entity::from_raw(999);
         app.world_mut().send_event(PlayerDeathEvent {
             player: fake_player,
+            cause: DeathCause::Unknown,
         });
 
         // System should not panic
@@ -369,4 +411,95 @@ mod tests {
             "Respawn delay should not be too long for gameplay"
         );
     }
+
+    #[test]
+    fn respawn_restores_hit_points() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_event::<PlayerDeathEvent>();
+        app.add_systems(Update, respawn_system);
+        app.insert_resource(GameState {
+            game_mode: crate::resources::game_state::GameMode::Playing,
+            ..Default::default()
+        });
+
+        let mut timer = Timer::from_seconds(RESPAWN_DELAY, TimerMode::Once);
+        timer.tick(Duration::from_secs_f32(RESPAWN_DELAY + 0.1));
+        let player = app
+            .world_mut()
+            .spawn((
+                Player,
+                Health::Dead,
+                Transform::default(),
+                HitPoints {
+                    current: 0.0,
+                    max: 100.0,
+                },
+                Invincibility::default(),
+                DeathTimer(timer),
+            ))
+            .id();
+
+        app.update();
+
+        assert_eq!(*app.world().get::<Health>(player).unwrap(), Health::Alive);
+        assert_eq!(app.world().get::<HitPoints>(player).unwrap().current, 100.0);
+        assert!(app.world().get::<Invincibility>(player).is_none());
+    }
+
+    #[test]
+    fn respawn_waits_while_game_over() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_event::<PlayerDeathEvent>();
+        app.add_systems(Update, respawn_system);
+        app.insert_resource(GameState {
+            game_mode: GameMode::GameOver,
+            ..Default::default()
+        });
+
+        let mut timer = Timer::from_seconds(RESPAWN_DELAY, TimerMode::Once);
+        timer.tick(Duration::from_secs_f32(RESPAWN_DELAY + 0.1));
+        let player = app
+            .world_mut()
+            .spawn((
+                Player,
+                Health::Dead,
+                Transform::default(),
+                DeathTimer(timer),
+            ))
+            .id();
+
+        app.update();
+
+        assert_eq!(*app.world().get::<Health>(player).unwrap(), Health::Dead);
+        assert!(app.world().get::<DeathTimer>(player).is_some());
+    }
+
+    #[test]
+    fn permadeath_never_starts_respawn() {
+        use crate::resources::game_rules::GameRuleSet;
+
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_event::<PlayerDeathEvent>();
+        app.add_systems(Update, respawn_system);
+        app.insert_resource(GameState {
+            game_mode: GameMode::Playing,
+            ..Default::default()
+        });
+        app.insert_resource(GameRules::for_rule_set(GameRuleSet::Survival));
+
+        let player = app
+            .world_mut()
+            .spawn((Player, Health::Dead, Transform::default()))
+            .id();
+        app.world_mut().send_event(PlayerDeathEvent {
+            player,
+            cause: DeathCause::Unknown,
+        });
+        app.update();
+
+        assert!(app.world().get::<DeathTimer>(player).is_none());
+    }
 }
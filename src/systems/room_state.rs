@@ -0,0 +1,283 @@
+use crate::components::lighting::CandleState;
+use crate::components::room::{LevelEntityId, Pushable};
+use crate::resources::room_state::RoomStateStore;
+use crate::systems::breakable::Breakable;
+use bevy::prelude::*;
+
+/// Plugin that keeps level lights lit, pushed crates in place and broken
+/// objects broken across room visits and saves
+pub struct RoomStatePlugin;
+
+impl Plugin for RoomStatePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<RoomStateStore>().add_systems(
+            Update,
+            (
+                (restore_light_state_system, record_light_state_system).chain(),
+                (restore_crate_position_system, record_crate_position_system).chain(),
+                remove_broken_objects_system,
+            ),
+        );
+    }
+}
+
+/// System that re-applies recorded light states to level lights
+///
+/// # System Dependencies
+/// - **Resources**: Reads `RoomStateStore`
+/// - **Components**: Reads `LevelEntityId`, writes `CandleState`
+/// - **Downstream**: `record_light_state_system` (must run after, so a newly
+///   spawned light's default state doesn't overwrite its recorded one)
+///
+/// # Behavior
+/// - Lights spawned this frame (e.g., the player returned to their room)
+///   take their recorded state
+/// - When the store itself was replaced (e.g., a save was loaded), every
+///   spawned light takes its recorded state
+pub fn restore_light_state_system(
+    store: Res<RoomStateStore>,
+    mut lights: Query<(Ref<LevelEntityId>, &mut CandleState)>,
+) {
+    let reapply_all = store.is_changed();
+
+    for (id, mut state) in &mut lights {
+        if !reapply_all && !id.is_added() {
+            continue;
+        }
+
+        if let Some(recorded) = store.light_state(*id) {
+            state.set_if_neq(recorded);
+        }
+    }
+}
+
+/// System that records level light state changes in `RoomStateStore`
+///
+/// Recording bypasses change detection on the store, so only outside
+/// replacements of the store (loading a save) make
+/// `restore_light_state_system` re-apply every light.
+///
+/// # System Dependencies
+/// - **Upstream**: `restore_light_state_system`
+/// - **Components**: Reads changed `CandleState` on entities with `LevelEntityId`
+/// - **Resources**: Writes `RoomStateStore`
+pub fn record_light_state_system(
+    mut store: ResMut<RoomStateStore>,
+    lights: Query<(&LevelEntityId, &CandleState), Changed<CandleState>>,
+) {
+    for (id, state) in &lights {
+        store
+            .bypass_change_detection()
+            .light_states
+            .insert(*id, *state);
+    }
+}
+
+/// System that moves pushable blocks back to their recorded positions
+///
+/// # System Dependencies
+/// - **Resources**: Reads `RoomStateStore`
+/// - **Components**: Reads `LevelEntityId`, writes `Transform` of `Pushable`
+///   blocks
+/// - **Downstream**: `record_crate_position_system` (must run after, so a
+///   newly spawned block's level position doesn't overwrite its recorded one)
+///
+/// # Behavior
+/// Same as `restore_light_state_system`: blocks spawned this frame, or every
+/// block when the store was replaced, take their recorded position. Only x
+/// and y are restored; the block keeps its layer.
+pub fn restore_crate_position_system(
+    store: Res<RoomStateStore>,
+    mut crates: Query<(Ref<LevelEntityId>, &mut Transform), With<Pushable>>,
+) {
+    let reapply_all = store.is_changed();
+
+    for (id, mut transform) in &mut crates {
+        if !reapply_all && !id.is_added() {
+            continue;
+        }
+
+        if let Some(position) = store.crate_position(*id)
+            && transform.translation.truncate() != position
+        {
+            transform.translation = position.extend(transform.translation.z);
+        }
+    }
+}
+
+/// System that records moved pushable blocks in `RoomStateStore`
+///
+/// Like `record_light_state_system`, recording bypasses change detection
+/// on the store.
+///
+/// # System Dependencies
+/// - **Upstream**: `restore_crate_position_system`
+/// - **Components**: Reads changed `Transform` of `Pushable` blocks with
+///   `LevelEntityId`
+/// - **Resources**: Writes `RoomStateStore`
+pub fn record_crate_position_system(
+    mut store: ResMut<RoomStateStore>,
+    crates: Query<(&LevelEntityId, &Transform), (With<Pushable>, Changed<Transform>)>,
+) {
+    for (id, transform) in &crates {
+        store
+            .bypass_change_detection()
+            .crate_positions
+            .insert(*id, transform.translation.truncate());
+    }
+}
+
+/// System that despawns level objects the player already broke
+///
+/// `break_system` records broken objects as it breaks them.
+///
+/// # System Dependencies
+/// - **Resources**: Reads `RoomStateStore`
+/// - **Components**: Reads `LevelEntityId` of `Breakable` objects; despawns them
+///
+/// # Behavior
+/// Same as `restore_light_state_system`: objects spawned this frame, or
+/// every object when the store was replaced, are checked.
+pub fn remove_broken_objects_system(
+    mut commands: Commands,
+    store: Res<RoomStateStore>,
+    breakables: Query<(Entity, Ref<LevelEntityId>), With<Breakable>>,
+) {
+    let reapply_all = store.is_changed();
+
+    for (entity, id) in &breakables {
+        if (reapply_all || id.is_added()) && store.is_broken(*id) {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::lighting::{Candle, StaticLight};
+
+    const SCONCE: LevelEntityId = LevelEntityId { room: 2, index: 4 };
+    const CRATE: LevelEntityId = LevelEntityId { room: 2, index: 7 };
+    const BOARDS: LevelEntityId = LevelEntityId { room: 2, index: 9 };
+
+    fn setup_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(RoomStatePlugin);
+        app
+    }
+
+    #[test]
+    fn lit_sconce_stays_lit_after_leaving_and_returning() {
+        let mut app = setup_app();
+        let sconce = app
+            .world_mut()
+            .spawn((StaticLight, SCONCE, CandleState::Unlit))
+            .id();
+        app.update();
+
+        *app.world_mut().get_mut::<CandleState>(sconce).unwrap() = CandleState::Lit;
+        app.update();
+
+        // Leave the room, then come back to a freshly spawned sconce
+        app.world_mut().despawn(sconce);
+        app.update();
+        let sconce = app
+            .world_mut()
+            .spawn((StaticLight, SCONCE, CandleState::Unlit))
+            .id();
+        app.update();
+
+        assert_eq!(
+            *app.world().get::<CandleState>(sconce).unwrap(),
+            CandleState::Lit
+        );
+    }
+
+    #[test]
+    fn pushed_crate_stays_put_after_leaving_and_returning() {
+        let mut app = setup_app();
+        let spawn_crate = |app: &mut App| {
+            app.world_mut()
+                .spawn((
+                    Pushable::default(),
+                    CRATE,
+                    Transform::from_xyz(64.0, 0.0, 20.0),
+                ))
+                .id()
+        };
+        let block = spawn_crate(&mut app);
+        app.update();
+
+        app.world_mut()
+            .get_mut::<Transform>(block)
+            .unwrap()
+            .translation
+            .x = 160.0;
+        app.update();
+
+        app.world_mut().despawn(block);
+        app.update();
+        let block = spawn_crate(&mut app);
+        app.update();
+
+        assert_eq!(
+            app.world().get::<Transform>(block).unwrap().translation,
+            Vec3::new(160.0, 0.0, 20.0)
+        );
+    }
+
+    #[test]
+    fn broken_boards_stay_gone_after_returning() {
+        let mut app = setup_app();
+        app.world_mut()
+            .resource_mut::<RoomStateStore>()
+            .broken
+            .insert(BOARDS);
+        app.update();
+
+        let boards = app.world_mut().spawn((Breakable::default(), BOARDS)).id();
+        let intact = app
+            .world_mut()
+            .spawn((Breakable::default(), LevelEntityId { room: 2, index: 10 }))
+            .id();
+        app.update();
+
+        assert!(app.world().get_entity(boards).is_err());
+        assert!(app.world().get_entity(intact).is_ok());
+    }
+
+    #[test]
+    fn replaced_store_is_applied_to_spawned_lights() {
+        let mut app = setup_app();
+        let candle = app
+            .world_mut()
+            .spawn((Candle, SCONCE, CandleState::Lit))
+            .id();
+        let untracked = app
+            .world_mut()
+            .spawn((
+                Candle,
+                LevelEntityId { room: 2, index: 5 },
+                CandleState::Lit,
+            ))
+            .id();
+        app.update();
+
+        app.world_mut()
+            .resource_mut::<RoomStateStore>()
+            .light_states = [(SCONCE, CandleState::Extinguished)].into_iter().collect();
+        app.update();
+
+        assert_eq!(
+            *app.world().get::<CandleState>(candle).unwrap(),
+            CandleState::Extinguished
+        );
+        assert_eq!(
+            *app.world().get::<CandleState>(untracked).unwrap(),
+            CandleState::Lit,
+            "Lights missing from the loaded store keep their state"
+        );
+    }
+}
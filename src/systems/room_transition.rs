@@ -1,9 +1,70 @@
+use crate::components::inventory::{Inventory, Item, KeyType};
 use crate::components::player::Player;
-use crate::components::room::{Room, RoomId};
+use crate::components::render_layer::RenderLayer;
+use crate::components::room::{Door, DoorState, Room, RoomId, TargetRoom};
+use crate::resources::game_clock::GameClock;
 use crate::resources::game_state::GameState;
 use crate::resources::map_state::MapState;
+use crate::systems::electrical::Electrified;
+use crate::systems::interaction::OpenDoorEvent;
+use crate::systems::level_loader::{LevelRegistry, RoomLevels};
+use crate::systems::oil_can::{OilStuckEvent, Stuck};
+use crate::systems::save_load::AutoSaveEvent;
+use crate::systems::tween::Ease;
+use crate::ui::toast::ToastEvent;
 use bevy::prelude::*;
 
+/// Seconds a door takes to swing open or shut
+pub const DOOR_SWING_DURATION: f32 = 0.3;
+
+/// Horizontal scale of a fully open door sprite (seen edge-on)
+pub const DOOR_OPEN_SCALE: f32 = 0.2;
+
+/// Distance (pixels) from an open door's center at which the player walks through it
+pub const DOOR_TRAVERSE_DISTANCE: f32 = 16.0;
+
+/// Seconds a door stays blocked after its target room failed to load
+pub const DOOR_BLOCKED_DURATION: f32 = 5.0;
+
+/// Seconds the screen takes to fade out (and back in) during a room transition
+pub const ROOM_FADE_DURATION: f32 = 0.4;
+
+/// Plugin that registers doors and room transitions
+///
+/// Covers the whole door flow: unlocking and opening doors through
+/// `OpenDoorEvent`, walking through open doors, fading between rooms and
+/// triggering an auto-save once the new room is entered. Toasts about rooms
+/// that fail to load are shown by `ToastPlugin`. Door swings and fades
+/// advance with `GameClock` (see `GameClockPlugin`), so they stop while
+/// the game is paused.
+pub struct RoomTransitionPlugin;
+
+impl Plugin for RoomTransitionPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<RoomFade>()
+            .init_resource::<GameClock>()
+            .init_resource::<LevelRegistry>()
+            .add_event::<OpenDoorEvent>()
+            .add_event::<OilStuckEvent>()
+            .add_event::<RoomChangedEvent>()
+            .add_event::<AutoSaveEvent>()
+            .add_event::<ToastEvent>()
+            .add_systems(
+                Update,
+                (
+                    door_use_system,
+                    door_swing_system,
+                    door_block_system,
+                    door_traversal_system,
+                    room_fade_system,
+                    room_transition_system,
+                    room_fade_overlay_system,
+                )
+                    .chain(),
+            );
+    }
+}
+
 /// Event emitted when the player transitions from one room to another
 ///
 /// This event triggers room loading/unloading, map state updates, and player repositioning.
@@ -42,10 +103,10 @@ pub struct RoomChangedEvent {
 /// 3. **Update game state**: Sets current_room to new_room
 /// 4. **Update map state**: Marks new room as explored
 /// 5. **Move player**: Repositions player to spawn point in new room
-/// 6. **Trigger auto-save**: (TODO: emit AutoSaveEvent)
 ///
 /// # System Dependencies
-/// - **Upstream**: Door interaction system or other trigger emits `RoomChangedEvent`
+/// - **Upstream**: `room_fade_system` (walking through a door) or other trigger
+///   emits `RoomChangedEvent`; the fade also emits the matching `AutoSaveEvent`
 /// - **Related**: Uses `GameState.player_spawn_point` for player position
 /// - **Related**: Updates `MapState` to track explored rooms
 ///
@@ -94,6 +155,378 @@ pub fn room_transition_system(
     }
 }
 
+/// Component animating a door swinging open or shut
+///
+/// Inserted by `door_use_system` and removed by `door_swing_system` once the
+/// door reaches its new `DoorState`.
+#[derive(Component, Debug)]
+pub struct DoorSwing {
+    /// Time since the swing started
+    pub timer: Timer,
+    /// `true` when opening (ends `Open`), `false` when closing (ends `Unlocked`)
+    pub opening: bool,
+}
+
+impl DoorSwing {
+    /// Starts a new swing
+    pub fn new(opening: bool) -> Self {
+        Self {
+            timer: Timer::from_seconds(DOOR_SWING_DURATION, TimerMode::Once),
+            opening,
+        }
+    }
+}
+
+/// Removes a key that unlocks a door locked with `required` from `inventory`
+///
+/// The matching key is consumed. The Master key opens every door and is
+/// never consumed.
+///
+/// # Returns
+/// `true` if the player had a key that unlocks the door
+pub fn use_door_key(inventory: &mut Inventory, required: KeyType) -> bool {
+    if let Some(pos) = inventory
+        .items
+        .iter()
+        .position(|item| matches!(item, Item::Key(key) if *key == required))
+    {
+        if required != KeyType::Master {
+            inventory.items.remove(pos);
+        }
+        return true;
+    }
+
+    inventory
+        .items
+        .iter()
+        .any(|item| matches!(item, Item::Key(KeyType::Master)))
+}
+
+/// System that unlocks, opens and closes doors the player interacts with
+///
+/// # System Dependencies
+/// - **Upstream**: `interaction_system` emits `OpenDoorEvent`
+/// - **Components**: Writes `DoorState` and the player's `Inventory`, inserts `DoorSwing`
+/// - **Downstream**: `door_swing_system` animates the door
+///
+/// # Behavior
+/// - `Locked(key)`: uses the key (see `use_door_key`), unlocks the door and
+///   starts opening it; without a key the door stays locked
+/// - `Unlocked`: starts opening the door
+/// - `Open`: starts closing the door
+/// - Doors that are mid-swing ignore interaction
+/// - Powered `Electrified` doors stay shut (`electric_shock_system` shocks
+///   the player instead)
+/// - `Stuck` doors stay shut and emit `OilStuckEvent` (`oil_system` frees
+///   them with the oil can)
+#[allow(clippy::type_complexity)]
+pub fn door_use_system(
+    mut commands: Commands,
+    mut events: EventReader<OpenDoorEvent>,
+    mut door_query: Query<
+        (&mut DoorState, Option<&Electrified>, Has<Stuck>),
+        (With<Door>, Without<DoorSwing>),
+    >,
+    mut inventory_query: Query<&mut Inventory, With<Player>>,
+    mut stuck_events: EventWriter<OilStuckEvent>,
+) {
+    for event in events.read() {
+        let Ok((mut state, electrified, stuck)) = door_query.get_mut(event.door) else {
+            continue;
+        };
+        if electrified.is_some_and(|electrified| electrified.powered) {
+            continue;
+        }
+        if stuck {
+            stuck_events.write(OilStuckEvent {
+                target: event.door,
+                player: event.player,
+            });
+            continue;
+        }
+
+        let opening = match *state {
+            DoorState::Locked(required) => {
+                let Ok(mut inventory) = inventory_query.get_mut(event.player) else {
+                    continue;
+                };
+                if !use_door_key(&mut inventory, required) {
+                    info!("The door is locked ({:?} key needed)", required);
+                    continue;
+                }
+                *state = DoorState::Unlocked;
+                info!("Door unlocked with {:?} key", required);
+                true
+            }
+            DoorState::Unlocked => true,
+            DoorState::Open => false,
+        };
+
+        commands.entity(event.door).insert(DoorSwing::new(opening));
+    }
+}
+
+/// System that animates swinging doors and settles their state
+///
+/// # System Dependencies
+/// - **Upstream**: `door_use_system` inserts `DoorSwing`
+/// - **Components**: Writes `DoorState`, door `Transform` scale, removes `DoorSwing`
+///
+/// # Behavior
+/// The door sprite narrows toward `DOOR_OPEN_SCALE` while opening (widens
+/// back while closing), easing in and out of the swing. When the swing
+/// finishes the door becomes `Open` (or `Unlocked` after closing).
+pub fn door_swing_system(
+    mut commands: Commands,
+    clock: Res<GameClock>,
+    mut door_query: Query<(
+        Entity,
+        &mut DoorSwing,
+        &mut DoorState,
+        Option<&mut Transform>,
+    )>,
+) {
+    for (entity, mut swing, mut state, transform) in &mut door_query {
+        swing.timer.tick(clock.delta_duration());
+        let progress = Ease::QuadInOut.apply(swing.timer.fraction());
+        let openness = if swing.opening {
+            progress
+        } else {
+            1.0 - progress
+        };
+
+        if let Some(mut transform) = transform {
+            transform.scale.x = DOOR_OPEN_SCALE * openness + (1.0 - openness);
+        }
+
+        if swing.timer.finished() {
+            *state = if swing.opening {
+                DoorState::Open
+            } else {
+                DoorState::Unlocked
+            };
+            commands.entity(entity).remove::<DoorSwing>();
+        }
+    }
+}
+
+/// Phase of the screen fade between rooms
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum FadePhase {
+    /// No transition in progress
+    #[default]
+    Idle,
+    /// Fading to black before entering `target_room`
+    Out {
+        /// Room the player is walking into
+        target_room: RoomId,
+    },
+    /// Fading back in after the player was moved
+    In,
+}
+
+/// Resource driving the fade-out / teleport / fade-in room transition
+#[derive(Resource, Debug)]
+pub struct RoomFade {
+    /// Current phase
+    pub phase: FadePhase,
+    /// Time spent in the current phase
+    pub timer: Timer,
+}
+
+impl Default for RoomFade {
+    fn default() -> Self {
+        Self {
+            phase: FadePhase::Idle,
+            timer: Timer::from_seconds(ROOM_FADE_DURATION, TimerMode::Once),
+        }
+    }
+}
+
+impl RoomFade {
+    /// Starts fading out toward `target_room`
+    pub fn start(&mut self, target_room: RoomId) {
+        self.phase = FadePhase::Out { target_room };
+        self.timer.reset();
+    }
+
+    /// Returns the opacity (0.0-1.0) of the black fade overlay
+    pub fn alpha(&self) -> f32 {
+        let progress = Ease::SmoothStep.apply(self.timer.fraction());
+        match self.phase {
+            FadePhase::Idle => 0.0,
+            FadePhase::Out { .. } => progress,
+            FadePhase::In => 1.0 - progress,
+        }
+    }
+}
+
+/// Component marking a door that can't be walked through for a while
+///
+/// Inserted by `door_traversal_system` when the target room's level file
+/// fails to load, and removed by `door_block_system` once the timer runs
+/// out (so a fixed file can be retried without restarting).
+#[derive(Component, Debug)]
+pub struct DoorBlocked(pub Timer);
+
+impl Default for DoorBlocked {
+    fn default() -> Self {
+        Self(Timer::from_seconds(DOOR_BLOCKED_DURATION, TimerMode::Once))
+    }
+}
+
+/// System that starts a room transition when the player walks through an open door
+///
+/// # System Dependencies
+/// - **Components**: Reads `Player`, `Door`, `DoorState`, `TargetRoom` and
+///   transforms, inserts `DoorBlocked`
+/// - **Resources**: Reads `LevelRegistry`, writes `RoomFade`
+/// - **Downstream**: `room_fade_system` runs the transition; emits
+///   `ToastEvent` when the target room can't be loaded
+///
+/// # Behavior
+/// The target room's level file is loaded before the fade starts (see
+/// `load_room_level`). If it is corrupted or invalid, the player stays in
+/// the current room, a toast shows the error (with the parse error
+/// location), and the door is blocked for `DOOR_BLOCKED_DURATION`.
+#[allow(clippy::type_complexity)]
+pub fn door_traversal_system(
+    mut commands: Commands,
+    mut fade: ResMut<RoomFade>,
+    mut toasts: EventWriter<ToastEvent>,
+    levels: RoomLevels,
+    player_query: Query<&Transform, With<Player>>,
+    door_query: Query<
+        (Entity, &Transform, &DoorState, &TargetRoom),
+        (With<Door>, Without<DoorSwing>, Without<DoorBlocked>),
+    >,
+) {
+    if fade.phase != FadePhase::Idle {
+        return;
+    }
+    let Ok(player_transform) = player_query.single() else {
+        return;
+    };
+
+    let player_pos = player_transform.translation.truncate();
+    let target = door_query.iter().find(|(_, transform, state, _)| {
+        **state == DoorState::Open
+            && transform.translation.truncate().distance(player_pos) <= DOOR_TRAVERSE_DISTANCE
+    });
+
+    let Some((door, _, _, target_room)) = target else {
+        return;
+    };
+
+    match levels.load(target_room.0) {
+        Ok(_) => fade.start(target_room.0),
+        Err(e) => {
+            error!("Blocking door to room {}: {}", target_room.0, e);
+            toasts.write(ToastEvent::error(format!(
+                "The way to room {} is blocked. {}",
+                target_room.0, e
+            )));
+            commands.entity(door).insert(DoorBlocked::default());
+        }
+    }
+}
+
+/// System that unblocks doors once their `DoorBlocked` timer runs out
+///
+/// # System Dependencies
+/// - **Upstream**: `door_traversal_system` inserts `DoorBlocked`
+/// - **Components**: Writes and removes `DoorBlocked`
+pub fn door_block_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut door_query: Query<(Entity, &mut DoorBlocked)>,
+) {
+    for (entity, mut blocked) in &mut door_query {
+        if blocked.0.tick(time.delta()).finished() {
+            commands.entity(entity).remove::<DoorBlocked>();
+        }
+    }
+}
+
+/// System that runs the fade-out / teleport / fade-in room transition
+///
+/// # System Dependencies
+/// - **Upstream**: `door_traversal_system` starts the fade
+/// - **Resources**: Writes `RoomFade`, reads `GameState` and `GameClock`
+/// - **Downstream**: Emits `RoomChangedEvent` (handled by
+///   `room_transition_system`, which moves the player) and `AutoSaveEvent`
+///
+/// # Behavior
+/// 1. **Fade out** for `ROOM_FADE_DURATION`
+/// 2. **Teleport**: emits `RoomChangedEvent` and `AutoSaveEvent` at full black
+/// 3. **Fade in** for `ROOM_FADE_DURATION`, then returns to idle
+pub fn room_fade_system(
+    clock: Res<GameClock>,
+    game_state: Res<GameState>,
+    mut fade: ResMut<RoomFade>,
+    mut room_events: EventWriter<RoomChangedEvent>,
+    mut save_events: EventWriter<AutoSaveEvent>,
+) {
+    if fade.phase == FadePhase::Idle {
+        return;
+    }
+
+    if !fade.timer.tick(clock.delta_duration()).finished() {
+        return;
+    }
+
+    match fade.phase {
+        FadePhase::Out { target_room } => {
+            room_events.write(RoomChangedEvent {
+                old_room: game_state.current_room,
+                new_room: target_room,
+            });
+            save_events.write(AutoSaveEvent);
+            fade.phase = FadePhase::In;
+            fade.timer.reset();
+        }
+        FadePhase::In => fade.phase = FadePhase::Idle,
+        FadePhase::Idle => {}
+    }
+}
+
+/// Marker component for the full-screen sprite drawn during room fades
+#[derive(Component)]
+pub struct RoomFadeOverlay;
+
+/// System that draws the room transition fade
+///
+/// Spawns a black full-screen sprite on the UI render layer the first time
+/// it is needed and keeps its opacity in sync with `RoomFade::alpha`.
+///
+/// # System Dependencies
+/// - **Resources**: Reads `RoomFade`
+/// - **Components**: Writes `Sprite` on `RoomFadeOverlay`
+pub fn room_fade_overlay_system(
+    mut commands: Commands,
+    fade: Res<RoomFade>,
+    mut overlay_query: Query<&mut Sprite, With<RoomFadeOverlay>>,
+) {
+    let color = Color::BLACK.with_alpha(fade.alpha());
+
+    if let Ok(mut sprite) = overlay_query.single_mut() {
+        if sprite.color != color {
+            sprite.color = color;
+        }
+    } else if fade.phase != FadePhase::Idle {
+        commands.spawn((
+            RoomFadeOverlay,
+            Sprite {
+                color,
+                custom_size: Some(Vec2::new(1920.0, 1080.0)),
+                ..default()
+            },
+            RenderLayer::Ui,
+            Transform::from_xyz(0.0, 0.0, RenderLayer::Ui.z()),
+        ));
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -371,4 +804,196 @@ mod tests {
         // Verify player still exists
         assert!(app.world().get::<Player>(player).is_some());
     }
+
+    #[test]
+    fn doors_consume_matching_keys_but_not_the_master_key() {
+        let mut inventory = Inventory {
+            items: vec![Item::Key(KeyType::Brass), Item::Key(KeyType::Master)],
+            max_capacity: 10,
+        };
+
+        assert!(use_door_key(&mut inventory, KeyType::Brass));
+        assert!(matches!(
+            inventory.items.as_slice(),
+            [Item::Key(KeyType::Master)]
+        ));
+
+        // Master opens any door and is kept
+        assert!(use_door_key(&mut inventory, KeyType::Iron));
+        assert!(use_door_key(&mut inventory, KeyType::Master));
+        assert!(matches!(
+            inventory.items.as_slice(),
+            [Item::Key(KeyType::Master)]
+        ));
+
+        inventory.items.clear();
+        assert!(!use_door_key(&mut inventory, KeyType::Ornate));
+    }
+
+    #[test]
+    fn unlocking_a_door_swings_it_open() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_event::<OpenDoorEvent>();
+        app.add_event::<OilStuckEvent>();
+        app.init_resource::<GameClock>();
+        app.add_systems(Update, (door_use_system, door_swing_system).chain());
+
+        let player = app
+            .world_mut()
+            .spawn((
+                Player,
+                Inventory {
+                    items: vec![Item::Key(KeyType::Iron)],
+                    max_capacity: 10,
+                },
+            ))
+            .id();
+        let door = app
+            .world_mut()
+            .spawn((Door, DoorState::Locked(KeyType::Iron), Transform::default()))
+            .id();
+
+        app.world_mut().send_event(OpenDoorEvent { door, player });
+        app.update();
+        assert_eq!(
+            *app.world().get::<DoorState>(door).unwrap(),
+            DoorState::Unlocked
+        );
+        assert!(
+            app.world()
+                .get::<Inventory>(player)
+                .unwrap()
+                .items
+                .is_empty()
+        );
+
+        app.world_mut()
+            .get_mut::<DoorSwing>(door)
+            .unwrap()
+            .timer
+            .tick(std::time::Duration::from_secs_f32(DOOR_SWING_DURATION));
+        app.update();
+        assert_eq!(
+            *app.world().get::<DoorState>(door).unwrap(),
+            DoorState::Open
+        );
+        assert!(app.world().get::<DoorSwing>(door).is_none());
+        assert_eq!(
+            app.world().get::<Transform>(door).unwrap().scale.x,
+            DOOR_OPEN_SCALE
+        );
+    }
+
+    #[test]
+    fn walking_through_open_door_fades_to_new_room_and_saves() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(RoomTransitionPlugin);
+        app.init_resource::<GameState>();
+        app.init_resource::<MapState>();
+        app.world_mut()
+            .resource_mut::<GameState>()
+            .player_spawn_point = Vec2::new(64.0, 32.0);
+
+        app.world_mut().spawn((
+            Door,
+            DoorState::Open,
+            TargetRoom(3),
+            Transform::from_xyz(200.0, 0.0, 0.0),
+        ));
+        let player = app
+            .world_mut()
+            .spawn((Player, Transform::from_xyz(195.0, 0.0, 0.0)))
+            .id();
+        app.update();
+        assert_eq!(
+            app.world().resource::<RoomFade>().phase,
+            FadePhase::Out { target_room: 3 }
+        );
+
+        // Finish fading out: the player is moved and the game auto-saves
+        app.world_mut()
+            .resource_mut::<RoomFade>()
+            .timer
+            .tick(std::time::Duration::from_secs_f32(ROOM_FADE_DURATION));
+        app.update();
+        assert_eq!(app.world().resource::<GameState>().current_room, 3);
+        assert_eq!(
+            app.world()
+                .get::<Transform>(player)
+                .unwrap()
+                .translation
+                .truncate(),
+            Vec2::new(64.0, 32.0)
+        );
+        assert!(!app.world().resource::<Events<AutoSaveEvent>>().is_empty());
+        assert_eq!(app.world().resource::<RoomFade>().phase, FadePhase::In);
+
+        app.world_mut()
+            .resource_mut::<RoomFade>()
+            .timer
+            .tick(std::time::Duration::from_secs_f32(ROOM_FADE_DURATION));
+        app.update();
+        assert_eq!(app.world().resource::<RoomFade>().phase, FadePhase::Idle);
+        assert_eq!(app.world().resource::<RoomFade>().alpha(), 0.0);
+    }
+
+    #[test]
+    fn corrupted_target_level_blocks_door_and_keeps_player() {
+        // Partially written level file for a room no real level uses
+        let room = 9_001;
+        let path = format!(
+            "assets/{}",
+            crate::systems::level_loader::get_level_path(room)
+        );
+        std::fs::write(
+            &path,
+            "(\n    id: 9001,\n    floor: Ground,\n    name: \"Half",
+        )
+        .unwrap();
+
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(RoomTransitionPlugin);
+        app.init_resource::<GameState>();
+        app.init_resource::<MapState>();
+
+        let door = app
+            .world_mut()
+            .spawn((
+                Door,
+                DoorState::Open,
+                TargetRoom(room),
+                Transform::from_xyz(10.0, 0.0, 0.0),
+            ))
+            .id();
+        app.world_mut().spawn((Player, Transform::default()));
+        app.update();
+        std::fs::remove_file(&path).ok();
+
+        assert_eq!(app.world().resource::<RoomFade>().phase, FadePhase::Idle);
+        assert!(app.world().get::<DoorBlocked>(door).is_some());
+        let toasts: Vec<ToastEvent> = app
+            .world_mut()
+            .resource_mut::<Events<ToastEvent>>()
+            .drain()
+            .collect();
+        assert_eq!(toasts.len(), 1);
+        assert!(
+            toasts[0].message.contains("at line 4, column"),
+            "Toast should include the parse error location: {}",
+            toasts[0].message
+        );
+
+        // Blocked doors reopen once the timer runs out
+        app.world_mut()
+            .get_mut::<DoorBlocked>(door)
+            .unwrap()
+            .0
+            .tick(std::time::Duration::from_secs_f32(DOOR_BLOCKED_DURATION));
+        app.update();
+        assert!(app.world().get::<DoorBlocked>(door).is_none());
+        assert_eq!(app.world().resource::<GameState>().current_room, 0);
+    }
 }
@@ -1,28 +1,88 @@
-use crate::components::inventory::{Inventory, Item, KeyType, PuzzleItemType, ToolType};
+use crate::components::inventory::{
+    HOTBAR_SLOTS, Hotbar, Inventory, Item, KeyType, OIL_CAN_CHARGES, PuzzleItemType, ToolType,
+};
 use crate::components::lighting::{CandleState, CandleWax};
-use crate::components::player::{DoubleJumpUnlocked, Health, Player};
-use crate::components::room::RoomId;
+use crate::components::player::{
+    DEFAULT_MAX_HIT_POINTS, DoubleJumpUnlocked, Health, HitPoints, Player, Stamina,
+};
+use crate::components::room::{LevelEntityId, RoomId};
+use crate::resources::game_rng::GameRng;
+use crate::resources::game_rules::{Difficulty, GameRuleSet, GameRules};
 use crate::resources::game_state::{GameMode, GameState};
-use crate::resources::map_state::MapState;
+use crate::resources::game_stats::GameStats;
+use crate::resources::map_state::{DeathRecord, MapState};
+use crate::resources::movement_tuning::DEFAULT_MAX_STAMINA;
+use crate::resources::room_state::RoomStateStore;
+use crate::systems::checkpoint::checkpoint_system;
+use crate::systems::daily::DailyChallenge;
+use crate::systems::game_set::GameSet;
+use crate::systems::save_migration::decode_save;
+use crate::systems::save_storage::{
+    SAVE_SLOT_EXTENSIONS, SaveBackend, SaveStorage, save_file_name, save_meta_file_name,
+};
+use crate::systems::victory::{Cosmetic, NewGamePlus};
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+#[cfg(not(target_arch = "wasm32"))]
 use std::fs;
-use std::path::PathBuf;
+#[cfg(not(target_arch = "wasm32"))]
+use std::path::{Path, PathBuf};
+
+/// Save file format version written by this build
+///
+/// Older saves are brought up to date by `save_migration::migrate_save`.
+pub const SAVE_VERSION: u32 = 1;
+
+/// Largest stack size restored from a save
+///
+/// Counts above this only come from corrupted saves, and are clamped so
+/// loading one can't fill memory with billions of items.
+pub const MAX_LOADED_STACK: u32 = 9_999;
+
+/// Plugin for saving and loading games
+///
+/// Registers the save events and the `SaveBackend` (platform storage unless
+/// one was inserted first) and runs `auto_save_system`,
+/// `manual_save_system` and `load_game_system` in `GameSet::Gameplay`,
+/// after `checkpoint_system` (so a checkpoint's auto-save records the new
+/// spawn point). Copying and renaming slots belong to
+/// `save_slots::SaveSlotMenuPlugin`.
+pub struct SaveLoadPlugin;
+
+impl Plugin for SaveLoadPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<GameState>()
+            .init_resource::<MapState>()
+            .init_resource::<SaveBackend>()
+            .add_event::<AutoSaveEvent>()
+            .add_event::<ManualSaveEvent>()
+            .add_event::<LoadGameEvent>()
+            .add_systems(
+                Update,
+                (auto_save_system, manual_save_system, load_game_system)
+                    .chain()
+                    .after(checkpoint_system)
+                    .in_set(GameSet::Gameplay),
+            );
+    }
+}
 
 /// Save data structure for game state serialization
 ///
 /// This structure contains all the necessary game state that needs to be
 /// persisted across sessions. It uses RON (Rusty Object Notation) format
 /// for human-readable serialization.
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct SaveData {
-    /// Save file format version (currently 1)
+    /// Save file format version (see `SAVE_VERSION`)
     pub version: u32,
     /// The room ID where the player is located
     pub current_room: RoomId,
     /// Player's XY coordinates in the current room
     pub player_position: (f32, f32),
-    /// Serialized list of inventory items
+    /// Serialized list of unstackable inventory items (older saves list
+    /// every match here too)
     pub inventory_items: Vec<SerializedItem>,
     /// Current candle wax percentage (0.0-100.0)
     pub candle_wax: f32,
@@ -40,6 +100,69 @@ pub struct SaveData {
     pub double_jump_unlocked: bool,
     /// Current game mode (Menu, Playing, Paused, etc.)
     pub game_mode: SerializedGameMode,
+    /// Player's remaining hit points (defaults to full for older saves)
+    #[serde(default = "default_hit_points")]
+    pub hit_points: f32,
+    /// Player's remaining sprint stamina (defaults to full for older saves)
+    #[serde(default = "default_stamina")]
+    pub stamina: f32,
+    /// Deaths recorded per room (position and cause), empty for older saves
+    #[serde(default)]
+    pub death_records: HashMap<RoomId, Vec<DeathRecord>>,
+    /// Recorded states of level candles and sconces, empty for older saves
+    #[serde(default)]
+    pub light_states: Vec<(LevelEntityId, SerializedCandleState)>,
+    /// Recorded positions of pushed crates, empty for older saves
+    #[serde(default)]
+    pub crate_positions: Vec<(LevelEntityId, (f32, f32))>,
+    /// Breakable objects the player has broken, empty for older saves
+    #[serde(default)]
+    pub broken_objects: Vec<LevelEntityId>,
+    /// Names of wires the player has cut, empty for older saves
+    #[serde(default)]
+    pub cut_wires: Vec<String>,
+    /// Stuck doors and levers the player has oiled, empty for older saves
+    #[serde(default)]
+    pub oiled_objects: Vec<LevelEntityId>,
+    /// Positions of discovered secret passages per room, empty for older saves
+    #[serde(default)]
+    pub secret_passages: HashMap<RoomId, Vec<(f32, f32)>>,
+    /// Stackable inventory items (matches) with their counts, empty for older saves
+    #[serde(default)]
+    pub inventory_stacks: Vec<(SerializedItem, u32)>,
+    /// Item assigned to each hotbar slot, empty for older saves
+    #[serde(default)]
+    pub hotbar: Vec<Option<SerializedItem>>,
+    /// Rule set the game is played with, Normal for older saves
+    #[serde(default)]
+    pub rules: GameRuleSet,
+    /// Difficulty the game is played at, Normal for older saves
+    #[serde(default)]
+    pub difficulty: Difficulty,
+    /// Gameplay statistics of the run, zeroed for older saves
+    #[serde(default)]
+    pub stats: GameStats,
+    /// Seed of the run's `GameRng`, `None` for older saves
+    #[serde(default)]
+    pub rng_seed: Option<u64>,
+    /// New Game+ cycle of the run (0 for a first run and older saves)
+    #[serde(default)]
+    pub new_game_plus_cycle: u32,
+    /// Cosmetics unlocked in earlier New Game+ cycles (none for older saves)
+    #[serde(default)]
+    pub new_game_plus_cosmetics: Vec<Cosmetic>,
+    /// Day of the Daily Escape being played (`None` outside the Daily
+    /// Escape and for older saves); its seed is `rng_seed`
+    #[serde(default)]
+    pub daily_day: Option<u64>,
+}
+
+fn default_hit_points() -> f32 {
+    DEFAULT_MAX_HIT_POINTS
+}
+
+fn default_stamina() -> f32 {
+    DEFAULT_MAX_STAMINA
 }
 
 /// Serializable representation of an inventory item
@@ -60,6 +183,10 @@ pub enum SerializedItem {
     DoubleJumpItem,
     /// Diary page with page number
     DiaryPage(usize),
+    /// Candle stub with its remaining wax
+    CandleStub(f32),
+    /// Oil can with its charges left
+    OilCan(u32),
 }
 
 /// Serializable key types for save system
@@ -86,10 +213,14 @@ pub enum SerializedToolType {
     WireCutters,
     /// Magnet for attracting metal objects
     Magnet,
-    /// Oil can for lubricating mechanisms
+    /// Full oil can (level data, and saves from before charges were kept)
     OilCan,
     /// Ladder for reaching high places
     Ladder,
+    /// Gas mask for breathing in poison gas rooms
+    GasMask,
+    /// Grappling rope for swinging from anchor points
+    GrapplingRope,
 }
 
 /// Serializable puzzle item types for save system
@@ -167,37 +298,134 @@ pub struct LoadGameEvent {
     pub slot: usize,
 }
 
+/// Event triggered when a save slot should be duplicated into another slot
+///
+/// Emitted by the save management UI. If the target slot already holds a
+/// save and `overwrite` is false, `copy_save_slot_system` asks for
+/// confirmation via `SaveSlotOverwriteRequest` instead of copying.
+#[derive(Event, Debug, Clone, Copy, PartialEq)]
+pub struct CopySaveSlotEvent {
+    /// Slot to copy from (0 for auto-save)
+    pub from: usize,
+    /// Slot to copy into
+    pub to: usize,
+    /// Whether an existing save in `to` may be replaced
+    pub overwrite: bool,
+}
+
+/// Event emitted when a save slot copy needs overwrite confirmation
+#[derive(Event, Debug, Clone, Copy, PartialEq)]
+pub struct SaveSlotOverwriteRequest {
+    /// Slot to copy from
+    pub from: usize,
+    /// Occupied slot that would be overwritten
+    pub to: usize,
+}
+
+/// Event triggered when the player names (or unnames) a save slot
+///
+/// Emitted by the save management UI with an already validated name.
+#[derive(Event, Debug, Clone, PartialEq)]
+pub struct RenameSaveSlotEvent {
+    /// Slot to rename (0 for auto-save)
+    pub slot: usize,
+    /// New name (`None` goes back to the default "Slot N" label)
+    pub name: Option<String>,
+}
+
+/// Player-set details of a save slot, stored in its metadata file
+///
+/// Kept apart from `SaveData` so renaming a slot doesn't rewrite the save,
+/// and so the name survives the slot being saved over.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct SaveSlotMeta {
+    /// Name the player gave the slot
+    #[serde(default)]
+    pub name: Option<String>,
+}
+
+/// Reads the metadata of `slot`
+///
+/// A slot without a metadata file (or with an unreadable one) has default
+/// metadata.
+pub fn read_slot_meta(storage: &impl SaveStorage, slot: usize) -> SaveSlotMeta {
+    storage
+        .read(&save_meta_file_name(slot))
+        .ok()
+        .and_then(|contents| ron::from_str(&contents).ok())
+        .unwrap_or_default()
+}
+
+/// Writes the metadata of `slot`
+///
+/// # Errors
+/// Returns error string if serialization or writing fails
+pub fn write_slot_meta(
+    storage: &impl SaveStorage,
+    slot: usize,
+    meta: &SaveSlotMeta,
+) -> Result<(), String> {
+    let contents = ron::ser::to_string_pretty(meta, ron::ser::PrettyConfig::default())
+        .map_err(|e| format!("Failed to serialize save slot metadata: {}", e))?;
+    storage.write(&save_meta_file_name(slot), &contents)
+}
+
 /// System that handles auto-save events
 ///
-/// Listens for `AutoSaveEvent` and saves the current game state to disk
-/// in RON format. The save file is stored in a platform-specific directory.
+/// Listens for `AutoSaveEvent` and saves the current game state in RON
+/// format through the `SaveBackend`.
 ///
 /// # System Dependencies
 /// - **Upstream**: Room transition system emits `AutoSaveEvent`
-/// - **Resources**: Reads `GameState`, `MapState`
+/// - **Resources**: Reads `GameState`, `MapState`, `RoomStateStore`, `GameRules`,
+///   `GameStats`, `GameRng`, `NewGamePlus`, `DailyChallenge`; writes through
+///   `SaveBackend`
 /// - **Components**: Queries `Player`, `Inventory`, `Candle`, `CandleWax`, `CandleState`
 ///
-/// # Save Location
+/// # Save Location (platform storage)
 /// - Linux: `~/.local/share/rust-game/save.ron`
 /// - Windows: `%APPDATA%/rust-game/save.ron`
 /// - macOS: `~/Library/Application Support/rust-game/save.ron`
+/// - Browser: `localStorage["rust-game/save.ron"]`
 ///
 /// From tasks.md T031: SaveLoadSystem
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
 pub fn auto_save_system(
     mut events: EventReader<AutoSaveEvent>,
+    storage: Res<SaveBackend>,
     game_state: Res<GameState>,
     map_state: Res<MapState>,
-    player_query: Query<(&Transform, &Inventory, Option<&DoubleJumpUnlocked>), With<Player>>,
-    candle_query: Query<(&CandleWax, &CandleState)>,
+    player_query: Query<
+        (
+            &Transform,
+            &Inventory,
+            Option<&DoubleJumpUnlocked>,
+            Option<&Hotbar>,
+        ),
+        With<Player>,
+    >,
+    hit_points_query: Query<&HitPoints, With<Player>>,
+    stamina_query: Query<&Stamina, With<Player>>,
+    candle_query: Query<(&CandleWax, &CandleState), Without<LevelEntityId>>,
+    room_state: Option<Res<RoomStateStore>>,
+    rules: Option<Res<GameRules>>,
+    stats: Option<Res<GameStats>>,
+    rng: Option<Res<GameRng>>,
+    new_game_plus: Option<Res<NewGamePlus>>,
+    daily: Option<Res<DailyChallenge>>,
 ) {
     for _ in events.read() {
         // Gather player data
-        let (player_position, inventory_items, double_jump_unlocked) =
-            if let Ok((transform, inventory, double_jump)) = player_query.single() {
+        let (player_position, (inventory_items, inventory_stacks), double_jump_unlocked, hotbar) =
+            if let Ok((transform, inventory, double_jump, hotbar)) = player_query.single() {
                 let pos = (transform.translation.x, transform.translation.y);
-                let items = inventory.items.iter().map(serialize_item).collect();
                 let has_double_jump = double_jump.is_some();
-                (pos, items, has_double_jump)
+                (
+                    pos,
+                    serialize_inventory(inventory),
+                    has_double_jump,
+                    hotbar.map(serialize_hotbar).unwrap_or_default(),
+                )
             } else {
                 // No player found, use defaults
                 (
@@ -205,8 +433,9 @@ pub fn auto_save_system(
                         game_state.player_spawn_point.x,
                         game_state.player_spawn_point.y,
                     ),
-                    vec![],
+                    (vec![], vec![]),
                     false,
+                    vec![],
                 )
             };
 
@@ -231,7 +460,7 @@ pub fn auto_save_system(
 
         // Create save data
         let save_data = SaveData {
-            version: 1,
+            version: SAVE_VERSION,
             current_room: game_state.current_room,
             player_position,
             inventory_items,
@@ -243,19 +472,66 @@ pub fn auto_save_system(
             collected_secrets: game_state.collected_secrets.len(),
             double_jump_unlocked,
             game_mode: serialize_game_mode(&game_state.game_mode),
+            hit_points: hit_points_query
+                .single()
+                .map(|hit_points| hit_points.current)
+                .unwrap_or(DEFAULT_MAX_HIT_POINTS),
+            stamina: stamina_query
+                .single()
+                .map(|stamina| stamina.current)
+                .unwrap_or(DEFAULT_MAX_STAMINA),
+            death_records: map_state.death_records.clone(),
+            secret_passages: map_state.secret_passages.clone(),
+            inventory_stacks,
+            hotbar,
+            light_states: room_state
+                .as_deref()
+                .map(serialize_light_states)
+                .unwrap_or_default(),
+            crate_positions: room_state
+                .as_deref()
+                .map(serialize_crate_positions)
+                .unwrap_or_default(),
+            broken_objects: room_state
+                .as_deref()
+                .map(serialize_broken_objects)
+                .unwrap_or_default(),
+            cut_wires: room_state
+                .as_deref()
+                .map(serialize_cut_wires)
+                .unwrap_or_default(),
+            oiled_objects: room_state
+                .as_deref()
+                .map(serialize_oiled_objects)
+                .unwrap_or_default(),
+            rules: rules
+                .as_deref()
+                .map_or(GameRuleSet::Normal, |rules| rules.rule_set),
+            difficulty: rules
+                .as_deref()
+                .map_or(Difficulty::Normal, |rules| rules.difficulty),
+            stats: stats.as_deref().cloned().unwrap_or_default(),
+            rng_seed: rng.as_deref().map(GameRng::seed),
+            new_game_plus_cycle: new_game_plus
+                .as_ref()
+                .map_or(0, |new_game_plus| new_game_plus.cycle),
+            new_game_plus_cosmetics: new_game_plus
+                .as_deref()
+                .map(|new_game_plus| new_game_plus.cosmetics.clone())
+                .unwrap_or_default(),
+            daily_day: daily.as_deref().and_then(|daily| daily.day),
         };
 
-        // Get save path
-        let save_path = get_save_path(0); // Slot 0 for auto-save
+        // Slot 0 for auto-save
+        let save_name = save_file_name(0);
 
         // Serialize to RON format
         match ron::ser::to_string_pretty(&save_data, ron::ser::PrettyConfig::default()) {
             Ok(ron_string) => {
-                // Write to file
-                if let Err(e) = fs::write(&save_path, ron_string) {
+                if let Err(e) = storage.write(&save_name, &ron_string) {
                     error!("Failed to save game: {}", e);
                 } else {
-                    info!("Game auto-saved to {:?}", save_path);
+                    info!("Game auto-saved to {}", storage.location(&save_name));
                 }
             }
             Err(e) => {
@@ -267,30 +543,65 @@ pub fn auto_save_system(
 
 /// System that handles manual save events
 ///
-/// Similar to auto_save_system but allows saving to specific slots.
+/// Similar to auto_save_system but allows saving to specific slots. Refused
+/// when `GameRules::manual_saves` is off (Survival).
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
 pub fn manual_save_system(
     mut events: EventReader<ManualSaveEvent>,
+    storage: Res<SaveBackend>,
     game_state: Res<GameState>,
     map_state: Res<MapState>,
-    player_query: Query<(&Transform, &Inventory, Option<&DoubleJumpUnlocked>), With<Player>>,
-    candle_query: Query<(&CandleWax, &CandleState)>,
+    player_query: Query<
+        (
+            &Transform,
+            &Inventory,
+            Option<&DoubleJumpUnlocked>,
+            Option<&Hotbar>,
+        ),
+        With<Player>,
+    >,
+    hit_points_query: Query<&HitPoints, With<Player>>,
+    stamina_query: Query<&Stamina, With<Player>>,
+    candle_query: Query<(&CandleWax, &CandleState), Without<LevelEntityId>>,
+    room_state: Option<Res<RoomStateStore>>,
+    rules: Option<Res<GameRules>>,
+    stats: Option<Res<GameStats>>,
+    rng: Option<Res<GameRng>>,
+    new_game_plus: Option<Res<NewGamePlus>>,
+    daily: Option<Res<DailyChallenge>>,
 ) {
     for event in events.read() {
+        if let Some(rules) = rules.as_deref()
+            && !rules.manual_saves
+        {
+            warn!(
+                "Manual saves are disabled in {} games, not saving to slot {}",
+                rules.rule_set.name(),
+                event.slot
+            );
+            continue;
+        }
+
         // Gather player data
-        let (player_position, inventory_items, double_jump_unlocked) =
-            if let Ok((transform, inventory, double_jump)) = player_query.single() {
+        let (player_position, (inventory_items, inventory_stacks), double_jump_unlocked, hotbar) =
+            if let Ok((transform, inventory, double_jump, hotbar)) = player_query.single() {
                 let pos = (transform.translation.x, transform.translation.y);
-                let items = inventory.items.iter().map(serialize_item).collect();
                 let has_double_jump = double_jump.is_some();
-                (pos, items, has_double_jump)
+                (
+                    pos,
+                    serialize_inventory(inventory),
+                    has_double_jump,
+                    hotbar.map(serialize_hotbar).unwrap_or_default(),
+                )
             } else {
                 (
                     (
                         game_state.player_spawn_point.x,
                         game_state.player_spawn_point.y,
                     ),
-                    vec![],
+                    (vec![], vec![]),
                     false,
+                    vec![],
                 )
             };
 
@@ -314,7 +625,7 @@ pub fn manual_save_system(
 
         // Create save data
         let save_data = SaveData {
-            version: 1,
+            version: SAVE_VERSION,
             current_room: game_state.current_room,
             player_position,
             inventory_items,
@@ -326,20 +637,68 @@ pub fn manual_save_system(
             collected_secrets: game_state.collected_secrets.len(),
             double_jump_unlocked,
             game_mode: serialize_game_mode(&game_state.game_mode),
+            hit_points: hit_points_query
+                .single()
+                .map(|hit_points| hit_points.current)
+                .unwrap_or(DEFAULT_MAX_HIT_POINTS),
+            stamina: stamina_query
+                .single()
+                .map(|stamina| stamina.current)
+                .unwrap_or(DEFAULT_MAX_STAMINA),
+            death_records: map_state.death_records.clone(),
+            secret_passages: map_state.secret_passages.clone(),
+            inventory_stacks,
+            hotbar,
+            light_states: room_state
+                .as_deref()
+                .map(serialize_light_states)
+                .unwrap_or_default(),
+            crate_positions: room_state
+                .as_deref()
+                .map(serialize_crate_positions)
+                .unwrap_or_default(),
+            broken_objects: room_state
+                .as_deref()
+                .map(serialize_broken_objects)
+                .unwrap_or_default(),
+            cut_wires: room_state
+                .as_deref()
+                .map(serialize_cut_wires)
+                .unwrap_or_default(),
+            oiled_objects: room_state
+                .as_deref()
+                .map(serialize_oiled_objects)
+                .unwrap_or_default(),
+            rules: rules
+                .as_deref()
+                .map_or(GameRuleSet::Normal, |rules| rules.rule_set),
+            difficulty: rules
+                .as_deref()
+                .map_or(Difficulty::Normal, |rules| rules.difficulty),
+            stats: stats.as_deref().cloned().unwrap_or_default(),
+            rng_seed: rng.as_deref().map(GameRng::seed),
+            new_game_plus_cycle: new_game_plus
+                .as_ref()
+                .map_or(0, |new_game_plus| new_game_plus.cycle),
+            new_game_plus_cosmetics: new_game_plus
+                .as_deref()
+                .map(|new_game_plus| new_game_plus.cosmetics.clone())
+                .unwrap_or_default(),
+            daily_day: daily.as_deref().and_then(|daily| daily.day),
         };
 
-        // Get save path for specific slot
-        let save_path = get_save_path(event.slot);
+        let save_name = save_file_name(event.slot);
 
         // Serialize to RON format
         match ron::ser::to_string_pretty(&save_data, ron::ser::PrettyConfig::default()) {
             Ok(ron_string) => {
-                if let Err(e) = fs::write(&save_path, ron_string) {
+                if let Err(e) = storage.write(&save_name, &ron_string) {
                     error!("Failed to save game to slot {}: {}", event.slot, e);
                 } else {
                     info!(
-                        "Game manually saved to slot {} at {:?}",
-                        event.slot, save_path
+                        "Game manually saved to slot {} at {}",
+                        event.slot,
+                        storage.location(&save_name)
                     );
                 }
             }
@@ -357,41 +716,56 @@ type PlayerLoadQuery<'a> = (
     &'a mut Inventory,
     &'a mut Health,
     Option<&'a DoubleJumpUnlocked>,
+    Option<&'a mut HitPoints>,
+    Option<&'a mut Stamina>,
+    Option<&'a mut Hotbar>,
 );
 
 /// System that handles load game events
 ///
 /// Loads game state from a save file and restores the world to that state.
 /// If the save file doesn't exist, the game starts with default state.
+/// Saves from older versions are migrated with `save_migration::migrate_save`;
+/// saves from newer versions are refused.
 ///
 /// # System Dependencies
 /// - **Commands**: Modifies world entities and resources
-/// - **Resources**: Writes `GameState`, `MapState`
-/// - **Components**: Modifies `Player`, `Inventory`, `Candle` components
+/// - **Resources**: Reads the save through `SaveBackend`; writes `GameState`,
+///   `MapState`, `RoomStateStore`, `GameRules`, `GameStats`, `GameRng`,
+///   `NewGamePlus`, `DailyChallenge`
+/// - **Components**: Modifies `Player`, `Inventory`, `Stamina`, `Candle` components
 ///
 /// From tasks.md T031: SaveLoadSystem
+#[allow(clippy::too_many_arguments)]
 pub fn load_game_system(
     mut events: EventReader<LoadGameEvent>,
+    storage: Res<SaveBackend>,
     mut commands: Commands,
     mut game_state: ResMut<GameState>,
     mut map_state: ResMut<MapState>,
     mut player_query: Query<PlayerLoadQuery, With<Player>>,
-    mut candle_query: Query<(&mut CandleWax, &mut CandleState)>,
+    mut candle_query: Query<(&mut CandleWax, &mut CandleState), Without<LevelEntityId>>,
+    mut room_state: Option<ResMut<RoomStateStore>>,
+    mut rules: Option<ResMut<GameRules>>,
+    mut stats: Option<ResMut<GameStats>>,
+    mut rng: Option<ResMut<GameRng>>,
+    mut new_game_plus: Option<ResMut<NewGamePlus>>,
+    mut daily: Option<ResMut<DailyChallenge>>,
 ) {
     for event in events.read() {
-        let save_path = get_save_path(event.slot);
+        let save_name = save_file_name(event.slot);
 
         // Check if save file exists
-        if !save_path.exists() {
+        if !storage.exists(&save_name) {
             warn!(
-                "Save file at {:?} does not exist. Starting with default state.",
-                save_path
+                "Save file at {} does not exist. Starting with default state.",
+                storage.location(&save_name)
             );
             continue;
         }
 
         // Read save file
-        let save_content = match fs::read_to_string(&save_path) {
+        let save_content = match storage.read(&save_name) {
             Ok(content) => content,
             Err(e) => {
                 error!("Failed to read save file: {}", e);
@@ -399,11 +773,16 @@ pub fn load_game_system(
             }
         };
 
-        // Deserialize save data
-        let save_data: SaveData = match ron::from_str(&save_content) {
-            Ok(data) => data,
+        // Deserialize save data, bringing saves from older versions up to date
+        let save_data = match decode_save(&save_content) {
+            Ok((data, changes)) => {
+                for change in changes {
+                    info!("Migrated save slot {}: {}", event.slot, change);
+                }
+                data
+            }
             Err(e) => {
-                error!("Failed to deserialize save data: {}", e);
+                error!("Can't load save slot {}: {}", event.slot, e);
                 continue;
             }
         };
@@ -416,15 +795,69 @@ pub fn load_game_system(
         game_state.deaths = save_data.deaths;
         game_state.game_mode = deserialize_game_mode(&save_data.game_mode);
 
+        // Restore the rules the game was started with
+        if let Some(rules) = rules.as_mut() {
+            **rules =
+                GameRules::for_rule_set(save_data.rules).with_difficulty(save_data.difficulty);
+        }
+        if let Some(stats) = stats.as_mut() {
+            **stats = save_data.stats.clone();
+        }
+        // Restart the run's random stream from its seed
+        if let (Some(rng), Some(seed)) = (rng.as_mut(), save_data.rng_seed) {
+            rng.reseed(seed);
+        }
+        // The cycle and seed together pick the New Game+ remix of the house
+        if let Some(new_game_plus) = new_game_plus.as_mut() {
+            new_game_plus.cycle = save_data.new_game_plus_cycle;
+            new_game_plus.cosmetics = save_data.new_game_plus_cosmetics.clone();
+        }
+        // A Daily Escape keeps its day, so the daily remix is rebuilt
+        if let Some(daily) = daily.as_mut() {
+            **daily = DailyChallenge {
+                day: save_data.daily_day,
+                result: None,
+            };
+        }
+
         // Restore map state
         map_state.explored_rooms.clear();
         for room_id in save_data.explored_rooms {
             map_state.mark_explored(room_id);
         }
+        map_state.death_records = save_data.death_records;
+        map_state.secret_passages = save_data.secret_passages;
+
+        // Restore level light states, crate positions, broken objects and
+        // cut wires (re-applied by the RoomStatePlugin and ElectricalPlugin
+        // systems)
+        if let Some(room_state) = room_state.as_mut() {
+            room_state.light_states = save_data
+                .light_states
+                .iter()
+                .map(|(id, state)| (*id, deserialize_candle_state(state)))
+                .collect();
+            room_state.crate_positions = save_data
+                .crate_positions
+                .iter()
+                .map(|(id, position)| (*id, Vec2::from(*position)))
+                .collect();
+            room_state.broken = save_data.broken_objects.iter().copied().collect();
+            room_state.cut_wires = save_data.cut_wires.iter().cloned().collect();
+            room_state.oiled = save_data.oiled_objects.iter().copied().collect();
+        }
 
         // Restore player state
-        if let Ok((entity, mut transform, mut inventory, mut health, double_jump)) =
-            player_query.single_mut()
+        if let Ok((
+            entity,
+            mut transform,
+            mut inventory,
+            mut health,
+            double_jump,
+            hit_points,
+            stamina,
+            hotbar,
+        )) = player_query.single_mut()
         {
             // Update player position
             transform.translation.x = save_data.player_position.0;
@@ -432,15 +865,39 @@ pub fn load_game_system(
             transform.translation.z = 0.0;
 
             // Restore inventory
-            inventory.items = save_data
-                .inventory_items
-                .iter()
-                .map(deserialize_item)
-                .collect();
+            inventory.items =
+                deserialize_inventory(&save_data.inventory_items, &save_data.inventory_stacks);
+
+            // Restore hotbar assignments
+            let saved_hotbar = deserialize_hotbar(&save_data.hotbar);
+            match hotbar {
+                Some(mut hotbar) => *hotbar = saved_hotbar,
+                None => {
+                    commands.entity(entity).insert(saved_hotbar);
+                }
+            }
 
             // Ensure player is alive
             *health = Health::Alive;
 
+            // Restore hit points (never load into a depleted state)
+            if let Some(mut hit_points) = hit_points {
+                if save_data.hit_points > 0.0 {
+                    hit_points.current = save_data.hit_points.min(hit_points.max);
+                } else {
+                    hit_points.restore();
+                }
+            }
+
+            // Restore sprint stamina (a player saved mid-sprint loads walking)
+            if let Some(mut stamina) = stamina {
+                *stamina = Stamina {
+                    current: save_data.stamina.max(0.0),
+                    sprinting: false,
+                    exhausted: save_data.stamina <= 0.0,
+                };
+            }
+
             // Handle double jump unlock
             if save_data.double_jump_unlocked && double_jump.is_none() {
                 commands.entity(entity).insert(DoubleJumpUnlocked);
@@ -455,7 +912,11 @@ pub fn load_game_system(
             *state = deserialize_candle_state(&save_data.candle_state);
         }
 
-        info!("Game loaded from slot {} at {:?}", event.slot, save_path);
+        info!(
+            "Game loaded from slot {} at {}",
+            event.slot,
+            storage.location(&save_name)
+        );
     }
 }
 
@@ -468,7 +929,24 @@ pub fn load_game_system(
 /// - Linux: `~/.local/share/rust-game/save{slot}.ron`
 /// - Windows: `%APPDATA%/rust-game/save{slot}.ron`
 /// - macOS: `~/Library/Application Support/rust-game/save{slot}.ron`
+///
+/// Not available in browser builds, which keep saves in localStorage
+/// (see `save_storage::LocalStorage`).
+#[cfg(not(target_arch = "wasm32"))]
 pub fn get_save_path(slot: usize) -> PathBuf {
+    let mut path = get_data_dir();
+
+    // Append slot-specific filename
+    path.push(save_file_name(slot));
+
+    path
+}
+
+/// Returns the platform-specific directory holding saves and screenshots
+///
+/// Created if it doesn't exist yet. See `get_save_path` for the paths.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn get_data_dir() -> PathBuf {
     let project_dirs = directories::ProjectDirs::from("com", "example", "rust-game")
         .expect("Failed to determine data directory");
 
@@ -478,20 +956,223 @@ pub fn get_save_path(slot: usize) -> PathBuf {
     // Create directory if it doesn't exist
     fs::create_dir_all(&path).ok();
 
-    // Append slot-specific filename
-    let filename = if slot == 0 {
-        "save.ron".to_string()
+    path
+}
+
+/// Returns the file name stem shared by every file of a save slot
+#[cfg(not(target_arch = "wasm32"))]
+fn save_file_stem(slot: usize) -> String {
+    if slot == 0 {
+        "save".to_string()
     } else {
-        format!("save{}.ron", slot)
-    };
-    path.push(filename);
+        format!("save{}", slot)
+    }
+}
 
-    path
+/// Returns true if `dir` contains a save file for `slot`
+#[cfg(not(target_arch = "wasm32"))]
+pub fn save_slot_exists_in(dir: &Path, slot: usize) -> bool {
+    dir.join(format!("{}.ron", save_file_stem(slot))).is_file()
+}
+
+/// Copy every file of save slot `from` into slot `to` inside `dir`
+///
+/// The copy is atomic with respect to failures: all files are first staged
+/// as `*.tmp` files next to their destination. Only once every file has
+/// been staged are the target slot's old files moved aside as `*.bak` and
+/// the staged files renamed into place. If any step fails, the staged and
+/// already placed files are removed and the old files restored, so the
+/// target slot is either the complete copy or untouched. Stale companion
+/// files in the target slot (e.g. an old screenshot the source slot
+/// doesn't have) are removed so the copy mirrors the source exactly.
+///
+/// # Arguments
+/// * `dir` - Directory holding the save files
+/// * `from` - Source slot (0 for auto-save)
+/// * `to` - Target slot
+/// * `overwrite` - Whether an existing save in `to` may be replaced
+///
+/// # Errors
+/// Returns error string if the slots are identical, the source slot is
+/// empty, the target is occupied without `overwrite`, or a file cannot be
+/// copied or moved into place
+#[cfg(not(target_arch = "wasm32"))]
+pub fn copy_save_slot_in(
+    dir: &Path,
+    from: usize,
+    to: usize,
+    overwrite: bool,
+) -> Result<(), String> {
+    if from == to {
+        return Err(format!("Cannot copy save slot {} onto itself", from));
+    }
+    if !save_slot_exists_in(dir, from) {
+        return Err(format!("Save slot {} is empty", from));
+    }
+    if !overwrite && save_slot_exists_in(dir, to) {
+        return Err(format!("Save slot {} is occupied", to));
+    }
+
+    let from_stem = save_file_stem(from);
+    let to_stem = save_file_stem(to);
+
+    // Stage every file of the source slot next to its destination
+    let mut copy = SlotCopy::default();
+    for extension in SAVE_SLOT_EXTENSIONS {
+        let source = dir.join(format!("{}.{}", from_stem, extension));
+        let target = dir.join(format!("{}.{}", to_stem, extension));
+        if target.exists() {
+            copy.replaced.push(target.clone());
+        }
+        if !source.exists() {
+            continue;
+        }
+
+        let temp = dir.join(format!("{}.{}.tmp", to_stem, extension));
+        copy.staged.push((temp.clone(), target));
+        if let Err(e) = fs::copy(&source, &temp) {
+            copy.roll_back();
+            return Err(format!(
+                "Failed to copy save file '{}': {}",
+                source.display(),
+                e
+            ));
+        }
+    }
+
+    // Move the old files (replaced and stale) aside
+    for target in copy.replaced.clone() {
+        let backup = backup_path(&target);
+        if let Err(e) = fs::rename(&target, &backup) {
+            copy.roll_back();
+            return Err(format!(
+                "Failed to move save file '{}' aside: {}",
+                target.display(),
+                e
+            ));
+        }
+        copy.backed_up.push(target);
+    }
+
+    // Move companions into place first so the slot only shows up complete
+    for (temp, target) in copy.staged.clone().into_iter().rev() {
+        if let Err(e) = fs::rename(&temp, &target) {
+            copy.roll_back();
+            return Err(format!(
+                "Failed to write save file '{}': {}",
+                target.display(),
+                e
+            ));
+        }
+        copy.placed.push(target);
+    }
+
+    for target in &copy.backed_up {
+        let _ = fs::remove_file(backup_path(target));
+    }
+    Ok(())
+}
+
+/// Files touched so far by `copy_save_slot_in`, for rolling back a failed copy
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Default)]
+struct SlotCopy {
+    /// Staged `*.tmp` files and the target each is renamed to
+    staged: Vec<(PathBuf, PathBuf)>,
+    /// Target files that existed before the copy
+    replaced: Vec<PathBuf>,
+    /// Targets moved aside to their `backup_path`
+    backed_up: Vec<PathBuf>,
+    /// Targets the copy has renamed into place
+    placed: Vec<PathBuf>,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl SlotCopy {
+    /// Removes the staged and placed files and restores the old ones
+    fn roll_back(&self) {
+        for (temp, _) in &self.staged {
+            let _ = fs::remove_file(temp);
+        }
+        for target in &self.placed {
+            let _ = fs::remove_file(target);
+        }
+        for target in &self.backed_up {
+            let _ = fs::rename(backup_path(target), target);
+        }
+    }
+}
+
+/// Where `copy_save_slot_in` keeps a target file while replacing it
+#[cfg(not(target_arch = "wasm32"))]
+fn backup_path(target: &Path) -> PathBuf {
+    let mut backup = target.as_os_str().to_owned();
+    backup.push(".bak");
+    PathBuf::from(backup)
+}
+
+/// System that duplicates save slots on request
+///
+/// # Behavior
+/// - Target slot empty, or `overwrite` set: copies the slot with
+///   `SaveStorage::copy_slot` (`copy_save_slot_in` for save files on disk)
+/// - Target slot occupied and `overwrite` unset: emits
+///   `SaveSlotOverwriteRequest` so the UI can ask the player to confirm
+///
+/// # System Dependencies
+/// - **Upstream**: Save management UI emits `CopySaveSlotEvent`
+/// - **Resources**: Reads and writes the slots through `SaveBackend`
+/// - **Downstream**: Save management UI reads `SaveSlotOverwriteRequest`
+pub fn copy_save_slot_system(
+    mut events: EventReader<CopySaveSlotEvent>,
+    mut overwrite_requests: EventWriter<SaveSlotOverwriteRequest>,
+    storage: Res<SaveBackend>,
+) {
+    for event in events.read() {
+        if !event.overwrite && storage.exists(&save_file_name(event.to)) {
+            overwrite_requests.write(SaveSlotOverwriteRequest {
+                from: event.from,
+                to: event.to,
+            });
+            continue;
+        }
+
+        match storage.copy_slot(event.from, event.to) {
+            Ok(()) => info!("Copied save slot {} to slot {}", event.from, event.to),
+            Err(e) => error!("{}", e),
+        }
+    }
+}
+
+/// System that stores save slot names
+///
+/// # System Dependencies
+/// - **Upstream**: Save management UI emits `RenameSaveSlotEvent`
+/// - **Resources**: Reads and writes slot metadata through `SaveBackend`
+///
+/// # Behavior
+/// Rewrites the slot's metadata file, keeping any other metadata it holds.
+pub fn rename_save_slot_system(
+    mut events: EventReader<RenameSaveSlotEvent>,
+    storage: Res<SaveBackend>,
+) {
+    for event in events.read() {
+        let meta = SaveSlotMeta {
+            name: event.name.clone(),
+            ..read_slot_meta(&*storage, event.slot)
+        };
+
+        match write_slot_meta(&*storage, event.slot, &meta) {
+            Ok(()) => info!("Renamed save slot {} to {:?}", event.slot, event.name),
+            Err(e) => error!("{}", e),
+        }
+    }
 }
 
 // Helper conversion functions
 
-fn serialize_item(item: &Item) -> SerializedItem {
+/// Converts an item into its serialized form (as saved, or as level data names it)
+pub fn serialize_item(item: &Item) -> SerializedItem {
     match item {
         Item::Match => SerializedItem::Match,
         Item::Key(key_type) => SerializedItem::Key(match key_type {
@@ -500,13 +1181,16 @@ fn serialize_item(item: &Item) -> SerializedItem {
             KeyType::Ornate => SerializedKeyType::Ornate,
             KeyType::Master => SerializedKeyType::Master,
         }),
+        Item::Tool(ToolType::OilCan(charges)) => SerializedItem::OilCan(*charges),
         Item::Tool(tool_type) => SerializedItem::Tool(match tool_type {
             ToolType::Wrench => SerializedToolType::Wrench,
             ToolType::Crowbar => SerializedToolType::Crowbar,
             ToolType::WireCutters => SerializedToolType::WireCutters,
             ToolType::Magnet => SerializedToolType::Magnet,
-            ToolType::OilCan => SerializedToolType::OilCan,
+            ToolType::OilCan(_) => SerializedToolType::OilCan,
             ToolType::Ladder => SerializedToolType::Ladder,
+            ToolType::GasMask => SerializedToolType::GasMask,
+            ToolType::GrapplingRope => SerializedToolType::GrapplingRope,
         }),
         Item::PuzzleItem(puzzle_type) => SerializedItem::PuzzleItem(match puzzle_type {
             PuzzleItemType::Fuse => SerializedPuzzleItemType::Fuse,
@@ -525,10 +1209,12 @@ fn serialize_item(item: &Item) -> SerializedItem {
         }),
         Item::DoubleJumpItem => SerializedItem::DoubleJumpItem,
         Item::DiaryPage(n) => SerializedItem::DiaryPage(*n),
+        Item::CandleStub(wax) => SerializedItem::CandleStub(*wax),
     }
 }
 
-fn deserialize_item(item: &SerializedItem) -> Item {
+/// Converts a serialized item (from a save file or level data) back into an `Item`
+pub fn deserialize_item(item: &SerializedItem) -> Item {
     match item {
         SerializedItem::Match => Item::Match,
         SerializedItem::Key(key_type) => Item::Key(match key_type {
@@ -542,8 +1228,10 @@ fn deserialize_item(item: &SerializedItem) -> Item {
             SerializedToolType::Crowbar => ToolType::Crowbar,
             SerializedToolType::WireCutters => ToolType::WireCutters,
             SerializedToolType::Magnet => ToolType::Magnet,
-            SerializedToolType::OilCan => ToolType::OilCan,
+            SerializedToolType::OilCan => ToolType::OilCan(OIL_CAN_CHARGES),
             SerializedToolType::Ladder => ToolType::Ladder,
+            SerializedToolType::GasMask => ToolType::GasMask,
+            SerializedToolType::GrapplingRope => ToolType::GrapplingRope,
         }),
         SerializedItem::PuzzleItem(puzzle_type) => Item::PuzzleItem(match puzzle_type {
             SerializedPuzzleItemType::Fuse => PuzzleItemType::Fuse,
@@ -560,9 +1248,79 @@ fn deserialize_item(item: &SerializedItem) -> Item {
         }),
         SerializedItem::DoubleJumpItem => Item::DoubleJumpItem,
         SerializedItem::DiaryPage(n) => Item::DiaryPage(*n),
+        SerializedItem::CandleStub(wax) => Item::CandleStub(*wax),
+        SerializedItem::OilCan(charges) => Item::Tool(ToolType::OilCan(*charges)),
     }
 }
 
+/// Serializes the inventory as its items plus the size of each stack
+///
+/// A stackable item (match) is listed once in the items, at its first
+/// position, with its count in the stacks.
+fn serialize_inventory(inventory: &Inventory) -> (Vec<SerializedItem>, Vec<(SerializedItem, u32)>) {
+    let mut items = Vec::new();
+    let mut stacks = Vec::new();
+    for (index, item) in inventory.items.iter().enumerate() {
+        if item.max_stack() <= 1 {
+            items.push(serialize_item(item));
+        } else if !inventory.items[..index]
+            .iter()
+            .any(|seen| seen.same_kind(item))
+        {
+            items.push(serialize_item(item));
+            stacks.push((serialize_item(item), inventory.count(item) as u32));
+        }
+    }
+    (items, stacks)
+}
+
+/// Rebuilds the inventory items from the saved items and stack sizes
+///
+/// Older saves have no stacks and list every match on its own. Stack sizes
+/// are clamped to `MAX_LOADED_STACK`.
+pub fn deserialize_inventory(
+    items: &[SerializedItem],
+    stacks: &[(SerializedItem, u32)],
+) -> Vec<Item> {
+    let stacks: Vec<(Item, u32)> = stacks
+        .iter()
+        .map(|(item, count)| (deserialize_item(item), (*count).min(MAX_LOADED_STACK)))
+        .collect();
+
+    let mut inventory: Vec<Item> = Vec::new();
+    for item in items.iter().map(deserialize_item) {
+        match stacks.iter().find(|(kind, _)| kind.same_kind(&item)) {
+            Some(_) if inventory.iter().any(|seen| seen.same_kind(&item)) => {}
+            Some((_, count)) => inventory.extend(std::iter::repeat_n(item, *count as usize)),
+            None => inventory.push(item),
+        }
+    }
+    inventory
+}
+
+/// Serializes the hotbar as the item assigned to each slot
+fn serialize_hotbar(hotbar: &Hotbar) -> Vec<Option<SerializedItem>> {
+    hotbar
+        .slots
+        .iter()
+        .map(|item| item.as_ref().map(serialize_item))
+        .collect()
+}
+
+/// Rebuilds the hotbar from the saved slots
+///
+/// Older saves have no slots; extra slots and items that can't go on the
+/// hotbar are dropped.
+pub fn deserialize_hotbar(slots: &[Option<SerializedItem>]) -> Hotbar {
+    let mut hotbar = Hotbar::default();
+    for (slot, item) in slots.iter().take(HOTBAR_SLOTS).enumerate() {
+        if let Some(item) = item {
+            hotbar.assign(slot, deserialize_item(item));
+        }
+    }
+    hotbar
+}
+
 fn serialize_candle_state(state: &CandleState) -> SerializedCandleState {
     match state {
         CandleState::Unlit => SerializedCandleState::Unlit,
@@ -579,6 +1337,44 @@ fn deserialize_candle_state(state: &SerializedCandleState) -> CandleState {
     }
 }
 
+fn serialize_light_states(store: &RoomStateStore) -> Vec<(LevelEntityId, SerializedCandleState)> {
+    let mut states: Vec<_> = store
+        .light_states
+        .iter()
+        .map(|(id, state)| (*id, serialize_candle_state(state)))
+        .collect();
+    states.sort_by_key(|(id, _)| *id);
+    states
+}
+
+fn serialize_crate_positions(store: &RoomStateStore) -> Vec<(LevelEntityId, (f32, f32))> {
+    let mut positions: Vec<_> = store
+        .crate_positions
+        .iter()
+        .map(|(id, position)| (*id, (*position).into()))
+        .collect();
+    positions.sort_by_key(|(id, _)| *id);
+    positions
+}
+
+fn serialize_broken_objects(store: &RoomStateStore) -> Vec<LevelEntityId> {
+    let mut broken: Vec<_> = store.broken.iter().copied().collect();
+    broken.sort();
+    broken
+}
+
+fn serialize_cut_wires(store: &RoomStateStore) -> Vec<String> {
+    let mut wires: Vec<_> = store.cut_wires.iter().cloned().collect();
+    wires.sort();
+    wires
+}
+
+fn serialize_oiled_objects(store: &RoomStateStore) -> Vec<LevelEntityId> {
+    let mut oiled: Vec<_> = store.oiled.iter().copied().collect();
+    oiled.sort();
+    oiled
+}
+
 fn serialize_game_mode(mode: &GameMode) -> SerializedGameMode {
     match mode {
         GameMode::Menu => SerializedGameMode::Menu,
@@ -602,6 +1398,7 @@ fn deserialize_game_mode(mode: &SerializedGameMode) -> GameMode {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::resources::map_state::DeathCause;
 
     #[test]
     fn save_data_serializes_to_ron() {
@@ -621,6 +1418,37 @@ mod tests {
             collected_secrets: 2,
             double_jump_unlocked: true,
             game_mode: SerializedGameMode::Playing,
+            hit_points: 80.0,
+            stamina: 35.0,
+            death_records: HashMap::from([(
+                2,
+                vec![DeathRecord {
+                    position: (40.0, 60.0),
+                    cause: DeathCause::Spikes,
+                }],
+            )]),
+            light_states: vec![(
+                LevelEntityId { room: 2, index: 4 },
+                SerializedCandleState::Lit,
+            )],
+            crate_positions: vec![(LevelEntityId { room: 2, index: 7 }, (160.0, 0.0))],
+            broken_objects: vec![LevelEntityId { room: 2, index: 9 }],
+            cut_wires: vec!["cellar_floor".to_string()],
+            oiled_objects: vec![LevelEntityId { room: 4, index: 1 }],
+            secret_passages: HashMap::from([(2, vec![(320.0, 64.0)])]),
+            inventory_stacks: vec![(SerializedItem::Match, 42)],
+            hotbar: vec![Some(SerializedItem::Match), None],
+            rules: GameRuleSet::Survival,
+            difficulty: Difficulty::Hard,
+            stats: GameStats {
+                matches_used: 4,
+                rooms_visited: [0, 2].into(),
+                ..default()
+            },
+            rng_seed: Some(0xC0FFEE),
+            new_game_plus_cycle: 2,
+            new_game_plus_cosmetics: vec![Cosmetic::PaleFlame],
+            daily_day: Some(20_743),
         };
 
         let ron_string = ron::ser::to_string_pretty(&save_data, ron::ser::PrettyConfig::default())
@@ -630,6 +1458,141 @@ mod tests {
         assert!(ron_string.contains("version: 1"));
         assert!(ron_string.contains("current_room: 2"));
         assert!(ron_string.contains("player_position: (150.0, 200.0)"));
+
+        let round_trip: SaveData = ron::from_str(&ron_string).expect("Failed to deserialize");
+        assert_eq!(round_trip.death_records[&2][0].cause, DeathCause::Spikes);
+        assert_eq!(round_trip.light_states, save_data.light_states);
+        assert_eq!(round_trip.crate_positions, save_data.crate_positions);
+        assert_eq!(round_trip.broken_objects, save_data.broken_objects);
+        assert_eq!(round_trip.cut_wires, save_data.cut_wires);
+        assert_eq!(round_trip.oiled_objects, save_data.oiled_objects);
+        assert_eq!(round_trip.secret_passages, save_data.secret_passages);
+        assert_eq!(round_trip.inventory_stacks.len(), 1);
+        assert_eq!(round_trip.hotbar, save_data.hotbar);
+        assert_eq!(round_trip.rules, GameRuleSet::Survival);
+        assert_eq!(round_trip.difficulty, Difficulty::Hard);
+        assert_eq!(round_trip.stats, save_data.stats);
+        assert_eq!(round_trip.rng_seed, Some(0xC0FFEE));
+        assert_eq!(round_trip.new_game_plus_cycle, 2);
+        assert_eq!(
+            round_trip.new_game_plus_cosmetics,
+            vec![Cosmetic::PaleFlame]
+        );
+        assert_eq!(round_trip.daily_day, Some(20_743));
+        assert_eq!(round_trip.stamina, 35.0);
+    }
+
+    #[test]
+    fn hotbar_round_trips_through_save_data() {
+        let mut hotbar = Hotbar::default();
+        hotbar.assign(0, Item::Match);
+        hotbar.assign(3, Item::Tool(ToolType::OilCan(2)));
+
+        let slots = serialize_hotbar(&hotbar);
+        assert_eq!(
+            slots,
+            vec![
+                Some(SerializedItem::Match),
+                None,
+                None,
+                Some(SerializedItem::OilCan(2)),
+            ]
+        );
+
+        let restored = deserialize_hotbar(&slots);
+        assert!(matches!(restored.get(0), Some(Item::Match)));
+        assert!(restored.get(1).is_none());
+        assert!(matches!(
+            restored.get(3),
+            Some(Item::Tool(ToolType::OilCan(2)))
+        ));
+
+        // Saves from before charges were kept hold full cans
+        assert!(matches!(
+            deserialize_item(&SerializedItem::Tool(SerializedToolType::OilCan)),
+            Item::Tool(ToolType::OilCan(OIL_CAN_CHARGES))
+        ));
+
+        // Keys can't be assigned, so a tampered save doesn't put one there
+        let tampered = deserialize_hotbar(&[Some(SerializedItem::Key(SerializedKeyType::Iron))]);
+        assert!(tampered.get(0).is_none());
+    }
+
+    #[test]
+    fn inventory_saves_matches_as_one_stack() {
+        let inventory = Inventory {
+            items: vec![
+                Item::Match,
+                Item::Key(KeyType::Brass),
+                Item::Match,
+                Item::Match,
+            ],
+            max_capacity: 10,
+        };
+
+        let (items, stacks) = serialize_inventory(&inventory);
+        assert!(matches!(
+            items.as_slice(),
+            [
+                SerializedItem::Match,
+                SerializedItem::Key(SerializedKeyType::Brass)
+            ]
+        ));
+        assert!(matches!(stacks.as_slice(), [(SerializedItem::Match, 3)]));
+
+        let restored = deserialize_inventory(&items, &stacks);
+        assert_eq!(restored.len(), 4);
+        assert!(matches!(
+            restored[..3],
+            [Item::Match, Item::Match, Item::Match]
+        ));
+        assert!(matches!(restored[3], Item::Key(KeyType::Brass)));
+
+        // Older saves listed every match on its own
+        let legacy = deserialize_inventory(&[SerializedItem::Match, SerializedItem::Match], &[]);
+        assert_eq!(legacy.len(), 2);
+    }
+
+    #[test]
+    fn candle_stubs_keep_their_own_wax() {
+        let inventory = Inventory {
+            items: vec![
+                Item::CandleStub(35.0),
+                Item::CandleStub(35.0),
+                Item::CandleStub(80.0),
+            ],
+            max_capacity: 10,
+        };
+
+        let (items, stacks) = serialize_inventory(&inventory);
+        assert_eq!(
+            items,
+            vec![
+                SerializedItem::CandleStub(35.0),
+                SerializedItem::CandleStub(35.0),
+                SerializedItem::CandleStub(80.0)
+            ]
+        );
+        assert!(stacks.is_empty());
+
+        let restored = deserialize_inventory(&items, &stacks);
+        assert!(matches!(
+            restored[..],
+            [
+                Item::CandleStub(35.0),
+                Item::CandleStub(35.0),
+                Item::CandleStub(80.0)
+            ]
+        ));
+    }
+
+    #[test]
+    fn corrupted_stack_sizes_are_clamped() {
+        let restored = deserialize_inventory(
+            &[SerializedItem::Match],
+            &[(SerializedItem::Match, u32::MAX)],
+        );
+        assert_eq!(restored.len(), MAX_LOADED_STACK as usize);
     }
 
     #[test]
@@ -656,6 +1619,16 @@ mod tests {
         assert_eq!(save_data.player_position, (100.0, 50.0));
         assert_eq!(save_data.inventory_items.len(), 2);
         assert_eq!(save_data.candle_wax, 50.0);
+        assert_eq!(
+            save_data.hit_points, DEFAULT_MAX_HIT_POINTS,
+            "Saves without hit points default to full health"
+        );
+        assert_eq!(save_data.stamina, DEFAULT_MAX_STAMINA);
+        assert!(save_data.death_records.is_empty());
+        assert!(save_data.inventory_stacks.is_empty());
+        assert_eq!(save_data.stats, GameStats::default());
+        assert_eq!(save_data.rules, GameRuleSet::Normal);
+        assert_eq!(save_data.rng_seed, None);
     }
 
     #[test]
@@ -668,6 +1641,164 @@ mod tests {
         assert!(path_slot1.ends_with("save1.ron"));
     }
 
+    fn temp_save_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("rust-game-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn copy_save_slot_copies_save_and_companions() {
+        let dir = temp_save_dir("copy-slot");
+        fs::write(dir.join("save1.ron"), "save one").unwrap();
+        fs::write(dir.join("save1.meta.ron"), "meta one").unwrap();
+        fs::write(dir.join("save1.png"), "png one").unwrap();
+
+        copy_save_slot_in(&dir, 1, 2, false).unwrap();
+
+        assert_eq!(
+            fs::read_to_string(dir.join("save2.ron")).unwrap(),
+            "save one"
+        );
+        assert_eq!(
+            fs::read_to_string(dir.join("save2.meta.ron")).unwrap(),
+            "meta one"
+        );
+        assert_eq!(
+            fs::read_to_string(dir.join("save2.png")).unwrap(),
+            "png one"
+        );
+        assert!(save_slot_exists_in(&dir, 1), "Source slot must be kept");
+
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn copy_save_slot_requires_overwrite_for_occupied_target() {
+        let dir = temp_save_dir("copy-occupied");
+        fs::write(dir.join("save.ron"), "auto").unwrap();
+        fs::write(dir.join("save3.ron"), "three").unwrap();
+        fs::write(dir.join("save3.png"), "old screenshot").unwrap();
+
+        let err = copy_save_slot_in(&dir, 0, 3, false).unwrap_err();
+        assert!(err.contains("occupied"));
+        assert_eq!(fs::read_to_string(dir.join("save3.ron")).unwrap(), "three");
+
+        copy_save_slot_in(&dir, 0, 3, true).unwrap();
+        assert_eq!(fs::read_to_string(dir.join("save3.ron")).unwrap(), "auto");
+        assert!(
+            !dir.join("save3.png").exists(),
+            "Stale screenshot should not survive an overwrite"
+        );
+
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn copy_save_slot_rejects_empty_source_and_same_slot() {
+        let dir = temp_save_dir("copy-invalid");
+        assert!(
+            copy_save_slot_in(&dir, 1, 2, true)
+                .unwrap_err()
+                .contains("empty")
+        );
+
+        fs::write(dir.join("save1.ron"), "one").unwrap();
+        assert!(copy_save_slot_in(&dir, 1, 1, true).is_err());
+
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn partial_copy_failure_leaves_target_untouched() {
+        let dir = temp_save_dir("copy-partial");
+        fs::write(dir.join("save1.ron"), "new").unwrap();
+        fs::write(dir.join("save1.meta.ron"), "new meta").unwrap();
+        // A directory where the screenshot should be makes its copy fail
+        fs::create_dir_all(dir.join("save1.png")).unwrap();
+        fs::write(dir.join("save2.ron"), "old").unwrap();
+        fs::write(dir.join("save2.meta.ron"), "old meta").unwrap();
+
+        let result = copy_save_slot_in(&dir, 1, 2, true);
+
+        assert!(result.unwrap_err().contains("Failed to copy save file"));
+        assert_eq!(fs::read_to_string(dir.join("save2.ron")).unwrap(), "old");
+        assert_eq!(
+            fs::read_to_string(dir.join("save2.meta.ron")).unwrap(),
+            "old meta"
+        );
+        let leftovers = fs::read_dir(&dir)
+            .unwrap()
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| entry.file_name().to_string_lossy().ends_with(".tmp"))
+            .count();
+        assert_eq!(leftovers, 0, "Staged files should be cleaned up");
+
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn failed_rename_restores_the_target_slot() {
+        let dir = temp_save_dir("copy-rename");
+        fs::write(dir.join("save1.ron"), "new").unwrap();
+        fs::write(dir.join("save1.meta.ron"), "new meta").unwrap();
+        fs::write(dir.join("save2.ron"), "old").unwrap();
+        fs::write(dir.join("save2.meta.ron"), "old meta").unwrap();
+        fs::write(dir.join("save2.png"), "old screenshot").unwrap();
+        // A directory in the way of the metadata backup fails its rename
+        // after the main save has already been moved aside
+        let obstacle = dir.join("save2.meta.ron.bak");
+        fs::create_dir_all(obstacle.join("blocker")).unwrap();
+
+        let result = copy_save_slot_in(&dir, 1, 2, true);
+
+        assert!(result.unwrap_err().contains("Failed to move save file"));
+        assert_eq!(fs::read_to_string(dir.join("save2.ron")).unwrap(), "old");
+        assert_eq!(
+            fs::read_to_string(dir.join("save2.meta.ron")).unwrap(),
+            "old meta"
+        );
+        assert_eq!(
+            fs::read_to_string(dir.join("save2.png")).unwrap(),
+            "old screenshot"
+        );
+        let leftovers: Vec<_> = fs::read_dir(&dir)
+            .unwrap()
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(|path| path != &obstacle)
+            .filter(|path| {
+                let name = path.to_string_lossy();
+                name.ends_with(".tmp") || name.ends_with(".bak")
+            })
+            .collect();
+        assert!(leftovers.is_empty(), "Left behind {:?}", leftovers);
+
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn slot_meta_round_trips_and_defaults_when_missing() {
+        use crate::systems::save_storage::FileStorage;
+
+        let dir = temp_save_dir("slot-meta");
+        let storage = FileStorage::new(&dir);
+        assert_eq!(read_slot_meta(&storage, 1), SaveSlotMeta::default());
+
+        let meta = SaveSlotMeta {
+            name: Some("Before the cellar".to_string()),
+        };
+        write_slot_meta(&storage, 1, &meta).unwrap();
+        assert!(dir.join("save1.meta.ron").is_file());
+        assert_eq!(read_slot_meta(&storage, 1), meta);
+
+        fs::write(dir.join("save2.meta.ron"), "not ron").unwrap();
+        assert_eq!(read_slot_meta(&storage, 2), SaveSlotMeta::default());
+
+        fs::remove_dir_all(&dir).ok();
+    }
+
     #[test]
     fn item_serialization_round_trip() {
         let items = vec![
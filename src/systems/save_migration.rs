@@ -0,0 +1,353 @@
+use crate::components::player::DEFAULT_MAX_HIT_POINTS;
+use crate::systems::save_load::{
+    SAVE_VERSION, SaveData, SerializedCandleState, SerializedGameMode, SerializedItem,
+};
+
+/// Largest candle wax value a save may hold
+pub const MAX_SAVED_WAX: f32 = 100.0;
+
+/// Parses save file content
+///
+/// Fields added after a save was written take their defaults, so any
+/// version-1 save parses; `migrate_save` then brings it to the current
+/// layout.
+///
+/// # Errors
+/// Returns error string with the position of the first RON syntax error
+pub fn parse_save(content: &str) -> Result<SaveData, String> {
+    ron::from_str(content).map_err(|e| format!("Failed to parse save data: {}", e))
+}
+
+/// Parses save file content and brings it up to date, as loading a slot does
+///
+/// # Returns
+/// The current-layout save data and a description of each migration change
+///
+/// # Errors
+/// Returns error string if the content isn't a save or was written by a
+/// newer version
+pub fn decode_save(content: &str) -> Result<(SaveData, Vec<String>), String> {
+    let mut save_data = parse_save(content)?;
+    let changes = migrate_save(&mut save_data)?;
+    Ok((save_data, changes))
+}
+
+/// Serializes save data as pretty-printed RON, the format the game writes
+///
+/// # Errors
+/// Returns error string if the data can't be serialized
+pub fn write_save(save_data: &SaveData) -> Result<String, String> {
+    ron::ser::to_string_pretty(save_data, ron::ser::PrettyConfig::default())
+        .map_err(|e| format!("Failed to serialize save data: {}", e))
+}
+
+/// Brings save data written by an older version of the game up to date
+///
+/// # Returns
+/// A description of each change made (empty if the save was current)
+///
+/// # Errors
+/// Returns error string if the save was written by a newer version
+pub fn migrate_save(save_data: &mut SaveData) -> Result<Vec<String>, String> {
+    if save_data.version > SAVE_VERSION {
+        return Err(format!(
+            "Save version {} is newer than supported version {}",
+            save_data.version, SAVE_VERSION
+        ));
+    }
+
+    let mut changes = Vec::new();
+    if save_data.version < SAVE_VERSION {
+        changes.push(format!(
+            "Updated version {} to {}",
+            save_data.version, SAVE_VERSION
+        ));
+        save_data.version = SAVE_VERSION;
+    }
+
+    // Saves from before match stacking list every match on its own
+    let has_match_stack = save_data
+        .inventory_stacks
+        .iter()
+        .any(|(item, _)| *item == SerializedItem::Match);
+    let loose_matches = save_data
+        .inventory_items
+        .iter()
+        .filter(|item| **item == SerializedItem::Match)
+        .count();
+    if !has_match_stack && loose_matches > 0 {
+        let first = save_data
+            .inventory_items
+            .iter()
+            .position(|item| *item == SerializedItem::Match)
+            .unwrap_or(0);
+        let mut index = 0;
+        save_data.inventory_items.retain(|item| {
+            let keep = *item != SerializedItem::Match || index == first;
+            index += 1;
+            keep
+        });
+        save_data
+            .inventory_stacks
+            .push((SerializedItem::Match, loose_matches as u32));
+        changes.push(format!("Stacked {} loose matches", loose_matches));
+    }
+
+    Ok(changes)
+}
+
+/// Lists the problems in save data that would break or confuse loading
+///
+/// # Returns
+/// A description of each problem (empty if the save is valid)
+pub fn validate_save(save_data: &SaveData) -> Vec<String> {
+    let mut problems = Vec::new();
+
+    if save_data.version > SAVE_VERSION {
+        problems.push(format!(
+            "Version {} is newer than supported version {}",
+            save_data.version, SAVE_VERSION
+        ));
+    }
+    if !save_data.player_position.0.is_finite() || !save_data.player_position.1.is_finite() {
+        problems.push(format!(
+            "Player position {:?} is not a finite position",
+            save_data.player_position
+        ));
+    }
+    if !(0.0..=MAX_SAVED_WAX).contains(&save_data.candle_wax) {
+        problems.push(format!(
+            "Candle wax {} is outside 0-{}",
+            save_data.candle_wax, MAX_SAVED_WAX
+        ));
+    }
+    if save_data.candle_state == SerializedCandleState::Lit && save_data.candle_wax <= 0.0 {
+        problems.push("Candle is lit with no wax left".to_string());
+    }
+    if !save_data.hit_points.is_finite() || save_data.hit_points <= 0.0 {
+        problems.push(format!(
+            "Hit points {} would load a dead player",
+            save_data.hit_points
+        ));
+    }
+    if !save_data.explored_rooms.contains(&save_data.current_room) {
+        problems.push(format!(
+            "Current room {} is not in the explored rooms",
+            save_data.current_room
+        ));
+    }
+    if has_duplicates(&save_data.explored_rooms) {
+        problems.push("Explored rooms contain duplicates".to_string());
+    }
+    let pages: Vec<usize> = save_data
+        .inventory_items
+        .iter()
+        .filter_map(|item| match item {
+            SerializedItem::DiaryPage(page) => Some(*page),
+            _ => None,
+        })
+        .collect();
+    if has_duplicates(&pages) {
+        problems.push("Inventory holds the same diary page twice".to_string());
+    }
+    if save_data
+        .inventory_stacks
+        .iter()
+        .any(|(_, count)| *count == 0)
+    {
+        problems.push("Inventory holds an empty stack".to_string());
+    }
+    if matches!(
+        save_data.game_mode,
+        SerializedGameMode::GameOver | SerializedGameMode::Victory | SerializedGameMode::Menu
+    ) {
+        problems.push(format!(
+            "Game mode {:?} can't be resumed",
+            save_data.game_mode
+        ));
+    }
+
+    problems
+}
+
+/// Repairs the problems `validate_save` finds where a safe value exists
+///
+/// Wax is clamped, a lit candle without wax is extinguished, hit points
+/// are restored to full, explored rooms and diary pages are deduplicated,
+/// empty stacks are dropped and unresumable game modes become `Playing`.
+/// Newer versions and broken player positions are left for
+/// `validate_save` to report.
+///
+/// # Returns
+/// A description of each repair made
+pub fn fix_save(save_data: &mut SaveData) -> Vec<String> {
+    let mut fixes = Vec::new();
+
+    if !(0.0..=MAX_SAVED_WAX).contains(&save_data.candle_wax) {
+        let wax = if save_data.candle_wax.is_nan() {
+            MAX_SAVED_WAX
+        } else {
+            save_data.candle_wax.clamp(0.0, MAX_SAVED_WAX)
+        };
+        fixes.push(format!(
+            "Set candle wax {} to {}",
+            save_data.candle_wax, wax
+        ));
+        save_data.candle_wax = wax;
+    }
+    if save_data.candle_state == SerializedCandleState::Lit && save_data.candle_wax <= 0.0 {
+        save_data.candle_state = SerializedCandleState::Extinguished;
+        fixes.push("Extinguished the candle with no wax left".to_string());
+    }
+    if !save_data.hit_points.is_finite() || save_data.hit_points <= 0.0 {
+        let full = DEFAULT_MAX_HIT_POINTS;
+        fixes.push(format!(
+            "Set hit points {} to {}",
+            save_data.hit_points, full
+        ));
+        save_data.hit_points = full;
+    }
+
+    let room_count = save_data.explored_rooms.len();
+    dedup_in_order(&mut save_data.explored_rooms);
+    if save_data.explored_rooms.len() != room_count {
+        fixes.push("Removed duplicate explored rooms".to_string());
+    }
+    if !save_data.explored_rooms.contains(&save_data.current_room) {
+        save_data.explored_rooms.push(save_data.current_room);
+        fixes.push(format!(
+            "Marked current room {} as explored",
+            save_data.current_room
+        ));
+    }
+
+    let item_count = save_data.inventory_items.len();
+    let mut seen_pages = Vec::new();
+    save_data.inventory_items.retain(|item| match item {
+        SerializedItem::DiaryPage(page) if seen_pages.contains(page) => false,
+        SerializedItem::DiaryPage(page) => {
+            seen_pages.push(*page);
+            true
+        }
+        _ => true,
+    });
+    if save_data.inventory_items.len() != item_count {
+        fixes.push("Removed duplicate diary pages".to_string());
+    }
+
+    let stack_count = save_data.inventory_stacks.len();
+    let empty: Vec<SerializedItem> = save_data
+        .inventory_stacks
+        .iter()
+        .filter(|(_, count)| *count == 0)
+        .map(|(item, _)| item.clone())
+        .collect();
+    save_data.inventory_stacks.retain(|(_, count)| *count > 0);
+    save_data
+        .inventory_items
+        .retain(|item| !empty.contains(item));
+    if save_data.inventory_stacks.len() != stack_count {
+        fixes.push("Removed empty stacks".to_string());
+    }
+
+    if matches!(
+        save_data.game_mode,
+        SerializedGameMode::GameOver | SerializedGameMode::Victory | SerializedGameMode::Menu
+    ) {
+        fixes.push(format!(
+            "Changed game mode {:?} to Playing",
+            save_data.game_mode
+        ));
+        save_data.game_mode = SerializedGameMode::Playing;
+    }
+
+    fixes
+}
+
+/// Returns true if `values` holds any value more than once
+fn has_duplicates<T: PartialEq>(values: &[T]) -> bool {
+    values
+        .iter()
+        .enumerate()
+        .any(|(index, value)| values[..index].contains(value))
+}
+
+/// Removes repeated values, keeping the first of each
+fn dedup_in_order<T: PartialEq + Clone>(values: &mut Vec<T>) {
+    let mut kept: Vec<T> = Vec::with_capacity(values.len());
+    for value in values.drain(..) {
+        if !kept.contains(&value) {
+            kept.push(value);
+        }
+    }
+    *values = kept;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LEGACY_SAVE: &str = r#"(
+        version: 1,
+        current_room: 3,
+        player_position: (100.0, 50.0),
+        inventory_items: [Match, Key(Brass), Match, Match, DiaryPage(2), DiaryPage(2)],
+        candle_wax: 140.0,
+        candle_state: Lit,
+        explored_rooms: [0, 1, 1],
+        completion_time_secs: 300,
+        deaths: 1,
+        collected_secrets: 0,
+        double_jump_unlocked: false,
+        game_mode: GameOver,
+        hit_points: 0.0,
+    )"#;
+
+    #[test]
+    fn migration_stacks_loose_matches_and_rejects_newer_saves() {
+        let mut save_data = parse_save(LEGACY_SAVE).unwrap();
+
+        let changes = migrate_save(&mut save_data).unwrap();
+        assert_eq!(changes, vec!["Stacked 3 loose matches".to_string()]);
+        assert_eq!(
+            save_data.inventory_items[..2],
+            [
+                SerializedItem::Match,
+                SerializedItem::Key(crate::systems::save_load::SerializedKeyType::Brass)
+            ]
+        );
+        assert_eq!(save_data.inventory_stacks, vec![(SerializedItem::Match, 3)]);
+        assert!(
+            migrate_save(&mut save_data).unwrap().is_empty(),
+            "Migrating twice changes nothing"
+        );
+
+        save_data.version = SAVE_VERSION + 1;
+        assert!(migrate_save(&mut save_data).is_err());
+    }
+
+    #[test]
+    fn fix_repairs_what_validate_reports() {
+        let mut save_data = parse_save(LEGACY_SAVE).unwrap();
+        assert_eq!(validate_save(&save_data).len(), 6);
+
+        let fixes = fix_save(&mut save_data);
+        assert!(!fixes.is_empty());
+        assert!(validate_save(&save_data).is_empty());
+        assert_eq!(save_data.candle_wax, MAX_SAVED_WAX);
+        assert_eq!(save_data.explored_rooms, vec![0, 1, 3]);
+        assert_eq!(save_data.game_mode, SerializedGameMode::Playing);
+
+        // Fixed saves round-trip through the game's format
+        let content = write_save(&save_data).unwrap();
+        assert_eq!(parse_save(&content).unwrap().explored_rooms, vec![0, 1, 3]);
+
+        save_data.player_position = (f32::NAN, 0.0);
+        fix_save(&mut save_data);
+        assert_eq!(
+            validate_save(&save_data).len(),
+            1,
+            "Positions can't be fixed"
+        );
+    }
+}
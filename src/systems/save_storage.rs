@@ -0,0 +1,478 @@
+use bevy::prelude::*;
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+
+/// Backend that stores save files as text, addressed by file name
+///
+/// Save/load systems go through this trait instead of touching the
+/// filesystem directly, using the backend in the `SaveBackend` resource.
+/// `PlatformStorage` picks the backend for the build target:
+/// - Native: `FileStorage`, files in the platform data directory
+/// - Browser (`wasm32`): `LocalStorage`, entries in `window.localStorage`
+///
+/// `MemoryStorage` keeps files in memory, for tests.
+pub trait SaveStorage: Send + Sync {
+    /// Reads the contents of save file `name`
+    ///
+    /// # Errors
+    /// Returns error string if the file doesn't exist or cannot be read
+    fn read(&self, name: &str) -> Result<String, String>;
+
+    /// Writes `contents` to save file `name`, replacing any previous contents
+    ///
+    /// # Errors
+    /// Returns error string if the file cannot be written
+    fn write(&self, name: &str, contents: &str) -> Result<(), String>;
+
+    /// Returns true if save file `name` exists
+    fn exists(&self, name: &str) -> bool;
+
+    /// Human-readable location of save file `name`, for log messages
+    fn location(&self, name: &str) -> String;
+
+    /// Removes save file `name` (removing a missing file is not an error)
+    ///
+    /// # Errors
+    /// Returns error string if the file exists but cannot be removed
+    fn remove(&self, name: &str) -> Result<(), String>;
+
+    /// Names of every save file held, in no particular order
+    fn file_names(&self) -> Vec<String>;
+
+    /// Copies every file of save slot `from` into slot `to`, replacing
+    /// whatever `to` held
+    ///
+    /// The default copies the save and metadata files as text; backends
+    /// holding binary companions (screenshots) override it.
+    ///
+    /// # Errors
+    /// Returns error string if the slots are identical, the source slot is
+    /// empty, or a file cannot be copied
+    fn copy_slot(&self, from: usize, to: usize) -> Result<(), String> {
+        if from == to {
+            return Err(format!("Cannot copy save slot {} onto itself", from));
+        }
+        if !self.exists(&save_file_name(from)) {
+            return Err(format!("Save slot {} is empty", from));
+        }
+        // Metadata first so the slot only shows up complete
+        for extension in ["meta.ron", "ron"] {
+            let target = slot_file_name(to, extension);
+            match self.read(&slot_file_name(from, extension)) {
+                Ok(contents) => self.write(&target, &contents)?,
+                Err(_) => self.remove(&target)?,
+            }
+        }
+        Ok(())
+    }
+
+    /// Reads the save of `slot`
+    ///
+    /// # Errors
+    /// Returns error string if the slot is empty or cannot be read
+    fn read_slot(&self, slot: usize) -> Result<String, String> {
+        self.read(&save_file_name(slot))
+    }
+
+    /// Writes `contents` as the save of `slot`
+    ///
+    /// # Errors
+    /// Returns error string if the save cannot be written
+    fn write_slot(&self, slot: usize, contents: &str) -> Result<(), String> {
+        self.write(&save_file_name(slot), contents)
+    }
+
+    /// Slots holding a save, in ascending order
+    fn list_slots(&self) -> Vec<usize> {
+        let mut slots: Vec<usize> = self
+            .file_names()
+            .iter()
+            .filter_map(|name| slot_of_file_name(name))
+            .collect();
+        slots.sort_unstable();
+        slots
+    }
+
+    /// Removes every file of `slot` (save, metadata and screenshot)
+    ///
+    /// # Errors
+    /// Returns error string if a file cannot be removed
+    fn delete_slot(&self, slot: usize) -> Result<(), String> {
+        for extension in SAVE_SLOT_EXTENSIONS {
+            self.remove(&slot_file_name(slot, extension))?;
+        }
+        Ok(())
+    }
+}
+
+/// Resource holding the save storage the save slots, settings files
+/// (profiles, accessibility, telemetry, achievements) and speedrun splits
+/// go through
+///
+/// Defaults to `PlatformStorage`. Tests insert one over a `MemoryStorage`
+/// so they never touch the player's saves (or each other's).
+#[derive(Resource)]
+pub struct SaveBackend(Box<dyn SaveStorage>);
+
+impl SaveBackend {
+    /// Uses `storage` for saving and loading
+    pub fn new(storage: impl SaveStorage + 'static) -> Self {
+        Self(Box::new(storage))
+    }
+}
+
+impl Default for SaveBackend {
+    fn default() -> Self {
+        Self::new(PlatformStorage::default())
+    }
+}
+
+impl SaveStorage for SaveBackend {
+    fn read(&self, name: &str) -> Result<String, String> {
+        self.0.read(name)
+    }
+
+    fn write(&self, name: &str, contents: &str) -> Result<(), String> {
+        self.0.write(name, contents)
+    }
+
+    fn exists(&self, name: &str) -> bool {
+        self.0.exists(name)
+    }
+
+    fn location(&self, name: &str) -> String {
+        self.0.location(name)
+    }
+
+    fn remove(&self, name: &str) -> Result<(), String> {
+        self.0.remove(name)
+    }
+
+    fn file_names(&self) -> Vec<String> {
+        self.0.file_names()
+    }
+
+    fn copy_slot(&self, from: usize, to: usize) -> Result<(), String> {
+        self.0.copy_slot(from, to)
+    }
+}
+
+/// Save storage for the current build target
+#[cfg(not(target_arch = "wasm32"))]
+pub type PlatformStorage = FileStorage;
+
+/// Save storage for the current build target
+#[cfg(target_arch = "wasm32")]
+pub type PlatformStorage = LocalStorage;
+
+/// File extensions of the files making up a save slot, main save file first
+///
+/// Besides the RON save itself, a slot may have a metadata file
+/// (`save{slot}.meta.ron`) and a screenshot (`save{slot}.png`).
+pub const SAVE_SLOT_EXTENSIONS: [&str; 3] = ["ron", "meta.ron", "png"];
+
+/// Returns the name of the `extension` file of `slot` (`save.{extension}`
+/// for the auto-save slot 0)
+pub fn slot_file_name(slot: usize, extension: &str) -> String {
+    if slot == 0 {
+        format!("save.{}", extension)
+    } else {
+        format!("save{}.{}", slot, extension)
+    }
+}
+
+/// Returns the save file name of `slot` (`save.ron` for the auto-save slot 0)
+pub fn save_file_name(slot: usize) -> String {
+    slot_file_name(slot, "ron")
+}
+
+/// Returns the metadata file name of `slot` (`save.meta.ron` for the auto-save slot 0)
+///
+/// The metadata file holds what the player set for the slot, such as its
+/// name, and lives alongside the save itself.
+pub fn save_meta_file_name(slot: usize) -> String {
+    slot_file_name(slot, "meta.ron")
+}
+
+/// Returns the slot whose save file is called `name`, if it is one
+fn slot_of_file_name(name: &str) -> Option<usize> {
+    match name.strip_suffix(".ron")?.strip_prefix("save")? {
+        "" => Some(0),
+        // No leading zeros or signs, so each slot has one file name
+        digits if digits.starts_with(|c: char| c.is_ascii_digit() && c != '0') => {
+            digits.parse().ok()
+        }
+        _ => None,
+    }
+}
+
+/// Name of the file listing the player profiles
+pub const PROFILES_FILE_NAME: &str = "profiles.ron";
+
+/// Name of the file holding the splits of the last completed speedrun
+pub const SPLITS_FILE_NAME: &str = "splits.ron";
+
+/// Name of the file holding unlocked achievements (shared by every save slot)
+pub const ACHIEVEMENTS_FILE_NAME: &str = "achievements.ron";
+
+/// Name of the file holding the accessibility options (shared by every save slot)
+pub const ACCESSIBILITY_FILE_NAME: &str = "accessibility.ron";
+
+/// Name of the file holding the cloud sync endpoint (see `cloud_sync`)
+pub const CLOUD_SYNC_FILE_NAME: &str = "cloud_sync.ron";
+
+/// Name of the file holding the telemetry opt-in (see `telemetry`)
+pub const TELEMETRY_FILE_NAME: &str = "telemetry.ron";
+
+/// Save storage backed by files in a directory
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Debug, Clone)]
+pub struct FileStorage {
+    dir: std::path::PathBuf,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl FileStorage {
+    /// Creates storage for save files in `dir`
+    pub fn new(dir: impl Into<std::path::PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// Directory holding the save files
+    pub fn dir(&self) -> &std::path::Path {
+        &self.dir
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Default for FileStorage {
+    /// Storage in the platform save directory (see `save_load::get_save_path`)
+    fn default() -> Self {
+        let save_path = crate::systems::save_load::get_save_path(0);
+        Self::new(save_path.parent().unwrap_or(std::path::Path::new(".")))
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl SaveStorage for FileStorage {
+    fn read(&self, name: &str) -> Result<String, String> {
+        let path = self.dir.join(name);
+        std::fs::read_to_string(&path)
+            .map_err(|e| format!("Failed to read save file '{}': {}", path.display(), e))
+    }
+
+    fn write(&self, name: &str, contents: &str) -> Result<(), String> {
+        let path = self.dir.join(name);
+        std::fs::write(&path, contents)
+            .map_err(|e| format!("Failed to write save file '{}': {}", path.display(), e))
+    }
+
+    fn exists(&self, name: &str) -> bool {
+        self.dir.join(name).is_file()
+    }
+
+    fn location(&self, name: &str) -> String {
+        self.dir.join(name).display().to_string()
+    }
+
+    fn remove(&self, name: &str) -> Result<(), String> {
+        let path = self.dir.join(name);
+        match std::fs::remove_file(&path) {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(format!(
+                "Failed to remove save file '{}': {}",
+                path.display(),
+                e
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    fn file_names(&self) -> Vec<String> {
+        std::fs::read_dir(&self.dir)
+            .map(|entries| {
+                entries
+                    .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Copies the slot's files, screenshot included, with
+    /// `save_load::copy_save_slot_in`
+    fn copy_slot(&self, from: usize, to: usize) -> Result<(), String> {
+        crate::systems::save_load::copy_save_slot_in(&self.dir, from, to, true)
+    }
+}
+
+/// Save storage kept in memory, for tests
+///
+/// Clones share their files, so a test can keep one to inspect what the
+/// systems using the other wrote.
+#[derive(Debug, Clone, Default)]
+pub struct MemoryStorage {
+    files: Arc<Mutex<BTreeMap<String, String>>>,
+}
+
+impl MemoryStorage {
+    fn files(&self) -> std::sync::MutexGuard<'_, BTreeMap<String, String>> {
+        // A test panicking mid-write can't leave a file half written
+        self.files
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl SaveStorage for MemoryStorage {
+    fn read(&self, name: &str) -> Result<String, String> {
+        self.files()
+            .get(name)
+            .cloned()
+            .ok_or_else(|| format!("Save '{}' does not exist", name))
+    }
+
+    fn write(&self, name: &str, contents: &str) -> Result<(), String> {
+        self.files().insert(name.to_string(), contents.to_string());
+        Ok(())
+    }
+
+    fn exists(&self, name: &str) -> bool {
+        self.files().contains_key(name)
+    }
+
+    fn location(&self, name: &str) -> String {
+        format!("memory[{}]", name)
+    }
+
+    fn remove(&self, name: &str) -> Result<(), String> {
+        self.files().remove(name);
+        Ok(())
+    }
+
+    fn file_names(&self) -> Vec<String> {
+        self.files().keys().cloned().collect()
+    }
+}
+
+/// Save storage backed by the browser's `localStorage`
+///
+/// Each save file is one entry whose key is the file name prefixed with
+/// `rust-game/`, so saves from other games on the same origin (itch.io
+/// serves many games from one domain) never collide.
+#[cfg(target_arch = "wasm32")]
+#[derive(Debug, Clone, Default)]
+pub struct LocalStorage;
+
+#[cfg(target_arch = "wasm32")]
+impl LocalStorage {
+    /// Prefix of every localStorage key written by the game
+    pub const KEY_PREFIX: &'static str = "rust-game/";
+
+    fn storage() -> Result<web_sys::Storage, String> {
+        web_sys::window()
+            .ok_or_else(|| "No browser window available".to_string())?
+            .local_storage()
+            .map_err(|e| format!("Failed to access localStorage: {:?}", e))?
+            .ok_or_else(|| "localStorage is unavailable".to_string())
+    }
+
+    fn key(name: &str) -> String {
+        format!("{}{}", Self::KEY_PREFIX, name)
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+impl SaveStorage for LocalStorage {
+    fn read(&self, name: &str) -> Result<String, String> {
+        Self::storage()?
+            .get_item(&Self::key(name))
+            .map_err(|e| format!("Failed to read save '{}': {:?}", name, e))?
+            .ok_or_else(|| format!("Save '{}' does not exist", name))
+    }
+
+    fn write(&self, name: &str, contents: &str) -> Result<(), String> {
+        Self::storage()?
+            .set_item(&Self::key(name), contents)
+            .map_err(|e| format!("Failed to write save '{}': {:?}", name, e))
+    }
+
+    fn exists(&self, name: &str) -> bool {
+        Self::storage()
+            .ok()
+            .and_then(|storage| storage.get_item(&Self::key(name)).ok().flatten())
+            .is_some()
+    }
+
+    fn location(&self, name: &str) -> String {
+        format!("localStorage[{}]", Self::key(name))
+    }
+
+    fn remove(&self, name: &str) -> Result<(), String> {
+        Self::storage()?
+            .remove_item(&Self::key(name))
+            .map_err(|e| format!("Failed to remove save '{}': {:?}", name, e))
+    }
+
+    fn file_names(&self) -> Vec<String> {
+        let Ok(storage) = Self::storage() else {
+            return Vec::new();
+        };
+        let count = storage.length().unwrap_or(0);
+        (0..count)
+            .filter_map(|index| storage.key(index).ok().flatten())
+            .filter_map(|key| key.strip_prefix(Self::KEY_PREFIX).map(str::to_string))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn file_storage_round_trips_saves() {
+        let dir =
+            std::env::temp_dir().join(format!("rust-game-file-storage-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        let storage = FileStorage::new(&dir);
+
+        assert!(!storage.exists(&save_file_name(2)));
+        assert!(storage.read(&save_file_name(2)).is_err());
+
+        storage.write(&save_file_name(2), "(version: 1)").unwrap();
+        assert!(storage.exists("save2.ron"));
+        assert_eq!(storage.read("save2.ron").unwrap(), "(version: 1)");
+        assert_eq!(save_file_name(0), "save.ron");
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn slots_are_listed_copied_and_deleted() {
+        let storage = MemoryStorage::default();
+        storage.write_slot(0, "auto").unwrap();
+        storage.write_slot(12, "twelve").unwrap();
+        storage
+            .write(&save_meta_file_name(12), "(name: None)")
+            .unwrap();
+        storage.write("save02.ron", "not a slot").unwrap();
+        storage.write(&save_meta_file_name(3), "stale").unwrap();
+        assert_eq!(storage.list_slots(), vec![0, 12]);
+
+        storage.copy_slot(12, 3).unwrap();
+        assert_eq!(storage.read_slot(3).unwrap(), "twelve");
+        assert_eq!(
+            storage.read(&save_meta_file_name(3)).unwrap(),
+            "(name: None)"
+        );
+        assert!(storage.copy_slot(5, 1).is_err(), "Slot 5 is empty");
+
+        storage.delete_slot(12).unwrap();
+        assert_eq!(storage.list_slots(), vec![0, 3]);
+        assert!(!storage.exists(&save_meta_file_name(12)));
+
+        // A backend shares the files of the storage it was made from
+        let backend = SaveBackend::new(storage.clone());
+        assert_eq!(backend.read_slot(0).unwrap(), "auto");
+    }
+}
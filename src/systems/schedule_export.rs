@@ -0,0 +1,278 @@
+use bevy::ecs::schedule::{NodeId, ScheduleGraph, Schedules};
+use bevy::prelude::*;
+use std::collections::{BTreeMap, HashSet};
+use std::fmt::Write;
+use std::fs;
+
+/// Command-line flag that writes the system schedules to a DOT file at startup
+pub const DUMP_SCHEDULE_FLAG: &str = "--dump-schedule";
+
+/// Returns the DOT path given after `--dump-schedule`, if present
+pub fn dump_schedule_arg(args: impl IntoIterator<Item = String>) -> Option<String> {
+    let mut args = args.into_iter();
+    args.find(|arg| arg == DUMP_SCHEDULE_FLAG)?;
+    args.next()
+}
+
+/// Plugin that exports every schedule to a graphviz DOT file at startup
+///
+/// Render the file with e.g. `dot -Tsvg schedule.dot -o schedule.svg` to
+/// see which systems run in which sets, what they are ordered against and
+/// the run conditions guarding them. Handy when chasing ordering bugs
+/// between collision, traps and respawn.
+///
+/// The export runs in `PreStartup`, before the frame schedules first run,
+/// so all the plugins adding systems must be added before this one. The
+/// running `Main` and `PreStartup` schedules can't be exported.
+pub struct ScheduleExportPlugin {
+    /// File the DOT graph is written to
+    pub path: String,
+}
+
+impl Plugin for ScheduleExportPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(ScheduleExportPath(self.path.clone()))
+            .add_systems(PreStartup, export_schedule_system);
+    }
+}
+
+/// Resource holding where `export_schedule_system` writes the DOT graph
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct ScheduleExportPath(pub String);
+
+/// Strips module paths from a type or system name
+///
+/// `rust_game::systems::trap::trap_activation_system` becomes
+/// `trap_activation_system` and `Vec<bevy::prelude::Entity>` becomes
+/// `Vec<Entity>`.
+pub fn short_name(full: &str) -> String {
+    let mut short = String::new();
+    let mut path = String::new();
+    for c in full.chars() {
+        if c.is_alphanumeric() || c == '_' || c == ':' {
+            path.push(c);
+        } else {
+            short.push_str(path.rsplit("::").next().unwrap_or_default());
+            path.clear();
+            short.push(c);
+        }
+    }
+    short.push_str(path.rsplit("::").next().unwrap_or_default());
+    short
+}
+
+/// Escapes a label for a double-quoted DOT string
+fn escape(label: &str) -> String {
+    label.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// A node label: the name followed by one "if condition" line per run condition
+fn node_label(name: &str, conditions: &[String]) -> String {
+    let mut label = escape(name);
+    for condition in conditions {
+        label.push_str("\\nif ");
+        label.push_str(&escape(condition));
+    }
+    label
+}
+
+/// Systems of `schedule` with the short names of their run conditions
+///
+/// Once a schedule has run, Bevy moves its systems and conditions out of
+/// the graph; such systems are still listed but without their conditions.
+fn schedule_systems(schedule: &Schedule) -> BTreeMap<usize, (String, Vec<String>)> {
+    let mut systems: BTreeMap<usize, (String, Vec<String>)> = schedule
+        .graph()
+        .systems()
+        .map(|(id, system, conditions)| {
+            let conditions = conditions
+                .iter()
+                .map(|condition| short_name(&condition.name()))
+                .collect();
+            (id.index(), (short_name(&system.name()), conditions))
+        })
+        .collect();
+    if let Ok(initialized) = schedule.systems() {
+        for (id, system) in initialized {
+            systems
+                .entry(id.index())
+                .or_insert_with(|| (short_name(&system.name()), Vec::new()));
+        }
+    }
+    systems
+}
+
+/// Nodes an ordering edge endpoint stands for
+///
+/// `.before(some_system)` orders against the set holding just that
+/// system's instances, so such sets are replaced by their systems.
+fn resolve(graph: &ScheduleGraph, node: NodeId) -> Vec<NodeId> {
+    match graph.get_set_at(node) {
+        Some(set) if set.system_type().is_some() => graph
+            .hierarchy()
+            .graph()
+            .neighbors(node)
+            .filter(NodeId::is_system)
+            .collect(),
+        _ => vec![node],
+    }
+}
+
+/// Writes `schedule` as a DOT cluster named `name`
+///
+/// Systems are boxes and named (or anonymous) sets are ellipses, each listing
+/// its run conditions. Dashed edges go from a set to its members, solid
+/// edges from a node to what runs after it.
+pub fn write_schedule_dot(dot: &mut String, name: &str, schedule: &Schedule) {
+    let graph = schedule.graph();
+    let id = |node: NodeId| {
+        let kind = if node.is_system() { "system" } else { "set" };
+        format!("\"{}/{}{}\"", escape(name), kind, node.index())
+    };
+
+    let _ = writeln!(dot, "  subgraph \"cluster_{}\" {{", escape(name));
+    let _ = writeln!(dot, "    label=\"{}\";", escape(name));
+
+    let systems = schedule_systems(schedule);
+    for (index, (system, conditions)) in &systems {
+        let _ = writeln!(
+            dot,
+            "    {} [label=\"{}\"];",
+            id(NodeId::System(*index)),
+            node_label(system, conditions)
+        );
+    }
+
+    let mut shown_sets = HashSet::new();
+    for (node, set, conditions) in graph.system_sets() {
+        if set.system_type().is_some() {
+            continue;
+        }
+        let set_name = if set.is_anonymous() {
+            "(anonymous set)".to_string()
+        } else {
+            short_name(&format!("{:?}", set))
+        };
+        let conditions: Vec<String> = conditions
+            .iter()
+            .map(|condition| short_name(&condition.name()))
+            .collect();
+        let _ = writeln!(
+            dot,
+            "    {} [label=\"{}\", shape=ellipse];",
+            id(node),
+            node_label(&set_name, &conditions)
+        );
+        shown_sets.insert(node);
+    }
+
+    let shown = |node: NodeId| match node {
+        NodeId::System(index) => systems.contains_key(&index),
+        NodeId::Set(_) => shown_sets.contains(&node),
+    };
+    let mut edges: Vec<(NodeId, NodeId, bool)> = graph
+        .hierarchy()
+        .graph()
+        .all_edges()
+        .filter(|(set, member)| shown(*set) && shown(*member))
+        .map(|(set, member)| (set, member, true))
+        .collect();
+    for (before, after) in graph.dependency().graph().all_edges() {
+        for before in resolve(graph, before) {
+            for after in resolve(graph, after) {
+                if shown(before) && shown(after) {
+                    edges.push((before, after, false));
+                }
+            }
+        }
+    }
+    edges.sort_by_key(|(from, to, membership)| (*membership, from.index(), to.index()));
+    edges.dedup();
+    for (from, to, membership) in edges {
+        let style = if membership { " [style=dashed]" } else { "" };
+        let _ = writeln!(dot, "    {} -> {}{};", id(from), id(to), style);
+    }
+
+    let _ = writeln!(dot, "  }}");
+}
+
+/// Renders every schedule in `schedules` as one DOT graph, sorted by name
+pub fn schedules_to_dot(schedules: &Schedules) -> String {
+    let mut sorted: Vec<(String, &Schedule)> = schedules
+        .iter()
+        .map(|(label, schedule)| (format!("{:?}", label), schedule))
+        .collect();
+    sorted.sort_by(|a, b| a.0.cmp(&b.0));
+
+    let mut dot = String::from("digraph schedules {\n  rankdir=LR;\n  node [shape=box];\n");
+    for (name, schedule) in sorted {
+        write_schedule_dot(&mut dot, &name, schedule);
+    }
+    dot.push_str("}\n");
+    dot
+}
+
+/// Startup system that writes the schedules to `ScheduleExportPath`
+///
+/// # System Dependencies
+/// - **Resources**: Reads `Schedules` and `ScheduleExportPath`
+pub fn export_schedule_system(world: &mut World) {
+    let path = world.resource::<ScheduleExportPath>().0.clone();
+    let dot = schedules_to_dot(world.resource::<Schedules>());
+    match fs::write(&path, dot) {
+        Ok(()) => info!("Exported system schedules to {}", path),
+        Err(e) => error!("Failed to export system schedules to '{}': {}", path, e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn first() {}
+    fn second() {}
+    fn third() {}
+
+    #[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+    struct Physics;
+
+    #[derive(Resource)]
+    struct Flag;
+
+    #[test]
+    fn dump_schedule_arg_reads_path_after_flag() {
+        let args = ["rust-game", "--dump-schedule", "schedule.dot"].map(String::from);
+        assert_eq!(dump_schedule_arg(args), Some("schedule.dot".to_string()));
+        assert_eq!(dump_schedule_arg(["rust-game".to_string()]), None);
+    }
+
+    #[test]
+    fn short_name_strips_module_paths() {
+        assert_eq!(
+            short_name("rust_game::systems::trap::trap_activation_system"),
+            "trap_activation_system"
+        );
+        assert_eq!(
+            short_name("Option<bevy::prelude::Res<a::GameState>>"),
+            "Option<Res<GameState>>"
+        );
+    }
+
+    #[test]
+    fn dot_shows_sets_ordering_and_run_conditions() {
+        let mut app = App::new();
+        app.configure_sets(Update, Physics.run_if(|| true))
+            .add_systems(Update, (first, second).chain().in_set(Physics))
+            .add_systems(Update, third.before(first).run_if(resource_exists::<Flag>));
+
+        let dot = schedules_to_dot(app.world().resource::<Schedules>());
+
+        assert!(dot.contains("subgraph \"cluster_Update\""));
+        assert!(dot.contains("[label=\"first\"]"));
+        assert!(dot.contains("[label=\"third\\nif resource_exists<Flag>\"]"));
+        assert!(dot.contains("[label=\"Physics\\nif"));
+        assert!(dot.contains("\"Update/system0\" -> \"Update/system1\";"));
+        assert!(dot.contains("\"Update/system2\" -> \"Update/system0\";"));
+        assert!(dot.contains("-> \"Update/system0\" [style=dashed];"));
+    }
+}
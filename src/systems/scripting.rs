@@ -0,0 +1,512 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fs;
+
+use crate::components::lighting::CandleState;
+use crate::components::room::{LevelEntityId, RoomId};
+use crate::entities::registry::SpawnRegistry;
+use crate::resources::game_state::{GameMode, GameState};
+use crate::systems::dialogue::StartDialogueEvent;
+use crate::systems::interaction::OpenDoorEvent;
+use crate::systems::level_loader::{EntitySpawn, LevelRegistry, RoomLevels};
+use crate::systems::puzzle::PuzzleSolvedEvent;
+use crate::systems::trigger_zone::GameplayTrigger;
+
+/// Most operations one script run may take before it is stopped
+pub const SCRIPT_MAX_OPERATIONS: u64 = 100_000;
+
+/// Plugin that runs the scripts level files attach to room events
+///
+/// Scripts are Rhai files in assets/ (see `LevelScript`) and only run in
+/// builds with the `scripting` feature; other builds log that they were
+/// skipped. Scripts can't touch the world or the file system directly: the
+/// functions they are given queue `ScriptCommand`s, applied afterwards by
+/// `run_level_scripts_system`.
+///
+/// # Script API
+/// - `spawn(entity_type, x, y)` - spawns a level entity type (e.g.
+///   `"Spikes"`) at the float position `x`, `y`
+/// - `set_light(index, lit)` - lights or puts out the room's level light at
+///   entity `index`
+/// - `set_lights(lit)` - lights or puts out every level light in the room
+/// - `say(script)` - plays a dialogue script
+/// - `print(text)` - writes to the log
+pub struct ScriptingPlugin;
+
+impl Plugin for ScriptingPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<LevelScripts>()
+            .init_resource::<ScriptHost>()
+            .init_resource::<LevelRegistry>()
+            .add_event::<OpenDoorEvent>()
+            .add_event::<PuzzleSolvedEvent>()
+            .add_event::<GameplayTrigger>()
+            .add_event::<StartDialogueEvent>()
+            .add_systems(
+                Update,
+                (level_script_trigger_system, run_level_scripts_system).chain(),
+            );
+    }
+}
+
+/// Room event a level script runs on
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub enum LevelScriptTrigger {
+    /// The player enters the room
+    EnterRoom,
+    /// The player opens any door of the room
+    DoorOpened,
+    /// A puzzle is solved while the player is in the room
+    PuzzleSolved,
+    /// A `GameplayTrigger` with this name fires
+    Gameplay(String),
+    /// The player has been in the room this many seconds of play
+    Timer(f32),
+}
+
+/// A script a level runs on one of its events
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct LevelScript {
+    /// Event the script runs on
+    pub on: LevelScriptTrigger,
+    /// Rhai file relative to assets/ (e.g., "scripts/attic_collapse.rhai")
+    pub script: String,
+    /// Run only the first time the event happens
+    #[serde(default = "default_once")]
+    pub once: bool,
+}
+
+fn default_once() -> bool {
+    true
+}
+
+/// Action a script asked for, applied once the script has finished
+#[derive(Debug, Clone, PartialEq)]
+pub enum ScriptCommand {
+    /// Spawn a level entity type at a position
+    Spawn {
+        /// Level entity type (e.g. `"Spikes"`)
+        entity_type: String,
+        /// Position in world space
+        position: (f32, f32),
+    },
+    /// Light (`true`) or put out the level light at this entity index
+    SetLight {
+        /// Index of the light in the room's level data
+        index: usize,
+        /// Whether the light burns
+        lit: bool,
+    },
+    /// Light (`true`) or put out every level light in the room
+    SetLights(bool),
+    /// Play the dialogue script with this ID
+    Dialogue(String),
+}
+
+/// Resource tracking the scripts of the current room
+#[derive(Resource, Debug, Default)]
+pub struct LevelScripts {
+    /// Room the scripts belong to (`None` before the first room)
+    pub room: Option<RoomId>,
+    /// The room's scripts, from its level file
+    pub scripts: Vec<LevelScript>,
+    /// Script files waiting to run
+    pub pending: Vec<String>,
+    /// Seconds of play since the player entered the room
+    pub elapsed: f32,
+    /// `once` scripts that have run, by room and index
+    fired: HashSet<(RoomId, usize)>,
+}
+
+impl LevelScripts {
+    /// Switches to `scripts` of `room` and runs its `EnterRoom` scripts
+    pub fn enter_room(&mut self, room: RoomId, scripts: Vec<LevelScript>) {
+        self.room = Some(room);
+        self.scripts = scripts;
+        self.elapsed = 0.0;
+        self.trigger(|on| *on == LevelScriptTrigger::EnterRoom);
+    }
+
+    /// Queues the scripts whose trigger matches, skipping `once` scripts
+    /// that have already run
+    pub fn trigger(&mut self, matches: impl Fn(&LevelScriptTrigger) -> bool) {
+        let Some(room) = self.room else {
+            return;
+        };
+        for (index, script) in self.scripts.iter().enumerate() {
+            if !matches(&script.on) || (script.once && self.fired.contains(&(room, index))) {
+                continue;
+            }
+            if script.once {
+                self.fired.insert((room, index));
+            }
+            self.pending.push(script.script.clone());
+        }
+    }
+
+    /// Advances the room timer by `secs`, queuing the `Timer` scripts it
+    /// passes
+    pub fn tick(&mut self, secs: f32) {
+        let before = self.elapsed;
+        self.elapsed += secs;
+        let now = self.elapsed;
+        self.trigger(
+            |on| matches!(on, LevelScriptTrigger::Timer(at) if before < *at && *at <= now),
+        );
+    }
+}
+
+/// Resource running scripts in a sandboxed Rhai engine
+///
+/// The engine has no module imports or `eval`, and stops scripts after
+/// `SCRIPT_MAX_OPERATIONS` or when they build huge strings or arrays.
+#[derive(Resource)]
+pub struct ScriptHost {
+    #[cfg(feature = "scripting")]
+    engine: rhai::Engine,
+    #[cfg(feature = "scripting")]
+    queue: std::sync::Arc<std::sync::Mutex<Vec<ScriptCommand>>>,
+}
+
+#[cfg(feature = "scripting")]
+impl Default for ScriptHost {
+    fn default() -> Self {
+        use std::sync::{Arc, Mutex};
+
+        let queue: Arc<Mutex<Vec<ScriptCommand>>> = Arc::default();
+        let push = |queue: &Arc<Mutex<Vec<ScriptCommand>>>, command| {
+            if let Ok(mut queue) = queue.lock() {
+                queue.push(command);
+            }
+        };
+
+        let mut engine = rhai::Engine::new();
+        engine
+            .set_max_operations(SCRIPT_MAX_OPERATIONS)
+            .set_max_call_levels(16)
+            .set_max_string_size(4_096)
+            .set_max_array_size(1_024)
+            .set_max_map_size(256)
+            .set_module_resolver(rhai::module_resolvers::DummyModuleResolver::new())
+            .disable_symbol("eval")
+            .on_print(|text| info!("Script: {}", text));
+
+        let spawn_queue = queue.clone();
+        engine.register_fn("spawn", move |entity_type: &str, x: f64, y: f64| {
+            push(
+                &spawn_queue,
+                ScriptCommand::Spawn {
+                    entity_type: entity_type.to_string(),
+                    position: (x as f32, y as f32),
+                },
+            );
+        });
+        let light_queue = queue.clone();
+        engine.register_fn("set_light", move |index: i64, lit: bool| {
+            if let Ok(index) = usize::try_from(index) {
+                push(&light_queue, ScriptCommand::SetLight { index, lit });
+            }
+        });
+        let lights_queue = queue.clone();
+        engine.register_fn("set_lights", move |lit: bool| {
+            push(&lights_queue, ScriptCommand::SetLights(lit));
+        });
+        let say_queue = queue.clone();
+        engine.register_fn("say", move |script: &str| {
+            push(&say_queue, ScriptCommand::Dialogue(script.to_string()));
+        });
+
+        Self { engine, queue }
+    }
+}
+
+#[cfg(not(feature = "scripting"))]
+impl Default for ScriptHost {
+    fn default() -> Self {
+        Self {}
+    }
+}
+
+impl ScriptHost {
+    /// Runs the script `source`
+    ///
+    /// # Returns
+    /// The commands the script queued, in order
+    ///
+    /// # Errors
+    /// Returns error string if the script doesn't compile, fails or runs
+    /// past its limits (its commands are dropped), or if the game was built
+    /// without the `scripting` feature
+    #[cfg(feature = "scripting")]
+    pub fn run(&self, source: &str) -> Result<Vec<ScriptCommand>, String> {
+        let result = self
+            .engine
+            .compile(source)
+            .map_err(|e| e.to_string())
+            .and_then(|ast| self.engine.run_ast(&ast).map_err(|e| e.to_string()));
+        let commands = self
+            .queue
+            .lock()
+            .map(|mut queue| std::mem::take(&mut *queue))
+            .unwrap_or_default();
+        result.map(|()| commands)
+    }
+
+    /// Runs the script `source`
+    ///
+    /// # Errors
+    /// Always: the game was built without the `scripting` feature
+    #[cfg(not(feature = "scripting"))]
+    pub fn run(&self, _source: &str) -> Result<Vec<ScriptCommand>, String> {
+        Err("built without the `scripting` feature".to_string())
+    }
+}
+
+/// System that queues level scripts as their events happen
+///
+/// # System Dependencies
+/// - **Resources**: Reads `GameState`, `LevelRegistry` and `Time`; writes
+///   `LevelScripts`
+/// - **Events**: Reads `OpenDoorEvent`, `PuzzleSolvedEvent` and
+///   `GameplayTrigger`
+/// - **Downstream**: `run_level_scripts_system` runs the queued scripts
+///
+/// # Behavior
+/// - On entering a room, loads its scripts from the level file and queues
+///   the `EnterRoom` ones
+/// - Queues `DoorOpened`, `PuzzleSolved` and matching `Gameplay` scripts as
+///   those events arrive
+/// - Counts play time in the room (not while paused) for `Timer` scripts
+pub fn level_script_trigger_system(
+    game_state: Res<GameState>,
+    levels: RoomLevels,
+    time: Res<Time>,
+    mut scripts: ResMut<LevelScripts>,
+    mut doors: EventReader<OpenDoorEvent>,
+    mut puzzles: EventReader<PuzzleSolvedEvent>,
+    mut gameplay: EventReader<GameplayTrigger>,
+) {
+    let room = game_state.current_room;
+    if scripts.room != Some(room) {
+        let level = levels.load(room).unwrap_or_else(|e| {
+            warn!("No scripts for room {}: {}", room, e);
+            None
+        });
+        scripts.enter_room(room, level.map(|level| level.scripts).unwrap_or_default());
+    }
+
+    if doors.read().count() > 0 {
+        scripts.trigger(|on| *on == LevelScriptTrigger::DoorOpened);
+    }
+    if puzzles.read().count() > 0 {
+        scripts.trigger(|on| *on == LevelScriptTrigger::PuzzleSolved);
+    }
+    for trigger in gameplay.read() {
+        scripts.trigger(
+            |on| matches!(on, LevelScriptTrigger::Gameplay(name) if *name == trigger.name),
+        );
+    }
+    if game_state.game_mode == GameMode::Playing {
+        scripts.tick(time.delta_secs());
+    }
+}
+
+/// System that runs the queued level scripts and applies their commands
+///
+/// # System Dependencies
+/// - **Upstream**: `level_script_trigger_system` queues the scripts
+/// - **Resources**: Reads `ScriptHost` and `SpawnRegistry` (the standard one
+///   when absent); writes `LevelScripts`
+/// - **Components**: Writes `CandleState` of the room's `LevelEntityId`
+///   lights
+/// - **Downstream**: Emits `StartDialogueEvent`
+///
+/// A script that can't be read or fails is logged and its commands
+/// dropped; the other scripts still run.
+pub fn run_level_scripts_system(
+    mut commands: Commands,
+    host: Res<ScriptHost>,
+    registry: Option<Res<SpawnRegistry>>,
+    mut scripts: ResMut<LevelScripts>,
+    mut lights: Query<(&LevelEntityId, &mut CandleState)>,
+    mut dialogue: EventWriter<StartDialogueEvent>,
+) {
+    if scripts.pending.is_empty() {
+        return;
+    }
+    let room = scripts.room;
+    let registry = registry.as_deref().unwrap_or(SpawnRegistry::standard());
+
+    for path in std::mem::take(&mut scripts.pending) {
+        let result = fs::read_to_string(format!("assets/{}", path))
+            .map_err(|e| format!("Failed to read script: {}", e))
+            .and_then(|source| host.run(&source));
+        let script_commands = match result {
+            Ok(script_commands) => script_commands,
+            Err(e) => {
+                warn!("Level script '{}' skipped: {}", path, e);
+                continue;
+            }
+        };
+
+        for command in script_commands {
+            match command {
+                ScriptCommand::Spawn {
+                    entity_type,
+                    position,
+                } => {
+                    let spawn = EntitySpawn {
+                        entity_type,
+                        position,
+                        ..default()
+                    };
+                    if registry.spawn(&mut commands, &spawn).is_none() {
+                        warn!(
+                            "Level script '{}' can't spawn '{}'",
+                            path, spawn.entity_type
+                        );
+                    }
+                }
+                ScriptCommand::SetLight { index, lit } => {
+                    for (id, mut state) in &mut lights {
+                        if Some(id.room) == room && id.index == index {
+                            state.set_if_neq(light_state(lit));
+                        }
+                    }
+                }
+                ScriptCommand::SetLights(lit) => {
+                    for (id, mut state) in &mut lights {
+                        if Some(id.room) == room {
+                            state.set_if_neq(light_state(lit));
+                        }
+                    }
+                }
+                ScriptCommand::Dialogue(script) => {
+                    dialogue.write(StartDialogueEvent { script });
+                }
+            }
+        }
+    }
+}
+
+fn light_state(lit: bool) -> CandleState {
+    if lit {
+        CandleState::Lit
+    } else {
+        CandleState::Unlit
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn script(on: LevelScriptTrigger, path: &str, once: bool) -> LevelScript {
+        LevelScript {
+            on,
+            script: path.to_string(),
+            once,
+        }
+    }
+
+    #[test]
+    fn scripts_queue_on_their_events_and_once_scripts_stay_spent() {
+        let mut scripts = LevelScripts::default();
+        let room_scripts = vec![
+            script(LevelScriptTrigger::EnterRoom, "enter.rhai", true),
+            script(
+                LevelScriptTrigger::Gameplay("stairs".into()),
+                "stairs.rhai",
+                false,
+            ),
+            script(LevelScriptTrigger::Timer(2.0), "timer.rhai", true),
+        ];
+
+        scripts.enter_room(3, room_scripts.clone());
+        assert_eq!(scripts.pending, vec!["enter.rhai"]);
+        scripts.pending.clear();
+
+        for _ in 0..2 {
+            scripts.trigger(|on| *on == LevelScriptTrigger::Gameplay("stairs".into()));
+        }
+        scripts.trigger(|on| *on == LevelScriptTrigger::DoorOpened);
+        assert_eq!(scripts.pending, vec!["stairs.rhai", "stairs.rhai"]);
+        scripts.pending.clear();
+
+        scripts.tick(1.5);
+        assert!(scripts.pending.is_empty());
+        scripts.tick(1.0);
+        assert_eq!(scripts.pending, vec!["timer.rhai"]);
+        scripts.pending.clear();
+
+        // Coming back: the once scripts have run, the timer restarts
+        scripts.enter_room(3, room_scripts);
+        scripts.tick(5.0);
+        assert!(scripts.pending.is_empty());
+        assert_eq!(scripts.elapsed, 5.0);
+    }
+
+    #[test]
+    fn script_commands_light_the_room_and_start_dialogue() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.init_resource::<ScriptHost>();
+        app.add_event::<StartDialogueEvent>();
+        app.insert_resource(LevelScripts {
+            room: Some(2),
+            ..default()
+        });
+        app.add_systems(Update, run_level_scripts_system);
+        let sconce = app
+            .world_mut()
+            .spawn((LevelEntityId { room: 2, index: 0 }, CandleState::Unlit))
+            .id();
+
+        #[cfg(feature = "scripting")]
+        {
+            let path = format!("scripts/test_{}.rhai", std::process::id());
+            fs::create_dir_all("assets/scripts").unwrap();
+            fs::write(
+                format!("assets/{}", path),
+                r#"set_lights(true); print("lit"); say("attic_whisper");"#,
+            )
+            .unwrap();
+            app.world_mut().resource_mut::<LevelScripts>().pending = vec![path.clone()];
+            app.update();
+            fs::remove_file(format!("assets/{}", path)).ok();
+
+            assert_eq!(
+                app.world().get::<CandleState>(sconce),
+                Some(&CandleState::Lit)
+            );
+            let started: Vec<String> = app
+                .world_mut()
+                .resource_mut::<Events<StartDialogueEvent>>()
+                .drain()
+                .map(|event| event.script)
+                .collect();
+            assert_eq!(started, vec!["attic_whisper"]);
+
+            // Runaway scripts are stopped
+            assert!(
+                app.world()
+                    .resource::<ScriptHost>()
+                    .run("set_lights(false); loop {}")
+                    .is_err()
+            );
+        }
+
+        // Missing files are skipped without touching the room
+        app.world_mut().resource_mut::<LevelScripts>().pending =
+            vec!["scripts/missing.rhai".to_string()];
+        app.update();
+        assert!(app.world().resource::<LevelScripts>().pending.is_empty());
+        let expected = if cfg!(feature = "scripting") {
+            CandleState::Lit
+        } else {
+            CandleState::Unlit
+        };
+        assert_eq!(app.world().get::<CandleState>(sconce), Some(&expected));
+    }
+}
@@ -0,0 +1,345 @@
+use crate::components::lighting::{CandleState, CarriedCandle};
+use crate::components::player::{Player, Velocity};
+use crate::components::puzzle::PuzzleReward;
+use crate::components::room::{
+    Door, DoorState, HiddenPassage, Interactable, InteractionKind, PassageReveal, RoomId,
+    TargetRoom,
+};
+use crate::resources::game_state::GameState;
+use crate::resources::map_state::MapState;
+use crate::systems::puzzle::PuzzleSolvedEvent;
+use bevy::prelude::*;
+
+/// Color of a hidden passage's sprite, matching the surrounding walls
+pub const HIDDEN_PASSAGE_WALL_COLOR: Color = Color::srgb(0.3, 0.28, 0.26);
+
+/// Color of a revealed passage's sprite
+pub const PASSAGE_REVEALED_COLOR: Color = Color::srgb(0.45, 0.3, 0.18);
+
+/// Distance (pixels) from a passage's center within which the player can push it
+pub const PASSAGE_PUSH_RANGE: f32 = 32.0;
+
+/// Plugin that registers hidden passages and their reveal triggers
+pub struct SecretPassagePlugin;
+
+impl Plugin for SecretPassagePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<PuzzleSolvedEvent>()
+            .add_event::<SecretRevealedEvent>()
+            .add_systems(
+                Update,
+                (
+                    restore_discovered_passages_system,
+                    passage_puzzle_reveal_system,
+                    passage_candle_reveal_system,
+                    passage_push_reveal_system,
+                    passage_reveal_system,
+                )
+                    .chain(),
+            );
+    }
+}
+
+/// Event emitted the first time the player discovers a secret passage
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SecretRevealedEvent {
+    /// The passage entity
+    pub passage: Entity,
+    /// Room the passage leads to
+    pub target_room: RoomId,
+}
+
+/// System that keeps passages found on an earlier visit revealed
+///
+/// # System Dependencies
+/// - **Resources**: Reads `GameState` and `MapState`
+/// - **Components**: Writes newly spawned `HiddenPassage`s
+pub fn restore_discovered_passages_system(
+    game_state: Res<GameState>,
+    map_state: Res<MapState>,
+    mut passages: Query<(&Transform, &mut HiddenPassage), Added<HiddenPassage>>,
+) {
+    for (transform, mut passage) in &mut passages {
+        if map_state.has_secret_at(game_state.current_room, transform.translation.truncate()) {
+            passage.revealed = true;
+        }
+    }
+}
+
+/// System that reveals passages rewarded by solved puzzles
+///
+/// # System Dependencies
+/// - **Upstream**: `puzzle_interaction_system` emits `PuzzleSolvedEvent`
+/// - **Components**: Reads `TargetRoom`, writes `HiddenPassage`
+///
+/// # Behavior
+/// A `PuzzleReward::RevealPassage(room)` reveals every hidden passage
+/// leading to `room`, whatever its `PassageReveal` method.
+pub fn passage_puzzle_reveal_system(
+    mut events: EventReader<PuzzleSolvedEvent>,
+    mut passages: Query<(&mut HiddenPassage, &TargetRoom)>,
+) {
+    for event in events.read() {
+        let PuzzleReward::RevealPassage(room) = &event.reward else {
+            continue;
+        };
+
+        for (mut passage, target_room) in &mut passages {
+            if target_room.0 == *room && !passage.revealed {
+                passage.revealed = true;
+            }
+        }
+    }
+}
+
+/// System that reveals `PassageReveal::CandleLight` passages near a carried lit candle
+///
+/// # System Dependencies
+/// - **Components**: Reads `CarriedCandle`, `CandleState`, transforms; writes `HiddenPassage`
+#[allow(clippy::type_complexity)]
+pub fn passage_candle_reveal_system(
+    candles: Query<(&Transform, &CandleState), (With<CarriedCandle>, Without<HiddenPassage>)>,
+    mut passages: Query<(&Transform, &mut HiddenPassage)>,
+) {
+    for (transform, mut passage) in &mut passages {
+        let PassageReveal::CandleLight { radius } = passage.reveal else {
+            continue;
+        };
+        if passage.revealed {
+            continue;
+        }
+
+        let position = transform.translation.truncate();
+        let lit_nearby = candles.iter().any(|(candle_transform, state)| {
+            *state == CandleState::Lit
+                && candle_transform.translation.truncate().distance(position) <= radius
+        });
+        if lit_nearby {
+            passage.revealed = true;
+        }
+    }
+}
+
+/// System that reveals `PassageReveal::Push` passages the player pushes against
+///
+/// # System Dependencies
+/// - **Resources**: Reads `Time`
+/// - **Components**: Reads `Player` `Transform` and `Velocity`; writes `HiddenPassage`
+///
+/// # Behavior
+/// The player pushes a passage while within `PASSAGE_PUSH_RANGE` of it and
+/// moving horizontally toward it. Pushing accumulates `push_progress`; the
+/// passage opens after `seconds` of pushing and progress resets as soon as
+/// the player stops.
+#[allow(clippy::type_complexity)]
+pub fn passage_push_reveal_system(
+    time: Res<Time>,
+    player_query: Query<(&Transform, &Velocity), (With<Player>, Without<HiddenPassage>)>,
+    mut passages: Query<(&Transform, &mut HiddenPassage)>,
+) {
+    let Ok((player_transform, velocity)) = player_query.single() else {
+        return;
+    };
+    let player_pos = player_transform.translation.truncate();
+
+    for (transform, mut passage) in &mut passages {
+        let PassageReveal::Push { seconds } = passage.reveal else {
+            continue;
+        };
+        if passage.revealed {
+            continue;
+        }
+
+        let offset = transform.translation.truncate() - player_pos;
+        let pushing = offset.length() <= PASSAGE_PUSH_RANGE
+            && velocity.0.x != 0.0
+            && velocity.0.x.signum() == offset.x.signum();
+
+        if pushing {
+            passage.push_progress += time.delta_secs();
+            if passage.push_progress >= seconds {
+                passage.revealed = true;
+            }
+        } else if passage.push_progress != 0.0 {
+            passage.push_progress = 0.0;
+        }
+    }
+}
+
+/// System that turns revealed passages into doors and records the discovery
+///
+/// # System Dependencies
+/// - **Upstream**: The reveal systems above set `HiddenPassage::revealed`
+/// - **Resources**: Writes `GameState::collected_secrets` and `MapState`
+/// - **Components**: Inserts `Door`, `DoorState`, `Interactable`; writes `Sprite`
+/// - **Downstream**: Emits `SecretRevealedEvent` for first discoveries
+///
+/// # Behavior
+/// Revealed passages become unlocked doors to their `TargetRoom` and are
+/// tinted `PASSAGE_REVEALED_COLOR`. Passages re-revealed on a later visit
+/// (see `restore_discovered_passages_system`) aren't counted again.
+#[allow(clippy::type_complexity)]
+pub fn passage_reveal_system(
+    mut commands: Commands,
+    mut game_state: ResMut<GameState>,
+    mut map_state: ResMut<MapState>,
+    mut revealed_events: EventWriter<SecretRevealedEvent>,
+    mut passages: Query<
+        (
+            Entity,
+            &Transform,
+            &HiddenPassage,
+            &TargetRoom,
+            Option<&mut Sprite>,
+        ),
+        (Changed<HiddenPassage>, Without<Door>),
+    >,
+) {
+    for (entity, transform, passage, target_room, sprite) in &mut passages {
+        if !passage.revealed {
+            continue;
+        }
+
+        commands.entity(entity).insert((
+            Door,
+            DoorState::Unlocked,
+            Interactable::new(InteractionKind::OpenDoor),
+        ));
+        if let Some(mut sprite) = sprite {
+            sprite.color = PASSAGE_REVEALED_COLOR;
+        }
+
+        let room = game_state.current_room;
+        if map_state.record_secret(room, transform.translation.truncate()) {
+            game_state.collected_secrets.insert(entity);
+            revealed_events.write(SecretRevealedEvent {
+                passage: entity,
+                target_room: target_room.0,
+            });
+            info!(
+                "Discovered a secret passage in room {} leading to room {}",
+                room, target_room.0
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn setup_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(SecretPassagePlugin);
+        app.init_resource::<GameState>();
+        app.init_resource::<MapState>();
+        app
+    }
+
+    #[test]
+    fn puzzle_reward_reveals_passage_as_door_and_records_secret() {
+        let mut app = setup_app();
+        let passage = app
+            .world_mut()
+            .spawn((
+                HiddenPassage::new(PassageReveal::PuzzleReward),
+                TargetRoom(4),
+                Transform::from_xyz(96.0, 0.0, 0.0),
+            ))
+            .id();
+        app.update();
+        assert!(app.world().get::<Door>(passage).is_none());
+
+        let puzzle = app.world_mut().spawn_empty().id();
+        app.world_mut().send_event(PuzzleSolvedEvent {
+            puzzle,
+            reward: PuzzleReward::RevealPassage(4),
+        });
+        app.update();
+
+        assert!(app.world().get::<Door>(passage).is_some());
+        assert_eq!(
+            *app.world().get::<DoorState>(passage).unwrap(),
+            DoorState::Unlocked
+        );
+        assert!(
+            app.world()
+                .resource::<GameState>()
+                .collected_secrets
+                .contains(&passage)
+        );
+        assert!(
+            app.world()
+                .resource::<MapState>()
+                .has_secret_at(0, Vec2::new(96.0, 0.0))
+        );
+        let events: Vec<SecretRevealedEvent> = app
+            .world_mut()
+            .resource_mut::<Events<SecretRevealedEvent>>()
+            .drain()
+            .collect();
+        assert_eq!(
+            events,
+            vec![SecretRevealedEvent {
+                passage,
+                target_room: 4
+            }]
+        );
+    }
+
+    #[test]
+    fn candle_light_and_pushing_reveal_passages() {
+        let mut app = setup_app();
+        let lit_wall = app
+            .world_mut()
+            .spawn((
+                HiddenPassage::new(PassageReveal::CandleLight { radius: 40.0 }),
+                TargetRoom(1),
+                Transform::from_xyz(-200.0, 0.0, 0.0),
+            ))
+            .id();
+        let pushed_wall = app
+            .world_mut()
+            .spawn((
+                HiddenPassage::new(PassageReveal::Push { seconds: 1.0 }),
+                TargetRoom(2),
+                Transform::from_xyz(20.0, 0.0, 0.0),
+            ))
+            .id();
+        app.world_mut().spawn((
+            Player,
+            Velocity(Vec2::new(100.0, 0.0)),
+            Transform::default(),
+        ));
+        let candle = app
+            .world_mut()
+            .spawn((
+                CarriedCandle::default(),
+                CandleState::Lit,
+                Transform::from_xyz(-100.0, 0.0, 0.0),
+            ))
+            .id();
+        app.update();
+        assert!(!app.world().get::<HiddenPassage>(lit_wall).unwrap().revealed);
+
+        // Bring the candle close and push for long enough
+        app.world_mut()
+            .get_mut::<Transform>(candle)
+            .unwrap()
+            .translation
+            .x = -180.0;
+        app.world_mut()
+            .get_mut::<HiddenPassage>(pushed_wall)
+            .unwrap()
+            .push_progress = 1.0;
+        app.update();
+
+        assert!(app.world().get::<Door>(lit_wall).is_some());
+        assert!(app.world().get::<Door>(pushed_wall).is_some());
+        assert_eq!(
+            app.world().resource::<GameState>().collected_secrets.len(),
+            2
+        );
+    }
+}
@@ -0,0 +1,105 @@
+use bevy::prelude::*;
+
+use crate::resources::game_clock::GameClock;
+
+/// Plugin that plays sprite sheet animations (flickering sconces, drips)
+pub struct SpriteAnimationPlugin;
+
+impl Plugin for SpriteAnimationPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<GameClock>()
+            .add_systems(Update, sprite_animation_system);
+    }
+}
+
+/// Component looping a sprite through consecutive frames of its atlas
+///
+/// Spawn it alongside a `Sprite` from `AssetHandles::sprite_frame`; sprites
+/// without a `TextureAtlas` are left alone.
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct SpriteAnimation {
+    /// Atlas index of the first frame
+    pub first: usize,
+    /// Number of frames in the loop
+    pub frames: usize,
+    /// Frames shown per second of game time
+    pub fps: f32,
+    /// Seconds since the animation started
+    pub elapsed: f32,
+}
+
+impl SpriteAnimation {
+    /// A loop over `frames` frames from atlas index `first`
+    pub fn new(first: usize, frames: usize, fps: f32) -> Self {
+        Self {
+            first,
+            frames,
+            fps,
+            elapsed: 0.0,
+        }
+    }
+
+    /// Atlas index of the frame currently shown
+    pub fn index(&self) -> usize {
+        let frame = (self.elapsed * self.fps) as usize;
+        self.first + frame % self.frames.max(1)
+    }
+}
+
+/// System that advances sprite animations
+///
+/// # System Dependencies
+/// - **Components**: Writes `SpriteAnimation` and the `Sprite`'s atlas index
+/// - **Resources**: Reads `GameClock` (animations freeze while paused)
+pub fn sprite_animation_system(
+    clock: Res<GameClock>,
+    mut query: Query<(&mut SpriteAnimation, &mut Sprite)>,
+) {
+    for (mut animation, mut sprite) in &mut query {
+        animation.elapsed += clock.delta;
+        let index = animation.index();
+        if let Some(atlas) = sprite.texture_atlas.as_mut()
+            && atlas.index != index
+        {
+            atlas.index = index;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn animations_loop_through_their_frames() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.insert_resource(GameClock {
+            delta: 0.25,
+            ..default()
+        });
+        app.add_systems(Update, sprite_animation_system);
+
+        let sconce = app
+            .world_mut()
+            .spawn((
+                SpriteAnimation::new(4, 3, 4.0),
+                Sprite::from_atlas_image(
+                    Handle::default(),
+                    TextureAtlas {
+                        layout: Handle::default(),
+                        index: 4,
+                    },
+                ),
+            ))
+            .id();
+
+        let mut indices = Vec::new();
+        for _ in 0..4 {
+            app.update();
+            let sprite = app.world().get::<Sprite>(sconce).unwrap();
+            indices.push(sprite.texture_atlas.as_ref().unwrap().index);
+        }
+        assert_eq!(indices, vec![5, 6, 4, 5]);
+    }
+}
@@ -0,0 +1,190 @@
+use crate::components::inventory::{Collectible, Item, StackableItem};
+use crate::components::room::Collider;
+use crate::components::trap::WaterDrip;
+use crate::systems::level_loader::{EntitySpawn, spawn_level_entities_batched};
+use crate::systems::water_drip::{DRIP_LIFETIME, DripParticle};
+use bevy::prelude::*;
+
+/// Number of entities in the stress room
+///
+/// The performance target: with `CollisionGrid` in place a room this full
+/// should hold 60 FPS on mid-range hardware (see `stress_room_bench` and
+/// the perf overlay).
+pub const STRESS_ROOM_ENTITY_COUNT: usize = 1000;
+
+/// Entities per row of the stress room layout
+pub const STRESS_ROOM_COLUMNS: usize = 40;
+
+/// Spacing (pixels) between stress room entities
+pub const STRESS_ROOM_SPACING: f32 = 32.0;
+
+/// Kind of entity at each position, repeating every ten entities
+///
+/// Four traps, three items, two falling drops and one dripping ceiling
+/// spot: the mix of a dense late-game room, scaled up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StressEntity {
+    /// Trap spawned through the level loader
+    Trap(&'static str),
+    /// Collectible stack of matches
+    Item,
+    /// Falling water drop
+    Particle,
+    /// Ceiling spot releasing water drops
+    Emitter,
+}
+
+impl StressEntity {
+    /// Kind of the `index`th stress room entity
+    pub fn at(index: usize) -> Self {
+        match index % 10 {
+            0 => StressEntity::Trap("Spikes"),
+            1 => StressEntity::Trap("PoisonGas"),
+            2 => StressEntity::Trap("SwingingBlade"),
+            3 => StressEntity::Trap("CollapsingFloor"),
+            4..=6 => StressEntity::Item,
+            7 | 8 => StressEntity::Particle,
+            _ => StressEntity::Emitter,
+        }
+    }
+}
+
+/// Position of the `index`th stress room entity
+pub fn stress_room_position(index: usize) -> Vec2 {
+    Vec2::new(
+        (index % STRESS_ROOM_COLUMNS) as f32,
+        (index / STRESS_ROOM_COLUMNS) as f32,
+    ) * STRESS_ROOM_SPACING
+}
+
+/// Spawns the stress-test fixture room: `count` active entities in a grid
+///
+/// Traps go through `spawn_level_entities_batched` as a level would; items,
+/// drops and drip spots are spawned directly. Used by the stress room
+/// benchmark and to profile with the perf overlay.
+///
+/// # Returns
+/// The spawned entities, in layout order
+pub fn spawn_stress_room(world: &mut World, count: usize) -> Vec<Entity> {
+    let spawns: Vec<EntitySpawn> = (0..count)
+        .filter_map(|index| match StressEntity::at(index) {
+            StressEntity::Trap(entity_type) => {
+                let position = stress_room_position(index);
+                Some(EntitySpawn {
+                    entity_type: entity_type.to_string(),
+                    position: (position.x, position.y),
+                    target_room: None,
+                    locked: None,
+                    key_type: None,
+                    trap_config: None,
+                    reveal: None,
+                    dialogue: None,
+                    trigger: None,
+                    water: None,
+                    breakable: None,
+                    item: None,
+                    electric: None,
+                    stuck: false,
+                    burn: None,
+                })
+            }
+            _ => None,
+        })
+        .collect();
+    let mut traps = spawn_level_entities_batched(world, &spawns).into_iter();
+
+    (0..count)
+        .filter_map(|index| {
+            let transform = Transform::from_translation(stress_room_position(index).extend(0.0));
+            match StressEntity::at(index) {
+                StressEntity::Trap(_) => traps.next().flatten(),
+                StressEntity::Item => Some(
+                    world
+                        .spawn((
+                            Item::Match,
+                            StackableItem(1),
+                            Collectible,
+                            Collider {
+                                min: Vec2::splat(-8.0),
+                                max: Vec2::splat(8.0),
+                            },
+                            transform,
+                        ))
+                        .id(),
+                ),
+                StressEntity::Particle => Some(
+                    world
+                        .spawn((
+                            DripParticle {
+                                extinguish_chance: 0.0,
+                                lifetime: Timer::from_seconds(DRIP_LIFETIME, TimerMode::Once),
+                            },
+                            transform,
+                        ))
+                        .id(),
+                ),
+                StressEntity::Emitter => {
+                    Some(world.spawn((WaterDrip::new(1.0, 0.0), transform)).id())
+                }
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::audio::sound_bank::PlaySoundEvent;
+    use crate::components::player::Player;
+    use crate::components::trap::Trap;
+    use crate::resources::game_rng::GameRng;
+    use crate::resources::game_state::{GameMode, GameState};
+    use crate::systems::collision::{CollisionGrid, CollisionPlugin};
+    use crate::systems::fixed_timestep::advance_fixed_timestep;
+    use crate::systems::water_drip::{
+        CandleSizzleEvent, drip_particle_system, water_drip_spawn_system,
+    };
+
+    #[test]
+    fn stress_room_spawns_mixed_entities() {
+        let mut world = World::new();
+        let entities = spawn_stress_room(&mut world, STRESS_ROOM_ENTITY_COUNT);
+
+        assert_eq!(entities.len(), STRESS_ROOM_ENTITY_COUNT);
+        assert_eq!(world.query::<&Trap>().iter(&world).count(), 400);
+        assert_eq!(world.query::<&Collectible>().iter(&world).count(), 300);
+        assert_eq!(world.query::<&DripParticle>().iter(&world).count(), 200);
+        assert_eq!(world.query::<&WaterDrip>().iter(&world).count(), 100);
+    }
+
+    #[test]
+    fn stress_room_runs_with_collision_grid() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(CollisionPlugin);
+        app.insert_resource(GameState {
+            game_mode: GameMode::Playing,
+            ..default()
+        });
+        app.add_event::<CandleSizzleEvent>();
+        app.add_event::<PlaySoundEvent>();
+        app.insert_resource(GameRng::from_seed(0));
+        app.add_systems(Update, (water_drip_spawn_system, drip_particle_system));
+        spawn_stress_room(app.world_mut(), STRESS_ROOM_ENTITY_COUNT);
+        app.world_mut().spawn((
+            Player,
+            Transform::default(),
+            Collider {
+                min: Vec2::splat(-8.0),
+                max: Vec2::splat(8.0),
+            },
+        ));
+
+        for _ in 0..3 {
+            advance_fixed_timestep(&mut app);
+        }
+
+        // Every trap and item is bucketed; drops and drip spots have no collider
+        assert_eq!(app.world().resource::<CollisionGrid>().len(), 700);
+    }
+}
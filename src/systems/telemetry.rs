@@ -0,0 +1,493 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::time::Duration;
+
+use crate::components::puzzle::Puzzle;
+use crate::resources::game_state::{GameMode, GameState};
+use crate::resources::map_state::DeathCause;
+use crate::systems::puzzle::{PuzzleInteractEvent, PuzzleSolvedEvent};
+use crate::systems::save_storage::{SaveBackend, SaveStorage, TELEMETRY_FILE_NAME};
+use crate::systems::trap::PlayerDeathEvent;
+
+/// Prefix of the JSONL telemetry log files, one per session
+pub const TELEMETRY_LOG_PREFIX: &str = "telemetry-";
+
+/// Seconds between writes of the pending events
+pub const TELEMETRY_FLUSH_SECS: f32 = 60.0;
+
+/// Pending events that trigger a write before `TELEMETRY_FLUSH_SECS` is up
+pub const TELEMETRY_BATCH_SIZE: usize = 50;
+
+/// Plugin for opt-in gameplay telemetry
+///
+/// While the player has opted in (`TelemetrySettings::enabled`, off by
+/// default), deaths, puzzle solve times and quits are batched and appended
+/// to a `telemetry-{session}.jsonl` file through the `SaveBackend`, and
+/// posted to `TelemetrySettings::endpoint` if one is set (needs the
+/// `telemetry-upload` feature). Events are anonymous: a random per-session
+/// ID, room IDs and times, never names or positions. `WipeTelemetryEvent`
+/// deletes every log file.
+pub struct TelemetryPlugin;
+
+impl Plugin for TelemetryPlugin {
+    fn build(&self, app: &mut App) {
+        let settings =
+            TelemetrySettings::load(&*app.world_mut().get_resource_or_init::<SaveBackend>());
+        app.insert_resource(settings)
+            .init_resource::<Telemetry>()
+            .init_resource::<GameState>()
+            .add_event::<PlayerDeathEvent>()
+            .add_event::<PuzzleInteractEvent>()
+            .add_event::<PuzzleSolvedEvent>()
+            .add_event::<WipeTelemetryEvent>()
+            .add_systems(
+                Update,
+                (
+                    record_telemetry_system,
+                    flush_telemetry_system,
+                    wipe_telemetry_system,
+                    save_telemetry_settings_system,
+                )
+                    .chain(),
+            );
+    }
+}
+
+/// Resource holding the player's telemetry choice, persisted to
+/// `TELEMETRY_FILE_NAME`
+#[derive(Resource, Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct TelemetrySettings {
+    /// Whether the player opted in to sharing gameplay data
+    #[serde(default)]
+    pub enabled: bool,
+    /// URL batches of events are posted to as JSONL; `None` keeps them local
+    #[serde(default)]
+    pub endpoint: Option<String>,
+}
+
+impl TelemetrySettings {
+    /// Reads the settings from `TELEMETRY_FILE_NAME`
+    ///
+    /// A missing or unreadable file gives the default (opted out) settings.
+    pub fn load(storage: &impl SaveStorage) -> Self {
+        match storage.read(TELEMETRY_FILE_NAME) {
+            Ok(contents) => ron::from_str(&contents).unwrap_or_else(|e| {
+                warn!(
+                    "Ignoring unreadable telemetry settings '{}': {}",
+                    storage.location(TELEMETRY_FILE_NAME),
+                    e
+                );
+                Self::default()
+            }),
+            Err(_) => Self::default(),
+        }
+    }
+
+    /// Writes the settings to `TELEMETRY_FILE_NAME`
+    ///
+    /// # Errors
+    /// Returns error string if serialization or writing fails
+    pub fn save(&self, storage: &impl SaveStorage) -> Result<(), String> {
+        let contents = ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())
+            .map_err(|e| format!("Failed to serialize telemetry settings: {}", e))?;
+        storage.write(TELEMETRY_FILE_NAME, &contents)
+    }
+}
+
+/// Something that happened in play, as recorded
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum TelemetryEvent {
+    /// The player died
+    Death {
+        /// Room the player died in
+        room: usize,
+        /// What killed the player
+        cause: DeathCause,
+    },
+    /// A puzzle was solved
+    PuzzleSolved {
+        /// Room of the puzzle
+        room: usize,
+        /// Kind of puzzle (see `puzzle_kind`)
+        puzzle: String,
+        /// Seconds from first touching the puzzle (or entering the room) to
+        /// solving it
+        solve_secs: f32,
+    },
+    /// The player left a run for the main menu or closed the game
+    Quit {
+        /// Room the player was in
+        room: usize,
+    },
+}
+
+/// One line of a telemetry log
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct TelemetryRecord {
+    /// Random ID of the play session, shared by its records
+    pub session: String,
+    /// Play time of the run when it happened, in seconds
+    pub play_secs: f32,
+    /// What happened
+    #[serde(flatten)]
+    pub event: TelemetryEvent,
+}
+
+/// Resource holding the events waiting to be written
+#[derive(Resource, Debug)]
+pub struct Telemetry {
+    /// Random ID of this play session
+    pub session: String,
+    /// Events recorded since the last write
+    pub pending: Vec<TelemetryRecord>,
+    /// Time until the next write
+    pub flush_timer: Timer,
+    /// When each puzzle was first touched, by elapsed app time
+    puzzle_started: HashMap<Entity, Duration>,
+    /// Room the player was last seen in and when they entered it
+    room_entered: (usize, Duration),
+    /// Game mode last frame, to notice quits to the main menu
+    last_mode: Option<GameMode>,
+}
+
+impl Default for Telemetry {
+    fn default() -> Self {
+        Self {
+            session: format!("{:016x}", rand::random::<u64>()),
+            pending: Vec::new(),
+            flush_timer: Timer::from_seconds(TELEMETRY_FLUSH_SECS, TimerMode::Repeating),
+            puzzle_started: HashMap::new(),
+            room_entered: (0, Duration::ZERO),
+            last_mode: None,
+        }
+    }
+}
+
+impl Telemetry {
+    /// Name of this session's log file
+    pub fn log_file_name(&self) -> String {
+        format!("{}{}.jsonl", TELEMETRY_LOG_PREFIX, self.session)
+    }
+}
+
+/// Event asking for every telemetry log to be deleted
+#[derive(Event, Debug, Clone, Copy, Default)]
+pub struct WipeTelemetryEvent;
+
+/// Name of a puzzle's kind as recorded, e.g. `"circuit_breaker"`
+pub fn puzzle_kind(puzzle: &Puzzle) -> &'static str {
+    match puzzle {
+        Puzzle::CircuitBreaker(_) => "circuit_breaker",
+        Puzzle::PressurePlate(_) => "pressure_plate",
+        Puzzle::SymbolMatch(_) => "symbol_match",
+        Puzzle::MirrorReflection => "mirror_reflection",
+        Puzzle::LeverCombination(_) => "lever_combination",
+    }
+}
+
+/// Returns true for the files `WipeTelemetryEvent` deletes
+pub fn is_telemetry_log(name: &str) -> bool {
+    name.starts_with(TELEMETRY_LOG_PREFIX) && name.ends_with(".jsonl")
+}
+
+/// System that records gameplay events while the player has opted in
+///
+/// # System Dependencies
+/// - **Upstream**: `trap_activation_system` emits `PlayerDeathEvent`;
+///   `puzzle_interaction_system` emits `PuzzleInteractEvent` and
+///   `PuzzleSolvedEvent`; menus set `GameMode::Menu`
+/// - **Resources**: Reads `Time`, `TelemetrySettings`, `GameState`, `AppExit`
+///   events; writes `Telemetry`
+/// - **Components**: Reads `Puzzle`
+/// - **Downstream**: `flush_telemetry_system` writes the events out
+#[allow(clippy::too_many_arguments)]
+pub fn record_telemetry_system(
+    time: Res<Time>,
+    settings: Res<TelemetrySettings>,
+    game_state: Res<GameState>,
+    mut telemetry: ResMut<Telemetry>,
+    mut deaths: EventReader<PlayerDeathEvent>,
+    mut interactions: EventReader<PuzzleInteractEvent>,
+    mut solves: EventReader<PuzzleSolvedEvent>,
+    mut exits: EventReader<AppExit>,
+    puzzle_query: Query<&Puzzle>,
+) {
+    let now = time.elapsed();
+    let room = game_state.current_room;
+    if telemetry.room_entered.0 != room {
+        telemetry.room_entered = (room, now);
+    }
+    let last_mode = telemetry.last_mode.replace(game_state.game_mode);
+    if !settings.enabled {
+        deaths.clear();
+        interactions.clear();
+        solves.clear();
+        exits.clear();
+        return;
+    }
+
+    let mut events = Vec::new();
+    for death in deaths.read() {
+        events.push(TelemetryEvent::Death {
+            room,
+            cause: death.cause,
+        });
+    }
+    for interaction in interactions.read() {
+        telemetry
+            .puzzle_started
+            .entry(interaction.puzzle)
+            .or_insert(now);
+    }
+    for solve in solves.read() {
+        let started = telemetry
+            .puzzle_started
+            .remove(&solve.puzzle)
+            .unwrap_or(telemetry.room_entered.1);
+        events.push(TelemetryEvent::PuzzleSolved {
+            room,
+            puzzle: puzzle_query
+                .get(solve.puzzle)
+                .map_or("unknown", puzzle_kind)
+                .to_string(),
+            solve_secs: (now - started).as_secs_f32(),
+        });
+    }
+    let in_run = |mode: Option<GameMode>| {
+        matches!(
+            mode,
+            Some(GameMode::Playing | GameMode::Paused | GameMode::GameOver)
+        )
+    };
+    let quit_to_menu = in_run(last_mode) && game_state.game_mode == GameMode::Menu;
+    let closed = exits.read().count() > 0 && in_run(Some(game_state.game_mode));
+    if quit_to_menu || closed {
+        events.push(TelemetryEvent::Quit { room });
+    }
+
+    let play_secs = game_state.completion_time.as_secs_f32();
+    let session = telemetry.session.clone();
+    telemetry
+        .pending
+        .extend(events.into_iter().map(|event| TelemetryRecord {
+            session: session.clone(),
+            play_secs,
+            event,
+        }));
+}
+
+/// System that appends pending telemetry to the session's log file
+///
+/// # System Dependencies
+/// - **Upstream**: `record_telemetry_system` queues events
+/// - **Resources**: Reads `Time`, `TelemetrySettings`, `AppExit` events;
+///   writes `Telemetry` and the log through `SaveBackend`
+///
+/// # Behavior
+/// Writes every `TELEMETRY_FLUSH_SECS`, once `TELEMETRY_BATCH_SIZE` events
+/// are pending, and when the game closes. Each batch is also posted to the
+/// endpoint, if set, in the background; the local log keeps it either way.
+pub fn flush_telemetry_system(
+    time: Res<Time>,
+    settings: Res<TelemetrySettings>,
+    mut telemetry: ResMut<Telemetry>,
+    mut exits: EventReader<AppExit>,
+    storage: Res<SaveBackend>,
+) {
+    telemetry.flush_timer.tick(time.delta());
+    if !settings.enabled {
+        telemetry.pending.clear();
+        exits.clear();
+        return;
+    }
+    let closing = exits.read().count() > 0;
+    if telemetry.pending.is_empty()
+        || !(closing
+            || telemetry.flush_timer.just_finished()
+            || telemetry.pending.len() >= TELEMETRY_BATCH_SIZE)
+    {
+        return;
+    }
+
+    let batch: String = telemetry
+        .pending
+        .iter()
+        .filter_map(|record| serde_json::to_string(record).ok())
+        .map(|line| line + "\n")
+        .collect();
+    let name = telemetry.log_file_name();
+    let log = storage.read(&name).unwrap_or_default() + &batch;
+    if let Err(e) = storage.write(&name, &log) {
+        error!("Failed to write telemetry: {}", e);
+        return;
+    }
+    telemetry.pending.clear();
+    if let Some(endpoint) = &settings.endpoint {
+        upload_batch(endpoint, batch);
+    }
+}
+
+/// Posts a JSONL batch to `endpoint` on the IO task pool
+#[cfg(all(feature = "telemetry-upload", not(target_arch = "wasm32")))]
+fn upload_batch(endpoint: &str, batch: String) {
+    let endpoint = endpoint.to_string();
+    bevy::tasks::IoTaskPool::get()
+        .spawn(async move {
+            if let Err(e) = ureq::post(&endpoint)
+                .set("Content-Type", "application/x-ndjson")
+                .send_string(&batch)
+            {
+                warn!("Failed to upload telemetry: {}", e);
+            }
+        })
+        .detach();
+}
+
+/// Builds without `telemetry-upload` keep telemetry local
+#[cfg(not(all(feature = "telemetry-upload", not(target_arch = "wasm32"))))]
+fn upload_batch(endpoint: &str, _batch: String) {
+    debug!(
+        "Not uploading telemetry to '{}': this build has no telemetry-upload support",
+        endpoint
+    );
+}
+
+/// System that deletes every telemetry log on `WipeTelemetryEvent`
+///
+/// # System Dependencies
+/// - **Upstream**: The pause menu's "Delete gameplay data" emits
+///   `WipeTelemetryEvent`
+/// - **Resources**: Writes `Telemetry` and removes logs through `SaveBackend`
+pub fn wipe_telemetry_system(
+    mut events: EventReader<WipeTelemetryEvent>,
+    mut telemetry: ResMut<Telemetry>,
+    storage: Res<SaveBackend>,
+) {
+    if events.read().count() == 0 {
+        return;
+    }
+    telemetry.pending.clear();
+    let logs: Vec<String> = storage
+        .file_names()
+        .into_iter()
+        .filter(|name| is_telemetry_log(name))
+        .collect();
+    for name in &logs {
+        if let Err(e) = storage.remove(name) {
+            error!("{}", e);
+        }
+    }
+    info!("Deleted {} telemetry log(s)", logs.len());
+}
+
+/// System that writes the telemetry settings to storage whenever they change
+///
+/// # System Dependencies
+/// - **Resources**: Reads `TelemetrySettings`, writes through `SaveBackend`
+pub fn save_telemetry_settings_system(settings: Res<TelemetrySettings>, storage: Res<SaveBackend>) {
+    if !settings.is_changed() || settings.is_added() {
+        return;
+    }
+
+    if let Err(e) = settings.save(&*storage) {
+        error!("{}", e);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::systems::save_storage::MemoryStorage;
+
+    fn app(storage: &MemoryStorage, enabled: bool) -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.insert_resource(SaveBackend::new(storage.clone()));
+        app.insert_resource(TelemetrySettings {
+            enabled,
+            endpoint: None,
+        });
+        app.insert_resource(GameState {
+            current_room: 3,
+            game_mode: GameMode::Playing,
+            ..default()
+        });
+        app.init_resource::<Telemetry>();
+        app.add_event::<PlayerDeathEvent>();
+        app.add_event::<PuzzleInteractEvent>();
+        app.add_event::<PuzzleSolvedEvent>();
+        app.add_event::<WipeTelemetryEvent>();
+        app.add_systems(
+            Update,
+            (
+                record_telemetry_system,
+                flush_telemetry_system,
+                wipe_telemetry_system,
+            )
+                .chain(),
+        );
+        app
+    }
+
+    fn die_then_quit(app: &mut App) {
+        app.update();
+        app.world_mut().send_event(PlayerDeathEvent {
+            player: Entity::PLACEHOLDER,
+            cause: DeathCause::Spikes,
+        });
+        app.update();
+        app.world_mut().resource_mut::<GameState>().game_mode = GameMode::Menu;
+        app.update();
+    }
+
+    #[test]
+    fn nothing_is_recorded_without_opting_in() {
+        let storage = MemoryStorage::default();
+        let mut app = app(&storage, false);
+        die_then_quit(&mut app);
+        app.world_mut().send_event(AppExit::Success);
+        app.update();
+
+        assert!(app.world().resource::<Telemetry>().pending.is_empty());
+        assert!(storage.file_names().is_empty());
+    }
+
+    #[test]
+    fn events_are_logged_as_jsonl_and_can_be_wiped() {
+        let storage = MemoryStorage::default();
+        let mut app = app(&storage, true);
+        die_then_quit(&mut app);
+        assert_eq!(app.world().resource::<Telemetry>().pending.len(), 2);
+
+        // Closing the game writes the batch out
+        app.world_mut().send_event(AppExit::Success);
+        app.update();
+        let name = app.world().resource::<Telemetry>().log_file_name();
+        let log = storage.read(&name).unwrap();
+        let records: Vec<TelemetryRecord> = log
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(
+            records
+                .iter()
+                .map(|record| &record.event)
+                .collect::<Vec<_>>(),
+            [
+                &TelemetryEvent::Death {
+                    room: 3,
+                    cause: DeathCause::Spikes
+                },
+                &TelemetryEvent::Quit { room: 3 },
+            ]
+        );
+        assert!(log.starts_with("{\"session\":"));
+
+        storage.write("save1.ron", "()").unwrap();
+        app.world_mut().send_event(WipeTelemetryEvent);
+        app.update();
+        assert_eq!(storage.file_names(), vec!["save1.ron"]);
+    }
+}
@@ -1,33 +1,96 @@
+use crate::components::render_layer::RenderLayer;
+use crate::resources::asset_handles::{AssetHandles, SpriteType};
 use crate::resources::game_state::GameState;
 use bevy::prelude::*;
 use bevy_ecs_tilemap::prelude::*;
 
+/// Index of the floor tile in the tileset
+pub const FLOOR_TILE: u32 = 0;
+
+/// Index of the wall tile in the tileset
+pub const WALL_TILE: u32 = 1;
+
+/// Tile size used when the tileset has no sheet layout in `AssetHandles`
+pub const DEFAULT_TILE_SIZE: TilemapTileSize = TilemapTileSize { x: 32.0, y: 32.0 };
+
 /// System that sets up tilemap rendering for a room
 ///
 /// Creates a tilemap entity with tile storage and spawns individual tiles
 /// for rendering room floors, walls, and environmental elements.
 ///
 /// # System Dependencies
-/// - **Resources**: AssetServer for loading tileset texture
+/// - **Resources**: AssetServer for loading tileset texture; reads
+///   `AssetHandles` (when present) for the tileset sheet
 /// - **Components**: Creates TilemapBundle with TileStorage
+/// - **Upstream**: `load_sprite_handles_system` must run first to use the
+///   manifest's tileset
 ///
 /// # Behavior
-/// 1. Loads tileset texture from assets/sprites/tileset.png
-/// 2. Creates tilemap entity with specified dimensions
-/// 3. Spawns individual tiles at each position
-/// 4. Configures tile size, grid size, and rendering properties
+/// 1. Takes the tileset and its tile size from the `SpriteType::Tileset`
+///    sheet, else loads assets/sprites/tileset.png with `DEFAULT_TILE_SIZE`
+/// 2. Lays out the room's tiles (walls around the edge, floor inside)
+/// 3. Spawns the tilemap and its tiles with `spawn_tilemap`
 ///
 /// From tasks.md T033: Tilemap rendering with bevy_ecs_tilemap 0.16.0
 pub fn setup_tilemap(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
     game_state: Res<GameState>,
+    handles: Option<Res<AssetHandles>>,
 ) {
-    // Load tileset texture
-    let texture_handle: Handle<Image> = asset_server.load("sprites/tileset.png");
+    // Tileset texture and the size of one tile in it
+    let (texture_handle, tile_size) = match handles
+        .as_ref()
+        .and_then(|handles| handles.atlases.get(&SpriteType::Tileset))
+    {
+        Some(sheet) => (
+            sheet.image.clone(),
+            TilemapTileSize::from(sheet.frame_size.as_vec2()),
+        ),
+        None => (asset_server.load("sprites/tileset.png"), DEFAULT_TILE_SIZE),
+    };
 
     // Define room dimensions (can be configured per room later)
-    let map_size = TilemapSize { x: 20, y: 15 }; // 20x15 tiles for standard room
+    // In a full implementation, this would read from level data
+    // (placeholder layout: walls around the edge, floor inside)
+    let (width, height) = (20, 15); // 20x15 tiles for standard room
+    let tiles: Vec<Vec<u32>> = (0..height)
+        .map(|row| {
+            (0..width)
+                .map(|column| {
+                    if column == 0 || column == width - 1 || row == 0 || row == height - 1 {
+                        WALL_TILE
+                    } else {
+                        FLOOR_TILE
+                    }
+                })
+                .collect()
+        })
+        .collect();
+
+    spawn_tilemap(&mut commands, &tiles, texture_handle, tile_size);
+
+    info!("Tilemap created for room {}", game_state.current_room);
+}
+
+/// Spawns a tilemap entity and one tile entity per cell of `tiles`
+///
+/// `tiles` holds tileset indices by row, with row 0 at the top like level
+/// files; rows shorter than the longest leave their remaining cells empty.
+/// The map is centered on the origin on the tiles render layer.
+///
+/// # Returns
+/// The tilemap entity
+pub fn spawn_tilemap(
+    commands: &mut Commands,
+    tiles: &[Vec<u32>],
+    texture: Handle<Image>,
+    tile_size: TilemapTileSize,
+) -> Entity {
+    let map_size = TilemapSize {
+        x: tiles.iter().map(Vec::len).max().unwrap_or(0) as u32,
+        y: tiles.len() as u32,
+    };
 
     // Create tilemap entity
     let tilemap_entity = commands.spawn_empty().id();
@@ -35,25 +98,16 @@ pub fn setup_tilemap(
     // Create tile storage for tracking individual tiles
     let mut tile_storage = TileStorage::empty(map_size);
 
-    // Spawn tiles for the room
-    // In a full implementation, this would read from level data
-    for x in 0..map_size.x {
-        for y in 0..map_size.y {
-            let tile_pos = TilePos { x, y };
-
-            // Determine tile texture index based on position
-            // Floor tiles = 0, Wall tiles = 1 (placeholder logic)
-            let texture_index = if x == 0 || x == map_size.x - 1 || y == 0 || y == map_size.y - 1 {
-                TileTextureIndex(1) // Walls
-            } else {
-                TileTextureIndex(0) // Floor
-            };
-
+    for (row, indices) in tiles.iter().enumerate() {
+        // Tile positions count up from the bottom
+        let y = map_size.y - 1 - row as u32;
+        for (x, index) in indices.iter().enumerate() {
+            let tile_pos = TilePos { x: x as u32, y };
             let tile_entity = commands
                 .spawn(TileBundle {
                     position: tile_pos,
                     tilemap_id: TilemapId(tilemap_entity),
-                    texture_index,
+                    texture_index: TileTextureIndex(*index),
                     ..Default::default()
                 })
                 .id();
@@ -71,17 +125,17 @@ pub fn setup_tilemap(
         map_type,
         size: map_size,
         storage: tile_storage,
-        texture: TilemapTexture::Single(texture_handle),
-        tile_size: TilemapTileSize { x: 32.0, y: 32.0 },
+        texture: TilemapTexture::Single(texture),
+        tile_size,
         transform: Transform::from_xyz(
             -(map_size.x as f32 * 32.0) / 2.0,
             -(map_size.y as f32 * 32.0) / 2.0,
-            0.0,
+            RenderLayer::Tiles.z(),
         ),
         ..Default::default()
     });
 
-    info!("Tilemap created for room {}", game_state.current_room);
+    tilemap_entity
 }
 
 /// Helper function to load room tilemap data from level files
@@ -116,6 +170,35 @@ mod tests {
     // Integration tests for setup_tilemap require full Bevy asset system initialization
     // including Image asset type. These are tested via full game integration tests.
 
+    #[test]
+    fn spawn_tilemap_stores_rows_from_the_top() {
+        let mut world = World::new();
+        let mut queue = bevy::ecs::world::CommandQueue::default();
+        let mut commands = Commands::new(&mut queue, &world);
+        let tilemap = spawn_tilemap(
+            &mut commands,
+            &[vec![WALL_TILE, WALL_TILE, WALL_TILE], vec![FLOOR_TILE]],
+            Handle::default(),
+            DEFAULT_TILE_SIZE,
+        );
+        queue.apply(&mut world);
+
+        let storage = world.get::<TileStorage>(tilemap).unwrap();
+        assert_eq!(storage.size, TilemapSize { x: 3, y: 2 });
+        assert!(storage.get(&TilePos { x: 1, y: 0 }).is_none());
+
+        let top_right = storage.get(&TilePos { x: 2, y: 1 }).unwrap();
+        let bottom_left = storage.get(&TilePos { x: 0, y: 0 }).unwrap();
+        assert_eq!(
+            world.get::<TileTextureIndex>(top_right).unwrap().0,
+            WALL_TILE
+        );
+        assert_eq!(
+            world.get::<TileTextureIndex>(bottom_left).unwrap().0,
+            FLOOR_TILE
+        );
+    }
+
     #[test]
     fn load_room_data_returns_grid() {
         let data = load_room_tilemap_data(1);
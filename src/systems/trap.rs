@@ -1,7 +1,53 @@
-use crate::components::player::{Health, Player};
-use crate::components::trap::TrapState;
+use crate::audio::sound_bank::{PlaySoundAtEvent, PlaySoundEvent, SoundCue};
+use crate::components::inventory::{Inventory, Item};
+use crate::components::player::{Health, HitPoints, Invincibility, Player};
+use crate::components::trap::{
+    ChandelierFall, CrumblingFloor, GasExposure, InstantDeath, PoisonGas, ResetTimer,
+    SwingingBlade, Trap, TrapDamage, TrapState,
+};
+use crate::resources::game_rules::GameRules;
+use crate::resources::game_state::{GameMode, GameState};
+use crate::resources::map_state::DeathCause;
+use crate::systems::game_set::GameSet;
+use crate::systems::respawn::{DeathTimer, respawn_system};
 use bevy::prelude::*;
 
+/// Plugin for traps and the deaths they cause
+///
+/// Registers the trap events and `TrapResetPolicy`, and runs in
+/// `GameSet::Gameplay`: blades swing before triggered traps are resolved,
+/// spent traps reset afterwards, and delayed hazards (crumbling floors,
+/// falling chandeliers, poison gas) run alongside. Traps re-arm after
+/// `respawn_system` brings the player back.
+pub struct TrapPlugin;
+
+impl Plugin for TrapPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<GameState>()
+            .init_resource::<TrapResetPolicy>()
+            .add_event::<TrapTriggeredEvent>()
+            .add_event::<PlayerDeathEvent>()
+            .add_event::<PlaySoundEvent>()
+            .add_event::<PlaySoundAtEvent>()
+            .add_systems(
+                Update,
+                (
+                    (
+                        swinging_blade_system,
+                        trap_activation_system,
+                        trap_reset_system,
+                    )
+                        .chain(),
+                    crumbling_floor_system,
+                    chandelier_fall_system,
+                    poison_gas_system,
+                    trap_respawn_reset_system.after(respawn_system),
+                )
+                    .in_set(GameSet::Gameplay),
+            );
+    }
+}
+
 /// Event emitted when a trap is triggered by a player.
 ///
 /// This event causes the trap to transition to `TrapState::Triggered`
@@ -47,6 +93,8 @@ pub struct TrapTriggeredEvent {
 pub struct PlayerDeathEvent {
     /// The entity of the player who died
     pub player: Entity,
+    /// What killed the player (used for death statistics)
+    pub cause: DeathCause,
 }
 
 /// System that processes trap activation events and kills players.
@@ -56,9 +104,16 @@ pub struct PlayerDeathEvent {
 ///
 /// # Behavior
 /// For each `TrapTriggeredEvent`:
-/// 1. Sets the trap's state to `TrapState::Triggered`
-/// 2. Sets the player's health to `Health::Dead`
-/// 3. Emits a `PlayerDeathEvent` for downstream systems
+/// 1. Skips the event if the trap is `Triggered` or `Resetting` (not armed),
+///    a swinging blade outside its lethal window, or a delayed hazard
+///    (`CrumblingFloor`, `PoisonGas`, `ChandelierFall`) handled by its own
+///    system
+/// 2. Skips `TrapDamage` traps while the player has `Invincibility`
+/// 3. Sets the trap's state to `TrapState::Triggered`
+/// 4. For `TrapDamage` traps (without `InstantDeath`), subtracts hit points
+///    and grants `Invincibility` if the player survives
+/// 5. Otherwise, or if hit points run out, sets the player's health to
+///    `Health::Dead` and emits a `PlayerDeathEvent` for downstream systems
 ///
 /// # Error Handling
 /// The system gracefully handles missing entities:
@@ -90,28 +145,494 @@ pub struct PlayerDeathEvent {
 ///         .run();
 /// }
 /// ```
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
 pub fn trap_activation_system(
     mut events: EventReader<TrapTriggeredEvent>,
     mut trap_query: Query<&mut TrapState>,
-    mut player_query: Query<&mut Health, With<Player>>,
+    hazard_query: Query<(), Or<(With<CrumblingFloor>, With<PoisonGas>, With<ChandelierFall>)>>,
+    blade_query: Query<&SwingingBlade>,
+    damage_query: Query<(Option<&Trap>, Option<&TrapDamage>, Has<InstantDeath>)>,
+    mut player_query: Query<
+        (&mut Health, Option<&mut HitPoints>, Has<Invincibility>),
+        With<Player>,
+    >,
     mut death_events: EventWriter<PlayerDeathEvent>,
+    mut commands: Commands,
 ) {
     for event in events.read() {
+        // Delayed hazards are resolved by their own systems
+        if hazard_query.contains(event.trap) {
+            continue;
+        }
+
+        // Blades only cut while sweeping through the lethal arc
+        if let Ok(blade) = blade_query.get(event.trap)
+            && !blade.is_lethal()
+        {
+            continue;
+        }
+
+        // Weaker traps deal partial damage unless marked InstantDeath
+        let partial_damage = damage_query
+            .get(event.trap)
+            .ok()
+            .and_then(|(_, damage, instant_death)| damage.filter(|_| !instant_death))
+            .map(|damage| damage.0);
+
+        // Invincibility frames protect against partial damage only
+        if partial_damage.is_some()
+            && player_query
+                .get(event.player)
+                .is_ok_and(|(_, _, invincible)| invincible)
+        {
+            continue;
+        }
+
         // Set trap to triggered
         if let Ok(mut trap_state) = trap_query.get_mut(event.trap) {
+            // Spent or resetting traps are harmless until re-armed
+            if *trap_state != TrapState::Armed {
+                continue;
+            }
             *trap_state = TrapState::Triggered;
         }
 
-        // Kill player
-        if let Ok(mut health) = player_query.get_mut(event.player) {
-            *health = Health::Dead;
-            death_events.write(PlayerDeathEvent {
-                player: event.player,
+        // Damage or kill player
+        if let Ok((mut health, hit_points, _)) = player_query.get_mut(event.player) {
+            let killed = match (partial_damage, hit_points) {
+                (Some(amount), Some(mut hit_points)) => hit_points.apply_damage(amount),
+                (_, hit_points) => {
+                    if let Some(mut hit_points) = hit_points {
+                        hit_points.current = 0.0;
+                    }
+                    true
+                }
+            };
+
+            if killed {
+                *health = Health::Dead;
+                let cause = damage_query
+                    .get(event.trap)
+                    .ok()
+                    .and_then(|(trap, _, _)| trap)
+                    .map_or(DeathCause::Unknown, DeathCause::from);
+                death_events.write(PlayerDeathEvent {
+                    player: event.player,
+                    cause,
+                });
+            } else {
+                commands
+                    .entity(event.player)
+                    .insert(Invincibility::default());
+            }
+        }
+    }
+}
+
+/// Resource controlling how traps are reset when the player respawns
+///
+/// When `reset_on_respawn` is true, every trap in the room (including spent
+/// one-shot traps) returns to `TrapState::Armed` as soon as the player
+/// respawns, so each attempt at a room starts from the same layout.
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct TrapResetPolicy {
+    /// Re-arm all room traps when the player respawns
+    pub reset_on_respawn: bool,
+}
+
+impl Default for TrapResetPolicy {
+    fn default() -> Self {
+        Self {
+            reset_on_respawn: true,
+        }
+    }
+}
+
+/// System that re-arms triggered traps over time
+///
+/// # Behavior
+/// For each trap with a `ResetTimer`:
+/// 1. `Triggered` re-armable traps move to `Resetting` and restart the timer
+/// 2. `Resetting` traps tick the timer and return to `Armed` when it finishes,
+///    the timer lasting `Difficulty::trap_rearm_multiplier` times as long
+/// 3. One-shot traps (see `Trap::is_one_shot`) stay `Triggered`
+///
+/// # System Dependencies
+/// - **Upstream**: `trap_activation_system` sets traps to `Triggered`
+/// - **Components**: Reads `Trap`, writes `TrapState` and `ResetTimer`
+/// - **Resources**: Only runs while `GameState.game_mode` is `Playing`;
+///   reads `GameRules` when present
+pub fn trap_reset_system(
+    time: Res<Time>,
+    game_state: Res<GameState>,
+    rules: Option<Res<GameRules>>,
+    mut trap_query: Query<(&Trap, &mut TrapState, &mut ResetTimer)>,
+) {
+    if game_state.game_mode != GameMode::Playing {
+        return;
+    }
+    let rearm = rules.map_or(1.0, |rules| rules.difficulty.trap_rearm_multiplier());
+    let delta = time.delta().div_f32(rearm);
+
+    for (trap, mut state, mut timer) in &mut trap_query {
+        match *state {
+            TrapState::Triggered if !trap.is_one_shot() => {
+                timer.0.reset();
+                *state = TrapState::Resetting;
+            }
+            TrapState::Resetting => {
+                timer.0.tick(delta);
+                if timer.0.finished() {
+                    *state = TrapState::Armed;
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// System that resets room traps when the player respawns
+///
+/// Detects respawns by watching for `DeathTimer` being removed from the
+/// player and, if `TrapResetPolicy::reset_on_respawn` is set, re-arms every
+/// trap and restarts any reset timers.
+///
+/// # System Dependencies
+/// - **Upstream**: `respawn_system` removes `DeathTimer` on respawn
+/// - **Resources**: Reads `TrapResetPolicy`
+/// - **Components**: Writes `TrapState` and `ResetTimer` on all traps
+pub fn trap_respawn_reset_system(
+    policy: Res<TrapResetPolicy>,
+    mut respawned: RemovedComponents<DeathTimer>,
+    player_query: Query<(), With<Player>>,
+    mut trap_query: Query<(&mut TrapState, Option<&mut ResetTimer>), With<Trap>>,
+) {
+    let player_respawned = respawned
+        .read()
+        .any(|entity| player_query.get(entity).is_ok());
+
+    if !player_respawned || !policy.reset_on_respawn {
+        return;
+    }
+
+    for (mut state, timer) in &mut trap_query {
+        *state = TrapState::Armed;
+        if let Some(mut timer) = timer {
+            timer.0.reset();
+        }
+    }
+}
+
+/// System that advances swinging blade sweep cycles
+///
+/// Plays `SoundCue::BladeSwoosh` from the blade each time it enters its
+/// lethal window so players can time their crossing by ear as well as by sight.
+///
+/// # System Dependencies
+/// - **Components**: Writes `SwingingBlade`
+/// - **Downstream**: `trap_activation_system` checks `SwingingBlade::is_lethal`
+pub fn swinging_blade_system(
+    time: Res<Time>,
+    game_state: Res<GameState>,
+    mut blade_query: Query<(Entity, &mut SwingingBlade)>,
+    mut sound_events: EventWriter<PlaySoundAtEvent>,
+) {
+    if game_state.game_mode != GameMode::Playing {
+        return;
+    }
+
+    for (entity, mut blade) in &mut blade_query {
+        let was_lethal = blade.is_lethal();
+        blade.cycle.tick(time.delta());
+        if blade.is_lethal() && !was_lethal {
+            sound_events.write(PlaySoundAtEvent {
+                cue: SoundCue::BladeSwoosh,
+                emitter: entity,
             });
         }
     }
 }
 
+/// System that crumbles floor tiles the player stands on and restores them later
+///
+/// # Behavior
+/// For each `CrumblingFloor`:
+/// 1. **Armed**: while a living player stands on it (`TrapTriggeredEvent`),
+///    ticks the crumble timer; stepping off resets it
+/// 2. **Collapse**: when the crumble timer finishes, the floor becomes
+///    `Triggered`, players on it fall (`Health::Dead`, `PlayerDeathEvent`)
+///    and `SoundCue::FloorCrumble` plays from the floor
+/// 3. **Triggered**: ticks the respawn timer and returns to `Armed` when done
+///
+/// # System Dependencies
+/// - **Upstream**: `collision_detection_system` emits `TrapTriggeredEvent` every
+///   frame the player overlaps the floor
+/// - **Downstream**: `respawn_system` consumes `PlayerDeathEvent`
+#[allow(clippy::too_many_arguments)]
+pub fn crumbling_floor_system(
+    time: Res<Time>,
+    game_state: Res<GameState>,
+    mut trap_events: EventReader<TrapTriggeredEvent>,
+    mut floor_query: Query<(Entity, &mut CrumblingFloor, &mut TrapState)>,
+    mut player_query: Query<&mut Health, With<Player>>,
+    mut death_events: EventWriter<PlayerDeathEvent>,
+    mut sound_events: EventWriter<PlaySoundAtEvent>,
+) {
+    if game_state.game_mode != GameMode::Playing {
+        return;
+    }
+
+    // Floors with a living player on them this frame
+    let mut standing: Vec<(Entity, Entity)> = Vec::new();
+    for event in trap_events.read() {
+        let alive = player_query
+            .get(event.player)
+            .is_ok_and(|health| *health == Health::Alive);
+        if alive && floor_query.contains(event.trap) {
+            standing.push((event.trap, event.player));
+        }
+    }
+
+    for (entity, mut floor, mut state) in &mut floor_query {
+        match *state {
+            TrapState::Armed => {
+                let players: Vec<Entity> = standing
+                    .iter()
+                    .filter(|(floor_entity, _)| *floor_entity == entity)
+                    .map(|(_, player)| *player)
+                    .collect();
+
+                if players.is_empty() {
+                    floor.crumble_timer.reset();
+                    continue;
+                }
+
+                floor.crumble_timer.tick(time.delta());
+                if floor.crumble_timer.finished() {
+                    *state = TrapState::Triggered;
+                    floor.respawn_timer.reset();
+                    sound_events.write(PlaySoundAtEvent {
+                        cue: SoundCue::FloorCrumble,
+                        emitter: entity,
+                    });
+
+                    for player in players {
+                        if let Ok(mut health) = player_query.get_mut(player) {
+                            *health = Health::Dead;
+                            death_events.write(PlayerDeathEvent {
+                                player,
+                                cause: DeathCause::CollapsingFloor,
+                            });
+                        }
+                    }
+                }
+            }
+            TrapState::Triggered | TrapState::Resetting => {
+                floor.respawn_timer.tick(time.delta());
+                if floor.respawn_timer.finished() {
+                    *state = TrapState::Armed;
+                    floor.crumble_timer.reset();
+                }
+            }
+        }
+    }
+}
+
+/// System that drops triggered chandeliers and crushes players where they land
+///
+/// # Behavior
+/// For each `ChandelierFall`:
+/// 1. **Armed**: a living player under it (`TrapTriggeredEvent`) releases
+///    it; the trap becomes `Triggered` and `SoundCue::TrapTriggered` plays
+/// 2. **Falling**: advances the fall; on landing `SoundCue::ChandelierCrash`
+///    plays and players under it that frame die
+///    (`DeathCause::FallingChandelier`)
+/// 3. A fallen chandelier re-armed by `trap_respawn_reset_system` is hung
+///    back up
+///
+/// # System Dependencies
+/// - **Upstream**: `collision_detection_system` emits `TrapTriggeredEvent` every
+///   frame the player overlaps the landing spot
+/// - **Components**: Writes `ChandelierFall`, `TrapState` and `Health`
+/// - **Downstream**: `trap_visual_system` draws the fall; `respawn_system`
+///   consumes `PlayerDeathEvent`
+#[allow(clippy::too_many_arguments)]
+pub fn chandelier_fall_system(
+    time: Res<Time>,
+    game_state: Res<GameState>,
+    mut trap_events: EventReader<TrapTriggeredEvent>,
+    mut chandelier_query: Query<(Entity, &mut ChandelierFall, &mut TrapState)>,
+    mut player_query: Query<&mut Health, With<Player>>,
+    mut death_events: EventWriter<PlayerDeathEvent>,
+    mut sound_events: EventWriter<PlaySoundAtEvent>,
+) {
+    if game_state.game_mode != GameMode::Playing {
+        return;
+    }
+
+    // Chandeliers with a living player under them this frame
+    let mut underneath: Vec<(Entity, Entity)> = Vec::new();
+    for event in trap_events.read() {
+        let alive = player_query
+            .get(event.player)
+            .is_ok_and(|health| *health == Health::Alive);
+        if alive && chandelier_query.contains(event.trap) {
+            underneath.push((event.trap, event.player));
+        }
+    }
+
+    for (entity, mut chandelier, mut state) in &mut chandelier_query {
+        let players: Vec<Entity> = underneath
+            .iter()
+            .filter(|(chandelier_entity, _)| *chandelier_entity == entity)
+            .map(|(_, player)| *player)
+            .collect();
+
+        if *state == TrapState::Armed {
+            if chandelier.falling.is_some() || chandelier.landed {
+                chandelier.rehang();
+            }
+            if players.is_empty() {
+                continue;
+            }
+            *state = TrapState::Triggered;
+            chandelier.falling = Some(0.0);
+            sound_events.write(PlaySoundAtEvent {
+                cue: SoundCue::TrapTriggered,
+                emitter: entity,
+            });
+            continue;
+        }
+
+        let Some(elapsed) = chandelier.falling.filter(|_| !chandelier.landed) else {
+            continue;
+        };
+        let elapsed = elapsed + time.delta_secs();
+        chandelier.falling = Some(elapsed);
+        if elapsed < chandelier.fall_duration() {
+            continue;
+        }
+
+        chandelier.landed = true;
+        sound_events.write(PlaySoundAtEvent {
+            cue: SoundCue::ChandelierCrash,
+            emitter: entity,
+        });
+        for player in players {
+            if let Ok(mut health) = player_query.get_mut(player) {
+                *health = Health::Dead;
+                death_events.write(PlayerDeathEvent {
+                    player,
+                    cause: DeathCause::FallingChandelier,
+                });
+            }
+        }
+    }
+}
+
+/// System that applies gradual poison gas exposure
+///
+/// # Behavior
+/// 1. Players inside a `PoisonGas` zone (`TrapTriggeredEvent`) gain exposure
+///    each second unless their inventory holds the zone's protection tool
+/// 2. Exposure reaching the zone's tolerance kills the player
+/// 3. Outside gas, exposure decays at the same rate and is removed at zero
+/// 4. `SoundCue::GasHiss` plays when a player starts breathing gas
+///
+/// # System Dependencies
+/// - **Upstream**: `collision_detection_system` emits `TrapTriggeredEvent`
+/// - **Components**: Reads `Inventory`, writes `Health` and `GasExposure`
+/// - **Downstream**: `respawn_system` consumes `PlayerDeathEvent`
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn poison_gas_system(
+    time: Res<Time>,
+    game_state: Res<GameState>,
+    mut commands: Commands,
+    mut trap_events: EventReader<TrapTriggeredEvent>,
+    gas_query: Query<&PoisonGas>,
+    mut player_query: Query<
+        (
+            Entity,
+            &mut Health,
+            Option<&Inventory>,
+            Option<&mut GasExposure>,
+        ),
+        With<Player>,
+    >,
+    mut death_events: EventWriter<PlayerDeathEvent>,
+    mut sound_events: EventWriter<PlaySoundEvent>,
+) {
+    if game_state.game_mode != GameMode::Playing {
+        return;
+    }
+
+    // Lowest tolerance of any unprotected gas zone each player is standing in
+    let mut breathing: Vec<(Entity, f32)> = Vec::new();
+    for event in trap_events.read() {
+        let Ok(gas) = gas_query.get(event.trap) else {
+            continue;
+        };
+        let Ok((_, _, inventory, _)) = player_query.get(event.player) else {
+            continue;
+        };
+
+        let protected = inventory.is_some_and(|inventory| {
+            inventory
+                .items
+                .iter()
+                .any(|item| matches!(item, Item::Tool(tool) if *tool == gas.protection))
+        });
+        if protected {
+            continue;
+        }
+
+        match breathing
+            .iter_mut()
+            .find(|(player, _)| *player == event.player)
+        {
+            Some((_, tolerance)) => *tolerance = tolerance.min(gas.tolerance),
+            None => breathing.push((event.player, gas.tolerance)),
+        }
+    }
+
+    let delta = time.delta_secs();
+    for (entity, mut health, _, exposure) in &mut player_query {
+        let in_gas = breathing.iter().find(|(player, _)| *player == entity);
+
+        match (in_gas, exposure) {
+            (Some(&(_, tolerance)), Some(mut exposure)) => {
+                if *health == Health::Dead {
+                    continue;
+                }
+                exposure.0 += delta;
+                if exposure.0 >= tolerance {
+                    *health = Health::Dead;
+                    death_events.write(PlayerDeathEvent {
+                        player: entity,
+                        cause: DeathCause::PoisonGas,
+                    });
+                    commands.entity(entity).remove::<GasExposure>();
+                }
+            }
+            (Some(_), None) => {
+                if *health == Health::Alive {
+                    commands.entity(entity).insert(GasExposure(delta));
+                    sound_events.write(PlaySoundEvent {
+                        cue: SoundCue::GasHiss,
+                    });
+                }
+            }
+            (None, Some(mut exposure)) => {
+                exposure.0 -= delta;
+                if exposure.0 <= 0.0 {
+                    commands.entity(entity).remove::<GasExposure>();
+                }
+            }
+            (None, None) => {}
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -120,10 +641,7 @@ mod tests {
     #[test]
     fn trap_activation_system_compiles() {
         let mut app = App::new();
-        app.add_plugins(MinimalPlugins);
-        app.add_event::<TrapTriggeredEvent>();
-        app.add_event::<PlayerDeathEvent>();
-        app.add_systems(Update, trap_activation_system);
+        app.add_plugins((MinimalPlugins, TrapPlugin));
 
         // System compiles and can be added to app
         assert!(true);
@@ -132,10 +650,7 @@ mod tests {
     #[test]
     fn trap_triggered_event_kills_player() {
         let mut app = App::new();
-        app.add_plugins(MinimalPlugins);
-        app.add_event::<TrapTriggeredEvent>();
-        app.add_event::<PlayerDeathEvent>();
-        app.add_systems(Update, trap_activation_system);
+        app.add_plugins((MinimalPlugins, TrapPlugin));
 
         // Spawn player
         let player = app
@@ -181,10 +696,7 @@ mod tests {
     #[test]
     fn trap_activation_sends_death_event() {
         let mut app = App::new();
-        app.add_plugins(MinimalPlugins);
-        app.add_event::<TrapTriggeredEvent>();
-        app.add_event::<PlayerDeathEvent>();
-        app.add_systems(Update, trap_activation_system);
+        app.add_plugins((MinimalPlugins, TrapPlugin));
 
         // Spawn player and trap
         let player = app
@@ -221,10 +733,7 @@ mod tests {
     #[test]
     fn trap_activation_handles_multiple_traps() {
         let mut app = App::new();
-        app.add_plugins(MinimalPlugins);
-        app.add_event::<TrapTriggeredEvent>();
-        app.add_event::<PlayerDeathEvent>();
-        app.add_systems(Update, trap_activation_system);
+        app.add_plugins((MinimalPlugins, TrapPlugin));
 
         // Spawn player
         let player = app
@@ -276,10 +785,7 @@ mod tests {
     #[test]
     fn trap_activation_graceful_on_invalid_entities() {
         let mut app = App::new();
-        app.add_plugins(MinimalPlugins);
-        app.add_event::<TrapTriggeredEvent>();
-        app.add_event::<PlayerDeathEvent>();
-        app.add_systems(Update, trap_activation_system);
+        app.add_plugins((MinimalPlugins, TrapPlugin));
 
         // Create entities that don't exist
         let fake_trap = Entity::from_raw(999);
@@ -301,10 +807,7 @@ mod tests {
     #[test]
     fn multiple_trap_events_processed_in_single_update() {
         let mut app = App::new();
-        app.add_plugins(MinimalPlugins);
-        app.add_event::<TrapTriggeredEvent>();
-        app.add_event::<PlayerDeathEvent>();
-        app.add_systems(Update, trap_activation_system);
+        app.add_plugins((MinimalPlugins, TrapPlugin));
 
         // Spawn player and traps
         let player = app
@@ -359,10 +862,7 @@ mod tests {
     #[test]
     fn trap_activation_only_affects_specified_player() {
         let mut app = App::new();
-        app.add_plugins(MinimalPlugins);
-        app.add_event::<TrapTriggeredEvent>();
-        app.add_event::<PlayerDeathEvent>();
-        app.add_systems(Update, trap_activation_system);
+        app.add_plugins((MinimalPlugins, TrapPlugin));
 
         // Spawn two players (hypothetically, though game has one)
         let player1 = app
@@ -398,4 +898,542 @@ mod tests {
         assert_eq!(*app.world().get::<Health>(player1).unwrap(), Health::Dead);
         assert_eq!(*app.world().get::<Health>(player2).unwrap(), Health::Alive);
     }
+
+    fn playing_state() -> GameState {
+        GameState {
+            current_room: 0,
+            player_spawn_point: Vec2::ZERO,
+            completion_time: std::time::Duration::ZERO,
+            collected_secrets: std::collections::HashSet::new(),
+            game_mode: GameMode::Playing,
+            deaths: 0,
+        }
+    }
+
+    #[test]
+    fn resetting_trap_does_not_kill_player() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, TrapPlugin));
+
+        let player = app
+            .world_mut()
+            .spawn((Player, Health::Alive, Transform::default()))
+            .id();
+
+        let trap = app
+            .world_mut()
+            .spawn((
+                Trap::Spikes,
+                TrapState::Resetting,
+                TrapTrigger::PressurePlate,
+            ))
+            .id();
+
+        app.world_mut()
+            .send_event(TrapTriggeredEvent { trap, player });
+        app.update();
+
+        assert_eq!(*app.world().get::<Health>(player).unwrap(), Health::Alive);
+        assert_eq!(
+            *app.world().get::<TrapState>(trap).unwrap(),
+            TrapState::Resetting
+        );
+    }
+
+    #[test]
+    fn rearmable_trap_rearms_after_reset_timer() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.insert_resource(playing_state());
+        app.add_systems(Update, trap_reset_system);
+
+        let trap = app
+            .world_mut()
+            .spawn((
+                Trap::Spikes,
+                TrapState::Triggered,
+                TrapTrigger::PressurePlate,
+                ResetTimer::from_seconds(0.0),
+            ))
+            .id();
+
+        // First update starts the reset
+        app.update();
+        assert_eq!(
+            *app.world().get::<TrapState>(trap).unwrap(),
+            TrapState::Resetting
+        );
+
+        // Zero-length timer finishes on the next tick
+        app.update();
+        assert_eq!(
+            *app.world().get::<TrapState>(trap).unwrap(),
+            TrapState::Armed
+        );
+    }
+
+    #[test]
+    fn trap_stays_resetting_until_timer_finishes() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.insert_resource(playing_state());
+        app.add_systems(Update, trap_reset_system);
+
+        let trap = app
+            .world_mut()
+            .spawn((
+                Trap::ArrowTrap,
+                TrapState::Triggered,
+                TrapTrigger::Proximity(3.0),
+                ResetTimer::from_seconds(100.0),
+            ))
+            .id();
+
+        for _ in 0..3 {
+            app.update();
+        }
+
+        assert_eq!(
+            *app.world().get::<TrapState>(trap).unwrap(),
+            TrapState::Resetting
+        );
+    }
+
+    #[test]
+    fn one_shot_trap_stays_spent() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.insert_resource(playing_state());
+        app.add_systems(Update, trap_reset_system);
+
+        let trap = app
+            .world_mut()
+            .spawn((
+                Trap::FallingChandelier,
+                TrapState::Triggered,
+                TrapTrigger::Proximity(5.0),
+                ResetTimer::from_seconds(0.0),
+            ))
+            .id();
+
+        for _ in 0..3 {
+            app.update();
+        }
+
+        assert_eq!(
+            *app.world().get::<TrapState>(trap).unwrap(),
+            TrapState::Triggered
+        );
+    }
+
+    #[test]
+    fn respawn_resets_traps_per_policy() {
+        for reset_on_respawn in [true, false] {
+            let mut app = App::new();
+            app.add_plugins(MinimalPlugins);
+            app.add_event::<PlayerDeathEvent>();
+            app.insert_resource(playing_state());
+            app.insert_resource(TrapResetPolicy { reset_on_respawn });
+            app.add_systems(
+                Update,
+                (
+                    crate::systems::respawn::respawn_system,
+                    trap_respawn_reset_system,
+                )
+                    .chain(),
+            );
+
+            let mut death_timer = Timer::from_seconds(1.0, TimerMode::Once);
+            death_timer.tick(std::time::Duration::from_secs(2));
+            app.world_mut().spawn((
+                Player,
+                Health::Dead,
+                Transform::default(),
+                DeathTimer(death_timer),
+            ));
+
+            let trap = app
+                .world_mut()
+                .spawn((
+                    Trap::FallingChandelier,
+                    TrapState::Triggered,
+                    TrapTrigger::Proximity(5.0),
+                ))
+                .id();
+
+            app.update();
+
+            let expected = if reset_on_respawn {
+                TrapState::Armed
+            } else {
+                TrapState::Triggered
+            };
+            assert_eq!(*app.world().get::<TrapState>(trap).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn swinging_blade_only_kills_in_lethal_window() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, TrapPlugin));
+
+        let player = app
+            .world_mut()
+            .spawn((Player, Health::Alive, Transform::default()))
+            .id();
+
+        // Blade at the start of its swing is harmless
+        let trap = app
+            .world_mut()
+            .spawn((
+                Trap::SwingingBlade,
+                TrapState::Armed,
+                SwingingBlade::new(2.0),
+            ))
+            .id();
+
+        app.world_mut()
+            .send_event(TrapTriggeredEvent { trap, player });
+        app.update();
+        assert_eq!(*app.world().get::<Health>(player).unwrap(), Health::Alive);
+
+        // Mid-swing the blade is lethal
+        app.world_mut()
+            .get_mut::<SwingingBlade>(trap)
+            .unwrap()
+            .cycle
+            .tick(std::time::Duration::from_secs_f32(1.0));
+        app.world_mut()
+            .send_event(TrapTriggeredEvent { trap, player });
+        app.update();
+        assert_eq!(*app.world().get::<Health>(player).unwrap(), Health::Dead);
+    }
+
+    fn hazard_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_event::<TrapTriggeredEvent>();
+        app.add_event::<PlayerDeathEvent>();
+        app.add_event::<PlaySoundEvent>();
+        app.add_event::<PlaySoundAtEvent>();
+        app.insert_resource(playing_state());
+        app.add_systems(
+            Update,
+            (
+                trap_activation_system,
+                crumbling_floor_system,
+                chandelier_fall_system,
+                poison_gas_system,
+            ),
+        );
+        app
+    }
+
+    #[test]
+    fn crumbling_floor_collapses_then_restores() {
+        let mut app = hazard_app();
+
+        let player = app
+            .world_mut()
+            .spawn((Player, Health::Alive, Transform::default()))
+            .id();
+        let floor = app
+            .world_mut()
+            .spawn((
+                Trap::CollapsingFloor,
+                TrapState::Armed,
+                CrumblingFloor::new(0.0, 0.0),
+            ))
+            .id();
+
+        // Standing on the floor collapses it (zero crumble delay)
+        app.world_mut().send_event(TrapTriggeredEvent {
+            trap: floor,
+            player,
+        });
+        app.update();
+        assert_eq!(
+            *app.world().get::<TrapState>(floor).unwrap(),
+            TrapState::Triggered
+        );
+        assert_eq!(*app.world().get::<Health>(player).unwrap(), Health::Dead);
+
+        // Zero respawn delay restores it on the next frame
+        app.update();
+        assert_eq!(
+            *app.world().get::<TrapState>(floor).unwrap(),
+            TrapState::Armed
+        );
+    }
+
+    #[test]
+    fn crumbling_floor_holds_briefly() {
+        let mut app = hazard_app();
+
+        let player = app
+            .world_mut()
+            .spawn((Player, Health::Alive, Transform::default()))
+            .id();
+        let floor = app
+            .world_mut()
+            .spawn((
+                Trap::CollapsingFloor,
+                TrapState::Armed,
+                CrumblingFloor::new(100.0, 5.0),
+            ))
+            .id();
+
+        app.world_mut().send_event(TrapTriggeredEvent {
+            trap: floor,
+            player,
+        });
+        app.update();
+
+        assert_eq!(
+            *app.world().get::<TrapState>(floor).unwrap(),
+            TrapState::Armed
+        );
+        assert_eq!(*app.world().get::<Health>(player).unwrap(), Health::Alive);
+    }
+
+    #[test]
+    fn chandelier_crushes_only_players_still_underneath() {
+        let mut app = hazard_app();
+
+        let player = app
+            .world_mut()
+            .spawn((Player, Health::Alive, Transform::default()))
+            .id();
+        // No drop height: lands on the frame after it is released
+        let chandelier = app
+            .world_mut()
+            .spawn((
+                Trap::FallingChandelier,
+                TrapState::Armed,
+                ChandelierFall::new(0.0),
+            ))
+            .id();
+        let trigger = |app: &mut App| {
+            app.world_mut().send_event(TrapTriggeredEvent {
+                trap: chandelier,
+                player,
+            });
+        };
+
+        // Releasing the chandelier doesn't hurt on its own
+        trigger(&mut app);
+        app.update();
+        assert_eq!(
+            *app.world().get::<TrapState>(chandelier).unwrap(),
+            TrapState::Triggered
+        );
+        assert_eq!(*app.world().get::<Health>(player).unwrap(), Health::Alive);
+
+        // Dodged: the player stepped out before it landed
+        app.update();
+        assert!(
+            app.world()
+                .get::<ChandelierFall>(chandelier)
+                .unwrap()
+                .landed
+        );
+        assert_eq!(*app.world().get::<Health>(player).unwrap(), Health::Alive);
+
+        // A room reset hangs it back up; this time the player stays put
+        *app.world_mut().get_mut::<TrapState>(chandelier).unwrap() = TrapState::Armed;
+        trigger(&mut app);
+        app.update();
+        assert_eq!(
+            app.world()
+                .get::<ChandelierFall>(chandelier)
+                .unwrap()
+                .falling,
+            Some(0.0)
+        );
+        trigger(&mut app);
+        app.update();
+        assert_eq!(*app.world().get::<Health>(player).unwrap(), Health::Dead);
+
+        let causes: Vec<DeathCause> = app
+            .world_mut()
+            .resource_mut::<Events<PlayerDeathEvent>>()
+            .drain()
+            .map(|event| event.cause)
+            .collect();
+        assert_eq!(causes, vec![DeathCause::FallingChandelier]);
+    }
+
+    #[test]
+    fn poison_gas_builds_exposure_until_death() {
+        let mut app = hazard_app();
+
+        let player = app
+            .world_mut()
+            .spawn((Player, Health::Alive, Transform::default()))
+            .id();
+        let gas = app
+            .world_mut()
+            .spawn((
+                Trap::PoisonGas,
+                TrapState::Armed,
+                PoisonGas {
+                    tolerance: 0.0,
+                    protection: crate::components::inventory::ToolType::GasMask,
+                },
+            ))
+            .id();
+
+        // First breath starts exposure without killing instantly
+        app.world_mut()
+            .send_event(TrapTriggeredEvent { trap: gas, player });
+        app.update();
+        assert!(app.world().get::<GasExposure>(player).is_some());
+        assert_eq!(*app.world().get::<Health>(player).unwrap(), Health::Alive);
+
+        // Continued exposure past tolerance kills
+        app.world_mut()
+            .send_event(TrapTriggeredEvent { trap: gas, player });
+        app.update();
+        assert_eq!(*app.world().get::<Health>(player).unwrap(), Health::Dead);
+    }
+
+    #[test]
+    fn gas_mask_protects_from_poison_gas() {
+        let mut app = hazard_app();
+
+        let player = app
+            .world_mut()
+            .spawn((
+                Player,
+                Health::Alive,
+                Transform::default(),
+                Inventory {
+                    items: vec![Item::Tool(crate::components::inventory::ToolType::GasMask)],
+                    max_capacity: 10,
+                },
+            ))
+            .id();
+        let gas = app
+            .world_mut()
+            .spawn((
+                Trap::PoisonGas,
+                TrapState::Armed,
+                PoisonGas {
+                    tolerance: 0.0,
+                    protection: crate::components::inventory::ToolType::GasMask,
+                },
+            ))
+            .id();
+
+        for _ in 0..3 {
+            app.world_mut()
+                .send_event(TrapTriggeredEvent { trap: gas, player });
+            app.update();
+        }
+
+        assert!(app.world().get::<GasExposure>(player).is_none());
+        assert_eq!(*app.world().get::<Health>(player).unwrap(), Health::Alive);
+    }
+
+    #[test]
+    fn damage_trap_deals_partial_damage_with_invincibility() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, TrapPlugin));
+
+        let player = app
+            .world_mut()
+            .spawn((Player, Health::Alive, HitPoints::new(100.0)))
+            .id();
+        let trap = app
+            .world_mut()
+            .spawn((Trap::Spikes, TrapState::Armed, TrapDamage(30.0)))
+            .id();
+        let second_trap = app
+            .world_mut()
+            .spawn((Trap::ArrowTrap, TrapState::Armed, TrapDamage(30.0)))
+            .id();
+
+        app.world_mut()
+            .send_event(TrapTriggeredEvent { trap, player });
+        app.update();
+
+        assert_eq!(*app.world().get::<Health>(player).unwrap(), Health::Alive);
+        assert_eq!(app.world().get::<HitPoints>(player).unwrap().current, 70.0);
+        assert!(app.world().get::<Invincibility>(player).is_some());
+
+        // Invincibility frames block the next hit and leave the trap armed
+        app.world_mut().send_event(TrapTriggeredEvent {
+            trap: second_trap,
+            player,
+        });
+        app.update();
+
+        assert_eq!(app.world().get::<HitPoints>(player).unwrap().current, 70.0);
+        assert_eq!(
+            *app.world().get::<TrapState>(second_trap).unwrap(),
+            TrapState::Armed
+        );
+    }
+
+    #[test]
+    fn instant_death_ignores_hit_points_and_invincibility() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, TrapPlugin));
+
+        let player = app
+            .world_mut()
+            .spawn((
+                Player,
+                Health::Alive,
+                HitPoints::new(100.0),
+                Invincibility::default(),
+            ))
+            .id();
+        let trap = app
+            .world_mut()
+            .spawn((
+                Trap::FallingChandelier,
+                TrapState::Armed,
+                TrapDamage(10.0),
+                InstantDeath,
+            ))
+            .id();
+
+        app.world_mut()
+            .send_event(TrapTriggeredEvent { trap, player });
+        app.update();
+
+        assert_eq!(*app.world().get::<Health>(player).unwrap(), Health::Dead);
+        assert!(app.world().get::<HitPoints>(player).unwrap().is_depleted());
+    }
+
+    #[test]
+    fn depleting_hit_points_kills_player() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, TrapPlugin));
+
+        let player = app
+            .world_mut()
+            .spawn((
+                Player,
+                Health::Alive,
+                HitPoints {
+                    current: 20.0,
+                    max: 100.0,
+                },
+            ))
+            .id();
+        let trap = app
+            .world_mut()
+            .spawn((Trap::Spikes, TrapState::Armed, TrapDamage(25.0)))
+            .id();
+
+        app.world_mut()
+            .send_event(TrapTriggeredEvent { trap, player });
+        app.update();
+
+        assert_eq!(*app.world().get::<Health>(player).unwrap(), Health::Dead);
+        let death_events = app.world().resource::<Events<PlayerDeathEvent>>();
+        assert_eq!(death_events.len(), 1);
+    }
 }
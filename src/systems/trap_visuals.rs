@@ -0,0 +1,338 @@
+use bevy::prelude::*;
+use bevy::sprite::Anchor;
+
+use crate::components::room::Collider;
+use crate::components::trap::{ChandelierFall, Trap, TrapState, TrapTrigger};
+use crate::resources::game_clock::GameClock;
+use crate::systems::tween::{Ease, Tween};
+
+/// Height (pixels) of fully extended spike blades
+pub const SPIKE_HEIGHT: f32 = 24.0;
+
+/// Fraction of the spike height showing while retracted
+pub const SPIKE_RETRACTED_SCALE: f32 = 0.15;
+
+/// Seconds spikes take to shoot up / sink back
+pub const SPIKE_EXTEND_SECS: (f32, f32) = (0.06, 0.4);
+
+/// Thickness (pixels) of a pressure plate
+pub const PLATE_THICKNESS: f32 = 6.0;
+
+/// How far (pixels) a pressure plate sinks when stepped on
+pub const PLATE_TRAVEL: f32 = 3.0;
+
+/// Seconds a pressure plate takes to sink / spring back
+pub const PLATE_PRESS_SECS: (f32, f32) = (0.05, 0.25);
+
+/// Size (pixels) of a chandelier
+pub const CHANDELIER_SIZE: Vec2 = Vec2::new(48.0, 32.0);
+
+const SPIKE_COLOR: Color = Color::srgb(0.62, 0.62, 0.66);
+const PLATE_COLOR: Color = Color::srgb(0.36, 0.32, 0.28);
+const CHANDELIER_COLOR: Color = Color::srgb(0.85, 0.68, 0.3);
+
+/// Plugin that draws traps and animates them between their states
+///
+/// Spikes shoot up and sink back, pressure plates sink under the player and
+/// chandeliers fall. The animations follow `TrapState`, so they stay in
+/// step with the trap systems that decide when a trap is dangerous.
+pub struct TrapVisualsPlugin;
+
+impl Plugin for TrapVisualsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<GameClock>().add_systems(
+            Update,
+            (attach_trap_visuals_system, trap_visual_system).chain(),
+        );
+    }
+}
+
+/// Moving part of a trap
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrapPart {
+    /// Spike blades; the tween scales their height
+    Spikes,
+    /// Pressure plate; the tween moves it down
+    Plate,
+    /// Chandelier body; placed by `ChandelierFall::offset`
+    Chandelier,
+}
+
+/// Component for a sprite drawing part of a trap, spawned as its child
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct TrapVisual {
+    /// Which part this sprite draws
+    pub part: TrapPart,
+    /// Whether the part is animating to (or at) its sprung pose
+    pub sprung: bool,
+    /// Animation of the part's scale (spikes) or height (plate)
+    pub tween: Tween,
+}
+
+impl TrapVisual {
+    /// A part at rest with its tween parked on `value`
+    pub fn at_rest(part: TrapPart, value: f32) -> Self {
+        Self {
+            part,
+            sprung: false,
+            tween: Tween::new(value, value, 0.0, Ease::Linear),
+        }
+    }
+
+    /// Starts animating towards the sprung (or rest) pose from where the
+    /// part is now, so a trap re-armed mid-animation doesn't jump
+    pub fn spring(&mut self, sprung: bool, rest: f32) {
+        if self.sprung == sprung {
+            return;
+        }
+        self.sprung = sprung;
+
+        let (target, (press_secs, release_secs), ease) = match self.part {
+            TrapPart::Spikes => (1.0, SPIKE_EXTEND_SECS, Ease::CubicOut),
+            TrapPart::Plate => (rest - PLATE_TRAVEL, PLATE_PRESS_SECS, Ease::QuadOut),
+            TrapPart::Chandelier => return,
+        };
+        let (to, duration) = if sprung {
+            (target, press_secs)
+        } else {
+            (rest, release_secs)
+        };
+        self.tween = Tween::new(self.tween.value(), to, duration, ease);
+    }
+}
+
+/// Resting value of a plate's tween: its height resting on the collider floor
+fn plate_rest(collider: &Collider) -> f32 {
+    collider.min.y + PLATE_THICKNESS / 2.0
+}
+
+/// System that gives newly spawned traps their sprites
+///
+/// # System Dependencies
+/// - **Components**: Reads `Trap`, `TrapTrigger`, `Collider` and
+///   `ChandelierFall` of traps added this frame
+/// - **Downstream**: `trap_visual_system` animates the spawned parts
+///
+/// # Behavior
+/// Pressure-plate traps get a plate across the bottom of their collider,
+/// spikes get blades standing on it and chandeliers get a body hanging at
+/// their drop height. Traps without moving parts are left invisible.
+#[allow(clippy::type_complexity)]
+pub fn attach_trap_visuals_system(
+    mut commands: Commands,
+    query: Query<
+        (
+            Entity,
+            &Trap,
+            Option<&TrapTrigger>,
+            &Collider,
+            Option<&ChandelierFall>,
+        ),
+        Added<Trap>,
+    >,
+) {
+    for (entity, trap, trigger, collider, chandelier) in &query {
+        let width = collider.max.x - collider.min.x;
+        let mut parts = Vec::new();
+
+        if matches!(trigger, Some(TrapTrigger::PressurePlate)) {
+            let rest = plate_rest(collider);
+            parts.push((
+                TrapVisual::at_rest(TrapPart::Plate, rest),
+                Sprite {
+                    color: PLATE_COLOR,
+                    custom_size: Some(Vec2::new(width, PLATE_THICKNESS)),
+                    ..default()
+                },
+                Transform::from_xyz(0.0, rest, 0.1),
+            ));
+        }
+        if matches!(trap, Trap::Spikes) {
+            // Blades grow upwards from the floor, so anchor them at their base
+            parts.push((
+                TrapVisual::at_rest(TrapPart::Spikes, SPIKE_RETRACTED_SCALE),
+                Sprite {
+                    color: SPIKE_COLOR,
+                    custom_size: Some(Vec2::new(width * 0.8, SPIKE_HEIGHT)),
+                    anchor: Anchor::BottomCenter,
+                    ..default()
+                },
+                Transform::from_xyz(0.0, collider.min.y, 0.2).with_scale(Vec3::new(
+                    1.0,
+                    SPIKE_RETRACTED_SCALE,
+                    1.0,
+                )),
+            ));
+        }
+        if let Some(chandelier) = chandelier {
+            let offset = chandelier.offset();
+            parts.push((
+                TrapVisual::at_rest(TrapPart::Chandelier, 0.0),
+                Sprite {
+                    color: CHANDELIER_COLOR,
+                    custom_size: Some(CHANDELIER_SIZE),
+                    ..default()
+                },
+                Transform::from_xyz(offset.x, offset.y, 0.3),
+            ));
+        }
+
+        if parts.is_empty() {
+            continue;
+        }
+        commands
+            .entity(entity)
+            .insert(Visibility::default())
+            .with_children(|parent| {
+                for part in parts {
+                    parent.spawn(part);
+                }
+            });
+    }
+}
+
+/// System that animates trap parts to match their trap's state
+///
+/// # System Dependencies
+/// - **Components**: Reads each trap's `TrapState`, `Collider` and
+///   `ChandelierFall`; writes its parts' `TrapVisual` and `Transform`
+/// - **Resources**: Reads `GameClock` (animations freeze while paused)
+/// - **Upstream**: `trap_activation_system`, `chandelier_fall_system` and
+///   `trap_reset_system` move traps between states
+///
+/// # Behavior
+/// Spikes extend and plates sink while their trap is `Triggered`, and
+/// return to rest as it resets. Chandeliers follow their fall exactly, so
+/// the body reaches the floor on the frame it deals damage.
+pub fn trap_visual_system(
+    clock: Res<GameClock>,
+    trap_query: Query<(&TrapState, &Collider, Option<&ChandelierFall>, &Children)>,
+    mut visual_query: Query<(&mut TrapVisual, &mut Transform)>,
+) {
+    for (state, collider, chandelier, children) in &trap_query {
+        let sprung = *state == TrapState::Triggered;
+
+        for child in children.iter() {
+            let Ok((mut visual, mut transform)) = visual_query.get_mut(child) else {
+                continue;
+            };
+
+            match visual.part {
+                TrapPart::Spikes => {
+                    visual.spring(sprung, SPIKE_RETRACTED_SCALE);
+                    transform.scale.y = visual.tween.tick(clock.delta).value();
+                }
+                TrapPart::Plate => {
+                    visual.spring(sprung, plate_rest(collider));
+                    transform.translation.y = visual.tween.tick(clock.delta).value();
+                }
+                TrapPart::Chandelier => {
+                    if let Some(chandelier) = chandelier {
+                        let offset = chandelier.offset();
+                        transform.translation.x = offset.x;
+                        transform.translation.y = offset.y;
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn visual_app() -> App {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, TrapVisualsPlugin));
+        app.insert_resource(GameClock {
+            delta: 0.5,
+            ..default()
+        });
+        app
+    }
+
+    fn part(app: &mut App, part: TrapPart) -> (TrapVisual, Transform) {
+        let mut query = app.world_mut().query::<(&TrapVisual, &Transform)>();
+        query
+            .iter(app.world())
+            .find(|(visual, _)| visual.part == part)
+            .map(|(visual, transform)| (visual.clone(), *transform))
+            .unwrap()
+    }
+
+    #[test]
+    fn spikes_extend_and_plates_sink_while_triggered() {
+        let mut app = visual_app();
+        let collider = Collider {
+            min: Vec2::splat(-16.0),
+            max: Vec2::splat(16.0),
+        };
+        let rest = plate_rest(&collider);
+        let spikes = app
+            .world_mut()
+            .spawn((
+                Trap::Spikes,
+                TrapTrigger::PressurePlate,
+                TrapState::Armed,
+                collider,
+            ))
+            .id();
+
+        app.update();
+        assert_eq!(
+            part(&mut app, TrapPart::Spikes).1.scale.y,
+            SPIKE_RETRACTED_SCALE
+        );
+        assert_eq!(part(&mut app, TrapPart::Plate).1.translation.y, rest);
+
+        *app.world_mut().get_mut::<TrapState>(spikes).unwrap() = TrapState::Triggered;
+        app.update();
+        assert_eq!(part(&mut app, TrapPart::Spikes).1.scale.y, 1.0);
+        assert_eq!(
+            part(&mut app, TrapPart::Plate).1.translation.y,
+            rest - PLATE_TRAVEL
+        );
+
+        // Re-arming sinks the spikes back slower than they shot up
+        *app.world_mut().get_mut::<TrapState>(spikes).unwrap() = TrapState::Resetting;
+        app.world_mut().resource_mut::<GameClock>().delta = 0.1;
+        app.update();
+        let (visual, transform) = part(&mut app, TrapPart::Spikes);
+        assert!(!visual.sprung);
+        assert!(transform.scale.y > SPIKE_RETRACTED_SCALE && transform.scale.y < 1.0);
+    }
+
+    #[test]
+    fn chandelier_body_follows_its_fall() {
+        let mut app = visual_app();
+        let chandelier = app
+            .world_mut()
+            .spawn((
+                Trap::FallingChandelier,
+                TrapState::Armed,
+                ChandelierFall::new(120.0),
+                Collider {
+                    min: Vec2::splat(-16.0),
+                    max: Vec2::splat(16.0),
+                },
+            ))
+            .id();
+
+        app.update();
+        assert_eq!(part(&mut app, TrapPart::Chandelier).1.translation.y, 120.0);
+
+        app.world_mut()
+            .get_mut::<ChandelierFall>(chandelier)
+            .unwrap()
+            .landed = true;
+        app.update();
+        assert_eq!(
+            part(&mut app, TrapPart::Chandelier)
+                .1
+                .translation
+                .truncate(),
+            Vec2::ZERO
+        );
+    }
+}
@@ -0,0 +1,218 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::components::lighting::{Candle, CandleState};
+use crate::components::player::Player;
+use crate::components::room::Collider;
+
+/// Plugin that fires `GameplayTrigger` events from level-placed trigger zones
+///
+/// Other systems subscribe by reading `GameplayTrigger` and matching its
+/// name, e.g. dialogue scripts with a `ScriptTrigger::Gameplay` trigger.
+pub struct TriggerZonePlugin;
+
+impl Plugin for TriggerZonePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<GameplayTrigger>()
+            .add_systems(Update, trigger_zone_system);
+    }
+}
+
+/// Condition the player must meet for a trigger zone to fire
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TriggerFilter {
+    /// Fires whenever the player walks in
+    #[default]
+    Always,
+    /// Fires only while the candle is lit
+    CandleLit,
+    /// Fires only while the candle is not lit
+    CandleOut,
+}
+
+impl TriggerFilter {
+    /// Whether the player passes the filter given the candle's state
+    pub fn allows(self, candle: Option<CandleState>) -> bool {
+        match self {
+            TriggerFilter::Always => true,
+            TriggerFilter::CandleLit => candle == Some(CandleState::Lit),
+            TriggerFilter::CandleOut => candle != Some(CandleState::Lit),
+        }
+    }
+}
+
+/// Component for an invisible area that fires a named `GameplayTrigger`
+///
+/// The area is the entity's `Collider`, relative to its `Transform`.
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct TriggerZone {
+    /// Name carried by the fired `GameplayTrigger`
+    pub name: String,
+    /// Fire only the first time the player enters
+    pub once: bool,
+    /// Condition the player must meet for the zone to fire
+    pub filter: TriggerFilter,
+    /// Whether the player is inside and passing the filter
+    pub occupied: bool,
+    /// Whether the zone has fired at least once
+    pub fired: bool,
+}
+
+impl TriggerZone {
+    /// A zone named `name` that fires once, whatever the candle's state
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            once: true,
+            filter: TriggerFilter::default(),
+            occupied: false,
+            fired: false,
+        }
+    }
+}
+
+/// Event sent when the player enters a trigger zone
+#[derive(Event, Debug, Clone, PartialEq)]
+pub struct GameplayTrigger {
+    /// Name of the zone that fired
+    pub name: String,
+    /// Zone entity that fired
+    pub zone: Entity,
+}
+
+/// System that fires trigger zones as the player enters them
+///
+/// # System Dependencies
+/// - **Components**: Reads `Player` `Transform`, the `Candle`'s `CandleState`
+///   and each zone's `Transform` and `Collider`; writes `TriggerZone`
+/// - **Downstream**: Emits `GameplayTrigger`
+///
+/// # Behavior
+/// A zone fires when the player's position is inside its collider and
+/// passes its filter, having not done both the frame before. Leaving (or
+/// failing the filter) re-arms it unless it is `once` and has fired. A zone
+/// the candle goes out in, for example, fires a `CandleOut` trigger without
+/// the player moving.
+pub fn trigger_zone_system(
+    player_query: Query<&Transform, With<Player>>,
+    candle_query: Query<&CandleState, With<Candle>>,
+    mut zone_query: Query<(Entity, &mut TriggerZone, &Transform, &Collider)>,
+    mut events: EventWriter<GameplayTrigger>,
+) {
+    let player = player_query
+        .single()
+        .ok()
+        .map(|transform| transform.translation.truncate());
+    let candle = candle_query.iter().next().copied();
+
+    for (entity, mut zone, transform, collider) in &mut zone_query {
+        let offset = player.map(|player| player - transform.translation.truncate());
+        let occupied = zone.filter.allows(candle)
+            && offset.is_some_and(|offset| {
+                offset.cmpge(collider.min).all() && offset.cmple(collider.max).all()
+            });
+        if occupied == zone.occupied {
+            continue;
+        }
+
+        zone.occupied = occupied;
+        if occupied && !(zone.once && zone.fired) {
+            zone.fired = true;
+            events.write(GameplayTrigger {
+                name: zone.name.clone(),
+                zone: entity,
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fired(app: &mut App) -> Vec<String> {
+        app.world_mut()
+            .resource_mut::<Events<GameplayTrigger>>()
+            .drain()
+            .map(|event| event.name)
+            .collect()
+    }
+
+    fn move_player(app: &mut App, player: Entity, x: f32) {
+        app.world_mut()
+            .get_mut::<Transform>(player)
+            .unwrap()
+            .translation
+            .x = x;
+        app.update();
+    }
+
+    fn spawn_zone(app: &mut App, zone: TriggerZone, x: f32) {
+        app.world_mut().spawn((
+            zone,
+            Transform::from_xyz(x, 0.0, 0.0),
+            Collider {
+                min: Vec2::splat(-16.0),
+                max: Vec2::splat(16.0),
+            },
+        ));
+    }
+
+    #[test]
+    fn zones_fire_on_entry_once_or_every_time() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, TriggerZonePlugin));
+        let player = app
+            .world_mut()
+            .spawn((Player, Transform::from_xyz(0.0, 0.0, 0.0)))
+            .id();
+        spawn_zone(&mut app, TriggerZone::new("stairs_scare"), 100.0);
+        spawn_zone(
+            &mut app,
+            TriggerZone {
+                once: false,
+                ..TriggerZone::new("creaky_board")
+            },
+            200.0,
+        );
+
+        app.update();
+        assert!(fired(&mut app).is_empty());
+
+        move_player(&mut app, player, 100.0);
+        assert_eq!(fired(&mut app), vec!["stairs_scare"]);
+        move_player(&mut app, player, 110.0);
+        assert!(fired(&mut app).is_empty());
+
+        for _ in 0..2 {
+            move_player(&mut app, player, 200.0);
+            assert_eq!(fired(&mut app), vec!["creaky_board"]);
+            move_player(&mut app, player, 100.0);
+            assert!(fired(&mut app).is_empty());
+        }
+    }
+
+    #[test]
+    fn filtered_zones_wait_for_the_candle() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, TriggerZonePlugin));
+        app.world_mut()
+            .spawn((Player, Transform::from_xyz(0.0, 0.0, 0.0)));
+        let candle = app.world_mut().spawn((Candle, CandleState::Lit)).id();
+        spawn_zone(
+            &mut app,
+            TriggerZone {
+                filter: TriggerFilter::CandleOut,
+                ..TriggerZone::new("whispers")
+            },
+            0.0,
+        );
+
+        app.update();
+        assert!(fired(&mut app).is_empty());
+
+        *app.world_mut().get_mut::<CandleState>(candle).unwrap() = CandleState::Extinguished;
+        app.update();
+        assert_eq!(fired(&mut app), vec!["whispers"]);
+    }
+}
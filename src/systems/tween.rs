@@ -0,0 +1,261 @@
+use std::f32::consts::PI;
+
+/// Shape of a tween's progress curve
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Ease {
+    /// Constant speed
+    #[default]
+    Linear,
+    /// Starts slow, speeds up
+    QuadIn,
+    /// Starts fast, slows down
+    QuadOut,
+    /// Slow at both ends
+    QuadInOut,
+    /// Starts fast, settles gently (slides and pop-ins)
+    CubicOut,
+    /// Hermite smoothstep, the falloff used by the lighting shader
+    SmoothStep,
+}
+
+impl Ease {
+    /// Maps linear progress `t` (clamped to 0.0-1.0) onto the curve
+    pub fn apply(self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Ease::Linear => t,
+            Ease::QuadIn => t * t,
+            Ease::QuadOut => 1.0 - (1.0 - t) * (1.0 - t),
+            Ease::QuadInOut => {
+                if t < 0.5 {
+                    2.0 * t * t
+                } else {
+                    1.0 - (-2.0 * t + 2.0).powi(2) / 2.0
+                }
+            }
+            Ease::CubicOut => 1.0 - (1.0 - t).powi(3),
+            Ease::SmoothStep => t * t * (3.0 - 2.0 * t),
+        }
+    }
+}
+
+/// Animates a value from `from` to `to` over `duration` seconds
+///
+/// Tweens don't read time themselves: tick them with `GameClock::delta` so
+/// they pause with the game and run at the same speed at any frame rate.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Tween {
+    /// Value before the tween starts
+    pub from: f32,
+    /// Value once the tween finishes
+    pub to: f32,
+    /// Seconds from start to finish (after the delay)
+    pub duration: f32,
+    /// Seconds to wait before starting
+    pub delay: f32,
+    /// Progress curve
+    pub ease: Ease,
+    /// Seconds ticked so far, including the delay
+    pub elapsed: f32,
+}
+
+impl Tween {
+    /// Creates a tween with no delay
+    pub fn new(from: f32, to: f32, duration: f32, ease: Ease) -> Self {
+        Self {
+            from,
+            to,
+            duration,
+            delay: 0.0,
+            ease,
+            elapsed: 0.0,
+        }
+    }
+
+    /// Waits `delay` seconds before starting
+    pub fn with_delay(mut self, delay: f32) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Advances the tween by `delta` seconds
+    pub fn tick(&mut self, delta: f32) -> &mut Self {
+        self.elapsed = (self.elapsed + delta.max(0.0)).min(self.total_duration());
+        self
+    }
+
+    /// Seconds from start to finish, including the delay
+    pub fn total_duration(&self) -> f32 {
+        self.delay + self.duration.max(0.0)
+    }
+
+    /// Eased progress (0.0 during the delay, 1.0 when finished)
+    pub fn progress(&self) -> f32 {
+        if self.duration <= 0.0 {
+            return if self.elapsed >= self.delay { 1.0 } else { 0.0 };
+        }
+        self.ease.apply((self.elapsed - self.delay) / self.duration)
+    }
+
+    /// Current value
+    pub fn value(&self) -> f32 {
+        self.from + (self.to - self.from) * self.progress()
+    }
+
+    /// Returns true once the delay and duration have passed
+    pub fn finished(&self) -> bool {
+        self.elapsed >= self.total_duration()
+    }
+
+    /// Restarts the tween from its delay
+    pub fn reset(&mut self) {
+        self.elapsed = 0.0;
+    }
+}
+
+/// Tweens played one after another
+///
+/// The value of a finished step holds until the next one starts, so chained
+/// steps normally pick up where the previous one ended (e.g. slide in, hold,
+/// slide out).
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TweenSequence {
+    /// Steps in play order
+    pub steps: Vec<Tween>,
+    /// Seconds ticked so far
+    pub elapsed: f32,
+}
+
+impl TweenSequence {
+    /// Creates a sequence starting with `first`
+    pub fn new(first: Tween) -> Self {
+        Self {
+            steps: vec![first],
+            elapsed: 0.0,
+        }
+    }
+
+    /// Appends `step` to play after the current last step
+    pub fn then(mut self, step: Tween) -> Self {
+        self.steps.push(step);
+        self
+    }
+
+    /// Seconds from the first step's start to the last step's end
+    pub fn total_duration(&self) -> f32 {
+        self.steps.iter().map(Tween::total_duration).sum()
+    }
+
+    /// Advances the sequence by `delta` seconds
+    pub fn tick(&mut self, delta: f32) -> &mut Self {
+        self.elapsed = (self.elapsed + delta.max(0.0)).min(self.total_duration());
+        self
+    }
+
+    /// Sets the sequence's position to `elapsed` seconds from its start
+    pub fn seek(&mut self, elapsed: f32) -> &mut Self {
+        self.elapsed = elapsed.clamp(0.0, self.total_duration());
+        self
+    }
+
+    /// Current value (the first step's start value before anything plays)
+    pub fn value(&self) -> f32 {
+        let mut start = 0.0;
+        for (index, step) in self.steps.iter().enumerate() {
+            let end = start + step.total_duration();
+            let last = index + 1 == self.steps.len();
+            if self.elapsed < end || last {
+                let mut step = *step;
+                step.elapsed = (self.elapsed - start).clamp(0.0, step.total_duration());
+                return step.value();
+            }
+            start = end;
+        }
+        0.0
+    }
+
+    /// Returns true once every step has played
+    pub fn finished(&self) -> bool {
+        self.elapsed >= self.total_duration()
+    }
+}
+
+/// Repeating 0.0 → 1.0 → 0.0 pulse with a period of `period` seconds
+///
+/// Follows a cosine wave, so the pulse eases in and out of its peaks.
+/// Feed it `GameClock::elapsed` to make it stop while paused.
+pub fn pulse(elapsed: f32, period: f32) -> f32 {
+    if period <= 0.0 {
+        return 0.0;
+    }
+    (0.5 - 0.5 * (elapsed / period * 2.0 * PI).cos()).clamp(0.0, 1.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn eases_start_at_zero_and_end_at_one() {
+        for ease in [
+            Ease::Linear,
+            Ease::QuadIn,
+            Ease::QuadOut,
+            Ease::QuadInOut,
+            Ease::CubicOut,
+            Ease::SmoothStep,
+        ] {
+            assert_eq!(ease.apply(0.0), 0.0, "{:?}", ease);
+            assert!((ease.apply(1.0) - 1.0).abs() < 1e-6, "{:?}", ease);
+            assert_eq!(ease.apply(2.0), ease.apply(1.0), "{:?} clamps", ease);
+        }
+        assert!(Ease::QuadIn.apply(0.5) < 0.5);
+        assert!(Ease::CubicOut.apply(0.5) > 0.5);
+    }
+
+    #[test]
+    fn delayed_tween_holds_then_reaches_target() {
+        let mut tween = Tween::new(10.0, 20.0, 1.0, Ease::Linear).with_delay(0.5);
+
+        tween.tick(0.25);
+        assert_eq!(tween.value(), 10.0);
+
+        tween.tick(0.75);
+        assert_eq!(tween.value(), 15.0);
+
+        // Ticking in many small steps gives the same result as one big step
+        let mut small_steps = Tween::new(10.0, 20.0, 1.0, Ease::Linear).with_delay(0.5);
+        for _ in 0..4 {
+            small_steps.tick(0.25);
+        }
+        assert_eq!(small_steps.value(), tween.value());
+
+        tween.tick(5.0);
+        assert!(tween.finished());
+        assert_eq!(tween.value(), 20.0);
+    }
+
+    #[test]
+    fn sequence_plays_steps_in_order() {
+        let mut sequence = TweenSequence::new(Tween::new(0.0, 1.0, 1.0, Ease::Linear))
+            .then(Tween::new(1.0, 1.0, 2.0, Ease::Linear))
+            .then(Tween::new(1.0, 0.0, 1.0, Ease::Linear));
+        assert_eq!(sequence.total_duration(), 4.0);
+
+        assert_eq!(sequence.tick(0.5).value(), 0.5);
+        assert_eq!(sequence.tick(1.5).value(), 1.0);
+        assert_eq!(sequence.tick(1.5).value(), 0.5);
+        assert!(!sequence.finished());
+
+        assert_eq!(sequence.tick(10.0).value(), 0.0);
+        assert!(sequence.finished());
+    }
+
+    #[test]
+    fn pulse_cycles_between_zero_and_one() {
+        assert_eq!(pulse(0.0, 2.0), 0.0);
+        assert!((pulse(1.0, 2.0) - 1.0).abs() < 1e-6);
+        assert!(pulse(2.0, 2.0) < 1e-6);
+        assert_eq!(pulse(1.0, 0.0), 0.0);
+    }
+}
@@ -0,0 +1,481 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+use crate::components::inventory::{Hotbar, Inventory, Item};
+use crate::components::lighting::{CandleState, CandleWax, CarriedCandle};
+use crate::components::player::Player;
+use crate::components::room::{DoorState, ExitDoor, RoomId};
+use crate::resources::game_state::{GameMode, GameState};
+use crate::resources::game_stats::GameStats;
+use crate::resources::map_state::MapState;
+use crate::resources::room_state::RoomStateStore;
+use crate::systems::room_transition::{DOOR_TRAVERSE_DISTANCE, DoorSwing, RoomChangedEvent};
+use crate::ui::journal::{NoteContent, collected_pages};
+use crate::ui::toast::ToastEvent;
+
+/// Room every run (including New Game+) starts in
+pub const START_ROOM: RoomId = 0;
+
+/// Extra candle burn per New Game+ cycle (0.25 burns 25% faster each cycle)
+pub const NEW_GAME_PLUS_BURN_STEP: f32 = 0.25;
+
+/// Toast shown when the player tries to leave without a lit candle
+pub const ESCAPE_IN_DARKNESS_MESSAGE: &str =
+    "The night outside is pitch black. Light your candle before you leave.";
+
+/// Plugin that registers the escape from the house and New Game+
+///
+/// Walking through the open `ExitDoor` with a lit candle switches to
+/// `GameMode::Victory` and records the `RunSummary` shown by the ending
+/// screen. `NewGamePlusEvent` starts the next run, in a house remixed by
+/// `remix::RemixPlugin`.
+pub struct VictoryPlugin;
+
+impl Plugin for VictoryPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<RunSummary>()
+            .init_resource::<NewGamePlus>()
+            .add_event::<NewGamePlusEvent>()
+            .add_event::<RoomChangedEvent>()
+            .add_event::<ToastEvent>()
+            .add_systems(Update, (escape_system, new_game_plus_system));
+    }
+}
+
+/// How the story ends, chosen by how much of the diary the player read
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Ending {
+    /// Escaped knowing next to nothing of what happened in the house
+    #[default]
+    Fled,
+    /// Escaped with at least half of the diary pieced together
+    Witness,
+    /// Escaped with every diary page: the whole story
+    Truth,
+}
+
+/// Picks the ending for `pages` collected diary pages out of `total`
+pub fn ending_for(pages: usize, total: usize) -> Ending {
+    if total > 0 && pages >= total {
+        Ending::Truth
+    } else if pages > 0 && pages * 2 >= total {
+        Ending::Witness
+    } else {
+        Ending::Fled
+    }
+}
+
+/// Resource holding the statistics of the finished run
+///
+/// Written by `escape_system` when the player escapes, shown by the
+/// ending screen.
+#[derive(Resource, Debug, Clone, Default, PartialEq)]
+pub struct RunSummary {
+    /// Time the run took
+    pub time: Duration,
+    /// Times the player died
+    pub deaths: u32,
+    /// Secrets found
+    pub secrets: usize,
+    /// Diary pages collected
+    pub diary_pages: usize,
+    /// Diary pages in the game
+    pub total_pages: usize,
+    /// Ending reached
+    pub ending: Ending,
+    /// New Game+ cycle the run was played in (0 for the first run)
+    pub cycle: u32,
+    /// Gameplay statistics of the run
+    pub stats: GameStats,
+}
+
+/// Resource counting completed runs carried into New Game+
+///
+/// Saved with the run, so the cosmetics unlocked along the way carry into
+/// every later cycle.
+#[derive(Resource, Debug, Clone, Default, PartialEq, Eq)]
+pub struct NewGamePlus {
+    /// Runs completed before the current one
+    pub cycle: u32,
+    /// Cosmetics unlocked by the completed runs, in the order first unlocked
+    pub cosmetics: Vec<Cosmetic>,
+}
+
+impl NewGamePlus {
+    /// Unlocks `cosmetic`, returning false if it already was
+    pub fn unlock(&mut self, cosmetic: Cosmetic) -> bool {
+        if self.cosmetics.contains(&cosmetic) {
+            return false;
+        }
+        self.cosmetics.push(cosmetic);
+        true
+    }
+
+    /// Flame the player's candle burns with: the best one unlocked
+    pub fn flame(&self) -> Option<Cosmetic> {
+        self.cosmetics.iter().copied().max()
+    }
+
+    /// Multiplier applied to every candle's `BurnRate` in this cycle
+    ///
+    /// Derived from the saved `cycle`, so a loaded New Game+ run burns as
+    /// fast as it did when saved.
+    pub fn burn_multiplier(&self) -> f32 {
+        1.0 + NEW_GAME_PLUS_BURN_STEP * self.cycle as f32
+    }
+}
+
+/// Cosmetic candle flames, unlocked by the endings runs reach
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Cosmetic {
+    /// Deep orange flame, for escaping (the `Fled` ending)
+    EmberFlame,
+    /// Cold white flame, for the `Witness` ending
+    PaleFlame,
+    /// Golden flame, for the `Truth` ending
+    GoldenFlame,
+}
+
+impl Cosmetic {
+    /// Cosmetic unlocked by reaching `ending`
+    pub fn for_ending(ending: Ending) -> Self {
+        match ending {
+            Ending::Fled => Cosmetic::EmberFlame,
+            Ending::Witness => Cosmetic::PaleFlame,
+            Ending::Truth => Cosmetic::GoldenFlame,
+        }
+    }
+
+    /// Color of the light the flame casts, at full brightness
+    pub fn flame_color(self) -> LinearRgba {
+        match self {
+            Cosmetic::EmberFlame => LinearRgba::new(1.0, 0.6, 0.3, 1.0),
+            Cosmetic::PaleFlame => LinearRgba::new(0.85, 0.9, 1.0, 1.0),
+            Cosmetic::GoldenFlame => LinearRgba::new(1.0, 0.8, 0.35, 1.0),
+        }
+    }
+}
+
+/// Event requesting a New Game+ run after victory
+#[derive(Event, Debug, Default)]
+pub struct NewGamePlusEvent;
+
+/// System that ends the game when the player walks out of the house
+///
+/// # System Dependencies
+/// - **Components**: Reads the player's `Transform`, `Inventory` and
+///   carried candle; reads `ExitDoor` `DoorState`s
+/// - **Resources**: Writes `GameState` and `RunSummary`, reads `NewGamePlus`,
+///   and `NoteContent` and `GameStats` when present
+/// - **Upstream**: `door_use_system` and `door_swing_system` open the door
+/// - **Downstream**: The ending screen shows `RunSummary`; emits `ToastEvent`
+///   when the candle isn't lit
+///
+/// # Behavior
+/// While playing, an open exit door within `DOOR_TRAVERSE_DISTANCE` of the
+/// player ends the run, unless the player's candle isn't lit: then a toast
+/// asks them to light it (once per approach).
+#[allow(clippy::too_many_arguments)]
+#[allow(clippy::type_complexity)]
+pub fn escape_system(
+    mut game_state: ResMut<GameState>,
+    mut summary: ResMut<RunSummary>,
+    new_game_plus: Res<NewGamePlus>,
+    notes: Option<Res<NoteContent>>,
+    stats: Option<Res<GameStats>>,
+    mut toasts: EventWriter<ToastEvent>,
+    mut warned: Local<bool>,
+    player_query: Query<(&Transform, Option<&Inventory>), With<Player>>,
+    candle_query: Query<(&CandleState, &CarriedCandle)>,
+    exit_query: Query<(&Transform, &DoorState), (With<ExitDoor>, Without<DoorSwing>)>,
+) {
+    if game_state.game_mode != GameMode::Playing {
+        return;
+    }
+    let Ok((player_transform, inventory)) = player_query.single() else {
+        return;
+    };
+
+    let player_pos = player_transform.translation.truncate();
+    let at_exit = exit_query.iter().any(|(transform, state)| {
+        *state == DoorState::Open
+            && transform.translation.truncate().distance(player_pos) <= DOOR_TRAVERSE_DISTANCE
+    });
+    if !at_exit {
+        *warned = false;
+        return;
+    }
+
+    let candle_lit = candle_query
+        .iter()
+        .any(|(state, carried)| carried.is_players() && *state == CandleState::Lit);
+    if !candle_lit {
+        if !*warned {
+            toasts.write(ToastEvent::info(ESCAPE_IN_DARKNESS_MESSAGE));
+            *warned = true;
+        }
+        return;
+    }
+
+    let diary_pages = inventory.map_or(0, |inventory| collected_pages(inventory).len());
+    let total_pages = notes.map_or(diary_pages, |notes| notes.pages.len().max(diary_pages));
+    *summary = RunSummary {
+        time: game_state.completion_time,
+        deaths: game_state.deaths,
+        secrets: game_state.collected_secrets.len(),
+        diary_pages,
+        total_pages,
+        ending: ending_for(diary_pages, total_pages),
+        cycle: new_game_plus.cycle,
+        stats: stats.as_deref().cloned().unwrap_or_default(),
+    };
+    game_state.game_mode = GameMode::Victory;
+    info!("Escaped the house: {:?}", *summary);
+}
+
+/// System that starts a New Game+ run
+///
+/// # System Dependencies
+/// - **Upstream**: The ending screen emits `NewGamePlusEvent`
+/// - **Components**: Writes the player's `Transform`, `Inventory`, `Hotbar`
+///   and carried candle
+/// - **Resources**: Reads `RunSummary`; writes `GameState`, `NewGamePlus`,
+///   and `MapState`, `RoomStateStore` and `GameStats` when present
+/// - **Downstream**: Emits `RoomChangedEvent` when the player isn't in
+///   `START_ROOM`
+///
+/// # Behavior
+/// The house is reset (map, room states, deaths, time, secrets, stats) and
+/// the player keeps only their diary pages, with a fresh unlit candle. Each
+/// cycle candles burn `NEW_GAME_PLUS_BURN_STEP` faster (see
+/// `NewGamePlus::burn_multiplier`). The ending reached
+/// unlocks its `Cosmetic`, kept with the earlier ones. Achievements are
+/// kept apart from runs, so unlocks carry over untouched.
+#[allow(clippy::too_many_arguments)]
+#[allow(clippy::type_complexity)]
+pub fn new_game_plus_system(
+    mut events: EventReader<NewGamePlusEvent>,
+    mut game_state: ResMut<GameState>,
+    mut new_game_plus: ResMut<NewGamePlus>,
+    summary: Res<RunSummary>,
+    map_state: Option<ResMut<MapState>>,
+    room_states: Option<ResMut<RoomStateStore>>,
+    stats: Option<ResMut<GameStats>>,
+    mut room_events: EventWriter<RoomChangedEvent>,
+    mut player_query: Query<
+        (&mut Transform, Option<&mut Inventory>, Option<&mut Hotbar>),
+        With<Player>,
+    >,
+    mut candle_query: Query<(&mut CandleState, &mut CandleWax, &CarriedCandle)>,
+) {
+    if events.read().count() == 0 {
+        return;
+    }
+
+    new_game_plus.cycle += 1;
+    let cosmetic = Cosmetic::for_ending(summary.ending);
+    if new_game_plus.unlock(cosmetic) {
+        info!("Unlocked the {:?} cosmetic", cosmetic);
+    }
+    if let Some(mut map_state) = map_state {
+        map_state.clear();
+    }
+    if let Some(mut room_states) = room_states {
+        room_states.light_states.clear();
+        room_states.crate_positions.clear();
+        room_states.broken.clear();
+        room_states.cut_wires.clear();
+        room_states.oiled.clear();
+    }
+    if let Some(mut stats) = stats {
+        *stats = GameStats::default();
+    }
+
+    if game_state.current_room != START_ROOM {
+        room_events.write(RoomChangedEvent {
+            old_room: game_state.current_room,
+            new_room: START_ROOM,
+        });
+    }
+    *game_state = GameState {
+        current_room: game_state.current_room,
+        game_mode: GameMode::Playing,
+        ..default()
+    };
+
+    for (mut transform, inventory, hotbar) in &mut player_query {
+        transform.translation = game_state
+            .player_spawn_point
+            .extend(transform.translation.z);
+        if let Some(mut inventory) = inventory {
+            inventory
+                .items
+                .retain(|item| matches!(item, Item::DiaryPage(_)));
+        }
+        if let Some(mut hotbar) = hotbar {
+            *hotbar = Hotbar::default();
+        }
+    }
+    for (mut state, mut wax, carried) in &mut candle_query {
+        if carried.is_players() {
+            *state = CandleState::Unlit;
+            wax.0 = 100.0;
+        }
+    }
+    info!("Starting New Game+ cycle {}", new_game_plus.cycle);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn setup_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(VictoryPlugin);
+        app.insert_resource(GameState {
+            game_mode: GameMode::Playing,
+            deaths: 3,
+            ..default()
+        });
+        app
+    }
+
+    fn spawn_player(app: &mut App, candle: CandleState) -> Entity {
+        let player = app
+            .world_mut()
+            .spawn((
+                Player,
+                Transform::default(),
+                Inventory {
+                    items: vec![Item::DiaryPage(1), Item::Match, Item::DiaryPage(2)],
+                    max_capacity: 10,
+                },
+            ))
+            .id();
+        app.world_mut()
+            .spawn((candle, CandleWax(40.0), CarriedCandle::default()));
+        player
+    }
+
+    #[test]
+    fn endings_follow_collected_pages() {
+        assert_eq!(ending_for(0, 6), Ending::Fled);
+        assert_eq!(ending_for(2, 6), Ending::Fled);
+        assert_eq!(ending_for(3, 6), Ending::Witness);
+        assert_eq!(ending_for(6, 6), Ending::Truth);
+        assert_eq!(ending_for(0, 0), Ending::Fled);
+    }
+
+    #[test]
+    fn walking_through_open_exit_with_lit_candle_wins() {
+        let mut app = setup_app();
+        spawn_player(&mut app, CandleState::Lit);
+        app.world_mut().spawn((
+            ExitDoor,
+            DoorState::Open,
+            Transform::from_xyz(8.0, 0.0, 0.0),
+        ));
+
+        app.update();
+
+        assert_eq!(
+            app.world().resource::<GameState>().game_mode,
+            GameMode::Victory
+        );
+        let summary = app.world().resource::<RunSummary>();
+        assert_eq!(summary.deaths, 3);
+        assert_eq!(summary.diary_pages, 2);
+        assert_eq!(summary.ending, Ending::Truth, "No note content loaded");
+    }
+
+    #[test]
+    fn exit_needs_open_door_and_lit_candle() {
+        let mut app = setup_app();
+        spawn_player(&mut app, CandleState::Unlit);
+        let door = app
+            .world_mut()
+            .spawn((
+                ExitDoor,
+                DoorState::Unlocked,
+                Transform::from_xyz(8.0, 0.0, 0.0),
+            ))
+            .id();
+
+        app.update();
+        assert_eq!(
+            app.world().resource::<GameState>().game_mode,
+            GameMode::Playing,
+            "Closed door"
+        );
+
+        *app.world_mut().get_mut::<DoorState>(door).unwrap() = DoorState::Open;
+        app.update();
+        app.update();
+        assert_eq!(
+            app.world().resource::<GameState>().game_mode,
+            GameMode::Playing,
+            "Unlit candle"
+        );
+        let toasts = app.world().resource::<Events<ToastEvent>>();
+        assert_eq!(toasts.get_cursor().read(toasts).count(), 1);
+    }
+
+    #[test]
+    fn new_game_plus_resets_house_and_keeps_diary_pages() {
+        let mut app = setup_app();
+        app.init_resource::<MapState>();
+        let player = spawn_player(&mut app, CandleState::Extinguished);
+        app.world_mut().resource_mut::<MapState>().mark_explored(4);
+        {
+            let mut game_state = app.world_mut().resource_mut::<GameState>();
+            game_state.game_mode = GameMode::Victory;
+            game_state.current_room = 4;
+        }
+
+        app.world_mut().send_event(NewGamePlusEvent);
+        app.update();
+
+        let game_state = app.world().resource::<GameState>();
+        assert_eq!(game_state.game_mode, GameMode::Playing);
+        assert_eq!(game_state.deaths, 0);
+        assert_eq!(app.world().resource::<NewGamePlus>().cycle, 1);
+        assert_eq!(app.world().resource::<MapState>().explored_count(), 0);
+        assert_eq!(
+            app.world().resource::<NewGamePlus>().burn_multiplier(),
+            1.0 + NEW_GAME_PLUS_BURN_STEP
+        );
+
+        let inventory = app.world().get::<Inventory>(player).unwrap();
+        assert_eq!(collected_pages(inventory), vec![1, 2]);
+        assert_eq!(inventory.items.len(), 2);
+
+        let events = app.world().resource::<Events<RoomChangedEvent>>();
+        let changes: Vec<(RoomId, RoomId)> = events
+            .get_cursor()
+            .read(events)
+            .map(|event| (event.old_room, event.new_room))
+            .collect();
+        assert_eq!(changes, vec![(4, START_ROOM)]);
+    }
+
+    #[test]
+    fn new_game_plus_carries_the_endings_cosmetics_over() {
+        let mut app = setup_app();
+        for ending in [Ending::Truth, Ending::Fled, Ending::Truth] {
+            app.world_mut().resource_mut::<RunSummary>().ending = ending;
+            app.world_mut().resource_mut::<GameState>().game_mode = GameMode::Victory;
+            app.world_mut().send_event(NewGamePlusEvent);
+            app.update();
+        }
+
+        let new_game_plus = app.world().resource::<NewGamePlus>();
+        assert_eq!(new_game_plus.cycle, 3);
+        assert_eq!(
+            new_game_plus.cosmetics,
+            vec![Cosmetic::GoldenFlame, Cosmetic::EmberFlame]
+        );
+        assert_eq!(new_game_plus.flame(), Some(Cosmetic::GoldenFlame));
+    }
+}
@@ -0,0 +1,417 @@
+use crate::resources::game_state::GameState;
+use crate::systems::level_loader::{LevelData, load_level_file, spawn_level_entity};
+use crate::systems::lighting::{LightingMaterial, LightingPlugin, spawn_lighting_overlay};
+use crate::systems::render_layer::RenderLayerPlugin;
+use crate::systems::tilemap::setup_tilemap;
+use bevy::app::PluginsState;
+use bevy::asset::RenderAssetUsages;
+use bevy::prelude::*;
+use bevy::render::camera::RenderTarget;
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat, TextureUsages};
+use bevy::render::view::screenshot::{Screenshot, ScreenshotCaptured};
+use bevy::window::ExitCondition;
+use bevy::winit::WinitPlugin;
+use image::RgbaImage;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Directory holding the committed golden room images
+pub const GOLDEN_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/golden");
+
+/// Directory holding the fixture rooms that are captured
+pub const FIXTURE_LEVEL_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/assets/levels");
+
+/// Environment variable that rewrites golden images instead of comparing them
+/// (shared with the UI layout snapshots)
+pub const UPDATE_GOLDEN_VAR: &str = crate::ui::snapshot::UPDATE_SNAPSHOTS_VAR;
+
+/// Size (pixels) of the offscreen frame rendered for each room
+pub const CAPTURE_SIZE: UVec2 = UVec2::new(960, 540);
+
+/// Frames rendered before the capture so pipelines compile and uploads settle
+pub const CAPTURE_WARMUP_FRAMES: u32 = 10;
+
+/// Most frames rendered while waiting for assets and the screenshot readback
+pub const MAX_CAPTURE_FRAMES: u32 = 600;
+
+/// How far a captured frame may drift from its golden image
+///
+/// GPU drivers differ slightly in rasterization and blending, so pixels
+/// whose channels all stay within `channel_delta` count as equal, and a
+/// small fraction of pixels may differ by more.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Tolerance {
+    /// Largest per-channel difference (0-255) for a pixel to still match
+    pub channel_delta: u8,
+    /// Largest fraction (0.0-1.0) of pixels allowed to mismatch
+    pub max_mismatched_fraction: f32,
+}
+
+impl Default for Tolerance {
+    fn default() -> Self {
+        Self {
+            channel_delta: 8,
+            max_mismatched_fraction: 0.001,
+        }
+    }
+}
+
+/// Result of comparing a captured frame against its golden image
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ImageDiff {
+    /// Pixels with a channel differing by more than the tolerance
+    pub mismatched: usize,
+    /// Pixels compared
+    pub total: usize,
+    /// Largest per-channel difference found
+    pub max_channel_delta: u8,
+}
+
+impl ImageDiff {
+    /// Fraction (0.0-1.0) of pixels that mismatch
+    pub fn mismatched_fraction(&self) -> f32 {
+        if self.total == 0 {
+            0.0
+        } else {
+            self.mismatched as f32 / self.total as f32
+        }
+    }
+
+    /// Returns true if the frame is close enough to its golden image
+    pub fn within(&self, tolerance: &Tolerance) -> bool {
+        self.mismatched_fraction() <= tolerance.max_mismatched_fraction
+    }
+}
+
+/// Compares two RGBA images pixel by pixel
+///
+/// # Errors
+/// Returns error string if the images have different dimensions
+pub fn diff_images(
+    expected: &RgbaImage,
+    actual: &RgbaImage,
+    tolerance: &Tolerance,
+) -> Result<ImageDiff, String> {
+    if expected.dimensions() != actual.dimensions() {
+        return Err(format!(
+            "Image size changed from {:?} to {:?}",
+            expected.dimensions(),
+            actual.dimensions()
+        ));
+    }
+
+    let mut diff = ImageDiff {
+        mismatched: 0,
+        total: (expected.width() * expected.height()) as usize,
+        max_channel_delta: 0,
+    };
+    for (expected, actual) in expected.pixels().zip(actual.pixels()) {
+        let delta = expected
+            .0
+            .iter()
+            .zip(actual.0.iter())
+            .map(|(a, b)| a.abs_diff(*b))
+            .max()
+            .unwrap_or(0);
+        diff.max_channel_delta = diff.max_channel_delta.max(delta);
+        if delta > tolerance.channel_delta {
+            diff.mismatched += 1;
+        }
+    }
+    Ok(diff)
+}
+
+/// Loads every fixture room in `dir`, sorted by file name
+///
+/// # Errors
+/// Returns error string if the directory or a level file cannot be read
+pub fn fixture_rooms(dir: &Path) -> Result<Vec<(String, LevelData)>, String> {
+    let entries = fs::read_dir(dir)
+        .map_err(|e| format!("Failed to read fixture rooms in '{}': {}", dir.display(), e))?;
+
+    let mut paths: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "ron"))
+        .collect();
+    paths.sort();
+
+    paths
+        .iter()
+        .map(|path| {
+            let name = path
+                .file_stem()
+                .map(|stem| stem.to_string_lossy().into_owned())
+                .unwrap_or_default();
+            load_level_file(&path.to_string_lossy()).map(|level| (name, level))
+        })
+        .collect()
+}
+
+/// Resource holding the room being captured
+#[derive(Resource, Debug, Clone)]
+pub struct CaptureRoom(pub LevelData);
+
+/// Resource tracking the capture of one frame
+#[derive(Resource, Debug, Default)]
+pub struct RoomCapture {
+    /// Offscreen image the fixed camera renders into
+    pub target: Handle<Image>,
+    /// Tileset the room's tilemap draws from
+    pub tileset: Handle<Image>,
+    /// Frames rendered so far
+    pub frames: u32,
+    /// Whether the screenshot was requested
+    pub requested: bool,
+    /// Captured frame, once the GPU readback finished
+    pub frame: Option<Image>,
+}
+
+/// Plugin that renders a room headlessly through a fixed camera and captures one frame
+///
+/// Adds Bevy's `DefaultPlugins` without a window or winit event loop, the
+/// room's rendering plugins (tilemap, lighting overlay, render layers) and
+/// a camera centered on the room bounds that renders into an offscreen
+/// image of `CAPTURE_SIZE`.
+pub struct RoomCapturePlugin {
+    /// Room to render
+    pub level: LevelData,
+}
+
+impl Plugin for RoomCapturePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(
+            DefaultPlugins
+                .build()
+                .disable::<WinitPlugin>()
+                .set(WindowPlugin {
+                    primary_window: None,
+                    exit_condition: ExitCondition::DontExit,
+                    ..default()
+                }),
+        )
+        .add_plugins((
+            bevy_ecs_tilemap::TilemapPlugin,
+            LightingPlugin,
+            RenderLayerPlugin,
+        ))
+        .insert_resource(CaptureRoom(self.level.clone()))
+        .insert_resource(GameState {
+            current_room: self.level.id,
+            ..Default::default()
+        })
+        .init_resource::<RoomCapture>()
+        .add_systems(Startup, (setup_room_capture_system, setup_tilemap))
+        .add_systems(Update, request_room_capture_system);
+    }
+}
+
+/// Startup system that spawns the capture camera, lighting overlay and room entities
+///
+/// # System Dependencies
+/// - **Resources**: Reads `CaptureRoom`, writes `RoomCapture`, `Assets<Image>`,
+///   `Assets<Mesh>` and `Assets<LightingMaterial>`
+pub fn setup_room_capture_system(
+    mut commands: Commands,
+    room: Res<CaptureRoom>,
+    asset_server: Res<AssetServer>,
+    mut capture: ResMut<RoomCapture>,
+    mut images: ResMut<Assets<Image>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<LightingMaterial>>,
+) {
+    let mut target = Image::new_fill(
+        Extent3d {
+            width: CAPTURE_SIZE.x,
+            height: CAPTURE_SIZE.y,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        &[0, 0, 0, 255],
+        TextureFormat::Rgba8UnormSrgb,
+        RenderAssetUsages::default(),
+    );
+    target.texture_descriptor.usage |=
+        TextureUsages::COPY_SRC | TextureUsages::RENDER_ATTACHMENT | TextureUsages::TEXTURE_BINDING;
+    capture.target = images.add(target);
+    capture.tileset = asset_server.load("sprites/tileset.png");
+
+    // Fit the whole room into the frame, whatever its size
+    let min = Vec2::new(room.0.bounds.min.0, room.0.bounds.min.1);
+    let max = Vec2::new(room.0.bounds.max.0, room.0.bounds.max.1);
+    let scale = ((max - min) / CAPTURE_SIZE.as_vec2())
+        .max_element()
+        .max(1.0);
+    let center = (min + max) / 2.0;
+
+    commands.spawn((
+        Camera2d,
+        Camera {
+            target: RenderTarget::Image(capture.target.clone().into()),
+            clear_color: ClearColorConfig::Custom(Color::BLACK),
+            ..default()
+        },
+        Projection::Orthographic(OrthographicProjection {
+            scale,
+            ..OrthographicProjection::default_2d()
+        }),
+        Transform::from_translation(center.extend(0.0)),
+    ));
+
+    spawn_lighting_overlay(&mut commands, &mut meshes, &mut materials);
+    for spawn in &room.0.entities {
+        spawn_level_entity(&mut commands, spawn);
+    }
+}
+
+/// System that requests the screenshot once the room has settled
+///
+/// # System Dependencies
+/// - **Resources**: Reads `AssetServer`, writes `RoomCapture`
+///
+/// # Behavior
+/// Waits for the tileset to load, then `CAPTURE_WARMUP_FRAMES` more frames,
+/// and spawns a `Screenshot` of the offscreen target. The GPU readback
+/// arrives asynchronously a few frames later and is stored in
+/// `RoomCapture::frame`.
+pub fn request_room_capture_system(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut capture: ResMut<RoomCapture>,
+) {
+    if capture.requested {
+        return;
+    }
+    if !asset_server.is_loaded_with_dependencies(&capture.tileset) {
+        return;
+    }
+
+    capture.frames += 1;
+    if capture.frames < CAPTURE_WARMUP_FRAMES {
+        return;
+    }
+
+    capture.requested = true;
+    commands
+        .spawn(Screenshot::image(capture.target.clone()))
+        .observe(
+            |trigger: Trigger<ScreenshotCaptured>, mut capture: ResMut<RoomCapture>| {
+                capture.frame = Some(trigger.event().0.clone());
+            },
+        );
+}
+
+/// Renders `level` headlessly and returns the captured frame
+///
+/// Blocks until the renderer has started (it initializes asynchronously)
+/// and the screenshot readback has arrived. Requires a GPU adapter;
+/// software adapters such as lavapipe work too.
+///
+/// # Errors
+/// Returns error string if no frame arrives within `MAX_CAPTURE_FRAMES`
+/// or it cannot be converted to RGBA
+pub fn capture_room(level: &LevelData) -> Result<RgbaImage, String> {
+    let mut app = App::new();
+    app.add_plugins(RoomCapturePlugin {
+        level: level.clone(),
+    });
+
+    while app.plugins_state() == PluginsState::Adding {
+        bevy::tasks::tick_global_task_pools_on_main_thread();
+    }
+    app.finish();
+    app.cleanup();
+
+    for _ in 0..MAX_CAPTURE_FRAMES {
+        app.update();
+        if let Some(frame) = app.world_mut().resource_mut::<RoomCapture>().frame.take() {
+            return frame
+                .try_into_dynamic()
+                .map(|image| image.to_rgba8())
+                .map_err(|e| format!("Captured frame of room {} is unreadable: {}", level.id, e));
+        }
+    }
+
+    Err(format!(
+        "No frame captured for room {} after {} frames",
+        level.id, MAX_CAPTURE_FRAMES
+    ))
+}
+
+/// Compares `actual` against `tests/golden/<name>.png`
+///
+/// A missing golden image is created. Setting `UPDATE_SNAPSHOTS=1` rewrites
+/// existing images after an intentional rendering change. When the frame
+/// differs, it is written next to the golden image as `<name>.actual.png`
+/// for inspection.
+///
+/// # Errors
+/// Returns error string describing the difference, or any IO failure
+pub fn check_golden(name: &str, actual: &RgbaImage, tolerance: &Tolerance) -> Result<(), String> {
+    let path = PathBuf::from(GOLDEN_DIR).join(format!("{}.png", name));
+    let update = std::env::var_os(UPDATE_GOLDEN_VAR).is_some();
+
+    if update || !path.exists() {
+        fs::create_dir_all(GOLDEN_DIR)
+            .map_err(|e| format!("Failed to create '{}': {}", GOLDEN_DIR, e))?;
+        return actual
+            .save(&path)
+            .map_err(|e| format!("Failed to write golden image '{}': {}", path.display(), e));
+    }
+
+    let expected = image::open(&path)
+        .map_err(|e| format!("Failed to read golden image '{}': {}", path.display(), e))?
+        .to_rgba8();
+    let diff =
+        diff_images(&expected, actual, tolerance).map_err(|e| format!("Room '{}': {}", name, e))?;
+    if diff.within(tolerance) {
+        return Ok(());
+    }
+
+    let actual_path = PathBuf::from(GOLDEN_DIR).join(format!("{}.actual.png", name));
+    let _ = actual.save(&actual_path);
+    Err(format!(
+        "Room '{}' rendering changed: {:.2}% of pixels differ (max channel delta {}); \
+         see '{}'. Rerun with {}=1 to accept the new rendering.",
+        name,
+        diff.mismatched_fraction() * 100.0,
+        diff.max_channel_delta,
+        actual_path.display(),
+        UPDATE_GOLDEN_VAR
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn diff_respects_channel_and_pixel_tolerance() {
+        let expected = RgbaImage::from_pixel(10, 10, image::Rgba([100, 100, 100, 255]));
+        let mut actual = expected.clone();
+        actual.put_pixel(0, 0, image::Rgba([104, 100, 100, 255]));
+        actual.put_pixel(1, 0, image::Rgba([200, 100, 100, 255]));
+
+        let tolerance = Tolerance {
+            channel_delta: 8,
+            max_mismatched_fraction: 0.0,
+        };
+        let diff = diff_images(&expected, &actual, &tolerance).unwrap();
+        assert_eq!(diff.mismatched, 1);
+        assert_eq!(diff.total, 100);
+        assert_eq!(diff.max_channel_delta, 100);
+        assert!(!diff.within(&tolerance));
+        assert!(diff.within(&Tolerance {
+            max_mismatched_fraction: 0.01,
+            ..tolerance
+        }));
+
+        let smaller = RgbaImage::new(5, 5);
+        assert!(diff_images(&expected, &smaller, &tolerance).is_err());
+    }
+
+    #[test]
+    fn fixture_rooms_load() {
+        let rooms = fixture_rooms(Path::new(FIXTURE_LEVEL_DIR)).unwrap();
+        assert!(rooms.iter().any(|(name, _)| name == "ground_floor_entry"));
+    }
+}
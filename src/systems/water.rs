@@ -0,0 +1,452 @@
+use bevy::prelude::*;
+
+use crate::audio::sound_bank::{PlaySoundEvent, SoundCue};
+use crate::components::lighting::{Candle, CandleState};
+use crate::components::player::{Health, Player};
+use crate::components::render_layer::RenderLayer;
+use crate::components::room::Collider;
+use crate::resources::game_state::{GameMode, GameState};
+use crate::resources::map_state::DeathCause;
+use crate::systems::trap::PlayerDeathEvent;
+use crate::systems::water_drip::FLAME_HEIGHT;
+
+/// Default upward acceleration (pixels per second squared) on a submerged
+/// player; a little over gravity, so they float back up to the surface
+pub const DEFAULT_BUOYANCY: f32 = 1150.0;
+
+/// Default multiplier on horizontal speed while in water
+pub const DEFAULT_WATER_SPEED_FACTOR: f32 = 0.5;
+
+/// Fraction of vertical velocity lost to drag per second in water
+pub const WATER_DRAG: f32 = 2.5;
+
+/// Upward speed (pixels per second) of a swim stroke (the jump action)
+pub const SWIM_STROKE_SPEED: f32 = 220.0;
+
+/// Seconds the player can stay submerged before drowning
+pub const AIR_SUPPLY_SECS: f32 = 12.0;
+
+/// How many times faster air refills at the surface than it runs out
+pub const AIR_REFILL_RATE: f32 = 4.0;
+
+/// Number of droplets thrown up by a splash
+pub const SPLASH_DROPLETS: usize = 8;
+
+/// Seconds a splash droplet lives
+pub const SPLASH_LIFETIME: f32 = 0.6;
+
+/// Color of water regions
+pub const WATER_COLOR: Color = Color::srgba(0.15, 0.3, 0.45, 0.55);
+
+/// Z offset of water in front of the player layer (and the carried candle)
+pub const WATER_Z_OFFSET: f32 = 1.0;
+
+/// Gravity (pixels per second squared) on the player and splash droplets
+const GRAVITY: f32 = 980.0;
+
+/// Plugin for water regions: swimming state, drowning and splashes
+///
+/// Movement itself is slowed and made buoyant by `player_movement_system`
+/// while the player has `InWater`.
+pub struct WaterPlugin;
+
+impl Plugin for WaterPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<SplashEvent>()
+            .add_event::<PlayerDeathEvent>()
+            .add_event::<PlaySoundEvent>()
+            .add_systems(
+                Update,
+                (
+                    water_detection_system,
+                    air_supply_system,
+                    splash_system,
+                    splash_particle_system,
+                )
+                    .chain(),
+            );
+    }
+}
+
+/// Component for a body of water the player can swim in
+///
+/// The water fills the entity's `Collider`, relative to its `Transform`;
+/// the collider's top edge is the surface.
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct WaterRegion {
+    /// Upward acceleration (pixels per second squared) on a submerged player
+    pub buoyancy: f32,
+    /// Multiplier on horizontal speed while in the water
+    pub speed_factor: f32,
+}
+
+impl Default for WaterRegion {
+    fn default() -> Self {
+        Self {
+            buoyancy: DEFAULT_BUOYANCY,
+            speed_factor: DEFAULT_WATER_SPEED_FACTOR,
+        }
+    }
+}
+
+/// Component on a player standing or swimming in water
+///
+/// Copies the region's physics so `player_movement_system` doesn't need to
+/// look the region up.
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct InWater {
+    /// The region the player is in
+    pub region: Entity,
+    /// World height of the water surface
+    pub surface: f32,
+    /// Whether the player's head (and candle flame) is under the surface
+    pub submerged: bool,
+    /// Upward acceleration while submerged (from the region)
+    pub buoyancy: f32,
+    /// Multiplier on horizontal speed (from the region)
+    pub speed_factor: f32,
+}
+
+/// Component tracking a player's remaining breath (seconds)
+///
+/// Added when the player goes under and removed once it has fully
+/// refilled at the surface.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct AirSupply(pub f32);
+
+impl AirSupply {
+    /// Fraction (0.0-1.0) of a full breath remaining
+    pub fn fraction(&self) -> f32 {
+        (self.0 / AIR_SUPPLY_SECS).clamp(0.0, 1.0)
+    }
+}
+
+/// Event sent when the player breaks the surface of water
+#[derive(Event, Debug, Clone, Copy, PartialEq)]
+pub struct SplashEvent {
+    /// Where on the surface the splash happens
+    pub position: Vec2,
+}
+
+/// Component for a droplet thrown up by a splash
+#[derive(Component, Debug)]
+pub struct SplashParticle {
+    /// Current velocity in pixels per second
+    pub velocity: Vec2,
+    /// Despawns the droplet when it finishes
+    pub lifetime: Timer,
+}
+
+/// System that tracks which water (if any) each player is in
+///
+/// # System Dependencies
+/// - **Components**: Reads `WaterRegion`, `Collider` and `Transform` of the
+///   water and `Player` `Transform`; writes `InWater` and `CandleState`
+/// - **Downstream**: Emits `SplashEvent`; `player_movement_system` reads
+///   `InWater`; `air_supply_system` reads `InWater::submerged`
+///
+/// # Behavior
+/// 1. A player whose feet are inside a region gets `InWater`, and loses it
+///    on leaving; both cross the surface with a splash
+/// 2. The player is submerged while the candle flame (`FLAME_HEIGHT` above
+///    their feet) is below the surface
+/// 3. Going under puts out a lit candle (`SoundCue::CandleSizzle`)
+#[allow(clippy::type_complexity)]
+pub fn water_detection_system(
+    game_state: Res<GameState>,
+    mut commands: Commands,
+    water_query: Query<(Entity, &WaterRegion, &Transform, &Collider), Without<Player>>,
+    mut player_query: Query<(Entity, &Transform, Option<&mut InWater>), With<Player>>,
+    mut candle_query: Query<&mut CandleState, With<Candle>>,
+    mut splash_events: EventWriter<SplashEvent>,
+    mut sound_events: EventWriter<PlaySoundEvent>,
+) {
+    if game_state.game_mode != GameMode::Playing {
+        return;
+    }
+
+    for (player, transform, in_water) in &mut player_query {
+        let feet = transform.translation.truncate();
+        let region = water_query.iter().find(|(_, _, water, collider)| {
+            let offset = feet - water.translation.truncate();
+            offset.cmpge(collider.min).all() && offset.cmple(collider.max).all()
+        });
+
+        let Some((region, water, water_transform, collider)) = region else {
+            if let Some(in_water) = in_water {
+                splash_events.write(SplashEvent {
+                    position: Vec2::new(feet.x, in_water.surface),
+                });
+                commands.entity(player).remove::<InWater>();
+            }
+            continue;
+        };
+
+        let surface = water_transform.translation.y + collider.max.y;
+        let submerged = feet.y + FLAME_HEIGHT < surface;
+        let was_submerged = in_water.as_ref().is_some_and(|in_water| in_water.submerged);
+        let state = InWater {
+            region,
+            surface,
+            submerged,
+            buoyancy: water.buoyancy,
+            speed_factor: water.speed_factor,
+        };
+
+        match in_water {
+            Some(mut in_water) => {
+                if *in_water != state {
+                    *in_water = state;
+                }
+            }
+            None => {
+                splash_events.write(SplashEvent {
+                    position: Vec2::new(feet.x, surface),
+                });
+                commands.entity(player).insert(state);
+            }
+        }
+
+        if submerged && !was_submerged {
+            for mut candle in &mut candle_query {
+                if *candle == CandleState::Lit {
+                    *candle = CandleState::Extinguished;
+                    sound_events.write(PlaySoundEvent {
+                        cue: SoundCue::CandleSizzle,
+                    });
+                }
+            }
+        }
+    }
+}
+
+/// System that runs down a submerged player's air and drowns them
+///
+/// # Behavior
+/// 1. A submerged player starts with a full `AirSupply` that drains by a
+///    second each second; reaching zero kills them
+///    (`DeathCause::Drowning`)
+/// 2. At the surface (or out of the water) air refills `AIR_REFILL_RATE`
+///    times as fast and `AirSupply` is removed once full
+///
+/// # System Dependencies
+/// - **Upstream**: `water_detection_system` sets `InWater::submerged`
+/// - **Components**: Writes `AirSupply` and `Health`
+/// - **Downstream**: `hud_system` draws the air meter; `respawn_system`
+///   consumes `PlayerDeathEvent`
+#[allow(clippy::type_complexity)]
+pub fn air_supply_system(
+    time: Res<Time>,
+    game_state: Res<GameState>,
+    mut commands: Commands,
+    mut player_query: Query<
+        (
+            Entity,
+            &mut Health,
+            Option<&InWater>,
+            Option<&mut AirSupply>,
+        ),
+        With<Player>,
+    >,
+    mut death_events: EventWriter<PlayerDeathEvent>,
+) {
+    if game_state.game_mode != GameMode::Playing {
+        return;
+    }
+
+    let delta = time.delta_secs();
+    for (entity, mut health, in_water, air) in &mut player_query {
+        let submerged = in_water.is_some_and(|in_water| in_water.submerged);
+
+        match (submerged, air) {
+            (true, Some(mut air)) => {
+                if *health == Health::Dead {
+                    continue;
+                }
+                air.0 -= delta;
+                if air.0 <= 0.0 {
+                    *health = Health::Dead;
+                    death_events.write(PlayerDeathEvent {
+                        player: entity,
+                        cause: DeathCause::Drowning,
+                    });
+                    commands.entity(entity).remove::<AirSupply>();
+                }
+            }
+            (true, None) => {
+                if *health == Health::Alive {
+                    commands.entity(entity).insert(AirSupply(AIR_SUPPLY_SECS));
+                }
+            }
+            (false, Some(mut air)) => {
+                air.0 += delta * AIR_REFILL_RATE;
+                if air.0 >= AIR_SUPPLY_SECS {
+                    commands.entity(entity).remove::<AirSupply>();
+                }
+            }
+            (false, None) => {}
+        }
+    }
+}
+
+/// System that throws up droplets and plays a splash where the surface breaks
+///
+/// # System Dependencies
+/// - **Upstream**: `water_detection_system` emits `SplashEvent`
+/// - **Downstream**: `splash_particle_system` moves the droplets; emits
+///   `PlaySoundEvent` (`SoundCue::Splash`)
+pub fn splash_system(
+    mut commands: Commands,
+    mut splash_events: EventReader<SplashEvent>,
+    mut sound_events: EventWriter<PlaySoundEvent>,
+) {
+    for splash in splash_events.read() {
+        // Fan the droplets out evenly between up-left and up-right
+        for index in 0..SPLASH_DROPLETS {
+            let t = index as f32 / (SPLASH_DROPLETS - 1) as f32;
+            let angle = (0.25 + 0.5 * t) * std::f32::consts::PI;
+            let speed = 120.0 + 60.0 * (1.0 - (2.0 * t - 1.0).abs());
+            commands.spawn((
+                SplashParticle {
+                    velocity: Vec2::from_angle(angle) * speed,
+                    lifetime: Timer::from_seconds(SPLASH_LIFETIME, TimerMode::Once),
+                },
+                Sprite {
+                    color: Color::srgba(0.7, 0.85, 1.0, 0.8),
+                    custom_size: Some(Vec2::splat(3.0)),
+                    ..default()
+                },
+                Transform::from_translation(
+                    splash
+                        .position
+                        .extend(RenderLayer::Player.z() + WATER_Z_OFFSET),
+                ),
+            ));
+        }
+        sound_events.write(PlaySoundEvent {
+            cue: SoundCue::Splash,
+        });
+    }
+}
+
+/// System that arcs splash droplets under gravity and despawns them
+///
+/// # System Dependencies
+/// - **Upstream**: `splash_system` spawns droplets
+/// - **Components**: Writes `SplashParticle` and `Transform`
+pub fn splash_particle_system(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut SplashParticle, &mut Transform)>,
+) {
+    let delta = time.delta_secs();
+    for (entity, mut particle, mut transform) in &mut query {
+        particle.velocity.y -= GRAVITY * delta;
+        transform.translation += (particle.velocity * delta).extend(0.0);
+
+        particle.lifetime.tick(time.delta());
+        if particle.lifetime.finished() {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn setup_app() -> App {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, WaterPlugin));
+        app.insert_resource(GameState {
+            game_mode: GameMode::Playing,
+            ..default()
+        });
+        // Surface at y = 50
+        app.world_mut().spawn((
+            WaterRegion::default(),
+            Transform::default(),
+            Collider {
+                min: Vec2::new(-100.0, -50.0),
+                max: Vec2::new(100.0, 50.0),
+            },
+        ));
+        app
+    }
+
+    fn move_player(app: &mut App, player: Entity, y: f32) {
+        app.world_mut()
+            .get_mut::<Transform>(player)
+            .unwrap()
+            .translation
+            .y = y;
+        app.update();
+    }
+
+    fn droplets(app: &mut App) -> usize {
+        let mut query = app.world_mut().query::<&SplashParticle>();
+        query.iter(app.world()).count()
+    }
+
+    #[test]
+    fn wading_in_splashes_and_going_under_puts_the_candle_out() {
+        let mut app = setup_app();
+        let player = app
+            .world_mut()
+            .spawn((Player, Health::Alive, Transform::from_xyz(0.0, 200.0, 0.0)))
+            .id();
+        let candle = app.world_mut().spawn((Candle, CandleState::Lit)).id();
+
+        app.update();
+        assert!(app.world().get::<InWater>(player).is_none());
+
+        // Feet in, flame above the surface
+        move_player(&mut app, player, 40.0);
+        let in_water = app.world().get::<InWater>(player).unwrap();
+        assert_eq!(in_water.surface, 50.0);
+        assert!(!in_water.submerged);
+        assert_eq!(droplets(&mut app), SPLASH_DROPLETS);
+        assert_eq!(
+            *app.world().get::<CandleState>(candle).unwrap(),
+            CandleState::Lit
+        );
+
+        move_player(&mut app, player, 0.0);
+        assert!(app.world().get::<InWater>(player).unwrap().submerged);
+        assert_eq!(
+            *app.world().get::<CandleState>(candle).unwrap(),
+            CandleState::Extinguished
+        );
+        assert_eq!(
+            app.world().get::<AirSupply>(player),
+            Some(&AirSupply(AIR_SUPPLY_SECS))
+        );
+
+        // Climbing out refills the air and splashes again
+        move_player(&mut app, player, 200.0);
+        assert!(app.world().get::<InWater>(player).is_none());
+        assert!(droplets(&mut app) > SPLASH_DROPLETS);
+    }
+
+    #[test]
+    fn running_out_of_air_drowns_the_player() {
+        let mut app = setup_app();
+        let player = app
+            .world_mut()
+            .spawn((Player, Health::Alive, Transform::default()))
+            .id();
+
+        app.update();
+        app.world_mut().entity_mut(player).insert(AirSupply(0.0));
+        app.update();
+
+        assert_eq!(*app.world().get::<Health>(player).unwrap(), Health::Dead);
+        let causes: Vec<DeathCause> = app
+            .world_mut()
+            .resource_mut::<Events<PlayerDeathEvent>>()
+            .drain()
+            .map(|event| event.cause)
+            .collect();
+        assert_eq!(causes, vec![DeathCause::Drowning]);
+    }
+}
@@ -0,0 +1,429 @@
+use crate::audio::sound_bank::{PlaySoundEvent, SoundCue};
+use crate::components::lighting::{Candle, CandleState, CarriedCandle, FlameSputter};
+use crate::components::player::Player;
+use crate::components::trap::WaterDrip;
+use crate::resources::game_rng::GameRng;
+use crate::resources::game_state::{GameMode, GameState};
+use crate::systems::game_set::GameSet;
+use bevy::prelude::*;
+use rand::Rng;
+
+/// Falling speed of a water drop in pixels per second
+pub const DRIP_FALL_SPEED: f32 = 240.0;
+
+/// Seconds before an unobstructed water drop is despawned
+pub const DRIP_LIFETIME: f32 = 3.0;
+
+/// Horizontal distance (pixels) from the player within which a drop hits the flame
+pub const DRIP_HIT_HALF_WIDTH: f32 = 12.0;
+
+/// Height (pixels) above the player's origin where the candle flame sits
+pub const FLAME_HEIGHT: f32 = 24.0;
+
+/// Seconds the flame sputters (reduced visibility) after being hit
+pub const SPUTTER_DURATION: f32 = 1.5;
+
+/// Plugin that drips water from ceiling spots onto the candle
+///
+/// Drops are released, fall and sizzle the flame in `GameSet::Gameplay`,
+/// where the sputter they leave also wears off.
+pub struct WaterDripPlugin;
+
+impl Plugin for WaterDripPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<GameState>()
+            .init_resource::<GameRng>()
+            .add_event::<CandleSizzleEvent>()
+            .add_event::<PlaySoundEvent>()
+            .add_systems(
+                Update,
+                (
+                    water_drip_spawn_system,
+                    drip_particle_system,
+                    flame_sputter_system,
+                )
+                    .chain()
+                    .in_set(GameSet::Gameplay),
+            );
+    }
+}
+
+/// Component for a single falling water drop
+///
+/// Spawned by `water_drip_spawn_system` and moved by `drip_particle_system`.
+#[derive(Component, Debug)]
+pub struct DripParticle {
+    /// Chance that this drop extinguishes the candle on hit (copied from its `WaterDrip`)
+    pub extinguish_chance: f32,
+    /// Despawns the drop when it finishes without hitting anything
+    pub lifetime: Timer,
+}
+
+/// Event emitted when a water drop hits a lit candle
+///
+/// Useful for UI feedback; audio is requested directly via `PlaySoundEvent`.
+#[derive(Event, Debug)]
+pub struct CandleSizzleEvent {
+    /// The candle that was hit
+    pub candle: Entity,
+    /// Whether the drop put the candle out
+    pub extinguished: bool,
+}
+
+/// System that releases water drops from dripping ceiling spots
+///
+/// # Behavior
+/// 1. Ticks each `WaterDrip` interval timer
+/// 2. Each time it finishes, spawns a small `DripParticle` sprite at the spot
+///
+/// # System Dependencies
+/// - **Components**: Reads `Transform`, writes `WaterDrip`
+/// - **Downstream**: `drip_particle_system` moves the spawned drops
+pub fn water_drip_spawn_system(
+    time: Res<Time>,
+    game_state: Res<GameState>,
+    mut commands: Commands,
+    mut drip_query: Query<(&Transform, &mut WaterDrip)>,
+) {
+    if game_state.game_mode != GameMode::Playing {
+        return;
+    }
+
+    for (transform, mut drip) in &mut drip_query {
+        drip.interval.tick(time.delta());
+        if drip.interval.just_finished() {
+            commands.spawn((
+                DripParticle {
+                    extinguish_chance: drip.extinguish_chance,
+                    lifetime: Timer::from_seconds(DRIP_LIFETIME, TimerMode::Once),
+                },
+                Sprite {
+                    color: Color::srgba(0.6, 0.75, 1.0, 0.8),
+                    custom_size: Some(Vec2::new(2.0, 5.0)),
+                    ..default()
+                },
+                Transform::from_translation(transform.translation),
+            ));
+        }
+    }
+}
+
+/// System that moves water drops and resolves hits on the candle flame
+///
+/// # Behavior
+/// For each `DripParticle`:
+/// 1. Falls at `DRIP_FALL_SPEED` and despawns when its lifetime ends
+/// 2. If it reaches the flame above a player, the player's lit candle (the
+///    one whose `CarriedCandle` is the player's) sizzles; candles held by
+///    other characters or standing elsewhere are left alone:
+///    - Gains `FlameSputter` (brief visibility shrink)
+///    - Is extinguished with the drop's `extinguish_chance`
+///    - Plays `SoundCue::CandleSizzle` and emits `CandleSizzleEvent`
+/// 3. The drop is despawned on hit
+///
+/// # System Dependencies
+/// - **Upstream**: `water_drip_spawn_system` spawns drops
+/// - **Resources**: Draws the extinguish roll from `GameRng`
+/// - **Downstream**: `candle_burn_system` applies the sputter radius
+#[allow(clippy::too_many_arguments)]
+pub fn drip_particle_system(
+    time: Res<Time>,
+    game_state: Res<GameState>,
+    mut rng: ResMut<GameRng>,
+    mut commands: Commands,
+    mut particle_query: Query<(Entity, &mut Transform, &mut DripParticle)>,
+    player_query: Query<&Transform, (With<Player>, Without<DripParticle>)>,
+    mut candle_query: Query<(Entity, &mut CandleState, &CarriedCandle), With<Candle>>,
+    mut sizzle_events: EventWriter<CandleSizzleEvent>,
+    mut sound_events: EventWriter<PlaySoundEvent>,
+) {
+    if game_state.game_mode != GameMode::Playing {
+        return;
+    }
+
+    for (entity, mut transform, mut particle) in &mut particle_query {
+        transform.translation.y -= DRIP_FALL_SPEED * time.delta_secs();
+
+        let drop = transform.translation.truncate();
+        let hit = player_query.iter().any(|player| {
+            let flame = player.translation.truncate() + Vec2::new(0.0, FLAME_HEIGHT);
+            (drop.x - flame.x).abs() <= DRIP_HIT_HALF_WIDTH
+                && drop.y <= flame.y
+                && drop.y >= player.translation.y
+        });
+
+        if hit {
+            for (candle, mut state, carried) in &mut candle_query {
+                if !carried.is_players() || *state != CandleState::Lit {
+                    continue;
+                }
+
+                let extinguished = rng.random::<f32>() < particle.extinguish_chance;
+                if extinguished {
+                    *state = CandleState::Extinguished;
+                }
+                commands
+                    .entity(candle)
+                    .insert(FlameSputter(Timer::from_seconds(
+                        SPUTTER_DURATION,
+                        TimerMode::Once,
+                    )));
+                sizzle_events.write(CandleSizzleEvent {
+                    candle,
+                    extinguished,
+                });
+                sound_events.write(PlaySoundEvent {
+                    cue: SoundCue::CandleSizzle,
+                });
+            }
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        particle.lifetime.tick(time.delta());
+        if particle.lifetime.finished() {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+/// System that ends the sputtering flame effect once its timer finishes
+pub fn flame_sputter_system(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut FlameSputter)>,
+) {
+    for (entity, mut sputter) in &mut query {
+        sputter.0.tick(time.delta());
+        if sputter.0.finished() {
+            commands.entity(entity).remove::<FlameSputter>();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::lighting::VisibilityRadius;
+
+    fn setup_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_event::<CandleSizzleEvent>();
+        app.add_event::<PlaySoundEvent>();
+        app.insert_resource(GameState {
+            current_room: 0,
+            player_spawn_point: Vec2::ZERO,
+            completion_time: std::time::Duration::ZERO,
+            collected_secrets: std::collections::HashSet::new(),
+            game_mode: GameMode::Playing,
+            deaths: 0,
+        });
+        app.insert_resource(GameRng::from_seed(0));
+        app.add_systems(Update, drip_particle_system);
+        app
+    }
+
+    fn spawn_drop(app: &mut App, position: Vec3, extinguish_chance: f32) -> Entity {
+        app.world_mut()
+            .spawn((
+                DripParticle {
+                    extinguish_chance,
+                    lifetime: Timer::from_seconds(DRIP_LIFETIME, TimerMode::Once),
+                },
+                Transform::from_translation(position),
+            ))
+            .id()
+    }
+
+    #[test]
+    fn drop_on_flame_sputters_candle() {
+        let mut app = setup_app();
+        app.world_mut()
+            .spawn((Player, Transform::from_xyz(50.0, 0.0, 0.0)));
+        let candle = app
+            .world_mut()
+            .spawn((
+                Candle,
+                CandleState::Lit,
+                VisibilityRadius(7.0),
+                CarriedCandle::default(),
+            ))
+            .id();
+        let drop = spawn_drop(&mut app, Vec3::new(50.0, 10.0, 0.0), 0.0);
+
+        app.update();
+
+        assert!(app.world().get::<FlameSputter>(candle).is_some());
+        assert_eq!(
+            *app.world().get::<CandleState>(candle).unwrap(),
+            CandleState::Lit
+        );
+        assert!(app.world().get_entity(drop).is_err(), "Drop should despawn");
+
+        let events = app.world().resource::<Events<PlaySoundEvent>>();
+        assert_eq!(events.len(), 1);
+    }
+
+    #[test]
+    fn drop_only_sizzles_the_players_candle() {
+        let mut app = setup_app();
+        let npc = app.world_mut().spawn(Transform::default()).id();
+        app.world_mut().spawn((Player, Transform::default()));
+        let candle = |app: &mut App, carried: Option<CarriedCandle>| {
+            let mut candle =
+                app.world_mut()
+                    .spawn((Candle, CandleState::Lit, VisibilityRadius(7.0)));
+            if let Some(carried) = carried {
+                candle.insert(carried);
+            }
+            candle.id()
+        };
+        let players = candle(&mut app, Some(CarriedCandle::default()));
+        let npcs = candle(&mut app, Some(CarriedCandle::held_by(npc)));
+        let standing = candle(&mut app, None);
+        spawn_drop(&mut app, Vec3::new(0.0, 5.0, 0.0), 1.0);
+
+        app.update();
+
+        let state = |candle| *app.world().get::<CandleState>(candle).unwrap();
+        assert_eq!(state(players), CandleState::Extinguished);
+        for other in [npcs, standing] {
+            assert_eq!(state(other), CandleState::Lit);
+            assert!(app.world().get::<FlameSputter>(other).is_none());
+        }
+        let events = app.world().resource::<Events<CandleSizzleEvent>>();
+        assert_eq!(events.len(), 1);
+    }
+
+    #[test]
+    fn certain_extinguish_puts_candle_out() {
+        let mut app = setup_app();
+        app.world_mut().spawn((Player, Transform::default()));
+        let candle = app
+            .world_mut()
+            .spawn((
+                Candle,
+                CandleState::Lit,
+                VisibilityRadius(7.0),
+                CarriedCandle::default(),
+            ))
+            .id();
+        spawn_drop(&mut app, Vec3::new(0.0, 5.0, 0.0), 1.0);
+
+        app.update();
+
+        assert_eq!(
+            *app.world().get::<CandleState>(candle).unwrap(),
+            CandleState::Extinguished
+        );
+    }
+
+    #[test]
+    fn drop_beside_player_misses() {
+        let mut app = setup_app();
+        app.world_mut().spawn((Player, Transform::default()));
+        let candle = app
+            .world_mut()
+            .spawn((
+                Candle,
+                CandleState::Lit,
+                VisibilityRadius(7.0),
+                CarriedCandle::default(),
+            ))
+            .id();
+        let drop = spawn_drop(&mut app, Vec3::new(100.0, 5.0, 0.0), 1.0);
+
+        app.update();
+
+        assert!(app.world().get::<FlameSputter>(candle).is_none());
+        assert!(app.world().get_entity(drop).is_ok());
+    }
+
+    #[test]
+    fn unlit_candle_ignores_drops() {
+        let mut app = setup_app();
+        app.world_mut().spawn((Player, Transform::default()));
+        let candle = app
+            .world_mut()
+            .spawn((
+                Candle,
+                CandleState::Unlit,
+                VisibilityRadius(1.5),
+                CarriedCandle::default(),
+            ))
+            .id();
+        spawn_drop(&mut app, Vec3::new(0.0, 5.0, 0.0), 1.0);
+
+        app.update();
+
+        assert_eq!(
+            *app.world().get::<CandleState>(candle).unwrap(),
+            CandleState::Unlit
+        );
+        assert!(app.world().get::<FlameSputter>(candle).is_none());
+    }
+
+    #[test]
+    fn water_drip_spawns_drop_when_interval_finishes() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.insert_resource(GameState {
+            current_room: 0,
+            player_spawn_point: Vec2::ZERO,
+            completion_time: std::time::Duration::ZERO,
+            collected_secrets: std::collections::HashSet::new(),
+            game_mode: GameMode::Playing,
+            deaths: 0,
+        });
+        app.add_systems(Update, water_drip_spawn_system);
+
+        app.world_mut().spawn((
+            WaterDrip::new(0.000_001, 0.5),
+            Transform::from_xyz(0.0, 200.0, 0.0),
+        ));
+
+        // First frame has zero delta; later frames finish the tiny interval
+        for _ in 0..5 {
+            app.update();
+        }
+
+        let mut query = app.world_mut().query::<&DripParticle>();
+        assert!(query.iter(app.world()).count() >= 1);
+    }
+
+    #[test]
+    fn plugin_drips_onto_the_candle_below() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, WaterDripPlugin));
+        app.insert_resource(GameState {
+            game_mode: GameMode::Playing,
+            ..default()
+        });
+
+        app.world_mut().spawn((Player, Transform::default()));
+        let candle = app
+            .world_mut()
+            .spawn((
+                Candle,
+                CandleState::Lit,
+                VisibilityRadius(7.0),
+                CarriedCandle::default(),
+            ))
+            .id();
+        // Releases a drop every frame just above the flame
+        app.world_mut().spawn((
+            WaterDrip::new(0.000_001, 1.0),
+            Transform::from_xyz(0.0, FLAME_HEIGHT, 0.0),
+        ));
+
+        for _ in 0..5 {
+            app.update();
+        }
+
+        assert_eq!(
+            *app.world().get::<CandleState>(candle).unwrap(),
+            CandleState::Extinguished
+        );
+    }
+}
@@ -0,0 +1,141 @@
+use bevy::prelude::*;
+use bevy_egui::{EguiContexts, egui};
+
+use crate::audio::sound_bank::{PlaySoundEvent, SoundCue};
+use crate::systems::achievements::{Achievement, Achievements};
+
+/// Key that opens and closes the achievements list
+pub const ACHIEVEMENTS_KEY: KeyCode = KeyCode::F7;
+
+/// Plugin that registers the achievements list screen
+///
+/// **NOTE**: EguiPlugin and `AchievementsPlugin` must be added to the app
+/// before this plugin.
+pub struct AchievementsScreenPlugin;
+
+impl Plugin for AchievementsScreenPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<AchievementsScreen>()
+            .add_event::<PlaySoundEvent>()
+            .add_systems(
+                Update,
+                (
+                    toggle_achievements_screen_system,
+                    achievements_screen_system,
+                )
+                    .chain(),
+            );
+    }
+}
+
+/// Resource holding whether the achievements list is shown
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct AchievementsScreen {
+    /// Whether the list is shown
+    pub open: bool,
+}
+
+/// System that opens and closes the achievements list with `ACHIEVEMENTS_KEY`
+///
+/// Plays `SoundCue::UiConfirm` when opening and `SoundCue::UiCancel` when closing.
+pub fn toggle_achievements_screen_system(
+    keyboard: Option<Res<ButtonInput<KeyCode>>>,
+    mut screen: ResMut<AchievementsScreen>,
+    mut sound_events: EventWriter<PlaySoundEvent>,
+) {
+    if !keyboard.is_some_and(|keyboard| keyboard.just_pressed(ACHIEVEMENTS_KEY)) {
+        return;
+    }
+
+    screen.open = !screen.open;
+    sound_events.write(PlaySoundEvent {
+        cue: if screen.open {
+            SoundCue::UiConfirm
+        } else {
+            SoundCue::UiCancel
+        },
+    });
+}
+
+/// Draws every achievement, locked ones greyed out
+///
+/// Returns false when the window was closed. Split from
+/// `achievements_screen_system` so the layout can be drawn without a
+/// running app.
+pub fn draw_achievements_screen(ctx: &egui::Context, achievements: &Achievements) -> bool {
+    let mut open = true;
+    egui::Window::new("Achievements")
+        .open(&mut open)
+        .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+        .resizable(false)
+        .collapsible(false)
+        .show(ctx, |ui| {
+            ui.label(format!(
+                "{} / {} unlocked",
+                achievements.unlocked.len(),
+                Achievement::ALL.len()
+            ));
+            ui.separator();
+
+            for achievement in Achievement::ALL {
+                let unlocked = achievements.is_unlocked(achievement);
+                let color = if unlocked {
+                    egui::Color32::from_rgb(255, 220, 150)
+                } else {
+                    egui::Color32::GRAY
+                };
+                ui.label(
+                    egui::RichText::new(achievement.name())
+                        .strong()
+                        .color(color),
+                );
+                ui.label(egui::RichText::new(achievement.description()).color(color));
+                ui.add_space(6.0);
+            }
+        });
+    open
+}
+
+/// System that shows the achievements list while it is open
+///
+/// # System Dependencies
+/// - **Resources**: EguiContexts (from bevy_egui), reads `Achievements`,
+///   writes `AchievementsScreen`
+pub fn achievements_screen_system(
+    mut contexts: EguiContexts,
+    achievements: Res<Achievements>,
+    mut screen: ResMut<AchievementsScreen>,
+) {
+    if !screen.open {
+        return;
+    }
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+
+    if !draw_achievements_screen(ctx, &achievements) {
+        screen.open = false;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn achievements_key_toggles_screen() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.init_resource::<AchievementsScreen>();
+        app.init_resource::<ButtonInput<KeyCode>>();
+        app.add_event::<PlaySoundEvent>();
+        app.add_systems(Update, toggle_achievements_screen_system);
+
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(ACHIEVEMENTS_KEY);
+        app.update();
+
+        assert!(app.world().resource::<AchievementsScreen>().open);
+    }
+}
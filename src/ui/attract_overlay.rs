@@ -0,0 +1,40 @@
+use bevy::prelude::*;
+use bevy_egui::{EguiContexts, egui};
+
+use crate::systems::attract_mode::AttractModeState;
+
+/// System that draws the title art over the attract mode playback
+///
+/// Shows the game title and a "press any key" prompt centered on screen
+/// while `AttractModeState::active` is set.
+///
+/// # System Dependencies
+/// - **Resources**: EguiContexts (from bevy_egui), AttractModeState
+pub fn attract_overlay_system(mut contexts: EguiContexts, state: Res<AttractModeState>) {
+    if !state.active {
+        return;
+    }
+
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+
+    egui::Area::new(egui::Id::new("attract_overlay"))
+        .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+        .interactable(false)
+        .show(ctx, |ui| {
+            ui.vertical_centered(|ui| {
+                ui.heading(
+                    egui::RichText::new("HOUSE ESCAPE")
+                        .size(64.0)
+                        .color(egui::Color32::from_rgb(255, 220, 150)),
+                );
+                ui.add_space(16.0);
+                ui.label(
+                    egui::RichText::new("Press any key")
+                        .size(24.0)
+                        .color(egui::Color32::LIGHT_GRAY),
+                );
+            });
+        });
+}
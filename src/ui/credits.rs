@@ -0,0 +1,446 @@
+use bevy::prelude::*;
+use bevy_egui::{EguiContexts, egui};
+use bevy_kira_audio::prelude::*;
+use serde::Deserialize;
+use std::fs;
+
+use crate::audio::sound_bank::{PlaySoundEvent, SoundCue};
+use crate::resources::game_state::{GameMode, GameState};
+
+/// Default location of the credits content, relative to the working directory
+pub const CREDITS_PATH: &str = "assets/credits/credits.ron";
+
+/// Key that opens the credits from the main menu
+pub const CREDITS_MENU_KEY: KeyCode = KeyCode::KeyC;
+
+/// Scroll speed multiplier while Space is held
+pub const FAST_FORWARD_MULTIPLIER: f32 = 4.0;
+
+/// Smallest and largest scroll speed multipliers selectable with the arrow keys
+pub const SPEED_MULTIPLIER_RANGE: (f32, f32) = (0.25, 3.0);
+
+/// Scroll speed multiplier change per arrow key press
+pub const SPEED_MULTIPLIER_STEP: f32 = 0.25;
+
+/// Height (pixels) at which logos are drawn
+const LOGO_HEIGHT: f32 = 96.0;
+
+/// Plugin that registers the scrolling credits screen
+///
+/// Credits are started from the ending screen after `GameMode::Victory`
+/// (see `ending_screen::EndingScreenPlugin`) and can be opened from the main
+/// menu with `CREDITS_MENU_KEY`.
+///
+/// **NOTE**: EguiPlugin and bevy_kira_audio's `AudioPlugin` must be added
+/// to the app before this plugin.
+pub struct CreditsPlugin;
+
+impl Plugin for CreditsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_audio_channel::<CreditsMusicChannel>()
+            .init_resource::<CreditsData>()
+            .init_resource::<CreditsState>()
+            .add_event::<ShowCreditsEvent>()
+            .add_event::<PlaySoundEvent>()
+            .add_systems(Startup, load_credits_system)
+            .add_systems(
+                Update,
+                (
+                    credits_menu_input_system,
+                    start_credits_system,
+                    credits_controls_system,
+                    credits_ui_system,
+                )
+                    .chain(),
+            );
+    }
+}
+
+/// Audio channel playing the credits music
+#[derive(Resource)]
+pub struct CreditsMusicChannel;
+
+/// One block of the credits
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct CreditsSection {
+    /// Section title (e.g., "Art")
+    pub heading: String,
+    /// People or projects credited in this section
+    #[serde(default)]
+    pub names: Vec<String>,
+    /// Optional logo image path relative to assets/
+    #[serde(default)]
+    pub logo: Option<String>,
+}
+
+/// Resource holding the credits content
+///
+/// Loaded from `assets/credits/credits.ron` at startup.
+#[derive(Resource, Deserialize, Debug, Clone, PartialEq)]
+pub struct CreditsData {
+    /// Base scroll speed in pixels per second
+    pub scroll_speed: f32,
+    /// Optional music path relative to assets/, looped while credits play
+    #[serde(default)]
+    pub music: Option<String>,
+    /// Sections in scroll order
+    pub sections: Vec<CreditsSection>,
+}
+
+impl Default for CreditsData {
+    fn default() -> Self {
+        Self {
+            scroll_speed: 40.0,
+            music: None,
+            sections: vec![CreditsSection {
+                heading: "House Escape".to_string(),
+                names: vec![],
+                logo: None,
+            }],
+        }
+    }
+}
+
+/// Load the credits content from a RON file
+///
+/// # Arguments
+/// * `path` - Path to the RON file (e.g., "assets/credits/credits.ron")
+///
+/// # Errors
+/// Returns error string if the file cannot be read or parsed
+pub fn load_credits(path: &str) -> Result<CreditsData, String> {
+    let content = fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read credits '{}': {}", path, e))?;
+
+    ron::from_str(&content).map_err(|e| format!("Failed to parse RON from '{}': {}", path, e))
+}
+
+/// Event requesting that the credits start playing
+#[derive(Event, Debug, Default)]
+pub struct ShowCreditsEvent;
+
+/// Resource tracking the credits playback
+#[derive(Resource, Debug)]
+pub struct CreditsState {
+    /// Whether the credits are showing
+    pub active: bool,
+    /// Distance (pixels) scrolled so far
+    pub offset: f32,
+    /// Player-selected scroll speed multiplier
+    pub speed_multiplier: f32,
+    /// Height (pixels) of the rendered content, measured by the UI
+    pub content_height: f32,
+    /// Height (pixels) of the screen the credits scroll across, measured by the UI
+    pub viewport_height: f32,
+    /// Logo images, in section order (`None` for sections without a logo)
+    pub logos: Vec<Option<Handle<Image>>>,
+}
+
+impl Default for CreditsState {
+    fn default() -> Self {
+        Self {
+            active: false,
+            offset: 0.0,
+            speed_multiplier: 1.0,
+            content_height: 0.0,
+            viewport_height: 0.0,
+            logos: Vec::new(),
+        }
+    }
+}
+
+/// Startup system that loads the credits content from disk
+///
+/// Falls back to a title-only credits roll if the file is missing or malformed.
+pub fn load_credits_system(mut commands: Commands) {
+    match load_credits(CREDITS_PATH) {
+        Ok(credits) => {
+            info!("Loaded credits with {} sections", credits.sections.len());
+            commands.insert_resource(credits);
+        }
+        Err(e) => {
+            warn!("{}. Using default credits.", e);
+            commands.insert_resource(CreditsData::default());
+        }
+    }
+}
+
+/// System that opens the credits from the main menu with `CREDITS_MENU_KEY`
+pub fn credits_menu_input_system(
+    keyboard: Option<Res<ButtonInput<KeyCode>>>,
+    game_state: Res<GameState>,
+    state: Res<CreditsState>,
+    mut events: EventWriter<ShowCreditsEvent>,
+    mut sound_events: EventWriter<PlaySoundEvent>,
+) {
+    let Some(keyboard) = keyboard else {
+        return;
+    };
+
+    if game_state.game_mode == GameMode::Menu
+        && !state.active
+        && keyboard.just_pressed(CREDITS_MENU_KEY)
+    {
+        events.write(ShowCreditsEvent);
+        sound_events.write(PlaySoundEvent {
+            cue: SoundCue::UiConfirm,
+        });
+    }
+}
+
+/// System that starts the credits roll on `ShowCreditsEvent`
+///
+/// Resets the scroll position, loads section logos and starts the looping
+/// credits music.
+pub fn start_credits_system(
+    mut events: EventReader<ShowCreditsEvent>,
+    mut state: ResMut<CreditsState>,
+    credits: Res<CreditsData>,
+    asset_server: Res<AssetServer>,
+    music: Res<AudioChannel<CreditsMusicChannel>>,
+) {
+    if events.read().count() == 0 || state.active {
+        return;
+    }
+
+    state.active = true;
+    state.offset = 0.0;
+    state.speed_multiplier = 1.0;
+    state.content_height = 0.0;
+    state.logos = credits
+        .sections
+        .iter()
+        .map(|section| section.logo.as_ref().map(|path| asset_server.load(path)))
+        .collect();
+
+    if let Some(path) = &credits.music {
+        music.play(asset_server.load(path)).looped();
+    }
+}
+
+/// Stops the credits and returns to the main menu
+fn end_credits(
+    state: &mut CreditsState,
+    game_state: &mut GameState,
+    music: &AudioChannel<CreditsMusicChannel>,
+) {
+    state.active = false;
+    state.logos.clear();
+    game_state.game_mode = GameMode::Menu;
+    music.stop();
+}
+
+/// System that scrolls the credits and handles skip/speed controls
+///
+/// # Controls
+/// - **Arrow Up/Down**: Faster/slower scrolling
+/// - **Space (hold)**: Fast-forward
+/// - **Escape/Enter**: Skip
+///
+/// Speed changes play `SoundCue::UiMove` (`SoundCue::UiError` at the limits)
+/// and skipping plays `SoundCue::UiCancel`.
+///
+/// The credits end on their own once the content has scrolled off screen,
+/// returning the game to `GameMode::Menu`.
+pub fn credits_controls_system(
+    time: Res<Time>,
+    keyboard: Option<Res<ButtonInput<KeyCode>>>,
+    credits: Res<CreditsData>,
+    mut state: ResMut<CreditsState>,
+    mut game_state: ResMut<GameState>,
+    music: Res<AudioChannel<CreditsMusicChannel>>,
+    mut sound_events: EventWriter<PlaySoundEvent>,
+) {
+    if !state.active {
+        return;
+    }
+
+    let mut multiplier = state.speed_multiplier;
+    if let Some(keyboard) = &keyboard {
+        if keyboard.any_just_pressed([KeyCode::Escape, KeyCode::Enter]) {
+            end_credits(&mut state, &mut game_state, &music);
+            sound_events.write(PlaySoundEvent {
+                cue: SoundCue::UiCancel,
+            });
+            return;
+        }
+
+        let (min, max) = SPEED_MULTIPLIER_RANGE;
+        let previous = state.speed_multiplier;
+        if keyboard.just_pressed(KeyCode::ArrowUp) {
+            state.speed_multiplier = (state.speed_multiplier + SPEED_MULTIPLIER_STEP).min(max);
+        }
+        if keyboard.just_pressed(KeyCode::ArrowDown) {
+            state.speed_multiplier = (state.speed_multiplier - SPEED_MULTIPLIER_STEP).max(min);
+        }
+        if keyboard.any_just_pressed([KeyCode::ArrowUp, KeyCode::ArrowDown]) {
+            sound_events.write(PlaySoundEvent {
+                cue: if state.speed_multiplier != previous {
+                    SoundCue::UiMove
+                } else {
+                    SoundCue::UiError
+                },
+            });
+        }
+        multiplier = state.speed_multiplier;
+        if keyboard.pressed(KeyCode::Space) {
+            multiplier *= FAST_FORWARD_MULTIPLIER;
+        }
+    }
+
+    state.offset += credits.scroll_speed * multiplier * time.delta_secs();
+
+    // Content starts below the screen, so it is gone after scrolling its
+    // own height plus a screen's worth (heights are 0 until measured)
+    if state.content_height > 0.0 && state.offset > state.content_height + state.viewport_height {
+        end_credits(&mut state, &mut game_state, &music);
+    }
+}
+
+/// System that renders the scrolling credits
+///
+/// Draws a black backdrop and the credits sections centered horizontally,
+/// starting below the screen and moving up by `CreditsState::offset`.
+///
+/// # System Dependencies
+/// - **Resources**: EguiContexts (from bevy_egui), CreditsData, CreditsState
+pub fn credits_ui_system(
+    mut contexts: EguiContexts,
+    credits: Res<CreditsData>,
+    mut state: ResMut<CreditsState>,
+) {
+    if !state.active {
+        return;
+    }
+
+    let logos: Vec<Option<egui::TextureId>> = state
+        .logos
+        .iter()
+        .map(|logo| {
+            logo.as_ref()
+                .map(|handle| contexts.add_image(handle.clone()))
+        })
+        .collect();
+
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+
+    let screen = ctx.screen_rect();
+    egui::Area::new(egui::Id::new("credits_backdrop"))
+        .fixed_pos(screen.min)
+        .order(egui::Order::Background)
+        .interactable(false)
+        .show(ctx, |ui| {
+            ui.painter().rect_filled(screen, 0.0, egui::Color32::BLACK);
+        });
+
+    let top = screen.max.y - state.offset;
+    let response = egui::Area::new(egui::Id::new("credits_scroll"))
+        .fixed_pos([screen.min.x, top])
+        .interactable(false)
+        .show(ctx, |ui| {
+            ui.set_width(screen.width());
+            ui.vertical_centered(|ui| {
+                for (section, logo) in credits.sections.iter().zip(&logos) {
+                    if let Some(texture) = logo {
+                        ui.image(egui::load::SizedTexture::new(
+                            *texture,
+                            [LOGO_HEIGHT, LOGO_HEIGHT],
+                        ));
+                        ui.add_space(8.0);
+                    }
+                    ui.label(
+                        egui::RichText::new(&section.heading)
+                            .size(32.0)
+                            .color(egui::Color32::from_rgb(255, 220, 150)),
+                    );
+                    ui.add_space(8.0);
+                    for name in &section.names {
+                        ui.label(
+                            egui::RichText::new(name)
+                                .size(22.0)
+                                .color(egui::Color32::LIGHT_GRAY),
+                        );
+                    }
+                    ui.add_space(48.0);
+                }
+            });
+        });
+
+    state.content_height = response.response.rect.height();
+    state.viewport_height = screen.height();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn setup_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_audio_channel::<CreditsMusicChannel>();
+        app.init_resource::<GameState>();
+        app.init_resource::<CreditsData>();
+        app.init_resource::<CreditsState>();
+        app.init_resource::<ButtonInput<KeyCode>>();
+        app.add_event::<PlaySoundEvent>();
+        app.add_systems(Update, credits_controls_system);
+        app
+    }
+
+    #[test]
+    fn credits_file_parses() {
+        let credits = load_credits(CREDITS_PATH).expect("credits should parse");
+        assert!(credits.scroll_speed > 0.0);
+        assert!(!credits.sections.is_empty());
+        assert!(
+            credits
+                .sections
+                .iter()
+                .any(|section| section.logo.is_some())
+        );
+    }
+
+    #[test]
+    fn credits_scroll_and_speed_controls() {
+        let mut app = setup_app();
+        app.world_mut().resource_mut::<CreditsState>().active = true;
+
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::ArrowUp);
+        app.update();
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .clear();
+        app.update();
+
+        let state = app.world().resource::<CreditsState>();
+        assert_eq!(state.speed_multiplier, 1.0 + SPEED_MULTIPLIER_STEP);
+        assert!(state.offset > 0.0);
+
+        let events = app.world().resource::<Events<PlaySoundEvent>>();
+        let mut cursor = events.get_cursor();
+        let cues: Vec<SoundCue> = cursor.read(events).map(|event| event.cue).collect();
+        assert_eq!(cues, vec![SoundCue::UiMove]);
+    }
+
+    #[test]
+    fn skip_ends_credits_and_returns_to_menu() {
+        let mut app = setup_app();
+        app.world_mut().resource_mut::<CreditsState>().active = true;
+        app.world_mut().resource_mut::<GameState>().game_mode = GameMode::Victory;
+
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::Escape);
+        app.update();
+
+        assert!(!app.world().resource::<CreditsState>().active);
+        assert_eq!(
+            app.world().resource::<GameState>().game_mode,
+            GameMode::Menu
+        );
+    }
+}
@@ -0,0 +1,580 @@
+use std::collections::BTreeMap;
+
+use bevy::ecs::system::SystemState;
+use bevy::prelude::*;
+use bevy_egui::{EguiContexts, egui};
+
+use crate::components::inventory::{
+    Inventory, Item, KeyType, OIL_CAN_CHARGES, PuzzleItemType, ToolType,
+};
+use crate::components::lighting::{CandleWax, CarriedCandle, FULL_CANDLE_WAX};
+use crate::components::player::Player;
+use crate::components::puzzle::{PuzzleReward, PuzzleState};
+use crate::resources::input_context::{InputContext, InputContextStack};
+use crate::systems::debug_draw::{DebugCategory, DebugDraw};
+use crate::systems::game_set::GameSet;
+use crate::systems::level_loader::{LevelRegistry, RoomLevels};
+use crate::systems::puzzle::PuzzleSolvedEvent;
+use crate::systems::room_transition::{FadePhase, RoomFade};
+use crate::ui::perf_overlay::PerfOverlay;
+
+/// Key that opens and closes the developer console
+pub const DEV_CONSOLE_KEY: KeyCode = KeyCode::Backquote;
+
+/// Most output lines the console keeps
+pub const MAX_CONSOLE_LINES: usize = 200;
+
+/// Plugin that adds the drop-down developer console
+///
+/// Only built with the `dev-tools` feature. While the console is open it
+/// holds `InputContext::Console`, so typing doesn't move the player.
+/// Commands come from the `ConsoleCommands` registry.
+///
+/// **NOTE**: EguiPlugin must be added to the app before this plugin.
+pub struct DevConsolePlugin;
+
+impl Plugin for DevConsolePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DevConsole>()
+            .init_resource::<ConsoleCommands>()
+            .init_resource::<InputContextStack>()
+            .add_systems(
+                Update,
+                (
+                    toggle_dev_console_system,
+                    dev_console_system,
+                    run_console_commands_system,
+                )
+                    .chain()
+                    .in_set(GameSet::Input),
+            );
+    }
+}
+
+/// Function running a console command
+///
+/// Gets the world and the words after the command name, and returns the
+/// line to print or an error message.
+pub type ConsoleCommandFn = fn(&mut World, &[&str]) -> Result<String, String>;
+
+/// A command the console can run
+#[derive(Debug, Clone, Copy)]
+pub struct ConsoleCommand {
+    /// Arguments the command takes, shown by `help` (e.g. "<room id>")
+    pub usage: &'static str,
+    /// Runs the command
+    pub run: ConsoleCommandFn,
+}
+
+/// Resource mapping command names to the functions running them
+///
+/// Starts with the built-in commands (`help`, `give`, `teleport`, `set`,
+/// `solve` and `toggle`). Other modules add their own from their plugin:
+///
+/// # Examples
+/// ```ignore
+/// app.init_resource::<ConsoleCommands>();
+/// app.world_mut()
+///     .resource_mut::<ConsoleCommands>()
+///     .register("heal", "", |world, _| Ok("Healed".to_string()));
+/// ```
+#[derive(Resource, Debug)]
+pub struct ConsoleCommands {
+    commands: BTreeMap<&'static str, ConsoleCommand>,
+}
+
+impl Default for ConsoleCommands {
+    fn default() -> Self {
+        let mut commands = Self {
+            commands: BTreeMap::new(),
+        };
+        commands.register("help", "", help_command);
+        commands.register("give", "<item> [kind]", give_command);
+        commands.register("teleport", "<room id>", teleport_command);
+        commands.register("set", "wax <amount>", set_command);
+        commands.register("solve", "nearest", solve_command);
+        commands.register("toggle", "<category>-debug | perf-overlay", toggle_command);
+        commands
+    }
+}
+
+impl ConsoleCommands {
+    /// Adds `name`, replacing any command already registered under it
+    pub fn register(&mut self, name: &'static str, usage: &'static str, run: ConsoleCommandFn) {
+        self.commands.insert(name, ConsoleCommand { usage, run });
+    }
+
+    /// Looks up the command called `name`
+    pub fn get(&self, name: &str) -> Option<ConsoleCommand> {
+        self.commands.get(name).copied()
+    }
+
+    /// Registered commands, sorted by name
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, &ConsoleCommand)> {
+        self.commands.iter().map(|(name, command)| (*name, command))
+    }
+}
+
+/// Resource holding the console's open state, input line and output
+#[derive(Resource, Debug, Default)]
+pub struct DevConsole {
+    /// Whether the console is shown
+    pub open: bool,
+    /// Line being typed
+    pub input: String,
+    /// Printed lines, oldest first (at most `MAX_CONSOLE_LINES`)
+    pub log: Vec<String>,
+    /// Lines submitted but not run yet
+    pub submitted: Vec<String>,
+    /// Whether the input line took keyboard focus since the console opened
+    focused: bool,
+}
+
+impl DevConsole {
+    /// Appends `line` to the output, dropping the oldest past the limit
+    pub fn print(&mut self, line: impl Into<String>) {
+        self.log.push(line.into());
+        if self.log.len() > MAX_CONSOLE_LINES {
+            let excess = self.log.len() - MAX_CONSOLE_LINES;
+            self.log.drain(..excess);
+        }
+    }
+}
+
+/// Runs one console line against `world`
+///
+/// The first word names the command and the rest are its arguments.
+///
+/// # Errors
+/// Returns the command's error, or an error for an unknown command
+pub fn run_console_command(world: &mut World, line: &str) -> Result<String, String> {
+    let words: Vec<&str> = line.split_whitespace().collect();
+    let Some((name, args)) = words.split_first() else {
+        return Ok(String::new());
+    };
+
+    let command = world
+        .get_resource::<ConsoleCommands>()
+        .and_then(|commands| commands.get(name))
+        .ok_or_else(|| format!("Unknown command '{}' (try 'help')", name))?;
+    (command.run)(world, args)
+}
+
+/// Lists every registered command with its usage
+fn help_command(world: &mut World, _args: &[&str]) -> Result<String, String> {
+    let commands = world.resource::<ConsoleCommands>();
+    Ok(commands
+        .iter()
+        .map(|(name, command)| format!("{} {}", name, command.usage).trim_end().to_string())
+        .collect::<Vec<_>>()
+        .join("\n"))
+}
+
+/// Parses the item named by `give`'s arguments (e.g. "key brass")
+fn parse_item(args: &[&str]) -> Result<Item, String> {
+    let kind = |options: &str| format!("Expected one of: {}", options);
+    match args {
+        ["match"] => Ok(Item::Match),
+        ["key", key] => match *key {
+            "brass" => Ok(Item::Key(KeyType::Brass)),
+            "iron" => Ok(Item::Key(KeyType::Iron)),
+            "ornate" => Ok(Item::Key(KeyType::Ornate)),
+            "master" => Ok(Item::Key(KeyType::Master)),
+            _ => Err(kind("brass, iron, ornate, master")),
+        },
+        ["tool", tool] => match *tool {
+            "wrench" => Ok(Item::Tool(ToolType::Wrench)),
+            "crowbar" => Ok(Item::Tool(ToolType::Crowbar)),
+            "wire-cutters" => Ok(Item::Tool(ToolType::WireCutters)),
+            "magnet" => Ok(Item::Tool(ToolType::Magnet)),
+            "oil-can" => Ok(Item::Tool(ToolType::OilCan(OIL_CAN_CHARGES))),
+            "ladder" => Ok(Item::Tool(ToolType::Ladder)),
+            "gas-mask" => Ok(Item::Tool(ToolType::GasMask)),
+            "grappling-rope" => Ok(Item::Tool(ToolType::GrapplingRope)),
+            _ => Err(kind(
+                "wrench, crowbar, wire-cutters, magnet, oil-can, ladder, gas-mask, grappling-rope",
+            )),
+        },
+        ["fuse"] => Ok(Item::PuzzleItem(PuzzleItemType::Fuse)),
+        ["circuit-component"] => Ok(Item::PuzzleItem(PuzzleItemType::CircuitComponent)),
+        ["double-jump"] => Ok(Item::DoubleJumpItem),
+        ["page", page] => page
+            .parse()
+            .map(Item::DiaryPage)
+            .map_err(|_| format!("'{}' is not a page number", page)),
+        _ => Err(kind(
+            "match, key <kind>, tool <kind>, fuse, circuit-component, double-jump, page <n>",
+        )),
+    }
+}
+
+/// Puts an item straight into the player's inventory, ignoring capacity
+fn give_command(world: &mut World, args: &[&str]) -> Result<String, String> {
+    let item = parse_item(args)?;
+    let mut inventories = world.query_filtered::<&mut Inventory, With<Player>>();
+    let mut inventory = inventories
+        .single_mut(world)
+        .map_err(|_| "No player inventory".to_string())?;
+    inventory.items.push(item);
+    Ok(format!("Gave {}", args.join(" ")))
+}
+
+/// Fades to the given room, as if the player walked through a door to it
+fn teleport_command(world: &mut World, args: &[&str]) -> Result<String, String> {
+    let [room] = args else {
+        return Err("Usage: teleport <room id>".to_string());
+    };
+    let room: usize = room
+        .parse()
+        .map_err(|_| format!("'{}' is not a room id", room))?;
+    world.init_resource::<LevelRegistry>();
+    let mut levels = SystemState::<RoomLevels>::new(world);
+    if levels.get(world).load(room)?.is_none() {
+        return Err(format!("Room {} has no level", room));
+    }
+
+    let mut fade = world
+        .get_resource_mut::<RoomFade>()
+        .ok_or_else(|| "Room transitions aren't running".to_string())?;
+    if fade.phase != FadePhase::Idle {
+        return Err("A room transition is already running".to_string());
+    }
+    fade.start(room);
+    Ok(format!("Teleporting to room {}", room))
+}
+
+/// Sets a value on the player; only their carried candle's wax for now
+fn set_command(world: &mut World, args: &[&str]) -> Result<String, String> {
+    let ["wax", amount] = args else {
+        return Err("Usage: set wax <amount>".to_string());
+    };
+    let amount: f32 = amount
+        .parse()
+        .map_err(|_| format!("'{}' is not a number", amount))?;
+    let amount = amount.clamp(0.0, FULL_CANDLE_WAX);
+
+    let mut candles = world.query::<(&mut CandleWax, &CarriedCandle)>();
+    let mut count = 0;
+    for (mut wax, carried) in candles.iter_mut(world) {
+        if carried.holder.is_none() {
+            wax.0 = amount;
+            count += 1;
+        }
+    }
+    if count == 0 {
+        return Err("No carried candle".to_string());
+    }
+    Ok(format!("Set wax to {}", amount))
+}
+
+/// Solves the unsolved puzzle closest to the player and grants its reward
+fn solve_command(world: &mut World, args: &[&str]) -> Result<String, String> {
+    let ["nearest"] = args else {
+        return Err("Usage: solve nearest".to_string());
+    };
+    let mut players = world.query_filtered::<&Transform, With<Player>>();
+    let player = players
+        .single(world)
+        .map_err(|_| "No player".to_string())?
+        .translation
+        .truncate();
+
+    let mut puzzles = world.query::<(Entity, &PuzzleState, &PuzzleReward, &Transform)>();
+    let (puzzle, reward) = puzzles
+        .iter(world)
+        .filter(|(_, state, _, _)| **state != PuzzleState::Solved)
+        .min_by(|(_, _, _, a), (_, _, _, b)| {
+            let a = a.translation.truncate().distance_squared(player);
+            let b = b.translation.truncate().distance_squared(player);
+            a.total_cmp(&b)
+        })
+        .map(|(entity, _, reward, _)| (entity, reward.clone()))
+        .ok_or_else(|| "No unsolved puzzle".to_string())?;
+
+    if let Some(mut state) = world.get_mut::<PuzzleState>(puzzle) {
+        *state = PuzzleState::Solved;
+    }
+    world.send_event(PuzzleSolvedEvent { puzzle, reward });
+    Ok(format!("Solved puzzle {}", puzzle))
+}
+
+/// Flips a debug view on or off
+///
+/// `<category>-debug` toggles a `DebugDraw` category (e.g. collision-debug)
+/// and `perf-overlay` the perf overlay.
+fn toggle_command(world: &mut World, args: &[&str]) -> Result<String, String> {
+    let usage = || {
+        let categories: Vec<String> = DebugCategory::ALL
+            .into_iter()
+            .map(|category| format!("{}-debug", category.name()))
+            .collect();
+        format!("Usage: toggle {} | perf-overlay", categories.join(" | "))
+    };
+    let [view] = args else {
+        return Err(usage());
+    };
+
+    let shown = if *view == "perf-overlay" {
+        let mut overlay = world.get_resource_or_init::<PerfOverlay>();
+        overlay.visible = !overlay.visible;
+        overlay.visible
+    } else {
+        let category = view
+            .strip_suffix("-debug")
+            .and_then(DebugCategory::from_name)
+            .ok_or_else(usage)?;
+        world.get_resource_or_init::<DebugDraw>().toggle(category)
+    };
+    Ok(format!("{} {}", view, if shown { "on" } else { "off" }))
+}
+
+/// System that opens and closes the console with `DEV_CONSOLE_KEY`
+///
+/// # System Dependencies
+/// - **Resources**: Reads `ButtonInput<KeyCode>` (when present); writes
+///   `DevConsole` and `InputContextStack`
+/// - **Downstream**: `dev_console_system` draws the open console
+///
+/// # Behavior
+/// Escape also closes the console. `InputContext::Console` is pushed while
+/// it is open, so movement and interaction ignore what is typed.
+pub fn toggle_dev_console_system(
+    keyboard: Option<Res<ButtonInput<KeyCode>>>,
+    mut console: ResMut<DevConsole>,
+    mut input: ResMut<InputContextStack>,
+) {
+    if let Some(keyboard) = keyboard {
+        if keyboard.just_pressed(DEV_CONSOLE_KEY) {
+            console.open = !console.open;
+            console.focused = false;
+        } else if console.open && keyboard.just_pressed(KeyCode::Escape) {
+            console.open = false;
+        }
+    }
+    input.set(InputContext::Console, console.open);
+}
+
+/// Draws the console's output and input line across the top of the screen
+///
+/// Split from `dev_console_system` so the layout can be drawn without a
+/// running app.
+///
+/// # Returns
+/// The line submitted with Enter, if any
+pub fn draw_dev_console(ctx: &egui::Context, console: &mut DevConsole) -> Option<String> {
+    let mut submitted = None;
+    egui::TopBottomPanel::top("dev_console")
+        .frame(
+            egui::Frame::new()
+                .fill(egui::Color32::from_black_alpha(220))
+                .inner_margin(8.0),
+        )
+        .show(ctx, |ui| {
+            egui::ScrollArea::vertical()
+                .max_height(240.0)
+                .stick_to_bottom(true)
+                .show(ui, |ui| {
+                    for line in &console.log {
+                        ui.monospace(line);
+                    }
+                });
+
+            // The toggle key's character reaches the field on the frame it opens
+            console.input.retain(|c| c != '`');
+            let response = ui.add(
+                egui::TextEdit::singleline(&mut console.input)
+                    .font(egui::TextStyle::Monospace)
+                    .desired_width(f32::INFINITY),
+            );
+            if !console.focused {
+                response.request_focus();
+                console.focused = true;
+            }
+            if response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter)) {
+                submitted = Some(std::mem::take(&mut console.input));
+                response.request_focus();
+            }
+        });
+    submitted
+}
+
+/// System that draws the open console and queues submitted lines
+///
+/// # System Dependencies
+/// - **Resources**: EguiContexts (from bevy_egui), writes `DevConsole`
+/// - **Upstream**: `toggle_dev_console_system` opens the console
+/// - **Downstream**: `run_console_commands_system` runs the queued lines
+pub fn dev_console_system(mut contexts: EguiContexts, mut console: ResMut<DevConsole>) {
+    if !console.open {
+        return;
+    }
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+
+    if let Some(line) = draw_dev_console(ctx, &mut console) {
+        console.submitted.push(line);
+    }
+}
+
+/// System that runs the lines submitted to the console
+///
+/// Exclusive, as commands may touch anything in the world.
+///
+/// # System Dependencies
+/// - **Upstream**: `dev_console_system` queues lines in `DevConsole`
+/// - **Resources**: Reads `ConsoleCommands`, writes `DevConsole`
+///
+/// # Behavior
+/// Each line is echoed with a "> " prefix, followed by the command's output
+/// or its error.
+pub fn run_console_commands_system(world: &mut World) {
+    let lines = std::mem::take(&mut world.resource_mut::<DevConsole>().submitted);
+    for line in lines {
+        let result = run_console_command(world, &line);
+        let mut console = world.resource_mut::<DevConsole>();
+        console.print(format!("> {}", line));
+        match result {
+            Ok(output) if output.is_empty() => {}
+            Ok(output) => output.lines().for_each(|output| console.print(output)),
+            Err(error) => console.print(format!("Error: {}", error)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn console_world() -> World {
+        let mut world = World::new();
+        world.init_resource::<ConsoleCommands>();
+        world.spawn((
+            Player,
+            Transform::default(),
+            Inventory {
+                items: vec![],
+                max_capacity: 10,
+            },
+        ));
+        world
+    }
+
+    #[test]
+    fn give_puts_the_named_item_in_the_inventory() {
+        let mut world = console_world();
+        run_console_command(&mut world, "give key brass").unwrap();
+        run_console_command(&mut world, "give  page 3").unwrap();
+        assert!(run_console_command(&mut world, "give key wooden").is_err());
+
+        let mut inventories = world.query::<&Inventory>();
+        assert!(matches!(
+            inventories.single(&world).unwrap().items[..],
+            [Item::Key(KeyType::Brass), Item::DiaryPage(3)]
+        ));
+    }
+
+    #[test]
+    fn set_wax_clamps_to_a_full_candle() {
+        let mut world = console_world();
+        let candle = world
+            .spawn((
+                CandleWax(10.0),
+                CarriedCandle {
+                    offset: Vec2::ZERO,
+                    facing_left: false,
+                    holder: None,
+                },
+            ))
+            .id();
+
+        run_console_command(&mut world, "set wax 50").unwrap();
+        assert_eq!(world.get::<CandleWax>(candle).unwrap().0, 50.0);
+        run_console_command(&mut world, "set wax 500").unwrap();
+        assert_eq!(world.get::<CandleWax>(candle).unwrap().0, FULL_CANDLE_WAX);
+        assert!(run_console_command(&mut world, "set wax lots").is_err());
+    }
+
+    #[test]
+    fn solve_nearest_picks_the_closest_unsolved_puzzle() {
+        let mut world = console_world();
+        world.init_resource::<Events<PuzzleSolvedEvent>>();
+        let far = world
+            .spawn((
+                PuzzleState::Unsolved,
+                PuzzleReward::UnlockDoor(1),
+                Transform::from_xyz(300.0, 0.0, 0.0),
+            ))
+            .id();
+        world.spawn((
+            PuzzleState::Solved,
+            PuzzleReward::UnlockDoor(2),
+            Transform::from_xyz(10.0, 0.0, 0.0),
+        ));
+        let near = world
+            .spawn((
+                PuzzleState::InProgress,
+                PuzzleReward::UnlockDoor(3),
+                Transform::from_xyz(-50.0, 0.0, 0.0),
+            ))
+            .id();
+
+        run_console_command(&mut world, "solve nearest").unwrap();
+        assert_eq!(world.get::<PuzzleState>(near), Some(&PuzzleState::Solved));
+        assert_eq!(world.get::<PuzzleState>(far), Some(&PuzzleState::Unsolved));
+        assert_eq!(world.resource::<Events<PuzzleSolvedEvent>>().len(), 1);
+    }
+
+    #[test]
+    fn other_modules_can_register_commands() {
+        let mut world = console_world();
+        assert!(run_console_command(&mut world, "ping").is_err());
+
+        world
+            .resource_mut::<ConsoleCommands>()
+            .register("ping", "", |_, args| Ok(format!("pong {}", args.join(" "))));
+        assert_eq!(
+            run_console_command(&mut world, "ping a b").as_deref(),
+            Ok("pong a b")
+        );
+        assert!(
+            run_console_command(&mut world, "help")
+                .unwrap()
+                .contains("ping")
+        );
+    }
+
+    #[test]
+    fn console_key_opens_console_and_runs_submitted_lines() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<DevConsole>()
+            .init_resource::<ConsoleCommands>()
+            .init_resource::<InputContextStack>()
+            .init_resource::<ButtonInput<KeyCode>>()
+            .add_systems(
+                Update,
+                (toggle_dev_console_system, run_console_commands_system).chain(),
+            );
+
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(DEV_CONSOLE_KEY);
+        app.world_mut()
+            .resource_mut::<DevConsole>()
+            .submitted
+            .push("toggle collision-debug".to_string());
+        app.update();
+
+        assert!(app.world().resource::<DevConsole>().open);
+        assert_eq!(
+            app.world().resource::<InputContextStack>().top(),
+            InputContext::Console
+        );
+        assert!(app.world().resource::<DebugDraw>().colliders);
+        assert_eq!(
+            app.world().resource::<DevConsole>().log,
+            vec!["> toggle collision-debug", "collision-debug on"]
+        );
+    }
+}
@@ -0,0 +1,156 @@
+use bevy::prelude::*;
+use bevy_egui::{EguiContexts, egui};
+
+use crate::audio::sound_bank::{PlaySoundEvent, SoundCue};
+use crate::systems::dialogue::{
+    AdvanceDialogueEvent, Dialogue, DialogueLine, DialogueScripts, revealed_text,
+};
+
+/// Width (points) of the dialogue box
+pub const DIALOGUE_BOX_WIDTH: f32 = 560.0;
+
+/// Plugin that draws the typewriter dialogue box
+///
+/// **NOTE**: EguiPlugin and `DialoguePlugin` must be added to the app
+/// before this plugin.
+pub struct DialogueBoxPlugin;
+
+impl Plugin for DialogueBoxPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<AdvanceDialogueEvent>()
+            .add_event::<PlaySoundEvent>()
+            .add_systems(Update, dialogue_box_system);
+    }
+}
+
+/// Draws `line` with its first `revealed` characters typed out
+///
+/// Choices are offered once the whole line is shown; until then, and for
+/// lines without choices, a Continue button advances. Split from
+/// `dialogue_box_system` so the layout can be drawn without a running app.
+///
+/// # Returns
+/// The advance the player clicked, if any
+pub fn draw_dialogue_box(
+    ctx: &egui::Context,
+    line: &DialogueLine,
+    revealed: usize,
+) -> Option<AdvanceDialogueEvent> {
+    let typed = revealed_text(line, revealed);
+    let complete = typed.len() == line.text.len();
+    let mut advance = None;
+
+    egui::Window::new("Dialogue")
+        .title_bar(false)
+        .anchor(egui::Align2::CENTER_BOTTOM, [0.0, -24.0])
+        .resizable(false)
+        .show(ctx, |ui| {
+            ui.set_width(DIALOGUE_BOX_WIDTH);
+            if let Some(speaker) = &line.speaker {
+                ui.label(
+                    egui::RichText::new(speaker)
+                        .strong()
+                        .color(egui::Color32::from_rgb(255, 220, 150)),
+                );
+            }
+            // Lay the full text out invisibly so the box doesn't grow as
+            // the line types out
+            let height = ui
+                .painter()
+                .layout(
+                    line.text.clone(),
+                    egui::TextStyle::Body.resolve(ui.style()),
+                    ui.visuals().text_color(),
+                    DIALOGUE_BOX_WIDTH,
+                )
+                .size()
+                .y;
+            ui.allocate_ui(egui::vec2(DIALOGUE_BOX_WIDTH, height), |ui| {
+                ui.label(typed);
+            });
+            ui.add_space(6.0);
+
+            ui.horizontal(|ui| {
+                if complete && !line.choices.is_empty() {
+                    for (index, choice) in line.choices.iter().enumerate() {
+                        if ui.button(&choice.text).clicked() {
+                            advance = Some(AdvanceDialogueEvent {
+                                choice: Some(index),
+                            });
+                        }
+                    }
+                } else if ui.button("Continue [F]").clicked() {
+                    advance = Some(AdvanceDialogueEvent { choice: None });
+                }
+            });
+        });
+
+    advance
+}
+
+/// System that shows the dialogue box while a script is playing
+///
+/// # System Dependencies
+/// - **Resources**: EguiContexts (from bevy_egui), reads `Dialogue` and
+///   `DialogueScripts`
+/// - **Upstream**: `typewriter_system` reveals the line
+/// - **Downstream**: Emits `AdvanceDialogueEvent` for clicked buttons and
+///   `PlaySoundEvent` (`SoundCue::UiConfirm`) for picked choices
+pub fn dialogue_box_system(
+    mut contexts: EguiContexts,
+    dialogue: Res<Dialogue>,
+    scripts: Res<DialogueScripts>,
+    mut advance_events: EventWriter<AdvanceDialogueEvent>,
+    mut sound_events: EventWriter<PlaySoundEvent>,
+) {
+    let Some((line, revealed)) = dialogue.current(&scripts) else {
+        return;
+    };
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+
+    if let Some(advance) = draw_dialogue_box(ctx, line, revealed) {
+        if advance.choice.is_some() {
+            sound_events.write(PlaySoundEvent {
+                cue: SoundCue::UiConfirm,
+            });
+        }
+        advance_events.write(advance);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::systems::dialogue::DialogueChoice;
+
+    #[test]
+    fn dialogue_box_layout_matches_snapshot() {
+        use crate::ui::snapshot::{assert_snapshot, layout_snapshot};
+
+        let choice = |text: &str, goto| DialogueChoice {
+            text: text.to_string(),
+            goto,
+        };
+        let line = DialogueLine {
+            speaker: Some("Narrator".to_string()),
+            text: "The portrait's eyes seem to follow you.".to_string(),
+            choices: vec![choice("Look closer", Some(1)), choice("Leave", None)],
+        };
+
+        // Choices only appear once the line is typed out
+        let typing = layout_snapshot(|ctx| {
+            draw_dialogue_box(ctx, &line, 10);
+        });
+        assert!(typing.contains("\"The portra\""));
+        assert!(!typing.contains("\"Leave\""));
+
+        assert_snapshot(
+            "dialogue_box",
+            &layout_snapshot(|ctx| {
+                draw_dialogue_box(ctx, &line, usize::MAX);
+            }),
+        );
+    }
+}
@@ -0,0 +1,197 @@
+use bevy::prelude::*;
+use bevy_egui::{EguiContexts, egui};
+
+use crate::audio::sound_bank::{PlaySoundEvent, SoundCue};
+use crate::resources::game_clock::format_run_time;
+use crate::resources::game_state::{GameMode, GameState};
+use crate::systems::daily::{DailyChallenge, DailyResult};
+use crate::systems::victory::{Ending, NewGamePlusEvent, RunSummary};
+use crate::ui::credits::{CreditsState, ShowCreditsEvent};
+
+/// Plugin that registers the ending screen shown after escaping the house
+///
+/// The screen shows the `RunSummary`, with the result token after a Daily
+/// Escape, and offers New Game+, the credits (which return to the main menu
+/// when they finish) or the main menu.
+///
+/// **NOTE**: EguiPlugin and `credits::CreditsPlugin` must be added to the
+/// app before this plugin.
+pub struct EndingScreenPlugin;
+
+impl Plugin for EndingScreenPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<RunSummary>()
+            .add_event::<NewGamePlusEvent>()
+            .add_event::<ShowCreditsEvent>()
+            .add_event::<PlaySoundEvent>()
+            .add_systems(Update, ending_screen_system);
+    }
+}
+
+/// What the player picked on the ending screen
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EndingChoice {
+    /// Start the next run keeping the diary pages
+    NewGamePlus,
+    /// Roll the credits
+    Credits,
+    /// Return to the main menu
+    MainMenu,
+}
+
+/// Title and closing text of an ending
+pub fn ending_text(ending: Ending) -> (&'static str, &'static str) {
+    match ending {
+        Ending::Fled => (
+            "You Fled",
+            "You ran into the night without looking back. Whatever lives in the house is still there.",
+        ),
+        Ending::Witness => (
+            "The Witness",
+            "The pages you carry tell part of the story. Enough to know you will come back.",
+        ),
+        Ending::Truth => (
+            "The Whole Truth",
+            "Every page of the diary is in your hands. The house has no secrets left to keep you.",
+        ),
+    }
+}
+
+/// Draws the ending screen and returns the button the player clicked
+///
+/// Split from `ending_screen_system` so the layout can be drawn without a
+/// running app.
+pub fn draw_ending_screen(
+    ctx: &egui::Context,
+    summary: &RunSummary,
+    daily: Option<&DailyResult>,
+) -> Option<EndingChoice> {
+    let (title, text) = ending_text(summary.ending);
+    let mut choice = None;
+
+    egui::Window::new("Escaped")
+        .title_bar(false)
+        .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+        .resizable(false)
+        .collapsible(false)
+        .show(ctx, |ui| {
+            ui.vertical_centered(|ui| {
+                ui.label(
+                    egui::RichText::new(title)
+                        .size(32.0)
+                        .color(egui::Color32::from_rgb(255, 220, 150)),
+                );
+                ui.add_space(8.0);
+                ui.label(text);
+                ui.add_space(16.0);
+            });
+
+            egui::Grid::new("run_summary")
+                .num_columns(2)
+                .spacing([24.0, 4.0])
+                .show(ui, |ui| {
+                    ui.label("Time");
+                    ui.label(format_run_time(summary.time));
+                    ui.end_row();
+                    ui.label("Deaths");
+                    ui.label(summary.deaths.to_string());
+                    ui.end_row();
+                    ui.label("Secrets found");
+                    ui.label(summary.secrets.to_string());
+                    ui.end_row();
+                    ui.label("Diary pages");
+                    ui.label(format!("{} / {}", summary.diary_pages, summary.total_pages));
+                    ui.end_row();
+                    if summary.cycle > 0 {
+                        ui.label("New Game+");
+                        ui.label(format!("Cycle {}", summary.cycle));
+                        ui.end_row();
+                    }
+                    if let Some(daily) = daily {
+                        ui.label("Daily Escape");
+                        ui.label(egui::RichText::new(daily.token()).monospace());
+                        ui.end_row();
+                    }
+                    for (label, value) in summary.stats.rows() {
+                        ui.label(label);
+                        ui.label(value);
+                        ui.end_row();
+                    }
+                });
+
+            ui.add_space(16.0);
+            ui.horizontal(|ui| {
+                if ui.button("New Game+").clicked() {
+                    choice = Some(EndingChoice::NewGamePlus);
+                }
+                if ui.button("Credits").clicked() {
+                    choice = Some(EndingChoice::Credits);
+                }
+                if ui.button("Main Menu").clicked() {
+                    choice = Some(EndingChoice::MainMenu);
+                }
+            });
+        });
+
+    choice
+}
+
+/// System that shows the ending screen while in `GameMode::Victory`
+///
+/// # System Dependencies
+/// - **Resources**: EguiContexts (from bevy_egui), `RunSummary`, writes
+///   `GameState`; reads `DailyChallenge` and `CreditsState` when present
+/// - **Upstream**: `victory::escape_system` switches to Victory
+/// - **Downstream**: Emits `NewGamePlusEvent`, `ShowCreditsEvent` and
+///   `PlaySoundEvent`
+///
+/// # Behavior
+/// Hidden while the credits roll. Main Menu switches to `GameMode::Menu`.
+pub fn ending_screen_system(
+    mut contexts: EguiContexts,
+    summary: Res<RunSummary>,
+    daily: Option<Res<DailyChallenge>>,
+    credits: Option<Res<CreditsState>>,
+    mut game_state: ResMut<GameState>,
+    mut new_game_plus: EventWriter<NewGamePlusEvent>,
+    mut show_credits: EventWriter<ShowCreditsEvent>,
+    mut sound_events: EventWriter<PlaySoundEvent>,
+) {
+    if game_state.game_mode != GameMode::Victory || credits.is_some_and(|credits| credits.active) {
+        return;
+    }
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+
+    let daily = daily.and_then(|daily| daily.result);
+    let Some(choice) = draw_ending_screen(ctx, &summary, daily.as_ref()) else {
+        return;
+    };
+    match choice {
+        EndingChoice::NewGamePlus => {
+            new_game_plus.write(NewGamePlusEvent);
+        }
+        EndingChoice::Credits => {
+            show_credits.write(ShowCreditsEvent);
+        }
+        EndingChoice::MainMenu => game_state.game_mode = GameMode::Menu,
+    }
+    sound_events.write(PlaySoundEvent {
+        cue: SoundCue::UiConfirm,
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_ending_has_distinct_title() {
+        let titles: Vec<&str> = [Ending::Fled, Ending::Witness, Ending::Truth]
+            .into_iter()
+            .map(|ending| ending_text(ending).0)
+            .collect();
+        assert_eq!(titles, vec!["You Fled", "The Witness", "The Whole Truth"]);
+    }
+}
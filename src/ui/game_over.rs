@@ -0,0 +1,145 @@
+use bevy::prelude::*;
+use bevy_egui::{EguiContexts, egui};
+
+use crate::audio::sound_bank::{PlaySoundEvent, SoundCue};
+use crate::resources::game_state::{GameMode, GameState};
+use crate::systems::game_over::{
+    GameOver, GameOverReason, LAST_SAVE_SLOT, RetryEvent, RetryOption,
+};
+use crate::systems::save_storage::{SaveBackend, SaveStorage, save_file_name};
+
+/// Plugin that registers the game over screen
+///
+/// Shown while in `GameMode::GameOver` (see `game_over::GameOverPlugin`),
+/// offering Load Last Save, Restart Room and Quit.
+///
+/// **NOTE**: EguiPlugin must be added to the app before this plugin.
+pub struct GameOverScreenPlugin;
+
+impl Plugin for GameOverScreenPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<GameOver>()
+            .init_resource::<SaveBackend>()
+            .add_event::<RetryEvent>()
+            .add_event::<PlaySoundEvent>()
+            .add_systems(Update, game_over_screen_system);
+    }
+}
+
+/// Heading and explanation shown for each way a run can end
+pub fn game_over_text(reason: Option<GameOverReason>) -> (&'static str, &'static str) {
+    match reason {
+        Some(GameOverReason::Permadeath) => (
+            "You Died",
+            "Permadeath is on. There is no waking up from this one.",
+        ),
+        Some(GameOverReason::Darkness) => (
+            "Lost in the Dark",
+            "Your candle burnt down to nothing and the darkness closed in.",
+        ),
+        None => ("Game Over", "Your escape ends here."),
+    }
+}
+
+/// Draws the game over screen and returns the option the player clicked
+///
+/// "Load Last Save" is greyed out when `has_save` is false.
+pub fn draw_game_over_screen(
+    ctx: &egui::Context,
+    reason: Option<GameOverReason>,
+    has_save: bool,
+) -> Option<RetryOption> {
+    let (title, text) = game_over_text(reason);
+    let mut choice = None;
+
+    egui::Window::new("Game Over")
+        .title_bar(false)
+        .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+        .resizable(false)
+        .collapsible(false)
+        .show(ctx, |ui| {
+            ui.vertical_centered(|ui| {
+                ui.label(
+                    egui::RichText::new(title)
+                        .size(32.0)
+                        .color(egui::Color32::from_rgb(200, 60, 60)),
+                );
+                ui.add_space(8.0);
+                ui.label(text);
+                ui.add_space(16.0);
+                if ui
+                    .add_enabled(has_save, egui::Button::new("Load Last Save"))
+                    .clicked()
+                {
+                    choice = Some(RetryOption::LoadLastSave);
+                }
+                if ui.button("Restart Room").clicked() {
+                    choice = Some(RetryOption::RestartRoom);
+                }
+                if ui.button("Quit").clicked() {
+                    choice = Some(RetryOption::Quit);
+                }
+            });
+        });
+
+    choice
+}
+
+/// System that shows the game over screen while in `GameMode::GameOver`
+///
+/// # System Dependencies
+/// - **Resources**: EguiContexts (from bevy_egui), reads `GameState`,
+///   `GameOver` and `SaveBackend`
+/// - **Upstream**: `game_over_trigger_system` switches to GameOver
+/// - **Downstream**: `retry_system` handles `RetryEvent`; emits
+///   `PlaySoundEvent`
+///
+/// # Behavior
+/// Whether `LAST_SAVE_SLOT` holds a save is checked once each time the
+/// screen opens.
+pub fn game_over_screen_system(
+    mut contexts: EguiContexts,
+    game_state: Res<GameState>,
+    game_over: Res<GameOver>,
+    storage: Res<SaveBackend>,
+    mut has_save: Local<Option<bool>>,
+    mut retry_events: EventWriter<RetryEvent>,
+    mut sound_events: EventWriter<PlaySoundEvent>,
+) {
+    if game_state.game_mode != GameMode::GameOver {
+        *has_save = None;
+        return;
+    }
+    let has_save = *has_save.get_or_insert_with(|| storage.exists(&save_file_name(LAST_SAVE_SLOT)));
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+
+    if let Some(option) = draw_game_over_screen(ctx, game_over.reason, has_save) {
+        retry_events.write(RetryEvent(option));
+        sound_events.write(PlaySoundEvent {
+            cue: if option == RetryOption::Quit {
+                SoundCue::UiCancel
+            } else {
+                SoundCue::UiConfirm
+            },
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn each_failure_has_its_own_heading() {
+        assert_eq!(
+            game_over_text(Some(GameOverReason::Permadeath)).0,
+            "You Died"
+        );
+        assert_eq!(
+            game_over_text(Some(GameOverReason::Darkness)).0,
+            "Lost in the Dark"
+        );
+    }
+}
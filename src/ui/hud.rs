@@ -1,9 +1,64 @@
 use bevy::prelude::*;
 use bevy_egui::{EguiContexts, egui};
+use std::time::Duration;
 
-use crate::components::inventory::{Inventory, Item};
-use crate::components::lighting::{Candle, CandleWax};
-use crate::components::player::Player;
+use crate::audio::sound_bank::{PlaySoundEvent, SoundCue};
+use crate::components::inventory::{
+    HOTBAR_SLOTS, Hotbar, Inventory, InventoryFullReason, Item, KeyType, ToolType,
+};
+use crate::components::lighting::{
+    BurnModifier, BurnRate, Candle, CandleState, CandleWax, CarriedCandle, Draft,
+};
+use crate::components::player::{HitPoints, Player, Stamina};
+use crate::resources::game_clock::{GameClock, format_run_time};
+use crate::resources::game_rules::GameRules;
+use crate::resources::game_state::GameState;
+use crate::resources::map_state::MapState;
+use crate::resources::movement_tuning::MovementTuning;
+use crate::systems::candle_burn::{BurnContext, burn_rate_breakdown};
+use crate::systems::interaction::InteractionPrompt;
+use crate::systems::inventory::InventoryFullEvent;
+use crate::systems::tween::pulse;
+use crate::systems::victory::NewGamePlus;
+use crate::systems::water::AirSupply;
+use crate::ui::photo_mode::photo_mode_inactive;
+use crate::ui::toast::ToastEvent;
+
+/// Seconds over which the light remaining estimate eases toward a new value
+pub const LIGHT_ESTIMATE_SMOOTHING_SECS: f32 = 1.0;
+
+/// Wax percentage below which the candle meter pulses as a warning
+pub const LOW_WAX_PULSE_THRESHOLD: f32 = 20.0;
+
+/// Seconds per low-wax pulse of the candle meter
+pub const LOW_WAX_PULSE_PERIOD: f32 = 1.2;
+
+/// Number of segments in the candle meter's wax bar
+pub const CANDLE_METER_SEGMENTS: usize = 10;
+
+/// Size (points) of the candle meter's wax bar
+pub const CANDLE_METER_SIZE: egui::Vec2 = egui::vec2(200.0, 18.0);
+
+/// Seconds the candle meter flashes red after the candle goes out
+pub const EXTINGUISH_FLASH_SECS: f32 = 1.5;
+
+/// Gap (points) between wax segments and around the bar's edge
+const SEGMENT_GAP: f32 = 2.0;
+
+/// Color of the wax segments
+const WAX_COLOR: egui::Color32 = egui::Color32::from_rgb(235, 190, 90);
+
+/// Color the candle meter turns for low-wax and extinguish warnings
+const WARNING_COLOR: egui::Color32 = egui::Color32::from_rgb(255, 90, 60);
+
+/// Color of the air meter
+const AIR_COLOR: egui::Color32 = egui::Color32::from_rgb(110, 170, 230);
+
+/// Air remaining (0.0-1.0) below which the air meter turns to the warning color
+pub const LOW_AIR_THRESHOLD: f32 = 0.25;
+
+/// Color of the stamina bar
+const STAMINA_COLOR: egui::Color32 = egui::Color32::from_rgb(120, 200, 120);
 
 /// Plugin that registers the HUD system
 ///
@@ -17,7 +72,161 @@ pub struct HudPlugin;
 
 impl Plugin for HudPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Update, hud_system);
+        app.init_resource::<HudSettings>()
+            .init_resource::<LightRemainingEstimate>()
+            .init_resource::<CandleAlert>()
+            .init_resource::<GameClock>()
+            .add_event::<InventoryFullEvent>()
+            .add_event::<ToastEvent>()
+            .add_event::<PlaySoundEvent>()
+            .add_systems(
+                Update,
+                (
+                    light_remaining_system,
+                    candle_alert_system,
+                    hud_system.run_if(photo_mode_inactive),
+                )
+                    .chain(),
+            )
+            .add_systems(Update, inventory_full_toast_system);
+    }
+}
+
+/// Resource holding player-configurable HUD options
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct HudSettings {
+    /// Show the estimated minutes of light remaining next to the wax meter
+    pub show_light_remaining: bool,
+}
+
+impl Default for HudSettings {
+    fn default() -> Self {
+        Self {
+            show_light_remaining: true,
+        }
+    }
+}
+
+/// Resource holding the smoothed estimate of the candle's remaining light
+#[derive(Resource, Default, Debug, Clone, Copy, PartialEq)]
+pub struct LightRemainingEstimate {
+    /// Seconds until the candle burns out (`None` while it isn't burning)
+    pub seconds: Option<f32>,
+}
+
+/// System that estimates how long the player's candle will keep burning
+///
+/// # System Dependencies
+/// - **Components**: Reads `Candle`, `CandleWax`, `CandleState`, `BurnRate`,
+///   `Draft`, and what `candle_burn::BurnContext` reads
+/// - **Resources**: Reads `Time`, and `GameRules` and `NewGamePlus` when
+///   present, writes `LightRemainingEstimate`
+/// - **Downstream**: `hud_system` shows the estimate
+///
+/// # Behavior
+/// - Uses `candle_burn::burn_rate_breakdown`, so movement, drafts,
+///   surroundings and difficulty change the estimate exactly as they change
+///   the burn
+/// - Eases toward the new value over `LIGHT_ESTIMATE_SMOOTHING_SECS`, so
+///   starting or stopping doesn't make the readout jump
+/// - Clears the estimate while the candle is unlit or not burning down
+#[allow(clippy::type_complexity)]
+pub fn light_remaining_system(
+    time: Res<Time>,
+    candle_query: Query<
+        (
+            &CandleWax,
+            &CandleState,
+            &BurnRate,
+            Option<&Draft>,
+            Option<&BurnModifier>,
+            Option<&CarriedCandle>,
+            Option<&Transform>,
+        ),
+        With<Candle>,
+    >,
+    context: BurnContext,
+    rules: Option<Res<GameRules>>,
+    new_game_plus: Option<Res<NewGamePlus>>,
+    mut estimate: ResMut<LightRemainingEstimate>,
+) {
+    let target = candle_query
+        .single()
+        .ok()
+        .filter(|(_, state, ..)| **state == CandleState::Lit)
+        .and_then(|(wax, _, burn_rate, draft, modifier, carried, transform)| {
+            let conditions = context.conditions(
+                transform.map(|transform| transform.translation.truncate()),
+                modifier,
+                carried,
+            );
+            burn_rate_breakdown(
+                burn_rate,
+                conditions,
+                draft,
+                rules.as_deref(),
+                new_game_plus.as_deref(),
+            )
+            .seconds_remaining(wax.0)
+        });
+
+    let smoothed = match (estimate.seconds, target) {
+        (Some(current), Some(target)) => {
+            let blend = 1.0 - (-time.delta_secs() / LIGHT_ESTIMATE_SMOOTHING_SECS).exp();
+            Some(current + (target - current) * blend)
+        }
+        (_, target) => target,
+    };
+    estimate.set_if_neq(LightRemainingEstimate { seconds: smoothed });
+}
+
+/// Resource tracking the candle for the candle meter's extinguish alert
+#[derive(Resource, Default, Debug, Clone, Copy, PartialEq)]
+pub struct CandleAlert {
+    /// Candle state seen last frame (`None` before a candle exists)
+    pub last_state: Option<CandleState>,
+    /// Seconds left of the meter's red flash
+    pub flash: f32,
+}
+
+/// System that alerts the player when their lit candle goes out
+///
+/// # System Dependencies
+/// - **Components**: Reads `CandleState` of the `Candle`
+/// - **Resources**: Reads `GameClock`, writes `CandleAlert`
+/// - **Upstream**: `candle_burn_system` (wax runs out) and `water_drip`
+///   (drips) extinguish the candle
+/// - **Downstream**: Emits `PlaySoundEvent` (`SoundCue::CandleSnuffed`);
+///   `hud_system` flashes the candle meter
+///
+/// # Behavior
+/// Going from `Lit` to `Extinguished` starts an `EXTINGUISH_FLASH_SECS`
+/// flash and plays the cue once. A candle that is already out when first
+/// seen (e.g. after loading) doesn't alert.
+pub fn candle_alert_system(
+    clock: Res<GameClock>,
+    candle_query: Query<&CandleState, With<Candle>>,
+    mut alert: ResMut<CandleAlert>,
+    mut sound_events: EventWriter<PlaySoundEvent>,
+) {
+    let state = candle_query.single().ok().copied();
+    if alert.last_state == Some(CandleState::Lit) && state == Some(CandleState::Extinguished) {
+        alert.flash = EXTINGUISH_FLASH_SECS;
+        sound_events.write(PlaySoundEvent {
+            cue: SoundCue::CandleSnuffed,
+        });
+    } else if alert.flash > 0.0 {
+        alert.flash = (alert.flash - clock.delta).max(0.0);
+    }
+    alert.last_state = state;
+}
+
+/// Formats the light remaining estimate shown next to the wax meter
+pub fn format_light_remaining(seconds: f32) -> String {
+    if seconds < 60.0 {
+        "<1 min of light".to_string()
+    } else {
+        format!("~{:.0} min of light", (seconds / 60.0).ceil())
     }
 }
 
@@ -28,12 +237,29 @@ impl Plugin for HudPlugin {
 ///
 /// # System Dependencies
 /// - **Resources**: EguiContexts (from bevy_egui)
-/// - **Components**: Reads CandleWax, Candle, Player, Inventory
+/// - **Resources**: Reads GameState, MapState (death counter) when present
+/// - **Resources**: Reads LightRemainingEstimate, HudSettings, GameRules,
+///   MovementTuning when present
+/// - **Resources**: Reads InteractionPrompt, CandleAlert when present
+/// - **Components**: Reads CandleWax, CandleState, Candle, Player, Inventory, HitPoints,
+///   AirSupply, Stamina
 ///
 /// # HUD Elements
-/// 1. **Candle Wax Meter**: Visual progress bar showing remaining wax percentage
-/// 2. **Match Count**: Number of matches in player inventory
-/// 3. **Inventory Bar**: List of all items in player inventory
+/// 1. **Health Bar**: Remaining hit points (when the player has `HitPoints`)
+/// 2. **Candle Meter**: Flame icon for the `CandleState` and a segmented
+///    wax bar that pulses red below `LOW_WAX_PULSE_THRESHOLD` and flashes
+///    when the candle goes out, with the estimated minutes of light
+///    remaining unless disabled in `HudSettings` or playing on a difficulty
+///    without hints
+/// 3. **Air Meter**: Breath left while the player is under water (see
+///    `AirSupply`), turning red below `LOW_AIR_THRESHOLD`
+/// 4. **Stamina Bar**: Sprint stamina while it isn't full (see `Stamina`),
+///    red while the player is exhausted
+/// 5. **Match Count**: Number of matches in player inventory
+/// 6. **Inventory Bar**: List of all items in player inventory
+/// 7. **Death Counter**: Deaths in the current room and in total
+/// 8. **Play Time**: In-game time of the run (`GameState::completion_time`)
+/// 9. **Interaction Prompt**: Action for the nearest interactable in range
 ///
 /// # Display Format
 /// - Fixed position at top-left corner (10, 10)
@@ -42,34 +268,159 @@ impl Plugin for HudPlugin {
 /// - Updates every frame
 ///
 /// From tasks.md T037: "Display candle meter, match count, inventory bar using bevy_egui 0.36.0"
+///
+/// Drawing is done by `draw_hud`.
+#[allow(clippy::too_many_arguments)]
 pub fn hud_system(
     mut contexts: EguiContexts,
-    candle_query: Query<&CandleWax, With<Candle>>,
+    candle_query: Query<(&CandleWax, Option<&CandleState>), With<Candle>>,
     player_query: Query<&Inventory, With<Player>>,
+    hotbar_query: Query<&Hotbar, With<Player>>,
+    vitals_query: Query<(Option<&HitPoints>, Option<&AirSupply>, Option<&Stamina>), With<Player>>,
+    game_state: Option<Res<GameState>>,
+    map_state: Option<Res<MapState>>,
+    light_remaining: Option<Res<LightRemainingEstimate>>,
+    settings: Option<Res<HudSettings>>,
+    rules: Option<Res<GameRules>>,
+    interaction_prompt: Option<Res<InteractionPrompt>>,
+    clock: Option<Res<GameClock>>,
+    alert: Option<Res<CandleAlert>>,
+    tuning: Option<Res<MovementTuning>>,
 ) {
     // Get egui context - this will succeed when EguiPlugin is properly configured
     let Ok(ctx) = contexts.ctx_mut() else {
         return; // Early return if egui context is not available
     };
 
+    let (hit_points, air, stamina) = vitals_query.single().unwrap_or_default();
+    let max_stamina = tuning.map_or_else(
+        || MovementTuning::default().max_stamina,
+        |tuning| tuning.max_stamina,
+    );
+    let candle = candle_query.single().ok();
+    let wax = candle.map(|(wax, _)| wax.0);
+    let play_time = game_state
+        .as_ref()
+        .map(|game_state| game_state.completion_time);
+    let info = HudInfo {
+        hit_points,
+        wax,
+        candle_state: candle.and_then(|(_, state)| state.copied()),
+        wax_pulse: wax
+            .zip(clock)
+            .map_or(0.0, |(wax, clock)| low_wax_pulse(wax, clock.elapsed)),
+        extinguish_flash: alert.map_or(0.0, |alert| alert.flash / EXTINGUISH_FLASH_SECS),
+        light_remaining: light_remaining
+            .filter(|_| settings.is_none_or(|settings| settings.show_light_remaining))
+            .filter(|_| rules.is_none_or(|rules| rules.difficulty.hints()))
+            .and_then(|estimate| estimate.seconds),
+        air: air.map(AirSupply::fraction),
+        stamina: stamina
+            .map(|stamina| (stamina.fraction(max_stamina), stamina.exhausted))
+            .filter(|(fraction, _)| *fraction < 1.0),
+        inventory: player_query.single().ok(),
+        hotbar: hotbar_query.single().ok(),
+        deaths: game_state.zip(map_state).map(|(game_state, map_state)| {
+            (
+                map_state.deaths_in_room(game_state.current_room).len(),
+                game_state.deaths,
+            )
+        }),
+        play_time,
+        prompt: interaction_prompt
+            .as_ref()
+            .and_then(|prompt| prompt.prompt.as_deref()),
+    };
+    draw_hud(ctx, &info);
+}
+
+/// Values shown by the HUD, gathered from the ECS each frame
+#[derive(Default)]
+pub struct HudInfo<'a> {
+    /// Player hit points (health bar hidden when `None`)
+    pub hit_points: Option<&'a HitPoints>,
+    /// Remaining candle wax percentage (`None` shows "N/A")
+    pub wax: Option<f32>,
+    /// Candle state shown by the flame icon (icon hidden when `None`)
+    pub candle_state: Option<CandleState>,
+    /// Strength (0.0-1.0) of the low-wax warning tint on the candle meter
+    pub wax_pulse: f32,
+    /// Strength (0.0-1.0) of the candle meter's extinguish flash
+    pub extinguish_flash: f32,
+    /// Estimated seconds of light remaining (estimate hidden when `None`)
+    pub light_remaining: Option<f32>,
+    /// Breath left (0.0-1.0) under water (air meter hidden when `None`)
+    pub air: Option<f32>,
+    /// Sprint stamina left (0.0-1.0) and whether the player is exhausted
+    /// (stamina bar hidden when `None`)
+    pub stamina: Option<(f32, bool)>,
+    /// Player inventory
+    pub inventory: Option<&'a Inventory>,
+    /// Player hotbar (hidden when `None`)
+    pub hotbar: Option<&'a Hotbar>,
+    /// Deaths in the current room and in total (counter hidden when `None`)
+    pub deaths: Option<(usize, u32)>,
+    /// In-game time of the run (hidden when `None`)
+    pub play_time: Option<Duration>,
+    /// Interaction prompt for the nearest interactable (hidden when `None`)
+    pub prompt: Option<&'a str>,
+}
+
+/// Draws the HUD window described by `info`
+///
+/// Split from `hud_system` so the layout can be snapshot-tested with
+/// `ui::snapshot::layout_snapshot` without a running app.
+pub fn draw_hud(ctx: &egui::Context, info: &HudInfo) {
     egui::Window::new("HUD")
         .title_bar(false)
         .fixed_pos([10.0, 10.0])
         .resizable(false)
         .show(ctx, |ui| {
-            // Candle wax meter
-            if let Ok(wax) = candle_query.single() {
-                ui.label(format!("Candle: {:.0}%", wax.0));
-                ui.add(egui::ProgressBar::new(wax.0 / 100.0).desired_width(200.0));
-            } else {
-                ui.label("Candle: N/A");
-                ui.add(egui::ProgressBar::new(0.0).desired_width(200.0));
+            // Health bar
+            if let Some(hit_points) = info.hit_points {
+                ui.label(format!(
+                    "Health: {:.0}/{:.0}",
+                    hit_points.current, hit_points.max
+                ));
+                ui.add(egui::ProgressBar::new(hit_points.fraction()).desired_width(200.0));
+                ui.add_space(10.0);
+            }
+
+            // Candle meter
+            draw_candle_meter(ui, info);
+
+            // Air meter
+            if let Some(air) = info.air {
+                ui.add_space(10.0);
+                ui.label("Air");
+                let color = if air < LOW_AIR_THRESHOLD {
+                    WARNING_COLOR
+                } else {
+                    AIR_COLOR
+                };
+                ui.add(egui::ProgressBar::new(air).desired_width(200.0).fill(color));
+            }
+
+            // Stamina bar
+            if let Some((stamina, exhausted)) = info.stamina {
+                ui.add_space(10.0);
+                ui.label("Stamina");
+                let color = if exhausted {
+                    WARNING_COLOR
+                } else {
+                    STAMINA_COLOR
+                };
+                ui.add(
+                    egui::ProgressBar::new(stamina)
+                        .desired_width(200.0)
+                        .fill(color),
+                );
             }
 
             ui.add_space(10.0);
 
             // Match count and inventory
-            if let Ok(inventory) = player_query.single() {
+            if let Some(inventory) = info.inventory {
                 // Count matches in inventory
                 let match_count = inventory
                     .items
@@ -84,7 +435,7 @@ pub fn hud_system(
                 // Inventory bar
                 ui.label(format!(
                     "Inventory: {}/{}",
-                    inventory.items.len(),
+                    inventory.slots_used(),
                     inventory.max_capacity
                 ));
 
@@ -92,29 +443,7 @@ pub fn hud_system(
                 if !inventory.items.is_empty() {
                     ui.group(|ui| {
                         for (idx, item) in inventory.items.iter().enumerate() {
-                            let item_label = match item {
-                                Item::Match => format!("{}. Match", idx + 1),
-                                Item::Key(key_type) => {
-                                    // Format key type for display
-                                    let key_name = match key_type {
-                                        crate::components::inventory::KeyType::Brass => "Brass Key",
-                                        crate::components::inventory::KeyType::Iron => "Iron Key",
-                                        crate::components::inventory::KeyType::Ornate => {
-                                            "Ornate Key"
-                                        }
-                                        crate::components::inventory::KeyType::Master => {
-                                            "Master Key"
-                                        }
-                                    };
-                                    format!("{}. {}", idx + 1, key_name)
-                                }
-                                Item::Tool(_) => format!("{}. Tool", idx + 1),
-                                Item::PuzzleItem(_) => format!("{}. Puzzle Item", idx + 1),
-                                Item::DoubleJumpItem => format!("{}. Double Jump", idx + 1),
-                                Item::DiaryPage(page) => {
-                                    format!("{}. Diary Page {}", idx + 1, page)
-                                }
-                            };
+                            let item_label = format!("{}. {}", idx + 1, item_name(item));
                             ui.label(item_label);
                         }
                     });
@@ -125,12 +454,278 @@ pub fn hud_system(
                 ui.label("Matches: 0");
                 ui.label("Inventory: 0/0");
             }
+
+            // Hotbar slots with the count of each assigned item
+            if let Some(hotbar) = info.hotbar {
+                ui.add_space(10.0);
+                ui.horizontal(|ui| {
+                    for slot in 0..HOTBAR_SLOTS {
+                        ui.group(|ui| {
+                            ui.label(hotbar_slot_label(slot, hotbar.get(slot), info.inventory));
+                        });
+                    }
+                });
+            }
+
+            // Death counter
+            if let Some((here, total)) = info.deaths {
+                ui.add_space(10.0);
+                ui.label(format!("Deaths: {} here / {} total", here, total));
+            }
+
+            // Play time
+            if let Some(time) = info.play_time {
+                ui.label(format!("Time: {}", format_run_time(time)));
+            }
+
+            // Interaction prompt
+            if let Some(prompt) = info.prompt {
+                ui.add_space(10.0);
+                ui.label(format!("[F] {}", prompt));
+            }
         });
 }
 
+/// Draws the candle meter: flame icon, wax label and segmented wax bar
+fn draw_candle_meter(ui: &mut egui::Ui, info: &HudInfo) {
+    let Some(wax) = info.wax else {
+        ui.label("Candle: N/A");
+        draw_wax_segments(ui, 0.0, 0.0, 0.0);
+        return;
+    };
+
+    let mut label = egui::RichText::new(format!("Candle: {:.0}%", wax));
+    if info.wax_pulse > 0.0 {
+        label = label.color(
+            ui.visuals()
+                .text_color()
+                .lerp_to_gamma(WARNING_COLOR, info.wax_pulse),
+        );
+    }
+    ui.horizontal(|ui| {
+        if let Some(state) = info.candle_state {
+            draw_flame_icon(ui, state);
+        }
+        ui.label(label);
+        if let Some(seconds) = info.light_remaining {
+            ui.label(format_light_remaining(seconds));
+        }
+    });
+    draw_wax_segments(ui, wax, info.wax_pulse, info.extinguish_flash);
+}
+
+/// Draws the wax bar as `CANDLE_METER_SEGMENTS` segments, the last one partly
+/// filled, tinted by the low-wax `warning` and extinguish `flash` (0.0-1.0)
+fn draw_wax_segments(ui: &mut egui::Ui, wax: f32, warning: f32, flash: f32) {
+    let (rect, _) = ui.allocate_exact_size(CANDLE_METER_SIZE, egui::Sense::hover());
+    let painter = ui.painter();
+    painter.rect_filled(
+        rect,
+        2.0,
+        egui::Color32::from_gray(40).lerp_to_gamma(WARNING_COLOR, flash),
+    );
+
+    let color = WAX_COLOR.lerp_to_gamma(WARNING_COLOR, warning);
+    let segment_size = egui::vec2(
+        (rect.width() - SEGMENT_GAP * (CANDLE_METER_SEGMENTS + 1) as f32)
+            / CANDLE_METER_SEGMENTS as f32,
+        rect.height() - SEGMENT_GAP * 2.0,
+    );
+    for index in 0..CANDLE_METER_SEGMENTS {
+        let fill = segment_fill(wax, index);
+        if fill <= 0.0 {
+            break;
+        }
+        let min = rect.min
+            + egui::vec2(
+                SEGMENT_GAP + index as f32 * (segment_size.x + SEGMENT_GAP),
+                SEGMENT_GAP,
+            );
+        painter.rect_filled(
+            egui::Rect::from_min_size(min, egui::vec2(segment_size.x * fill, segment_size.y)),
+            1.0,
+            color,
+        );
+    }
+}
+
+/// Draws a small candle wick with a flame when `Lit`, bare when `Unlit`
+/// and trailing smoke when `Extinguished`
+fn draw_flame_icon(ui: &mut egui::Ui, state: CandleState) {
+    let (rect, response) = ui.allocate_exact_size(egui::vec2(12.0, 18.0), egui::Sense::hover());
+    let painter = ui.painter();
+    let wick_base = rect.center_bottom();
+    let wick_top = wick_base - egui::vec2(0.0, 5.0);
+    painter.line_segment(
+        [wick_base, wick_top],
+        egui::Stroke::new(1.5, egui::Color32::from_gray(90)),
+    );
+
+    match state {
+        CandleState::Lit => {
+            let base = wick_top - egui::vec2(0.0, 3.0);
+            let tip = egui::pos2(base.x, rect.top());
+            for (radius, color) in [
+                (4.5, egui::Color32::from_rgb(255, 140, 40)),
+                (2.5, egui::Color32::from_rgb(255, 230, 140)),
+            ] {
+                painter.circle_filled(base, radius, color);
+                painter.add(egui::Shape::convex_polygon(
+                    vec![
+                        base + egui::vec2(-radius, 0.0),
+                        tip + egui::vec2(0.0, 4.5 - radius),
+                        base + egui::vec2(radius, 0.0),
+                    ],
+                    color,
+                    egui::Stroke::NONE,
+                ));
+            }
+        }
+        CandleState::Unlit => {}
+        CandleState::Extinguished => {
+            let smoke = egui::Stroke::new(1.0, egui::Color32::from_gray(150));
+            painter.add(egui::Shape::line(
+                vec![
+                    wick_top,
+                    wick_top + egui::vec2(2.0, -3.0),
+                    wick_top + egui::vec2(-1.0, -6.0),
+                    wick_top + egui::vec2(2.0, -9.0),
+                ],
+                smoke,
+            ));
+        }
+    }
+    response.on_hover_text(match state {
+        CandleState::Lit => "Lit",
+        CandleState::Unlit => "Unlit",
+        CandleState::Extinguished => "Out",
+    });
+}
+
+/// Fill (0.0-1.0) of the candle meter's wax segment `index` at `wax` percent
+pub fn segment_fill(wax: f32, index: usize) -> f32 {
+    let per_segment = 100.0 / CANDLE_METER_SEGMENTS as f32;
+    ((wax - index as f32 * per_segment) / per_segment).clamp(0.0, 1.0)
+}
+
+/// Strength (0.0-1.0) of the candle meter's warning pulse at `elapsed` game seconds
+///
+/// Zero while the wax is at or above `LOW_WAX_PULSE_THRESHOLD` or gone.
+pub fn low_wax_pulse(wax: f32, elapsed: f32) -> f32 {
+    if wax <= 0.0 || wax >= LOW_WAX_PULSE_THRESHOLD {
+        return 0.0;
+    }
+    pulse(elapsed, LOW_WAX_PULSE_PERIOD)
+}
+
+/// Returns the display name of an inventory item
+pub fn item_name(item: &Item) -> String {
+    match item {
+        Item::Match => "Match".to_string(),
+        Item::Key(key_type) => match key_type {
+            KeyType::Brass => "Brass Key",
+            KeyType::Iron => "Iron Key",
+            KeyType::Ornate => "Ornate Key",
+            KeyType::Master => "Master Key",
+        }
+        .to_string(),
+        Item::Tool(tool_type) => match tool_type {
+            ToolType::Wrench => "Wrench",
+            ToolType::Crowbar => "Crowbar",
+            ToolType::WireCutters => "Wire Cutters",
+            ToolType::Magnet => "Magnet",
+            ToolType::OilCan(charges) => return format!("Oil Can ({})", charges),
+            ToolType::Ladder => "Ladder",
+            ToolType::GasMask => "Gas Mask",
+            ToolType::GrapplingRope => "Grappling Rope",
+        }
+        .to_string(),
+        Item::PuzzleItem(_) => "Puzzle Item".to_string(),
+        Item::DoubleJumpItem => "Double Jump".to_string(),
+        Item::DiaryPage(page) => format!("Diary Page {}", page),
+        Item::CandleStub(wax) => format!("Candle Stub ({:.0}%)", wax),
+    }
+}
+
+/// Formats a hotbar slot as its key, item and carried count (e.g. "1: Match x5")
+///
+/// The item is named after the first carried unit, so an oil can shows the
+/// charges it has left rather than those it had when assigned.
+pub fn hotbar_slot_label(
+    slot: usize,
+    item: Option<&Item>,
+    inventory: Option<&Inventory>,
+) -> String {
+    match item {
+        Some(item) => {
+            let count = inventory.map_or(0, |inventory| inventory.count(item));
+            let carried = inventory
+                .and_then(|inventory| inventory.items.iter().find(|unit| unit.same_kind(item)));
+            format!(
+                "{}: {} x{}",
+                slot + 1,
+                item_name(carried.unwrap_or(item)),
+                count
+            )
+        }
+        None => format!("{}: -", slot + 1),
+    }
+}
+
+/// Formats the notification shown when an item doesn't fit
+pub fn inventory_full_message(event: &InventoryFullEvent) -> String {
+    match event.reason {
+        InventoryFullReason::NoFreeSlots => {
+            format!("Inventory full: no room for the {}", item_name(&event.item))
+        }
+        InventoryFullReason::CategoryFull(category) => format!(
+            "Can't carry any more {} (left the {})",
+            category.name(),
+            item_name(&event.item)
+        ),
+    }
+}
+
+/// System that turns refused pickups into toast notifications
+///
+/// # System Dependencies
+/// - **Upstream**: `inventory_collection_system` emits `InventoryFullEvent`
+/// - **Downstream**: `toast_update_system` queues the `ToastEvent`
+///
+/// Standing on an item the player can't take refuses it every frame, so
+/// repeats of the last message are dropped until a different item is
+/// refused.
+pub fn inventory_full_toast_system(
+    mut events: EventReader<InventoryFullEvent>,
+    mut toasts: EventWriter<ToastEvent>,
+    mut last_message: Local<Option<String>>,
+    mut frames_quiet: Local<u32>,
+) {
+    let mut refused = false;
+    for event in events.read() {
+        refused = true;
+        let message = inventory_full_message(event);
+        if last_message.as_ref() != Some(&message) {
+            toasts.write(ToastEvent::info(message.clone()));
+            *last_message = Some(message);
+        }
+    }
+
+    // Once pickups stop being refused, the same message may show again
+    if refused {
+        *frames_quiet = 0;
+    } else {
+        *frames_quiet = frames_quiet.saturating_add(1);
+        if *frames_quiet > 1 {
+            *last_message = None;
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::components::inventory::OIL_CAN_CHARGES;
 
     #[test]
     fn hud_plugin_compiles() {
@@ -264,4 +859,260 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn hud_layout_matches_snapshot() {
+        use crate::components::inventory::KeyType;
+        use crate::ui::snapshot::{assert_snapshot, layout_snapshot};
+
+        let hit_points = HitPoints::new(3.0);
+        let inventory = Inventory {
+            items: vec![Item::Match, Item::Key(KeyType::Brass), Item::DiaryPage(2)],
+            max_capacity: 10,
+        };
+        let info = HudInfo {
+            hit_points: Some(&hit_points),
+            wax: Some(80.0),
+            candle_state: Some(CandleState::Lit),
+            wax_pulse: 0.0,
+            extinguish_flash: 0.0,
+            light_remaining: None,
+            air: None,
+            stamina: None,
+            inventory: Some(&inventory),
+            hotbar: None,
+            deaths: Some((1, 4)),
+            play_time: None,
+            prompt: None,
+        };
+
+        assert_snapshot("hud_full", &layout_snapshot(|ctx| draw_hud(ctx, &info)));
+    }
+
+    #[test]
+    fn empty_hud_layout_matches_snapshot() {
+        use crate::ui::snapshot::{assert_snapshot, layout_snapshot};
+
+        let info = HudInfo::default();
+        assert_snapshot("hud_empty", &layout_snapshot(|ctx| draw_hud(ctx, &info)));
+    }
+
+    #[test]
+    fn air_meter_only_shows_under_water() {
+        use crate::ui::snapshot::layout_snapshot;
+
+        let dry = layout_snapshot(|ctx| draw_hud(ctx, &HudInfo::default()));
+        assert!(!dry.contains("\"Air\""));
+
+        let info = HudInfo {
+            air: Some(0.2),
+            ..Default::default()
+        };
+        assert!(layout_snapshot(|ctx| draw_hud(ctx, &info)).contains("\"Air\""));
+    }
+
+    #[test]
+    fn stamina_bar_only_shows_while_recovering() {
+        use crate::ui::snapshot::layout_snapshot;
+
+        let rested = layout_snapshot(|ctx| draw_hud(ctx, &HudInfo::default()));
+        assert!(!rested.contains("\"Stamina\""));
+
+        let info = HudInfo {
+            stamina: Some((0.4, true)),
+            ..Default::default()
+        };
+        assert!(layout_snapshot(|ctx| draw_hud(ctx, &info)).contains("\"Stamina\""));
+    }
+
+    #[test]
+    fn light_remaining_layout_matches_snapshot() {
+        use crate::ui::snapshot::{assert_snapshot, layout_snapshot};
+
+        let info = HudInfo {
+            wax: Some(45.0),
+            light_remaining: Some(270.0),
+            ..Default::default()
+        };
+        assert_snapshot(
+            "hud_light_remaining",
+            &layout_snapshot(|ctx| draw_hud(ctx, &info)),
+        );
+    }
+
+    #[test]
+    fn hotbar_slots_show_key_item_and_count() {
+        let inventory = Inventory {
+            items: vec![Item::Match, Item::Match, Item::Tool(ToolType::OilCan(2))],
+            max_capacity: 10,
+        };
+
+        assert_eq!(
+            hotbar_slot_label(0, Some(&Item::Match), Some(&inventory)),
+            "1: Match x2"
+        );
+        assert_eq!(
+            hotbar_slot_label(2, Some(&Item::Tool(ToolType::Crowbar)), Some(&inventory)),
+            "3: Crowbar x0"
+        );
+        assert_eq!(
+            hotbar_slot_label(
+                1,
+                Some(&Item::Tool(ToolType::OilCan(OIL_CAN_CHARGES))),
+                Some(&inventory)
+            ),
+            "2: Oil Can (2) x1",
+            "The carried can's charges are shown"
+        );
+        assert_eq!(hotbar_slot_label(3, None, Some(&inventory)), "4: -");
+    }
+
+    #[test]
+    fn candle_meter_pulses_only_when_wax_is_low() {
+        let peak = LOW_WAX_PULSE_PERIOD / 2.0;
+        assert_eq!(low_wax_pulse(80.0, peak), 0.0);
+        assert_eq!(low_wax_pulse(0.0, peak), 0.0);
+        assert!(low_wax_pulse(10.0, peak) > 0.99);
+        assert!(low_wax_pulse(10.0, 0.0) < 0.01);
+    }
+
+    #[test]
+    fn wax_segments_fill_in_order() {
+        let fills: Vec<f32> = (0..CANDLE_METER_SEGMENTS)
+            .map(|index| segment_fill(45.0, index))
+            .collect();
+        assert_eq!(fills, [1.0, 1.0, 1.0, 1.0, 0.5, 0.0, 0.0, 0.0, 0.0, 0.0]);
+        assert_eq!(segment_fill(100.0, CANDLE_METER_SEGMENTS - 1), 1.0);
+        assert_eq!(segment_fill(0.0, 0), 0.0);
+    }
+
+    #[test]
+    fn extinguishing_the_lit_candle_alerts_once() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.init_resource::<CandleAlert>();
+        app.insert_resource(GameClock {
+            delta: 0.5,
+            ..default()
+        });
+        app.add_event::<PlaySoundEvent>();
+        app.add_systems(Update, candle_alert_system);
+
+        let candle = app.world_mut().spawn((Candle, CandleState::Lit)).id();
+        app.update();
+        *app.world_mut().get_mut::<CandleState>(candle).unwrap() = CandleState::Extinguished;
+        app.update();
+        assert_eq!(
+            app.world().resource::<CandleAlert>().flash,
+            EXTINGUISH_FLASH_SECS
+        );
+        app.update();
+        assert_eq!(
+            app.world().resource::<CandleAlert>().flash,
+            EXTINGUISH_FLASH_SECS - 0.5
+        );
+
+        let cues: Vec<SoundCue> = app
+            .world_mut()
+            .resource_mut::<Events<PlaySoundEvent>>()
+            .drain()
+            .map(|event| event.cue)
+            .collect();
+        assert_eq!(cues, vec![SoundCue::CandleSnuffed]);
+
+        // A candle first seen already out (a loaded save) doesn't alert
+        let mut app_loaded = App::new();
+        app_loaded.add_plugins(MinimalPlugins);
+        app_loaded.init_resource::<CandleAlert>();
+        app_loaded.init_resource::<GameClock>();
+        app_loaded.add_event::<PlaySoundEvent>();
+        app_loaded.add_systems(Update, candle_alert_system);
+        app_loaded
+            .world_mut()
+            .spawn((Candle, CandleState::Extinguished));
+        app_loaded.update();
+        assert_eq!(app_loaded.world().resource::<CandleAlert>().flash, 0.0);
+    }
+
+    #[test]
+    fn light_remaining_formats_whole_minutes() {
+        assert_eq!(format_light_remaining(30.0), "<1 min of light");
+        assert_eq!(format_light_remaining(60.0), "~1 min of light");
+        assert_eq!(format_light_remaining(270.0), "~5 min of light");
+    }
+
+    #[test]
+    fn light_remaining_follows_burn_modifiers() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.init_resource::<LightRemainingEstimate>();
+        app.add_systems(Update, light_remaining_system);
+
+        let candle = app
+            .world_mut()
+            .spawn((Candle, CandleWax(60.0), CandleState::Lit, BurnRate(1.0)))
+            .id();
+        app.update();
+        assert_eq!(
+            app.world().resource::<LightRemainingEstimate>().seconds,
+            Some(60.0)
+        );
+
+        // A draft doubles the burn rate; the estimate eases toward 30s
+        app.world_mut().entity_mut(candle).insert(Draft(2.0));
+        app.update();
+        let seconds = app
+            .world()
+            .resource::<LightRemainingEstimate>()
+            .seconds
+            .unwrap();
+        assert!((30.0..=60.0).contains(&seconds));
+
+        *app.world_mut().get_mut::<CandleState>(candle).unwrap() = CandleState::Extinguished;
+        app.update();
+        assert_eq!(
+            app.world().resource::<LightRemainingEstimate>().seconds,
+            None
+        );
+    }
+
+    #[test]
+    fn refused_pickups_show_one_toast_per_contact() {
+        use crate::components::inventory::{ItemCategory, ToolType};
+
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_event::<InventoryFullEvent>();
+        app.add_event::<ToastEvent>();
+        app.add_systems(Update, inventory_full_toast_system);
+
+        let player = app.world_mut().spawn(Player).id();
+        let event = InventoryFullEvent {
+            item: Item::Tool(ToolType::Ladder),
+            player,
+            reason: InventoryFullReason::CategoryFull(ItemCategory::Tool),
+        };
+
+        // Standing on the item: refused on consecutive frames
+        let mut shown = Vec::new();
+        for _ in 0..3 {
+            app.world_mut().send_event(event.clone());
+            app.update();
+            shown.extend(app.world_mut().resource_mut::<Events<ToastEvent>>().drain());
+        }
+        assert_eq!(
+            shown,
+            vec![ToastEvent::info(
+                "Can't carry any more tools (left the Tool)"
+            )]
+        );
+
+        // Stepping away and back shows it again
+        app.update();
+        app.update();
+        app.world_mut().send_event(event);
+        app.update();
+        let events = app.world().resource::<Events<ToastEvent>>();
+        assert_eq!(events.get_cursor().read(events).count(), 1);
+    }
 }
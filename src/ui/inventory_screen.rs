@@ -0,0 +1,529 @@
+use bevy::prelude::*;
+use bevy_egui::{EguiContexts, egui};
+use leafwing_input_manager::prelude::*;
+
+use crate::audio::sound_bank::{PlaySoundEvent, SoundCue};
+use crate::components::inventory::{HOTBAR_SLOTS, Inventory, Item, KeyType, ToolType};
+use crate::components::lighting::{CandleState, CandleWax, CarriedCandle};
+use crate::components::player::Player;
+use crate::resources::input_config::PlayerAction;
+use crate::systems::hotbar::AssignHotbarSlotEvent;
+use crate::systems::inventory::{
+    ItemUsedEvent, MergeCandleStubsEvent, can_light_candle, can_refill_candle,
+    candle_stub_merge_target, same_item,
+};
+use crate::ui::hud::item_name;
+use crate::ui::journal::{Journal, NoteContent, collected_pages, journal_tab_ui};
+
+/// Size (pixels) of an item slot in the inventory grid
+pub const ITEM_SLOT_SIZE: f32 = 56.0;
+
+/// Item slots per row of the inventory grid
+pub const GRID_COLUMNS: usize = 5;
+
+/// Plugin that registers the inventory screen
+///
+/// The screen opens with `PlayerAction::OpenInventory` (I) and has two tabs:
+/// a grid of carried items (identical items stacked, with context actions)
+/// and the journal of collected diary pages.
+///
+/// **NOTE**: EguiPlugin and `JournalPlugin` must be added to the app before
+/// this plugin.
+pub struct InventoryScreenPlugin;
+
+impl Plugin for InventoryScreenPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<InventoryScreen>()
+            .add_event::<ItemUsedEvent>()
+            .add_event::<MergeCandleStubsEvent>()
+            .add_event::<AssignHotbarSlotEvent>()
+            .add_event::<PlaySoundEvent>()
+            .add_systems(
+                Update,
+                (toggle_inventory_screen_system, inventory_screen_system).chain(),
+            );
+    }
+}
+
+/// Tabs of the inventory screen
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum InventoryTab {
+    /// Everything the player carries
+    #[default]
+    Items,
+    /// Collected diary pages in page order
+    Journal,
+}
+
+/// Resource holding the inventory screen state
+#[derive(Resource, Default, Debug)]
+pub struct InventoryScreen {
+    /// Whether the screen is shown
+    pub open: bool,
+    /// Tab shown
+    pub tab: InventoryTab,
+    /// Index (into `stack_items`) of the stack being examined
+    pub examining: Option<usize>,
+}
+
+/// Identical inventory items shown in one grid slot
+#[derive(Clone)]
+pub struct ItemStack {
+    /// The item
+    pub item: Item,
+    /// How many the player carries
+    pub count: usize,
+}
+
+/// Action offered for an item on the inventory screen
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ItemAction {
+    /// Strike a match to light the carried candle
+    LightCandle,
+    /// Pour a candle stub into the carried candle
+    RefillCandle,
+    /// Pour a candle stub into another stub
+    MergeStubs,
+    /// Open a diary page in the reader
+    Read(usize),
+    /// Show the item close up in the item viewer
+    Examine,
+    /// Put the item in a hotbar slot (0 for the slot bound to key 1)
+    AssignHotbar(usize),
+}
+
+/// Groups identical items, in order of first appearance
+pub fn stack_items(items: &[Item]) -> Vec<ItemStack> {
+    let mut stacks: Vec<ItemStack> = Vec::new();
+    for item in items {
+        match stacks.iter_mut().find(|stack| same_item(&stack.item, item)) {
+            Some(stack) => stack.count += 1,
+            None => stacks.push(ItemStack {
+                item: item.clone(),
+                count: 1,
+            }),
+        }
+    }
+    stacks
+}
+
+/// Returns the actions offered for `item`
+pub fn item_actions(item: &Item) -> Vec<ItemAction> {
+    match item {
+        Item::Match => vec![ItemAction::LightCandle, ItemAction::Examine],
+        Item::DiaryPage(page) => vec![ItemAction::Read(*page), ItemAction::Examine],
+        Item::CandleStub(_) => vec![
+            ItemAction::RefillCandle,
+            ItemAction::MergeStubs,
+            ItemAction::Examine,
+        ],
+        _ => vec![ItemAction::Examine],
+    }
+}
+
+/// Returns the text shown when examining `item` if it has no close-up
+pub fn item_description(item: &Item) -> &'static str {
+    match item {
+        Item::Match => "A dry match. Strike it to light an unlit candle.",
+        Item::Key(KeyType::Brass) => "A worn brass key. Common locks in the house take these.",
+        Item::Key(KeyType::Iron) => "A heavy iron key for a sturdy lock.",
+        Item::Key(KeyType::Ornate) => "An ornate key, too beautiful for an ordinary door.",
+        Item::Key(KeyType::Master) => "The master key. It opens most locks and is never used up.",
+        Item::Tool(ToolType::Wrench) => "A wrench for stubborn bolts and valves.",
+        Item::Tool(ToolType::Crowbar) => "A crowbar. Good for prying things open.",
+        Item::Tool(ToolType::WireCutters) => "Wire cutters for electrical work.",
+        Item::Tool(ToolType::Magnet) => "A magnet strong enough to pull metal from a distance.",
+        Item::Tool(ToolType::OilCan(_)) => "An oil can for rusted mechanisms.",
+        Item::Tool(ToolType::Ladder) => "A folding ladder to reach high places.",
+        Item::Tool(ToolType::GasMask) => "A gas mask. Breathe easy in poisoned rooms.",
+        Item::Tool(ToolType::GrapplingRope) => "A rope and hook. Swing from beams and iron rings.",
+        Item::PuzzleItem(_) => "Part of a mechanism somewhere in the house.",
+        Item::DoubleJumpItem => "It makes you feel lighter on your feet.",
+        Item::DiaryPage(_) => "A page torn from a diary.",
+        Item::CandleStub(_) => {
+            "A stub of candle. Pour its wax into your candle or melt it into another stub."
+        }
+    }
+}
+
+/// Returns the sprite drawn for `item`, if it has one
+pub fn item_icon_path(item: &Item) -> Option<&'static str> {
+    match item {
+        Item::Match => Some("sprites/match.png"),
+        Item::Key(_) => Some("sprites/key.png"),
+        Item::CandleStub(_) => Some("sprites/candle.png"),
+        _ => None,
+    }
+}
+
+/// Label of an action button
+fn action_label(action: ItemAction) -> &'static str {
+    match action {
+        ItemAction::LightCandle => "Light candle",
+        ItemAction::RefillCandle => "Top up candle",
+        ItemAction::MergeStubs => "Merge with another stub",
+        ItemAction::Read(_) => "Read",
+        ItemAction::Examine => "Examine",
+        ItemAction::AssignHotbar(_) => "Assign to hotbar",
+    }
+}
+
+/// Which of the conditional actions can be taken for a stack
+#[derive(Debug, Clone, Copy, Default)]
+struct ActionAvailability {
+    /// A match would light the carried candle
+    can_light: bool,
+    /// The carried candle has room for more wax
+    can_refill: bool,
+    /// Another stub has room for this stub's wax
+    can_merge: bool,
+}
+
+impl ActionAvailability {
+    /// Returns true if the button for `action` is enabled
+    fn allows(self, action: ItemAction) -> bool {
+        match action {
+            ItemAction::LightCandle => self.can_light,
+            ItemAction::RefillCandle => self.can_refill,
+            ItemAction::MergeStubs => self.can_merge,
+            _ => true,
+        }
+    }
+}
+
+/// Draws the action buttons for `stack`
+///
+/// `LightCandle`, `RefillCandle` and `MergeStubs` are disabled unless
+/// `available` allows them. Quick-usable items also get one button per
+/// hotbar slot.
+///
+/// # Returns
+/// The action whose button was clicked, if any
+fn item_action_buttons(
+    ui: &mut egui::Ui,
+    stack: &ItemStack,
+    available: ActionAvailability,
+) -> Option<ItemAction> {
+    let mut chosen = None;
+    for action in item_actions(&stack.item) {
+        let enabled = available.allows(action);
+        if ui
+            .add_enabled(enabled, egui::Button::new(action_label(action)))
+            .clicked()
+        {
+            chosen = Some(action);
+            ui.close_kind(egui::UiKind::Menu);
+        }
+    }
+    if stack.item.is_quick_usable() {
+        for slot in 0..HOTBAR_SLOTS {
+            let action = ItemAction::AssignHotbar(slot);
+            if ui
+                .button(format!("Hotbar {}", slot + 1))
+                .on_hover_text(action_label(action))
+                .clicked()
+            {
+                chosen = Some(action);
+                ui.close_kind(egui::UiKind::Menu);
+            }
+        }
+    }
+    chosen
+}
+
+/// System that opens and closes the inventory screen with `PlayerAction::OpenInventory`
+///
+/// Plays `SoundCue::UiConfirm` when opening and `SoundCue::UiCancel` when closing.
+pub fn toggle_inventory_screen_system(
+    player_query: Query<&ActionState<PlayerAction>, With<Player>>,
+    mut screen: ResMut<InventoryScreen>,
+    mut sound_events: EventWriter<PlaySoundEvent>,
+) {
+    if !player_query
+        .iter()
+        .any(|actions| actions.just_pressed(&PlayerAction::OpenInventory))
+    {
+        return;
+    }
+
+    screen.open = !screen.open;
+    screen.examining = None;
+    sound_events.write(PlaySoundEvent {
+        cue: if screen.open {
+            SoundCue::UiConfirm
+        } else {
+            SoundCue::UiCancel
+        },
+    });
+}
+
+/// System that renders the inventory screen and applies the chosen item actions
+///
+/// The "Items" tab shows a grid of item stacks with icons and counts.
+/// Hovering a slot shows its name; clicking it examines the item (shown
+/// close up by `item_viewer_system`) and right-clicking opens its context
+/// actions. The "Journal" tab lists the collected diary pages.
+///
+/// # System Dependencies
+/// - **Resources**: EguiContexts (from bevy_egui), AssetServer, NoteContent,
+///   writes InventoryScreen and Journal
+/// - **Components**: Reads the player's `Inventory` and the `CarriedCandle` state
+/// - **Downstream**: `match_use_system`, `candle_stub_use_system` and
+///   `inventory_usage_system` read `ItemUsedEvent`;
+///   `candle_stub_merge_system` reads `MergeCandleStubsEvent`;
+///   `hotbar_assign_system` reads `AssignHotbarSlotEvent`;
+///   `diary_reader_ui_system` shows pages picked to read
+#[allow(clippy::too_many_arguments)]
+pub fn inventory_screen_system(
+    mut contexts: EguiContexts,
+    asset_server: Res<AssetServer>,
+    notes: Res<NoteContent>,
+    mut screen: ResMut<InventoryScreen>,
+    mut journal: ResMut<Journal>,
+    player_query: Query<(Entity, &Inventory), With<Player>>,
+    candle_query: Query<(&CandleState, &CandleWax), With<CarriedCandle>>,
+    mut item_used_events: EventWriter<ItemUsedEvent>,
+    mut merge_events: EventWriter<MergeCandleStubsEvent>,
+    mut hotbar_events: EventWriter<AssignHotbarSlotEvent>,
+    mut sound_events: EventWriter<PlaySoundEvent>,
+) {
+    if !screen.open {
+        return;
+    }
+    let Ok((player, inventory)) = player_query.single() else {
+        return;
+    };
+
+    let stacks = stack_items(&inventory.items);
+    let icons: Vec<Option<egui::TextureId>> = stacks
+        .iter()
+        .map(|stack| {
+            item_icon_path(&stack.item).map(|path| contexts.add_image(asset_server.load(path)))
+        })
+        .collect();
+    let can_light = candle_query
+        .iter()
+        .any(|(state, wax)| can_light_candle(state, wax));
+    let can_refill = candle_query
+        .iter()
+        .any(|(state, wax)| can_refill_candle(state, wax));
+    let available: Vec<ActionAvailability> = stacks
+        .iter()
+        .map(|stack| ActionAvailability {
+            can_light,
+            can_refill,
+            can_merge: inventory
+                .items
+                .iter()
+                .position(|item| same_item(&stack.item, item))
+                .and_then(|from| candle_stub_merge_target(inventory, from))
+                .is_some(),
+        })
+        .collect();
+
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+
+    let mut open = true;
+    let mut chosen: Option<(usize, ItemAction)> = None;
+    let mut read = None;
+    egui::Window::new("Inventory")
+        .open(&mut open)
+        .anchor(egui::Align2::LEFT_CENTER, [48.0, 0.0])
+        .resizable(false)
+        .collapsible(false)
+        .show(ctx, |ui| {
+            ui.horizontal(|ui| {
+                ui.selectable_value(&mut screen.tab, InventoryTab::Items, "Items");
+                ui.selectable_value(&mut screen.tab, InventoryTab::Journal, "Journal");
+            });
+            ui.separator();
+
+            match screen.tab {
+                InventoryTab::Items => {
+                    ui.label(format!(
+                        "{}/{} slots used",
+                        inventory.slots_used(),
+                        inventory.max_capacity
+                    ));
+                    if stacks.is_empty() {
+                        ui.label("(empty)");
+                        return;
+                    }
+
+                    egui::Grid::new("inventory_grid")
+                        .spacing([6.0, 6.0])
+                        .show(ui, |ui| {
+                            for (index, (stack, icon)) in stacks.iter().zip(&icons).enumerate() {
+                                let slot = egui::vec2(ITEM_SLOT_SIZE, ITEM_SLOT_SIZE);
+                                let button = match icon {
+                                    Some(texture) => egui::Button::image((*texture, slot * 0.7)),
+                                    None => egui::Button::new(item_name(&stack.item)).wrap(),
+                                };
+                                let response = ui
+                                    .add_sized(
+                                        slot,
+                                        button.selected(screen.examining == Some(index)),
+                                    )
+                                    .on_hover_text(item_name(&stack.item));
+                                if stack.count > 1 {
+                                    ui.painter().text(
+                                        response.rect.right_bottom() - egui::vec2(4.0, 2.0),
+                                        egui::Align2::RIGHT_BOTTOM,
+                                        format!("x{}", stack.count),
+                                        egui::FontId::proportional(14.0),
+                                        egui::Color32::WHITE,
+                                    );
+                                }
+                                if response.clicked() {
+                                    chosen = Some((index, ItemAction::Examine));
+                                }
+                                response.context_menu(|ui| {
+                                    if let Some(action) =
+                                        item_action_buttons(ui, stack, available[index])
+                                    {
+                                        chosen = Some((index, action));
+                                    }
+                                });
+
+                                if (index + 1) % GRID_COLUMNS == 0 {
+                                    ui.end_row();
+                                }
+                            }
+                        });
+
+                    if let Some(stack) = screen.examining.and_then(|index| stacks.get(index)) {
+                        ui.separator();
+                        ui.strong(item_name(&stack.item));
+                        ui.horizontal(|ui| {
+                            if let Some(index) = screen.examining
+                                && let Some(action) =
+                                    item_action_buttons(ui, stack, available[index])
+                            {
+                                chosen = Some((index, action));
+                            }
+                        });
+                    }
+                }
+                InventoryTab::Journal => {
+                    read = journal_tab_ui(ui, &notes, &collected_pages(inventory));
+                }
+            }
+        });
+
+    if let Some((index, action)) = chosen {
+        match action {
+            ItemAction::LightCandle => {
+                item_used_events.write(ItemUsedEvent {
+                    item: Item::Match,
+                    player,
+                });
+            }
+            ItemAction::RefillCandle => {
+                if let Some(stack) = stacks.get(index) {
+                    item_used_events.write(ItemUsedEvent {
+                        item: stack.item.clone(),
+                        player,
+                    });
+                }
+            }
+            ItemAction::MergeStubs => {
+                if let Some(Item::CandleStub(wax)) = stacks.get(index).map(|stack| &stack.item) {
+                    merge_events.write(MergeCandleStubsEvent { wax: *wax, player });
+                }
+            }
+            ItemAction::Read(page) => read = Some(page),
+            ItemAction::Examine => screen.examining = Some(index),
+            ItemAction::AssignHotbar(slot) => {
+                hotbar_events.write(AssignHotbarSlotEvent {
+                    player,
+                    slot,
+                    item: stacks.get(index).map(|stack| stack.item.clone()),
+                });
+            }
+        }
+        sound_events.write(PlaySoundEvent {
+            cue: SoundCue::UiConfirm,
+        });
+    }
+
+    if let Some(page) = read {
+        journal.reading = Some(page);
+        sound_events.write(PlaySoundEvent {
+            cue: SoundCue::UiConfirm,
+        });
+    }
+
+    if !open {
+        screen.open = false;
+        screen.examining = None;
+        sound_events.write(PlaySoundEvent {
+            cue: SoundCue::UiCancel,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn identical_items_stack_in_first_seen_order() {
+        let stacks = stack_items(&[
+            Item::Match,
+            Item::Key(KeyType::Brass),
+            Item::Match,
+            Item::Key(KeyType::Iron),
+            Item::Match,
+        ]);
+
+        assert_eq!(stacks.len(), 3);
+        assert!(matches!(stacks[0].item, Item::Match));
+        assert_eq!(stacks[0].count, 3);
+        assert!(matches!(stacks[1].item, Item::Key(KeyType::Brass)));
+        assert_eq!(stacks[1].count, 1);
+        assert!(matches!(stacks[2].item, Item::Key(KeyType::Iron)));
+    }
+
+    #[test]
+    fn items_offer_their_context_actions() {
+        assert_eq!(
+            item_actions(&Item::Match),
+            vec![ItemAction::LightCandle, ItemAction::Examine]
+        );
+        assert_eq!(
+            item_actions(&Item::DiaryPage(4)),
+            vec![ItemAction::Read(4), ItemAction::Examine]
+        );
+        assert_eq!(
+            item_actions(&Item::Key(KeyType::Ornate)),
+            vec![ItemAction::Examine]
+        );
+        assert_eq!(
+            item_actions(&Item::CandleStub(25.0)),
+            vec![
+                ItemAction::RefillCandle,
+                ItemAction::MergeStubs,
+                ItemAction::Examine
+            ]
+        );
+        assert!(!item_description(&Item::Key(KeyType::Master)).is_empty());
+    }
+
+    #[test]
+    fn inventory_action_toggles_screen() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.init_resource::<InventoryScreen>();
+        app.add_event::<PlaySoundEvent>();
+        app.add_systems(Update, toggle_inventory_screen_system);
+
+        let mut actions = ActionState::<PlayerAction>::default();
+        actions.press(&PlayerAction::OpenInventory);
+        app.world_mut().spawn((Player, actions));
+        app.update();
+
+        assert!(app.world().resource::<InventoryScreen>().open);
+    }
+}
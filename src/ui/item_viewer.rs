@@ -0,0 +1,393 @@
+use bevy::prelude::*;
+use bevy_egui::{EguiContexts, egui};
+use serde::Deserialize;
+use std::fs;
+
+use crate::audio::sound_bank::{PlaySoundEvent, SoundCue};
+use crate::components::inventory::{Inventory, Item};
+use crate::components::player::Player;
+use crate::systems::save_load::{SerializedItem, serialize_item};
+use crate::ui::hud::item_name;
+use crate::ui::inventory_screen::{
+    InventoryScreen, inventory_screen_system, item_description, stack_items,
+};
+
+/// Default location of the item close-ups, relative to the working directory
+pub const CLOSE_UPS_PATH: &str = "assets/items/close_ups.ron";
+
+/// Width and height (points) of the close-up drawing
+pub const CLOSE_UP_SIZE: f32 = 256.0;
+
+/// Font size of the item name drawn for faces without an image
+const PLACEHOLDER_FONT_SIZE: f32 = 28.0;
+
+/// Plugin that registers the item close-ups and the viewer window
+///
+/// The viewer shows the stack examined on the inventory screen (see
+/// `inventory_screen::InventoryScreenPlugin`): a large drawing of the item
+/// that can be turned and flipped over, its description and any
+/// inscription that reads the right way up.
+///
+/// **NOTE**: EguiPlugin and `InventoryScreenPlugin` must be added to the app
+/// before this plugin.
+pub struct ItemViewerPlugin;
+
+impl Plugin for ItemViewerPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<CloseUps>()
+            .init_resource::<ItemViewer>()
+            .add_event::<PlaySoundEvent>()
+            .add_systems(Startup, load_close_ups_system)
+            .add_systems(Update, item_viewer_system.after(inventory_screen_system));
+    }
+}
+
+/// One side of an item in close-up
+#[derive(Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct CloseUpFace {
+    /// Image drawn for the side; the back without one is the front from behind
+    #[serde(default)]
+    pub image: Option<String>,
+    /// Text engraved or written on the side
+    #[serde(default)]
+    pub inscription: Option<String>,
+    /// Quarter turns clockwise at which the inscription reads the right way up
+    #[serde(default)]
+    pub upright_turns: u8,
+}
+
+/// Close-up of one item kind
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct ItemCloseUp {
+    /// Item shown; candle stubs and oil cans match whatever wax or oil is left
+    pub item: SerializedItem,
+    /// Text shown under the drawing
+    pub description: String,
+    /// Side shown first
+    #[serde(default)]
+    pub front: CloseUpFace,
+    /// Side shown once the item is flipped over
+    #[serde(default)]
+    pub back: CloseUpFace,
+}
+
+impl ItemCloseUp {
+    /// Returns true if this is the close-up of `item`
+    pub fn shows(&self, item: &Item) -> bool {
+        match (&self.item, serialize_item(item)) {
+            (SerializedItem::CandleStub(_), SerializedItem::CandleStub(_))
+            | (SerializedItem::OilCan(_), SerializedItem::OilCan(_)) => true,
+            (close_up, item) => *close_up == item,
+        }
+    }
+}
+
+/// Resource holding the close-ups of every item with one
+///
+/// Loaded from `assets/items/close_ups.ron` at startup.
+#[derive(Resource, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct CloseUps {
+    /// Close-ups, looked up by the item they show
+    pub items: Vec<ItemCloseUp>,
+}
+
+impl CloseUps {
+    /// Returns the close-up of `item`, if it has one
+    pub fn get(&self, item: &Item) -> Option<&ItemCloseUp> {
+        self.items.iter().find(|close_up| close_up.shows(item))
+    }
+}
+
+/// How the examined item is turned in the viewer
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CloseUpView {
+    /// Quarter turns clockwise (0-3)
+    pub quarter_turns: u8,
+    /// Whether the back is showing
+    pub flipped: bool,
+}
+
+impl CloseUpView {
+    /// Turns the item a quarter turn clockwise
+    pub fn turn_clockwise(&mut self) {
+        self.quarter_turns = (self.quarter_turns + 1) % 4;
+    }
+
+    /// Turns the item a quarter turn anticlockwise
+    pub fn turn_anticlockwise(&mut self) {
+        self.quarter_turns = (self.quarter_turns + 3) % 4;
+    }
+
+    /// Flips the item over
+    ///
+    /// Flipping mirrors the item left to right, so a turned item keeps its
+    /// outline and the turn reads the other way round.
+    pub fn flip(&mut self) {
+        self.flipped = !self.flipped;
+        self.quarter_turns = (4 - self.quarter_turns) % 4;
+    }
+
+    /// Returns the side of `close_up` that is showing
+    pub fn face<'a>(&self, close_up: &'a ItemCloseUp) -> &'a CloseUpFace {
+        if self.flipped {
+            &close_up.back
+        } else {
+            &close_up.front
+        }
+    }
+
+    /// Returns the inscription on the showing side if it reads the right way up
+    pub fn legible_inscription<'a>(&self, close_up: &'a ItemCloseUp) -> Option<&'a str> {
+        let face = self.face(close_up);
+        if face.upright_turns % 4 != self.quarter_turns {
+            return None;
+        }
+        face.inscription.as_deref()
+    }
+
+    /// Rotation of the drawing in radians (clockwise on screen)
+    pub fn angle(&self) -> f32 {
+        self.quarter_turns as f32 * std::f32::consts::FRAC_PI_2
+    }
+}
+
+/// Resource holding the item viewer state
+#[derive(Resource, Default, Debug)]
+pub struct ItemViewer {
+    /// Stack (index into `stack_items`) the view belongs to
+    pub showing: Option<usize>,
+    /// How the item is turned
+    pub view: CloseUpView,
+}
+
+/// Load the item close-ups from a RON file
+///
+/// # Arguments
+/// * `path` - Path to the RON file (e.g., "assets/items/close_ups.ron")
+///
+/// # Errors
+/// Returns error string if the file cannot be read or parsed
+pub fn load_close_ups(path: &str) -> Result<CloseUps, String> {
+    let content = fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read close-ups '{}': {}", path, e))?;
+
+    ron::from_str(&content).map_err(|e| format!("Failed to parse RON from '{}': {}", path, e))
+}
+
+/// Startup system that loads the item close-ups from disk
+///
+/// Falls back to no close-ups (items show their plain description) if the
+/// file is missing or malformed.
+pub fn load_close_ups_system(mut commands: Commands) {
+    match load_close_ups(CLOSE_UPS_PATH) {
+        Ok(close_ups) => {
+            info!("Loaded {} item close-ups", close_ups.items.len());
+            commands.insert_resource(close_ups);
+        }
+        Err(e) => {
+            warn!("{}. Items will have no close-ups.", e);
+            commands.insert_resource(CloseUps::default());
+        }
+    }
+}
+
+/// Draws the showing side of `item` at `CLOSE_UP_SIZE`, turned and flipped
+///
+/// Sides without an image show the item's name in their place.
+fn draw_close_up(
+    ui: &mut egui::Ui,
+    item: &Item,
+    view: CloseUpView,
+    texture: Option<egui::TextureId>,
+    mirrored: bool,
+) {
+    let size = egui::Vec2::splat(CLOSE_UP_SIZE);
+    let Some(texture) = texture else {
+        let (rect, _) = ui.allocate_exact_size(size, egui::Sense::hover());
+        let painter = ui.painter_at(rect);
+        painter.rect_filled(rect, 8.0, ui.visuals().extreme_bg_color);
+        let color = ui.visuals().strong_text_color();
+        let galley = painter.layout_no_wrap(
+            item_name(item),
+            egui::FontId::proportional(PLACEHOLDER_FONT_SIZE),
+            color,
+        );
+        // Text turns about its top-left corner, so offset it to stay centred
+        let rotation = egui::emath::Rot2::from_angle(view.angle());
+        let position = rect.center() - rotation * (galley.size() / 2.0);
+        painter.add(egui::epaint::TextShape::new(position, galley, color).with_angle(view.angle()));
+        return;
+    };
+
+    let uv = if mirrored {
+        egui::Rect::from_min_max(egui::pos2(1.0, 0.0), egui::pos2(0.0, 1.0))
+    } else {
+        egui::Rect::from_min_max(egui::Pos2::ZERO, egui::pos2(1.0, 1.0))
+    };
+    ui.add(
+        egui::Image::new(egui::load::SizedTexture::new(texture, size))
+            .uv(uv)
+            .rotate(view.angle(), egui::Vec2::splat(0.5)),
+    );
+}
+
+/// System that renders the close-up of the stack examined on the inventory screen
+///
+/// # System Dependencies
+/// - **Upstream**: `inventory_screen_system` sets `InventoryScreen::examining`
+/// - **Resources**: EguiContexts (from bevy_egui), AssetServer, CloseUps,
+///   writes ItemViewer and InventoryScreen
+/// - **Components**: Reads the player's `Inventory`
+/// - **Downstream**: `play_sound_requests` plays the turn/close cues
+///
+/// # Behavior
+/// The view resets whenever a different stack is examined. Closing the
+/// viewer stops examining the stack.
+pub fn item_viewer_system(
+    mut contexts: EguiContexts,
+    asset_server: Res<AssetServer>,
+    close_ups: Res<CloseUps>,
+    mut viewer: ResMut<ItemViewer>,
+    mut screen: ResMut<InventoryScreen>,
+    inventory_query: Query<&Inventory, With<Player>>,
+    mut sound_events: EventWriter<PlaySoundEvent>,
+) {
+    if viewer.showing != screen.examining {
+        viewer.showing = screen.examining;
+        viewer.view = CloseUpView::default();
+    }
+    let Some(index) = screen.examining.filter(|_| screen.open) else {
+        return;
+    };
+    let Ok(inventory) = inventory_query.single() else {
+        return;
+    };
+    let Some(stack) = stack_items(&inventory.items).into_iter().nth(index) else {
+        return;
+    };
+
+    let close_up = close_ups.get(&stack.item);
+    let view = viewer.view;
+    let face = close_up.map(|close_up| view.face(close_up));
+    // Without an image of its own the back is the front seen from behind
+    let (image, mirrored) = match face.and_then(|face| face.image.clone()) {
+        Some(image) => (Some(image), false),
+        None if view.flipped => (
+            close_up.and_then(|close_up| close_up.front.image.clone()),
+            true,
+        ),
+        None => (None, false),
+    };
+    let texture = image.map(|path| contexts.add_image(asset_server.load(path)));
+
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+
+    let mut open = true;
+    egui::Window::new(item_name(&stack.item))
+        .id(egui::Id::new("item_viewer"))
+        .open(&mut open)
+        .anchor(egui::Align2::RIGHT_CENTER, [-48.0, 0.0])
+        .resizable(false)
+        .collapsible(false)
+        .show(ctx, |ui| {
+            ui.set_max_width(CLOSE_UP_SIZE);
+            ui.vertical_centered(|ui| {
+                draw_close_up(ui, &stack.item, view, texture, mirrored);
+            });
+
+            ui.horizontal(|ui| {
+                let turn_left = ui.button("Turn left").clicked();
+                let turn_right = ui.button("Turn right").clicked();
+                let flip = ui.button("Flip over").clicked();
+                if turn_left {
+                    viewer.view.turn_anticlockwise();
+                }
+                if turn_right {
+                    viewer.view.turn_clockwise();
+                }
+                if flip {
+                    viewer.view.flip();
+                }
+                if turn_left || turn_right || flip {
+                    sound_events.write(PlaySoundEvent {
+                        cue: SoundCue::UiMove,
+                    });
+                }
+            });
+
+            ui.separator();
+            match close_up {
+                Some(close_up) => {
+                    ui.label(&close_up.description);
+                    if let Some(inscription) = view.legible_inscription(close_up) {
+                        ui.label(egui::RichText::new(inscription).italics().strong());
+                    } else if view.face(close_up).inscription.is_some() {
+                        ui.weak("Something is written here, but not the right way up.");
+                    }
+                }
+                None => {
+                    ui.label(item_description(&stack.item));
+                }
+            }
+        });
+
+    if !open {
+        screen.examining = None;
+        sound_events.write(PlaySoundEvent {
+            cue: SoundCue::UiCancel,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::inventory::{KeyType, PuzzleItemType, ToolType};
+
+    #[test]
+    fn close_ups_file_parses() {
+        let close_ups = load_close_ups(CLOSE_UPS_PATH).expect("close-ups should parse");
+        assert!(!close_ups.items.is_empty());
+
+        assert!(close_ups.get(&Item::Key(KeyType::Ornate)).is_some());
+        assert!(close_ups.get(&Item::CandleStub(12.0)).is_some());
+        assert!(
+            close_ups
+                .get(&Item::PuzzleItem(PuzzleItemType::Gemstone(Color::srgb(
+                    0.9, 0.1, 0.1
+                ))))
+                .is_some()
+        );
+        assert!(close_ups.get(&Item::Tool(ToolType::Wrench)).is_none());
+    }
+
+    #[test]
+    fn inscriptions_read_only_the_right_way_up() {
+        let close_up: ItemCloseUp = ron::from_str(
+            r#"(
+                item: Key(Ornate),
+                description: "An ornate key.",
+                front: (inscription: Some("Circle"), upright_turns: 1),
+                back: (inscription: Some("Star")),
+            )"#,
+        )
+        .unwrap();
+
+        let mut view = CloseUpView::default();
+        assert_eq!(view.legible_inscription(&close_up), None);
+        view.turn_clockwise();
+        assert_eq!(view.legible_inscription(&close_up), Some("Circle"));
+
+        // Flipping the turned key over turns it back the other way
+        view.flip();
+        assert_eq!(view.quarter_turns, 3);
+        assert_eq!(view.legible_inscription(&close_up), None);
+        view.turn_clockwise();
+        assert_eq!(view.legible_inscription(&close_up), Some("Star"));
+
+        view.turn_anticlockwise();
+        assert_eq!(view.quarter_turns, 3);
+    }
+}
@@ -0,0 +1,304 @@
+use bevy::prelude::*;
+use bevy_egui::{EguiContexts, egui};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fs;
+
+use crate::audio::sound_bank::{PlaySoundEvent, SoundCue};
+use crate::components::inventory::{Inventory, Item};
+use crate::components::player::Player;
+use crate::systems::inventory::{ItemCollectedEvent, inventory_collection_system};
+use crate::ui::hud::item_name;
+
+/// Default location of the diary page text, relative to the working directory
+pub const NOTES_PATH: &str = "assets/notes/diary.ron";
+
+/// Plugin that registers the diary page text and reader
+///
+/// Picking up a diary page opens it in the reader straight away; collected
+/// pages can be re-read from the inventory screen (see
+/// `inventory_screen::InventoryScreenPlugin`).
+///
+/// **NOTE**: EguiPlugin must be added to the app before this plugin.
+pub struct JournalPlugin;
+
+impl Plugin for JournalPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<NoteContent>()
+            .init_resource::<Journal>()
+            .add_event::<ItemCollectedEvent>()
+            .add_event::<PlaySoundEvent>()
+            .add_systems(Startup, load_notes_system)
+            .add_systems(
+                Update,
+                (
+                    // Must see the page before the collection system despawns it
+                    diary_page_pickup_system.before(inventory_collection_system),
+                    diary_reader_ui_system,
+                ),
+            );
+    }
+}
+
+/// Text of one diary page
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct NotePage {
+    /// Title shown at the top of the reader and in the journal list
+    pub title: String,
+    /// Page text; blank lines separate paragraphs, "# " lines are headings
+    pub text: String,
+}
+
+/// Resource holding the text of every diary page, keyed by page number
+///
+/// Loaded from `assets/notes/diary.ron` at startup.
+#[derive(Resource, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct NoteContent {
+    /// Pages by the number of their `Item::DiaryPage`
+    pub pages: BTreeMap<usize, NotePage>,
+}
+
+impl NoteContent {
+    /// Returns the title of `page`, falling back to "Diary Page n"
+    pub fn title(&self, page: usize) -> String {
+        self.pages
+            .get(&page)
+            .map(|note| note.title.clone())
+            .unwrap_or_else(|| item_name(&Item::DiaryPage(page)))
+    }
+}
+
+/// Load the diary page text from a RON file
+///
+/// # Arguments
+/// * `path` - Path to the RON file (e.g., "assets/notes/diary.ron")
+///
+/// # Errors
+/// Returns error string if the file cannot be read or parsed
+pub fn load_notes(path: &str) -> Result<NoteContent, String> {
+    let content =
+        fs::read_to_string(path).map_err(|e| format!("Failed to read notes '{}': {}", path, e))?;
+
+    ron::from_str(&content).map_err(|e| format!("Failed to parse RON from '{}': {}", path, e))
+}
+
+/// Startup system that loads the diary page text from disk
+///
+/// Falls back to no page text (pages show a placeholder) if the file is
+/// missing or malformed.
+pub fn load_notes_system(mut commands: Commands) {
+    match load_notes(NOTES_PATH) {
+        Ok(notes) => {
+            info!("Loaded {} diary pages", notes.pages.len());
+            commands.insert_resource(notes);
+        }
+        Err(e) => {
+            warn!("{}. Diary pages will have no text.", e);
+            commands.insert_resource(NoteContent::default());
+        }
+    }
+}
+
+/// Resource holding the diary page reader state
+#[derive(Resource, Default, Debug)]
+pub struct Journal {
+    /// Diary page open in the reader
+    pub reading: Option<usize>,
+}
+
+/// Returns the diary pages in `inventory`, sorted and without duplicates
+pub fn collected_pages(inventory: &Inventory) -> Vec<usize> {
+    let mut pages: Vec<usize> = inventory
+        .items
+        .iter()
+        .filter_map(|item| match item {
+            Item::DiaryPage(page) => Some(*page),
+            _ => None,
+        })
+        .collect();
+    pages.sort_unstable();
+    pages.dedup();
+    pages
+}
+
+/// System that opens picked-up diary pages in the reader
+///
+/// # System Dependencies
+/// - **Upstream**: `collision_detection_system` emits `ItemCollectedEvent`
+/// - **Components**: Reads `Item`
+/// - **Resources**: Writes `Journal`
+///
+/// Runs before `inventory_collection_system`, which despawns the page entity.
+pub fn diary_page_pickup_system(
+    mut events: EventReader<ItemCollectedEvent>,
+    item_query: Query<&Item>,
+    mut journal: ResMut<Journal>,
+) {
+    for event in events.read() {
+        if let Ok(Item::DiaryPage(page)) = item_query.get(event.item) {
+            journal.reading = Some(*page);
+        }
+    }
+}
+
+/// Draws the journal tab: one button per collected page, by title
+///
+/// # Returns
+/// The page whose button was clicked, if any
+pub fn journal_tab_ui(ui: &mut egui::Ui, notes: &NoteContent, pages: &[usize]) -> Option<usize> {
+    if pages.is_empty() {
+        ui.label("No diary pages found yet.");
+    }
+
+    let mut read = None;
+    for &page in pages {
+        if ui
+            .button(format!("{}. {}", page, notes.title(page)))
+            .clicked()
+        {
+            read = Some(page);
+        }
+    }
+    read
+}
+
+/// System that renders the diary page reader
+///
+/// Shows the page in `Journal::reading` with buttons to flip between the
+/// collected pages.
+///
+/// # System Dependencies
+/// - **Resources**: EguiContexts (from bevy_egui), NoteContent, Journal
+/// - **Components**: Reads the player's `Inventory`
+/// - **Downstream**: `play_sound_requests` plays the page/close cues
+pub fn diary_reader_ui_system(
+    mut contexts: EguiContexts,
+    notes: Res<NoteContent>,
+    mut journal: ResMut<Journal>,
+    inventory_query: Query<&Inventory, With<Player>>,
+    mut sound_events: EventWriter<PlaySoundEvent>,
+) {
+    let Some(page) = journal.reading else {
+        return;
+    };
+
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+
+    let pages = inventory_query
+        .single()
+        .map(collected_pages)
+        .unwrap_or_default();
+
+    let position = pages.iter().position(|&p| p == page);
+    let previous = position.and_then(|i| i.checked_sub(1)).map(|i| pages[i]);
+    let next = position.and_then(|i| pages.get(i + 1).copied());
+    let mut reading = Some(page);
+
+    egui::Window::new(notes.title(page))
+        .id(egui::Id::new("diary_reader"))
+        .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+        .resizable(false)
+        .collapsible(false)
+        .show(ctx, |ui| {
+            ui.set_max_width(420.0);
+            match notes.pages.get(&page) {
+                Some(note) => {
+                    for line in note.text.lines() {
+                        if let Some(heading) = line.strip_prefix("# ") {
+                            ui.heading(heading);
+                        } else if line.trim().is_empty() {
+                            ui.add_space(6.0);
+                        } else {
+                            ui.label(line);
+                        }
+                    }
+                }
+                None => {
+                    ui.weak("The ink has run; the page can't be read.");
+                }
+            }
+
+            ui.separator();
+            ui.horizontal(|ui| {
+                if ui
+                    .add_enabled(previous.is_some(), egui::Button::new("< Previous"))
+                    .clicked()
+                {
+                    reading = previous;
+                    sound_events.write(PlaySoundEvent {
+                        cue: SoundCue::UiMove,
+                    });
+                }
+                if ui
+                    .add_enabled(next.is_some(), egui::Button::new("Next >"))
+                    .clicked()
+                {
+                    reading = next;
+                    sound_events.write(PlaySoundEvent {
+                        cue: SoundCue::UiMove,
+                    });
+                }
+                if ui.button("Close").clicked() {
+                    reading = None;
+                    sound_events.write(PlaySoundEvent {
+                        cue: SoundCue::UiCancel,
+                    });
+                }
+            });
+        });
+
+    journal.reading = reading;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn notes_file_parses() {
+        let notes = load_notes(NOTES_PATH).expect("notes should parse");
+        assert!(!notes.pages.is_empty());
+        assert!(notes.pages.values().all(|page| !page.text.is_empty()));
+        assert_eq!(notes.title(9999), "Diary Page 9999");
+    }
+
+    #[test]
+    fn collected_pages_are_sorted_and_unique() {
+        let inventory = Inventory {
+            items: vec![
+                Item::DiaryPage(3),
+                Item::Match,
+                Item::DiaryPage(1),
+                Item::DiaryPage(3),
+            ],
+            max_capacity: 10,
+        };
+        assert_eq!(collected_pages(&inventory), vec![1, 3]);
+    }
+
+    #[test]
+    fn picking_up_a_page_opens_the_reader() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.init_resource::<Journal>();
+        app.add_event::<ItemCollectedEvent>();
+        app.add_systems(Update, diary_page_pickup_system);
+
+        let player = app.world_mut().spawn(Player).id();
+        let page = app.world_mut().spawn(Item::DiaryPage(2)).id();
+        let matchstick = app.world_mut().spawn(Item::Match).id();
+        app.world_mut().send_event(ItemCollectedEvent {
+            item: matchstick,
+            player,
+        });
+        app.update();
+        assert_eq!(app.world().resource::<Journal>().reading, None);
+
+        app.world_mut()
+            .send_event(ItemCollectedEvent { item: page, player });
+        app.update();
+        assert_eq!(app.world().resource::<Journal>().reading, Some(2));
+    }
+}
@@ -0,0 +1,236 @@
+use bevy::prelude::*;
+use bevy_egui::{EguiContexts, egui};
+
+use crate::audio::sound_bank::{PlaySoundEvent, SoundCue};
+use crate::resources::game_rules::{Difficulty, GameRuleSet, NewGameEvent};
+use crate::resources::game_state::{GameMode, GameState};
+use crate::systems::attract_mode::AttractModeState;
+use crate::ui::credits::CreditsState;
+
+/// Rule sets offered on the New Game screen
+pub const NEW_GAME_RULE_SETS: [GameRuleSet; 3] = [
+    GameRuleSet::Normal,
+    GameRuleSet::Survival,
+    GameRuleSet::Explorer,
+];
+
+/// Difficulties offered on the New Game screen
+pub const NEW_GAME_DIFFICULTIES: [Difficulty; 3] =
+    [Difficulty::Easy, Difficulty::Normal, Difficulty::Hard];
+
+/// Plugin that registers the main menu
+///
+/// The menu is shown in `GameMode::Menu` and starts a new game with the
+/// rule set and difficulty picked on it, or the Daily Escape, by sending
+/// `NewGameEvent`
+/// (applied by `game_rules::GameRulesPlugin` and `daily::DailyPlugin`).
+///
+/// **NOTE**: EguiPlugin must be added to the app before this plugin.
+pub struct MainMenuPlugin;
+
+impl Plugin for MainMenuPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<MainMenu>()
+            .add_event::<NewGameEvent>()
+            .add_event::<PlaySoundEvent>()
+            .add_systems(Update, main_menu_system);
+    }
+}
+
+/// Resource holding the choices made on the main menu
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct MainMenu {
+    /// Rule set the next new game is played with
+    pub rules: GameRuleSet,
+    /// Difficulty the next new game is played at
+    pub difficulty: Difficulty,
+}
+
+impl MainMenu {
+    /// Event starting the game `choice` asks for
+    pub fn new_game_event(&self, choice: MenuChoice) -> NewGameEvent {
+        match choice {
+            MenuChoice::NewGame => NewGameEvent {
+                rules: self.rules,
+                difficulty: self.difficulty,
+            },
+            MenuChoice::DailyEscape => NewGameEvent {
+                rules: GameRuleSet::Daily,
+                difficulty: Difficulty::Normal,
+            },
+        }
+    }
+}
+
+/// What the player picked on the main menu
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MenuChoice {
+    /// Start a new game with the picked rules
+    NewGame,
+    /// Start today's Daily Escape
+    DailyEscape,
+}
+
+/// Draws the main menu and returns the button the player clicked
+///
+/// Split from `main_menu_system` so the layout can be drawn without a
+/// running app. Picking a rule set or difficulty only updates `menu`; the
+/// Daily Escape has fixed rules, so it gets its own button.
+pub fn draw_main_menu(ctx: &egui::Context, menu: &mut MainMenu) -> Option<MenuChoice> {
+    let mut choice = None;
+
+    egui::Window::new("New Game")
+        .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+        .resizable(false)
+        .collapsible(false)
+        .show(ctx, |ui| {
+            ui.label("Rules");
+            ui.horizontal(|ui| {
+                for rule_set in NEW_GAME_RULE_SETS {
+                    ui.selectable_value(&mut menu.rules, rule_set, rule_set.name());
+                }
+            });
+            ui.label("Difficulty");
+            ui.horizontal(|ui| {
+                for difficulty in NEW_GAME_DIFFICULTIES {
+                    ui.selectable_value(&mut menu.difficulty, difficulty, difficulty.name());
+                }
+            });
+            ui.separator();
+
+            ui.vertical_centered(|ui| {
+                if ui.button("Start").clicked() {
+                    choice = Some(MenuChoice::NewGame);
+                }
+            });
+            ui.separator();
+
+            ui.vertical_centered(|ui| {
+                if ui
+                    .button(GameRuleSet::Daily.name())
+                    .on_hover_text("Today's house, the same for every player")
+                    .clicked()
+                {
+                    choice = Some(MenuChoice::DailyEscape);
+                }
+            });
+        });
+
+    choice
+}
+
+/// System that shows the main menu while in `GameMode::Menu`
+///
+/// # System Dependencies
+/// - **Resources**: EguiContexts (from bevy_egui), `GameState`, writes
+///   `MainMenu`; reads `AttractModeState` and `CreditsState` (when present)
+/// - **Downstream**: Emits `NewGameEvent` for `new_game_system` and
+///   `PlaySoundEvent`
+///
+/// The menu is hidden while attract mode or the credits are showing.
+pub fn main_menu_system(
+    mut contexts: EguiContexts,
+    mut menu: ResMut<MainMenu>,
+    game_state: Res<GameState>,
+    attract: Option<Res<AttractModeState>>,
+    credits: Option<Res<CreditsState>>,
+    mut new_games: EventWriter<NewGameEvent>,
+    mut sound_events: EventWriter<PlaySoundEvent>,
+) {
+    if game_state.game_mode != GameMode::Menu
+        || attract.is_some_and(|attract| attract.active)
+        || credits.is_some_and(|credits| credits.active)
+    {
+        return;
+    }
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+
+    let mut picked = *menu;
+    let choice = draw_main_menu(ctx, &mut picked);
+    menu.set_if_neq(picked);
+    let Some(choice) = choice else {
+        return;
+    };
+    new_games.write(menu.new_game_event(choice));
+    sound_events.write(PlaySoundEvent {
+        cue: SoundCue::UiConfirm,
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::resources::game_rules::{DAILY_BURN_MULTIPLIER, GameRules, GameRulesPlugin};
+    use crate::systems::daily::{DailyChallenge, DailyPlugin, today};
+    use crate::ui::snapshot::click_text;
+
+    #[test]
+    fn new_game_screen_starts_the_picked_rules_and_difficulty() {
+        let mut menu = MainMenu::default();
+        let mut choice = None;
+        for label in ["Survival", "Hard", "Start"] {
+            click_text(
+                |ctx| {
+                    if let Some(clicked) = draw_main_menu(ctx, &mut menu) {
+                        choice = Some(clicked);
+                    }
+                },
+                label,
+            );
+        }
+        assert_eq!(menu.rules, GameRuleSet::Survival);
+        assert_eq!(menu.difficulty, Difficulty::Hard);
+        let Some(choice) = choice else {
+            panic!("Start wasn't clicked");
+        };
+        assert_eq!(choice, MenuChoice::NewGame);
+
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(GameRulesPlugin);
+        app.init_resource::<GameState>();
+        app.world_mut().send_event(menu.new_game_event(choice));
+        app.update();
+
+        let rules = *app.world().resource::<GameRules>();
+        assert_eq!(rules.rule_set, GameRuleSet::Survival);
+        assert_eq!(rules.difficulty, Difficulty::Hard);
+        assert!(rules.permadeath && !rules.difficulty.hints());
+        assert_eq!(
+            app.world().resource::<GameState>().game_mode,
+            GameMode::Playing
+        );
+    }
+
+    #[test]
+    fn daily_escape_button_starts_todays_daily() {
+        let mut menu = MainMenu::default();
+        let mut choice = None;
+        click_text(
+            |ctx| {
+                if let Some(clicked) = draw_main_menu(ctx, &mut menu) {
+                    choice = Some(clicked);
+                }
+            },
+            GameRuleSet::Daily.name(),
+        );
+        let Some(choice) = choice else {
+            panic!("Daily Escape wasn't clicked");
+        };
+        assert_eq!(choice, MenuChoice::DailyEscape);
+
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins((GameRulesPlugin, DailyPlugin));
+        app.init_resource::<GameState>();
+        app.world_mut().send_event(menu.new_game_event(choice));
+        app.update();
+
+        let rules = *app.world().resource::<GameRules>();
+        assert_eq!(rules.rule_set, GameRuleSet::Daily);
+        assert_eq!(rules.burn_multiplier(), DAILY_BURN_MULTIPLIER);
+        assert_eq!(app.world().resource::<DailyChallenge>().day, Some(today()));
+    }
+}
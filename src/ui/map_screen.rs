@@ -0,0 +1,770 @@
+use bevy::prelude::*;
+use bevy_egui::{EguiContexts, egui};
+use leafwing_input_manager::prelude::*;
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use crate::audio::sound_bank::{PlaySoundEvent, SoundCue};
+use crate::components::inventory::KeyType;
+use crate::components::player::Player;
+use crate::components::room::{ConnectionType, Floor, RoomId};
+use crate::resources::game_state::GameState;
+use crate::resources::input_config::PlayerAction;
+use crate::resources::map_state::MapState;
+use crate::systems::level_loader::{LevelData, LevelRegistry, RoomLevels};
+use crate::ui::photo_mode::photo_mode_inactive;
+
+/// World units left between neighbouring rooms on the schematic
+pub const ROOM_GAP: f32 = 240.0;
+
+/// Size (pixels) of the minimap in the HUD corner
+pub const MINIMAP_SIZE: f32 = 160.0;
+
+/// Size (pixels) of the drawing area of the full map screen
+pub const MAP_SCREEN_SIZE: egui::Vec2 = egui::vec2(720.0, 480.0);
+
+/// Floors in the order the map screen lists them
+pub const FLOORS: [Floor; 4] = [Floor::Basement, Floor::Ground, Floor::First, Floor::Second];
+
+/// Plugin that registers the map screen and the HUD minimap
+///
+/// The map opens with `PlayerAction::OpenMap` (Tab) and shows the rooms
+/// visited so far, one floor at a time. While it is closed a minimap of
+/// the current floor stays in the bottom-right corner of the screen.
+///
+/// **NOTE**: EguiPlugin must be added to the app before this plugin.
+pub struct MapScreenPlugin;
+
+impl Plugin for MapScreenPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<MapScreen>()
+            .init_resource::<MapLayout>()
+            .init_resource::<MapState>()
+            .init_resource::<LevelRegistry>()
+            .add_event::<PlaySoundEvent>()
+            .add_systems(
+                Update,
+                (
+                    map_layout_system,
+                    toggle_map_screen_system,
+                    map_screen_system,
+                    minimap_system.run_if(photo_mode_inactive),
+                )
+                    .chain(),
+            );
+    }
+}
+
+/// Resource holding the map screen state
+#[derive(Resource, Default, Debug)]
+pub struct MapScreen {
+    /// Whether the screen is shown
+    pub open: bool,
+    /// Floor shown (`None` follows the floor of the current room)
+    pub floor: Option<Floor>,
+}
+
+/// A door, staircase or passage out of a room, as drawn on the map
+#[derive(Debug, Clone, PartialEq)]
+pub struct MapDoor {
+    /// Room the connection leads to
+    pub target_room: RoomId,
+    /// Type of connection
+    pub connection_type: ConnectionType,
+    /// Position in the room's world space
+    pub position: Vec2,
+    /// Key the connection is locked with in the level file
+    pub locked: Option<KeyType>,
+}
+
+/// The parts of a room's level file the map needs
+#[derive(Debug, Clone, PartialEq)]
+pub struct RoomLayout {
+    /// Display name of the room
+    pub name: String,
+    /// Floor the room is on
+    pub floor: Floor,
+    /// Room bounds in world space
+    pub bounds: Rect,
+    /// Connections out of the room
+    pub doors: Vec<MapDoor>,
+}
+
+impl From<&LevelData> for RoomLayout {
+    fn from(level: &LevelData) -> Self {
+        Self {
+            name: level.name.clone(),
+            floor: level.floor,
+            bounds: Rect::new(
+                level.bounds.min.0,
+                level.bounds.min.1,
+                level.bounds.max.0,
+                level.bounds.max.1,
+            ),
+            doors: level
+                .connections
+                .iter()
+                .map(|connection| MapDoor {
+                    target_room: connection.target_room,
+                    connection_type: connection.connection_type,
+                    position: Vec2::new(connection.position.0, connection.position.1),
+                    locked: connection.locked,
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Resource caching the layouts of visited rooms
+///
+/// Filled from the rooms' level files by `map_layout_system`, so each
+/// file is read once rather than every frame the map is drawn.
+#[derive(Resource, Default, Debug)]
+pub struct MapLayout {
+    /// Layouts of the rooms whose level file has been read
+    pub rooms: HashMap<RoomId, RoomLayout>,
+    /// Rooms without a (valid) level file, which are left off the map
+    pub unavailable: HashSet<RoomId>,
+}
+
+impl MapLayout {
+    /// Whether the level file of `room_id` has already been looked at
+    pub fn knows(&self, room_id: RoomId) -> bool {
+        self.rooms.contains_key(&room_id) || self.unavailable.contains(&room_id)
+    }
+
+    /// Floor of `room_id`, when its layout is known
+    pub fn floor_of(&self, room_id: RoomId) -> Option<Floor> {
+        self.rooms.get(&room_id).map(|room| room.floor)
+    }
+}
+
+/// Side of a room a door sits on
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Side {
+    /// Left edge (lowest x)
+    West,
+    /// Right edge (highest x)
+    East,
+    /// Bottom edge (lowest y)
+    South,
+    /// Top edge (highest y)
+    North,
+}
+
+/// The edge of `bounds` nearest to `position`
+pub fn nearest_side(bounds: Rect, position: Vec2) -> Side {
+    [
+        (Side::West, position.x - bounds.min.x),
+        (Side::East, bounds.max.x - position.x),
+        (Side::South, position.y - bounds.min.y),
+        (Side::North, bounds.max.y - position.y),
+    ]
+    .into_iter()
+    .min_by(|(_, a), (_, b)| a.total_cmp(b))
+    .map_or(Side::East, |(side, _)| side)
+}
+
+/// Arranges the visited rooms of `floor` into a schematic
+///
+/// Every room keeps its own size, but rooms share one world space in the
+/// level files, so each is shifted next to the room it was reached from:
+/// a room behind an east door goes east of it, centred on the door, and
+/// so on. Rooms not connected to an earlier one start a new group to the
+/// east of everything placed so far. Rooms are visited in id order, so
+/// the same exploration always gives the same schematic.
+///
+/// # Returns
+/// The offset from each placed room's world space to schematic space
+pub fn schematic_offsets(
+    layout: &MapLayout,
+    map_state: &MapState,
+    floor: Floor,
+) -> HashMap<RoomId, Vec2> {
+    let mut rooms: Vec<RoomId> = layout
+        .rooms
+        .iter()
+        .filter(|(id, room)| room.floor == floor && map_state.is_visited(**id))
+        .map(|(id, _)| *id)
+        .collect();
+    rooms.sort_unstable();
+
+    let mut offsets: HashMap<RoomId, Vec2> = HashMap::new();
+    let mut extent: Option<Rect> = None;
+    for start in rooms.iter().copied() {
+        if offsets.contains_key(&start) {
+            continue;
+        }
+        let bounds = layout.rooms[&start].bounds;
+        let offset = extent.map_or(Vec2::ZERO, |extent| {
+            Vec2::new(extent.max.x + ROOM_GAP - bounds.min.x, -bounds.min.y)
+        });
+        offsets.insert(start, offset);
+        extent = Some(extent.map_or(bounds, |extent| extent.union(shifted(bounds, offset))));
+
+        let mut queue = VecDeque::from([start]);
+        while let Some(id) = queue.pop_front() {
+            let room = &layout.rooms[&id];
+            let placed = shifted(room.bounds, offsets[&id]);
+            for door in &room.doors {
+                let target = door.target_room;
+                if offsets.contains_key(&target) || !rooms.contains(&target) {
+                    continue;
+                }
+                let door_pos = door.position + offsets[&id];
+                let target_bounds = layout.rooms[&target].bounds;
+                let size = target_bounds.size();
+                let min = match nearest_side(room.bounds, door.position) {
+                    Side::East => Vec2::new(placed.max.x + ROOM_GAP, door_pos.y - size.y / 2.0),
+                    Side::West => {
+                        Vec2::new(placed.min.x - ROOM_GAP - size.x, door_pos.y - size.y / 2.0)
+                    }
+                    Side::North => Vec2::new(door_pos.x - size.x / 2.0, placed.max.y + ROOM_GAP),
+                    Side::South => {
+                        Vec2::new(door_pos.x - size.x / 2.0, placed.min.y - ROOM_GAP - size.y)
+                    }
+                };
+                let offset = min - target_bounds.min;
+                offsets.insert(target, offset);
+                extent = extent.map(|extent| extent.union(shifted(target_bounds, offset)));
+                queue.push_back(target);
+            }
+        }
+    }
+    offsets
+}
+
+/// `rect` moved by `offset`
+fn shifted(rect: Rect, offset: Vec2) -> Rect {
+    Rect::from_corners(rect.min + offset, rect.max + offset)
+}
+
+/// Maps schematic space onto a screen area
+///
+/// Schematic y points up (as in the level files) and screen y points
+/// down, so the transform flips the y axis.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MapView {
+    /// Schematic point drawn at the centre of the area
+    pub center: Vec2,
+    /// Screen pixels per world unit
+    pub scale: f32,
+    /// Screen area the map is drawn in
+    pub area: egui::Rect,
+}
+
+impl MapView {
+    /// A view showing all of `content` as large as it fits in `area`,
+    /// leaving `margin` pixels free on every side
+    pub fn fit(content: Rect, area: egui::Rect, margin: f32) -> Self {
+        let size = content.size().max(Vec2::ONE);
+        let room = (Vec2::new(area.width(), area.height()) - 2.0 * margin).max(Vec2::ONE);
+        Self {
+            center: content.center(),
+            scale: (room.x / size.x).min(room.y / size.y),
+            area,
+        }
+    }
+
+    /// Screen position of the schematic point `point`
+    pub fn to_screen(&self, point: Vec2) -> egui::Pos2 {
+        let offset = (point - self.center) * self.scale;
+        self.area.center() + egui::vec2(offset.x, -offset.y)
+    }
+
+    /// Screen rectangle of the schematic rectangle `rect`
+    pub fn rect_to_screen(&self, rect: Rect) -> egui::Rect {
+        egui::Rect::from_two_pos(self.to_screen(rect.min), self.to_screen(rect.max))
+    }
+}
+
+/// What `draw_map` shows, gathered from the ECS each frame
+pub struct MapInfo<'a> {
+    /// Cached room layouts
+    pub layout: &'a MapLayout,
+    /// Exploration state (only visited rooms are drawn)
+    pub map_state: &'a MapState,
+    /// Floor drawn
+    pub floor: Floor,
+    /// Room the player is in
+    pub current_room: Option<RoomId>,
+    /// Player position in the current room's world space
+    pub player: Option<Vec2>,
+    /// Whether room names are written inside the rooms
+    pub labels: bool,
+}
+
+/// Paints the explored rooms of one floor into `area`
+///
+/// Rooms are outlined rectangles (the current room filled brighter),
+/// doors are short connectors between rooms. Doors into rooms not
+/// visited yet are drawn as stubs, with a padlock while the level file
+/// says they're locked. Staircases and ladders are marked with a
+/// triangle. The player is a dot in the current room.
+pub fn draw_map(painter: &egui::Painter, area: egui::Rect, info: &MapInfo) {
+    let offsets = schematic_offsets(info.layout, info.map_state, info.floor);
+    let Some(content) = offsets
+        .iter()
+        .map(|(id, offset)| shifted(info.layout.rooms[id].bounds, *offset))
+        .reduce(|a, b| a.union(b))
+    else {
+        painter.text(
+            area.center(),
+            egui::Align2::CENTER_CENTER,
+            "Unexplored",
+            egui::FontId::proportional(14.0),
+            egui::Color32::GRAY,
+        );
+        return;
+    };
+    let view = MapView::fit(content, area, 12.0);
+    let room_stroke = egui::Stroke::new(1.5, egui::Color32::from_gray(200));
+    let door_stroke = egui::Stroke::new(2.0, egui::Color32::from_rgb(200, 160, 90));
+    let door_len = 6.0;
+
+    let mut ids: Vec<&RoomId> = offsets.keys().collect();
+    ids.sort_unstable();
+    for id in ids {
+        let room = &info.layout.rooms[id];
+        let offset = offsets[id];
+        let rect = view.rect_to_screen(shifted(room.bounds, offset));
+        let fill = if info.current_room == Some(*id) {
+            egui::Color32::from_rgb(70, 62, 45)
+        } else {
+            egui::Color32::from_gray(35)
+        };
+        painter.rect_filled(rect, 0.0, fill);
+        painter.rect_stroke(rect, 0.0, room_stroke, egui::StrokeKind::Inside);
+        if info.labels {
+            painter.text(
+                rect.center(),
+                egui::Align2::CENTER_CENTER,
+                &room.name,
+                egui::FontId::proportional(12.0),
+                egui::Color32::from_gray(220),
+            );
+        }
+
+        for door in &room.doors {
+            let from = view.to_screen(door.position + offset);
+            if matches!(
+                door.connection_type,
+                ConnectionType::Staircase | ConnectionType::Ladder
+            ) {
+                draw_stairs(painter, from, door_stroke);
+                continue;
+            }
+            if door.connection_type == ConnectionType::Hidden
+                && !info.map_state.has_secret_at(*id, door.position)
+            {
+                continue;
+            }
+            let to = match offsets.get(&door.target_room) {
+                Some(target_offset) => {
+                    let target = &info.layout.rooms[&door.target_room];
+                    let entry = target
+                        .doors
+                        .iter()
+                        .find(|back| back.target_room == *id)
+                        .map_or(target.bounds.center(), |back| back.position);
+                    view.to_screen(entry + *target_offset)
+                }
+                None => {
+                    let direction = match nearest_side(room.bounds, door.position) {
+                        Side::East => egui::vec2(1.0, 0.0),
+                        Side::West => egui::vec2(-1.0, 0.0),
+                        Side::North => egui::vec2(0.0, -1.0),
+                        Side::South => egui::vec2(0.0, 1.0),
+                    };
+                    from + direction * door_len * 2.0
+                }
+            };
+            painter.line_segment([from, to], door_stroke);
+            if door.locked.is_some() && !info.map_state.is_visited(door.target_room) {
+                draw_padlock(painter, to);
+            }
+        }
+    }
+
+    if let (Some(current), Some(player)) = (info.current_room, info.player)
+        && let Some(offset) = offsets.get(&current)
+    {
+        painter.circle_filled(
+            view.to_screen(player + *offset),
+            4.0,
+            egui::Color32::from_rgb(255, 210, 120),
+        );
+    }
+}
+
+/// Paints a small padlock centred on `center`
+fn draw_padlock(painter: &egui::Painter, center: egui::Pos2) {
+    let color = egui::Color32::from_rgb(220, 80, 60);
+    let body = egui::Rect::from_center_size(center + egui::vec2(0.0, 2.0), egui::vec2(8.0, 6.0));
+    painter.rect_filled(body, 1.0, color);
+    painter.circle_stroke(
+        center + egui::vec2(0.0, -1.5),
+        2.5,
+        egui::Stroke::new(1.5, color),
+    );
+}
+
+/// Paints a staircase marker (a triangle) centred on `center`
+fn draw_stairs(painter: &egui::Painter, center: egui::Pos2, stroke: egui::Stroke) {
+    let points = vec![
+        center + egui::vec2(0.0, -5.0),
+        center + egui::vec2(5.0, 4.0),
+        center + egui::vec2(-5.0, 4.0),
+    ];
+    painter.add(egui::Shape::convex_polygon(
+        points,
+        egui::Color32::TRANSPARENT,
+        stroke,
+    ));
+}
+
+/// Display name of a floor
+pub fn floor_name(floor: Floor) -> &'static str {
+    match floor {
+        Floor::Basement => "Basement",
+        Floor::Ground => "Ground Floor",
+        Floor::First => "First Floor",
+        Floor::Second => "Second Floor",
+    }
+}
+
+/// System that reads the level files of newly visited rooms into `MapLayout`
+///
+/// # System Dependencies
+/// - **Resources**: Reads MapState and LevelRegistry, writes MapLayout
+/// - **Upstream**: `room_transition_system` and save loading mark rooms visited
+/// - **Downstream**: `map_screen_system` and `minimap_system` draw the layouts
+///
+/// # Behavior
+/// Runs only when `MapState` changed. Rooms without a level file, or with
+/// one that fails to load, are remembered as unavailable and not retried.
+pub fn map_layout_system(
+    map_state: Res<MapState>,
+    levels: RoomLevels,
+    mut layout: ResMut<MapLayout>,
+) {
+    if !map_state.is_changed() {
+        return;
+    }
+
+    let mut new_rooms: Vec<RoomId> = map_state
+        .explored_rooms
+        .iter()
+        .filter(|(id, status)| status.visited && !layout.knows(**id))
+        .map(|(id, _)| *id)
+        .collect();
+    new_rooms.sort_unstable();
+
+    for room_id in new_rooms {
+        match levels.load(room_id) {
+            Ok(Some(level)) => {
+                layout.rooms.insert(room_id, RoomLayout::from(&level));
+            }
+            Ok(None) => {
+                layout.unavailable.insert(room_id);
+            }
+            Err(e) => {
+                warn!("Leaving room {} off the map: {}", room_id, e);
+                layout.unavailable.insert(room_id);
+            }
+        }
+    }
+}
+
+/// System that opens and closes the map screen with `PlayerAction::OpenMap`
+///
+/// Plays `SoundCue::UiConfirm` when opening and `SoundCue::UiCancel` when
+/// closing. Opening always starts on the floor of the current room.
+pub fn toggle_map_screen_system(
+    player_query: Query<&ActionState<PlayerAction>, With<Player>>,
+    mut screen: ResMut<MapScreen>,
+    mut sound_events: EventWriter<PlaySoundEvent>,
+) {
+    if !player_query
+        .iter()
+        .any(|actions| actions.just_pressed(&PlayerAction::OpenMap))
+    {
+        return;
+    }
+
+    screen.open = !screen.open;
+    screen.floor = None;
+    sound_events.write(PlaySoundEvent {
+        cue: if screen.open {
+            SoundCue::UiConfirm
+        } else {
+            SoundCue::UiCancel
+        },
+    });
+}
+
+/// System that renders the full map screen
+///
+/// # System Dependencies
+/// - **Resources**: EguiContexts (from bevy_egui), reads MapLayout, MapState
+///   and GameState when present, writes MapScreen
+/// - **Components**: Reads the player's `Transform`
+/// - **Upstream**: `map_layout_system` fills `MapLayout`
+///
+/// # Behavior
+/// Floors with at least one visited room get a tab; the floor of the
+/// current room is shown until another tab is picked.
+pub fn map_screen_system(
+    mut contexts: EguiContexts,
+    mut screen: ResMut<MapScreen>,
+    layout: Res<MapLayout>,
+    map_state: Res<MapState>,
+    game_state: Option<Res<GameState>>,
+    player_query: Query<&Transform, With<Player>>,
+) {
+    if !screen.open {
+        return;
+    }
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+
+    let current_room = game_state.map(|state| state.current_room);
+    let current_floor = current_room.and_then(|room| layout.floor_of(room));
+    let floor = screen.floor.or(current_floor).unwrap_or(Floor::Ground);
+    let explored_floors: Vec<Floor> = FLOORS
+        .into_iter()
+        .filter(|floor| {
+            layout
+                .rooms
+                .iter()
+                .any(|(id, room)| room.floor == *floor && map_state.is_visited(*id))
+        })
+        .collect();
+
+    let mut open = true;
+    egui::Window::new("Map")
+        .open(&mut open)
+        .collapsible(false)
+        .resizable(false)
+        .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+        .show(ctx, |ui| {
+            ui.horizontal(|ui| {
+                for tab in &explored_floors {
+                    if ui
+                        .selectable_label(*tab == floor, floor_name(*tab))
+                        .clicked()
+                    {
+                        screen.floor = Some(*tab);
+                    }
+                }
+            });
+            ui.separator();
+
+            let (response, painter) = ui.allocate_painter(MAP_SCREEN_SIZE, egui::Sense::hover());
+            let info = MapInfo {
+                layout: &layout,
+                map_state: &map_state,
+                floor,
+                current_room: current_room.filter(|_| current_floor == Some(floor)),
+                player: player_query
+                    .single()
+                    .ok()
+                    .map(|transform| transform.translation.truncate()),
+                labels: true,
+            };
+            draw_map(&painter, response.rect, &info);
+        });
+    if !open {
+        screen.open = false;
+    }
+}
+
+/// System that draws the minimap of the current floor in the HUD corner
+///
+/// # System Dependencies
+/// - **Resources**: EguiContexts (from bevy_egui), reads MapScreen,
+///   MapLayout, MapState and GameState when present
+/// - **Components**: Reads the player's `Transform`
+///
+/// # Behavior
+/// Hidden while the full map screen is open or before the current room's
+/// layout is known.
+pub fn minimap_system(
+    mut contexts: EguiContexts,
+    screen: Res<MapScreen>,
+    layout: Res<MapLayout>,
+    map_state: Res<MapState>,
+    game_state: Option<Res<GameState>>,
+    player_query: Query<&Transform, With<Player>>,
+) {
+    if screen.open {
+        return;
+    }
+    let Some(current_room) = game_state.map(|state| state.current_room) else {
+        return;
+    };
+    let Some(floor) = layout.floor_of(current_room) else {
+        return;
+    };
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+
+    egui::Area::new(egui::Id::new("minimap"))
+        .anchor(egui::Align2::RIGHT_BOTTOM, [-16.0, -16.0])
+        .interactable(false)
+        .show(ctx, |ui| {
+            egui::Frame::new()
+                .fill(egui::Color32::from_black_alpha(160))
+                .corner_radius(4.0)
+                .show(ui, |ui| {
+                    let (response, painter) =
+                        ui.allocate_painter(egui::Vec2::splat(MINIMAP_SIZE), egui::Sense::hover());
+                    let info = MapInfo {
+                        layout: &layout,
+                        map_state: &map_state,
+                        floor,
+                        current_room: Some(current_room),
+                        player: player_query
+                            .single()
+                            .ok()
+                            .map(|transform| transform.translation.truncate()),
+                        labels: false,
+                    };
+                    draw_map(&painter, response.rect, &info);
+                });
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn room(floor: Floor, size: Vec2, doors: Vec<MapDoor>) -> RoomLayout {
+        RoomLayout {
+            name: "Room".to_string(),
+            floor,
+            bounds: Rect::from_corners(Vec2::ZERO, size),
+            doors,
+        }
+    }
+
+    fn door(target_room: RoomId, position: Vec2) -> MapDoor {
+        MapDoor {
+            target_room,
+            connection_type: ConnectionType::Door,
+            position,
+            locked: None,
+        }
+    }
+
+    #[test]
+    fn nearest_side_picks_closest_edge() {
+        let bounds = Rect::new(0.0, 0.0, 1920.0, 1080.0);
+        assert_eq!(nearest_side(bounds, Vec2::new(1840.0, 540.0)), Side::East);
+        assert_eq!(nearest_side(bounds, Vec2::new(40.0, 540.0)), Side::West);
+        assert_eq!(nearest_side(bounds, Vec2::new(960.0, 1050.0)), Side::North);
+        assert_eq!(nearest_side(bounds, Vec2::new(960.0, 20.0)), Side::South);
+    }
+
+    #[test]
+    fn room_layout_from_level_data() {
+        let level = crate::systems::level_loader::load_level_data("levels/ground_floor_entry.ron")
+            .expect("entry hall should load");
+        let layout = RoomLayout::from(&level);
+
+        assert_eq!(layout.floor, Floor::Ground);
+        assert_eq!(layout.bounds, Rect::new(0.0, 0.0, 1920.0, 1080.0));
+        assert_eq!(layout.doors.len(), 1);
+        assert_eq!(layout.doors[0].target_room, 1);
+        assert_eq!(layout.doors[0].locked, Some(KeyType::Brass));
+    }
+
+    #[test]
+    fn schematic_places_room_behind_east_door_to_the_east() {
+        let mut layout = MapLayout::default();
+        let size = Vec2::new(1920.0, 1080.0);
+        layout.rooms.insert(
+            0,
+            room(Floor::Ground, size, vec![door(1, Vec2::new(1840.0, 540.0))]),
+        );
+        layout.rooms.insert(
+            1,
+            room(Floor::Ground, size, vec![door(0, Vec2::new(80.0, 540.0))]),
+        );
+        let mut map_state = MapState::default();
+        map_state.mark_explored(0);
+        map_state.mark_explored(1);
+
+        let offsets = schematic_offsets(&layout, &map_state, Floor::Ground);
+
+        assert_eq!(offsets[&0], Vec2::ZERO);
+        assert_eq!(offsets[&1], Vec2::new(1920.0 + ROOM_GAP, 0.0));
+    }
+
+    #[test]
+    fn schematic_skips_unvisited_rooms_and_other_floors() {
+        let mut layout = MapLayout::default();
+        let size = Vec2::new(640.0, 480.0);
+        layout.rooms.insert(0, room(Floor::Ground, size, vec![]));
+        layout.rooms.insert(1, room(Floor::Ground, size, vec![]));
+        layout.rooms.insert(2, room(Floor::First, size, vec![]));
+        let mut map_state = MapState::default();
+        map_state.mark_explored(0);
+        map_state.mark_explored(2);
+
+        let offsets = schematic_offsets(&layout, &map_state, Floor::Ground);
+
+        assert_eq!(offsets.len(), 1);
+        assert!(offsets.contains_key(&0));
+    }
+
+    #[test]
+    fn schematic_separates_unconnected_rooms() {
+        let mut layout = MapLayout::default();
+        let size = Vec2::new(640.0, 480.0);
+        layout.rooms.insert(0, room(Floor::Ground, size, vec![]));
+        layout.rooms.insert(3, room(Floor::Ground, size, vec![]));
+        let mut map_state = MapState::default();
+        map_state.mark_explored(0);
+        map_state.mark_explored(3);
+
+        let offsets = schematic_offsets(&layout, &map_state, Floor::Ground);
+
+        assert_eq!(offsets[&3], Vec2::new(640.0 + ROOM_GAP, 0.0));
+    }
+
+    #[test]
+    fn map_view_fits_and_flips_y() {
+        let content = Rect::new(0.0, 0.0, 200.0, 100.0);
+        let area = egui::Rect::from_min_size(egui::pos2(0.0, 0.0), egui::vec2(100.0, 100.0));
+        let view = MapView::fit(content, area, 0.0);
+
+        assert_eq!(view.scale, 0.5);
+        assert_eq!(view.to_screen(Vec2::new(0.0, 0.0)), egui::pos2(0.0, 75.0));
+        assert_eq!(
+            view.to_screen(Vec2::new(200.0, 100.0)),
+            egui::pos2(100.0, 25.0)
+        );
+    }
+
+    #[test]
+    fn map_layout_system_reads_visited_rooms_once() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.init_resource::<MapLayout>();
+        let mut map_state = MapState::default();
+        map_state.mark_explored(0);
+        map_state.mark_explored(9999);
+        app.insert_resource(map_state);
+        app.init_resource::<LevelRegistry>();
+        app.add_systems(Update, map_layout_system);
+
+        app.update();
+
+        let layout = app.world().resource::<MapLayout>();
+        assert_eq!(layout.floor_of(0), Some(Floor::Ground));
+        assert!(layout.unavailable.contains(&9999));
+        assert!(layout.knows(9999));
+    }
+}
@@ -0,0 +1,70 @@
+use bevy::prelude::*;
+use bevy_egui::{EguiContexts, egui};
+
+use crate::audio::sound_bank::{PlaySoundEvent, SoundCue};
+
+/// Plugin that plays a move sound when keyboard navigation changes the
+/// focused menu widget
+///
+/// Menus request their own confirm/cancel/error sounds with `PlaySoundEvent`;
+/// this plugin covers navigation, which egui handles without telling the
+/// menus. All cues are rate-limited by their sound bank cooldown, so holding
+/// Tab (key repeat) doesn't spam sounds.
+///
+/// **NOTE**: EguiPlugin must be added to the app before this plugin.
+pub struct MenuAudioPlugin;
+
+impl Plugin for MenuAudioPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<PlaySoundEvent>()
+            .add_systems(Update, menu_navigation_sound_system);
+    }
+}
+
+/// Returns true if focus moved from one widget to another
+///
+/// Gaining focus from nothing counts as a move (first Tab press in a menu);
+/// losing focus (menu closed, click elsewhere) doesn't.
+pub fn focus_moved(previous: Option<egui::Id>, current: Option<egui::Id>) -> bool {
+    current.is_some() && current != previous
+}
+
+/// System that emits `SoundCue::UiMove` when the focused egui widget changes
+///
+/// # System Dependencies
+/// - **Resources**: EguiContexts (from bevy_egui)
+/// - **Downstream**: `play_sound_requests` plays the cue
+pub fn menu_navigation_sound_system(
+    mut contexts: EguiContexts,
+    mut last_focus: Local<Option<egui::Id>>,
+    mut sound_events: EventWriter<PlaySoundEvent>,
+) {
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+
+    let focus = ctx.memory(|memory| memory.focused());
+    if focus_moved(*last_focus, focus) {
+        sound_events.write(PlaySoundEvent {
+            cue: SoundCue::UiMove,
+        });
+    }
+    *last_focus = focus;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_focus_changes_between_widgets_count_as_moves() {
+        let a = egui::Id::new("a");
+        let b = egui::Id::new("b");
+
+        assert!(focus_moved(None, Some(a)));
+        assert!(focus_moved(Some(a), Some(b)));
+        assert!(!focus_moved(Some(a), Some(a)));
+        assert!(!focus_moved(Some(a), None));
+        assert!(!focus_moved(None, None));
+    }
+}
@@ -3,5 +3,140 @@
 //! This module contains the user interface systems using bevy_egui,
 //! including the heads-up display (HUD) showing candle status, inventory, and game stats.
 
+/// List of every achievement, locked and unlocked
+pub mod achievements_screen;
+
+/// Title art overlay shown during attract mode
+pub mod attract_overlay;
+
+/// Scrolling credits screen opened from the ending screen or the menu
+pub mod credits;
+
+/// Drop-down developer console with a command registry (`dev-tools` only)
+#[cfg(feature = "dev-tools")]
+pub mod dev_console;
+
+/// Typewriter dialogue box with speaker names and choices
+pub mod dialogue_box;
+
+/// Run statistics and ending shown after escaping, with New Game+
+pub mod ending_screen;
+
+/// Game over screen with Load Last Save, Restart Room and Quit
+pub mod game_over;
+
 /// HUD system displaying candle meter, matches, and inventory
 pub mod hud;
+
+/// Inventory screen with an item grid, item actions and a journal tab
+pub mod inventory_screen;
+
+/// Close-up item viewer with turnable, flippable views and inscriptions
+pub mod item_viewer;
+
+/// Diary page text, reader window and the journal page list
+pub mod journal;
+
+/// Main menu whose New Game screen picks the rule set and difficulty
+pub mod main_menu;
+
+/// Explored-room map screen and the HUD minimap
+pub mod map_screen;
+
+/// Navigation sounds for keyboard focus changes in menus
+pub mod menu_audio;
+
+/// Pause menu with Resume / Photo Mode / Main Menu and a run statistics tab
+pub mod pause_menu;
+
+/// Toggleable FPS, frame time graph, entity count and stage timing overlay
+pub mod perf_overlay;
+
+/// Screenshot key and the pause menu's free-camera photo mode
+pub mod photo_mode;
+
+/// Interaction panel for entering symbols and placing fuses in puzzles
+pub mod puzzle_panel;
+
+/// Save management window for duplicating save slots
+pub mod save_slots;
+
+/// Offscreen egui layout snapshots for UI regression tests
+pub mod snapshot;
+
+/// Optional speedrun timer with real time, in-game time and room splits
+pub mod speedrun_overlay;
+
+/// Name entry dialog with validation and an on-screen keyboard for gamepads
+pub mod text_input;
+
+/// Short on-screen notifications (e.g. level loading errors)
+pub mod toast;
+
+use bevy::prelude::*;
+use bevy_egui::EguiPlugin;
+
+use crate::resources::game_clock::GameClockPlugin;
+use crate::resources::game_stats::GameStatsPlugin;
+use crate::systems::achievements::AchievementsPlugin;
+use crate::systems::dialogue::DialoguePlugin;
+
+/// Plugin adding every screen, overlay and panel of the UI
+///
+/// Adds `EguiPlugin` and the game plugins the screens read from
+/// (achievements, dialogue, run statistics and the game clock) if they
+/// aren't already, then the UI plugins in the order they depend on
+/// each other (the journal before the inventory screen before the item
+/// viewer, credits before the ending screen).
+///
+/// **NOTE**: bevy_kira_audio's `AudioPlugin` (added by
+/// `audio::sound_events::SoundEventsPlugin`) must be added to the app
+/// before this plugin, for the credits music.
+pub struct UiPlugin;
+
+impl Plugin for UiPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<EguiPlugin>() {
+            app.add_plugins(EguiPlugin::default());
+        }
+        if !app.is_plugin_added::<AchievementsPlugin>() {
+            app.add_plugins(AchievementsPlugin);
+        }
+        if !app.is_plugin_added::<DialoguePlugin>() {
+            app.add_plugins(DialoguePlugin);
+        }
+        if !app.is_plugin_added::<GameStatsPlugin>() {
+            app.add_plugins(GameStatsPlugin);
+        }
+        if !app.is_plugin_added::<GameClockPlugin>() {
+            app.add_plugins(GameClockPlugin);
+        }
+
+        app.add_plugins((
+            hud::HudPlugin,
+            toast::ToastPlugin,
+            journal::JournalPlugin,
+            inventory_screen::InventoryScreenPlugin,
+            item_viewer::ItemViewerPlugin,
+            map_screen::MapScreenPlugin,
+            puzzle_panel::PuzzlePanelPlugin,
+            dialogue_box::DialogueBoxPlugin,
+            pause_menu::PauseMenuPlugin,
+            save_slots::SaveSlotMenuPlugin,
+            achievements_screen::AchievementsScreenPlugin,
+            game_over::GameOverScreenPlugin,
+            credits::CreditsPlugin,
+            ending_screen::EndingScreenPlugin,
+        ))
+        .add_plugins((
+            main_menu::MainMenuPlugin,
+            speedrun_overlay::SpeedrunOverlayPlugin,
+            perf_overlay::PerfOverlayPlugin,
+            photo_mode::PhotoModePlugin,
+            menu_audio::MenuAudioPlugin,
+        ));
+
+        #[cfg(feature = "dev-tools")]
+        app.add_plugins(dev_console::DevConsolePlugin);
+    }
+}
@@ -0,0 +1,311 @@
+use bevy::prelude::*;
+use bevy_egui::{EguiContexts, egui};
+use leafwing_input_manager::prelude::*;
+
+use crate::audio::sound_bank::{PlaySoundEvent, SoundCue};
+use crate::components::player::Player;
+use crate::resources::game_state::{GameMode, GameState};
+use crate::resources::game_stats::GameStats;
+use crate::resources::input_config::PlayerAction;
+use crate::systems::accessibility::AccessibilitySettings;
+use crate::systems::telemetry::{TelemetrySettings, WipeTelemetryEvent};
+use crate::ui::photo_mode::PhotoMode;
+
+/// Plugin that registers the pause menu
+///
+/// `PlayerAction::Pause` (Escape) pauses and resumes the game. The menu has
+/// three tabs: Resume / Photo Mode / Main Menu buttons, the statistics of
+/// the run and the accessibility (and telemetry) options.
+///
+/// **NOTE**: EguiPlugin and `GameStatsPlugin` must be added to the app
+/// before this plugin. Add `AccessibilityPlugin` to persist the options,
+/// `PhotoModePlugin` for the Photo Mode button and `TelemetryPlugin` for
+/// the telemetry opt-in.
+pub struct PauseMenuPlugin;
+
+impl Plugin for PauseMenuPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PauseMenu>()
+            .init_resource::<AccessibilitySettings>()
+            .add_event::<PlaySoundEvent>()
+            .add_event::<WipeTelemetryEvent>()
+            .add_systems(Update, (toggle_pause_system, pause_menu_system).chain());
+    }
+}
+
+/// Tabs of the pause menu
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PauseTab {
+    /// Resume and quit buttons
+    #[default]
+    Game,
+    /// Statistics of the run so far
+    Stats,
+    /// Accessibility and telemetry options
+    Settings,
+}
+
+/// Resource holding the pause menu state
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct PauseMenu {
+    /// Tab shown
+    pub tab: PauseTab,
+}
+
+/// What the player picked in the pause menu
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PauseChoice {
+    /// Go back to playing
+    Resume,
+    /// Hide the menu and move the camera freely, keeping the world paused
+    PhotoMode,
+    /// Leave for the main menu
+    MainMenu,
+    /// Delete the telemetry logs recorded so far
+    DeleteGameplayData,
+}
+
+/// System that pauses and resumes the game with `PlayerAction::Pause`
+///
+/// Plays `SoundCue::UiConfirm` when pausing and `SoundCue::UiCancel` when
+/// resuming. Only toggles between `Playing` and `Paused`; in photo mode it
+/// goes back to the menu instead of resuming.
+pub fn toggle_pause_system(
+    player_query: Query<&ActionState<PlayerAction>, With<Player>>,
+    mut game_state: ResMut<GameState>,
+    mut menu: ResMut<PauseMenu>,
+    photo_mode: Option<ResMut<PhotoMode>>,
+    mut sound_events: EventWriter<PlaySoundEvent>,
+) {
+    if !player_query
+        .iter()
+        .any(|actions| actions.just_pressed(&PlayerAction::Pause))
+    {
+        return;
+    }
+
+    if let Some(mut photo_mode) = photo_mode.filter(|photo_mode| photo_mode.active) {
+        photo_mode.active = false;
+        sound_events.write(PlaySoundEvent {
+            cue: SoundCue::UiCancel,
+        });
+        return;
+    }
+
+    let cue = match game_state.game_mode {
+        GameMode::Playing => {
+            game_state.game_mode = GameMode::Paused;
+            menu.tab = PauseTab::Game;
+            SoundCue::UiConfirm
+        }
+        GameMode::Paused => {
+            game_state.game_mode = GameMode::Playing;
+            SoundCue::UiCancel
+        }
+        _ => return,
+    };
+    sound_events.write(PlaySoundEvent { cue });
+}
+
+/// Draws the pause menu and returns the button the player clicked
+///
+/// Split from `pause_menu_system` so the layout can be drawn without a
+/// running app. The Photo Mode button is only shown if `photo_mode` is set,
+/// and the telemetry opt-in only if `telemetry` is.
+pub fn draw_pause_menu(
+    ctx: &egui::Context,
+    menu: &mut PauseMenu,
+    stats: &GameStats,
+    settings: &mut AccessibilitySettings,
+    photo_mode: bool,
+    telemetry: Option<&mut bool>,
+) -> Option<PauseChoice> {
+    let mut choice = None;
+
+    egui::Window::new("Paused")
+        .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+        .resizable(false)
+        .collapsible(false)
+        .show(ctx, |ui| {
+            ui.horizontal(|ui| {
+                ui.selectable_value(&mut menu.tab, PauseTab::Game, "Game");
+                ui.selectable_value(&mut menu.tab, PauseTab::Stats, "Stats");
+                ui.selectable_value(&mut menu.tab, PauseTab::Settings, "Settings");
+            });
+            ui.separator();
+
+            match menu.tab {
+                PauseTab::Game => {
+                    ui.vertical_centered(|ui| {
+                        if ui.button("Resume").clicked() {
+                            choice = Some(PauseChoice::Resume);
+                        }
+                        if photo_mode && ui.button("Photo Mode").clicked() {
+                            choice = Some(PauseChoice::PhotoMode);
+                        }
+                        if ui.button("Main Menu").clicked() {
+                            choice = Some(PauseChoice::MainMenu);
+                        }
+                    });
+                }
+                PauseTab::Stats => {
+                    egui::Grid::new("pause_stats")
+                        .num_columns(2)
+                        .spacing([24.0, 4.0])
+                        .show(ui, |ui| {
+                            for (label, value) in stats.rows() {
+                                ui.label(label);
+                                ui.label(value);
+                                ui.end_row();
+                            }
+                        });
+                }
+                PauseTab::Settings => {
+                    ui.checkbox(&mut settings.colorblind_symbols, "Colorblind-safe symbols")
+                        .on_hover_text("Give each puzzle symbol its own pattern and name");
+                    ui.checkbox(&mut settings.high_contrast, "High-contrast lighting")
+                        .on_hover_text("Brighten the darkness and outline interactables");
+                    if let Some(telemetry) = telemetry {
+                        ui.separator();
+                        ui.checkbox(telemetry, "Share anonymous gameplay data")
+                            .on_hover_text(
+                                "Record deaths, puzzle solve times and quits (no names) \
+                                 to help tune difficulty",
+                            );
+                        if ui.button("Delete gameplay data").clicked() {
+                            choice = Some(PauseChoice::DeleteGameplayData);
+                        }
+                    }
+                }
+            }
+        });
+
+    choice
+}
+
+/// System that shows the pause menu while in `GameMode::Paused`
+///
+/// # System Dependencies
+/// - **Resources**: EguiContexts (from bevy_egui), `PauseMenu`, `GameStats`,
+///   writes `GameState`, `AccessibilitySettings`, and `PhotoMode` and
+///   `TelemetrySettings` (when present)
+/// - **Upstream**: `toggle_pause_system` pauses the game
+/// - **Downstream**: Emits `PlaySoundEvent`; `photo_camera_system` moves
+///   the camera once photo mode is on; `wipe_telemetry_system` reads
+///   `WipeTelemetryEvent`
+///
+/// The menu is hidden while photo mode is on.
+#[allow(clippy::too_many_arguments)]
+pub fn pause_menu_system(
+    mut contexts: EguiContexts,
+    mut menu: ResMut<PauseMenu>,
+    stats: Res<GameStats>,
+    mut accessibility: ResMut<AccessibilitySettings>,
+    mut game_state: ResMut<GameState>,
+    mut photo_mode: Option<ResMut<PhotoMode>>,
+    mut telemetry: Option<ResMut<TelemetrySettings>>,
+    mut wipe_events: EventWriter<WipeTelemetryEvent>,
+    mut sound_events: EventWriter<PlaySoundEvent>,
+) {
+    if game_state.game_mode != GameMode::Paused {
+        return;
+    }
+    if photo_mode
+        .as_ref()
+        .is_some_and(|photo_mode| photo_mode.active)
+    {
+        return;
+    }
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+
+    // Edit a copy so the options are only marked changed (and saved) when
+    // a checkbox is toggled
+    let mut settings = *accessibility;
+    let mut share_data = telemetry.as_ref().map(|telemetry| telemetry.enabled);
+    let choice = draw_pause_menu(
+        ctx,
+        &mut menu,
+        &stats,
+        &mut settings,
+        photo_mode.is_some(),
+        share_data.as_mut(),
+    );
+    accessibility.set_if_neq(settings);
+    if let (Some(telemetry), Some(enabled)) = (telemetry.as_mut(), share_data)
+        && telemetry.enabled != enabled
+    {
+        telemetry.enabled = enabled;
+    }
+    let Some(choice) = choice else {
+        return;
+    };
+    match choice {
+        PauseChoice::Resume => game_state.game_mode = GameMode::Playing,
+        PauseChoice::PhotoMode => {
+            if let Some(photo_mode) = photo_mode.as_mut() {
+                photo_mode.active = true;
+            }
+        }
+        PauseChoice::MainMenu => game_state.game_mode = GameMode::Menu,
+        PauseChoice::DeleteGameplayData => {
+            wipe_events.write(WipeTelemetryEvent);
+        }
+    }
+    sound_events.write(PlaySoundEvent {
+        cue: SoundCue::UiConfirm,
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pause_action_pauses_the_game() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.init_resource::<PauseMenu>();
+        app.add_event::<PlaySoundEvent>();
+        app.insert_resource(GameState {
+            game_mode: GameMode::Playing,
+            ..default()
+        });
+        app.add_systems(Update, toggle_pause_system);
+
+        let mut actions = ActionState::<PlayerAction>::default();
+        actions.press(&PlayerAction::Pause);
+        app.world_mut().spawn((Player, actions));
+        app.update();
+        assert_eq!(
+            app.world().resource::<GameState>().game_mode,
+            GameMode::Paused
+        );
+    }
+
+    #[test]
+    fn pause_action_leaves_photo_mode_before_resuming() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.init_resource::<PauseMenu>();
+        app.add_event::<PlaySoundEvent>();
+        app.insert_resource(GameState {
+            game_mode: GameMode::Paused,
+            ..default()
+        });
+        app.init_resource::<PhotoMode>();
+        app.world_mut().resource_mut::<PhotoMode>().active = true;
+        app.add_systems(Update, toggle_pause_system);
+
+        let mut actions = ActionState::<PlayerAction>::default();
+        actions.press(&PlayerAction::Pause);
+        app.world_mut().spawn((Player, actions));
+        app.update();
+        assert!(!app.world().resource::<PhotoMode>().active);
+        assert_eq!(
+            app.world().resource::<GameState>().game_mode,
+            GameMode::Paused
+        );
+    }
+}
@@ -0,0 +1,624 @@
+use bevy::app::{RunFixedMainLoop, RunFixedMainLoopSystem};
+use bevy::diagnostic::{
+    Diagnostic, DiagnosticPath, Diagnostics, DiagnosticsStore, EntityCountDiagnosticsPlugin,
+    FrameTimeDiagnosticsPlugin, RegisterDiagnostic,
+};
+use bevy::platform::time::Instant;
+use bevy::prelude::*;
+use bevy_egui::{EguiContexts, egui};
+
+use crate::systems::collision::CollisionGrid;
+use crate::systems::game_set::GameSet;
+#[cfg(feature = "dev-tools")]
+use crate::ui::dev_console::ConsoleCommands;
+
+/// Key that shows and hides the perf overlay
+pub const PERF_OVERLAY_KEY: KeyCode = KeyCode::F3;
+
+/// Frame rate the game targets, including the 1000-entity stress room
+pub const TARGET_FPS: f64 = 60.0;
+
+/// Frame rate the game must never drop below during normal gameplay
+pub const MIN_FPS: f64 = 30.0;
+
+/// Longest frame (milliseconds) `MIN_FPS` allows
+pub const FRAME_BUDGET_MS: f64 = 1000.0 / MIN_FPS;
+
+/// Seconds between two frame budget warnings in the log
+pub const FRAME_WARNING_INTERVAL: f64 = 5.0;
+
+/// Most samples `PerfLog` keeps (ten minutes at the target frame rate)
+pub const MAX_PERF_SAMPLES: usize = 36_000;
+
+/// Milliseconds spent in `GameSet::Input`
+pub const INPUT_STAGE_TIME: DiagnosticPath = DiagnosticPath::const_new("game_set/input");
+
+/// Milliseconds spent in the fixed-timestep loop (`GameSet::Physics`)
+pub const PHYSICS_STAGE_TIME: DiagnosticPath = DiagnosticPath::const_new("game_set/physics");
+
+/// Milliseconds spent in `GameSet::Gameplay`
+pub const GAMEPLAY_STAGE_TIME: DiagnosticPath = DiagnosticPath::const_new("game_set/gameplay");
+
+/// Milliseconds spent in `GameSet::Presentation`
+pub const PRESENTATION_STAGE_TIME: DiagnosticPath =
+    DiagnosticPath::const_new("game_set/presentation");
+
+/// Stage timing diagnostics with their labels, in frame order
+pub const STAGE_TIMES: [(&str, DiagnosticPath); 4] = [
+    ("Input", INPUT_STAGE_TIME),
+    ("Physics", PHYSICS_STAGE_TIME),
+    ("Gameplay", GAMEPLAY_STAGE_TIME),
+    ("Presentation", PRESENTATION_STAGE_TIME),
+];
+
+/// Plugin that adds the perf overlay and frame diagnostics
+///
+/// The overlay shows FPS, a frame time graph, the entity count and how
+/// long each `GameSet` stage took. Adds Bevy's frame time and entity count
+/// diagnostics unless the app already has them, and registers the stage
+/// timings as diagnostics. Frames over `FRAME_BUDGET_MS` are logged as
+/// warnings whether or not the overlay is shown, and the samples taken
+/// while it is shown can be saved as CSV (see `PerfLog`).
+///
+/// **NOTE**: EguiPlugin must be added to the app before this plugin.
+pub struct PerfOverlayPlugin;
+
+impl Plugin for PerfOverlayPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<FrameTimeDiagnosticsPlugin>() {
+            app.add_plugins(FrameTimeDiagnosticsPlugin::default());
+        }
+        if !app.is_plugin_added::<EntityCountDiagnosticsPlugin>() {
+            app.add_plugins(EntityCountDiagnosticsPlugin);
+        }
+        for (_, path) in STAGE_TIMES {
+            app.register_diagnostic(Diagnostic::new(path).with_suffix("ms"));
+        }
+
+        app.init_resource::<PerfOverlay>()
+            .init_resource::<StageClock>()
+            .init_resource::<FrameBudget>()
+            .init_resource::<PerfLog>()
+            .add_systems(
+                RunFixedMainLoop,
+                (
+                    start_stage_clock_system.in_set(RunFixedMainLoopSystem::BeforeFixedMainLoop),
+                    record_stage_time_system(PHYSICS_STAGE_TIME)
+                        .in_set(RunFixedMainLoopSystem::AfterFixedMainLoop),
+                ),
+            )
+            .add_systems(
+                Update,
+                (
+                    start_stage_clock_system.before(GameSet::Input),
+                    record_stage_time_system(INPUT_STAGE_TIME)
+                        .after(GameSet::Input)
+                        .before(GameSet::Physics),
+                    start_stage_clock_system
+                        .after(GameSet::Physics)
+                        .before(GameSet::Gameplay),
+                    (
+                        record_stage_time_system(GAMEPLAY_STAGE_TIME),
+                        start_stage_clock_system,
+                    )
+                        .chain()
+                        .after(GameSet::Gameplay)
+                        .before(GameSet::Presentation),
+                    record_stage_time_system(PRESENTATION_STAGE_TIME).after(GameSet::Presentation),
+                    (
+                        frame_budget_system,
+                        perf_log_system,
+                        toggle_perf_overlay_system,
+                        perf_overlay_system,
+                    )
+                        .chain(),
+                ),
+            );
+
+        #[cfg(feature = "dev-tools")]
+        {
+            app.init_resource::<ConsoleCommands>();
+            app.world_mut().resource_mut::<ConsoleCommands>().register(
+                "perf-export",
+                "<file.csv>",
+                perf_export_command,
+            );
+        }
+    }
+}
+
+/// Resource holding whether the perf overlay is shown
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct PerfOverlay {
+    /// Whether the overlay is drawn
+    pub visible: bool,
+}
+
+/// Figures shown by the perf overlay
+///
+/// Each is `None` until its diagnostic has a value.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct PerfStats {
+    /// Smoothed frames per second
+    pub fps: Option<f64>,
+    /// Smoothed frame time in milliseconds
+    pub frame_time_ms: Option<f64>,
+    /// Number of entities in the world
+    pub entities: Option<f64>,
+    /// Number of traps and items in the `CollisionGrid`
+    pub colliders: Option<usize>,
+    /// Smoothed milliseconds per stage, in `STAGE_TIMES` order
+    pub stage_ms: [Option<f64>; 4],
+    /// Recent frame times in milliseconds, oldest first
+    pub frame_history: Vec<f64>,
+    /// Frames over `FRAME_BUDGET_MS` since the game started
+    pub slow_frames: u64,
+}
+
+impl PerfStats {
+    /// Reads the smoothed diagnostics from `diagnostics`
+    pub fn from_diagnostics(diagnostics: &DiagnosticsStore, grid: Option<&CollisionGrid>) -> Self {
+        let smoothed = |path| {
+            diagnostics
+                .get(path)
+                .and_then(|diagnostic| diagnostic.smoothed())
+        };
+        Self {
+            fps: smoothed(&FrameTimeDiagnosticsPlugin::FPS),
+            frame_time_ms: smoothed(&FrameTimeDiagnosticsPlugin::FRAME_TIME),
+            entities: smoothed(&EntityCountDiagnosticsPlugin::ENTITY_COUNT),
+            colliders: grid.map(CollisionGrid::len),
+            stage_ms: STAGE_TIMES.map(|(_, path)| smoothed(&path)),
+            frame_history: diagnostics
+                .get(&FrameTimeDiagnosticsPlugin::FRAME_TIME)
+                .map(|diagnostic| diagnostic.values().copied().collect())
+                .unwrap_or_default(),
+            slow_frames: 0,
+        }
+    }
+}
+
+/// Formats an optional figure with `decimals` places, or "--" without one
+fn format_stat(value: Option<f64>, decimals: usize) -> String {
+    value.map_or_else(
+        || "--".to_string(),
+        |value| format!("{:.*}", decimals, value),
+    )
+}
+
+/// Resource holding when the stage being timed started
+#[derive(Resource, Debug)]
+pub struct StageClock {
+    /// Start of the current stage
+    pub start: Instant,
+}
+
+impl Default for StageClock {
+    fn default() -> Self {
+        Self {
+            start: Instant::now(),
+        }
+    }
+}
+
+/// System that marks the start of a timed stage
+///
+/// # System Dependencies
+/// - **Resources**: Writes `StageClock`
+/// - **Downstream**: `record_stage_time_system` measures from the mark
+pub fn start_stage_clock_system(mut clock: ResMut<StageClock>) {
+    clock.start = Instant::now();
+}
+
+/// Makes a system recording the time since the last `StageClock` mark under
+/// `path`
+///
+/// Stages are timed from their start to their end, so systems outside the
+/// `GameSet`s that happen to run meanwhile count towards them.
+///
+/// # System Dependencies
+/// - **Upstream**: `start_stage_clock_system` marks the stage's start
+/// - **Resources**: Reads `StageClock`, writes `Diagnostics`
+pub fn record_stage_time_system(path: DiagnosticPath) -> impl FnMut(Res<StageClock>, Diagnostics) {
+    move |clock: Res<StageClock>, mut diagnostics: Diagnostics| {
+        diagnostics.add_measurement(&path, || clock.start.elapsed().as_secs_f64() * 1000.0);
+    }
+}
+
+/// Resource counting frames over `FRAME_BUDGET_MS`
+#[derive(Resource, Debug, Default, Clone, PartialEq)]
+pub struct FrameBudget {
+    /// Slow frames since the game started
+    pub slow_frames: u64,
+    /// Slow frames since the last warning
+    pending: u32,
+    /// Longest of the pending slow frames, in milliseconds
+    slowest_ms: f64,
+    /// Real time (seconds) of the last warning
+    last_warning: Option<f64>,
+}
+
+impl FrameBudget {
+    /// Records a frame that took `frame_ms`, at real time `now` (seconds)
+    ///
+    /// # Returns
+    /// A warning to log if the frame was over budget and no warning was
+    /// given in the last `FRAME_WARNING_INTERVAL` seconds
+    pub fn record(&mut self, frame_ms: f64, now: f64) -> Option<String> {
+        if frame_ms <= FRAME_BUDGET_MS {
+            return None;
+        }
+        self.slow_frames += 1;
+        self.pending += 1;
+        self.slowest_ms = self.slowest_ms.max(frame_ms);
+
+        if self
+            .last_warning
+            .is_some_and(|last| now - last < FRAME_WARNING_INTERVAL)
+        {
+            return None;
+        }
+        let warning = format!(
+            "{} frame(s) over the {:.1} ms budget ({} FPS minimum), slowest {:.1} ms",
+            self.pending, FRAME_BUDGET_MS, MIN_FPS, self.slowest_ms
+        );
+        self.pending = 0;
+        self.slowest_ms = 0.0;
+        self.last_warning = Some(now);
+        Some(warning)
+    }
+}
+
+/// System that warns about frames over the `MIN_FPS` budget
+///
+/// # System Dependencies
+/// - **Resources**: Reads `Time<Real>`, writes `FrameBudget`
+///
+/// # Behavior
+/// Logs at most one warning per `FRAME_WARNING_INTERVAL`, counting the
+/// slow frames since the previous one.
+pub fn frame_budget_system(time: Res<Time<Real>>, mut budget: ResMut<FrameBudget>) {
+    let frame_ms = time.delta_secs_f64() * 1000.0;
+    if let Some(warning) = budget.record(frame_ms, time.elapsed_secs_f64()) {
+        warn!("{}", warning);
+    }
+}
+
+/// One frame's figures in a `PerfLog`
+#[derive(Debug, Clone, PartialEq)]
+pub struct PerfSample {
+    /// Real time (seconds) since the game started
+    pub time: f64,
+    /// Frame time in milliseconds
+    pub frame_time_ms: f64,
+    /// Number of entities in the world
+    pub entities: Option<f64>,
+    /// Milliseconds per stage, in `STAGE_TIMES` order
+    pub stage_ms: [Option<f64>; 4],
+}
+
+/// Resource recording a sample every frame the perf overlay is shown
+///
+/// Keeps the latest `MAX_PERF_SAMPLES`; `to_csv` turns them into a file
+/// for spreadsheets and profiling notes (the `perf-export` console command
+/// with the `dev-tools` feature).
+#[derive(Resource, Debug, Default, Clone, PartialEq)]
+pub struct PerfLog {
+    /// Samples, oldest first
+    pub samples: Vec<PerfSample>,
+}
+
+impl PerfLog {
+    /// Appends `sample`, dropping the oldest past the limit
+    pub fn push(&mut self, sample: PerfSample) {
+        self.samples.push(sample);
+        if self.samples.len() > MAX_PERF_SAMPLES {
+            let excess = self.samples.len() - MAX_PERF_SAMPLES;
+            self.samples.drain(..excess);
+        }
+    }
+
+    /// Formats the samples as CSV with a header row
+    ///
+    /// Missing figures are left empty.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("time_s,frame_time_ms,entities");
+        for (label, _) in STAGE_TIMES {
+            csv.push_str(&format!(",{}_ms", label.to_lowercase()));
+        }
+        csv.push('\n');
+
+        let field = |value: Option<f64>| value.map(|value| format!("{:.3}", value));
+        for sample in &self.samples {
+            let mut row = vec![
+                format!("{:.3}", sample.time),
+                format!("{:.3}", sample.frame_time_ms),
+                field(sample.entities).unwrap_or_default(),
+            ];
+            row.extend(sample.stage_ms.map(|ms| field(ms).unwrap_or_default()));
+            csv.push_str(&row.join(","));
+            csv.push('\n');
+        }
+        csv
+    }
+
+    /// Writes `to_csv` to `path`
+    ///
+    /// # Errors
+    /// Returns error string if the file cannot be written
+    pub fn export_csv(&self, path: &str) -> Result<(), String> {
+        std::fs::write(path, self.to_csv())
+            .map_err(|e| format!("Failed to write perf log {}: {}", path, e))
+    }
+}
+
+/// Console command saving the `PerfLog` as CSV
+#[cfg(feature = "dev-tools")]
+fn perf_export_command(world: &mut World, args: &[&str]) -> Result<String, String> {
+    let [path] = args else {
+        return Err("Usage: perf-export <file.csv>".to_string());
+    };
+    let log = world
+        .get_resource::<PerfLog>()
+        .ok_or_else(|| "No perf log".to_string())?;
+    log.export_csv(path)?;
+    Ok(format!("Wrote {} samples to {}", log.samples.len(), path))
+}
+
+/// System that records a `PerfLog` sample while the overlay is shown
+///
+/// # System Dependencies
+/// - **Resources**: Reads `PerfOverlay`, `Time<Real>` and `DiagnosticsStore`;
+///   writes `PerfLog`
+/// - **Upstream**: The stage timing systems and `EntityCountDiagnosticsPlugin`
+///   record the diagnostics
+pub fn perf_log_system(
+    overlay: Res<PerfOverlay>,
+    time: Res<Time<Real>>,
+    diagnostics: Res<DiagnosticsStore>,
+    mut log: ResMut<PerfLog>,
+) {
+    if !overlay.visible {
+        return;
+    }
+    let latest = |path: &DiagnosticPath| diagnostics.get(path).and_then(Diagnostic::value);
+
+    log.push(PerfSample {
+        time: time.elapsed_secs_f64(),
+        frame_time_ms: time.delta_secs_f64() * 1000.0,
+        entities: latest(&EntityCountDiagnosticsPlugin::ENTITY_COUNT),
+        stage_ms: STAGE_TIMES.map(|(_, path)| latest(&path)),
+    });
+}
+
+/// System that shows and hides the perf overlay with `PERF_OVERLAY_KEY`
+pub fn toggle_perf_overlay_system(
+    keyboard: Option<Res<ButtonInput<KeyCode>>>,
+    mut overlay: ResMut<PerfOverlay>,
+) {
+    if keyboard.is_some_and(|keyboard| keyboard.just_pressed(PERF_OVERLAY_KEY)) {
+        overlay.visible = !overlay.visible;
+    }
+}
+
+/// Draws recent frame times as bars, with a line at `FRAME_BUDGET_MS`
+///
+/// Bars over the budget are red. The graph's height covers twice the budget
+/// and taller frames are cut off.
+fn frame_time_graph(ui: &mut egui::Ui, frame_history: &[f64]) {
+    let (rect, _) = ui.allocate_exact_size(egui::vec2(160.0, 48.0), egui::Sense::hover());
+    let painter = ui.painter_at(rect);
+    painter.rect_filled(rect, 0.0, egui::Color32::from_black_alpha(120));
+
+    let max_ms = FRAME_BUDGET_MS * 2.0;
+    let y_of = |ms: f64| rect.bottom() - (ms.min(max_ms) / max_ms) as f32 * rect.height();
+    let bar_width = rect.width() / frame_history.len().max(1) as f32;
+    for (i, ms) in frame_history.iter().enumerate() {
+        let x = rect.left() + i as f32 * bar_width;
+        let color = if *ms > FRAME_BUDGET_MS {
+            egui::Color32::from_rgb(220, 100, 100)
+        } else {
+            egui::Color32::from_rgb(120, 220, 120)
+        };
+        painter.rect_filled(
+            egui::Rect::from_min_max(
+                egui::pos2(x, y_of(*ms)),
+                egui::pos2(x + bar_width, rect.bottom()),
+            ),
+            0.0,
+            color,
+        );
+    }
+
+    let budget_y = y_of(FRAME_BUDGET_MS);
+    painter.line_segment(
+        [
+            egui::pos2(rect.left(), budget_y),
+            egui::pos2(rect.right(), budget_y),
+        ],
+        egui::Stroke::new(1.0, egui::Color32::from_rgb(220, 180, 80)),
+    );
+}
+
+/// Draws the perf figures in the top-left corner
+///
+/// The FPS turns red below `TARGET_FPS`. Split from `perf_overlay_system`
+/// so the layout can be drawn without a running app.
+pub fn draw_perf_overlay(ctx: &egui::Context, stats: &PerfStats) {
+    egui::Area::new(egui::Id::new("perf_overlay"))
+        .anchor(egui::Align2::LEFT_TOP, [16.0, 16.0])
+        .interactable(false)
+        .show(ctx, |ui| {
+            egui::Frame::new()
+                .fill(egui::Color32::from_black_alpha(160))
+                .corner_radius(4.0)
+                .inner_margin(8.0)
+                .show(ui, |ui| {
+                    // Slightly under the target still counts, as the FPS is smoothed
+                    let below_target = stats.fps.is_some_and(|fps| fps < TARGET_FPS - 1.0);
+                    ui.label(
+                        egui::RichText::new(format!("FPS {}", format_stat(stats.fps, 0)))
+                            .monospace()
+                            .color(if below_target {
+                                egui::Color32::from_rgb(220, 100, 100)
+                            } else {
+                                egui::Color32::from_rgb(120, 220, 120)
+                            }),
+                    );
+                    ui.monospace(format!("Frame {} ms", format_stat(stats.frame_time_ms, 2)));
+                    frame_time_graph(ui, &stats.frame_history);
+                    if stats.slow_frames > 0 {
+                        ui.label(
+                            egui::RichText::new(format!(
+                                "Over {:.1} ms: {} frames",
+                                FRAME_BUDGET_MS, stats.slow_frames
+                            ))
+                            .monospace()
+                            .color(egui::Color32::from_rgb(220, 100, 100)),
+                        );
+                    }
+                    ui.monospace(format!("Entities {}", format_stat(stats.entities, 0)));
+                    if let Some(colliders) = stats.colliders {
+                        ui.monospace(format!("Colliders {}", colliders));
+                    }
+                    for ((label, _), ms) in STAGE_TIMES.iter().zip(stats.stage_ms) {
+                        ui.monospace(format!("{:<12} {} ms", label, format_stat(ms, 2)));
+                    }
+                });
+        });
+}
+
+/// System that draws the perf overlay while it is visible
+///
+/// # System Dependencies
+/// - **Resources**: EguiContexts (from bevy_egui), reads `PerfOverlay`,
+///   `DiagnosticsStore`, `FrameBudget` and `CollisionGrid` (when present)
+/// - **Upstream**: `FrameTimeDiagnosticsPlugin`,
+///   `EntityCountDiagnosticsPlugin` and the stage timing systems record the
+///   diagnostics
+pub fn perf_overlay_system(
+    mut contexts: EguiContexts,
+    overlay: Res<PerfOverlay>,
+    diagnostics: Res<DiagnosticsStore>,
+    budget: Res<FrameBudget>,
+    grid: Option<Res<CollisionGrid>>,
+) {
+    if !overlay.visible {
+        return;
+    }
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+
+    draw_perf_overlay(
+        ctx,
+        &PerfStats {
+            slow_frames: budget.slow_frames,
+            ..PerfStats::from_diagnostics(&diagnostics, grid.as_deref())
+        },
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missing_figures_show_placeholders() {
+        assert_eq!(format_stat(None, 0), "--");
+        assert_eq!(format_stat(Some(59.6), 0), "60");
+        assert_eq!(format_stat(Some(16.666), 2), "16.67");
+    }
+
+    #[test]
+    fn perf_overlay_key_toggles_visibility() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.init_resource::<PerfOverlay>();
+        app.init_resource::<ButtonInput<KeyCode>>();
+        app.add_systems(Update, toggle_perf_overlay_system);
+
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(PERF_OVERLAY_KEY);
+        app.update();
+
+        assert!(app.world().resource::<PerfOverlay>().visible);
+    }
+
+    #[test]
+    fn stats_read_entity_count_diagnostic() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(EntityCountDiagnosticsPlugin);
+        app.init_resource::<CollisionGrid>();
+        for _ in 0..3 {
+            app.world_mut().spawn_empty();
+        }
+        app.update();
+
+        let stats = PerfStats::from_diagnostics(
+            app.world().resource::<DiagnosticsStore>(),
+            Some(app.world().resource::<CollisionGrid>()),
+        );
+        assert!(stats.entities.is_some_and(|count| count >= 3.0));
+        assert_eq!(stats.colliders, Some(0));
+    }
+
+    #[test]
+    fn slow_frames_warn_at_most_once_per_interval() {
+        let mut budget = FrameBudget::default();
+        assert_eq!(budget.record(16.0, 1.0), None);
+
+        let warning = budget.record(50.0, 1.0).unwrap();
+        assert!(warning.starts_with("1 frame(s)"));
+        assert_eq!(budget.record(40.0, 2.0), None);
+        assert_eq!(budget.record(60.0, 3.0), None);
+
+        let warning = budget.record(35.0, 1.0 + FRAME_WARNING_INTERVAL).unwrap();
+        assert!(warning.starts_with("3 frame(s)"));
+        assert!(warning.ends_with("slowest 60.0 ms"));
+        assert_eq!(budget.slow_frames, 4);
+    }
+
+    #[test]
+    fn perf_log_exports_one_csv_row_per_sample() {
+        let mut log = PerfLog::default();
+        log.push(PerfSample {
+            time: 1.5,
+            frame_time_ms: 16.6667,
+            entities: Some(120.0),
+            stage_ms: [Some(0.25), None, Some(1.0), Some(0.5)],
+        });
+
+        assert_eq!(
+            log.to_csv(),
+            "time_s,frame_time_ms,entities,input_ms,physics_ms,gameplay_ms,presentation_ms\n\
+             1.500,16.667,120.000,0.250,,1.000,0.500\n"
+        );
+    }
+
+    #[test]
+    fn stage_times_are_recorded_as_diagnostics() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<StageClock>()
+            .register_diagnostic(Diagnostic::new(INPUT_STAGE_TIME))
+            .add_systems(
+                Update,
+                (
+                    start_stage_clock_system,
+                    record_stage_time_system(INPUT_STAGE_TIME),
+                )
+                    .chain(),
+            );
+        app.update();
+
+        let input_ms = app
+            .world()
+            .resource::<DiagnosticsStore>()
+            .get(&INPUT_STAGE_TIME)
+            .and_then(Diagnostic::value);
+        assert!(input_ms.is_some_and(|ms| ms >= 0.0));
+    }
+}
@@ -0,0 +1,273 @@
+use bevy::input::mouse::{MouseScrollUnit, MouseWheel};
+use bevy::prelude::*;
+use bevy::render::view::screenshot::{Screenshot, save_to_disk};
+use std::path::PathBuf;
+
+use crate::resources::game_state::{GameMode, GameState};
+
+/// Key that saves the current frame as a PNG
+pub const SCREENSHOT_KEY: KeyCode = KeyCode::F12;
+
+/// Photo mode camera pan speed at 1x zoom, in pixels per second
+pub const PHOTO_PAN_SPEED: f32 = 600.0;
+
+/// Factor the zoom keys change the camera scale by per second
+pub const PHOTO_ZOOM_RATE: f32 = 2.0;
+
+/// Factor one mouse wheel notch changes the camera scale by
+pub const PHOTO_WHEEL_ZOOM: f32 = 1.1;
+
+/// Smallest photo mode camera scale (most zoomed in)
+pub const PHOTO_MIN_SCALE: f32 = 0.25;
+
+/// Largest photo mode camera scale (most zoomed out)
+pub const PHOTO_MAX_SCALE: f32 = 4.0;
+
+/// Plugin for screenshots and the pause menu's photo mode
+///
+/// `SCREENSHOT_KEY` saves the window to a timestamped PNG in the
+/// `screenshots` folder of the data directory (a download in browser
+/// builds) at any time. Photo mode, opened from the pause menu, keeps the
+/// world paused, hides the HUD and lets the camera pan (arrow keys or
+/// WASD) and zoom (`+`/`-` or the mouse wheel); leaving it puts the camera
+/// back.
+pub struct PhotoModePlugin;
+
+impl Plugin for PhotoModePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PhotoMode>()
+            .add_event::<MouseWheel>()
+            .add_systems(Update, (screenshot_key_system, photo_camera_system));
+    }
+}
+
+/// Resource holding the photo mode state
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq)]
+pub struct PhotoMode {
+    /// Whether photo mode is on (the pause menu is hidden meanwhile)
+    pub active: bool,
+    /// Camera translation and scale from before photo mode, restored when
+    /// it ends
+    saved_camera: Option<(Vec3, f32)>,
+}
+
+/// Run condition that is true unless photo mode is on
+///
+/// Keeps the HUD and overlays out of the shot.
+pub fn photo_mode_inactive(photo_mode: Option<Res<PhotoMode>>) -> bool {
+    !photo_mode.is_some_and(|photo_mode| photo_mode.active)
+}
+
+/// File name of a screenshot taken `since_epoch` after the Unix epoch
+///
+/// e.g. `screenshot-2024-03-09_18-05-42-250.png` (UTC, with milliseconds so
+/// quick shots don't overwrite each other).
+pub fn screenshot_file_name(since_epoch: std::time::Duration) -> String {
+    let secs = since_epoch.as_secs();
+    let (year, month, day) = civil_date(secs / 86_400);
+    format!(
+        "screenshot-{:04}-{:02}-{:02}_{:02}-{:02}-{:02}-{:03}.png",
+        year,
+        month,
+        day,
+        secs % 86_400 / 3_600,
+        secs % 3_600 / 60,
+        secs % 60,
+        since_epoch.subsec_millis()
+    )
+}
+
+/// Year, month and day of the date `days` after 1970-01-01
+pub fn civil_date(days: u64) -> (u64, u64, u64) {
+    // Days since 0000-03-01, counted in 400-year eras of 146097 days
+    let days = days + 719_468;
+    let era = days / 146_097;
+    let day_of_era = days % 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    // Months counted from March, so the leap day falls at the end
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + u64::from(month <= 2);
+    (year, month, day)
+}
+
+/// Returns where a screenshot taken now is saved
+///
+/// The `screenshots` folder of `save_load::get_data_dir`, created if
+/// missing.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn screenshot_path() -> PathBuf {
+    let dir = crate::systems::save_load::get_data_dir().join("screenshots");
+    std::fs::create_dir_all(&dir).ok();
+    let since_epoch = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default();
+    dir.join(screenshot_file_name(since_epoch))
+}
+
+/// Returns the name a screenshot taken now is downloaded as
+///
+/// Browsers have no wall clock in `std`; they number repeated downloads.
+#[cfg(target_arch = "wasm32")]
+pub fn screenshot_path() -> PathBuf {
+    PathBuf::from("screenshot.png")
+}
+
+/// System that saves a screenshot when `SCREENSHOT_KEY` is pressed
+///
+/// # System Dependencies
+/// - **Resources**: Reads `ButtonInput<KeyCode>` (when present)
+/// - **Downstream**: Bevy's screenshot readback captures the primary window
+///   a few frames later and `save_to_disk` writes it to `screenshot_path`
+pub fn screenshot_key_system(mut commands: Commands, keyboard: Option<Res<ButtonInput<KeyCode>>>) {
+    if keyboard.is_some_and(|keyboard| keyboard.just_pressed(SCREENSHOT_KEY)) {
+        commands
+            .spawn(Screenshot::primary_window())
+            .observe(save_to_disk(screenshot_path()));
+    }
+}
+
+/// System that moves the camera while photo mode is on
+///
+/// # System Dependencies
+/// - **Upstream**: `pause_menu_system` turns photo mode on
+/// - **Resources**: Reads `Time`, `ButtonInput<KeyCode>` (when present),
+///   `MouseWheel` events and `GameState`; writes `PhotoMode`
+/// - **Components**: Writes the `Camera2d`'s `Transform` and `Projection`
+///
+/// # Behavior
+/// - Remembers the camera when photo mode starts, and puts it back once
+///   photo mode ends
+/// - Pans with the arrow keys or WASD, faster when zoomed out
+/// - Zooms with `+`/`-` and the mouse wheel, within `PHOTO_MIN_SCALE` to
+///   `PHOTO_MAX_SCALE`
+/// - Ends photo mode if the game leaves `GameMode::Paused` (e.g. quitting
+///   to the main menu)
+pub fn photo_camera_system(
+    time: Res<Time>,
+    keyboard: Option<Res<ButtonInput<KeyCode>>>,
+    mut wheel: EventReader<MouseWheel>,
+    game_state: Option<Res<GameState>>,
+    mut photo_mode: ResMut<PhotoMode>,
+    mut camera_query: Query<(&mut Transform, &mut Projection), With<Camera2d>>,
+) {
+    if game_state.is_some_and(|state| state.game_mode != GameMode::Paused) {
+        photo_mode.active = false;
+    }
+    let Ok((mut transform, mut projection)) = camera_query.single_mut() else {
+        wheel.clear();
+        return;
+    };
+    let Projection::Orthographic(ortho) = &mut *projection else {
+        wheel.clear();
+        return;
+    };
+
+    if !photo_mode.active {
+        wheel.clear();
+        if let Some((translation, scale)) = photo_mode.saved_camera.take() {
+            transform.translation = translation;
+            ortho.scale = scale;
+        }
+        return;
+    }
+    if photo_mode.saved_camera.is_none() {
+        photo_mode.saved_camera = Some((transform.translation, ortho.scale));
+    }
+
+    let pressed = |keys: [KeyCode; 2]| {
+        keyboard
+            .as_ref()
+            .is_some_and(|keyboard| keyboard.any_pressed(keys))
+    };
+    let direction = Vec2::new(
+        f32::from(pressed([KeyCode::ArrowRight, KeyCode::KeyD]))
+            - f32::from(pressed([KeyCode::ArrowLeft, KeyCode::KeyA])),
+        f32::from(pressed([KeyCode::ArrowUp, KeyCode::KeyW]))
+            - f32::from(pressed([KeyCode::ArrowDown, KeyCode::KeyS])),
+    );
+    let delta = time.delta_secs();
+    transform.translation += (direction * PHOTO_PAN_SPEED * ortho.scale * delta).extend(0.0);
+
+    let zoom_keys = f32::from(pressed([KeyCode::Minus, KeyCode::NumpadSubtract]))
+        - f32::from(pressed([KeyCode::Equal, KeyCode::NumpadAdd]));
+    let notches: f32 = wheel
+        .read()
+        .map(|event| match event.unit {
+            MouseScrollUnit::Line => event.y,
+            MouseScrollUnit::Pixel => event.y / 100.0,
+        })
+        .sum();
+    ortho.scale =
+        (ortho.scale * PHOTO_ZOOM_RATE.powf(zoom_keys * delta) * PHOTO_WHEEL_ZOOM.powf(-notches))
+            .clamp(PHOTO_MIN_SCALE, PHOTO_MAX_SCALE);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn screenshot_names_carry_the_utc_time() {
+        assert_eq!(
+            screenshot_file_name(Duration::ZERO),
+            "screenshot-1970-01-01_00-00-00-000.png"
+        );
+        assert_eq!(
+            screenshot_file_name(Duration::from_millis(1_700_000_000_250)),
+            "screenshot-2023-11-14_22-13-20-250.png"
+        );
+        // Leap day
+        assert_eq!(
+            screenshot_file_name(Duration::from_secs(951_782_400)),
+            "screenshot-2000-02-29_00-00-00-000.png"
+        );
+    }
+
+    #[test]
+    fn photo_mode_pans_the_camera_and_puts_it_back() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.init_resource::<ButtonInput<KeyCode>>();
+        app.insert_resource(GameState {
+            game_mode: GameMode::Paused,
+            ..default()
+        });
+        app.add_event::<MouseWheel>();
+        app.insert_resource(PhotoMode {
+            active: true,
+            ..default()
+        });
+        app.add_systems(Update, photo_camera_system);
+        let camera = app
+            .world_mut()
+            .spawn((Camera2d, Transform::from_xyz(100.0, 50.0, 0.0)))
+            .id();
+
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::ArrowRight);
+        app.update();
+        app.update();
+        let x = app.world().get::<Transform>(camera).unwrap().translation.x;
+        assert!(x > 100.0, "Camera should pan right, at x {}", x);
+        assert!(app.world().resource::<PhotoMode>().saved_camera.is_some());
+
+        // Resuming the game ends photo mode and restores the camera
+        app.world_mut().resource_mut::<GameState>().game_mode = GameMode::Playing;
+        app.update();
+        assert!(!app.world().resource::<PhotoMode>().active);
+        assert_eq!(
+            app.world().get::<Transform>(camera).unwrap().translation,
+            Vec3::new(100.0, 50.0, 0.0)
+        );
+    }
+}
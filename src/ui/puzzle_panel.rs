@@ -0,0 +1,670 @@
+use bevy::prelude::*;
+use bevy_egui::{EguiContexts, egui};
+use leafwing_input_manager::prelude::*;
+
+use crate::audio::sound_bank::{PlaySoundEvent, SoundCue};
+use crate::components::inventory::{Inventory, Item, PuzzleItemType};
+use crate::components::player::Player;
+use crate::components::puzzle::*;
+use crate::resources::game_state::{GameMode, GameState};
+use crate::resources::input_config::PlayerAction;
+use crate::resources::input_context::{InputContextStack, InputPermission};
+use crate::systems::accessibility::AccessibilitySettings;
+use crate::systems::puzzle::PuzzleInteractEvent;
+use crate::systems::puzzle_undo::{
+    PuzzleCommand, PuzzleCommandEvent, PuzzleHistory, UndoPuzzleEvent,
+};
+
+/// Maximum distance (pixels) between the player and a puzzle to open its panel
+pub const PUZZLE_INTERACT_RANGE: f32 = 48.0;
+
+/// Symbols offered by the symbol-match panel, in button order
+pub const SYMBOL_BUTTONS: [Symbol; 4] = [
+    Symbol::Circle,
+    Symbol::Triangle,
+    Symbol::Square,
+    Symbol::Star,
+];
+
+/// Size (points) of a symbol button
+pub const SYMBOL_BUTTON_SIZE: f32 = 40.0;
+
+/// Size (points) of the icons listing the symbols entered so far
+pub const ENTERED_SYMBOL_SIZE: f32 = 20.0;
+
+/// Plugin that registers the puzzle interaction panel (symbols and fuses)
+///
+/// The panel's inputs are applied by `PuzzleUndoPlugin`.
+///
+/// **NOTE**: EguiPlugin must be added to the app before this plugin.
+pub struct PuzzlePanelPlugin;
+
+impl Plugin for PuzzlePanelPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PuzzlePanel>()
+            .add_event::<PuzzleInteractEvent>()
+            .add_event::<PuzzleCommandEvent>()
+            .add_event::<UndoPuzzleEvent>()
+            .add_event::<PlaySoundEvent>()
+            .add_systems(
+                Update,
+                (open_puzzle_panel_system, puzzle_panel_system).chain(),
+            );
+    }
+}
+
+/// Resource holding the puzzle interaction panel state
+#[derive(Resource, Default, Debug)]
+pub struct PuzzlePanel {
+    /// Puzzle entity the panel is open for
+    pub puzzle: Option<Entity>,
+    /// Feedback shown after a wrong input
+    pub feedback: Option<String>,
+}
+
+/// Outcome of entering a symbol into a symbol-match puzzle
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SymbolInput {
+    /// Symbol is correct so far; more are needed
+    Accepted,
+    /// Symbol completed the correct sequence
+    Complete,
+    /// Symbol doesn't match the sequence; the input was reset
+    Wrong,
+}
+
+/// Appends `symbol` to the puzzle's input sequence
+///
+/// A symbol that breaks the correct sequence clears the input so the
+/// player starts over.
+pub fn enter_symbol(puzzle: &mut SymbolMatchPuzzle, symbol: Symbol) -> SymbolInput {
+    puzzle.input_sequence.push(symbol);
+
+    if !puzzle.correct_sequence.starts_with(&puzzle.input_sequence) {
+        puzzle.input_sequence.clear();
+        return SymbolInput::Wrong;
+    }
+
+    if puzzle.input_sequence.len() == puzzle.correct_sequence.len() {
+        SymbolInput::Complete
+    } else {
+        SymbolInput::Accepted
+    }
+}
+
+/// Returns true for a fuse inventory item
+fn is_fuse(item: &Item) -> bool {
+    matches!(item, Item::PuzzleItem(PuzzleItemType::Fuse))
+}
+
+/// Returns the number of fuses in an inventory
+pub fn fuse_count(inventory: &Inventory) -> usize {
+    inventory.items.iter().filter(|item| is_fuse(item)).count()
+}
+
+/// Moves a fuse from the inventory into an empty breaker slot
+///
+/// The placed fuse becomes an entity referenced by the slot.
+///
+/// # Returns
+/// `false` if the slot doesn't exist, is already filled, or the inventory
+/// holds no fuse
+pub fn place_fuse(
+    commands: &mut Commands,
+    breaker: &mut CircuitBreakerPuzzle,
+    slot: usize,
+    inventory: &mut Inventory,
+) -> bool {
+    let Some(slot) = breaker.fuse_slots.get_mut(slot) else {
+        return false;
+    };
+    if slot.is_some() {
+        return false;
+    }
+    let Some(index) = inventory.items.iter().position(is_fuse) else {
+        return false;
+    };
+
+    let fuse = inventory.items.remove(index);
+    *slot = Some(commands.spawn(fuse).id());
+    true
+}
+
+/// Takes the fuse out of a breaker slot and returns it to the inventory
+///
+/// # Returns
+/// `false` if the slot doesn't exist, is empty, or the inventory has no
+/// room for the fuse
+pub fn remove_fuse(
+    commands: &mut Commands,
+    breaker: &mut CircuitBreakerPuzzle,
+    slot: usize,
+    inventory: &mut Inventory,
+) -> bool {
+    let fuse_item = Item::PuzzleItem(PuzzleItemType::Fuse);
+    if inventory.check_add(&fuse_item).is_err() {
+        return false;
+    }
+    let Some(fuse) = breaker.fuse_slots.get_mut(slot).and_then(Option::take) else {
+        return false;
+    };
+
+    commands.entity(fuse).despawn();
+    inventory.add(fuse_item).is_ok()
+}
+
+/// Pattern marking a symbol in colorblind-safe mode, on top of its shape
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SymbolPattern {
+    /// Filled, no marks
+    Solid,
+    /// Horizontal stripes across the middle
+    Striped,
+    /// A dot in the middle
+    Dotted,
+    /// Outline only, no fill
+    Hollow,
+}
+
+/// Pattern of a symbol in colorblind-safe mode (one per symbol)
+pub fn symbol_pattern(symbol: Symbol) -> SymbolPattern {
+    match symbol {
+        Symbol::Circle => SymbolPattern::Solid,
+        Symbol::Triangle => SymbolPattern::Striped,
+        Symbol::Square => SymbolPattern::Dotted,
+        Symbol::Star => SymbolPattern::Hollow,
+    }
+}
+
+/// Rune color a symbol glows in
+pub fn symbol_color(symbol: Symbol) -> egui::Color32 {
+    match symbol {
+        Symbol::Circle => egui::Color32::from_rgb(210, 70, 60),
+        Symbol::Triangle => egui::Color32::from_rgb(80, 170, 80),
+        Symbol::Square => egui::Color32::from_rgb(70, 120, 220),
+        Symbol::Star => egui::Color32::from_rgb(230, 190, 60),
+    }
+}
+
+/// Returns the name of a symbol
+fn symbol_name(symbol: Symbol) -> &'static str {
+    match symbol {
+        Symbol::Circle => "Circle",
+        Symbol::Triangle => "Triangle",
+        Symbol::Square => "Square",
+        Symbol::Star => "Star",
+    }
+}
+
+/// Corners of a symbol's shape fitting in `rect`, around its center
+///
+/// Every shape is star-shaped around the center, so it can be filled as a
+/// triangle fan (see `paint_symbol`).
+pub fn symbol_points(symbol: Symbol, rect: egui::Rect) -> Vec<egui::Pos2> {
+    let center = rect.center();
+    let radius = rect.width().min(rect.height()) / 2.0;
+    let ring = |count: usize, start: f32, scale: &dyn Fn(usize) -> f32| -> Vec<egui::Pos2> {
+        (0..count)
+            .map(|i| {
+                let angle = start + std::f32::consts::TAU * i as f32 / count as f32;
+                center + egui::Vec2::angled(angle) * radius * scale(i)
+            })
+            .collect()
+    };
+    // Screen y points down, so -90° is straight up
+    let up = -std::f32::consts::FRAC_PI_2;
+
+    match symbol {
+        Symbol::Circle => ring(24, 0.0, &|_| 1.0),
+        Symbol::Triangle => ring(3, up, &|_| 1.0),
+        Symbol::Square => ring(4, std::f32::consts::FRAC_PI_4, &|_| 0.9),
+        Symbol::Star => ring(10, up, &|i| if i % 2 == 0 { 1.0 } else { 0.45 }),
+    }
+}
+
+/// Paints `symbol` in `rect`: its shape in its color, plus a white outline
+/// and its `SymbolPattern` when `colorblind` is set
+pub fn paint_symbol(painter: &egui::Painter, rect: egui::Rect, symbol: Symbol, colorblind: bool) {
+    let center = rect.center();
+    let radius = rect.width().min(rect.height()) / 2.0;
+    let points = symbol_points(symbol, rect);
+    let pattern = if colorblind {
+        Some(symbol_pattern(symbol))
+    } else {
+        None
+    };
+
+    if pattern != Some(SymbolPattern::Hollow) {
+        let color = symbol_color(symbol);
+        let mut mesh = egui::Mesh::default();
+        mesh.colored_vertex(center, color);
+        for point in &points {
+            mesh.colored_vertex(*point, color);
+        }
+        let count = points.len() as u32;
+        for i in 0..count {
+            mesh.add_triangle(0, 1 + i, 1 + (i + 1) % count);
+        }
+        painter.add(egui::Shape::mesh(mesh));
+    }
+
+    let Some(pattern) = pattern else {
+        return;
+    };
+    painter.add(egui::Shape::closed_line(
+        points,
+        egui::Stroke::new(2.5, egui::Color32::WHITE),
+    ));
+    let mark = egui::Stroke::new(2.0, egui::Color32::BLACK);
+    match pattern {
+        SymbolPattern::Striped => {
+            for dy in [-0.2, 0.0, 0.2] {
+                painter.line_segment(
+                    [
+                        center + egui::vec2(-0.25, dy) * radius,
+                        center + egui::vec2(0.25, dy) * radius,
+                    ],
+                    mark,
+                );
+            }
+        }
+        SymbolPattern::Dotted => {
+            painter.circle_filled(center, 0.15 * radius, egui::Color32::BLACK);
+        }
+        SymbolPattern::Solid | SymbolPattern::Hollow => {}
+    }
+}
+
+/// Clickable button showing a painted symbol, named on hover
+fn symbol_button(ui: &mut egui::Ui, symbol: Symbol, colorblind: bool) -> egui::Response {
+    let (rect, response) =
+        ui.allocate_exact_size(egui::Vec2::splat(SYMBOL_BUTTON_SIZE), egui::Sense::click());
+    let visuals = ui.style().interact(&response);
+    ui.painter().rect_filled(rect, 4.0, visuals.bg_fill);
+    paint_symbol(ui.painter(), rect.shrink(6.0), symbol, colorblind);
+    response.on_hover_text(symbol_name(symbol))
+}
+
+/// System that opens the puzzle panel when the player interacts with a puzzle
+///
+/// # Behavior
+/// When the player presses interact (F) within `PUZZLE_INTERACT_RANGE` of an
+/// unsolved, unlocked puzzle with a panel, the closest one is opened in
+/// `PuzzlePanel`.
+/// Symbol-match puzzles always open; circuit breakers open while the player
+/// holds a fuse or a fuse is already placed (so it can be taken back).
+///
+/// # System Dependencies
+/// - **Components**: Reads `Player`, `ActionState<PlayerAction>`, `Inventory`, `Puzzle`, `PuzzleState`
+/// - **Resources**: Reads `GameState` and `InputContextStack` (when
+///   present), writes `PuzzlePanel`
+/// - **Downstream**: `puzzle_panel_system` renders the panel
+#[allow(clippy::type_complexity)]
+pub fn open_puzzle_panel_system(
+    game_state: Res<GameState>,
+    input: Option<Res<InputContextStack>>,
+    mut panel: ResMut<PuzzlePanel>,
+    player_query: Query<(&Transform, &ActionState<PlayerAction>, Option<&Inventory>), With<Player>>,
+    puzzle_query: Query<
+        (Entity, &Transform, &Puzzle, &PuzzleState),
+        (Without<Player>, Without<PuzzleLocked>),
+    >,
+) {
+    if game_state.game_mode != GameMode::Playing
+        || input.is_some_and(|input| !input.allows(InputPermission::Interact))
+    {
+        return;
+    }
+
+    for (player_transform, actions, inventory) in &player_query {
+        if !actions.just_pressed(&PlayerAction::Interact) {
+            continue;
+        }
+
+        let has_fuse = inventory.is_some_and(|inventory| fuse_count(inventory) > 0);
+        let player_pos = player_transform.translation.truncate();
+        let closest = puzzle_query
+            .iter()
+            .filter(|(_, _, puzzle, state)| {
+                **state != PuzzleState::Solved
+                    && match puzzle {
+                        Puzzle::SymbolMatch(_) => true,
+                        Puzzle::CircuitBreaker(breaker) => {
+                            has_fuse || breaker.fuse_slots.iter().any(Option::is_some)
+                        }
+                        _ => false,
+                    }
+            })
+            .map(|(entity, transform, _, _)| {
+                (
+                    entity,
+                    transform.translation.truncate().distance(player_pos),
+                )
+            })
+            .filter(|(_, distance)| *distance <= PUZZLE_INTERACT_RANGE)
+            .min_by(|a, b| a.1.total_cmp(&b.1));
+
+        if let Some((puzzle, _)) = closest {
+            panel.puzzle = Some(puzzle);
+            panel.feedback = None;
+        }
+    }
+}
+
+/// System that renders the puzzle interaction panel
+///
+/// For symbol-match puzzles, shows the symbols entered so far and a button
+/// per symbol. Each click emits a `PuzzleCommandEvent`; a wrong symbol
+/// resets the input and shows feedback. Symbols are painted in their rune colors; with
+/// `AccessibilitySettings::colorblind_symbols` each also gets an outline,
+/// its own pattern and its name, so none is told apart by color alone.
+///
+/// For circuit breakers, lists the fuse slots: an empty slot can take a fuse
+/// from the player's inventory and a filled slot returns its fuse.
+///
+/// Puzzles with a `PuzzleHistory` also get an Undo button stepping back
+/// their last input.
+///
+/// # System Dependencies
+/// - **Resources**: EguiContexts (from bevy_egui), PuzzlePanel,
+///   AccessibilitySettings (when present)
+/// - **Components**: Reads `Puzzle`, `PuzzleState`, `PuzzleHistory` and the
+///   player's `Inventory`
+/// - **Downstream**: `puzzle_command_system` applies `PuzzleCommandEvent`
+///   and `UndoPuzzleEvent`; `play_sound_requests` plays the cancel cue on
+///   close
+#[allow(clippy::too_many_arguments)]
+pub fn puzzle_panel_system(
+    mut contexts: EguiContexts,
+    mut panel: ResMut<PuzzlePanel>,
+    accessibility: Option<Res<AccessibilitySettings>>,
+    puzzle_query: Query<(&Puzzle, &PuzzleState, Option<&PuzzleHistory>)>,
+    inventory_query: Query<&Inventory, With<Player>>,
+    mut command_events: EventWriter<PuzzleCommandEvent>,
+    mut undo_events: EventWriter<UndoPuzzleEvent>,
+    mut sound_events: EventWriter<PlaySoundEvent>,
+) {
+    let Some(entity) = panel.puzzle else {
+        return;
+    };
+    let Ok((puzzle, state, history)) = puzzle_query.get(entity) else {
+        panel.puzzle = None;
+        return;
+    };
+    let can_undo = *state != PuzzleState::Solved && history.is_some_and(PuzzleHistory::can_undo);
+    let mut command = None;
+
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+
+    let colorblind = accessibility.is_some_and(|settings| settings.colorblind_symbols);
+    let mut close = false;
+    let mut undo = false;
+    match puzzle {
+        Puzzle::SymbolMatch(symbols) => {
+            egui::Window::new("Symbols")
+                .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+                .resizable(false)
+                .collapsible(false)
+                .show(ctx, |ui| {
+                    ui.horizontal(|ui| {
+                        ui.label(format!(
+                            "Entered ({}/{}):",
+                            symbols.input_sequence.len(),
+                            symbols.correct_sequence.len()
+                        ));
+                        for symbol in &symbols.input_sequence {
+                            let (rect, _) = ui.allocate_exact_size(
+                                egui::Vec2::splat(ENTERED_SYMBOL_SIZE),
+                                egui::Sense::hover(),
+                            );
+                            paint_symbol(ui.painter(), rect, *symbol, colorblind);
+                        }
+                    });
+
+                    if *state == PuzzleState::Solved {
+                        ui.colored_label(egui::Color32::from_rgb(120, 220, 120), "Solved!");
+                    } else {
+                        ui.horizontal(|ui| {
+                            for symbol in SYMBOL_BUTTONS {
+                                let clicked = ui
+                                    .vertical(|ui| {
+                                        let clicked =
+                                            symbol_button(ui, symbol, colorblind).clicked();
+                                        if colorblind {
+                                            ui.label(symbol_name(symbol));
+                                        }
+                                        clicked
+                                    })
+                                    .inner;
+                                if clicked {
+                                    command = Some(PuzzleCommand::EnterSymbol(symbol));
+                                }
+                            }
+                        });
+                    }
+
+                    if let Some(feedback) = &panel.feedback {
+                        ui.colored_label(egui::Color32::from_rgb(230, 90, 80), feedback);
+                    }
+
+                    ui.horizontal(|ui| {
+                        if ui.button("Reset").clicked() {
+                            command = Some(PuzzleCommand::SetSymbols(vec![]));
+                        }
+                        undo |= ui
+                            .add_enabled(can_undo, egui::Button::new("Undo"))
+                            .clicked();
+                        if ui.button("Close").clicked() {
+                            close = true;
+                        }
+                    });
+                });
+        }
+        Puzzle::CircuitBreaker(breaker) => {
+            let Ok(inventory) = inventory_query.single() else {
+                panel.puzzle = None;
+                return;
+            };
+
+            egui::Window::new("Breaker Panel")
+                .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+                .resizable(false)
+                .collapsible(false)
+                .show(ctx, |ui| {
+                    ui.label(format!("Fuses carried: {}", fuse_count(inventory)));
+
+                    for slot in 0..breaker.fuse_slots.len() {
+                        ui.horizontal(|ui| {
+                            let filled = breaker.fuse_slots[slot].is_some();
+                            ui.label(format!(
+                                "Slot {}: {}",
+                                slot + 1,
+                                if filled { "Fuse" } else { "Empty" }
+                            ));
+
+                            if *state == PuzzleState::Solved {
+                                return;
+                            }
+
+                            let clicked = if filled {
+                                ui.button("Remove").clicked()
+                            } else {
+                                ui.add_enabled(
+                                    fuse_count(inventory) > 0,
+                                    egui::Button::new("Place fuse"),
+                                )
+                                .clicked()
+                            };
+                            if clicked {
+                                command = Some(if filled {
+                                    PuzzleCommand::RemoveFuse(slot)
+                                } else {
+                                    PuzzleCommand::PlaceFuse(slot)
+                                });
+                            }
+                        });
+                    }
+
+                    if *state == PuzzleState::Solved {
+                        ui.colored_label(
+                            egui::Color32::from_rgb(120, 220, 120),
+                            "The power hums back on.",
+                        );
+                    }
+
+                    ui.horizontal(|ui| {
+                        undo |= ui
+                            .add_enabled(can_undo, egui::Button::new("Undo"))
+                            .clicked();
+                        if ui.button("Close").clicked() {
+                            close = true;
+                        }
+                    });
+                });
+        }
+        _ => close = true,
+    }
+
+    if let Some(command) = command {
+        command_events.write(PuzzleCommandEvent {
+            puzzle: entity,
+            command,
+        });
+    }
+    if undo {
+        undo_events.write(UndoPuzzleEvent { puzzle: entity });
+    }
+
+    if close {
+        panel.puzzle = None;
+        panel.feedback = None;
+        sound_events.write(PlaySoundEvent {
+            cue: SoundCue::UiCancel,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn symbol_puzzle() -> SymbolMatchPuzzle {
+        SymbolMatchPuzzle {
+            input_sequence: vec![],
+            correct_sequence: vec![Symbol::Star, Symbol::Circle],
+        }
+    }
+
+    #[test]
+    fn entering_symbols_completes_the_sequence() {
+        let mut puzzle = symbol_puzzle();
+
+        assert_eq!(
+            enter_symbol(&mut puzzle, Symbol::Star),
+            SymbolInput::Accepted
+        );
+        assert_eq!(
+            enter_symbol(&mut puzzle, Symbol::Circle),
+            SymbolInput::Complete
+        );
+        assert_eq!(puzzle.input_sequence, puzzle.correct_sequence);
+    }
+
+    #[test]
+    fn colorblind_patterns_tell_every_symbol_apart() {
+        let rect = egui::Rect::from_center_size(egui::Pos2::ZERO, egui::Vec2::splat(40.0));
+        for (i, a) in SYMBOL_BUTTONS.iter().enumerate() {
+            for b in &SYMBOL_BUTTONS[i + 1..] {
+                assert_ne!(symbol_pattern(*a), symbol_pattern(*b));
+                assert_ne!(
+                    symbol_points(*a, rect).len(),
+                    symbol_points(*b, rect).len(),
+                    "{:?} and {:?} share a shape",
+                    a,
+                    b
+                );
+            }
+            assert!(
+                symbol_points(*a, rect)
+                    .iter()
+                    .all(|point| rect.expand(0.01).contains(*point))
+            );
+        }
+    }
+
+    #[test]
+    fn wrong_symbol_resets_input() {
+        let mut puzzle = symbol_puzzle();
+
+        enter_symbol(&mut puzzle, Symbol::Star);
+        assert_eq!(
+            enter_symbol(&mut puzzle, Symbol::Square),
+            SymbolInput::Wrong
+        );
+        assert!(puzzle.input_sequence.is_empty());
+    }
+
+    #[test]
+    fn interact_opens_panel_for_nearby_symbol_puzzle() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.init_resource::<GameState>();
+        app.world_mut().resource_mut::<GameState>().game_mode = GameMode::Playing;
+        app.init_resource::<PuzzlePanel>();
+        app.add_systems(Update, open_puzzle_panel_system);
+
+        let puzzle = app
+            .world_mut()
+            .spawn((
+                Puzzle::SymbolMatch(symbol_puzzle()),
+                PuzzleState::Unsolved,
+                Transform::from_xyz(30.0, 0.0, 0.0),
+            ))
+            .id();
+        let mut actions = ActionState::<PlayerAction>::default();
+        actions.press(&PlayerAction::Interact);
+        app.world_mut()
+            .spawn((Player, Transform::from_xyz(0.0, 0.0, 0.0), actions));
+
+        app.update();
+
+        assert_eq!(app.world().resource::<PuzzlePanel>().puzzle, Some(puzzle));
+    }
+
+    #[test]
+    fn fuses_move_between_inventory_and_breaker_slots() {
+        use bevy::ecs::world::CommandQueue;
+
+        let mut world = World::new();
+        let mut queue = CommandQueue::default();
+        let mut commands = Commands::new(&mut queue, &world);
+        let mut breaker = CircuitBreakerPuzzle {
+            fuse_slots: vec![None, None],
+            correct_sequence: vec![0, 1],
+        };
+        let mut inventory = Inventory {
+            items: vec![Item::Match, Item::PuzzleItem(PuzzleItemType::Fuse)],
+            max_capacity: 2,
+        };
+
+        assert!(place_fuse(&mut commands, &mut breaker, 1, &mut inventory));
+        assert!(breaker.fuse_slots[1].is_some());
+        assert_eq!(fuse_count(&inventory), 0);
+        assert!(
+            !place_fuse(&mut commands, &mut breaker, 0, &mut inventory),
+            "No fuse left to place"
+        );
+
+        assert!(remove_fuse(&mut commands, &mut breaker, 1, &mut inventory));
+        assert!(breaker.fuse_slots[1].is_none());
+        assert_eq!(fuse_count(&inventory), 1);
+        assert!(
+            !remove_fuse(&mut commands, &mut breaker, 1, &mut inventory),
+            "Slot is already empty"
+        );
+
+        queue.apply(&mut world);
+    }
+}
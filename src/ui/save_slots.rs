@@ -0,0 +1,438 @@
+use bevy::prelude::*;
+use bevy_egui::{EguiContexts, egui};
+use std::collections::HashMap;
+
+use crate::audio::sound_bank::{PlaySoundEvent, SoundCue};
+use crate::resources::profiles::Profiles;
+use crate::systems::cloud_sync::{CloudSync, SyncState, SyncStatus};
+use crate::systems::save_load::{
+    CopySaveSlotEvent, RenameSaveSlotEvent, SaveSlotOverwriteRequest, copy_save_slot_system,
+    read_slot_meta, rename_save_slot_system,
+};
+use crate::systems::save_storage::{SaveBackend, SaveStorage};
+use crate::ui::text_input::{TextInput, TextInputOutcome, gamepad_text_nav, text_input_window};
+
+/// Save slots shown in the save management window (0 is the auto-save)
+pub const SAVE_SLOTS: [usize; 4] = [0, 1, 2, 3];
+
+/// Key that opens and closes the save management window
+pub const SAVE_MENU_KEY: KeyCode = KeyCode::F6;
+
+/// Plugin that registers the save management window and slot copying
+///
+/// **NOTE**: EguiPlugin must be added to the app before this plugin.
+pub struct SaveSlotMenuPlugin;
+
+impl Plugin for SaveSlotMenuPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SaveSlotMenu>()
+            .init_resource::<Profiles>()
+            .init_resource::<SaveBackend>()
+            .add_event::<CopySaveSlotEvent>()
+            .add_event::<SaveSlotOverwriteRequest>()
+            .add_event::<RenameSaveSlotEvent>()
+            .add_event::<PlaySoundEvent>()
+            .add_systems(
+                Update,
+                (
+                    toggle_save_slot_menu_system,
+                    save_slot_menu_system,
+                    copy_save_slot_system,
+                    rename_save_slot_system,
+                )
+                    .chain(),
+            );
+    }
+}
+
+/// Resource holding the save management window state
+#[derive(Resource, Default, Debug)]
+pub struct SaveSlotMenu {
+    /// Whether the window is shown
+    pub open: bool,
+    /// Slot picked for duplication, waiting for a target slot
+    pub copy_source: Option<usize>,
+    /// Copy waiting for the player to confirm overwriting its target
+    pub pending_overwrite: Option<SaveSlotOverwriteRequest>,
+    /// Names the player gave the slots, read when the window opens
+    pub slot_names: HashMap<usize, String>,
+    /// Name being typed, and what it is for
+    pub naming: Option<(NameTarget, TextInput)>,
+}
+
+/// What a name typed in the save management window is for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NameTarget {
+    /// A new player profile
+    NewProfile,
+    /// Renaming a save slot
+    Slot(usize),
+}
+
+/// Reads the names the player gave the save slots from their metadata files
+pub fn load_slot_names(storage: &impl SaveStorage) -> HashMap<usize, String> {
+    SAVE_SLOTS
+        .into_iter()
+        .filter_map(|slot| read_slot_meta(storage, slot).name.map(|name| (slot, name)))
+        .collect()
+}
+
+/// System that opens and closes the save management window with `SAVE_MENU_KEY`
+///
+/// Plays `SoundCue::UiConfirm` when opening and `SoundCue::UiCancel` when closing.
+pub fn toggle_save_slot_menu_system(
+    keyboard: Option<Res<ButtonInput<KeyCode>>>,
+    mut menu: ResMut<SaveSlotMenu>,
+    mut sound_events: EventWriter<PlaySoundEvent>,
+    storage: Res<SaveBackend>,
+) {
+    let Some(keyboard) = keyboard else {
+        return;
+    };
+
+    if keyboard.just_pressed(SAVE_MENU_KEY) {
+        menu.open = !menu.open;
+        menu.copy_source = None;
+        menu.pending_overwrite = None;
+        menu.naming = None;
+        if menu.open {
+            menu.slot_names = load_slot_names(&*storage);
+        }
+        sound_events.write(PlaySoundEvent {
+            cue: if menu.open {
+                SoundCue::UiConfirm
+            } else {
+                SoundCue::UiCancel
+            },
+        });
+    }
+}
+
+/// System that renders the save management window
+///
+/// Shows the active profile with a picker and a "New Profile" action,
+/// then lists every save slot with "Duplicate" and (for saved slots)
+/// "Rename" actions. After picking a source slot, the other slots offer
+/// "Copy here". With cloud sync on, a line under the profile shows how the
+/// last sync went. Copying onto an occupied slot shows a confirmation dialog
+/// before the save is overwritten. Profile and slot names are typed in a
+/// `text_input_window`, with the on-screen keyboard while a gamepad is
+/// connected.
+///
+/// # System Dependencies
+/// - **Resources**: EguiContexts (from bevy_egui), SaveSlotMenu, Profiles,
+///   SaveBackend (which slots hold a save), CloudSync (when present)
+/// - **Components**: Reads `Gamepad`s for on-screen keyboard input
+/// - **Upstream**: `copy_save_slot_system` emits `SaveSlotOverwriteRequest`
+/// - **Downstream**: `copy_save_slot_system` reads `CopySaveSlotEvent`;
+///   `rename_save_slot_system` reads `RenameSaveSlotEvent`;
+///   `save_profiles_system` stores changed profiles;
+///   `play_sound_requests` plays the confirm/cancel/error cues
+#[allow(clippy::too_many_arguments)]
+pub fn save_slot_menu_system(
+    mut contexts: EguiContexts,
+    mut menu: ResMut<SaveSlotMenu>,
+    mut profiles: ResMut<Profiles>,
+    gamepads: Query<&Gamepad>,
+    mut overwrite_requests: EventReader<SaveSlotOverwriteRequest>,
+    mut copy_events: EventWriter<CopySaveSlotEvent>,
+    mut rename_events: EventWriter<RenameSaveSlotEvent>,
+    mut sound_events: EventWriter<PlaySoundEvent>,
+    storage: Res<SaveBackend>,
+    cloud_sync: Option<Res<CloudSync>>,
+) {
+    if let Some(request) = overwrite_requests.read().last() {
+        // Copying onto an occupied slot was refused until confirmed
+        menu.pending_overwrite = Some(*request);
+        sound_events.write(PlaySoundEvent {
+            cue: SoundCue::UiError,
+        });
+    }
+
+    if !menu.open {
+        return;
+    }
+
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+
+    if let Some((target, mut input)) = menu.naming.take() {
+        let navs = gamepad_text_nav(&gamepads);
+        let cue = match text_input_window(ctx, &mut input, !gamepads.is_empty(), &navs) {
+            Some(TextInputOutcome::Submitted(name)) => match target {
+                NameTarget::NewProfile => match profiles.create(name) {
+                    Ok(_) => SoundCue::UiConfirm,
+                    Err(error) => {
+                        input.error = Some(error);
+                        menu.naming = Some((target, input));
+                        SoundCue::UiError
+                    }
+                },
+                NameTarget::Slot(slot) => {
+                    rename_events.write(RenameSaveSlotEvent {
+                        slot,
+                        name: Some(name.clone()),
+                    });
+                    menu.slot_names.insert(slot, name);
+                    SoundCue::UiConfirm
+                }
+            },
+            Some(TextInputOutcome::Cancelled) => SoundCue::UiCancel,
+            None => {
+                menu.naming = Some((target, input));
+                return;
+            }
+        };
+        sound_events.write(PlaySoundEvent { cue });
+        return;
+    }
+
+    egui::Window::new("Save Slots")
+        .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+        .resizable(false)
+        .collapsible(false)
+        .show(ctx, |ui| {
+            ui.horizontal(|ui| {
+                ui.label("Profile:");
+                let mut picked = None;
+                egui::ComboBox::from_id_salt("profile")
+                    .selected_text(profiles.active_name().unwrap_or("None"))
+                    .show_ui(ui, |ui| {
+                        for (index, name) in profiles.names.iter().enumerate() {
+                            if ui
+                                .selectable_label(profiles.active == Some(index), name)
+                                .clicked()
+                            {
+                                picked = Some(index);
+                            }
+                        }
+                    });
+                if let Some(index) = picked.filter(|index| profiles.active != Some(*index)) {
+                    profiles.active = Some(index);
+                    sound_events.write(PlaySoundEvent {
+                        cue: SoundCue::UiConfirm,
+                    });
+                }
+                if ui.button("New Profile").clicked() {
+                    menu.naming = Some((NameTarget::NewProfile, TextInput::new("New Profile", "")));
+                    sound_events.write(PlaySoundEvent {
+                        cue: SoundCue::UiConfirm,
+                    });
+                }
+            });
+            if let Some(cloud_sync) = &cloud_sync {
+                ui.label(sync_status_label(&cloud_sync.storage.state()));
+            }
+            ui.separator();
+
+            let saved_slots = storage.list_slots();
+            for slot in SAVE_SLOTS {
+                let occupied = saved_slots.contains(&slot);
+                ui.horizontal(|ui| {
+                    ui.label(slot_label(
+                        slot,
+                        menu.slot_names.get(&slot).map(String::as_str),
+                        occupied,
+                    ));
+
+                    match menu.copy_source {
+                        None => {
+                            if ui
+                                .add_enabled(occupied, egui::Button::new("Duplicate"))
+                                .clicked()
+                            {
+                                menu.copy_source = Some(slot);
+                                sound_events.write(PlaySoundEvent {
+                                    cue: SoundCue::UiConfirm,
+                                });
+                            }
+                            if ui
+                                .add_enabled(occupied, egui::Button::new("Rename"))
+                                .clicked()
+                            {
+                                let current = menu.slot_names.get(&slot).cloned();
+                                menu.naming = Some((
+                                    NameTarget::Slot(slot),
+                                    TextInput::new(
+                                        format!("Rename {}", slot_name(slot)),
+                                        current.unwrap_or_default(),
+                                    ),
+                                ));
+                                sound_events.write(PlaySoundEvent {
+                                    cue: SoundCue::UiConfirm,
+                                });
+                            }
+                        }
+                        Some(source) if source == slot => {
+                            if ui.button("Cancel").clicked() {
+                                menu.copy_source = None;
+                                sound_events.write(PlaySoundEvent {
+                                    cue: SoundCue::UiCancel,
+                                });
+                            }
+                        }
+                        // The auto-save slot is only ever written by the game
+                        Some(_) if slot == 0 => {}
+                        Some(source) => {
+                            if ui.button("Copy here").clicked() {
+                                copy_events.write(CopySaveSlotEvent {
+                                    from: source,
+                                    to: slot,
+                                    overwrite: false,
+                                });
+                                menu.copy_source = None;
+                                sound_events.write(PlaySoundEvent {
+                                    cue: SoundCue::UiConfirm,
+                                });
+                            }
+                        }
+                    }
+                });
+            }
+        });
+
+    let Some(request) = menu.pending_overwrite else {
+        return;
+    };
+
+    egui::Window::new("Overwrite Save?")
+        .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+        .resizable(false)
+        .collapsible(false)
+        .show(ctx, |ui| {
+            ui.label(format!(
+                "{} already holds a save. Replace it with a copy of {}?",
+                slot_name(request.to),
+                slot_name(request.from)
+            ));
+            ui.horizontal(|ui| {
+                if ui.button("Overwrite").clicked() {
+                    copy_events.write(CopySaveSlotEvent {
+                        from: request.from,
+                        to: request.to,
+                        overwrite: true,
+                    });
+                    menu.pending_overwrite = None;
+                    sound_events.write(PlaySoundEvent {
+                        cue: SoundCue::UiConfirm,
+                    });
+                }
+                if ui.button("Cancel").clicked() {
+                    menu.pending_overwrite = None;
+                    sound_events.write(PlaySoundEvent {
+                        cue: SoundCue::UiCancel,
+                    });
+                }
+            });
+        });
+}
+
+/// Returns the label of a save slot row, e.g. "Slot 2: Saved" or
+/// "Slot 2: Before the cellar" once the player named it
+fn slot_label(slot: usize, name: Option<&str>, occupied: bool) -> String {
+    let status = match name {
+        Some(name) if occupied => name,
+        _ if occupied => "Saved",
+        _ => "Empty",
+    };
+    format!("{}: {}", slot_name(slot), status)
+}
+
+/// Returns the cloud sync line of the window, e.g. "Cloud sync: up to date"
+fn sync_status_label(state: &SyncState) -> String {
+    let status = match &state.status {
+        SyncStatus::Syncing => "syncing...".to_string(),
+        SyncStatus::Failed(e) => format!("failed ({})", e),
+        _ if state.pending => "changes waiting".to_string(),
+        SyncStatus::NotSynced => "not synced yet".to_string(),
+        SyncStatus::Synced => "up to date".to_string(),
+    };
+    format!("Cloud sync: {}", status)
+}
+
+/// Returns the display name of a save slot
+fn slot_name(slot: usize) -> String {
+    if slot == 0 {
+        "Auto-save".to_string()
+    } else {
+        format!("Slot {}", slot)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::systems::save_storage::MemoryStorage;
+
+    #[test]
+    fn save_slot_menu_plugin_compiles() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.insert_resource(SaveBackend::new(MemoryStorage::default()));
+        app.add_plugins(SaveSlotMenuPlugin);
+
+        assert!(!app.world().resource::<SaveSlotMenu>().open);
+    }
+
+    #[test]
+    fn menu_key_toggles_window() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.init_resource::<SaveSlotMenu>();
+        app.init_resource::<ButtonInput<KeyCode>>();
+        app.add_event::<PlaySoundEvent>();
+        app.insert_resource(SaveBackend::new(MemoryStorage::default()));
+        app.add_systems(Update, toggle_save_slot_menu_system);
+
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(SAVE_MENU_KEY);
+        app.update();
+
+        assert!(app.world().resource::<SaveSlotMenu>().open);
+        let events = app.world().resource::<Events<PlaySoundEvent>>();
+        let mut cursor = events.get_cursor();
+        assert!(matches!(
+            cursor.read(events).next(),
+            Some(PlaySoundEvent {
+                cue: SoundCue::UiConfirm
+            })
+        ));
+    }
+
+    #[test]
+    fn slot_names() {
+        assert_eq!(slot_name(0), "Auto-save");
+        assert_eq!(slot_name(2), "Slot 2");
+    }
+
+    #[test]
+    fn slot_labels_show_names_of_saved_slots() {
+        assert_eq!(slot_label(1, None, true), "Slot 1: Saved");
+        assert_eq!(slot_label(1, Some("Attic run"), true), "Slot 1: Attic run");
+        assert_eq!(slot_label(1, Some("Attic run"), false), "Slot 1: Empty");
+    }
+
+    #[test]
+    fn sync_status_line_puts_failures_first() {
+        let synced = SyncState {
+            status: SyncStatus::Synced,
+            pending: false,
+        };
+        assert_eq!(sync_status_label(&synced), "Cloud sync: up to date");
+        let saved_since = SyncState {
+            pending: true,
+            ..synced
+        };
+        assert_eq!(
+            sync_status_label(&saved_since),
+            "Cloud sync: changes waiting"
+        );
+        let offline = SyncState {
+            status: SyncStatus::Failed("offline".to_string()),
+            pending: true,
+        };
+        assert_eq!(sync_status_label(&offline), "Cloud sync: failed (offline)");
+    }
+}
@@ -0,0 +1,215 @@
+use bevy_egui::egui;
+use std::fs;
+use std::path::PathBuf;
+
+/// Directory holding committed UI snapshots
+pub const SNAPSHOT_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/snapshots");
+
+/// Environment variable that rewrites snapshots instead of comparing them
+pub const UPDATE_SNAPSHOTS_VAR: &str = "UPDATE_SNAPSHOTS";
+
+/// Screen size used when laying out UI for snapshots
+const SNAPSHOT_SCREEN_SIZE: egui::Vec2 = egui::vec2(1920.0, 1080.0);
+
+/// Lays out egui UI offscreen and describes what it draws
+///
+/// Runs `draw` on a fresh `egui::Context` without any renderer. Windows
+/// need one pass to measure themselves, so the UI is laid out twice and
+/// only the second pass is recorded.
+///
+/// # Returns
+/// One line per painted text or filled rectangle, in paint order, with
+/// positions and sizes rounded to whole pixels, e.g.
+/// `text (17, 65) "Candle: 80%"` or `rect (17, 82) 200x18`
+pub fn layout_snapshot(mut draw: impl FnMut(&egui::Context)) -> String {
+    let ctx = egui::Context::default();
+    let _ = ctx.run(snapshot_input(Vec::new()), &mut draw);
+    let output = ctx.run(snapshot_input(Vec::new()), &mut draw);
+
+    let mut lines = Vec::new();
+    for clipped in &output.shapes {
+        describe_shape(&clipped.shape, &mut lines);
+    }
+    lines.join("\n") + "\n"
+}
+
+/// Lays out egui UI offscreen and clicks the painted text `label`
+///
+/// Lays `draw` out like `layout_snapshot`, then moves the pointer to the
+/// centre of the text and presses and releases the primary button over
+/// three more passes, so widgets see a regular click.
+///
+/// # Panics
+/// When no painted text reads `label`
+pub fn click_text(mut draw: impl FnMut(&egui::Context), label: &str) {
+    let ctx = egui::Context::default();
+    let _ = ctx.run(snapshot_input(Vec::new()), &mut draw);
+    let output = ctx.run(snapshot_input(Vec::new()), &mut draw);
+
+    let mut position = None;
+    for clipped in &output.shapes {
+        find_text(&clipped.shape, label, &mut position);
+    }
+    let Some(position) = position else {
+        panic!("No text {:?} to click", label);
+    };
+
+    let button = |pressed| egui::Event::PointerButton {
+        pos: position,
+        button: egui::PointerButton::Primary,
+        pressed,
+        modifiers: egui::Modifiers::NONE,
+    };
+    for event in [
+        egui::Event::PointerMoved(position),
+        button(true),
+        button(false),
+    ] {
+        let _ = ctx.run(snapshot_input(vec![event]), &mut draw);
+    }
+}
+
+/// Input for one offscreen pass over a `SNAPSHOT_SCREEN_SIZE` screen
+fn snapshot_input(events: Vec<egui::Event>) -> egui::RawInput {
+    egui::RawInput {
+        screen_rect: Some(egui::Rect::from_min_size(
+            egui::Pos2::ZERO,
+            SNAPSHOT_SCREEN_SIZE,
+        )),
+        events,
+        ..Default::default()
+    }
+}
+
+/// Sets `found` to the centre of the first text in `shape` reading `label`
+fn find_text(shape: &egui::Shape, label: &str, found: &mut Option<egui::Pos2>) {
+    match shape {
+        egui::Shape::Vec(shapes) => {
+            for shape in shapes {
+                find_text(shape, label, found);
+            }
+        }
+        egui::Shape::Text(text) if found.is_none() && text.galley.text() == label => {
+            *found = Some(text.visual_bounding_rect().center());
+        }
+        _ => {}
+    }
+}
+
+/// Appends the snapshot lines for `shape` (and any nested shapes)
+fn describe_shape(shape: &egui::Shape, lines: &mut Vec<String>) {
+    match shape {
+        egui::Shape::Vec(shapes) => {
+            for shape in shapes {
+                describe_shape(shape, lines);
+            }
+        }
+        egui::Shape::Text(text) => {
+            lines.push(format!(
+                "text ({:.0}, {:.0}) {:?}",
+                text.pos.x,
+                text.pos.y,
+                text.galley.text()
+            ));
+        }
+        egui::Shape::Rect(rect) if rect.fill != egui::Color32::TRANSPARENT => {
+            lines.push(format!(
+                "rect ({:.0}, {:.0}) {:.0}x{:.0}",
+                rect.rect.min.x,
+                rect.rect.min.y,
+                rect.rect.width(),
+                rect.rect.height()
+            ));
+        }
+        _ => {}
+    }
+}
+
+/// Compares a layout snapshot against `tests/snapshots/<name>.snap`
+///
+/// A missing snapshot file is created. Setting `UPDATE_SNAPSHOTS=1`
+/// rewrites existing files after an intentional UI change.
+///
+/// # Panics
+/// When `actual` differs from the committed snapshot, listing the first
+/// differing line of each.
+pub fn assert_snapshot(name: &str, actual: &str) {
+    let path = PathBuf::from(SNAPSHOT_DIR).join(format!("{}.snap", name));
+    let update = std::env::var_os(UPDATE_SNAPSHOTS_VAR).is_some();
+
+    let expected = match fs::read_to_string(&path) {
+        Ok(expected) if !update => expected,
+        _ => {
+            if let Err(e) = fs::create_dir_all(SNAPSHOT_DIR).and_then(|_| fs::write(&path, actual))
+            {
+                panic!("Failed to write snapshot '{}': {}", path.display(), e);
+            }
+            return;
+        }
+    };
+
+    if expected == actual {
+        return;
+    }
+
+    let (line, expected_line, actual_line) = expected
+        .lines()
+        .zip(actual.lines())
+        .enumerate()
+        .find(|(_, (expected, actual))| expected != actual)
+        .map(|(index, (expected, actual))| (index + 1, expected, actual))
+        .unwrap_or((
+            expected.lines().count().min(actual.lines().count()) + 1,
+            "<end of snapshot>",
+            "<end of snapshot>",
+        ));
+
+    panic!(
+        "UI snapshot '{}' changed at line {}:\n  expected: {}\n  actual:   {}\n\
+         Rerun with {}=1 to accept the new layout.",
+        name, line, expected_line, actual_line, UPDATE_SNAPSHOTS_VAR
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn snapshot_lists_text_and_filled_rects() {
+        let snapshot = layout_snapshot(|ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                ui.label("Hello");
+            });
+        });
+
+        assert!(snapshot.starts_with("rect (0, 0) 1920x1080\n"));
+        assert!(snapshot.contains("\"Hello\""));
+    }
+
+    #[test]
+    fn layout_snapshot_is_deterministic() {
+        let draw = |ctx: &egui::Context| {
+            egui::Window::new("Test").show(ctx, |ui| {
+                ui.label("Same every time");
+            });
+        };
+
+        assert_eq!(layout_snapshot(draw), layout_snapshot(draw));
+    }
+
+    #[test]
+    fn click_text_clicks_the_labelled_button() {
+        let mut clicked = false;
+        click_text(
+            |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    clicked |= ui.button("Press me").clicked();
+                });
+            },
+            "Press me",
+        );
+
+        assert!(clicked);
+    }
+}
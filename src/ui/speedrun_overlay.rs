@@ -0,0 +1,368 @@
+use bevy::prelude::*;
+use bevy_egui::{EguiContexts, egui};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+use crate::components::room::RoomId;
+use crate::resources::game_state::{GameMode, GameState};
+use crate::systems::room_transition::RoomChangedEvent;
+use crate::systems::save_storage::{SPLITS_FILE_NAME, SaveBackend, SaveStorage};
+use crate::ui::photo_mode::photo_mode_inactive;
+
+/// Plugin that adds the optional speedrun timer and splits overlay
+///
+/// Shows real time and in-game time (`GameState::completion_time`) with a
+/// split for each room left. Completed runs are written to
+/// `SPLITS_FILE_NAME`, and the next run shows how far ahead or behind each
+/// split is against them.
+///
+/// **NOTE**: EguiPlugin and `game_clock::GameClockPlugin` (which ticks the
+/// in-game time) must be added to the app before this plugin.
+pub struct SpeedrunOverlayPlugin;
+
+impl Plugin for SpeedrunOverlayPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SpeedrunTimer>()
+            .init_resource::<SaveBackend>()
+            .add_event::<RoomChangedEvent>()
+            .add_systems(
+                Update,
+                (
+                    speedrun_timer_system,
+                    speedrun_overlay_system.run_if(photo_mode_inactive),
+                )
+                    .chain(),
+            );
+    }
+}
+
+/// Times at which the player left a room
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Split {
+    /// Room that was left (the room escaped from for the final split)
+    pub room: RoomId,
+    /// Real time since the run started, in seconds
+    pub real_time_secs: f32,
+    /// In-game time since the run started, in seconds
+    pub game_time_secs: f32,
+}
+
+/// Splits of a completed run, as written to `SPLITS_FILE_NAME`
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct SplitsRun {
+    /// Splits in the order the rooms were left; the last is the escape
+    pub splits: Vec<Split>,
+}
+
+impl SplitsRun {
+    /// Reads the splits from `SPLITS_FILE_NAME`
+    ///
+    /// A missing or unreadable file gives no splits.
+    pub fn load(storage: &impl SaveStorage) -> Option<Self> {
+        let contents = storage.read(SPLITS_FILE_NAME).ok()?;
+        ron::from_str(&contents)
+            .inspect_err(|e| {
+                warn!(
+                    "Ignoring unreadable splits '{}': {}",
+                    storage.location(SPLITS_FILE_NAME),
+                    e
+                );
+            })
+            .ok()
+    }
+
+    /// Writes the splits to `SPLITS_FILE_NAME`
+    ///
+    /// # Errors
+    /// Returns error string if serialization or writing fails
+    pub fn save(&self, storage: &impl SaveStorage) -> Result<(), String> {
+        let contents = ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())
+            .map_err(|e| format!("Failed to serialize splits: {}", e))?;
+        storage.write(SPLITS_FILE_NAME, &contents)
+    }
+}
+
+/// Resource holding the timer of the current run
+#[derive(Resource, Debug, Default, Clone, PartialEq)]
+pub struct SpeedrunTimer {
+    /// Whether a run has started (the overlay is hidden before the first)
+    pub started: bool,
+    /// Real time since the run started, pauses included
+    pub real_time: Duration,
+    /// In-game time last seen, to notice a new game resetting it
+    pub game_time: Duration,
+    /// Splits of the current run
+    pub run: SplitsRun,
+    /// Whether the run has ended with the escape
+    pub finished: bool,
+    /// Run the splits are compared against
+    pub comparison: Option<SplitsRun>,
+}
+
+impl SpeedrunTimer {
+    /// Records a split for leaving `room` at the current times
+    pub fn split(&mut self, room: RoomId) {
+        self.run.splits.push(Split {
+            room,
+            real_time_secs: self.real_time.as_secs_f32(),
+            game_time_secs: self.game_time.as_secs_f32(),
+        });
+    }
+
+    /// Real-time difference between split `index` and the comparison
+    ///
+    /// Negative when ahead. `None` without a comparison split for the same
+    /// room at that position.
+    pub fn delta(&self, index: usize) -> Option<f32> {
+        let split = self.run.splits.get(index)?;
+        let compared = self.comparison.as_ref()?.splits.get(index)?;
+        (compared.room == split.room).then(|| split.real_time_secs - compared.real_time_secs)
+    }
+}
+
+/// Formats seconds as "m:ss.cc" (e.g. "1:05.20")
+pub fn format_split_time(secs: f32) -> String {
+    let centis = (secs.max(0.0) * 100.0).round() as u64;
+    format!(
+        "{}:{:02}.{:02}",
+        centis / 6000,
+        centis / 100 % 60,
+        centis % 100
+    )
+}
+
+/// Formats a split delta with its sign (e.g. "-1.20" when ahead)
+pub fn format_split_delta(secs: f32) -> String {
+    format!("{:+.2}", secs)
+}
+
+/// System that runs the speedrun timer and records splits
+///
+/// # System Dependencies
+/// - **Resources**: Reads `Time<Real>` and `GameState`, writes `SpeedrunTimer`;
+///   reads and writes the splits through `SaveBackend`
+/// - **Upstream**: `room_fade_system` emits `RoomChangedEvent`;
+///   `completion_time_system` ticks the in-game time
+/// - **Downstream**: `speedrun_overlay_system` draws the timer
+///
+/// # Behavior
+/// - **Start**: The run starts when the game leaves the menu, loading the
+///   comparison from `SPLITS_FILE_NAME`. A new game (in-game time going
+///   back) starts a fresh run.
+/// - **Splits**: Each `RoomChangedEvent` splits for the room left
+/// - **Finish**: Escaping (`GameMode::Victory`) splits for the last room,
+///   stops the timer and writes the run to `SPLITS_FILE_NAME`
+pub fn speedrun_timer_system(
+    time: Res<Time<Real>>,
+    game_state: Res<GameState>,
+    mut room_events: EventReader<RoomChangedEvent>,
+    mut timer: ResMut<SpeedrunTimer>,
+    storage: Res<SaveBackend>,
+) {
+    if game_state.game_mode == GameMode::Menu && !timer.started {
+        room_events.clear();
+        return;
+    }
+
+    if !timer.started || game_state.completion_time < timer.game_time {
+        *timer = SpeedrunTimer {
+            started: true,
+            comparison: SplitsRun::load(&*storage),
+            ..default()
+        };
+    }
+    if timer.finished {
+        room_events.clear();
+        return;
+    }
+
+    timer.real_time += time.delta();
+    timer.game_time = game_state.completion_time;
+    for event in room_events.read() {
+        timer.split(event.old_room);
+    }
+
+    if game_state.game_mode == GameMode::Victory {
+        timer.split(game_state.current_room);
+        timer.finished = true;
+        match timer.run.save(&*storage) {
+            Ok(()) => info!(
+                "Run finished in {}, splits written to {}",
+                format_split_time(timer.real_time.as_secs_f32()),
+                storage.location(SPLITS_FILE_NAME)
+            ),
+            Err(e) => error!("Failed to write splits: {}", e),
+        }
+    }
+}
+
+/// Draws the timer and splits in the top-right corner
+///
+/// Split from `speedrun_overlay_system` so the layout can be drawn without a
+/// running app.
+pub fn draw_speedrun_overlay(ctx: &egui::Context, timer: &SpeedrunTimer) {
+    egui::Area::new(egui::Id::new("speedrun_overlay"))
+        .anchor(egui::Align2::RIGHT_TOP, [-16.0, 16.0])
+        .interactable(false)
+        .show(ctx, |ui| {
+            egui::Frame::new()
+                .fill(egui::Color32::from_black_alpha(160))
+                .corner_radius(4.0)
+                .inner_margin(8.0)
+                .show(ui, |ui| {
+                    ui.label(
+                        egui::RichText::new(format_split_time(timer.real_time.as_secs_f32()))
+                            .monospace()
+                            .size(28.0)
+                            .color(if timer.finished {
+                                egui::Color32::from_rgb(255, 220, 150)
+                            } else {
+                                egui::Color32::WHITE
+                            }),
+                    );
+                    ui.label(
+                        egui::RichText::new(format!(
+                            "IGT {}",
+                            format_split_time(timer.game_time.as_secs_f32())
+                        ))
+                        .monospace()
+                        .color(egui::Color32::LIGHT_GRAY),
+                    );
+                    if timer.run.splits.is_empty() {
+                        return;
+                    }
+
+                    ui.separator();
+                    egui::Grid::new("speedrun_splits").show(ui, |ui| {
+                        for (index, split) in timer.run.splits.iter().enumerate() {
+                            ui.label(format!("Room {}", split.room));
+                            ui.monospace(format_split_time(split.real_time_secs));
+                            match timer.delta(index) {
+                                Some(delta) => ui.label(
+                                    egui::RichText::new(format_split_delta(delta))
+                                        .monospace()
+                                        .color(if delta <= 0.0 {
+                                            egui::Color32::from_rgb(120, 220, 120)
+                                        } else {
+                                            egui::Color32::from_rgb(220, 100, 100)
+                                        }),
+                                ),
+                                None => ui.label(""),
+                            };
+                            ui.end_row();
+                        }
+                    });
+                });
+        });
+}
+
+/// System that shows the speedrun overlay once a run has started
+///
+/// # System Dependencies
+/// - **Resources**: EguiContexts (from bevy_egui), reads `SpeedrunTimer`
+/// - **Upstream**: `speedrun_timer_system` updates the timer
+pub fn speedrun_overlay_system(mut contexts: EguiContexts, timer: Res<SpeedrunTimer>) {
+    if !timer.started {
+        return;
+    }
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+
+    draw_speedrun_overlay(ctx, &timer);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::systems::save_storage::MemoryStorage;
+
+    #[test]
+    fn split_times_format_with_centiseconds() {
+        assert_eq!(format_split_time(65.2), "1:05.20");
+        assert_eq!(format_split_time(3.456), "0:03.46");
+        assert_eq!(format_split_delta(-1.2), "-1.20");
+        assert_eq!(format_split_delta(0.5), "+0.50");
+    }
+
+    #[test]
+    fn deltas_compare_splits_of_the_same_room() {
+        let split = |room, real_time_secs| Split {
+            room,
+            real_time_secs,
+            game_time_secs: real_time_secs,
+        };
+        let timer = SpeedrunTimer {
+            run: SplitsRun {
+                splits: vec![split(0, 10.0), split(2, 30.0)],
+            },
+            comparison: Some(SplitsRun {
+                splits: vec![split(0, 12.0), split(1, 25.0)],
+            }),
+            ..default()
+        };
+
+        assert_eq!(timer.delta(0), Some(-2.0));
+        assert_eq!(timer.delta(1), None, "A different route has no delta");
+    }
+
+    #[test]
+    fn room_changes_split_while_playing() {
+        let storage = MemoryStorage::default();
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.init_resource::<SpeedrunTimer>();
+        app.insert_resource(SaveBackend::new(storage.clone()));
+        app.add_event::<RoomChangedEvent>();
+        app.add_systems(Update, speedrun_timer_system);
+        app.insert_resource(GameState {
+            game_mode: GameMode::Playing,
+            completion_time: Duration::from_secs(4),
+            ..default()
+        });
+
+        app.update();
+        app.world_mut().send_event(RoomChangedEvent {
+            old_room: 0,
+            new_room: 1,
+        });
+        app.update();
+
+        let timer = app.world().resource::<SpeedrunTimer>();
+        assert!(timer.started && !timer.finished);
+        assert_eq!(timer.run.splits.len(), 1);
+        assert_eq!(timer.run.splits[0].room, 0);
+        assert_eq!(timer.run.splits[0].game_time_secs, 4.0);
+
+        // A new game resets the in-game time and starts a fresh run
+        app.world_mut().resource_mut::<GameState>().completion_time = Duration::ZERO;
+        app.update();
+        assert!(
+            app.world()
+                .resource::<SpeedrunTimer>()
+                .run
+                .splits
+                .is_empty()
+        );
+
+        // Escaping writes the run through the save backend
+        app.world_mut().resource_mut::<GameState>().game_mode = GameMode::Victory;
+        app.update();
+        let saved = SplitsRun::load(&storage).expect("Splits should be written");
+        assert_eq!(saved, app.world().resource::<SpeedrunTimer>().run);
+    }
+
+    #[test]
+    fn splits_run_round_trips_through_ron() {
+        let run = SplitsRun {
+            splits: vec![Split {
+                room: 3,
+                real_time_secs: 42.5,
+                game_time_secs: 40.0,
+            }],
+        };
+        let ron_string = ron::ser::to_string_pretty(&run, ron::ser::PrettyConfig::default())
+            .expect("Failed to serialize");
+        assert_eq!(ron::from_str::<SplitsRun>(&ron_string).unwrap(), run);
+    }
+}
@@ -0,0 +1,456 @@
+use bevy::prelude::*;
+use bevy_egui::egui;
+
+/// Most characters a profile or save slot name may have
+pub const MAX_NAME_LEN: usize = 24;
+
+/// Characters not allowed in names, since names may end up in file names
+/// on any platform
+pub const ILLEGAL_NAME_CHARS: [char; 9] = ['<', '>', ':', '"', '/', '\\', '|', '?', '*'];
+
+/// Character rows of the on-screen keyboard (upper case; lowered unless caps is on)
+pub const KEYBOARD_ROWS: [&str; 4] = ["1234567890", "QWERTYUIOP", "ASDFGHJKL-", "ZXCVBNM_'."];
+
+/// Keys of the on-screen keyboard's bottom row
+pub const KEYBOARD_ACTIONS: [KeyboardKey; 4] = [
+    KeyboardKey::Caps,
+    KeyboardKey::Space,
+    KeyboardKey::Backspace,
+    KeyboardKey::Done,
+];
+
+/// Why a name was refused
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NameError {
+    /// Nothing but whitespace
+    Empty,
+    /// More than `MAX_NAME_LEN` characters
+    TooLong,
+    /// Contains a character from `ILLEGAL_NAME_CHARS` or a control character
+    IllegalCharacter(char),
+    /// Already used by another profile
+    Taken,
+}
+
+impl NameError {
+    /// Message shown under the text field
+    pub fn message(&self) -> String {
+        match self {
+            NameError::Empty => "Enter a name.".to_string(),
+            NameError::TooLong => format!("Names can have at most {} characters.", MAX_NAME_LEN),
+            NameError::IllegalCharacter(c) if c.is_control() => {
+                "Names can't contain control characters.".to_string()
+            }
+            NameError::IllegalCharacter(c) => format!("Names can't contain '{}'.", c),
+            NameError::Taken => "That name is already taken.".to_string(),
+        }
+    }
+}
+
+/// Checks a profile or save slot name typed by the player
+///
+/// # Returns
+/// The name with surrounding whitespace trimmed
+///
+/// # Errors
+/// Returns the first problem found: empty, too long, or an illegal
+/// character
+pub fn validate_name(input: &str) -> Result<String, NameError> {
+    let name = input.trim();
+    if name.is_empty() {
+        return Err(NameError::Empty);
+    }
+    if name.chars().count() > MAX_NAME_LEN {
+        return Err(NameError::TooLong);
+    }
+    if let Some(c) = name
+        .chars()
+        .find(|c| c.is_control() || ILLEGAL_NAME_CHARS.contains(c))
+    {
+        return Err(NameError::IllegalCharacter(c));
+    }
+    Ok(name.to_string())
+}
+
+/// A key of the on-screen keyboard
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyboardKey {
+    /// Types a character
+    Char(char),
+    /// Toggles upper case letters
+    Caps,
+    /// Types a space
+    Space,
+    /// Deletes the last character
+    Backspace,
+    /// Submits the text
+    Done,
+}
+
+impl KeyboardKey {
+    /// Label drawn on the key
+    pub fn label(&self) -> String {
+        match self {
+            KeyboardKey::Char(c) => c.to_string(),
+            KeyboardKey::Caps => "Caps".to_string(),
+            KeyboardKey::Space => "Space".to_string(),
+            KeyboardKey::Backspace => "Del".to_string(),
+            KeyboardKey::Done => "Done".to_string(),
+        }
+    }
+}
+
+/// Cursor of the on-screen keyboard, moved with the gamepad D-pad
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct OnScreenKeyboard {
+    /// Selected row (`KEYBOARD_ROWS.len()` is the action row)
+    pub row: usize,
+    /// Selected key within the row
+    pub column: usize,
+    /// Whether letters are typed in upper case
+    pub caps: bool,
+}
+
+impl OnScreenKeyboard {
+    /// Number of keys in `row`
+    fn row_len(row: usize) -> usize {
+        KEYBOARD_ROWS
+            .get(row)
+            .map_or(KEYBOARD_ACTIONS.len(), |keys| keys.chars().count())
+    }
+
+    /// Key at `row`, `column`, with the current caps setting
+    pub fn key_at(&self, row: usize, column: usize) -> KeyboardKey {
+        match KEYBOARD_ROWS.get(row) {
+            Some(keys) => {
+                let c = keys.chars().nth(column).unwrap_or(' ');
+                KeyboardKey::Char(if self.caps { c } else { c.to_ascii_lowercase() })
+            }
+            None => KEYBOARD_ACTIONS[column.min(KEYBOARD_ACTIONS.len() - 1)],
+        }
+    }
+
+    /// The selected key
+    pub fn selected(&self) -> KeyboardKey {
+        self.key_at(self.row, self.column)
+    }
+
+    /// Moves the cursor, wrapping around the edges
+    ///
+    /// Moving between rows of different lengths keeps the cursor at the
+    /// same relative position in the row.
+    pub fn move_by(&mut self, columns: i32, rows: i32) {
+        let row_count = KEYBOARD_ROWS.len() + 1;
+        let old_len = Self::row_len(self.row);
+        self.row = (self.row as i32 + rows).rem_euclid(row_count as i32) as usize;
+        let new_len = Self::row_len(self.row);
+        if new_len != old_len {
+            self.column = self.column * new_len / old_len;
+        }
+        self.column = (self.column as i32 + columns).rem_euclid(new_len as i32) as usize;
+    }
+}
+
+/// Gamepad input for a text input
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextNav {
+    /// Move the keyboard cursor up
+    Up,
+    /// Move the keyboard cursor down
+    Down,
+    /// Move the keyboard cursor left
+    Left,
+    /// Move the keyboard cursor right
+    Right,
+    /// Press the selected key
+    Press,
+    /// Delete the last character
+    Backspace,
+    /// Submit the text
+    Submit,
+    /// Close without submitting
+    Cancel,
+}
+
+/// Gamepad buttons and the text input navigation they trigger
+pub const GAMEPAD_TEXT_NAV: [(GamepadButton, TextNav); 8] = [
+    (GamepadButton::DPadUp, TextNav::Up),
+    (GamepadButton::DPadDown, TextNav::Down),
+    (GamepadButton::DPadLeft, TextNav::Left),
+    (GamepadButton::DPadRight, TextNav::Right),
+    (GamepadButton::South, TextNav::Press),
+    (GamepadButton::West, TextNav::Backspace),
+    (GamepadButton::Start, TextNav::Submit),
+    (GamepadButton::East, TextNav::Cancel),
+];
+
+/// Text input navigation pressed this frame on any gamepad
+pub fn gamepad_text_nav<'a>(gamepads: impl IntoIterator<Item = &'a Gamepad>) -> Vec<TextNav> {
+    let mut navs = Vec::new();
+    for gamepad in gamepads {
+        for (button, nav) in GAMEPAD_TEXT_NAV {
+            if gamepad.just_pressed(button) {
+                navs.push(nav);
+            }
+        }
+    }
+    navs
+}
+
+/// How a text input was closed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TextInputOutcome {
+    /// The player confirmed this (validated) name
+    Submitted(String),
+    /// The player closed the input without a name
+    Cancelled,
+}
+
+/// State of a text input dialog
+///
+/// Menus keep one while the dialog is open and show it with
+/// `text_input_window`. Keyboard players type into an egui text field
+/// (which handles IME composition); gamepad players type with the
+/// on-screen keyboard.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextInput {
+    /// Dialog title
+    pub title: String,
+    /// Text typed so far
+    pub text: String,
+    /// On-screen keyboard cursor
+    pub keyboard: OnScreenKeyboard,
+    /// Why the last submitted text was refused
+    pub error: Option<NameError>,
+    /// Whether the text field has taken keyboard focus yet
+    focused: bool,
+}
+
+impl TextInput {
+    /// A dialog titled `title`, prefilled with `text`
+    pub fn new(title: impl Into<String>, text: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            text: text.into(),
+            keyboard: OnScreenKeyboard {
+                caps: true,
+                ..default()
+            },
+            error: None,
+            focused: false,
+        }
+    }
+
+    /// Types `c` unless the text is already `MAX_NAME_LEN` characters long
+    pub fn push(&mut self, c: char) {
+        if self.text.chars().count() < MAX_NAME_LEN {
+            self.text.push(c);
+            self.error = None;
+        }
+    }
+
+    /// Deletes the last character
+    pub fn backspace(&mut self) {
+        self.text.pop();
+        self.error = None;
+    }
+
+    /// Validates the text, keeping the error for display if it is refused
+    pub fn submit(&mut self) -> Option<TextInputOutcome> {
+        match validate_name(&self.text) {
+            Ok(name) => Some(TextInputOutcome::Submitted(name)),
+            Err(error) => {
+                self.error = Some(error);
+                None
+            }
+        }
+    }
+
+    /// Presses an on-screen keyboard key
+    pub fn press(&mut self, key: KeyboardKey) -> Option<TextInputOutcome> {
+        match key {
+            KeyboardKey::Char(c) => self.push(c),
+            KeyboardKey::Caps => self.keyboard.caps = !self.keyboard.caps,
+            KeyboardKey::Space => self.push(' '),
+            KeyboardKey::Backspace => self.backspace(),
+            KeyboardKey::Done => return self.submit(),
+        }
+        None
+    }
+
+    /// Applies one gamepad input
+    pub fn apply_nav(&mut self, nav: TextNav) -> Option<TextInputOutcome> {
+        match nav {
+            TextNav::Up => self.keyboard.move_by(0, -1),
+            TextNav::Down => self.keyboard.move_by(0, 1),
+            TextNav::Left => self.keyboard.move_by(-1, 0),
+            TextNav::Right => self.keyboard.move_by(1, 0),
+            TextNav::Press => return self.press(self.keyboard.selected()),
+            TextNav::Backspace => self.backspace(),
+            TextNav::Submit => return self.submit(),
+            TextNav::Cancel => return Some(TextInputOutcome::Cancelled),
+        }
+        None
+    }
+}
+
+/// Shows a text input dialog
+///
+/// The text field takes keyboard focus when the dialog opens; Enter
+/// submits and Escape cancels. With `show_keyboard` (a gamepad is
+/// connected) the on-screen keyboard is drawn below the field, with the
+/// gamepad cursor highlighted; its keys can also be clicked.
+///
+/// # Returns
+/// How the dialog was closed, or `None` while it stays open
+pub fn text_input_window(
+    ctx: &egui::Context,
+    input: &mut TextInput,
+    show_keyboard: bool,
+    navs: &[TextNav],
+) -> Option<TextInputOutcome> {
+    let mut outcome = None;
+    for nav in navs {
+        outcome = outcome.or_else(|| input.apply_nav(*nav));
+    }
+
+    egui::Window::new(input.title.clone())
+        .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+        .resizable(false)
+        .collapsible(false)
+        .show(ctx, |ui| {
+            let response = ui.add(
+                egui::TextEdit::singleline(&mut input.text)
+                    .char_limit(MAX_NAME_LEN)
+                    .desired_width(240.0),
+            );
+            if !input.focused {
+                response.request_focus();
+                input.focused = true;
+            }
+            if response.changed() {
+                input.error = None;
+            }
+            if response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter)) {
+                outcome = outcome.or_else(|| input.submit());
+            }
+            if ui.input(|i| i.key_pressed(egui::Key::Escape)) {
+                outcome = Some(TextInputOutcome::Cancelled);
+            }
+
+            if let Some(error) = input.error {
+                ui.colored_label(egui::Color32::from_rgb(220, 90, 70), error.message());
+            }
+
+            if show_keyboard {
+                ui.separator();
+                if let Some(pressed) = keyboard_ui(ui, &input.keyboard) {
+                    outcome = outcome.or_else(|| input.press(pressed));
+                }
+            }
+
+            ui.horizontal(|ui| {
+                if ui.button("OK").clicked() {
+                    outcome = outcome.or_else(|| input.submit());
+                }
+                if ui.button("Cancel").clicked() {
+                    outcome = Some(TextInputOutcome::Cancelled);
+                }
+            });
+        });
+
+    outcome
+}
+
+/// Draws the on-screen keyboard
+///
+/// # Returns
+/// The key clicked with the mouse this frame, if any
+fn keyboard_ui(ui: &mut egui::Ui, keyboard: &OnScreenKeyboard) -> Option<KeyboardKey> {
+    let mut clicked = None;
+    for row in 0..=KEYBOARD_ROWS.len() {
+        ui.horizontal(|ui| {
+            for column in 0..OnScreenKeyboard::row_len(row) {
+                let key = keyboard.key_at(row, column);
+                let selected = keyboard.row == row && keyboard.column == column;
+                if ui.selectable_label(selected, key.label()).clicked() {
+                    clicked = Some(key);
+                }
+            }
+        });
+    }
+    clicked
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn names_are_trimmed_and_validated() {
+        assert_eq!(validate_name("  Ada  "), Ok("Ada".to_string()));
+        assert_eq!(validate_name("   "), Err(NameError::Empty));
+        assert_eq!(
+            validate_name(&"x".repeat(MAX_NAME_LEN + 1)),
+            Err(NameError::TooLong)
+        );
+        assert_eq!(validate_name("a/b"), Err(NameError::IllegalCharacter('/')));
+        assert_eq!(
+            validate_name("tab\there"),
+            Err(NameError::IllegalCharacter('\t'))
+        );
+        // Non-ASCII names (typed with an IME) are fine and counted by character
+        assert_eq!(validate_name("ろうそく"), Ok("ろうそく".to_string()));
+    }
+
+    #[test]
+    fn keyboard_cursor_wraps_and_keeps_relative_position() {
+        let mut keyboard = OnScreenKeyboard::default();
+        keyboard.move_by(-1, 0);
+        assert_eq!(keyboard.selected(), KeyboardKey::Char('0'));
+
+        keyboard.move_by(0, -1);
+        assert_eq!(keyboard.row, KEYBOARD_ROWS.len());
+        assert_eq!(keyboard.selected(), KeyboardKey::Done);
+
+        keyboard.move_by(0, 1);
+        assert_eq!(keyboard.row, 0);
+    }
+
+    #[test]
+    fn gamepad_typing_builds_text_and_submits() {
+        let mut input = TextInput::new("Name", "");
+        assert_eq!(input.apply_nav(TextNav::Down), None);
+        assert_eq!(input.apply_nav(TextNav::Press), None);
+        input.keyboard.caps = false;
+        assert_eq!(input.apply_nav(TextNav::Right), None);
+        assert_eq!(input.apply_nav(TextNav::Press), None);
+        assert_eq!(input.text, "Qw");
+
+        assert_eq!(
+            input.apply_nav(TextNav::Submit),
+            Some(TextInputOutcome::Submitted("Qw".to_string()))
+        );
+        assert_eq!(
+            input.apply_nav(TextNav::Cancel),
+            Some(TextInputOutcome::Cancelled)
+        );
+    }
+
+    #[test]
+    fn refused_submit_keeps_error_until_edited() {
+        let mut input = TextInput::new("Name", "a:b");
+        assert_eq!(input.submit(), None);
+        assert_eq!(input.error, Some(NameError::IllegalCharacter(':')));
+
+        input.backspace();
+        assert_eq!(input.error, None);
+    }
+
+    #[test]
+    fn typing_stops_at_max_length() {
+        let mut input = TextInput::new("Name", "x".repeat(MAX_NAME_LEN));
+        input.push('y');
+        assert_eq!(input.text.chars().count(), MAX_NAME_LEN);
+    }
+}
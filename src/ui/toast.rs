@@ -0,0 +1,243 @@
+use bevy::prelude::*;
+use bevy_egui::{EguiContexts, egui};
+
+use crate::resources::game_clock::GameClock;
+use crate::systems::tween::{Ease, Tween, TweenSequence};
+use crate::ui::photo_mode::photo_mode_inactive;
+
+/// Seconds a toast stays on screen
+pub const TOAST_DURATION: f32 = 6.0;
+
+/// Most toasts shown at once; older ones are dropped first
+pub const MAX_TOASTS: usize = 4;
+
+/// Seconds a toast takes to slide in (and back out)
+pub const TOAST_SLIDE_SECS: f32 = 0.25;
+
+/// Distance (points) a toast slides in from beyond the right edge
+pub const TOAST_SLIDE_DISTANCE: f32 = 440.0;
+
+/// Plugin that registers toast notifications
+///
+/// Toasts slide in and out and expire with `GameClock`, so they wait while
+/// the game is paused.
+///
+/// **NOTE**: EguiPlugin must be added to the app before this plugin.
+pub struct ToastPlugin;
+
+impl Plugin for ToastPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Toasts>()
+            .init_resource::<GameClock>()
+            .add_event::<ToastEvent>()
+            .add_systems(
+                Update,
+                (
+                    toast_update_system,
+                    toast_ui_system.run_if(photo_mode_inactive),
+                )
+                    .chain(),
+            );
+    }
+}
+
+/// Severity of a toast, which picks its color
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ToastKind {
+    /// Neutral notification
+    Info,
+    /// Something went wrong but the game carries on
+    Error,
+}
+
+/// Event requesting a short on-screen notification
+#[derive(Event, Debug, Clone, PartialEq)]
+pub struct ToastEvent {
+    /// Text to show
+    pub message: String,
+    /// Severity of the message
+    pub kind: ToastKind,
+}
+
+impl ToastEvent {
+    /// Creates an info toast
+    pub fn info(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            kind: ToastKind::Info,
+        }
+    }
+
+    /// Creates an error toast
+    pub fn error(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            kind: ToastKind::Error,
+        }
+    }
+}
+
+/// A toast currently on screen
+#[derive(Debug, Clone)]
+pub struct Toast {
+    /// Text shown
+    pub message: String,
+    /// Severity of the message
+    pub kind: ToastKind,
+    /// Time left on screen
+    pub timer: Timer,
+}
+
+impl Toast {
+    /// Horizontal offset (points, toward the right edge) of the sliding toast
+    ///
+    /// Slides in over `TOAST_SLIDE_SECS`, holds, and slides back out during
+    /// its last `TOAST_SLIDE_SECS`.
+    pub fn slide_offset(&self) -> f32 {
+        let duration = self.timer.duration().as_secs_f32();
+        let hold = (duration - 2.0 * TOAST_SLIDE_SECS).max(0.0);
+        TweenSequence::new(Tween::new(
+            TOAST_SLIDE_DISTANCE,
+            0.0,
+            TOAST_SLIDE_SECS,
+            Ease::CubicOut,
+        ))
+        .then(Tween::new(0.0, 0.0, hold, Ease::Linear))
+        .then(Tween::new(
+            0.0,
+            TOAST_SLIDE_DISTANCE,
+            TOAST_SLIDE_SECS,
+            Ease::QuadIn,
+        ))
+        .seek(self.timer.elapsed_secs())
+        .value()
+    }
+}
+
+/// Resource holding the toasts currently on screen, oldest first
+#[derive(Resource, Default, Debug)]
+pub struct Toasts {
+    /// Visible toasts
+    pub active: Vec<Toast>,
+}
+
+/// System that queues new toasts and expires old ones
+///
+/// # System Dependencies
+/// - **Upstream**: Any system emitting `ToastEvent`
+/// - **Resources**: Reads `GameClock`, writes `Toasts`
+/// - **Downstream**: `toast_ui_system` draws the toasts
+pub fn toast_update_system(
+    clock: Res<GameClock>,
+    mut events: EventReader<ToastEvent>,
+    mut toasts: ResMut<Toasts>,
+) {
+    for toast in &mut toasts.active {
+        toast.timer.tick(clock.delta_duration());
+    }
+    toasts.active.retain(|toast| !toast.timer.finished());
+
+    for event in events.read() {
+        toasts.active.push(Toast {
+            message: event.message.clone(),
+            kind: event.kind,
+            timer: Timer::from_seconds(TOAST_DURATION, TimerMode::Once),
+        });
+    }
+
+    let overflow = toasts.active.len().saturating_sub(MAX_TOASTS);
+    toasts.active.drain(..overflow);
+}
+
+/// System that draws the active toasts in the top-right corner
+///
+/// Each toast is pushed right by its `Toast::slide_offset`, so it slides in
+/// from (and back out past) the edge of the screen.
+///
+/// # System Dependencies
+/// - **Resources**: EguiContexts (from bevy_egui), Toasts
+pub fn toast_ui_system(mut contexts: EguiContexts, toasts: Res<Toasts>) {
+    if toasts.active.is_empty() {
+        return;
+    }
+
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+
+    // Anchored a full slide beyond the corner; settled toasts are pulled back in
+    egui::Area::new(egui::Id::new("toasts"))
+        .anchor(egui::Align2::RIGHT_TOP, [TOAST_SLIDE_DISTANCE - 16.0, 16.0])
+        .constrain(false)
+        .interactable(false)
+        .show(ctx, |ui| {
+            for toast in &toasts.active {
+                let color = match toast.kind {
+                    ToastKind::Info => egui::Color32::LIGHT_GRAY,
+                    ToastKind::Error => egui::Color32::from_rgb(255, 120, 100),
+                };
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::TOP), |ui| {
+                    ui.add_space(TOAST_SLIDE_DISTANCE - toast.slide_offset());
+                    egui::Frame::popup(ui.style()).show(ui, |ui| {
+                        ui.set_max_width(420.0);
+                        ui.label(egui::RichText::new(&toast.message).color(color));
+                    });
+                });
+            }
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn toasts_queue_and_cap_at_max() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.init_resource::<Toasts>();
+        app.init_resource::<GameClock>();
+        app.add_event::<ToastEvent>();
+        app.add_systems(Update, toast_update_system);
+
+        for i in 0..MAX_TOASTS + 1 {
+            app.world_mut()
+                .send_event(ToastEvent::error(format!("Toast {}", i)));
+        }
+        app.update();
+
+        let toasts = app.world().resource::<Toasts>();
+        assert_eq!(toasts.active.len(), MAX_TOASTS);
+        assert_eq!(toasts.active[0].message, "Toast 1");
+        assert_eq!(toasts.active[0].kind, ToastKind::Error);
+
+        // Expired toasts are removed
+        for toast in &mut app.world_mut().resource_mut::<Toasts>().active {
+            toast
+                .timer
+                .tick(std::time::Duration::from_secs_f32(TOAST_DURATION));
+        }
+        app.update();
+        assert!(app.world().resource::<Toasts>().active.is_empty());
+    }
+
+    #[test]
+    fn toasts_slide_in_hold_and_slide_out() {
+        let mut toast = Toast {
+            message: "Saved".to_string(),
+            kind: ToastKind::Info,
+            timer: Timer::from_seconds(TOAST_DURATION, TimerMode::Once),
+        };
+        assert_eq!(toast.slide_offset(), TOAST_SLIDE_DISTANCE);
+
+        toast
+            .timer
+            .tick(std::time::Duration::from_secs_f32(TOAST_DURATION / 2.0));
+        assert_eq!(toast.slide_offset(), 0.0);
+
+        toast
+            .timer
+            .tick(std::time::Duration::from_secs_f32(TOAST_DURATION / 2.0));
+        assert_eq!(toast.slide_offset(), TOAST_SLIDE_DISTANCE);
+    }
+}
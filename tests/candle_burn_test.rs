@@ -1,7 +1,16 @@
 use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use leafwing_input_manager::prelude::ActionState;
 use rust_game::components::lighting::*;
+use rust_game::components::player::{JumpState, Player, Stamina, Velocity};
+use rust_game::components::room::Collider;
 use rust_game::resources::game_state::*;
-use rust_game::systems::candle_burn::candle_burn_system;
+use rust_game::resources::input_config::PlayerAction;
+use rust_game::systems::candle_burn::{
+    CandleBurnPlugin, MOVING_BURN_MULTIPLIER, SPRINT_BURN_MULTIPLIER, candle_burn_system,
+};
+use rust_game::systems::game_set::GameSetPlugin;
+use rust_game::systems::player_movement::PlayerMovementPlugin;
 use std::time::Duration;
 
 #[test]
@@ -463,3 +472,127 @@ fn multiple_candles_burn_independently() {
         );
     }
 }
+
+#[test]
+fn burn_zone_speeds_up_candles_inside_it_through_the_plugin() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, GameSetPlugin, CandleBurnPlugin));
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        100,
+    )));
+    app.insert_resource(GameState {
+        game_mode: GameMode::Playing,
+        ..default()
+    });
+
+    // A drafty hallway doubling the burn within 50 px of the origin
+    app.world_mut().spawn((
+        BurnZone,
+        BurnModifier(2.0),
+        Transform::from_xyz(0.0, 0.0, 0.0),
+        Collider {
+            min: Vec2::splat(-50.0),
+            max: Vec2::splat(50.0),
+        },
+    ));
+    let spawn_candle = |app: &mut App, x: f32| {
+        app.world_mut()
+            .spawn((
+                Candle,
+                CandleWax(100.0),
+                CandleState::Lit,
+                BurnRate(1.0),
+                VisibilityRadius(7.0),
+                Transform::from_xyz(x, 0.0, 0.0),
+            ))
+            .id()
+    };
+    let inside = spawn_candle(&mut app, 10.0);
+    let outside = spawn_candle(&mut app, 200.0);
+
+    for _ in 0..10 {
+        app.update();
+    }
+
+    let burned = |candle: Entity| 100.0 - app.world().get::<CandleWax>(candle).unwrap().0;
+    assert!(burned(outside) > 0.0, "Candle outside the zone should burn");
+    assert!(
+        (burned(inside) / burned(outside) - 2.0).abs() < 1e-3,
+        "Candle in the zone should burn twice as fast (burned {} vs {})",
+        burned(inside),
+        burned(outside)
+    );
+}
+
+/// Wax a candle loses while the player holds right for ten 100 ms frames,
+/// with Shift held or not, and whether the player ended up sprinting
+fn wax_burned_walking(sprint: bool) -> (f32, bool) {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        GameSetPlugin,
+        PlayerMovementPlugin,
+        CandleBurnPlugin,
+    ));
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        100,
+    )));
+    app.insert_resource(GameState {
+        game_mode: GameMode::Playing,
+        ..default()
+    });
+
+    let mut actions = ActionState::<PlayerAction>::default();
+    actions.press(&PlayerAction::MoveRight);
+    if sprint {
+        actions.press(&PlayerAction::Sprint);
+    }
+    let player = app
+        .world_mut()
+        .spawn((
+            Player,
+            Transform::from_xyz(0.0, 0.0, 0.0),
+            Velocity(Vec2::ZERO),
+            JumpState::Grounded,
+            actions,
+        ))
+        .id();
+    // Nobody carries the candle, so the player counts as its holder
+    let candle = app
+        .world_mut()
+        .spawn((
+            Candle,
+            CandleWax(100.0),
+            CandleState::Lit,
+            BurnRate(1.0),
+            VisibilityRadius(7.0),
+        ))
+        .id();
+
+    for _ in 0..10 {
+        app.update();
+    }
+
+    let sprinting = app.world().get::<Stamina>(player).unwrap().sprinting;
+    (
+        100.0 - app.world().get::<CandleWax>(candle).unwrap().0,
+        sprinting,
+    )
+}
+
+#[test]
+fn sprinting_player_burns_the_candle_faster_than_walking() {
+    let (walked, walk_sprinting) = wax_burned_walking(false);
+    let (sprinted, sprinting) = wax_burned_walking(true);
+    assert!(!walk_sprinting && sprinting);
+
+    assert!(walked > 0.0, "Candle should burn while walking");
+    let expected = SPRINT_BURN_MULTIPLIER / MOVING_BURN_MULTIPLIER;
+    assert!(
+        (sprinted / walked - expected).abs() < 1e-3,
+        "Sprinting should burn {}x the wax of walking (burned {} vs {})",
+        expected,
+        sprinted,
+        walked
+    );
+}
@@ -0,0 +1,106 @@
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use rust_game::components::inventory::{Collectible, Item, StackableItem};
+use rust_game::components::lighting::*;
+use rust_game::components::trap::{ResetTimer, Trap, TrapState, TrapTrigger};
+use rust_game::resources::game_rules::{Difficulty, GameRuleSet, GameRulesPlugin, NewGameEvent};
+use rust_game::systems::candle_burn::CandleBurnPlugin;
+use rust_game::systems::game_set::GameSetPlugin;
+use rust_game::systems::player::PlayerPlugin;
+use rust_game::systems::trap::TrapPlugin;
+use std::time::Duration;
+
+/// What a game started at some difficulty looks like 0.6 s in
+struct DifficultyOutcome {
+    /// Wax burned by a lit candle with `BurnRate(1.0)`
+    wax_burned: f32,
+    /// Matches in a pickup authored with 4
+    matches: u32,
+    /// Whether a trap triggered at the start with a 1 s reset timer re-armed
+    trap_armed: bool,
+}
+
+/// Starts a new game at `difficulty` through `NewGameEvent` and runs the
+/// plugins' scheduled systems for six 100 ms frames
+fn play_new_game(difficulty: Difficulty) -> DifficultyOutcome {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        GameSetPlugin,
+        GameRulesPlugin,
+        PlayerPlugin,
+        TrapPlugin,
+        CandleBurnPlugin,
+    ));
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        100,
+    )));
+
+    // Starting the game sets the rules and switches to Playing
+    app.world_mut().send_event(NewGameEvent {
+        rules: GameRuleSet::Normal,
+        difficulty,
+    });
+    app.update();
+
+    let candle = app
+        .world_mut()
+        .spawn((
+            Candle,
+            CandleWax(100.0),
+            CandleState::Lit,
+            BurnRate(1.0),
+            VisibilityRadius(7.0),
+        ))
+        .id();
+    let pickup = app
+        .world_mut()
+        .spawn((Item::Match, Collectible, StackableItem(4)))
+        .id();
+    let trap = app
+        .world_mut()
+        .spawn((
+            Trap::Spikes,
+            TrapState::Triggered,
+            TrapTrigger::PressurePlate,
+            ResetTimer::from_seconds(1.0),
+        ))
+        .id();
+
+    for _ in 0..6 {
+        app.update();
+    }
+
+    DifficultyOutcome {
+        wax_burned: 100.0 - app.world().get::<CandleWax>(candle).unwrap().0,
+        matches: app.world().get::<StackableItem>(pickup).unwrap().0,
+        trap_armed: *app.world().get::<TrapState>(trap).unwrap() == TrapState::Armed,
+    }
+}
+
+#[test]
+fn new_game_difficulty_scales_candles_matches_and_trap_rearm() {
+    let easy = play_new_game(Difficulty::Easy);
+    let hard = play_new_game(Difficulty::Hard);
+
+    // Candles burn at the difficulty's multiplier
+    assert!(easy.wax_burned > 0.0, "Candle should burn on Easy");
+    let expected = Difficulty::Hard.burn_multiplier() / Difficulty::Easy.burn_multiplier();
+    let ratio = hard.wax_burned / easy.wax_burned;
+    assert!(
+        (ratio - expected).abs() < 1e-3,
+        "Hard should burn {}x the wax of Easy (burned {} vs {})",
+        expected,
+        hard.wax_burned,
+        easy.wax_burned
+    );
+
+    // Match pickups hold more matches on Easy and fewer on Hard
+    assert_eq!(easy.matches, Difficulty::Easy.match_count(4));
+    assert_eq!(hard.matches, Difficulty::Hard.match_count(4));
+    assert!(easy.matches > 4 && hard.matches < 4);
+
+    // 0.5 s of resetting re-arms a 1 s trap only at Hard's quicker rate
+    assert!(hard.trap_armed, "Hard trap should have re-armed");
+    assert!(!easy.trap_armed, "Easy trap should still be resetting");
+}
@@ -12,7 +12,8 @@ use rust_game::components::player::Player;
 use rust_game::components::room::Collider;
 use rust_game::systems::collision::collision_detection_system;
 use rust_game::systems::inventory::{
-    ItemCollectedEvent, ItemUsedEvent, inventory_collection_system, inventory_usage_system,
+    InventoryFullEvent, ItemCollectedEvent, ItemUsedEvent, inventory_collection_system,
+    inventory_usage_system,
 };
 use rust_game::systems::trap::TrapTriggeredEvent;
 
@@ -23,6 +24,7 @@ fn full_item_pickup_flow() {
 
     // Add events
     app.add_event::<ItemCollectedEvent>();
+    app.add_event::<InventoryFullEvent>();
     app.add_event::<TrapTriggeredEvent>();
 
     // Add systems in correct order
@@ -106,6 +108,7 @@ fn inventory_capacity_enforcement() {
     app.add_plugins(MinimalPlugins);
 
     app.add_event::<ItemCollectedEvent>();
+    app.add_event::<InventoryFullEvent>();
     app.add_event::<TrapTriggeredEvent>();
 
     app.add_systems(
@@ -122,7 +125,7 @@ fn inventory_capacity_enforcement() {
             Inventory {
                 items: vec![
                     Item::Match,
-                    Item::Match,
+                    Item::Tool(ToolType::WireCutters),
                     Item::Key(KeyType::Brass),
                     Item::Key(KeyType::Iron),
                     Item::Key(KeyType::Ornate),
@@ -185,6 +188,12 @@ fn inventory_capacity_enforcement() {
             "Item should remain in world when inventory full"
         );
     }
+
+    // Verify the refusal was reported for HUD feedback
+    {
+        let events = app.world().resource::<Events<InventoryFullEvent>>();
+        assert_eq!(events.get_cursor().read(events).count(), 1);
+    }
 }
 
 #[test]
@@ -193,6 +202,7 @@ fn multiple_items_collected_in_order() {
     app.add_plugins(MinimalPlugins);
 
     app.add_event::<ItemCollectedEvent>();
+    app.add_event::<InventoryFullEvent>();
     app.add_event::<TrapTriggeredEvent>();
 
     app.add_systems(
@@ -289,6 +299,7 @@ fn stackable_items_increment_count() {
     app.add_plugins(MinimalPlugins);
 
     app.add_event::<ItemCollectedEvent>();
+    app.add_event::<InventoryFullEvent>();
     app.add_event::<TrapTriggeredEvent>();
 
     app.add_systems(
@@ -420,6 +431,7 @@ fn unique_items_occupy_separate_slots() {
     app.add_plugins(MinimalPlugins);
 
     app.add_event::<ItemCollectedEvent>();
+    app.add_event::<InventoryFullEvent>();
     app.add_event::<TrapTriggeredEvent>();
 
     app.add_systems(
@@ -4,10 +4,14 @@ use rust_game::components::player::*;
 use rust_game::components::room::Collider;
 use rust_game::components::trap::*;
 use rust_game::resources::game_state::*;
+use rust_game::resources::map_state::DeathCause;
 use rust_game::systems::collision::collision_detection_system;
 use rust_game::systems::inventory::ItemCollectedEvent;
 use rust_game::systems::respawn::{DeathTimer, RESPAWN_DELAY, respawn_system};
-use rust_game::systems::trap::{PlayerDeathEvent, TrapTriggeredEvent, trap_activation_system};
+use rust_game::systems::trap::{
+    PlayerDeathEvent, TrapResetPolicy, TrapTriggeredEvent, trap_activation_system,
+    trap_respawn_reset_system,
+};
 use std::time::Duration;
 
 #[test]
@@ -195,6 +199,7 @@ fn trap_resets_after_player_respawn() {
     app.add_event::<TrapTriggeredEvent>();
     app.add_event::<PlayerDeathEvent>();
     app.add_event::<ItemCollectedEvent>();
+    app.init_resource::<TrapResetPolicy>();
 
     // Add systems
     app.add_systems(
@@ -203,6 +208,7 @@ fn trap_resets_after_player_respawn() {
             collision_detection_system,
             trap_activation_system,
             respawn_system,
+            trap_respawn_reset_system,
         )
             .chain(),
     );
@@ -292,14 +298,13 @@ fn trap_resets_after_player_respawn() {
         );
     }
 
-    // Assert: Trap remains triggered (no auto-reset implemented yet)
-    // Note: Trap reset logic would be a future enhancement
+    // Assert: Trap re-armed on respawn (default TrapResetPolicy)
     {
         let trap_state = app.world().get::<TrapState>(trap_entity).unwrap();
         assert_eq!(
             *trap_state,
-            TrapState::Triggered,
-            "Trap should remain triggered (no reset system yet)"
+            TrapState::Armed,
+            "Trap should be re-armed when the player respawns"
         );
     }
 }
@@ -464,7 +469,10 @@ fn multiple_deaths_increment_counter() {
     // Helper function to kill player and wait for respawn
     let kill_and_respawn = |app: &mut App, player: Entity| {
         // Manually send death event (simulating trap kill)
-        app.world_mut().send_event(PlayerDeathEvent { player });
+        app.world_mut().send_event(PlayerDeathEvent {
+            player,
+            cause: DeathCause::Unknown,
+        });
         app.update();
 
         // Fast-forward death timer
@@ -53,7 +53,7 @@ fn pressure_plate_puzzle_unlocks_door() {
             Door,
             DoorState::Locked(KeyType::Master), // Requires puzzle completion
             TargetRoom(2),
-            Interactable,
+            Interactable::new(InteractionKind::OpenDoor),
         ))
         .id();
 
@@ -417,10 +417,10 @@ fn lever_combination_puzzle_requires_correct_states() {
         deaths: 0,
     });
 
-    // Setup: Lever combination puzzle
-    let lever1 = app.world_mut().spawn(()).id();
-    let lever2 = app.world_mut().spawn(()).id();
-    let lever3 = app.world_mut().spawn(()).id();
+    // Setup: Lever combination puzzle with the middle lever in the wrong state
+    let lever1 = app.world_mut().spawn(LeverState::Up).id();
+    let lever2 = app.world_mut().spawn(LeverState::Up).id();
+    let lever3 = app.world_mut().spawn(LeverState::Up).id();
 
     let puzzle_entity = app
         .world_mut()
@@ -434,21 +434,36 @@ fn lever_combination_puzzle_requires_correct_states() {
         ))
         .id();
 
-    // Act: Trigger puzzle interaction
-    // Note: Actual lever state checking would require LeverState components
-    // For now, the puzzle system marks it as InProgress
+    // Act: Trigger puzzle interaction with an incorrect combination
     app.world_mut().send_event(PuzzleInteractEvent {
         puzzle: puzzle_entity,
     });
     app.update();
 
-    // Assert: Puzzle transitions to InProgress (simplified implementation)
+    // Assert: Puzzle transitions to InProgress
     {
         let puzzle_state = app.world().get::<PuzzleState>(puzzle_entity).unwrap();
         assert_eq!(
             *puzzle_state,
             PuzzleState::InProgress,
-            "Lever puzzle should transition to InProgress when structure is valid"
+            "Lever puzzle should be InProgress while the combination is wrong"
+        );
+    }
+
+    // Act: Flip the middle lever and interact again
+    app.world_mut().entity_mut(lever2).insert(LeverState::Down);
+    app.world_mut().send_event(PuzzleInteractEvent {
+        puzzle: puzzle_entity,
+    });
+    app.update();
+
+    // Assert: Puzzle is solved once every lever matches
+    {
+        let puzzle_state = app.world().get::<PuzzleState>(puzzle_entity).unwrap();
+        assert_eq!(
+            *puzzle_state,
+            PuzzleState::Solved,
+            "Lever puzzle should be solved when all levers match"
         );
     }
 }
@@ -4,6 +4,7 @@ use rust_game::components::player::{Health, Player};
 use rust_game::components::room::Collider;
 use rust_game::components::trap::{Trap, TrapState, TrapTrigger};
 use rust_game::resources::game_state::{GameMode, GameState};
+use rust_game::resources::map_state::DeathCause;
 use rust_game::systems::collision::collision_detection_system;
 use rust_game::systems::inventory::ItemCollectedEvent;
 use rust_game::systems::respawn::{DeathTimer, RESPAWN_DELAY, respawn_system};
@@ -173,7 +174,10 @@ fn respawn_with_death_event_only() {
     assert_eq!(initial_pos.truncate(), Vec2::new(300.0, 300.0));
 
     // Send death event
-    app.world_mut().send_event(PlayerDeathEvent { player });
+    app.world_mut().send_event(PlayerDeathEvent {
+        player,
+        cause: DeathCause::Unknown,
+    });
 
     // Update to add timer
     app.update();
@@ -229,7 +233,10 @@ fn multiple_death_respawn_cycles() {
         .id();
 
     // Death-respawn cycle 1
-    app.world_mut().send_event(PlayerDeathEvent { player });
+    app.world_mut().send_event(PlayerDeathEvent {
+        player,
+        cause: DeathCause::Unknown,
+    });
     app.update();
     {
         let mut query = app.world_mut().query::<&mut DeathTimer>();
@@ -241,7 +248,10 @@ fn multiple_death_respawn_cycles() {
     assert_eq!(*app.world().get::<Health>(player).unwrap(), Health::Alive);
 
     // Death-respawn cycle 2
-    app.world_mut().send_event(PlayerDeathEvent { player });
+    app.world_mut().send_event(PlayerDeathEvent {
+        player,
+        cause: DeathCause::Unknown,
+    });
     app.update();
     {
         let mut query = app.world_mut().query::<&mut DeathTimer>();
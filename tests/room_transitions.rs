@@ -1,6 +1,8 @@
 use bevy::prelude::*;
 use rust_game::components::player::Player;
-use rust_game::components::room::{Door, DoorState, Floor, Interactable, Room, TargetRoom};
+use rust_game::components::room::{
+    Door, DoorState, Floor, Interactable, InteractionKind, Room, TargetRoom,
+};
 use rust_game::resources::game_state::GameState;
 use rust_game::resources::map_state::MapState;
 use rust_game::systems::room_transition::{RoomChangedEvent, room_transition_system};
@@ -60,7 +62,7 @@ fn room_transition_loads_new_room() {
             Door,
             DoorState::Unlocked,
             TargetRoom(1),
-            Interactable,
+            Interactable::new(InteractionKind::OpenDoor),
             Transform::from_xyz(200.0, 100.0, 0.0),
             Name::new("Door to Room B"),
         ))
@@ -290,7 +292,7 @@ fn locked_door_state_verification() {
             Door,
             DoorState::Locked(KeyType::Brass),
             TargetRoom(1),
-            Interactable,
+            Interactable::new(InteractionKind::OpenDoor),
         ))
         .id();
 
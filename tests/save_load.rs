@@ -2,20 +2,34 @@ use bevy::prelude::*;
 use rust_game::components::inventory::*;
 use rust_game::components::lighting::*;
 use rust_game::components::player::*;
+use rust_game::components::room::LevelEntityId;
+use rust_game::resources::game_rng::GameRng;
+use rust_game::resources::game_rules::{DAILY_BURN_MULTIPLIER, GameRuleSet, GameRules};
 use rust_game::resources::game_state::*;
 use rust_game::resources::map_state::*;
+use rust_game::resources::room_state::RoomStateStore;
+use rust_game::systems::daily::{DAILY_REMIX_CYCLE, DailyChallenge, daily_seed};
+use rust_game::systems::remix::{ActiveRemix, RemixPlugin};
+use rust_game::systems::room_state::RoomStatePlugin;
 use rust_game::systems::save_load::*;
-use std::fs;
+use rust_game::systems::save_storage::{
+    FileStorage, MemoryStorage, SaveBackend, SaveStorage, save_file_name,
+};
 use std::time::Duration;
 
-#[test]
-fn auto_save_on_room_transition() {
-    // Clean up any existing save files before test
-    let save_path = get_save_path(0);
-    let _ = fs::remove_file(&save_path);
-
+/// App whose saves go to `storage` instead of the player's save directory
+fn app_with_storage(storage: &MemoryStorage) -> App {
     let mut app = App::new();
     app.add_plugins(MinimalPlugins);
+    app.insert_resource(SaveBackend::new(storage.clone()));
+    app
+}
+
+#[test]
+fn auto_save_on_room_transition() {
+    // Saves stay in memory, so tests never share slots
+    let storage = MemoryStorage::default();
+    let mut app = app_with_storage(&storage);
 
     // Add save/load systems and events
     app.add_event::<AutoSaveEvent>();
@@ -79,7 +93,10 @@ fn auto_save_on_room_transition() {
     app.update();
 
     // Assert: Save file exists
-    assert!(save_path.exists(), "Save file should exist after auto-save");
+    assert!(
+        storage.exists(&save_file_name(0)),
+        "Save file should exist after auto-save"
+    );
 
     // Act: Modify state (simulate room transition)
     {
@@ -162,19 +179,12 @@ fn auto_save_on_room_transition() {
         let state = app.world().get::<CandleState>(candle_entity).unwrap();
         assert_eq!(*state, CandleState::Lit, "Candle state should be preserved");
     }
-
-    // Cleanup
-    let _ = fs::remove_file(&save_path);
 }
 
 #[test]
 fn manual_save_preserves_all_state() {
-    // Clean up save file
-    let save_path = get_save_path(20);
-    let _ = fs::remove_file(&save_path);
-
-    let mut app = App::new();
-    app.add_plugins(MinimalPlugins);
+    let storage = MemoryStorage::default();
+    let mut app = app_with_storage(&storage);
 
     // Add save/load systems
     app.add_event::<ManualSaveEvent>();
@@ -245,7 +255,7 @@ fn manual_save_preserves_all_state() {
 
     // Assert: Save file exists
     assert!(
-        save_path.exists(),
+        storage.exists(&save_file_name(20)),
         "Save file should exist after manual save"
     );
 
@@ -309,19 +319,13 @@ fn manual_save_preserves_all_state() {
         assert!(map_state.is_visited(2), "Room 2 should be explored");
         assert!(map_state.is_visited(3), "Room 3 should be explored");
     }
-
-    // Cleanup
-    let _ = fs::remove_file(&save_path);
 }
 
 #[test]
 fn save_file_format_is_ron() {
     // This test verifies the save file uses RON format for human readability
-    let save_path = get_save_path(11);
-    let _ = fs::remove_file(&save_path);
-
-    let mut app = App::new();
-    app.add_plugins(MinimalPlugins);
+    let storage = MemoryStorage::default();
+    let mut app = app_with_storage(&storage);
 
     app.add_event::<ManualSaveEvent>();
     app.add_systems(Update, manual_save_system);
@@ -359,9 +363,12 @@ fn save_file_format_is_ron() {
     app.update();
 
     // Assert: Save file exists and is RON format
-    assert!(save_path.exists(), "Save file should exist");
+    assert!(
+        storage.exists(&save_file_name(11)),
+        "Save file should exist"
+    );
 
-    let content = fs::read_to_string(&save_path).expect("Failed to read save file");
+    let content = storage.read_slot(11).expect("Failed to read save file");
 
     // Verify RON syntax
     assert!(content.contains("version: 1"), "Should have version field");
@@ -375,9 +382,6 @@ fn save_file_format_is_ron() {
     );
     assert!(content.contains("("), "Should contain RON parentheses");
     assert!(content.contains(":"), "Should contain RON field separators");
-
-    // Cleanup
-    let _ = fs::remove_file(&save_path);
 }
 
 #[test]
@@ -386,12 +390,24 @@ fn save_to_platform_specific_directory() {
     // Linux: ~/.local/share/rust-game/
     // Windows: %APPDATA%/rust-game/
     // macOS: ~/Library/Application Support/rust-game/
+    let platform_path = get_save_path(12);
+    assert!(
+        platform_path.to_string_lossy().contains("rust-game"),
+        "Path should contain 'rust-game' directory"
+    );
+    assert!(
+        platform_path.ends_with(save_file_name(12)),
+        "Save file should be named after its slot"
+    );
 
-    let save_path = get_save_path(12);
-    let _ = fs::remove_file(&save_path);
-
+    // Saving to disk is checked in a scratch directory rather than the
+    // player's save directory
+    let dir = std::env::temp_dir().join(format!("rust-game-save-dir-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
     let mut app = App::new();
     app.add_plugins(MinimalPlugins);
+    app.insert_resource(SaveBackend::new(FileStorage::new(&dir)));
 
     app.add_event::<ManualSaveEvent>();
     app.add_systems(Update, manual_save_system);
@@ -421,38 +437,23 @@ fn save_to_platform_specific_directory() {
     app.world_mut().send_event(ManualSaveEvent { slot: 12 });
     app.update();
 
-    // Assert: Save file exists at expected platform-specific path
+    // Assert: Save file exists in the storage directory
+    let save_path = dir.join(save_file_name(12));
     assert!(
-        save_path.exists(),
+        save_path.is_file(),
         "Save file should exist at {:?}",
         save_path
     );
-    assert!(
-        save_path.to_string_lossy().contains("rust-game"),
-        "Path should contain 'rust-game' directory"
-    );
 
-    // Verify parent directory was created
-    let parent_dir = save_path.parent().expect("Should have parent directory");
-    assert!(
-        parent_dir.exists(),
-        "Parent directory should exist at {:?}",
-        parent_dir
-    );
-
-    // Cleanup
-    let _ = fs::remove_file(&save_path);
+    let _ = std::fs::remove_dir_all(&dir);
 }
 
 #[test]
 fn load_nonexistent_save_returns_default_state() {
     // Verifies game starts fresh if no save file exists
 
-    let save_path = get_save_path(99); // Use unlikely slot number
-    let _ = fs::remove_file(&save_path); // Ensure it doesn't exist
-
-    let mut app = App::new();
-    app.add_plugins(MinimalPlugins);
+    let storage = MemoryStorage::default();
+    let mut app = app_with_storage(&storage);
 
     app.add_event::<LoadGameEvent>();
     app.add_systems(Update, load_game_system);
@@ -479,7 +480,10 @@ fn load_nonexistent_save_returns_default_state() {
     ));
 
     // Verify save file doesn't exist
-    assert!(!save_path.exists(), "Save file should not exist");
+    assert!(
+        !storage.exists(&save_file_name(99)),
+        "Save file should not exist"
+    );
 
     // Act: Try to load from nonexistent save
     app.world_mut().send_event(LoadGameEvent { slot: 99 });
@@ -503,17 +507,8 @@ fn load_nonexistent_save_returns_default_state() {
 fn multiple_save_slots_supported() {
     // Verifies game can maintain multiple save files
 
-    let slot1_path = get_save_path(1);
-    let slot2_path = get_save_path(2);
-    let slot3_path = get_save_path(3);
-
-    // Cleanup
-    let _ = fs::remove_file(&slot1_path);
-    let _ = fs::remove_file(&slot2_path);
-    let _ = fs::remove_file(&slot3_path);
-
-    let mut app = App::new();
-    app.add_plugins(MinimalPlugins);
+    let storage = MemoryStorage::default();
+    let mut app = app_with_storage(&storage);
 
     app.add_event::<ManualSaveEvent>();
     app.add_event::<LoadGameEvent>();
@@ -573,9 +568,11 @@ fn multiple_save_slots_supported() {
     app.update();
 
     // Assert: All three save files exist
-    assert!(slot1_path.exists(), "Slot 1 save should exist");
-    assert!(slot2_path.exists(), "Slot 2 save should exist");
-    assert!(slot3_path.exists(), "Slot 3 save should exist");
+    assert_eq!(
+        storage.list_slots(),
+        vec![1, 2, 3],
+        "Slots 1, 2 and 3 should hold saves"
+    );
 
     // Act: Load slot 2 specifically
     app.world_mut().send_event(LoadGameEvent { slot: 2 });
@@ -587,22 +584,14 @@ fn multiple_save_slots_supported() {
         assert_eq!(game_state.current_room, 1, "Should load room 1 from slot 2");
         assert_eq!(game_state.deaths, 5, "Should load 5 deaths from slot 2");
     }
-
-    // Cleanup
-    let _ = fs::remove_file(&slot1_path);
-    let _ = fs::remove_file(&slot2_path);
-    let _ = fs::remove_file(&slot3_path);
 }
 
 #[test]
 fn save_version_compatibility() {
     // Verifies save file includes version for future compatibility
 
-    let save_path = get_save_path(10);
-    let _ = fs::remove_file(&save_path);
-
-    let mut app = App::new();
-    app.add_plugins(MinimalPlugins);
+    let storage = MemoryStorage::default();
+    let mut app = app_with_storage(&storage);
 
     app.add_event::<ManualSaveEvent>();
     app.add_systems(Update, manual_save_system);
@@ -633,9 +622,12 @@ fn save_version_compatibility() {
     app.update();
 
     // Assert: Parse save file and check version
-    assert!(save_path.exists(), "Save file should exist");
+    assert!(
+        storage.exists(&save_file_name(10)),
+        "Save file should exist"
+    );
 
-    let content = fs::read_to_string(&save_path).expect("Failed to read save file");
+    let content = storage.read_slot(10).expect("Failed to read save file");
 
     // Parse as RON
     let save_data: SaveData = ron::from_str(&content).expect("Failed to deserialize save file");
@@ -648,7 +640,102 @@ fn save_version_compatibility() {
         content.contains("version: 1"),
         "Save file should explicitly contain version field"
     );
+}
+
+#[test]
+fn level_light_states_persist_across_save_and_load() {
+    let storage = MemoryStorage::default();
+    let sconce_id = LevelEntityId { room: 1, index: 3 };
+
+    let mut app = app_with_storage(&storage);
+    app.add_plugins(RoomStatePlugin);
+    app.add_event::<ManualSaveEvent>();
+    app.add_event::<LoadGameEvent>();
+    app.add_systems(Update, (manual_save_system, load_game_system));
+    app.insert_resource(GameState::default());
+    app.insert_resource(MapState::default());
+
+    // The player's own candle is saved separately from level lights
+    app.world_mut()
+        .spawn((Candle, CandleWax(80.0), CandleState::Lit));
+    let sconce = app
+        .world_mut()
+        .spawn((Candle, sconce_id, CandleState::Lit))
+        .id();
+    app.update();
+
+    app.world_mut().send_event(ManualSaveEvent { slot: 13 });
+    app.update();
+    assert!(
+        storage.exists(&save_file_name(13)),
+        "Save file should be created"
+    );
+    let save_content = storage.read_slot(13).expect("Failed to read save");
+    assert!(save_content.contains("light_states"));
+
+    // Blow the sconce out, then reload the save
+    *app.world_mut().get_mut::<CandleState>(sconce).unwrap() = CandleState::Extinguished;
+    app.update();
+    assert_eq!(
+        app.world()
+            .resource::<RoomStateStore>()
+            .light_state(sconce_id),
+        Some(CandleState::Extinguished)
+    );
+
+    app.world_mut().send_event(LoadGameEvent { slot: 13 });
+    app.update();
+    app.update();
+
+    assert_eq!(
+        *app.world().get::<CandleState>(sconce).unwrap(),
+        CandleState::Lit,
+        "Loaded sconce state should be re-applied"
+    );
+}
 
-    // Cleanup
-    let _ = fs::remove_file(&save_path);
+#[test]
+fn daily_escape_keeps_its_day_remix_and_burn_across_save_and_load() {
+    let storage = MemoryStorage::default();
+    let mut app = app_with_storage(&storage);
+    app.add_plugins(RemixPlugin);
+    app.add_event::<AutoSaveEvent>();
+    app.add_event::<LoadGameEvent>();
+    app.add_systems(Update, (auto_save_system, load_game_system));
+    app.init_resource::<GameState>();
+    app.init_resource::<MapState>();
+
+    let day = 20_743;
+    app.insert_resource(GameRules::for_rule_set(GameRuleSet::Daily));
+    app.insert_resource(DailyChallenge {
+        day: Some(day),
+        result: None,
+    });
+    app.insert_resource(GameRng::from_seed(daily_seed(day)));
+    app.update();
+    let remix = app.world().resource::<ActiveRemix>().clone();
+
+    // Daily games only auto-save
+    app.world_mut().send_event(AutoSaveEvent);
+    app.update();
+
+    // A later, non-daily game
+    app.insert_resource(GameRules::default());
+    app.insert_resource(DailyChallenge::default());
+    app.world_mut().resource_mut::<GameRng>().reseed(7);
+    app.update();
+    assert!(!app.world().contains_resource::<ActiveRemix>());
+
+    app.world_mut().send_event(LoadGameEvent { slot: 0 });
+    app.update();
+    app.update();
+
+    assert_eq!(app.world().resource::<DailyChallenge>().day, Some(day));
+    assert_eq!(app.world().resource::<GameRng>().seed(), daily_seed(day));
+    assert_eq!(
+        app.world().resource::<GameRules>().burn_multiplier(),
+        DAILY_BURN_MULTIPLIER
+    );
+    assert_eq!(*app.world().resource::<ActiveRemix>(), remix);
+    assert_eq!(remix.0.cycle, DAILY_REMIX_CYCLE);
 }
@@ -0,0 +1,304 @@
+//! Property tests: any save data survives a RON round trip unchanged, and
+//! corrupted save content never panics the load path
+
+use proptest::prelude::*;
+use rust_game::components::room::LevelEntityId;
+use rust_game::resources::game_rules::{Difficulty, GameRuleSet};
+use rust_game::resources::game_stats::GameStats;
+use rust_game::resources::map_state::{DeathCause, DeathRecord};
+use rust_game::systems::save_load::*;
+use rust_game::systems::save_migration::{decode_save, parse_save, write_save};
+use rust_game::systems::victory::Cosmetic;
+
+/// Finite floats of every magnitude, including subnormals and -0.0
+///
+/// NaN is left out because it never compares equal to itself.
+fn finite_f32() -> impl Strategy<Value = f32> {
+    use proptest::num::f32::*;
+    POSITIVE | NEGATIVE | NORMAL | SUBNORMAL | ZERO
+}
+
+fn position() -> impl Strategy<Value = (f32, f32)> {
+    (finite_f32(), finite_f32())
+}
+
+fn level_entity_id() -> impl Strategy<Value = LevelEntityId> {
+    (any::<usize>(), any::<usize>()).prop_map(|(room, index)| LevelEntityId { room, index })
+}
+
+fn serialized_item() -> impl Strategy<Value = SerializedItem> {
+    let key = prop_oneof![
+        Just(SerializedKeyType::Brass),
+        Just(SerializedKeyType::Iron),
+        Just(SerializedKeyType::Ornate),
+        Just(SerializedKeyType::Master),
+    ];
+    let tool = prop_oneof![
+        Just(SerializedToolType::Wrench),
+        Just(SerializedToolType::Crowbar),
+        Just(SerializedToolType::WireCutters),
+        Just(SerializedToolType::Magnet),
+        Just(SerializedToolType::OilCan),
+        Just(SerializedToolType::Ladder),
+        Just(SerializedToolType::GasMask),
+        Just(SerializedToolType::GrapplingRope),
+    ];
+    let puzzle_item = prop_oneof![
+        Just(SerializedPuzzleItemType::Fuse),
+        Just(SerializedPuzzleItemType::GemstoneRed),
+        Just(SerializedPuzzleItemType::GemstoneGreen),
+        Just(SerializedPuzzleItemType::GemstoneBlue),
+        Just(SerializedPuzzleItemType::CircuitComponent),
+    ];
+    prop_oneof![
+        Just(SerializedItem::Match),
+        key.prop_map(SerializedItem::Key),
+        tool.prop_map(SerializedItem::Tool),
+        puzzle_item.prop_map(SerializedItem::PuzzleItem),
+        Just(SerializedItem::DoubleJumpItem),
+        any::<usize>().prop_map(SerializedItem::DiaryPage),
+        finite_f32().prop_map(SerializedItem::CandleStub),
+        any::<u32>().prop_map(SerializedItem::OilCan),
+    ]
+}
+
+fn candle_state() -> impl Strategy<Value = SerializedCandleState> {
+    prop_oneof![
+        Just(SerializedCandleState::Unlit),
+        Just(SerializedCandleState::Lit),
+        Just(SerializedCandleState::Extinguished),
+    ]
+}
+
+fn game_mode() -> impl Strategy<Value = SerializedGameMode> {
+    prop_oneof![
+        Just(SerializedGameMode::Menu),
+        Just(SerializedGameMode::Playing),
+        Just(SerializedGameMode::Paused),
+        Just(SerializedGameMode::GameOver),
+        Just(SerializedGameMode::Victory),
+    ]
+}
+
+fn death_record() -> impl Strategy<Value = DeathRecord> {
+    let cause = prop_oneof![
+        Just(DeathCause::Spikes),
+        Just(DeathCause::FallingChandelier),
+        Just(DeathCause::CollapsingFloor),
+        Just(DeathCause::Pendulum),
+        Just(DeathCause::ArrowTrap),
+        Just(DeathCause::SwingingBlade),
+        Just(DeathCause::PoisonGas),
+        Just(DeathCause::Drowning),
+        Just(DeathCause::Electrocution),
+        Just(DeathCause::Unknown),
+    ];
+    (position(), cause).prop_map(|(position, cause)| DeathRecord { position, cause })
+}
+
+fn game_stats() -> impl Strategy<Value = GameStats> {
+    (
+        finite_f32(),
+        any::<u32>(),
+        any::<u32>(),
+        any::<u32>(),
+        any::<u32>(),
+        prop::collection::btree_set(any::<usize>(), 0..50),
+    )
+        .prop_map(
+            |(
+                distance_walked,
+                matches_used,
+                candles_relit,
+                traps_triggered,
+                puzzles_solved,
+                rooms_visited,
+            )| GameStats {
+                distance_walked,
+                matches_used,
+                candles_relit,
+                traps_triggered,
+                puzzles_solved,
+                rooms_visited,
+            },
+        )
+}
+
+fn save_data() -> impl Strategy<Value = SaveData> {
+    let progress = (
+        any::<u32>(),
+        any::<usize>(),
+        position(),
+        prop::collection::vec(serialized_item(), 0..40),
+        finite_f32(),
+        candle_state(),
+        // Long explored-room lists, as a completionist run would have
+        prop::collection::vec(any::<usize>(), 0..2_000),
+        any::<u64>(),
+        any::<u32>(),
+        any::<usize>(),
+        any::<bool>(),
+        game_mode(),
+    );
+    let world = (
+        (finite_f32(), finite_f32()),
+        prop::collection::hash_map(
+            any::<usize>(),
+            prop::collection::vec(death_record(), 0..8),
+            0..8,
+        ),
+        prop::collection::vec((level_entity_id(), candle_state()), 0..16),
+        prop::collection::vec((level_entity_id(), position()), 0..16),
+        prop::collection::vec(level_entity_id(), 0..16),
+        prop::collection::vec(any::<String>(), 0..8),
+        prop::collection::vec(level_entity_id(), 0..16),
+        prop::collection::hash_map(
+            any::<usize>(),
+            prop::collection::vec(position(), 0..4),
+            0..8,
+        ),
+        prop::collection::vec((serialized_item(), any::<u32>()), 0..8),
+        prop::collection::vec(prop::option::of(serialized_item()), 0..8),
+        prop_oneof![
+            Just(GameRuleSet::Normal),
+            Just(GameRuleSet::Survival),
+            Just(GameRuleSet::Explorer),
+            Just(GameRuleSet::Daily),
+        ],
+        (
+            prop_oneof![
+                Just(Difficulty::Easy),
+                Just(Difficulty::Normal),
+                Just(Difficulty::Hard),
+            ],
+            game_stats(),
+            prop::option::of(any::<u64>()),
+            any::<u32>(),
+            prop::collection::vec(
+                prop_oneof![
+                    Just(Cosmetic::EmberFlame),
+                    Just(Cosmetic::PaleFlame),
+                    Just(Cosmetic::GoldenFlame),
+                ],
+                0..4,
+            ),
+            prop::option::of(any::<u64>()),
+        ),
+    );
+
+    (progress, world).prop_map(
+        |(
+            (
+                version,
+                current_room,
+                player_position,
+                inventory_items,
+                candle_wax,
+                candle_state,
+                explored_rooms,
+                completion_time_secs,
+                deaths,
+                collected_secrets,
+                double_jump_unlocked,
+                game_mode,
+            ),
+            (
+                (hit_points, stamina),
+                death_records,
+                light_states,
+                crate_positions,
+                broken_objects,
+                cut_wires,
+                oiled_objects,
+                secret_passages,
+                inventory_stacks,
+                hotbar,
+                rules,
+                (
+                    difficulty,
+                    stats,
+                    rng_seed,
+                    new_game_plus_cycle,
+                    new_game_plus_cosmetics,
+                    daily_day,
+                ),
+            ),
+        )| SaveData {
+            version,
+            current_room,
+            player_position,
+            inventory_items,
+            candle_wax,
+            candle_state,
+            explored_rooms,
+            completion_time_secs,
+            deaths,
+            collected_secrets,
+            double_jump_unlocked,
+            game_mode,
+            hit_points,
+            stamina,
+            death_records,
+            light_states,
+            crate_positions,
+            broken_objects,
+            cut_wires,
+            oiled_objects,
+            secret_passages,
+            inventory_stacks,
+            hotbar,
+            rules,
+            difficulty,
+            stats,
+            rng_seed,
+            new_game_plus_cycle,
+            new_game_plus_cosmetics,
+            daily_day,
+        },
+    )
+}
+
+/// Feeds content through everything `load_game_system` does before
+/// touching the world; returning at all is the property
+fn load(content: &str) {
+    if let Ok((save_data, _)) = decode_save(content) {
+        deserialize_inventory(&save_data.inventory_items, &save_data.inventory_stacks);
+        deserialize_hotbar(&save_data.hotbar);
+    }
+}
+
+proptest! {
+    #[test]
+    fn save_data_round_trips_through_ron(save_data in save_data()) {
+        let content = write_save(&save_data).unwrap();
+        prop_assert_eq!(parse_save(&content).unwrap(), save_data);
+    }
+
+    #[test]
+    fn truncated_saves_never_panic(save_data in save_data(), cut in any::<prop::sample::Index>()) {
+        let content = write_save(&save_data).unwrap();
+        let mut end = cut.index(content.len() + 1);
+        while !content.is_char_boundary(end) {
+            end -= 1;
+        }
+        load(&content[..end]);
+    }
+
+    #[test]
+    fn corrupted_saves_never_panic(
+        save_data in save_data(),
+        edits in prop::collection::vec((any::<prop::sample::Index>(), any::<u8>()), 1..16),
+    ) {
+        let mut bytes = write_save(&save_data).unwrap().into_bytes();
+        for (at, byte) in edits {
+            let at = at.index(bytes.len());
+            bytes[at] = byte;
+        }
+        load(&String::from_utf8_lossy(&bytes));
+    }
+
+    #[test]
+    fn arbitrary_text_never_panics(content in any::<String>()) {
+        load(&content);
+    }
+}
@@ -0,0 +1,40 @@
+//! Integration test: Room rendering visual regression
+//!
+//! Renders every fixture room in assets/levels/ headlessly through a fixed
+//! camera and compares the frame against tests/golden/<room>.png, catching
+//! regressions in the tilemap, lighting overlay and z-ordering.
+//!
+//! Needs a GPU adapter (software adapters such as lavapipe work), so it
+//! only runs with the dev feature:
+//!
+//! ```text
+//! cargo test --features visual-regression --test visual_regression
+//! ```
+//!
+//! Missing golden images are created on the first run; rerun with
+//! `UPDATE_SNAPSHOTS=1` to accept an intentional rendering change.
+
+#[cfg(feature = "visual-regression")]
+mod golden_tests {
+    use rust_game::systems::visual_regression::{
+        FIXTURE_LEVEL_DIR, Tolerance, capture_room, check_golden, fixture_rooms,
+    };
+    use std::path::Path;
+
+    #[test]
+    fn fixture_rooms_match_golden_images() {
+        let rooms = fixture_rooms(Path::new(FIXTURE_LEVEL_DIR)).expect("fixture rooms should load");
+        assert!(!rooms.is_empty(), "No fixture rooms to capture");
+
+        let failures: Vec<String> = rooms
+            .iter()
+            .filter_map(|(name, level)| {
+                capture_room(level)
+                    .and_then(|frame| check_golden(name, &frame, &Tolerance::default()))
+                    .err()
+            })
+            .collect();
+
+        assert!(failures.is_empty(), "{}", failures.join("\n"));
+    }
+}